@@ -14,7 +14,7 @@ use {
     std::{
         collections::{HashMap, HashSet},
         env::var,
-        io::{self, Cursor, Write},
+        io::Cursor,
         mem,
         os::unix::ffi::OsStrExt,
         path::{Path, PathBuf},
@@ -23,10 +23,9 @@ use {
         sync::{Arc, LazyLock},
     },
     thiserror::Error,
-    uapi::c,
     wl_proxy::{
-        object::ObjectCoreApi,
         protocols::wayland::{wl_buffer::WlBuffer, wl_shm::WlShmFormat},
+        shm::{OwnedShmBuffer, ShmError},
     },
 };
 
@@ -669,21 +668,15 @@ fn create_shm_buf(
     g: &Globals,
     data: &[u8],
     buffer_size: [i32; 2],
-) -> Result<Rc<WlBuffer>, io::Error> {
-    let mut memfd = uapi::memfd_create("", c::MFD_CLOEXEC | c::MFD_ALLOW_SEALING)?;
-    uapi::fcntl_add_seals(memfd.raw(), c::F_SEAL_SHRINK)?;
-    memfd.write_all(data)?;
-    let pool = g
-        .wl_shm
-        .new_send_create_pool(&Rc::new(memfd.into()), data.len() as _);
-    let buffer = pool.new_send_create_buffer(
-        0,
+) -> Result<Rc<WlBuffer>, ShmError> {
+    let mut buffer = OwnedShmBuffer::new(
+        &g.wl_shm,
         buffer_size[0],
         buffer_size[1],
         buffer_size[0] * 4,
         WlShmFormat::ARGB8888,
-    );
-    buffer.set_forward_to_client(false);
-    pool.send_destroy();
-    Ok(buffer)
+    )?;
+    let len = data.len().min(buffer.data().len());
+    buffer.data_mut()[..len].copy_from_slice(&data[..len]);
+    Ok(buffer.wl_buffer().clone())
 }
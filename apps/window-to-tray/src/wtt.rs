@@ -6,18 +6,16 @@ use {
         icon::{BufferIcon, BufferIconFrame, IconTemplate},
     },
     arrayvec::ArrayVec,
+    error_reporter::Report,
     linearize::{Linearize, StaticMap},
     run_on_drop::on_drop,
     std::{
-        collections::{HashMap, VecDeque},
-        io::Write,
+        collections::HashMap,
         mem,
-        os::fd::OwnedFd,
         process::{Command, exit},
         rc::Rc,
         sync::Arc,
     },
-    uapi::c,
     wl_proxy::{
         baseline::Baseline,
         fixed::Fixed,
@@ -65,7 +63,7 @@ use {
             },
             viewporter::{wp_viewport::WpViewport, wp_viewporter::WpViewporter},
             wayland::{
-                wl_buffer::{WlBuffer, WlBufferHandler},
+                wl_buffer::WlBuffer,
                 wl_callback::{WlCallback, WlCallbackHandler},
                 wl_compositor::{WlCompositor, WlCompositorHandler},
                 wl_display::{WlDisplay, WlDisplayHandler},
@@ -78,8 +76,7 @@ use {
                 wl_region::{WlRegion, WlRegionHandler},
                 wl_registry::{WlRegistry, WlRegistryHandler},
                 wl_seat::{WlSeat, WlSeatCapability, WlSeatHandler},
-                wl_shm::{WlShm, WlShmFormat, WlShmHandler},
-                wl_shm_pool::{WlShmPool, WlShmPoolHandler},
+                wl_shm::{WlShm, WlShmFormat},
                 wl_subcompositor::{WlSubcompositor, WlSubcompositorHandler},
                 wl_subsurface::{WlSubsurface, WlSubsurfaceHandler},
                 wl_surface::{WlSurface, WlSurfaceHandler},
@@ -113,7 +110,9 @@ use {
                 xdg_toplevel_icon_v1::{XdgToplevelIconV1, XdgToplevelIconV1Handler},
             },
         },
+        shm::{self, OwnedShmBuffer, ShmBuffer},
         simple::{SimpleCommandExt, SimpleProxy},
+        xdg_tracker::{TrackedXdgSurface, XdgTracker},
     },
 };
 
@@ -283,6 +282,7 @@ struct ClientWlSurface {
 struct ClientXdgWmBase {
     wl_display: Rc<WlDisplay>,
     globals: Rc<Globals>,
+    xdg_tracker: Rc<XdgTracker>,
 }
 
 /// Handler for all proxy-created wl_surface objects that are the subsurface parents of
@@ -314,11 +314,9 @@ struct ClientXdgSurface {
     geometry: [i32; 4],
     /// The geometry of this xdg_surface set but not yet committed by the client.
     pending_geometry: Option<[i32; 4]>,
-    /// The next configure serial we send to the client.
-    next_client_serial: u64,
-    /// The pending configure serials we've sent to the client that have not yet been
-    /// ack'd. The second field is the corresponding server serial.
-    pending_serials: VecDeque<(u64, Option<u32>)>,
+    /// The configure state of the client's xdg_surface. This maps the configure serials
+    /// we send to the client to the server serials.
+    xdg: Rc<TrackedXdgSurface>,
 
     // popups
     /// The client's xdg_popup extension, if any.
@@ -338,7 +336,7 @@ struct ClientXdgSurface {
     map_unpon_configure: bool,
     /// Whether this xdg_surface should be mapped when the client sends the next
     /// ack_configure message.
-    map_unpon_ack: Option<u64>,
+    map_unpon_ack: Option<u32>,
     /// The borders drawn around the xdg_toplevel surface.
     tray_popup_borders: Option<StaticMap<WindowEdge, TrayPopupBorder>>,
     /// The last configure size sent to the client.
@@ -583,26 +581,7 @@ struct ClientXdgToplevelIconV1 {
     /// The name set by the client.
     name: Option<String>,
     /// The buffers set by the client.
-    buffers: HashMap<([i32; 2], i32), Rc<WlBuffer>>,
-}
-
-/// Handler for client-created wl_shm objects.
-struct ClientWlShm;
-
-/// Handler for client-created wl_shm_pool objects.
-struct ClientWlShmPool {
-    fd: Rc<OwnedFd>,
-    size: i32,
-}
-
-/// Handler for client-created wl_buffer objects that are backed by wl_shm.
-struct ClientShmWlBuffer {
-    pool_fd: Rc<OwnedFd>,
-    pool_size: i32,
-    offset: i32,
-    size: [i32; 2],
-    stride: i32,
-    format: WlShmFormat,
+    buffers: HashMap<([i32; 2], i32), Rc<ShmBuffer>>,
 }
 
 impl WlDisplayHandler for ClientWlDisplay {
@@ -812,6 +791,7 @@ impl WlRegistryHandler for ClientWlRegistry {
                 id.downcast::<XdgWmBase>().set_handler(ClientXdgWmBase {
                     wl_display: self.wl_display.clone(),
                     globals: self.globals.clone(),
+                    xdg_tracker: XdgTracker::new(),
                 });
                 return;
             }
@@ -829,7 +809,7 @@ impl WlRegistryHandler for ClientWlRegistry {
                 id.downcast::<WlSeat>().set_handler(ClientWlSeat);
             }
             WlShm::INTERFACE => {
-                id.downcast::<WlShm>().set_handler(ClientWlShm);
+                shm::track_shm(&id.downcast::<WlShm>());
             }
             ZwpRelativePointerManagerV1::INTERFACE => {
                 id.downcast::<ZwpRelativePointerManagerV1>()
@@ -1066,6 +1046,7 @@ impl WlSurfaceHandler for ClientWlSurface {
             return;
         };
         let h = &mut *xdg_surface.get_handler_mut::<ClientXdgSurface>();
+        h.xdg.commit();
         match (old_attached, new_attached) {
             (false, false) => {
                 slf.send_commit();
@@ -1111,8 +1092,10 @@ impl ClientXdgSurface {
             let width = self.geometry[2];
             let height = self.geometry[3];
             client_xdg_toplevel.send_configure(width, height, uapi::as_bytes(&*states));
-            let serial = self.create_client_serial(None);
-            xdg_surface.send_configure(serial as u32);
+            self.xdg
+                .toplevel_configure(width, height, uapi::as_bytes(&*states));
+            let serial = self.xdg.configure(None);
+            xdg_surface.send_configure(serial);
         }
     }
 
@@ -1211,23 +1194,6 @@ impl ClientXdgSurface {
         self.jay_tray_items.insert(name, jay_tray_item_v1);
     }
 
-    fn create_client_serial(&mut self, server_serial: Option<u32>) -> u64 {
-        let client_serial = self.next_client_serial;
-        self.next_client_serial += 1;
-        self.pending_serials
-            .push_back((client_serial, server_serial));
-        client_serial
-    }
-
-    fn recover_client_serial(&self, client_serial: u32) -> u64 {
-        let mut client_serial =
-            self.next_client_serial & !(u32::MAX as u64) | (client_serial as u64);
-        if client_serial >= self.next_client_serial {
-            client_serial = client_serial.saturating_sub(u32::MAX as u64 + 1);
-        }
-        client_serial
-    }
-
     fn set_border_scale(&mut self, window_edge: WindowEdge, scale: i32) {
         self.tray_popup_borders.as_mut().unwrap()[window_edge].scale = scale;
         self.ensure_borders();
@@ -1358,24 +1324,18 @@ impl ClientXdgSurface {
                     mem[y * required_size[0]..][..required_size[0]].fill(v);
                 }
             }
-            let mut fd = uapi::memfd_create("", c::MFD_CLOEXEC | c::MFD_ALLOW_SEALING).unwrap();
-            uapi::fcntl_add_seals(fd.raw(), c::F_SEAL_SHRINK).unwrap();
-            fd.write_all(uapi::as_bytes(&*mem)).unwrap();
-            let wl_shm_pool = self
-                .globals
-                .wl_shm
-                .new_send_create_pool(&Rc::new(fd.into()), size_of_val(&*mem) as _);
-            let wl_buffer = wl_shm_pool.new_send_create_buffer(
-                0,
+            let mut buffer = OwnedShmBuffer::new(
+                &self.globals.wl_shm,
                 required_size[0] as i32,
                 required_size[1] as i32,
                 required_size[0] as i32 * 4,
                 WlShmFormat::ARGB8888,
-            );
-            v.wl_surface.send_attach(Some(&wl_buffer), 0, 0);
+            )
+            .unwrap();
+            buffer.data_mut().copy_from_slice(uapi::as_bytes(&*mem));
+            v.wl_surface.send_attach(Some(buffer.wl_buffer()), 0, 0);
             v.wl_surface.send_commit();
-            wl_buffer.send_destroy();
-            wl_shm_pool.send_destroy();
+            buffer.wl_buffer().send_destroy();
             v.buffer_size = required_size;
         }
     }
@@ -1431,8 +1391,9 @@ impl XdgWmBaseHandler for ClientXdgWmBase {
             pending_geometry: None,
             map_unpon_configure: Default::default(),
             map_unpon_ack: None,
-            next_client_serial: 1,
-            pending_serials: Default::default(),
+            xdg: self
+                .xdg_tracker
+                .create_xdg_surface(client_xdg_surface, client_surface),
             tray_popup_borders: Default::default(),
             last_configure_size: None,
             ignore_configure: false,
@@ -1455,12 +1416,16 @@ impl XdgSurfaceHandler for ClientXdgSurface {
         self.subsurface.send_destroy();
         self.proxy_wl_surface.unset_handler();
         self.proxy_wl_surface.send_destroy();
+        self.xdg.destroy();
         slf.unset_handler();
         slf.delete_id();
     }
 
     fn handle_get_toplevel(&mut self, slf: &Rc<XdgSurface>, id: &Rc<XdgToplevel>) {
         id.set_forward_to_server(false);
+        if let Err(e) = self.xdg.set_toplevel(id) {
+            log::warn!("Could not track the toplevel: {}", Report::new(e));
+        }
         let display = &mut *self.wl_display.get_handler_mut::<ClientWlDisplay>();
         display.toplevels.insert(id.unique_id(), slf.clone());
         id.set_handler(ClientXdgToplevel {
@@ -1539,6 +1504,12 @@ impl XdgSurfaceHandler for ClientXdgSurface {
                 .client_popups
                 .insert(id.unique_id(), id.clone());
         }
+        if let Err(e) = self
+            .xdg
+            .set_popup(id, parent_xdg_surface.as_ref().map(|p| &p.xdg))
+        {
+            log::warn!("Could not track the popup: {}", Report::new(e));
+        }
         let parent = parent_xdg_surface.as_ref().map(|p| &p.proxy_xdg_surface);
         self.client_xdg_popup = Some(id.clone());
         let proxy_xdg_popup = self
@@ -1565,23 +1536,18 @@ impl XdgSurfaceHandler for ClientXdgSurface {
             .set_window_geometry(&self.client_wl_surface, Some([x, y, width, height]));
         self.proxy_xdg_surface
             .send_set_window_geometry(x, y, width, height);
+        self.xdg.set_window_geometry(x, y, width, height);
         self.pending_geometry = Some([x, y, width, height]);
     }
 
     fn handle_ack_configure(&mut self, _slf: &Rc<XdgSurface>, client_serial: u32) {
-        let client_serial = self.recover_client_serial(client_serial);
-        while let Some(&(expected, server_serial)) = self.pending_serials.front() {
-            if expected <= client_serial {
-                if let Some(server_serial) = server_serial {
-                    self.proxy_xdg_surface.send_ack_configure(server_serial);
-                }
-                self.pending_serials.pop_front();
-            } else {
-                break;
-            }
+        match self.xdg.ack_configure(client_serial) {
+            Ok(Some(server_serial)) => self.proxy_xdg_surface.send_ack_configure(server_serial),
+            Ok(None) => {}
+            Err(e) => log::warn!("Client sent an invalid ack: {}", Report::new(e)),
         }
         if let Some(serial) = self.map_unpon_ack
-            && serial <= client_serial
+            && self.xdg.is_acked(serial)
         {
             self.map_unpon_ack = None;
             self.ensure_borders();
@@ -2181,7 +2147,7 @@ impl XdgSurfaceHandler for ProxyXdgSurface {
             .get_handler_mut::<ClientXdgSurface>();
         let client_serial;
         if h.ignore_configure {
-            if h.pending_serials.is_empty() {
+            let Some(serial) = h.xdg.coalesce_configure(server_serial) else {
                 slf.send_ack_configure(server_serial);
                 if h.map_unpon_configure {
                     h.map_unpon_configure = false;
@@ -2191,14 +2157,11 @@ impl XdgSurfaceHandler for ProxyXdgSurface {
                     h.proxy_wl_surface.send_commit();
                 }
                 return;
-            } else {
-                client_serial = h.next_client_serial - 1;
-                h.pending_serials
-                    .push_back((client_serial, Some(server_serial)));
-            }
+            };
+            client_serial = serial;
         } else {
-            client_serial = h.create_client_serial(Some(server_serial));
-            self.client_xdg_surface.send_configure(client_serial as u32);
+            client_serial = h.xdg.configure(Some(server_serial));
+            self.client_xdg_surface.send_configure(client_serial);
         }
         if h.map_unpon_configure {
             h.map_unpon_configure = false;
@@ -2245,6 +2208,9 @@ impl XdgPopupHandler for ProxyTrayXdgPopup {
             height,
             uapi::as_bytes(&*states),
         );
+        client_xdg_surface
+            .xdg
+            .toplevel_configure(width, height, uapi::as_bytes(&*states));
     }
 
     fn handle_popup_done(&mut self, slf: &Rc<XdgPopup>) {
@@ -2496,17 +2462,17 @@ impl XdgToplevelIconManagerV1Handler for ClientXdgToplevelIconManagerV1 {
             let handler = icon.get_handler_mut::<ClientXdgToplevelIconV1>();
             name = handler.name.clone();
             for (&(size, scale), buffer) in &handler.buffers {
-                let buffer = buffer.get_handler_mut::<ClientShmWlBuffer>();
+                let pool = buffer.pool();
                 let wl_shm_pool = self
                     .globals
                     .wl_shm
-                    .new_send_create_pool(&buffer.pool_fd, buffer.pool_size);
+                    .new_send_create_pool(pool.fd(), pool.size());
                 let wl_buffer = wl_shm_pool.new_send_create_buffer(
-                    buffer.offset,
-                    buffer.size[0],
-                    buffer.size[1],
-                    buffer.stride,
-                    buffer.format,
+                    buffer.offset(),
+                    buffer.width(),
+                    buffer.height(),
+                    buffer.stride(),
+                    buffer.format(),
                 );
                 wl_buffer.set_forward_to_client(false);
                 icons.push(Rc::new(BufferIconFrame {
@@ -2545,52 +2511,14 @@ impl XdgToplevelIconV1Handler for ClientXdgToplevelIconV1 {
         buffer: &Rc<WlBuffer>,
         scale: i32,
     ) {
-        let size = buffer.get_handler_mut::<ClientShmWlBuffer>().size;
-        self.buffers.insert((size, scale), buffer.clone());
-    }
-}
-
-impl WlShmHandler for ClientWlShm {
-    fn handle_create_pool(
-        &mut self,
-        slf: &Rc<WlShm>,
-        id: &Rc<WlShmPool>,
-        fd: &Rc<OwnedFd>,
-        size: i32,
-    ) {
-        id.set_handler(ClientWlShmPool {
-            fd: fd.clone(),
-            size,
-        });
-        slf.send_create_pool(id, fd, size);
-    }
-}
-
-impl WlShmPoolHandler for ClientWlShmPool {
-    fn handle_create_buffer(
-        &mut self,
-        slf: &Rc<WlShmPool>,
-        id: &Rc<WlBuffer>,
-        offset: i32,
-        width: i32,
-        height: i32,
-        stride: i32,
-        format: WlShmFormat,
-    ) {
-        id.set_handler(ClientShmWlBuffer {
-            pool_fd: self.fd.clone(),
-            pool_size: self.size,
-            offset,
-            size: [width, height],
-            stride,
-            format,
-        });
-        slf.send_create_buffer(id, offset, width, height, stride, format);
+        let Some(buffer) = ShmBuffer::get(buffer) else {
+            return;
+        };
+        let size = [buffer.width(), buffer.height()];
+        self.buffers.insert((size, scale), buffer);
     }
 }
 
-impl WlBufferHandler for ClientShmWlBuffer {}
-
 #[cfg(test)]
 mod tests {
     use super::{SCALE_BASE, round_scale};
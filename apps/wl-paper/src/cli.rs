@@ -40,6 +40,16 @@ struct WlPaper {
     /// The namespace.
     #[clap(long, default_value = "")]
     namespace: String,
+    /// Pass the connection to the program via WAYLAND_SOCKET.
+    ///
+    /// By default, the program connects to a socket in XDG_RUNTIME_DIR that other
+    /// processes can connect to as well. If this flag is used, no such socket is created
+    /// and WAYLAND_DISPLAY is removed from the environment of the program. Only the first
+    /// wayland client started by the program connects to the proxy. Later clients fall
+    /// back to the default display wayland-0 and bypass the proxy if a compositor listens
+    /// on that socket.
+    #[clap(long)]
+    wayland_socket: bool,
     /// The program to run.
     #[clap(
         trailing_var_arg = true,
@@ -90,5 +100,5 @@ pub fn main() -> Result<(), PaperError> {
         margin_left: args.margin_left,
         namespace: args.namespace,
     };
-    paper::main(config, &args.program.unwrap())
+    paper::main(config, args.wayland_socket, &args.program.unwrap())
 }
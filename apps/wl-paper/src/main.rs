@@ -14,6 +14,8 @@ enum PaperError {
     SpawnChild(#[source] io::Error),
     #[error("the server terminated")]
     ServerFailed(#[source] SimpleProxyError),
+    #[error("could not wait for child")]
+    WaitChild(#[source] io::Error),
 }
 
 fn main() -> Result<(), Report<PaperError>> {
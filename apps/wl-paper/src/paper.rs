@@ -48,25 +48,40 @@ use {
                 xdg_wm_base::{XdgWmBase, XdgWmBaseHandler},
             },
         },
-        simple::{SimpleCommandExt, SimpleProxy},
+        simple::{SimpleCommandExt, SimpleProxy, SingleClientProxy, wait_and_forward_exit_code},
     },
 };
 
-pub fn main(config: Config, program: &[String]) -> Result<(), PaperError> {
+pub fn main(config: Config, wayland_socket: bool, program: &[String]) -> Result<(), PaperError> {
     let config = Arc::new(config);
+    let display_handler = || ClientWlDisplay {
+        init: false,
+        layer_shell: Default::default(),
+        config: config.clone(),
+        registries_without_handlers: Default::default(),
+        wl_fixes: Default::default(),
+    };
+    if wayland_socket {
+        let mut server = SingleClientProxy::new(Baseline::V5).map_err(PaperError::CreateServer)?;
+        let mut child = Command::new(&program[0])
+            .args(&program[1..])
+            .with_wayland_socket(server.take_socket().unwrap())
+            .spawn()
+            .map_err(PaperError::SpawnChild)?;
+        server
+            .run(display_handler())
+            .map_err(PaperError::ServerFailed)?;
+        return Err(PaperError::WaitChild(wait_and_forward_exit_code(
+            &mut child,
+        )));
+    }
     let server = SimpleProxy::new(Baseline::V5).map_err(PaperError::CreateServer)?;
     Command::new(&program[0])
         .args(&program[1..])
         .with_wayland_display(server.display())
         .spawn_and_forward_exit_code()
         .map_err(PaperError::SpawnChild)?;
-    let err = server.run(|| ClientWlDisplay {
-        init: false,
-        layer_shell: Default::default(),
-        config: config.clone(),
-        registries_without_handlers: Default::default(),
-        wl_fixes: Default::default(),
-    });
+    let err = server.run(display_handler);
     Err(PaperError::ServerFailed(err))
 }
 
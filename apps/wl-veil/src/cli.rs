@@ -29,6 +29,16 @@ struct WlVeil {
     /// Each filter should either be `<global_name>` to filter the global outright or
     /// `<global_name>=<version>` to downgrade the global to that version.
     filter: Vec<HashMap<String, Option<u32>>>,
+    /// Pass the connection to the program via WAYLAND_SOCKET.
+    ///
+    /// By default, the program connects to a socket in XDG_RUNTIME_DIR that other
+    /// processes can connect to as well. If this flag is used, no such socket is created
+    /// and WAYLAND_DISPLAY is removed from the environment of the program. Only the first
+    /// wayland client started by the program connects to the proxy. Later clients fall
+    /// back to the default display wayland-0 and bypass the proxy if a compositor listens
+    /// on that socket.
+    #[clap(long)]
+    wayland_socket: bool,
     #[clap(
         trailing_var_arg = true,
         value_hint = ValueHint::CommandWithArguments,
@@ -79,5 +89,10 @@ pub fn main() -> Result<(), VeilError> {
     for f in args.filter {
         filter.extend(f);
     }
-    veil::main(args.invert, filter, args.program.unwrap())
+    veil::main(
+        args.invert,
+        filter,
+        args.wayland_socket,
+        args.program.unwrap(),
+    )
 }
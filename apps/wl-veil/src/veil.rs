@@ -16,22 +16,36 @@ use {
                 wl_registry::{WlRegistry, WlRegistryHandler},
             },
         },
-        simple::{SimpleCommandExt, SimpleProxy},
+        simple::{SimpleCommandExt, SimpleProxy, SingleClientProxy, wait_and_forward_exit_code},
     },
 };
 
 pub fn main(
     invert: bool,
     filter: HashMap<String, Option<u32>>,
+    wayland_socket: bool,
     program: Vec<String>,
 ) -> Result<(), VeilError> {
+    let filter = create_filter(invert, filter);
+    if wayland_socket {
+        let mut server =
+            SingleClientProxy::new(Baseline::ALL_OF_THEM).map_err(VeilError::CreateServer)?;
+        let mut child = Command::new(&program[0])
+            .args(&program[1..])
+            .with_wayland_socket(server.take_socket().unwrap())
+            .spawn()
+            .map_err(VeilError::SpawnChild)?;
+        server
+            .run(WlDisplayHandlerImpl { filter })
+            .map_err(VeilError::ServerFailed)?;
+        return Err(VeilError::WaitChild(wait_and_forward_exit_code(&mut child)));
+    }
     let server = SimpleProxy::new(Baseline::ALL_OF_THEM).map_err(VeilError::CreateServer)?;
     Command::new(&program[0])
         .args(&program[1..])
         .with_wayland_display(server.display())
         .spawn_and_forward_exit_code()
         .map_err(VeilError::SpawnChild)?;
-    let filter = create_filter(invert, filter);
     let err = server.run(|| WlDisplayHandlerImpl {
         filter: filter.clone(),
     });
@@ -1,10 +1,10 @@
-pub(crate) struct Protocol {
-    pub(crate) name: String,
-    pub(crate) is_wayland: bool,
-    pub(crate) is_wlproxy_test: bool,
+pub struct Protocol {
+    pub name: String,
+    pub is_wayland: bool,
+    pub is_wlproxy_test: bool,
     pub(crate) _copyright: Option<Copyright>,
-    pub(crate) description: Option<Description>,
-    pub(crate) interfaces: Vec<Interface>,
+    pub description: Option<Description>,
+    pub interfaces: Vec<Interface>,
 }
 
 pub(crate) struct Copyright {
@@ -12,35 +12,35 @@ pub(crate) struct Copyright {
 }
 
 #[derive(Debug)]
-pub(crate) struct Description {
-    pub(crate) summary: Option<String>,
-    pub(crate) body: String,
+pub struct Description {
+    pub summary: Option<String>,
+    pub body: String,
 }
 
-pub(crate) struct Interface {
-    pub(crate) name: String,
-    pub(crate) version: u32,
-    pub(crate) is_wl_display: bool,
-    pub(crate) is_wl_registry: bool,
-    pub(crate) is_wl_fixes: bool,
-    pub(crate) description: Option<Description>,
-    pub(crate) messages: Vec<Message>,
-    pub(crate) enums: Vec<Enum>,
+pub struct Interface {
+    pub name: String,
+    pub version: u32,
+    pub is_wl_display: bool,
+    pub is_wl_registry: bool,
+    pub is_wl_fixes: bool,
+    pub description: Option<Description>,
+    pub messages: Vec<Message>,
+    pub enums: Vec<Enum>,
 }
 
 #[derive(Debug)]
-pub(crate) struct Arg {
-    pub(crate) name: String,
-    pub(crate) ty: ArgType,
-    pub(crate) summary: Option<String>,
-    pub(crate) description: Option<Description>,
-    pub(crate) interface: Option<String>,
-    pub(crate) allow_null: bool,
-    pub(crate) enum_: Option<String>,
+pub struct Arg {
+    pub name: String,
+    pub ty: ArgType,
+    pub summary: Option<String>,
+    pub description: Option<Description>,
+    pub interface: Option<String>,
+    pub allow_null: bool,
+    pub enum_: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub(crate) enum ArgType {
+pub enum ArgType {
     NewId,
     Int,
     Uint,
@@ -52,35 +52,35 @@ pub(crate) enum ArgType {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub(crate) enum MessageType {
+pub enum MessageType {
     Destructor,
 }
 
-pub(crate) struct Entry {
-    pub(crate) name: String,
-    pub(crate) value: String,
-    pub(crate) value_u32: u32,
-    pub(crate) summary: Option<String>,
-    pub(crate) since: Option<u32>,
-    pub(crate) deprecated_since: Option<u32>,
-    pub(crate) description: Option<Description>,
+pub struct Entry {
+    pub name: String,
+    pub value: String,
+    pub value_u32: u32,
+    pub summary: Option<String>,
+    pub since: Option<u32>,
+    pub deprecated_since: Option<u32>,
+    pub description: Option<Description>,
 }
 
-pub(crate) struct Enum {
-    pub(crate) name: String,
+pub struct Enum {
+    pub name: String,
     pub(crate) _since: Option<u32>,
-    pub(crate) bitfield: bool,
-    pub(crate) description: Option<Description>,
-    pub(crate) entries: Vec<Entry>,
+    pub bitfield: bool,
+    pub description: Option<Description>,
+    pub entries: Vec<Entry>,
 }
 
-pub(crate) struct Message {
-    pub(crate) name: String,
-    pub(crate) message_id: usize,
-    pub(crate) is_request: bool,
-    pub(crate) ty: Option<MessageType>,
-    pub(crate) since: Option<u32>,
-    pub(crate) deprecated_since: Option<u32>,
-    pub(crate) description: Option<Description>,
-    pub(crate) args: Vec<Arg>,
+pub struct Message {
+    pub name: String,
+    pub message_id: usize,
+    pub is_request: bool,
+    pub ty: Option<MessageType>,
+    pub since: Option<u32>,
+    pub deprecated_since: Option<u32>,
+    pub description: Option<Description>,
+    pub args: Vec<Arg>,
 }
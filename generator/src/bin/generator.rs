@@ -1,6 +1,6 @@
 use {
     error_reporter::Report,
-    generator::{generate, generate::GeneratorError},
+    wl_proxy_generator::{generate, generate::GeneratorError},
 };
 
 fn main() -> Result<(), Report<GeneratorError>> {
@@ -344,7 +344,7 @@ fn format_interface_message_functions(w: &mut impl Write, interface: &Interface)
                 ArgType::NewId => {
                     if arg.interface.is_none() {
                         flush_words!();
-                        wl!(r#"        fmt.string(arg{idx}.interface.name());"#)?;
+                        wl!(r#"        fmt.string(arg{idx}.interface_name());"#)?;
                         words.push(format!("arg{idx}.version"));
                     }
                     words.push(format!("arg{idx}_id"));
@@ -499,10 +499,161 @@ fn format_interface_message_functions(w: &mut impl Write, interface: &Interface)
             wl!(r#"    }}"#)?;
         }
     }
+    if interface.is_wl_registry {
+        format_registry_unknown_global_functions(w)?;
+    }
     wl!(r#"}}"#)?;
     Ok(())
 }
 
+fn format_registry_unknown_global_functions(w: &mut impl Write) -> io::Result<()> {
+    w.write_all(
+        br#"
+    /// announce global object with an unknown interface
+    ///
+    /// This is the same as [`Self::try_send_global`] but the interface is passed as a
+    /// string. This can be used to forward globals whose interface is not known to this
+    /// crate.
+    ///
+    /// See [`UnknownObject`](crate::unknown::UnknownObject).
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: numeric name of the global object
+    /// - `interface`: interface implemented by the object
+    /// - `version`: interface version
+    #[inline]
+    pub fn try_send_unknown_global(
+        &self,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) -> Result<(), ObjectError> {
+        let (
+            arg0,
+            arg1,
+            arg2,
+        ) = (
+            name,
+            interface,
+            version,
+        );
+        let core = self.core();
+        let client_ref = core.client.borrow();
+        let Some(client) = &*client_ref else {
+            return Err(ObjectError(ObjectErrorKind::ReceiverNoClient));
+        };
+        let id = core.client_obj_id.get().unwrap_or(0);
+        #[cfg(feature = "logging")]
+        if self.core.state.log {
+            #[cold]
+            fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str, arg2: u32) {
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_registry",
+                    id,
+                    "global",
+                    &[
+                        LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("interface"), LogValue::String(Some(arg1))),
+                        LogArg::new(Some("version"), LogValue::Uint(arg2)),
+                    ],
+                );
+            }
+            log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
+        }
+        let endpoint = &client.endpoint;
+        if !endpoint.flush_queued.replace(true) {
+            self.core.state.add_flushable_endpoint(endpoint, Some(client));
+        }
+        let mut outgoing_ref = endpoint.outgoing.borrow_mut();
+        let outgoing = &mut *outgoing_ref;
+        let mut fmt = outgoing.formatter();
+        fmt.words([
+            id,
+            0,
+            arg0,
+        ]);
+        fmt.string(arg1);
+        fmt.words([
+            arg2,
+        ]);
+        Ok(())
+    }
+
+    /// announce global object with an unknown interface
+    ///
+    /// This is the same as [`Self::send_global`] but the interface is passed as a
+    /// string. This can be used to forward globals whose interface is not known to this
+    /// crate.
+    ///
+    /// See [`UnknownObject`](crate::unknown::UnknownObject).
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: numeric name of the global object
+    /// - `interface`: interface implemented by the object
+    /// - `version`: interface version
+    #[inline]
+    pub fn send_unknown_global(
+        &self,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        let res = self.try_send_unknown_global(
+            name,
+            interface,
+            version,
+        );
+        if let Err(e) = res {
+            log_send("wl_registry.global", &e);
+        }
+    }
+"#,
+    )
+}
+
+fn format_registry_unknown_global_handler(w: &mut impl Write) -> io::Result<()> {
+    w.write_all(
+        br#"
+    /// announce global object with an unknown interface
+    ///
+    /// This handler is invoked instead of [`Self::handle_global`] if the interface of
+    /// the global is not known to this crate. This only happens if the state was
+    /// configured to forward such globals. See
+    /// [`StateBuilder::with_unknown_interfaces`](crate::state::StateBuilder::with_unknown_interfaces).
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: numeric name of the global object
+    /// - `interface`: interface implemented by the object
+    /// - `version`: interface version
+    #[inline]
+    fn handle_unknown_global(
+        &mut self,
+        slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        if !slf.core.forward_to_client.get() {
+            return;
+        }
+        let res = slf.try_send_unknown_global(
+            name,
+            interface,
+            version,
+        );
+        if let Err(e) = res {
+            log_forward("wl_registry.global", &e);
+        }
+    }
+"#,
+    )
+}
+
 fn format_message_since(w: &mut impl Write, message: &Message) -> io::Result<()> {
     format_since(
         w,
@@ -631,6 +782,9 @@ fn format_interface_message_handler(w: &mut impl Write, interface: &Interface) -
         wl!(r#"        }}"#)?;
         wl!(r#"    }}"#)?;
     }
+    if interface.is_wl_registry {
+        format_registry_unknown_global_handler(w)?;
+    }
     wl!(r#"}}"#)?;
     Ok(())
 }
@@ -699,6 +853,8 @@ pub fn format_mod_file(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
         wl!(r#"pub mod {};"#, protocol.name)?;
     }
     wl!()?;
+    wl!("pub(crate) use all_types::create_object_for_interface;")?;
+    wl!()?;
     wl!("#[allow(unused_imports)]")?;
     wl!("mod all_types {{")?;
     for (protocol, interface) in interfaces() {
@@ -722,13 +878,12 @@ pub fn format_mod_file(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
     wl!("    use crate::protocol_helpers::prelude::*;")?;
     wl!()?;
     wl!(
-        "    pub(super) fn create_object_for_interface(state: &Rc<State>, interface: &str, version: u32) -> Result<Rc<dyn Object>, ObjectError> {{"
+        "    pub(crate) fn create_object_for_interface(state: &Rc<State>, interface: &str, version: u32) -> Result<Rc<dyn Object>, ObjectError> {{"
     )?;
-    wl!("        ObjectInterface::from_str(interface)")?;
-    wl!(
-        "            .ok_or(ObjectError(ObjectErrorKind::UnsupportedInterface(interface.to_string())))"
-    )?;
-    wl!("            .and_then(|i| i.create_object(state, version))")?;
+    wl!("        let Some(i) = ObjectInterface::from_str(interface) else {{")?;
+    wl!("            return crate::unknown::create_unknown_object(state, interface, version);")?;
+    wl!("        }};")?;
+    wl!("        i.create_object(state, version)")?;
     wl!("    }}")?;
     wl!()?;
     wl!("    impl ObjectInterface {{")?;
@@ -777,6 +932,11 @@ pub fn format_mod_file(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
         wl!(r#"                    Ok({PREFIX}{camel}::new(state, version))"#)?;
         wl!(r#"                }}"#)?;
     }
+    wl!(r#"                Self::Unknown => {{"#)?;
+    wl!(
+        r#"                    Err(ObjectError(ObjectErrorKind::UnsupportedInterface(self.name().to_string())))"#
+    )?;
+    wl!(r#"                }}"#)?;
     wl!("            }}")?;
     wl!("        }}")?;
     wl!("    }}")?;
@@ -784,6 +944,7 @@ pub fn format_mod_file(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
     wl!()?;
     wl!("#[derive(Copy, Clone, Debug, Eq, PartialEq, linearize::Linearize)]")?;
     wl!("#[linearize(const)]")?;
+    wl!("#[non_exhaustive]")?;
     wl!("pub enum ObjectInterface {{")?;
     for (protocol, interface) in interfaces() {
         let snake = &interface.name;
@@ -792,6 +953,10 @@ pub fn format_mod_file(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
         write_cfg!(protocol, "    ");
         wl!(r#"    {camel},"#)?;
     }
+    wl!(r#"    /// An interface that is not known to this crate."#)?;
+    wl!(r#"    ///"#)?;
+    wl!(r#"    /// See [`UnknownObject`](crate::unknown::UnknownObject)."#)?;
+    wl!(r#"    Unknown,"#)?;
     wl!("}}")?;
     wl!()?;
     wl!("impl ObjectInterface {{")?;
@@ -803,6 +968,7 @@ pub fn format_mod_file(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
         write_cfg!(protocol, "            ");
         wl!(r#"            Self::{camel} => "{snake}","#)?;
     }
+    wl!(r#"            Self::Unknown => "unknown","#)?;
     wl!("        }}")?;
     wl!("    }}")?;
     wl!()?;
@@ -814,6 +980,7 @@ pub fn format_mod_file(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
         write_cfg!(protocol, "            ");
         wl!(r#"            Self::{camel} => {version},"#)?;
     }
+    wl!(r#"            Self::Unknown => 0,"#)?;
     wl!("        }}")?;
     wl!("    }}")?;
     wl!("}}")?;
@@ -876,6 +1043,305 @@ pub fn format_baseline_txt(w: &mut impl Write, suits: &[Suite]) -> io::Result<()
     Ok(())
 }
 
+pub fn format_wireshark_dissector(w: &mut impl Write, suits: &[Suite]) -> io::Result<()> {
+    define_w!(w);
+    let mut interfaces: Vec<_> = suits
+        .iter()
+        .flat_map(|s| s.protocols.iter())
+        .filter(|p| !p.is_wlproxy_test)
+        .flat_map(|p| p.interfaces.iter())
+        .collect();
+    interfaces.sort_by_key(|i| &i.name);
+    w.write_all(WIRESHARK_HEADER.as_bytes())?;
+    wl!()?;
+    wl!(r#"local interfaces = {{"#)?;
+    for interface in interfaces {
+        wl!(r#"    ["{}"] = {{"#, interface.name)?;
+        for (field, requests) in [("requests", true), ("events", false)] {
+            if interface.messages.iter().all(|m| m.is_request != requests) {
+                wl!(r#"        {field} = {{}},"#)?;
+                continue;
+            }
+            wl!(r#"        {field} = {{"#)?;
+            for msg in &interface.messages {
+                if msg.is_request != requests {
+                    continue;
+                }
+                w!(r#"            {{ "{}", {{"#, msg.name)?;
+                for (idx, arg) in msg.args.iter().enumerate() {
+                    if idx > 0 {
+                        w!(r#","#)?;
+                    }
+                    let ty = match arg.ty {
+                        ArgType::NewId => "new_id",
+                        ArgType::Int => "int",
+                        ArgType::Uint => "uint",
+                        ArgType::Fixed => "fixed",
+                        ArgType::String => "string",
+                        ArgType::Object => "object",
+                        ArgType::Array => "array",
+                        ArgType::Fd => "fd",
+                    };
+                    w!(r#" {{ "{}", "{ty}""#, arg.name)?;
+                    if let Some(interface) = &arg.interface {
+                        w!(r#", "{interface}""#)?;
+                    }
+                    w!(r#" }}"#)?;
+                }
+                if msg.args.is_not_empty() {
+                    w!(r#" "#)?;
+                }
+                wl!(r#"}} }},"#)?;
+            }
+            wl!(r#"        }},"#)?;
+        }
+        wl!(r#"    }},"#)?;
+    }
+    wl!(r#"}}"#)?;
+    wl!()?;
+    w.write_all(WIRESHARK_DISSECTOR.as_bytes())?;
+    Ok(())
+}
+
+const WIRESHARK_HEADER: &str = r#"-- This file was generated from the protocols in the wl-proxy repository. Do not edit.
+--
+-- A Wireshark dissector for pcapng files written by states built with
+-- StateBuilder::with_pcapng_recorder.
+--
+-- To install it, copy this file to the personal Wireshark plugin directory, e.g.
+-- ~/.local/lib/wireshark/plugins.
+"#;
+
+const WIRESHARK_DISSECTOR: &str = r#"local proto = Proto("wl_proxy", "Wayland (wl-proxy)")
+
+local fields = {
+    endpoint = ProtoField.uint64("wl_proxy.endpoint", "Endpoint"),
+    peer = ProtoField.uint8("wl_proxy.peer", "Peer", base.DEC, { [0] = "client", [1] = "server" }),
+    direction = ProtoField.uint8("wl_proxy.direction", "Direction", base.DEC, { [0] = "received", [1] = "sent" }),
+    fds = ProtoField.uint16("wl_proxy.fds", "File descriptors"),
+    object = ProtoField.uint32("wl_proxy.object", "Object"),
+    opcode = ProtoField.uint16("wl_proxy.opcode", "Opcode"),
+    size = ProtoField.uint16("wl_proxy.size", "Size"),
+    request = ProtoField.bool("wl_proxy.request", "Request"),
+    interface = ProtoField.string("wl_proxy.interface", "Interface"),
+    message = ProtoField.string("wl_proxy.message", "Message"),
+    arg = ProtoField.string("wl_proxy.arg", "Argument"),
+}
+
+proto.fields = {
+    fields.endpoint,
+    fields.peer,
+    fields.direction,
+    fields.fds,
+    fields.object,
+    fields.opcode,
+    fields.size,
+    fields.request,
+    fields.interface,
+    fields.message,
+    fields.arg,
+}
+
+-- The interfaces of the objects of each endpoint.
+local objects = {}
+-- The interfaces of the objects referenced by each frame. These are determined when
+-- the frame is dissected for the first time.
+local frames = {}
+
+function proto.init()
+    objects = {}
+    frames = {}
+end
+
+local function padded(len)
+    return math.floor((len + 3) / 4) * 4
+end
+
+-- Parses the arguments of a message. Returns a list of tables describing the
+-- arguments.
+local function parse_args(tvb, offset, stop, args, resolve)
+    local res = {}
+    for idx, arg in ipairs(args) do
+        local name, ty, interface = arg[1], arg[2], arg[3]
+        local start = offset
+        local text
+        local new_id
+        if ty == "fd" then
+            text = "fd"
+        elseif ty == "string" or ty == "array" or (ty == "new_id" and interface == nil) then
+            if offset + 4 > stop then
+                break
+            end
+            local len = tvb(offset, 4):le_uint()
+            offset = offset + 4
+            if offset + padded(len) > stop then
+                break
+            end
+            if ty == "array" then
+                if len == 0 then
+                    text = "[]"
+                else
+                    text = "[" .. tostring(tvb(offset, len):bytes()) .. "]"
+                end
+            elseif len == 0 then
+                text = "null"
+            elseif len == 1 then
+                text = '""'
+            else
+                text = string.format("%q", tvb(offset, len - 1):string())
+            end
+            offset = offset + padded(len)
+            if ty == "new_id" then
+                if offset + 8 > stop or len < 2 then
+                    break
+                end
+                interface = tvb(start + 4, len - 1):string()
+                local version = tvb(offset, 4):le_uint()
+                new_id = tvb(offset + 4, 4):le_uint()
+                offset = offset + 8
+                text = string.format("%s#%d (version: %d)", interface, new_id, version)
+            end
+        else
+            if offset + 4 > stop then
+                break
+            end
+            local range = tvb(offset, 4)
+            offset = offset + 4
+            if ty == "int" then
+                text = tostring(range:le_int())
+            elseif ty == "uint" then
+                text = tostring(range:le_uint())
+            elseif ty == "fixed" then
+                text = tostring(range:le_int() / 256)
+            else
+                local id = range:le_uint()
+                if ty == "new_id" then
+                    new_id = id
+                end
+                if interface == nil then
+                    interface = resolve(id) or "unknown"
+                end
+                if id == 0 and ty == "object" then
+                    text = "null"
+                else
+                    text = string.format("%s#%d", interface, id)
+                end
+            end
+        end
+        res[idx] = {
+            name = name,
+            text = text,
+            offset = start,
+            len = offset - start,
+            new_id = new_id,
+            interface = interface,
+        }
+    end
+    return res
+end
+
+function proto.dissector(tvb, pinfo, tree)
+    if tvb:len() < 20 then
+        return 0
+    end
+    pinfo.cols.protocol = "wl-proxy"
+    local endpoint = tostring(tvb(0, 8):le_uint64())
+    local peer = tvb(8, 1):uint()
+    local direction = tvb(9, 1):uint()
+    local is_request = (peer == 0) == (direction == 0)
+    local id = tvb(12, 4):le_uint()
+    local opcode = tvb(16, 2):le_uint()
+    local size = tvb(18, 2):le_uint()
+    local stop = math.min(12 + size, tvb:len())
+
+    local endpoint_objects = objects[endpoint]
+    if endpoint_objects == nil then
+        endpoint_objects = { [1] = "wl_display" }
+        objects[endpoint] = endpoint_objects
+    end
+    local frame = frames[pinfo.number]
+    local first = frame == nil
+    if first then
+        frame = { interface = endpoint_objects[id], resolved = {} }
+        frames[pinfo.number] = frame
+    end
+    local function resolve(object)
+        if first then
+            frame.resolved[object] = endpoint_objects[object]
+        end
+        return frame.resolved[object]
+    end
+
+    local interface = frame.interface
+    local description = interfaces[interface or ""]
+    local message
+    if description ~= nil then
+        if is_request then
+            message = description.requests[opcode + 1]
+        else
+            message = description.events[opcode + 1]
+        end
+    end
+    local name = "msg" .. opcode
+    local args = {}
+    if message ~= nil then
+        name = message[1]
+        args = parse_args(tvb, 20, stop, message[2], resolve)
+        if first then
+            for _, arg in ipairs(args) do
+                if arg.new_id ~= nil then
+                    endpoint_objects[arg.new_id] = arg.interface
+                end
+            end
+        end
+    end
+
+    local subtree = tree:add(proto, tvb())
+    subtree:add_le(fields.endpoint, tvb(0, 8))
+    subtree:add(fields.peer, tvb(8, 1))
+    subtree:add(fields.direction, tvb(9, 1))
+    subtree:add_le(fields.fds, tvb(10, 2))
+    subtree:add(fields.request, tvb(9, 1), is_request)
+    subtree:add_le(fields.object, tvb(12, 4))
+    subtree:add_le(fields.opcode, tvb(16, 2))
+    subtree:add_le(fields.size, tvb(18, 2))
+    subtree:add(fields.interface, tvb(12, 4), interface or "unknown")
+    subtree:add(fields.message, tvb(16, 2), name)
+    local texts = {}
+    for idx, arg in ipairs(args) do
+        local text = arg.name .. ": " .. arg.text
+        texts[idx] = text
+        if arg.len > 0 then
+            subtree:add(fields.arg, tvb(arg.offset, arg.len), text)
+        else
+            subtree:add(fields.arg, tvb(10, 2), text)
+        end
+    end
+
+    local source = "server"
+    if peer == 0 then
+        source = "client#" .. endpoint
+    end
+    local arrow = "->"
+    if direction == 1 then
+        arrow = "<="
+    end
+    pinfo.cols.info = string.format(
+        "%s %s %s#%d.%s(%s)",
+        source,
+        arrow,
+        interface or "unknown",
+        id,
+        name,
+        table.concat(texts, ", ")
+    )
+    return tvb:len()
+end
+
+local encaps = wtap_encaps or wtap
+DissectorTable.get("wtap_encap"):add(encaps.USER0, proto)
+"#;
+
 pub fn format_protocol_file(w: &mut impl Write, protocol: &Protocol) -> io::Result<()> {
     define_w!(w);
     if let Some(description) = &protocol.description {
@@ -1115,6 +1581,14 @@ fn format_object_impl(w: &mut impl Write, interface: &Interface) -> io::Result<(
     format_object_message_name(w, interface, false)?;
     wl!(r#"    }}"#)?;
     wl!()?;
+    wl!(r#"    fn get_request_signature(&self, id: u32) -> Option<&'static str> {{"#)?;
+    format_object_message_signature(w, interface, true)?;
+    wl!(r#"    }}"#)?;
+    wl!()?;
+    wl!(r#"    fn get_event_signature(&self, id: u32) -> Option<&'static str> {{"#)?;
+    format_object_message_signature(w, interface, false)?;
+    wl!(r#"    }}"#)?;
+    wl!()?;
     wl!(r#"    fn create_zombie(&self) -> Rc<dyn Object> {{"#)?;
     wl!(r#"        let slf = Self::new(&self.core.state, self.core.version);"#)?;
     wl!(r#"        slf.core.make_zombie();"#)?;
@@ -1196,6 +1670,54 @@ fn format_object_message_name(
     Ok(())
 }
 
+fn format_object_message_signature(
+    w: &mut impl Write,
+    interface: &Interface,
+    requests: bool,
+) -> io::Result<()> {
+    define_w!(w);
+    let p = "        ";
+    if interface.messages.iter().any(|m| m.is_request == requests) {
+        wl!(r#"{p}let signature = match id {{"#)?;
+        for msg in &interface.messages {
+            if msg.is_request != requests {
+                continue;
+            }
+            let mut signature = String::new();
+            for arg in &msg.args {
+                if arg.allow_null && matches!(arg.ty, ArgType::String | ArgType::Object) {
+                    signature.push('?');
+                }
+                match arg.ty {
+                    ArgType::NewId => {
+                        signature.push('n');
+                        if let Some(interface) = &arg.interface {
+                            signature.push('[');
+                            signature.push_str(interface);
+                            signature.push(']');
+                        }
+                    }
+                    ArgType::Int => signature.push('i'),
+                    ArgType::Uint => signature.push('u'),
+                    ArgType::Fixed => signature.push('f'),
+                    ArgType::String => signature.push('s'),
+                    ArgType::Object => signature.push('o'),
+                    ArgType::Array => signature.push('a'),
+                    ArgType::Fd => signature.push('h'),
+                }
+            }
+            wl!(r#"{p}    {} => "{}","#, msg.message_id, signature)?;
+        }
+        wl!(r#"{p}    _ => return None,"#)?;
+        wl!(r#"{p}}};"#)?;
+        wl!(r#"{p}Some(signature)"#)?;
+    } else {
+        wl!(r#"{p}let _ = id;"#)?;
+        wl!(r#"{p}None"#)?;
+    }
+    Ok(())
+}
+
 fn format_wayland_debug(
     w: &mut impl Write,
     interface: &Interface,
@@ -1242,67 +1764,60 @@ fn format_wayland_debug(
         w!(r#", arg{idx}: {ty}"#)?;
     }
     wl!(r#") {{"#)?;
-    wl!(r#"{prefix}        let (millis, micros) = time_since_epoch();"#)?;
-    wl!(r#"{prefix}        let prefix = &state.log_prefix;"#)?;
-    w!(r#"{prefix}        let args = format_args!("[{{millis:7}}.{{micros:03}}] "#)?;
-    w!(r#"{{prefix}}"#)?;
+    wl!(r#"{prefix}        state.log("#)?;
     if msg.is_request ^ outgoing {
-        w!(r#"client#{{:<4}}"#)?;
+        wl!(r#"{prefix}            Some(client_id),"#)?;
     } else {
-        w!(r#"server     "#)?;
+        wl!(r#"{prefix}            None,"#)?;
     }
     if outgoing {
-        w!(r#" <= "#)?;
+        wl!(r#"{prefix}            Direction::Sent,"#)?;
     } else {
-        w!(r#" -> "#)?;
+        wl!(r#"{prefix}            Direction::Received,"#)?;
     }
-    w!(r#"{}#{{}}.{}("#, interface.name, msg.name)?;
+    wl!(r#"{prefix}            "{}","#, interface.name)?;
+    wl!(r#"{prefix}            id,"#)?;
+    wl!(r#"{prefix}            "{}","#, msg.name)?;
+    wl!(r#"{prefix}            &["#)?;
     for (idx, arg) in msg.args.iter().enumerate() {
-        if idx > 0 {
-            w!(r#", "#)?;
-        }
-        w!(r#"{}: "#, arg.name)?;
+        w!(
+            r#"{prefix}                LogArg::new(Some("{}"), "#,
+            arg.name
+        )?;
         match arg.ty {
-            ArgType::NewId | ArgType::Object => {
+            ArgType::NewId => {
                 if let Some(interface) = &arg.interface {
-                    w!(r#"{interface}"#)?
-                } else if arg.ty == ArgType::NewId {
-                    w!(r#"{{}}"#)?
+                    w!(
+                        r#"LogValue::NewId {{ interface: "{interface}", id: arg{idx}, version: None }}"#
+                    )?;
                 } else {
-                    w!(r#"unknown"#)?
-                }
-                w!(r#"#{{}}"#)?;
-                if arg.interface.is_none() && arg.ty == ArgType::NewId {
-                    w!(r#" (version: {{}})"#)?;
+                    w!(
+                        r#"LogValue::NewId {{ interface: arg{idx}_interface, id: arg{idx}_id, version: Some(arg{idx}_version) }}"#
+                    )?;
                 }
             }
-            _ if arg.enum_.is_some() => w!(r#"{{:?}}"#)?,
-            ArgType::Int | ArgType::Uint | ArgType::Fixed | ArgType::Fd | ArgType::Array => {
-                w!(r#"{{}}"#)?
-            }
-            ArgType::String => w!(r#"{{:?}}"#)?,
-        }
-    }
-    w!(r#")\n""#)?;
-    if msg.is_request ^ outgoing {
-        w!(r#", client_id"#)?;
-    }
-    w!(r#", id"#)?;
-    for (idx, arg) in msg.args.iter().enumerate() {
-        match arg.ty {
-            ArgType::NewId => {
-                if arg.interface.is_none() {
-                    w!(r#", arg{idx}_interface, arg{idx}_id, arg{idx}_version"#)?;
+            ArgType::Object => {
+                if let Some(interface) = &arg.interface {
+                    w!(r#"LogValue::Object {{ interface: Some("{interface}"), id: arg{idx} }}"#)?;
                 } else {
-                    w!(r#", arg{idx}"#)?;
+                    w!(r#"LogValue::Object {{ interface: None, id: arg{idx} }}"#)?;
                 }
             }
-            ArgType::Array => w!(r#", debug_array(arg{idx})"#)?,
-            _ => w!(r#", arg{idx}"#)?,
-        }
+            _ if arg.enum_.is_some() => {
+                w!(r#"LogValue::Enum {{ value: arg{idx}.0, debug: &arg{idx} }}"#)?
+            }
+            ArgType::Int => w!(r#"LogValue::Int(arg{idx})"#)?,
+            ArgType::Uint => w!(r#"LogValue::Uint(arg{idx})"#)?,
+            ArgType::Fixed => w!(r#"LogValue::Fixed(arg{idx})"#)?,
+            ArgType::String if arg.allow_null => w!(r#"LogValue::String(arg{idx})"#)?,
+            ArgType::String => w!(r#"LogValue::String(Some(arg{idx}))"#)?,
+            ArgType::Array => w!(r#"LogValue::Array(arg{idx})"#)?,
+            ArgType::Fd => w!(r#"LogValue::Fd(arg{idx})"#)?,
+        }
+        wl!(r#"),"#)?;
     }
-    wl!(r#");"#)?;
-    wl!(r#"{prefix}        state.log(args);"#)?;
+    wl!(r#"{prefix}            ],"#)?;
+    wl!(r#"{prefix}        );"#)?;
     wl!(r#"{prefix}    }}"#)?;
     w!(r#"{prefix}    log(&self.core.state"#)?;
     if msg.is_request ^ outgoing {
@@ -1318,7 +1833,7 @@ fn format_wayland_debug(
             ArgType::NewId => {
                 if arg.interface.is_none() {
                     if outgoing {
-                        w!(r#", arg{idx}.interface.name()"#)?;
+                        w!(r#", arg{idx}.interface_name()"#)?;
                     } else {
                         w!(r#", arg{idx}_interface"#)?;
                     }
@@ -1516,6 +2031,22 @@ fn format_object_message_handler_body<W: Write>(
         format_wayland_debug(w, interface, msg, false)?;
         if interface.is_wl_registry && msg.name == "global" {
             wl!(r#"{p}        let Some(arg1) = ObjectInterface::from_str(arg1) else {{"#)?;
+            wl!(
+                r#"{p}            let Some(max_version) = self.core.state.unknown_interfaces.max_version(arg1) else {{"#
+            )?;
+            wl!(r#"{p}                return Ok(());"#)?;
+            wl!(r#"{p}            }};"#)?;
+            wl!(r#"{p}            self.names.borrow_mut().insert(arg0);"#)?;
+            wl!(r#"{p}            let arg2 = max_version.min(arg2);"#)?;
+            wl!(r#"{p}            if let Some(handler) = handler {{"#)?;
+            wl!(
+                r#"{p}                (**handler).handle_unknown_global(&self, arg0, arg1, arg2);"#
+            )?;
+            wl!(r#"{p}            }} else {{"#)?;
+            wl!(
+                r#"{p}                DefaultHandler.handle_unknown_global(&self, arg0, arg1, arg2);"#
+            )?;
+            wl!(r#"{p}            }}"#)?;
             wl!(r#"{p}            return Ok(());"#)?;
             wl!(r#"{p}        }};"#)?;
             wl!(r#"{p}        let max_version = self.core.state.baseline.1[arg1];"#)?;
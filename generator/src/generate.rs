@@ -3,7 +3,7 @@ use {
         collector::{CollectorError, collect},
         formatter::{
             format_baseline_file, format_baseline_txt, format_interface_file, format_mod_file,
-            format_protocol_file,
+            format_protocol_file, format_wireshark_dissector,
         },
     },
     std::{
@@ -72,6 +72,12 @@ pub fn main() -> Result<(), GeneratorError> {
         format_baseline_txt(f, &suits)
     })?;
 
+    let wireshark_dir = root_dir.join("wireshark");
+    create_dir(&wireshark_dir)?;
+    format_file(&wireshark_dir.join("wl-proxy.lua"), |f| {
+        format_wireshark_dissector(f, &suits)
+    })?;
+
     {
         let mut interface_to_protocol = HashMap::new();
         for protocol in suits.iter().flat_map(|s| s.protocols.iter()) {
@@ -1,7 +1,7 @@
 #![expect(clippy::single_char_add_str, clippy::collapsible_else_if)]
 
-mod ast;
+pub mod ast;
 mod collector;
 mod formatter;
 pub mod generate;
-mod parser;
+pub mod parser;
@@ -167,7 +167,8 @@ pub enum AttributeError {
     DecodeUtf8(#[from] quick_xml::Error),
 }
 
-pub(crate) fn parse(input: &[u8]) -> Result<Vec<Protocol>, ParserError> {
+/// Parses the protocols contained in a protocol XML file.
+pub fn parse(input: &[u8]) -> Result<Vec<Protocol>, ParserError> {
     let mut reader = Reader::from_reader(input);
     let mut protocols = Vec::new();
     loop {
@@ -0,0 +1,3168 @@
+-- This file was generated from the protocols in the wl-proxy repository. Do not edit.
+--
+-- A Wireshark dissector for pcapng files written by states built with
+-- StateBuilder::with_pcapng_recorder.
+--
+-- To install it, copy this file to the personal Wireshark plugin directory, e.g.
+-- ~/.local/lib/wireshark/plugins.
+
+local interfaces = {
+    ["cosmic_a11y_manager_v1"] = {
+        requests = {
+            { "set_magnifier", { { "active", "uint" } } },
+            { "set_screen_filter", { { "inverted", "uint" }, { "filter", "uint" } } },
+            { "set_screen_filter2", { { "inverted", "uint" }, { "filter", "uint" }, { "filter_state", "uint" } } },
+        },
+        events = {
+            { "magnifier", { { "active", "uint" } } },
+            { "screen_filter", { { "inverted", "uint" }, { "filter", "uint" } } },
+            { "screen_filter2", { { "inverted", "uint" }, { "filter", "uint" }, { "filter_state", "uint" } } },
+        },
+    },
+    ["cosmic_corner_radius_layer_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_radius", { { "top_left", "uint" }, { "top_right", "uint" }, { "bottom_right", "uint" }, { "bottom_left", "uint" } } },
+            { "unset_radius", {} },
+            { "set_padding", { { "top", "int" }, { "right", "int" }, { "bottom", "int" }, { "left", "int" } } },
+            { "unset_padding", {} },
+        },
+        events = {},
+    },
+    ["cosmic_corner_radius_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_corner_radius", { { "id", "new_id", "cosmic_corner_radius_toplevel_v1" }, { "toplevel", "object", "xdg_toplevel" } } },
+            { "get_corner_radius_surface", { { "id", "new_id", "cosmic_corner_radius_toplevel_v1" }, { "surface", "object", "xdg_surface" } } },
+            { "get_corner_radius_layer", { { "id", "new_id", "cosmic_corner_radius_layer_v1" }, { "layer", "object", "zwlr_layer_surface_v1" } } },
+        },
+        events = {},
+    },
+    ["cosmic_corner_radius_toplevel_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_radius", { { "top_left", "uint" }, { "top_right", "uint" }, { "bottom_right", "uint" }, { "bottom_left", "uint" } } },
+            { "unset_radius", {} },
+        },
+        events = {},
+    },
+    ["ext_background_effect_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_background_effect", { { "id", "new_id", "ext_background_effect_surface_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {
+            { "capabilities", { { "flags", "uint" } } },
+        },
+    },
+    ["ext_background_effect_surface_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_blur_region", { { "region", "object", "wl_region" } } },
+        },
+        events = {},
+    },
+    ["ext_data_control_device_v1"] = {
+        requests = {
+            { "set_selection", { { "source", "object", "ext_data_control_source_v1" } } },
+            { "destroy", {} },
+            { "set_primary_selection", { { "source", "object", "ext_data_control_source_v1" } } },
+        },
+        events = {
+            { "data_offer", { { "id", "new_id", "ext_data_control_offer_v1" } } },
+            { "selection", { { "id", "object", "ext_data_control_offer_v1" } } },
+            { "finished", {} },
+            { "primary_selection", { { "id", "object", "ext_data_control_offer_v1" } } },
+        },
+    },
+    ["ext_data_control_manager_v1"] = {
+        requests = {
+            { "create_data_source", { { "id", "new_id", "ext_data_control_source_v1" } } },
+            { "get_data_device", { { "id", "new_id", "ext_data_control_device_v1" }, { "seat", "object", "wl_seat" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["ext_data_control_offer_v1"] = {
+        requests = {
+            { "receive", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "offer", { { "mime_type", "string" } } },
+        },
+    },
+    ["ext_data_control_source_v1"] = {
+        requests = {
+            { "offer", { { "mime_type", "string" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "send", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "cancelled", {} },
+        },
+    },
+    ["ext_foreign_toplevel_handle_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "closed", {} },
+            { "done", {} },
+            { "title", { { "title", "string" } } },
+            { "app_id", { { "app_id", "string" } } },
+            { "identifier", { { "identifier", "string" } } },
+        },
+    },
+    ["ext_foreign_toplevel_image_capture_source_manager_v1"] = {
+        requests = {
+            { "create_source", { { "source", "new_id", "ext_image_capture_source_v1" }, { "toplevel_handle", "object", "ext_foreign_toplevel_handle_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["ext_foreign_toplevel_list_v1"] = {
+        requests = {
+            { "stop", {} },
+            { "destroy", {} },
+        },
+        events = {
+            { "toplevel", { { "toplevel", "new_id", "ext_foreign_toplevel_handle_v1" } } },
+            { "finished", {} },
+        },
+    },
+    ["ext_idle_notification_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "idled", {} },
+            { "resumed", {} },
+        },
+    },
+    ["ext_idle_notifier_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_idle_notification", { { "id", "new_id", "ext_idle_notification_v1" }, { "timeout", "uint" }, { "seat", "object", "wl_seat" } } },
+            { "get_input_idle_notification", { { "id", "new_id", "ext_idle_notification_v1" }, { "timeout", "uint" }, { "seat", "object", "wl_seat" } } },
+        },
+        events = {},
+    },
+    ["ext_image_capture_source_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["ext_image_copy_capture_cursor_session_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_capture_session", { { "session", "new_id", "ext_image_copy_capture_session_v1" } } },
+        },
+        events = {
+            { "enter", {} },
+            { "leave", {} },
+            { "position", { { "x", "int" }, { "y", "int" } } },
+            { "hotspot", { { "x", "int" }, { "y", "int" } } },
+        },
+    },
+    ["ext_image_copy_capture_frame_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "attach_buffer", { { "buffer", "object", "wl_buffer" } } },
+            { "damage_buffer", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "capture", {} },
+        },
+        events = {
+            { "transform", { { "transform", "uint" } } },
+            { "damage", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "presentation_time", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+            { "ready", {} },
+            { "failed", { { "reason", "uint" } } },
+        },
+    },
+    ["ext_image_copy_capture_manager_v1"] = {
+        requests = {
+            { "create_session", { { "session", "new_id", "ext_image_copy_capture_session_v1" }, { "source", "object", "ext_image_capture_source_v1" }, { "options", "uint" } } },
+            { "create_pointer_cursor_session", { { "session", "new_id", "ext_image_copy_capture_cursor_session_v1" }, { "source", "object", "ext_image_capture_source_v1" }, { "pointer", "object", "wl_pointer" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["ext_image_copy_capture_session_v1"] = {
+        requests = {
+            { "create_frame", { { "frame", "new_id", "ext_image_copy_capture_frame_v1" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "buffer_size", { { "width", "uint" }, { "height", "uint" } } },
+            { "shm_format", { { "format", "uint" } } },
+            { "dmabuf_device", { { "device", "array" } } },
+            { "dmabuf_format", { { "format", "uint" }, { "modifiers", "array" } } },
+            { "done", {} },
+            { "stopped", {} },
+        },
+    },
+    ["ext_output_image_capture_source_manager_v1"] = {
+        requests = {
+            { "create_source", { { "source", "new_id", "ext_image_capture_source_v1" }, { "output", "object", "wl_output" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["ext_session_lock_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "lock", { { "id", "new_id", "ext_session_lock_v1" } } },
+        },
+        events = {},
+    },
+    ["ext_session_lock_surface_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "ack_configure", { { "serial", "uint" } } },
+        },
+        events = {
+            { "configure", { { "serial", "uint" }, { "width", "uint" }, { "height", "uint" } } },
+        },
+    },
+    ["ext_session_lock_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_lock_surface", { { "id", "new_id", "ext_session_lock_surface_v1" }, { "surface", "object", "wl_surface" }, { "output", "object", "wl_output" } } },
+            { "unlock_and_destroy", {} },
+        },
+        events = {
+            { "locked", {} },
+            { "finished", {} },
+        },
+    },
+    ["ext_transient_seat_manager_v1"] = {
+        requests = {
+            { "create", { { "seat", "new_id", "ext_transient_seat_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["ext_transient_seat_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "ready", { { "global_name", "uint" } } },
+            { "denied", {} },
+        },
+    },
+    ["ext_workspace_group_handle_v1"] = {
+        requests = {
+            { "create_workspace", { { "workspace", "string" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "capabilities", { { "capabilities", "uint" } } },
+            { "output_enter", { { "output", "object", "wl_output" } } },
+            { "output_leave", { { "output", "object", "wl_output" } } },
+            { "workspace_enter", { { "workspace", "object", "ext_workspace_handle_v1" } } },
+            { "workspace_leave", { { "workspace", "object", "ext_workspace_handle_v1" } } },
+            { "removed", {} },
+        },
+    },
+    ["ext_workspace_handle_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "activate", {} },
+            { "deactivate", {} },
+            { "assign", { { "workspace_group", "object", "ext_workspace_group_handle_v1" } } },
+            { "remove", {} },
+        },
+        events = {
+            { "id", { { "id", "string" } } },
+            { "name", { { "name", "string" } } },
+            { "coordinates", { { "coordinates", "array" } } },
+            { "state", { { "state", "uint" } } },
+            { "capabilities", { { "capabilities", "uint" } } },
+            { "removed", {} },
+        },
+    },
+    ["ext_workspace_manager_v1"] = {
+        requests = {
+            { "commit", {} },
+            { "stop", {} },
+        },
+        events = {
+            { "workspace_group", { { "workspace_group", "new_id", "ext_workspace_group_handle_v1" } } },
+            { "workspace", { { "workspace", "new_id", "ext_workspace_handle_v1" } } },
+            { "done", {} },
+            { "finished", {} },
+        },
+    },
+    ["hyprland_ctm_control_manager_v1"] = {
+        requests = {
+            { "set_ctm_for_output", { { "output", "object", "wl_output" }, { "mat0", "fixed" }, { "mat1", "fixed" }, { "mat2", "fixed" }, { "mat3", "fixed" }, { "mat4", "fixed" }, { "mat5", "fixed" }, { "mat6", "fixed" }, { "mat7", "fixed" }, { "mat8", "fixed" } } },
+            { "commit", {} },
+            { "destroy", {} },
+        },
+        events = {
+            { "blocked", {} },
+        },
+    },
+    ["hyprland_focus_grab_manager_v1"] = {
+        requests = {
+            { "create_grab", { { "grab", "new_id", "hyprland_focus_grab_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["hyprland_focus_grab_v1"] = {
+        requests = {
+            { "add_surface", { { "surface", "object", "wl_surface" } } },
+            { "remove_surface", { { "surface", "object", "wl_surface" } } },
+            { "commit", {} },
+            { "destroy", {} },
+        },
+        events = {
+            { "cleared", {} },
+        },
+    },
+    ["hyprland_global_shortcut_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "pressed", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+            { "released", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+        },
+    },
+    ["hyprland_global_shortcuts_manager_v1"] = {
+        requests = {
+            { "register_shortcut", { { "shortcut", "new_id", "hyprland_global_shortcut_v1" }, { "id", "string" }, { "app_id", "string" }, { "description", "string" }, { "trigger_description", "string" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["hyprland_input_capture_manager_v1"] = {
+        requests = {
+            { "create_session", { { "session", "new_id", "hyprland_input_capture_v1" }, { "handle", "string" } } },
+        },
+        events = {},
+    },
+    ["hyprland_input_capture_v1"] = {
+        requests = {
+            { "clear_barriers", {} },
+            { "add_barrier", { { "zone_set", "uint" }, { "id", "uint" }, { "x1", "uint" }, { "y1", "uint" }, { "x2", "uint" }, { "y2", "uint" } } },
+            { "enable", {} },
+            { "disable", {} },
+            { "release", { { "activation_id", "uint" }, { "x", "fixed" }, { "y", "fixed" } } },
+        },
+        events = {
+            { "eis_fd", { { "fd", "fd" } } },
+            { "disabled", {} },
+            { "activated", { { "activation_id", "uint" }, { "x", "fixed" }, { "y", "fixed" }, { "barrier_id", "uint" } } },
+            { "deactivated", { { "activation_id", "uint" } } },
+        },
+    },
+    ["hyprland_lock_notification_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "locked", {} },
+            { "unlocked", {} },
+        },
+    },
+    ["hyprland_lock_notifier_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_lock_notification", { { "id", "new_id", "hyprland_lock_notification_v1" } } },
+        },
+        events = {},
+    },
+    ["hyprland_surface_manager_v1"] = {
+        requests = {
+            { "get_hyprland_surface", { { "id", "new_id", "hyprland_surface_v1" }, { "surface", "object", "wl_surface" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["hyprland_surface_v1"] = {
+        requests = {
+            { "set_opacity", { { "opacity", "fixed" } } },
+            { "destroy", {} },
+            { "set_visible_region", { { "region", "object", "wl_region" } } },
+        },
+        events = {},
+    },
+    ["hyprland_toplevel_export_frame_v1"] = {
+        requests = {
+            { "copy", { { "buffer", "object", "wl_buffer" }, { "ignore_damage", "int" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "buffer", { { "format", "uint" }, { "width", "uint" }, { "height", "uint" }, { "stride", "uint" } } },
+            { "damage", { { "x", "uint" }, { "y", "uint" }, { "width", "uint" }, { "height", "uint" } } },
+            { "flags", { { "flags", "uint" } } },
+            { "ready", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+            { "failed", {} },
+            { "linux_dmabuf", { { "format", "uint" }, { "width", "uint" }, { "height", "uint" } } },
+            { "buffer_done", {} },
+        },
+    },
+    ["hyprland_toplevel_export_manager_v1"] = {
+        requests = {
+            { "capture_toplevel", { { "frame", "new_id", "hyprland_toplevel_export_frame_v1" }, { "overlay_cursor", "int" }, { "handle", "uint" } } },
+            { "destroy", {} },
+            { "capture_toplevel_with_wlr_toplevel_handle", { { "frame", "new_id", "hyprland_toplevel_export_frame_v1" }, { "overlay_cursor", "int" }, { "handle", "object", "zwlr_foreign_toplevel_handle_v1" } } },
+        },
+        events = {},
+    },
+    ["hyprland_toplevel_mapping_manager_v1"] = {
+        requests = {
+            { "get_window_for_toplevel", { { "handle", "new_id", "hyprland_toplevel_window_mapping_handle_v1" }, { "toplevel", "object", "ext_foreign_toplevel_handle_v1" } } },
+            { "get_window_for_toplevel_wlr", { { "handle", "new_id", "hyprland_toplevel_window_mapping_handle_v1" }, { "toplevel", "object", "zwlr_foreign_toplevel_handle_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["hyprland_toplevel_window_mapping_handle_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "window_address", { { "address_hi", "uint" }, { "address", "uint" } } },
+            { "failed", {} },
+        },
+    },
+    ["ivi_application"] = {
+        requests = {
+            { "surface_create", { { "ivi_id", "uint" }, { "surface", "object", "wl_surface" }, { "id", "new_id", "ivi_surface" } } },
+        },
+        events = {},
+    },
+    ["ivi_hmi_controller"] = {
+        requests = {
+            { "UI_ready", {} },
+            { "workspace_control", { { "seat", "object", "wl_seat" }, { "serial", "uint" } } },
+            { "switch_mode", { { "layout_mode", "uint" } } },
+            { "home", { { "home", "uint" } } },
+        },
+        events = {
+            { "workspace_end_control", { { "is_controlled", "int" } } },
+        },
+    },
+    ["ivi_surface"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "configure", { { "width", "int" }, { "height", "int" } } },
+        },
+    },
+    ["jay_popup_ext_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_ext", { { "id", "new_id", "jay_popup_ext_v1" }, { "popup", "object", "xdg_popup" } } },
+        },
+        events = {},
+    },
+    ["jay_popup_ext_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "move", { { "seat", "object", "wl_seat" }, { "serial", "uint" } } },
+            { "resize", { { "seat", "object", "wl_seat" }, { "serial", "uint" }, { "edges", "uint" } } },
+        },
+        events = {},
+    },
+    ["jay_tray_item_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "ack_configure", { { "serial", "uint" } } },
+            { "get_popup", { { "popup", "object", "xdg_popup" }, { "seat", "object", "wl_seat" }, { "serial", "uint" }, { "focus_hint", "uint" } } },
+        },
+        events = {
+            { "configure_size", { { "width", "int" }, { "height", "int" } } },
+            { "preferred_anchor", { { "anchor", "uint" } } },
+            { "preferred_gravity", { { "gravity", "uint" } } },
+            { "configure", { { "serial", "uint" } } },
+        },
+    },
+    ["jay_tray_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_tray_item", { { "id", "new_id", "jay_tray_item_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["org_kde_kwin_blur"] = {
+        requests = {
+            { "commit", {} },
+            { "set_region", { { "region", "object", "wl_region" } } },
+            { "release", {} },
+        },
+        events = {},
+    },
+    ["org_kde_kwin_blur_manager"] = {
+        requests = {
+            { "create", { { "id", "new_id", "org_kde_kwin_blur" }, { "surface", "object", "wl_surface" } } },
+            { "unset", { { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["org_kde_kwin_server_decoration"] = {
+        requests = {
+            { "release", {} },
+            { "request_mode", { { "mode", "uint" } } },
+        },
+        events = {
+            { "mode", { { "mode", "uint" } } },
+        },
+    },
+    ["org_kde_kwin_server_decoration_manager"] = {
+        requests = {
+            { "create", { { "id", "new_id", "org_kde_kwin_server_decoration" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {
+            { "default_mode", { { "mode", "uint" } } },
+        },
+    },
+    ["org_kde_kwin_server_decoration_palette"] = {
+        requests = {
+            { "set_palette", { { "palette", "string" } } },
+            { "release", {} },
+        },
+        events = {},
+    },
+    ["org_kde_kwin_server_decoration_palette_manager"] = {
+        requests = {
+            { "create", { { "id", "new_id", "org_kde_kwin_server_decoration_palette" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["org_kde_kwin_shadow"] = {
+        requests = {
+            { "commit", {} },
+            { "attach_left", { { "buffer", "object", "wl_buffer" } } },
+            { "attach_top_left", { { "buffer", "object", "wl_buffer" } } },
+            { "attach_top", { { "buffer", "object", "wl_buffer" } } },
+            { "attach_top_right", { { "buffer", "object", "wl_buffer" } } },
+            { "attach_right", { { "buffer", "object", "wl_buffer" } } },
+            { "attach_bottom_right", { { "buffer", "object", "wl_buffer" } } },
+            { "attach_bottom", { { "buffer", "object", "wl_buffer" } } },
+            { "attach_bottom_left", { { "buffer", "object", "wl_buffer" } } },
+            { "set_left_offset", { { "offset", "fixed" } } },
+            { "set_top_offset", { { "offset", "fixed" } } },
+            { "set_right_offset", { { "offset", "fixed" } } },
+            { "set_bottom_offset", { { "offset", "fixed" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["org_kde_kwin_shadow_manager"] = {
+        requests = {
+            { "create", { { "id", "new_id", "org_kde_kwin_shadow" }, { "surface", "object", "wl_surface" } } },
+            { "unset", { { "surface", "object", "wl_surface" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["river_decoration_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_offset", { { "x", "int" }, { "y", "int" } } },
+            { "sync_next_commit", {} },
+        },
+        events = {},
+    },
+    ["river_input_device_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "assign_to_seat", { { "name", "string" } } },
+            { "set_repeat_info", { { "rate", "int" }, { "delay", "int" } } },
+            { "set_scroll_factor", { { "factor", "fixed" } } },
+            { "map_to_output", { { "output", "object", "wl_output" } } },
+            { "map_to_rectangle", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+        },
+        events = {
+            { "removed", {} },
+            { "type", { { "type", "uint" } } },
+            { "name", { { "name", "string" } } },
+            { "done", {} },
+        },
+    },
+    ["river_input_manager_v1"] = {
+        requests = {
+            { "stop", {} },
+            { "destroy", {} },
+            { "create_seat", { { "name", "string" } } },
+            { "destroy_seat", { { "name", "string" } } },
+        },
+        events = {
+            { "finished", {} },
+            { "input_device", { { "id", "new_id", "river_input_device_v1" } } },
+        },
+    },
+    ["river_layer_shell_output_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_default", {} },
+        },
+        events = {
+            { "non_exclusive_area", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+        },
+    },
+    ["river_layer_shell_seat_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "focus_exclusive", {} },
+            { "focus_non_exclusive", {} },
+            { "focus_none", {} },
+        },
+    },
+    ["river_layer_shell_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_output", { { "id", "new_id", "river_layer_shell_output_v1" }, { "output", "object", "river_output_v1" } } },
+            { "get_seat", { { "id", "new_id", "river_layer_shell_seat_v1" }, { "seat", "object", "river_seat_v1" } } },
+        },
+        events = {},
+    },
+    ["river_libinput_accel_config_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_points", { { "result", "new_id", "river_libinput_result_v1" }, { "type", "uint" }, { "step", "array" }, { "points", "array" } } },
+        },
+        events = {},
+    },
+    ["river_libinput_config_v1"] = {
+        requests = {
+            { "stop", {} },
+            { "destroy", {} },
+            { "create_accel_config", { { "id", "new_id", "river_libinput_accel_config_v1" }, { "profile", "uint" } } },
+        },
+        events = {
+            { "finished", {} },
+            { "libinput_device", { { "id", "new_id", "river_libinput_device_v1" } } },
+        },
+    },
+    ["river_libinput_device_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_send_events", { { "result", "new_id", "river_libinput_result_v1" }, { "mode", "uint" } } },
+            { "set_tap", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_tap_button_map", { { "result", "new_id", "river_libinput_result_v1" }, { "button_map", "uint" } } },
+            { "set_drag", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_drag_lock", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_three_finger_drag", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_calibration_matrix", { { "result", "new_id", "river_libinput_result_v1" }, { "matrix", "array" } } },
+            { "set_accel_profile", { { "result", "new_id", "river_libinput_result_v1" }, { "profile", "uint" } } },
+            { "set_accel_speed", { { "result", "new_id", "river_libinput_result_v1" }, { "speed", "array" } } },
+            { "apply_accel_config", { { "result", "new_id", "river_libinput_result_v1" }, { "config", "object", "river_libinput_accel_config_v1" } } },
+            { "set_natural_scroll", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_left_handed", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_click_method", { { "result", "new_id", "river_libinput_result_v1" }, { "method", "uint" } } },
+            { "set_clickfinger_button_map", { { "result", "new_id", "river_libinput_result_v1" }, { "button_map", "uint" } } },
+            { "set_middle_emulation", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_scroll_method", { { "result", "new_id", "river_libinput_result_v1" }, { "method", "uint" } } },
+            { "set_scroll_button", { { "result", "new_id", "river_libinput_result_v1" }, { "button", "uint" } } },
+            { "set_scroll_button_lock", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_dwt", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_dwtp", { { "result", "new_id", "river_libinput_result_v1" }, { "state", "uint" } } },
+            { "set_rotation", { { "result", "new_id", "river_libinput_result_v1" }, { "angle", "uint" } } },
+        },
+        events = {
+            { "removed", {} },
+            { "input_device", { { "device", "object", "river_input_device_v1" } } },
+            { "send_events_support", { { "modes", "uint" } } },
+            { "send_events_default", { { "mode", "uint" } } },
+            { "send_events_current", { { "mode", "uint" } } },
+            { "tap_support", { { "finger_count", "int" } } },
+            { "tap_default", { { "state", "uint" } } },
+            { "tap_current", { { "state", "uint" } } },
+            { "tap_button_map_default", { { "button_map", "uint" } } },
+            { "tap_button_map_current", { { "button_map", "uint" } } },
+            { "drag_default", { { "state", "uint" } } },
+            { "drag_current", { { "state", "uint" } } },
+            { "drag_lock_default", { { "state", "uint" } } },
+            { "drag_lock_current", { { "state", "uint" } } },
+            { "three_finger_drag_support", { { "finger_count", "int" } } },
+            { "three_finger_drag_default", { { "state", "uint" } } },
+            { "three_finger_drag_current", { { "state", "uint" } } },
+            { "calibration_matrix_support", { { "supported", "int" } } },
+            { "calibration_matrix_default", { { "matrix", "array" } } },
+            { "calibration_matrix_current", { { "matrix", "array" } } },
+            { "accel_profiles_support", { { "profiles", "uint" } } },
+            { "accel_profile_default", { { "profile", "uint" } } },
+            { "accel_profile_current", { { "profile", "uint" } } },
+            { "accel_speed_default", { { "speed", "array" } } },
+            { "accel_speed_current", { { "speed", "array" } } },
+            { "natural_scroll_support", { { "supported", "int" } } },
+            { "natural_scroll_default", { { "state", "uint" } } },
+            { "natural_scroll_current", { { "state", "uint" } } },
+            { "left_handed_support", { { "supported", "int" } } },
+            { "left_handed_default", { { "state", "uint" } } },
+            { "left_handed_current", { { "state", "uint" } } },
+            { "click_method_support", { { "methods", "uint" } } },
+            { "click_method_default", { { "method", "uint" } } },
+            { "click_method_current", { { "method", "uint" } } },
+            { "clickfinger_button_map_default", { { "button_map", "uint" } } },
+            { "clickfinger_button_map_current", { { "button_map", "uint" } } },
+            { "middle_emulation_support", { { "supported", "int" } } },
+            { "middle_emulation_default", { { "state", "uint" } } },
+            { "middle_emulation_current", { { "state", "uint" } } },
+            { "scroll_method_support", { { "methods", "uint" } } },
+            { "scroll_method_default", { { "method", "uint" } } },
+            { "scroll_method_current", { { "method", "uint" } } },
+            { "scroll_button_default", { { "button", "uint" } } },
+            { "scroll_button_current", { { "button", "uint" } } },
+            { "scroll_button_lock_default", { { "state", "uint" } } },
+            { "scroll_button_lock_current", { { "state", "uint" } } },
+            { "dwt_support", { { "supported", "int" } } },
+            { "dwt_default", { { "state", "uint" } } },
+            { "dwt_current", { { "state", "uint" } } },
+            { "dwtp_support", { { "supported", "int" } } },
+            { "dwtp_default", { { "state", "uint" } } },
+            { "dwtp_current", { { "state", "uint" } } },
+            { "rotation_support", { { "supported", "int" } } },
+            { "rotation_default", { { "angle", "uint" } } },
+            { "rotation_current", { { "angle", "uint" } } },
+            { "done", {} },
+        },
+    },
+    ["river_libinput_result_v1"] = {
+        requests = {},
+        events = {
+            { "success", {} },
+            { "unsupported", {} },
+            { "invalid", {} },
+        },
+    },
+    ["river_node_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_position", { { "x", "int" }, { "y", "int" } } },
+            { "place_top", {} },
+            { "place_bottom", {} },
+            { "place_above", { { "other", "object", "river_node_v1" } } },
+            { "place_below", { { "other", "object", "river_node_v1" } } },
+        },
+        events = {},
+    },
+    ["river_output_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_presentation_mode", { { "mode", "uint" } } },
+        },
+        events = {
+            { "removed", {} },
+            { "wl_output", { { "name", "uint" } } },
+            { "position", { { "x", "int" }, { "y", "int" } } },
+            { "dimensions", { { "width", "int" }, { "height", "int" } } },
+            { "capture_sessions", { { "count", "uint" } } },
+        },
+    },
+    ["river_pointer_binding_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "enable", {} },
+            { "disable", {} },
+        },
+        events = {
+            { "pressed", {} },
+            { "released", {} },
+        },
+    },
+    ["river_seat_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "focus_window", { { "window", "object", "river_window_v1" } } },
+            { "focus_shell_surface", { { "shell_surface", "object", "river_shell_surface_v1" } } },
+            { "clear_focus", {} },
+            { "op_start_pointer", {} },
+            { "op_end", {} },
+            { "get_pointer_binding", { { "id", "new_id", "river_pointer_binding_v1" }, { "button", "uint" }, { "modifiers", "uint" } } },
+            { "set_xcursor_theme", { { "name", "string" }, { "size", "uint" } } },
+            { "pointer_warp", { { "x", "int" }, { "y", "int" } } },
+        },
+        events = {
+            { "removed", {} },
+            { "wl_seat", { { "name", "uint" } } },
+            { "pointer_enter", { { "window", "object", "river_window_v1" } } },
+            { "pointer_leave", {} },
+            { "window_interaction", { { "window", "object", "river_window_v1" } } },
+            { "shell_surface_interaction", { { "shell_surface", "object", "river_shell_surface_v1" } } },
+            { "op_delta", { { "dx", "int" }, { "dy", "int" } } },
+            { "op_release", {} },
+            { "pointer_position", { { "x", "int" }, { "y", "int" } } },
+        },
+    },
+    ["river_shell_surface_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_node", { { "id", "new_id", "river_node_v1" } } },
+            { "sync_next_commit", {} },
+        },
+        events = {},
+    },
+    ["river_window_manager_v1"] = {
+        requests = {
+            { "stop", {} },
+            { "destroy", {} },
+            { "manage_finish", {} },
+            { "manage_dirty", {} },
+            { "render_finish", {} },
+            { "get_shell_surface", { { "id", "new_id", "river_shell_surface_v1" }, { "surface", "object", "wl_surface" } } },
+            { "exit_session", {} },
+        },
+        events = {
+            { "unavailable", {} },
+            { "finished", {} },
+            { "manage_start", {} },
+            { "render_start", {} },
+            { "session_locked", {} },
+            { "session_unlocked", {} },
+            { "window", { { "id", "new_id", "river_window_v1" } } },
+            { "output", { { "id", "new_id", "river_output_v1" } } },
+            { "seat", { { "id", "new_id", "river_seat_v1" } } },
+        },
+    },
+    ["river_window_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "close", {} },
+            { "get_node", { { "id", "new_id", "river_node_v1" } } },
+            { "propose_dimensions", { { "width", "int" }, { "height", "int" } } },
+            { "hide", {} },
+            { "show", {} },
+            { "use_csd", {} },
+            { "use_ssd", {} },
+            { "set_borders", { { "edges", "uint" }, { "width", "int" }, { "r", "uint" }, { "g", "uint" }, { "b", "uint" }, { "a", "uint" } } },
+            { "set_tiled", { { "edges", "uint" } } },
+            { "get_decoration_above", { { "id", "new_id", "river_decoration_v1" }, { "surface", "object", "wl_surface" } } },
+            { "get_decoration_below", { { "id", "new_id", "river_decoration_v1" }, { "surface", "object", "wl_surface" } } },
+            { "inform_resize_start", {} },
+            { "inform_resize_end", {} },
+            { "set_capabilities", { { "caps", "uint" } } },
+            { "inform_maximized", {} },
+            { "inform_unmaximized", {} },
+            { "inform_fullscreen", {} },
+            { "inform_not_fullscreen", {} },
+            { "fullscreen", { { "output", "object", "river_output_v1" } } },
+            { "exit_fullscreen", {} },
+            { "set_clip_box", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "set_content_clip_box", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "set_dimension_bounds", { { "max_width", "int" }, { "max_height", "int" } } },
+        },
+        events = {
+            { "closed", {} },
+            { "dimensions_hint", { { "min_width", "int" }, { "min_height", "int" }, { "max_width", "int" }, { "max_height", "int" } } },
+            { "dimensions", { { "width", "int" }, { "height", "int" } } },
+            { "app_id", { { "app_id", "string" } } },
+            { "title", { { "title", "string" } } },
+            { "parent", { { "parent", "object", "river_window_v1" } } },
+            { "decoration_hint", { { "hint", "uint" } } },
+            { "pointer_move_requested", { { "seat", "object", "river_seat_v1" } } },
+            { "pointer_resize_requested", { { "seat", "object", "river_seat_v1" }, { "edges", "uint" } } },
+            { "show_window_menu_requested", { { "x", "int" }, { "y", "int" } } },
+            { "maximize_requested", {} },
+            { "unmaximize_requested", {} },
+            { "fullscreen_requested", { { "output", "object", "river_output_v1" } } },
+            { "exit_fullscreen_requested", {} },
+            { "minimize_requested", {} },
+            { "unreliable_pid", { { "unreliable_pid", "int" } } },
+            { "presentation_hint", { { "hint", "uint" } } },
+            { "identifier", { { "identifier", "string" } } },
+            { "capture_sessions", { { "count", "uint" } } },
+        },
+    },
+    ["river_xkb_binding_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_layout_override", { { "layout", "uint" } } },
+            { "enable", {} },
+            { "disable", {} },
+        },
+        events = {
+            { "pressed", {} },
+            { "released", {} },
+            { "stop_repeat", {} },
+        },
+    },
+    ["river_xkb_bindings_seat_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "ensure_next_key_eaten", {} },
+            { "cancel_ensure_next_key_eaten", {} },
+            { "modifiers_watch", { { "modifiers", "uint" } } },
+        },
+        events = {
+            { "ate_unbound_key", {} },
+            { "modifiers_update", { { "old", "uint" }, { "new", "uint" } } },
+        },
+    },
+    ["river_xkb_bindings_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_xkb_binding", { { "seat", "object", "river_seat_v1" }, { "id", "new_id", "river_xkb_binding_v1" }, { "keysym", "uint" }, { "modifiers", "uint" } } },
+            { "get_seat", { { "id", "new_id", "river_xkb_bindings_seat_v1" }, { "seat", "object", "river_seat_v1" } } },
+        },
+        events = {},
+    },
+    ["river_xkb_config_v1"] = {
+        requests = {
+            { "stop", {} },
+            { "destroy", {} },
+            { "create_keymap", { { "id", "new_id", "river_xkb_keymap_v1" }, { "fd", "fd" }, { "format", "uint" } } },
+        },
+        events = {
+            { "finished", {} },
+            { "xkb_keyboard", { { "id", "new_id", "river_xkb_keyboard_v1" } } },
+        },
+    },
+    ["river_xkb_keyboard_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_keymap", { { "keymap", "object", "river_xkb_keymap_v1" } } },
+            { "set_layout_by_index", { { "index", "int" } } },
+            { "set_layout_by_name", { { "name", "string" } } },
+            { "capslock_enable", {} },
+            { "capslock_disable", {} },
+            { "numlock_enable", {} },
+            { "numlock_disable", {} },
+        },
+        events = {
+            { "removed", {} },
+            { "input_device", { { "device", "object", "river_input_device_v1" } } },
+            { "layout", { { "index", "uint" }, { "name", "string" } } },
+            { "capslock_enabled", {} },
+            { "capslock_disabled", {} },
+            { "numlock_enabled", {} },
+            { "numlock_disabled", {} },
+            { "done", {} },
+        },
+    },
+    ["river_xkb_keymap_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "success", {} },
+            { "failure", { { "error_msg", "string" } } },
+        },
+    },
+    ["weston_capture_source_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "capture", { { "buffer", "object", "wl_buffer" } } },
+        },
+        events = {
+            { "format", { { "drm_format", "uint" } } },
+            { "size", { { "width", "int" }, { "height", "int" } } },
+            { "complete", {} },
+            { "retry", {} },
+            { "failed", { { "msg", "string" } } },
+            { "formats_done", {} },
+        },
+    },
+    ["weston_capture_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "create", { { "output", "object", "wl_output" }, { "source", "uint" }, { "capture_source_new_id", "new_id", "weston_capture_source_v1" } } },
+        },
+        events = {},
+    },
+    ["weston_content_protection"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_protection", { { "id", "new_id", "weston_protected_surface" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["weston_debug_stream_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "complete", {} },
+            { "failure", { { "message", "string" } } },
+        },
+    },
+    ["weston_debug_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "subscribe", { { "name", "string" }, { "streamfd", "fd" }, { "stream", "new_id", "weston_debug_stream_v1" } } },
+        },
+        events = {
+            { "available", { { "name", "string" }, { "description", "string" } } },
+        },
+    },
+    ["weston_desktop_shell"] = {
+        requests = {
+            { "set_background", { { "output", "object", "wl_output" }, { "surface", "object", "wl_surface" } } },
+            { "set_panel", { { "output", "object", "wl_output" }, { "surface", "object", "wl_surface" } } },
+            { "set_lock_surface", { { "surface", "object", "wl_surface" } } },
+            { "unlock", {} },
+            { "set_grab_surface", { { "surface", "object", "wl_surface" } } },
+            { "desktop_ready", {} },
+            { "set_panel_position", { { "position", "uint" } } },
+        },
+        events = {
+            { "configure", { { "edges", "uint" }, { "surface", "object", "wl_surface" }, { "width", "int" }, { "height", "int" } } },
+            { "prepare_lock_surface", {} },
+            { "grab_cursor", { { "cursor", "uint" } } },
+        },
+    },
+    ["weston_direct_display_v1"] = {
+        requests = {
+            { "enable", { { "dmabuf", "object", "zwp_linux_buffer_params_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["weston_protected_surface"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_type", { { "type", "uint" } } },
+            { "enforce", {} },
+            { "relax", {} },
+        },
+        events = {
+            { "status", { { "type", "uint" } } },
+        },
+    },
+    ["weston_restricted_buffer_v1"] = {
+        requests = {
+            { "enable", { { "dmabuf", "object", "zwp_linux_buffer_params_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["weston_screensaver"] = {
+        requests = {
+            { "set_surface", { { "surface", "object", "wl_surface" }, { "output", "object", "wl_output" } } },
+        },
+        events = {},
+    },
+    ["weston_test"] = {
+        requests = {
+            { "move_surface", { { "surface", "object", "wl_surface" }, { "x", "int" }, { "y", "int" } } },
+            { "move_pointer", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" }, { "x", "int" }, { "y", "int" } } },
+            { "send_button", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" }, { "button", "int" }, { "state", "uint" } } },
+            { "send_axis", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" }, { "axis", "uint" }, { "value", "fixed" } } },
+            { "activate_surface", { { "surface", "object", "wl_surface" } } },
+            { "send_key", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" }, { "key", "uint" }, { "state", "uint" } } },
+            { "device_release", { { "device", "string" } } },
+            { "device_add", { { "device", "string" } } },
+            { "send_touch", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" }, { "touch_id", "int" }, { "x", "fixed" }, { "y", "fixed" }, { "touch_type", "uint" } } },
+            { "client_break", { { "breakpoint", "uint" }, { "resource_id", "uint" } } },
+        },
+        events = {
+            { "pointer_position", { { "x", "fixed" }, { "y", "fixed" } } },
+        },
+    },
+    ["weston_test_runner"] = {
+        requests = {
+            { "destroy", {} },
+            { "run", { { "test_name", "string" } } },
+        },
+        events = {
+            { "finished", {} },
+        },
+    },
+    ["weston_touch_calibration"] = {
+        requests = {
+            { "destroy", {} },
+            { "create_calibrator", { { "surface", "object", "wl_surface" }, { "device", "string" }, { "cal", "new_id", "weston_touch_calibrator" } } },
+            { "save", { { "device", "string" }, { "matrix", "array" } } },
+        },
+        events = {
+            { "touch_device", { { "device", "string" }, { "head", "string" } } },
+        },
+    },
+    ["weston_touch_calibrator"] = {
+        requests = {
+            { "destroy", {} },
+            { "convert", { { "x", "int" }, { "y", "int" }, { "reply", "new_id", "weston_touch_coordinate" } } },
+        },
+        events = {
+            { "configure", { { "width", "int" }, { "height", "int" } } },
+            { "cancel_calibration", {} },
+            { "invalid_touch", {} },
+            { "down", { { "time", "uint" }, { "id", "int" }, { "x", "uint" }, { "y", "uint" } } },
+            { "up", { { "time", "uint" }, { "id", "int" } } },
+            { "motion", { { "time", "uint" }, { "id", "int" }, { "x", "uint" }, { "y", "uint" } } },
+            { "frame", {} },
+            { "cancel", {} },
+        },
+    },
+    ["weston_touch_coordinate"] = {
+        requests = {},
+        events = {
+            { "result", { { "x", "uint" }, { "y", "uint" } } },
+        },
+    },
+    ["wl_buffer"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "release", {} },
+        },
+    },
+    ["wl_callback"] = {
+        requests = {},
+        events = {
+            { "done", { { "callback_data", "uint" } } },
+        },
+    },
+    ["wl_compositor"] = {
+        requests = {
+            { "create_surface", { { "id", "new_id", "wl_surface" } } },
+            { "create_region", { { "id", "new_id", "wl_region" } } },
+            { "release", {} },
+        },
+        events = {},
+    },
+    ["wl_data_device"] = {
+        requests = {
+            { "start_drag", { { "source", "object", "wl_data_source" }, { "origin", "object", "wl_surface" }, { "icon", "object", "wl_surface" }, { "serial", "uint" } } },
+            { "set_selection", { { "source", "object", "wl_data_source" }, { "serial", "uint" } } },
+            { "release", {} },
+        },
+        events = {
+            { "data_offer", { { "id", "new_id", "wl_data_offer" } } },
+            { "enter", { { "serial", "uint" }, { "surface", "object", "wl_surface" }, { "x", "fixed" }, { "y", "fixed" }, { "id", "object", "wl_data_offer" } } },
+            { "leave", {} },
+            { "motion", { { "time", "uint" }, { "x", "fixed" }, { "y", "fixed" } } },
+            { "drop", {} },
+            { "selection", { { "id", "object", "wl_data_offer" } } },
+        },
+    },
+    ["wl_data_device_manager"] = {
+        requests = {
+            { "create_data_source", { { "id", "new_id", "wl_data_source" } } },
+            { "get_data_device", { { "id", "new_id", "wl_data_device" }, { "seat", "object", "wl_seat" } } },
+            { "release", {} },
+        },
+        events = {},
+    },
+    ["wl_data_offer"] = {
+        requests = {
+            { "accept", { { "serial", "uint" }, { "mime_type", "string" } } },
+            { "receive", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "destroy", {} },
+            { "finish", {} },
+            { "set_actions", { { "dnd_actions", "uint" }, { "preferred_action", "uint" } } },
+        },
+        events = {
+            { "offer", { { "mime_type", "string" } } },
+            { "source_actions", { { "source_actions", "uint" } } },
+            { "action", { { "dnd_action", "uint" } } },
+        },
+    },
+    ["wl_data_source"] = {
+        requests = {
+            { "offer", { { "mime_type", "string" } } },
+            { "destroy", {} },
+            { "set_actions", { { "dnd_actions", "uint" } } },
+        },
+        events = {
+            { "target", { { "mime_type", "string" } } },
+            { "send", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "cancelled", {} },
+            { "dnd_drop_performed", {} },
+            { "dnd_finished", {} },
+            { "action", { { "dnd_action", "uint" } } },
+        },
+    },
+    ["wl_display"] = {
+        requests = {
+            { "sync", { { "callback", "new_id", "wl_callback" } } },
+            { "get_registry", { { "registry", "new_id", "wl_registry" } } },
+        },
+        events = {
+            { "error", { { "object_id", "object" }, { "code", "uint" }, { "message", "string" } } },
+            { "delete_id", { { "id", "uint" } } },
+        },
+    },
+    ["wl_drm"] = {
+        requests = {
+            { "authenticate", { { "id", "uint" } } },
+            { "create_buffer", { { "id", "new_id", "wl_buffer" }, { "name", "uint" }, { "width", "int" }, { "height", "int" }, { "stride", "uint" }, { "format", "uint" } } },
+            { "create_planar_buffer", { { "id", "new_id", "wl_buffer" }, { "name", "uint" }, { "width", "int" }, { "height", "int" }, { "format", "uint" }, { "offset0", "int" }, { "stride0", "int" }, { "offset1", "int" }, { "stride1", "int" }, { "offset2", "int" }, { "stride2", "int" } } },
+            { "create_prime_buffer", { { "id", "new_id", "wl_buffer" }, { "name", "fd" }, { "width", "int" }, { "height", "int" }, { "format", "uint" }, { "offset0", "int" }, { "stride0", "int" }, { "offset1", "int" }, { "stride1", "int" }, { "offset2", "int" }, { "stride2", "int" } } },
+        },
+        events = {
+            { "device", { { "name", "string" } } },
+            { "format", { { "format", "uint" } } },
+            { "authenticated", {} },
+            { "capabilities", { { "value", "uint" } } },
+        },
+    },
+    ["wl_fixes"] = {
+        requests = {
+            { "destroy", {} },
+            { "destroy_registry", { { "registry", "object", "wl_registry" } } },
+            { "ack_global_remove", { { "registry", "object", "wl_registry" }, { "name", "uint" } } },
+        },
+        events = {},
+    },
+    ["wl_keyboard"] = {
+        requests = {
+            { "release", {} },
+        },
+        events = {
+            { "keymap", { { "format", "uint" }, { "fd", "fd" }, { "size", "uint" } } },
+            { "enter", { { "serial", "uint" }, { "surface", "object", "wl_surface" }, { "keys", "array" } } },
+            { "leave", { { "serial", "uint" }, { "surface", "object", "wl_surface" } } },
+            { "key", { { "serial", "uint" }, { "time", "uint" }, { "key", "uint" }, { "state", "uint" } } },
+            { "modifiers", { { "serial", "uint" }, { "mods_depressed", "uint" }, { "mods_latched", "uint" }, { "mods_locked", "uint" }, { "group", "uint" } } },
+            { "repeat_info", { { "rate", "int" }, { "delay", "int" } } },
+        },
+    },
+    ["wl_output"] = {
+        requests = {
+            { "release", {} },
+        },
+        events = {
+            { "geometry", { { "x", "int" }, { "y", "int" }, { "physical_width", "int" }, { "physical_height", "int" }, { "subpixel", "int" }, { "make", "string" }, { "model", "string" }, { "transform", "int" } } },
+            { "mode", { { "flags", "uint" }, { "width", "int" }, { "height", "int" }, { "refresh", "int" } } },
+            { "done", {} },
+            { "scale", { { "factor", "int" } } },
+            { "name", { { "name", "string" } } },
+            { "description", { { "description", "string" } } },
+        },
+    },
+    ["wl_pointer"] = {
+        requests = {
+            { "set_cursor", { { "serial", "uint" }, { "surface", "object", "wl_surface" }, { "hotspot_x", "int" }, { "hotspot_y", "int" } } },
+            { "release", {} },
+        },
+        events = {
+            { "enter", { { "serial", "uint" }, { "surface", "object", "wl_surface" }, { "surface_x", "fixed" }, { "surface_y", "fixed" } } },
+            { "leave", { { "serial", "uint" }, { "surface", "object", "wl_surface" } } },
+            { "motion", { { "time", "uint" }, { "surface_x", "fixed" }, { "surface_y", "fixed" } } },
+            { "button", { { "serial", "uint" }, { "time", "uint" }, { "button", "uint" }, { "state", "uint" } } },
+            { "axis", { { "time", "uint" }, { "axis", "uint" }, { "value", "fixed" } } },
+            { "frame", {} },
+            { "axis_source", { { "axis_source", "uint" } } },
+            { "axis_stop", { { "time", "uint" }, { "axis", "uint" } } },
+            { "axis_discrete", { { "axis", "uint" }, { "discrete", "int" } } },
+            { "axis_value120", { { "axis", "uint" }, { "value120", "int" } } },
+            { "axis_relative_direction", { { "axis", "uint" }, { "direction", "uint" } } },
+            { "warp", { { "surface_x", "fixed" }, { "surface_y", "fixed" } } },
+        },
+    },
+    ["wl_region"] = {
+        requests = {
+            { "destroy", {} },
+            { "add", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "subtract", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+        },
+        events = {},
+    },
+    ["wl_registry"] = {
+        requests = {
+            { "bind", { { "name", "uint" }, { "id", "new_id" } } },
+        },
+        events = {
+            { "global", { { "name", "uint" }, { "interface", "string" }, { "version", "uint" } } },
+            { "global_remove", { { "name", "uint" } } },
+        },
+    },
+    ["wl_seat"] = {
+        requests = {
+            { "get_pointer", { { "id", "new_id", "wl_pointer" } } },
+            { "get_keyboard", { { "id", "new_id", "wl_keyboard" } } },
+            { "get_touch", { { "id", "new_id", "wl_touch" } } },
+            { "release", {} },
+        },
+        events = {
+            { "capabilities", { { "capabilities", "uint" } } },
+            { "name", { { "name", "string" } } },
+        },
+    },
+    ["wl_shell"] = {
+        requests = {
+            { "get_shell_surface", { { "id", "new_id", "wl_shell_surface" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wl_shell_surface"] = {
+        requests = {
+            { "pong", { { "serial", "uint" } } },
+            { "move", { { "seat", "object", "wl_seat" }, { "serial", "uint" } } },
+            { "resize", { { "seat", "object", "wl_seat" }, { "serial", "uint" }, { "edges", "uint" } } },
+            { "set_toplevel", {} },
+            { "set_transient", { { "parent", "object", "wl_surface" }, { "x", "int" }, { "y", "int" }, { "flags", "uint" } } },
+            { "set_fullscreen", { { "method", "uint" }, { "framerate", "uint" }, { "output", "object", "wl_output" } } },
+            { "set_popup", { { "seat", "object", "wl_seat" }, { "serial", "uint" }, { "parent", "object", "wl_surface" }, { "x", "int" }, { "y", "int" }, { "flags", "uint" } } },
+            { "set_maximized", { { "output", "object", "wl_output" } } },
+            { "set_title", { { "title", "string" } } },
+            { "set_class", { { "class_", "string" } } },
+        },
+        events = {
+            { "ping", { { "serial", "uint" } } },
+            { "configure", { { "edges", "uint" }, { "width", "int" }, { "height", "int" } } },
+            { "popup_done", {} },
+        },
+    },
+    ["wl_shm"] = {
+        requests = {
+            { "create_pool", { { "id", "new_id", "wl_shm_pool" }, { "fd", "fd" }, { "size", "int" } } },
+            { "release", {} },
+        },
+        events = {
+            { "format", { { "format", "uint" } } },
+        },
+    },
+    ["wl_shm_pool"] = {
+        requests = {
+            { "create_buffer", { { "id", "new_id", "wl_buffer" }, { "offset", "int" }, { "width", "int" }, { "height", "int" }, { "stride", "int" }, { "format", "uint" } } },
+            { "destroy", {} },
+            { "resize", { { "size", "int" } } },
+        },
+        events = {},
+    },
+    ["wl_subcompositor"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_subsurface", { { "id", "new_id", "wl_subsurface" }, { "surface", "object", "wl_surface" }, { "parent", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wl_subsurface"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_position", { { "x", "int" }, { "y", "int" } } },
+            { "place_above", { { "sibling", "object", "wl_surface" } } },
+            { "place_below", { { "sibling", "object", "wl_surface" } } },
+            { "set_sync", {} },
+            { "set_desync", {} },
+        },
+        events = {},
+    },
+    ["wl_surface"] = {
+        requests = {
+            { "destroy", {} },
+            { "attach", { { "buffer", "object", "wl_buffer" }, { "x", "int" }, { "y", "int" } } },
+            { "damage", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "frame", { { "callback", "new_id", "wl_callback" } } },
+            { "set_opaque_region", { { "region", "object", "wl_region" } } },
+            { "set_input_region", { { "region", "object", "wl_region" } } },
+            { "commit", {} },
+            { "set_buffer_transform", { { "transform", "int" } } },
+            { "set_buffer_scale", { { "scale", "int" } } },
+            { "damage_buffer", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "offset", { { "x", "int" }, { "y", "int" } } },
+            { "get_release", { { "callback", "new_id", "wl_callback" } } },
+        },
+        events = {
+            { "enter", { { "output", "object", "wl_output" } } },
+            { "leave", { { "output", "object", "wl_output" } } },
+            { "preferred_buffer_scale", { { "factor", "int" } } },
+            { "preferred_buffer_transform", { { "transform", "uint" } } },
+        },
+    },
+    ["wl_touch"] = {
+        requests = {
+            { "release", {} },
+        },
+        events = {
+            { "down", { { "serial", "uint" }, { "time", "uint" }, { "surface", "object", "wl_surface" }, { "id", "int" }, { "x", "fixed" }, { "y", "fixed" } } },
+            { "up", { { "serial", "uint" }, { "time", "uint" }, { "id", "int" } } },
+            { "motion", { { "time", "uint" }, { "id", "int" }, { "x", "fixed" }, { "y", "fixed" } } },
+            { "frame", {} },
+            { "cancel", {} },
+            { "shape", { { "id", "int" }, { "major", "fixed" }, { "minor", "fixed" } } },
+            { "orientation", { { "id", "int" }, { "orientation", "fixed" } } },
+        },
+    },
+    ["wlproxy_sync_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "sync_with_proxy", { { "id_hi", "uint" }, { "id_lo", "uint" } } },
+        },
+        events = {
+            { "sync_with_client", { { "id_hi", "uint" }, { "id_lo", "uint" } } },
+        },
+    },
+    ["wp_alpha_modifier_surface_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_multiplier", { { "factor", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_alpha_modifier_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_surface", { { "id", "new_id", "wp_alpha_modifier_surface_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wp_color_management_output_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_image_description", { { "image_description", "new_id", "wp_image_description_v1" } } },
+        },
+        events = {
+            { "image_description_changed", {} },
+        },
+    },
+    ["wp_color_management_surface_feedback_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_preferred", { { "image_description", "new_id", "wp_image_description_v1" } } },
+            { "get_preferred_parametric", { { "image_description", "new_id", "wp_image_description_v1" } } },
+        },
+        events = {
+            { "preferred_changed", { { "identity", "uint" } } },
+            { "preferred_changed2", { { "identity_hi", "uint" }, { "identity_lo", "uint" } } },
+        },
+    },
+    ["wp_color_management_surface_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_image_description", { { "image_description", "object", "wp_image_description_v1" }, { "render_intent", "uint" } } },
+            { "unset_image_description", {} },
+        },
+        events = {},
+    },
+    ["wp_color_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_output", { { "id", "new_id", "wp_color_management_output_v1" }, { "output", "object", "wl_output" } } },
+            { "get_surface", { { "id", "new_id", "wp_color_management_surface_v1" }, { "surface", "object", "wl_surface" } } },
+            { "get_surface_feedback", { { "id", "new_id", "wp_color_management_surface_feedback_v1" }, { "surface", "object", "wl_surface" } } },
+            { "create_icc_creator", { { "obj", "new_id", "wp_image_description_creator_icc_v1" } } },
+            { "create_parametric_creator", { { "obj", "new_id", "wp_image_description_creator_params_v1" } } },
+            { "create_windows_scrgb", { { "image_description", "new_id", "wp_image_description_v1" } } },
+            { "get_image_description", { { "image_description", "new_id", "wp_image_description_v1" }, { "reference", "object", "wp_image_description_reference_v1" } } },
+            { "create_windows_bt2100", { { "image_description", "new_id", "wp_image_description_v1" } } },
+        },
+        events = {
+            { "supported_intent", { { "render_intent", "uint" } } },
+            { "supported_feature", { { "feature", "uint" } } },
+            { "supported_tf_named", { { "tf", "uint" } } },
+            { "supported_primaries_named", { { "primaries", "uint" } } },
+            { "done", {} },
+        },
+    },
+    ["wp_color_representation_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_surface", { { "id", "new_id", "wp_color_representation_surface_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {
+            { "supported_alpha_mode", { { "alpha_mode", "uint" } } },
+            { "supported_coefficients_and_ranges", { { "coefficients", "uint" }, { "range", "uint" } } },
+            { "done", {} },
+        },
+    },
+    ["wp_color_representation_surface_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_alpha_mode", { { "alpha_mode", "uint" } } },
+            { "set_coefficients_and_range", { { "coefficients", "uint" }, { "range", "uint" } } },
+            { "set_chroma_location", { { "chroma_location", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_commit_timer_v1"] = {
+        requests = {
+            { "set_timestamp", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["wp_commit_timing_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_timer", { { "id", "new_id", "wp_commit_timer_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wp_content_type_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_surface_content_type", { { "id", "new_id", "wp_content_type_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wp_content_type_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_content_type", { { "content_type", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_cursor_shape_device_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_shape", { { "serial", "uint" }, { "shape", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_cursor_shape_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_pointer", { { "cursor_shape_device", "new_id", "wp_cursor_shape_device_v1" }, { "pointer", "object", "wl_pointer" } } },
+            { "get_tablet_tool_v2", { { "cursor_shape_device", "new_id", "wp_cursor_shape_device_v1" }, { "tablet_tool", "object", "zwp_tablet_tool_v2" } } },
+        },
+        events = {},
+    },
+    ["wp_drm_lease_connector_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "name", { { "name", "string" } } },
+            { "description", { { "description", "string" } } },
+            { "connector_id", { { "connector_id", "uint" } } },
+            { "done", {} },
+            { "withdrawn", {} },
+        },
+    },
+    ["wp_drm_lease_device_v1"] = {
+        requests = {
+            { "create_lease_request", { { "id", "new_id", "wp_drm_lease_request_v1" } } },
+            { "release", {} },
+        },
+        events = {
+            { "drm_fd", { { "fd", "fd" } } },
+            { "connector", { { "id", "new_id", "wp_drm_lease_connector_v1" } } },
+            { "done", {} },
+            { "released", {} },
+        },
+    },
+    ["wp_drm_lease_request_v1"] = {
+        requests = {
+            { "request_connector", { { "connector", "object", "wp_drm_lease_connector_v1" } } },
+            { "submit", { { "id", "new_id", "wp_drm_lease_v1" } } },
+        },
+        events = {},
+    },
+    ["wp_drm_lease_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "lease_fd", { { "leased_fd", "fd" } } },
+            { "finished", {} },
+        },
+    },
+    ["wp_fifo_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_fifo", { { "id", "new_id", "wp_fifo_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wp_fifo_v1"] = {
+        requests = {
+            { "set_barrier", {} },
+            { "wait_barrier", {} },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["wp_fractional_scale_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_fractional_scale", { { "id", "new_id", "wp_fractional_scale_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wp_fractional_scale_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "preferred_scale", { { "scale", "uint" } } },
+        },
+    },
+    ["wp_image_description_creator_icc_v1"] = {
+        requests = {
+            { "create", { { "image_description", "new_id", "wp_image_description_v1" } } },
+            { "set_icc_file", { { "icc_profile", "fd" }, { "offset", "uint" }, { "length", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_image_description_creator_params_v1"] = {
+        requests = {
+            { "create", { { "image_description", "new_id", "wp_image_description_v1" } } },
+            { "set_tf_named", { { "tf", "uint" } } },
+            { "set_tf_power", { { "eexp", "uint" } } },
+            { "set_primaries_named", { { "primaries", "uint" } } },
+            { "set_primaries", { { "r_x", "int" }, { "r_y", "int" }, { "g_x", "int" }, { "g_y", "int" }, { "b_x", "int" }, { "b_y", "int" }, { "w_x", "int" }, { "w_y", "int" } } },
+            { "set_luminances", { { "min_lum", "uint" }, { "max_lum", "uint" }, { "reference_lum", "uint" } } },
+            { "set_mastering_display_primaries", { { "r_x", "int" }, { "r_y", "int" }, { "g_x", "int" }, { "g_y", "int" }, { "b_x", "int" }, { "b_y", "int" }, { "w_x", "int" }, { "w_y", "int" } } },
+            { "set_mastering_luminance", { { "min_lum", "uint" }, { "max_lum", "uint" } } },
+            { "set_max_cll", { { "max_cll", "uint" } } },
+            { "set_max_fall", { { "max_fall", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_image_description_info_v1"] = {
+        requests = {},
+        events = {
+            { "done", {} },
+            { "icc_file", { { "icc", "fd" }, { "icc_size", "uint" } } },
+            { "primaries", { { "r_x", "int" }, { "r_y", "int" }, { "g_x", "int" }, { "g_y", "int" }, { "b_x", "int" }, { "b_y", "int" }, { "w_x", "int" }, { "w_y", "int" } } },
+            { "primaries_named", { { "primaries", "uint" } } },
+            { "tf_power", { { "eexp", "uint" } } },
+            { "tf_named", { { "tf", "uint" } } },
+            { "luminances", { { "min_lum", "uint" }, { "max_lum", "uint" }, { "reference_lum", "uint" } } },
+            { "target_primaries", { { "r_x", "int" }, { "r_y", "int" }, { "g_x", "int" }, { "g_y", "int" }, { "b_x", "int" }, { "b_y", "int" }, { "w_x", "int" }, { "w_y", "int" } } },
+            { "target_luminance", { { "min_lum", "uint" }, { "max_lum", "uint" } } },
+            { "target_max_cll", { { "max_cll", "uint" } } },
+            { "target_max_fall", { { "max_fall", "uint" } } },
+        },
+    },
+    ["wp_image_description_reference_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["wp_image_description_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_information", { { "information", "new_id", "wp_image_description_info_v1" } } },
+        },
+        events = {
+            { "failed", { { "cause", "uint" }, { "msg", "string" } } },
+            { "ready", { { "identity", "uint" } } },
+            { "ready2", { { "identity_hi", "uint" }, { "identity_lo", "uint" } } },
+        },
+    },
+    ["wp_linux_drm_syncobj_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_surface", { { "id", "new_id", "wp_linux_drm_syncobj_surface_v1" }, { "surface", "object", "wl_surface" } } },
+            { "import_timeline", { { "id", "new_id", "wp_linux_drm_syncobj_timeline_v1" }, { "fd", "fd" } } },
+        },
+        events = {},
+    },
+    ["wp_linux_drm_syncobj_surface_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_acquire_point", { { "timeline", "object", "wp_linux_drm_syncobj_timeline_v1" }, { "point_hi", "uint" }, { "point_lo", "uint" } } },
+            { "set_release_point", { { "timeline", "object", "wp_linux_drm_syncobj_timeline_v1" }, { "point_hi", "uint" }, { "point_lo", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_linux_drm_syncobj_timeline_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["wp_pointer_warp_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "warp_pointer", { { "surface", "object", "wl_surface" }, { "pointer", "object", "wl_pointer" }, { "x", "fixed" }, { "y", "fixed" }, { "serial", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_presentation"] = {
+        requests = {
+            { "destroy", {} },
+            { "feedback", { { "surface", "object", "wl_surface" }, { "callback", "new_id", "wp_presentation_feedback" } } },
+        },
+        events = {
+            { "clock_id", { { "clk_id", "uint" } } },
+        },
+    },
+    ["wp_presentation_feedback"] = {
+        requests = {},
+        events = {
+            { "sync_output", { { "output", "object", "wl_output" } } },
+            { "presented", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" }, { "refresh", "uint" }, { "seq_hi", "uint" }, { "seq_lo", "uint" }, { "flags", "uint" } } },
+            { "discarded", {} },
+        },
+    },
+    ["wp_security_context_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "create_listener", { { "id", "new_id", "wp_security_context_v1" }, { "listen_fd", "fd" }, { "close_fd", "fd" } } },
+        },
+        events = {},
+    },
+    ["wp_security_context_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_sandbox_engine", { { "name", "string" } } },
+            { "set_app_id", { { "app_id", "string" } } },
+            { "set_instance_id", { { "instance_id", "string" } } },
+            { "commit", {} },
+        },
+        events = {},
+    },
+    ["wp_single_pixel_buffer_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "create_u32_rgba_buffer", { { "id", "new_id", "wl_buffer" }, { "r", "uint" }, { "g", "uint" }, { "b", "uint" }, { "a", "uint" } } },
+        },
+        events = {},
+    },
+    ["wp_tearing_control_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_tearing_control", { { "id", "new_id", "wp_tearing_control_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["wp_tearing_control_v1"] = {
+        requests = {
+            { "set_presentation_hint", { { "hint", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["wp_viewport"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_source", { { "x", "fixed" }, { "y", "fixed" }, { "width", "fixed" }, { "height", "fixed" } } },
+            { "set_destination", { { "width", "int" }, { "height", "int" } } },
+        },
+        events = {},
+    },
+    ["wp_viewporter"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_viewport", { { "id", "new_id", "wp_viewport" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["xdg_activation_token_v1"] = {
+        requests = {
+            { "set_serial", { { "serial", "uint" }, { "seat", "object", "wl_seat" } } },
+            { "set_app_id", { { "app_id", "string" } } },
+            { "set_surface", { { "surface", "object", "wl_surface" } } },
+            { "commit", {} },
+            { "destroy", {} },
+        },
+        events = {
+            { "done", { { "token", "string" } } },
+        },
+    },
+    ["xdg_activation_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_activation_token", { { "id", "new_id", "xdg_activation_token_v1" } } },
+            { "activate", { { "token", "string" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["xdg_dialog_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_modal", {} },
+            { "unset_modal", {} },
+        },
+        events = {},
+    },
+    ["xdg_popup"] = {
+        requests = {
+            { "destroy", {} },
+            { "grab", { { "seat", "object", "wl_seat" }, { "serial", "uint" } } },
+            { "reposition", { { "positioner", "object", "xdg_positioner" }, { "token", "uint" } } },
+        },
+        events = {
+            { "configure", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "popup_done", {} },
+            { "repositioned", { { "token", "uint" } } },
+        },
+    },
+    ["xdg_positioner"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_size", { { "width", "int" }, { "height", "int" } } },
+            { "set_anchor_rect", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "set_anchor", { { "anchor", "uint" } } },
+            { "set_gravity", { { "gravity", "uint" } } },
+            { "set_constraint_adjustment", { { "constraint_adjustment", "uint" } } },
+            { "set_offset", { { "x", "int" }, { "y", "int" } } },
+            { "set_reactive", {} },
+            { "set_parent_size", { { "parent_width", "int" }, { "parent_height", "int" } } },
+            { "set_parent_configure", { { "serial", "uint" } } },
+        },
+        events = {},
+    },
+    ["xdg_session_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_session", { { "id", "new_id", "xdg_session_v1" }, { "reason", "uint" }, { "session_id", "string" } } },
+        },
+        events = {},
+    },
+    ["xdg_session_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "remove", {} },
+            { "add_toplevel", { { "id", "new_id", "xdg_toplevel_session_v1" }, { "toplevel", "object", "xdg_toplevel" }, { "name", "string" } } },
+            { "restore_toplevel", { { "id", "new_id", "xdg_toplevel_session_v1" }, { "toplevel", "object", "xdg_toplevel" }, { "name", "string" } } },
+            { "remove_toplevel", { { "name", "string" } } },
+        },
+        events = {
+            { "created", { { "session_id", "string" } } },
+            { "restored", {} },
+            { "replaced", {} },
+        },
+    },
+    ["xdg_surface"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_toplevel", { { "id", "new_id", "xdg_toplevel" } } },
+            { "get_popup", { { "id", "new_id", "xdg_popup" }, { "parent", "object", "xdg_surface" }, { "positioner", "object", "xdg_positioner" } } },
+            { "set_window_geometry", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "ack_configure", { { "serial", "uint" } } },
+        },
+        events = {
+            { "configure", { { "serial", "uint" } } },
+        },
+    },
+    ["xdg_system_bell_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "ring", { { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["xdg_toplevel"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_parent", { { "parent", "object", "xdg_toplevel" } } },
+            { "set_title", { { "title", "string" } } },
+            { "set_app_id", { { "app_id", "string" } } },
+            { "show_window_menu", { { "seat", "object", "wl_seat" }, { "serial", "uint" }, { "x", "int" }, { "y", "int" } } },
+            { "move", { { "seat", "object", "wl_seat" }, { "serial", "uint" } } },
+            { "resize", { { "seat", "object", "wl_seat" }, { "serial", "uint" }, { "edges", "uint" } } },
+            { "set_max_size", { { "width", "int" }, { "height", "int" } } },
+            { "set_min_size", { { "width", "int" }, { "height", "int" } } },
+            { "set_maximized", {} },
+            { "unset_maximized", {} },
+            { "set_fullscreen", { { "output", "object", "wl_output" } } },
+            { "unset_fullscreen", {} },
+            { "set_minimized", {} },
+        },
+        events = {
+            { "configure", { { "width", "int" }, { "height", "int" }, { "states", "array" } } },
+            { "close", {} },
+            { "configure_bounds", { { "width", "int" }, { "height", "int" } } },
+            { "wm_capabilities", { { "capabilities", "array" } } },
+        },
+    },
+    ["xdg_toplevel_drag_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_xdg_toplevel_drag", { { "id", "new_id", "xdg_toplevel_drag_v1" }, { "data_source", "object", "wl_data_source" } } },
+        },
+        events = {},
+    },
+    ["xdg_toplevel_drag_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "attach", { { "toplevel", "object", "xdg_toplevel" }, { "x_offset", "int" }, { "y_offset", "int" } } },
+        },
+        events = {},
+    },
+    ["xdg_toplevel_icon_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "create_icon", { { "id", "new_id", "xdg_toplevel_icon_v1" } } },
+            { "set_icon", { { "toplevel", "object", "xdg_toplevel" }, { "icon", "object", "xdg_toplevel_icon_v1" } } },
+        },
+        events = {
+            { "icon_size", { { "size", "int" } } },
+            { "done", {} },
+        },
+    },
+    ["xdg_toplevel_icon_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_name", { { "icon_name", "string" } } },
+            { "add_buffer", { { "buffer", "object", "wl_buffer" }, { "scale", "int" } } },
+        },
+        events = {},
+    },
+    ["xdg_toplevel_session_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "rename", { { "name", "string" } } },
+        },
+        events = {
+            { "restored", {} },
+        },
+    },
+    ["xdg_toplevel_tag_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_toplevel_tag", { { "toplevel", "object", "xdg_toplevel" }, { "tag", "string" } } },
+            { "set_toplevel_description", { { "toplevel", "object", "xdg_toplevel" }, { "description", "string" } } },
+        },
+        events = {},
+    },
+    ["xdg_wm_base"] = {
+        requests = {
+            { "destroy", {} },
+            { "create_positioner", { { "id", "new_id", "xdg_positioner" } } },
+            { "get_xdg_surface", { { "id", "new_id", "xdg_surface" }, { "surface", "object", "wl_surface" } } },
+            { "pong", { { "serial", "uint" } } },
+        },
+        events = {
+            { "ping", { { "serial", "uint" } } },
+        },
+    },
+    ["xdg_wm_dialog_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_xdg_dialog", { { "id", "new_id", "xdg_dialog_v1" }, { "toplevel", "object", "xdg_toplevel" } } },
+        },
+        events = {},
+    },
+    ["xwayland_shell_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_xwayland_surface", { { "id", "new_id", "xwayland_surface_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["xwayland_surface_v1"] = {
+        requests = {
+            { "set_serial", { { "serial_lo", "uint" }, { "serial_hi", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zcosmic_keyboard_layout_manager_v1"] = {
+        requests = {
+            { "get_keyboard_layout", { { "keyboard_layout", "new_id", "zcosmic_keyboard_layout_v1" }, { "keyboard", "object", "wl_keyboard" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zcosmic_keyboard_layout_v1"] = {
+        requests = {
+            { "set_group", { { "group", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "group", { { "group", "uint" } } },
+        },
+    },
+    ["zcosmic_output_configuration_head_v1"] = {
+        requests = {
+            { "set_scale_1000", { { "scale_1000", "int" } } },
+            { "release", {} },
+            { "set_adaptive_sync_ext", { { "state", "uint" } } },
+        },
+        events = {},
+    },
+    ["zcosmic_output_configuration_v1"] = {
+        requests = {
+            { "mirror_head", { { "id", "new_id", "zwlr_output_configuration_head_v1" }, { "head", "object", "zwlr_output_head_v1" }, { "mirroring", "object", "zwlr_output_head_v1" } } },
+            { "release", {} },
+        },
+        events = {
+            { "finished", {} },
+        },
+    },
+    ["zcosmic_output_head_v1"] = {
+        requests = {
+            { "release", {} },
+        },
+        events = {
+            { "scale_1000", { { "scale_1000", "int" } } },
+            { "mirroring", { { "name", "string" } } },
+            { "adaptive_sync_available", { { "available", "uint" } } },
+            { "adaptive_sync_ext", { { "state", "uint" } } },
+            { "xwayland_primary", { { "state", "uint" } } },
+        },
+    },
+    ["zcosmic_output_manager_v1"] = {
+        requests = {
+            { "get_head", { { "extended", "new_id", "zcosmic_output_head_v1" }, { "head", "object", "zwlr_output_head_v1" } } },
+            { "get_configuration", { { "extended", "new_id", "zcosmic_output_configuration_v1" }, { "config", "object", "zwlr_output_configuration_v1" } } },
+            { "get_configuration_head", { { "extended", "new_id", "zcosmic_output_configuration_head_v1" }, { "config_head", "object", "zwlr_output_configuration_head_v1" } } },
+            { "release", {} },
+            { "set_xwayland_primary", { { "head", "object", "zcosmic_output_head_v1" } } },
+        },
+        events = {},
+    },
+    ["zcosmic_overlap_notification_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "toplevel_enter", { { "toplevel", "object", "ext_foreign_toplevel_handle_v1" }, { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "toplevel_leave", { { "toplevel", "object", "ext_foreign_toplevel_handle_v1" } } },
+            { "layer_enter", { { "identifier", "string" }, { "namespace", "string" }, { "exclusive", "uint" }, { "layer", "uint" }, { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "layer_leave", { { "identifier", "string" } } },
+        },
+    },
+    ["zcosmic_overlap_notify_v1"] = {
+        requests = {
+            { "notify_on_overlap", { { "overlap_notification", "new_id", "zcosmic_overlap_notification_v1" }, { "layer_surface", "object", "zwlr_layer_surface_v1" } } },
+        },
+        events = {},
+    },
+    ["zcosmic_workspace_handle_v2"] = {
+        requests = {
+            { "destroy", {} },
+            { "rename", { { "name", "string" } } },
+            { "set_tiling_state", { { "state", "uint" } } },
+            { "move_before", { { "other_workspace", "object", "ext_workspace_handle_v1" }, { "axis", "uint" } } },
+            { "move_after", { { "other_workspace", "object", "ext_workspace_handle_v1" }, { "axis", "uint" } } },
+            { "pin", {} },
+            { "unpin", {} },
+        },
+        events = {
+            { "capabilities", { { "capabilities", "uint" } } },
+            { "tiling_state", { { "state", "uint" } } },
+            { "state", { { "state", "uint" } } },
+        },
+    },
+    ["zcosmic_workspace_image_capture_source_manager_v1"] = {
+        requests = {
+            { "create_source", { { "source", "new_id", "ext_image_capture_source_v1" }, { "output", "object", "ext_workspace_handle_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zcosmic_workspace_manager_v2"] = {
+        requests = {
+            { "get_cosmic_workspace", { { "cosmic_workspace", "new_id", "zcosmic_workspace_handle_v2" }, { "workspace", "object", "ext_workspace_handle_v1" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_data_control_device_v1"] = {
+        requests = {
+            { "set_selection", { { "source", "object", "zwlr_data_control_source_v1" } } },
+            { "destroy", {} },
+            { "set_primary_selection", { { "source", "object", "zwlr_data_control_source_v1" } } },
+        },
+        events = {
+            { "data_offer", { { "id", "new_id", "zwlr_data_control_offer_v1" } } },
+            { "selection", { { "id", "object", "zwlr_data_control_offer_v1" } } },
+            { "finished", {} },
+            { "primary_selection", { { "id", "object", "zwlr_data_control_offer_v1" } } },
+        },
+    },
+    ["zwlr_data_control_manager_v1"] = {
+        requests = {
+            { "create_data_source", { { "id", "new_id", "zwlr_data_control_source_v1" } } },
+            { "get_data_device", { { "id", "new_id", "zwlr_data_control_device_v1" }, { "seat", "object", "wl_seat" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_data_control_offer_v1"] = {
+        requests = {
+            { "receive", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "offer", { { "mime_type", "string" } } },
+        },
+    },
+    ["zwlr_data_control_source_v1"] = {
+        requests = {
+            { "offer", { { "mime_type", "string" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "send", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "cancelled", {} },
+        },
+    },
+    ["zwlr_export_dmabuf_frame_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "frame", { { "width", "uint" }, { "height", "uint" }, { "offset_x", "uint" }, { "offset_y", "uint" }, { "buffer_flags", "uint" }, { "flags", "uint" }, { "format", "uint" }, { "mod_high", "uint" }, { "mod_low", "uint" }, { "num_objects", "uint" } } },
+            { "object", { { "index", "uint" }, { "fd", "fd" }, { "size", "uint" }, { "offset", "uint" }, { "stride", "uint" }, { "plane_index", "uint" } } },
+            { "ready", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+            { "cancel", { { "reason", "uint" } } },
+        },
+    },
+    ["zwlr_export_dmabuf_manager_v1"] = {
+        requests = {
+            { "capture_output", { { "frame", "new_id", "zwlr_export_dmabuf_frame_v1" }, { "overlay_cursor", "int" }, { "output", "object", "wl_output" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_foreign_toplevel_handle_v1"] = {
+        requests = {
+            { "set_maximized", {} },
+            { "unset_maximized", {} },
+            { "set_minimized", {} },
+            { "unset_minimized", {} },
+            { "activate", { { "seat", "object", "wl_seat" } } },
+            { "close", {} },
+            { "set_rectangle", { { "surface", "object", "wl_surface" }, { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "destroy", {} },
+            { "set_fullscreen", { { "output", "object", "wl_output" } } },
+            { "unset_fullscreen", {} },
+        },
+        events = {
+            { "title", { { "title", "string" } } },
+            { "app_id", { { "app_id", "string" } } },
+            { "output_enter", { { "output", "object", "wl_output" } } },
+            { "output_leave", { { "output", "object", "wl_output" } } },
+            { "state", { { "state", "array" } } },
+            { "done", {} },
+            { "closed", {} },
+            { "parent", { { "parent", "object", "zwlr_foreign_toplevel_handle_v1" } } },
+        },
+    },
+    ["zwlr_foreign_toplevel_manager_v1"] = {
+        requests = {
+            { "stop", {} },
+        },
+        events = {
+            { "toplevel", { { "toplevel", "new_id", "zwlr_foreign_toplevel_handle_v1" } } },
+            { "finished", {} },
+        },
+    },
+    ["zwlr_gamma_control_manager_v1"] = {
+        requests = {
+            { "get_gamma_control", { { "id", "new_id", "zwlr_gamma_control_v1" }, { "output", "object", "wl_output" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_gamma_control_v1"] = {
+        requests = {
+            { "set_gamma", { { "fd", "fd" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "gamma_size", { { "size", "uint" } } },
+            { "failed", {} },
+        },
+    },
+    ["zwlr_input_inhibit_manager_v1"] = {
+        requests = {
+            { "get_inhibitor", { { "id", "new_id", "zwlr_input_inhibitor_v1" } } },
+        },
+        events = {},
+    },
+    ["zwlr_input_inhibitor_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_layer_shell_v1"] = {
+        requests = {
+            { "get_layer_surface", { { "id", "new_id", "zwlr_layer_surface_v1" }, { "surface", "object", "wl_surface" }, { "output", "object", "wl_output" }, { "layer", "uint" }, { "namespace", "string" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_layer_surface_v1"] = {
+        requests = {
+            { "set_size", { { "width", "uint" }, { "height", "uint" } } },
+            { "set_anchor", { { "anchor", "uint" } } },
+            { "set_exclusive_zone", { { "zone", "int" } } },
+            { "set_margin", { { "top", "int" }, { "right", "int" }, { "bottom", "int" }, { "left", "int" } } },
+            { "set_keyboard_interactivity", { { "keyboard_interactivity", "uint" } } },
+            { "get_popup", { { "popup", "object", "xdg_popup" } } },
+            { "ack_configure", { { "serial", "uint" } } },
+            { "destroy", {} },
+            { "set_layer", { { "layer", "uint" } } },
+            { "set_exclusive_edge", { { "edge", "uint" } } },
+        },
+        events = {
+            { "configure", { { "serial", "uint" }, { "width", "uint" }, { "height", "uint" } } },
+            { "closed", {} },
+        },
+    },
+    ["zwlr_output_configuration_head_v1"] = {
+        requests = {
+            { "set_mode", { { "mode", "object", "zwlr_output_mode_v1" } } },
+            { "set_custom_mode", { { "width", "int" }, { "height", "int" }, { "refresh", "int" } } },
+            { "set_position", { { "x", "int" }, { "y", "int" } } },
+            { "set_transform", { { "transform", "int" } } },
+            { "set_scale", { { "scale", "fixed" } } },
+            { "set_adaptive_sync", { { "state", "uint" } } },
+        },
+        events = {},
+    },
+    ["zwlr_output_configuration_v1"] = {
+        requests = {
+            { "enable_head", { { "id", "new_id", "zwlr_output_configuration_head_v1" }, { "head", "object", "zwlr_output_head_v1" } } },
+            { "disable_head", { { "head", "object", "zwlr_output_head_v1" } } },
+            { "apply", {} },
+            { "test", {} },
+            { "destroy", {} },
+        },
+        events = {
+            { "succeeded", {} },
+            { "failed", {} },
+            { "cancelled", {} },
+        },
+    },
+    ["zwlr_output_head_v1"] = {
+        requests = {
+            { "release", {} },
+        },
+        events = {
+            { "name", { { "name", "string" } } },
+            { "description", { { "description", "string" } } },
+            { "physical_size", { { "width", "int" }, { "height", "int" } } },
+            { "mode", { { "mode", "new_id", "zwlr_output_mode_v1" } } },
+            { "enabled", { { "enabled", "int" } } },
+            { "current_mode", { { "mode", "object", "zwlr_output_mode_v1" } } },
+            { "position", { { "x", "int" }, { "y", "int" } } },
+            { "transform", { { "transform", "int" } } },
+            { "scale", { { "scale", "fixed" } } },
+            { "finished", {} },
+            { "make", { { "make", "string" } } },
+            { "model", { { "model", "string" } } },
+            { "serial_number", { { "serial_number", "string" } } },
+            { "adaptive_sync", { { "state", "uint" } } },
+        },
+    },
+    ["zwlr_output_manager_v1"] = {
+        requests = {
+            { "create_configuration", { { "id", "new_id", "zwlr_output_configuration_v1" }, { "serial", "uint" } } },
+            { "stop", {} },
+        },
+        events = {
+            { "head", { { "head", "new_id", "zwlr_output_head_v1" } } },
+            { "done", { { "serial", "uint" } } },
+            { "finished", {} },
+        },
+    },
+    ["zwlr_output_mode_v1"] = {
+        requests = {
+            { "release", {} },
+        },
+        events = {
+            { "size", { { "width", "int" }, { "height", "int" } } },
+            { "refresh", { { "refresh", "int" } } },
+            { "preferred", {} },
+            { "finished", {} },
+        },
+    },
+    ["zwlr_output_power_manager_v1"] = {
+        requests = {
+            { "get_output_power", { { "id", "new_id", "zwlr_output_power_v1" }, { "output", "object", "wl_output" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_output_power_v1"] = {
+        requests = {
+            { "set_mode", { { "mode", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "mode", { { "mode", "uint" } } },
+            { "failed", {} },
+        },
+    },
+    ["zwlr_screencopy_frame_v1"] = {
+        requests = {
+            { "copy", { { "buffer", "object", "wl_buffer" } } },
+            { "destroy", {} },
+            { "copy_with_damage", { { "buffer", "object", "wl_buffer" } } },
+        },
+        events = {
+            { "buffer", { { "format", "uint" }, { "width", "uint" }, { "height", "uint" }, { "stride", "uint" } } },
+            { "flags", { { "flags", "uint" } } },
+            { "ready", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+            { "failed", {} },
+            { "damage", { { "x", "uint" }, { "y", "uint" }, { "width", "uint" }, { "height", "uint" } } },
+            { "linux_dmabuf", { { "format", "uint" }, { "width", "uint" }, { "height", "uint" } } },
+            { "buffer_done", {} },
+        },
+    },
+    ["zwlr_screencopy_manager_v1"] = {
+        requests = {
+            { "capture_output", { { "frame", "new_id", "zwlr_screencopy_frame_v1" }, { "overlay_cursor", "int" }, { "output", "object", "wl_output" } } },
+            { "capture_output_region", { { "frame", "new_id", "zwlr_screencopy_frame_v1" }, { "overlay_cursor", "int" }, { "output", "object", "wl_output" }, { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwlr_virtual_pointer_manager_v1"] = {
+        requests = {
+            { "create_virtual_pointer", { { "seat", "object", "wl_seat" }, { "id", "new_id", "zwlr_virtual_pointer_v1" } } },
+            { "destroy", {} },
+            { "create_virtual_pointer_with_output", { { "seat", "object", "wl_seat" }, { "output", "object", "wl_output" }, { "id", "new_id", "zwlr_virtual_pointer_v1" } } },
+        },
+        events = {},
+    },
+    ["zwlr_virtual_pointer_v1"] = {
+        requests = {
+            { "motion", { { "time", "uint" }, { "dx", "fixed" }, { "dy", "fixed" } } },
+            { "motion_absolute", { { "time", "uint" }, { "x", "uint" }, { "y", "uint" }, { "x_extent", "uint" }, { "y_extent", "uint" } } },
+            { "button", { { "time", "uint" }, { "button", "uint" }, { "state", "uint" } } },
+            { "axis", { { "time", "uint" }, { "axis", "uint" }, { "value", "fixed" } } },
+            { "frame", {} },
+            { "axis_source", { { "axis_source", "uint" } } },
+            { "axis_stop", { { "time", "uint" }, { "axis", "uint" } } },
+            { "axis_discrete", { { "time", "uint" }, { "axis", "uint" }, { "value", "fixed" }, { "discrete", "int" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwp_confined_pointer_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_region", { { "region", "object", "wl_region" } } },
+        },
+        events = {
+            { "confined", {} },
+            { "unconfined", {} },
+        },
+    },
+    ["zwp_fullscreen_shell_mode_feedback_v1"] = {
+        requests = {},
+        events = {
+            { "mode_successful", {} },
+            { "mode_failed", {} },
+            { "present_cancelled", {} },
+        },
+    },
+    ["zwp_fullscreen_shell_v1"] = {
+        requests = {
+            { "release", {} },
+            { "present_surface", { { "surface", "object", "wl_surface" }, { "method", "uint" }, { "output", "object", "wl_output" } } },
+            { "present_surface_for_mode", { { "surface", "object", "wl_surface" }, { "output", "object", "wl_output" }, { "framerate", "int" }, { "feedback", "new_id", "zwp_fullscreen_shell_mode_feedback_v1" } } },
+        },
+        events = {
+            { "capability", { { "capability", "uint" } } },
+        },
+    },
+    ["zwp_idle_inhibit_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "create_inhibitor", { { "id", "new_id", "zwp_idle_inhibitor_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["zwp_idle_inhibitor_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwp_input_method_context_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "commit_string", { { "serial", "uint" }, { "text", "string" } } },
+            { "preedit_string", { { "serial", "uint" }, { "text", "string" }, { "commit", "string" } } },
+            { "preedit_styling", { { "index", "uint" }, { "length", "uint" }, { "style", "uint" } } },
+            { "preedit_cursor", { { "index", "int" } } },
+            { "delete_surrounding_text", { { "index", "int" }, { "length", "uint" } } },
+            { "cursor_position", { { "index", "int" }, { "anchor", "int" } } },
+            { "modifiers_map", { { "map", "array" } } },
+            { "keysym", { { "serial", "uint" }, { "time", "uint" }, { "sym", "uint" }, { "state", "uint" }, { "modifiers", "uint" } } },
+            { "grab_keyboard", { { "keyboard", "new_id", "wl_keyboard" } } },
+            { "key", { { "serial", "uint" }, { "time", "uint" }, { "key", "uint" }, { "state", "uint" } } },
+            { "modifiers", { { "serial", "uint" }, { "mods_depressed", "uint" }, { "mods_latched", "uint" }, { "mods_locked", "uint" }, { "group", "uint" } } },
+            { "language", { { "serial", "uint" }, { "language", "string" } } },
+            { "text_direction", { { "serial", "uint" }, { "direction", "uint" } } },
+        },
+        events = {
+            { "surrounding_text", { { "text", "string" }, { "cursor", "uint" }, { "anchor", "uint" } } },
+            { "reset", {} },
+            { "content_type", { { "hint", "uint" }, { "purpose", "uint" } } },
+            { "invoke_action", { { "button", "uint" }, { "index", "uint" } } },
+            { "commit_state", { { "serial", "uint" } } },
+            { "preferred_language", { { "language", "string" } } },
+        },
+    },
+    ["zwp_input_method_keyboard_grab_v2"] = {
+        requests = {
+            { "release", {} },
+        },
+        events = {
+            { "keymap", { { "format", "uint" }, { "fd", "fd" }, { "size", "uint" } } },
+            { "key", { { "serial", "uint" }, { "time", "uint" }, { "key", "uint" }, { "state", "uint" } } },
+            { "modifiers", { { "serial", "uint" }, { "mods_depressed", "uint" }, { "mods_latched", "uint" }, { "mods_locked", "uint" }, { "group", "uint" } } },
+            { "repeat_info", { { "rate", "int" }, { "delay", "int" } } },
+        },
+    },
+    ["zwp_input_method_manager_v2"] = {
+        requests = {
+            { "get_input_method", { { "seat", "object", "wl_seat" }, { "input_method", "new_id", "zwp_input_method_v2" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwp_input_method_v1"] = {
+        requests = {},
+        events = {
+            { "activate", { { "id", "new_id", "zwp_input_method_context_v1" } } },
+            { "deactivate", { { "context", "object", "zwp_input_method_context_v1" } } },
+        },
+    },
+    ["zwp_input_method_v2"] = {
+        requests = {
+            { "commit_string", { { "text", "string" } } },
+            { "set_preedit_string", { { "text", "string" }, { "cursor_begin", "int" }, { "cursor_end", "int" } } },
+            { "delete_surrounding_text", { { "before_length", "uint" }, { "after_length", "uint" } } },
+            { "commit", { { "serial", "uint" } } },
+            { "get_input_popup_surface", { { "id", "new_id", "zwp_input_popup_surface_v2" }, { "surface", "object", "wl_surface" } } },
+            { "grab_keyboard", { { "keyboard", "new_id", "zwp_input_method_keyboard_grab_v2" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "activate", {} },
+            { "deactivate", {} },
+            { "surrounding_text", { { "text", "string" }, { "cursor", "uint" }, { "anchor", "uint" } } },
+            { "text_change_cause", { { "cause", "uint" } } },
+            { "content_type", { { "hint", "uint" }, { "purpose", "uint" } } },
+            { "done", {} },
+            { "unavailable", {} },
+        },
+    },
+    ["zwp_input_panel_surface_v1"] = {
+        requests = {
+            { "set_toplevel", { { "output", "object", "wl_output" }, { "position", "uint" } } },
+            { "set_overlay_panel", {} },
+        },
+        events = {},
+    },
+    ["zwp_input_panel_v1"] = {
+        requests = {
+            { "get_input_panel_surface", { { "id", "new_id", "zwp_input_panel_surface_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["zwp_input_popup_surface_v2"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "text_input_rectangle", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+        },
+    },
+    ["zwp_input_timestamps_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_keyboard_timestamps", { { "id", "new_id", "zwp_input_timestamps_v1" }, { "keyboard", "object", "wl_keyboard" } } },
+            { "get_pointer_timestamps", { { "id", "new_id", "zwp_input_timestamps_v1" }, { "pointer", "object", "wl_pointer" } } },
+            { "get_touch_timestamps", { { "id", "new_id", "zwp_input_timestamps_v1" }, { "touch", "object", "wl_touch" } } },
+        },
+        events = {},
+    },
+    ["zwp_input_timestamps_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "timestamp", { { "tv_sec_hi", "uint" }, { "tv_sec_lo", "uint" }, { "tv_nsec", "uint" } } },
+        },
+    },
+    ["zwp_keyboard_shortcuts_inhibit_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "inhibit_shortcuts", { { "id", "new_id", "zwp_keyboard_shortcuts_inhibitor_v1" }, { "surface", "object", "wl_surface" }, { "seat", "object", "wl_seat" } } },
+        },
+        events = {},
+    },
+    ["zwp_keyboard_shortcuts_inhibitor_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "active", {} },
+            { "inactive", {} },
+        },
+    },
+    ["zwp_linux_buffer_params_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "add", { { "fd", "fd" }, { "plane_idx", "uint" }, { "offset", "uint" }, { "stride", "uint" }, { "modifier_hi", "uint" }, { "modifier_lo", "uint" } } },
+            { "create", { { "width", "int" }, { "height", "int" }, { "format", "uint" }, { "flags", "uint" } } },
+            { "create_immed", { { "buffer_id", "new_id", "wl_buffer" }, { "width", "int" }, { "height", "int" }, { "format", "uint" }, { "flags", "uint" } } },
+            { "set_sampling_device", { { "device", "array" } } },
+        },
+        events = {
+            { "created", { { "buffer", "new_id", "wl_buffer" } } },
+            { "failed", {} },
+        },
+    },
+    ["zwp_linux_buffer_release_v1"] = {
+        requests = {},
+        events = {
+            { "fenced_release", { { "fence", "fd" } } },
+            { "immediate_release", {} },
+        },
+    },
+    ["zwp_linux_dmabuf_feedback_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "done", {} },
+            { "format_table", { { "fd", "fd" }, { "size", "uint" } } },
+            { "main_device", { { "device", "array" } } },
+            { "tranche_done", {} },
+            { "tranche_target_device", { { "device", "array" } } },
+            { "tranche_formats", { { "indices", "array" } } },
+            { "tranche_flags", { { "flags", "uint" } } },
+        },
+    },
+    ["zwp_linux_dmabuf_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "create_params", { { "params_id", "new_id", "zwp_linux_buffer_params_v1" } } },
+            { "get_default_feedback", { { "id", "new_id", "zwp_linux_dmabuf_feedback_v1" } } },
+            { "get_surface_feedback", { { "id", "new_id", "zwp_linux_dmabuf_feedback_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {
+            { "format", { { "format", "uint" } } },
+            { "modifier", { { "format", "uint" }, { "modifier_hi", "uint" }, { "modifier_lo", "uint" } } },
+        },
+    },
+    ["zwp_linux_explicit_synchronization_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_synchronization", { { "id", "new_id", "zwp_linux_surface_synchronization_v1" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["zwp_linux_surface_synchronization_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_acquire_fence", { { "fd", "fd" } } },
+            { "get_release", { { "release", "new_id", "zwp_linux_buffer_release_v1" } } },
+        },
+        events = {},
+    },
+    ["zwp_locked_pointer_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_cursor_position_hint", { { "surface_x", "fixed" }, { "surface_y", "fixed" } } },
+            { "set_region", { { "region", "object", "wl_region" } } },
+        },
+        events = {
+            { "locked", {} },
+            { "unlocked", {} },
+        },
+    },
+    ["zwp_pointer_constraints_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "lock_pointer", { { "id", "new_id", "zwp_locked_pointer_v1" }, { "surface", "object", "wl_surface" }, { "pointer", "object", "wl_pointer" }, { "region", "object", "wl_region" }, { "lifetime", "uint" } } },
+            { "confine_pointer", { { "id", "new_id", "zwp_confined_pointer_v1" }, { "surface", "object", "wl_surface" }, { "pointer", "object", "wl_pointer" }, { "region", "object", "wl_region" }, { "lifetime", "uint" } } },
+        },
+        events = {},
+    },
+    ["zwp_pointer_gesture_hold_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "begin", { { "serial", "uint" }, { "time", "uint" }, { "surface", "object", "wl_surface" }, { "fingers", "uint" } } },
+            { "end", { { "serial", "uint" }, { "time", "uint" }, { "cancelled", "int" } } },
+        },
+    },
+    ["zwp_pointer_gesture_pinch_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "begin", { { "serial", "uint" }, { "time", "uint" }, { "surface", "object", "wl_surface" }, { "fingers", "uint" } } },
+            { "update", { { "time", "uint" }, { "dx", "fixed" }, { "dy", "fixed" }, { "scale", "fixed" }, { "rotation", "fixed" } } },
+            { "end", { { "serial", "uint" }, { "time", "uint" }, { "cancelled", "int" } } },
+        },
+    },
+    ["zwp_pointer_gesture_swipe_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "begin", { { "serial", "uint" }, { "time", "uint" }, { "surface", "object", "wl_surface" }, { "fingers", "uint" } } },
+            { "update", { { "time", "uint" }, { "dx", "fixed" }, { "dy", "fixed" } } },
+            { "end", { { "serial", "uint" }, { "time", "uint" }, { "cancelled", "int" } } },
+        },
+    },
+    ["zwp_pointer_gestures_v1"] = {
+        requests = {
+            { "get_swipe_gesture", { { "id", "new_id", "zwp_pointer_gesture_swipe_v1" }, { "pointer", "object", "wl_pointer" } } },
+            { "get_pinch_gesture", { { "id", "new_id", "zwp_pointer_gesture_pinch_v1" }, { "pointer", "object", "wl_pointer" } } },
+            { "release", {} },
+            { "get_hold_gesture", { { "id", "new_id", "zwp_pointer_gesture_hold_v1" }, { "pointer", "object", "wl_pointer" } } },
+        },
+        events = {},
+    },
+    ["zwp_primary_selection_device_manager_v1"] = {
+        requests = {
+            { "create_source", { { "id", "new_id", "zwp_primary_selection_source_v1" } } },
+            { "get_device", { { "id", "new_id", "zwp_primary_selection_device_v1" }, { "seat", "object", "wl_seat" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwp_primary_selection_device_v1"] = {
+        requests = {
+            { "set_selection", { { "source", "object", "zwp_primary_selection_source_v1" }, { "serial", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "data_offer", { { "offer", "new_id", "zwp_primary_selection_offer_v1" } } },
+            { "selection", { { "id", "object", "zwp_primary_selection_offer_v1" } } },
+        },
+    },
+    ["zwp_primary_selection_offer_v1"] = {
+        requests = {
+            { "receive", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "offer", { { "mime_type", "string" } } },
+        },
+    },
+    ["zwp_primary_selection_source_v1"] = {
+        requests = {
+            { "offer", { { "mime_type", "string" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "send", { { "mime_type", "string" }, { "fd", "fd" } } },
+            { "cancelled", {} },
+        },
+    },
+    ["zwp_relative_pointer_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_relative_pointer", { { "id", "new_id", "zwp_relative_pointer_v1" }, { "pointer", "object", "wl_pointer" } } },
+        },
+        events = {},
+    },
+    ["zwp_relative_pointer_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "relative_motion", { { "utime_hi", "uint" }, { "utime_lo", "uint" }, { "dx", "fixed" }, { "dy", "fixed" }, { "dx_unaccel", "fixed" }, { "dy_unaccel", "fixed" } } },
+        },
+    },
+    ["zwp_tablet_manager_v2"] = {
+        requests = {
+            { "get_tablet_seat", { { "tablet_seat", "new_id", "zwp_tablet_seat_v2" }, { "seat", "object", "wl_seat" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwp_tablet_pad_dial_v2"] = {
+        requests = {
+            { "set_feedback", { { "description", "string" }, { "serial", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "delta", { { "value120", "int" } } },
+            { "frame", { { "time", "uint" } } },
+        },
+    },
+    ["zwp_tablet_pad_group_v2"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "buttons", { { "buttons", "array" } } },
+            { "ring", { { "ring", "new_id", "zwp_tablet_pad_ring_v2" } } },
+            { "strip", { { "strip", "new_id", "zwp_tablet_pad_strip_v2" } } },
+            { "modes", { { "modes", "uint" } } },
+            { "done", {} },
+            { "mode_switch", { { "time", "uint" }, { "serial", "uint" }, { "mode", "uint" } } },
+            { "dial", { { "dial", "new_id", "zwp_tablet_pad_dial_v2" } } },
+        },
+    },
+    ["zwp_tablet_pad_ring_v2"] = {
+        requests = {
+            { "set_feedback", { { "description", "string" }, { "serial", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "source", { { "source", "uint" } } },
+            { "angle", { { "degrees", "fixed" } } },
+            { "stop", {} },
+            { "frame", { { "time", "uint" } } },
+        },
+    },
+    ["zwp_tablet_pad_strip_v2"] = {
+        requests = {
+            { "set_feedback", { { "description", "string" }, { "serial", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "source", { { "source", "uint" } } },
+            { "position", { { "position", "uint" } } },
+            { "stop", {} },
+            { "frame", { { "time", "uint" } } },
+        },
+    },
+    ["zwp_tablet_pad_v2"] = {
+        requests = {
+            { "set_feedback", { { "button", "uint" }, { "description", "string" }, { "serial", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "group", { { "pad_group", "new_id", "zwp_tablet_pad_group_v2" } } },
+            { "path", { { "path", "string" } } },
+            { "buttons", { { "buttons", "uint" } } },
+            { "done", {} },
+            { "button", { { "time", "uint" }, { "button", "uint" }, { "state", "uint" } } },
+            { "enter", { { "serial", "uint" }, { "tablet", "object", "zwp_tablet_v2" }, { "surface", "object", "wl_surface" } } },
+            { "leave", { { "serial", "uint" }, { "surface", "object", "wl_surface" } } },
+            { "removed", {} },
+        },
+    },
+    ["zwp_tablet_seat_v2"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "tablet_added", { { "id", "new_id", "zwp_tablet_v2" } } },
+            { "tool_added", { { "id", "new_id", "zwp_tablet_tool_v2" } } },
+            { "pad_added", { { "id", "new_id", "zwp_tablet_pad_v2" } } },
+        },
+    },
+    ["zwp_tablet_tool_v2"] = {
+        requests = {
+            { "set_cursor", { { "serial", "uint" }, { "surface", "object", "wl_surface" }, { "hotspot_x", "int" }, { "hotspot_y", "int" } } },
+            { "destroy", {} },
+        },
+        events = {
+            { "type", { { "tool_type", "uint" } } },
+            { "hardware_serial", { { "hardware_serial_hi", "uint" }, { "hardware_serial_lo", "uint" } } },
+            { "hardware_id_wacom", { { "hardware_id_hi", "uint" }, { "hardware_id_lo", "uint" } } },
+            { "capability", { { "capability", "uint" } } },
+            { "done", {} },
+            { "removed", {} },
+            { "proximity_in", { { "serial", "uint" }, { "tablet", "object", "zwp_tablet_v2" }, { "surface", "object", "wl_surface" } } },
+            { "proximity_out", {} },
+            { "down", { { "serial", "uint" } } },
+            { "up", {} },
+            { "motion", { { "x", "fixed" }, { "y", "fixed" } } },
+            { "pressure", { { "pressure", "uint" } } },
+            { "distance", { { "distance", "uint" } } },
+            { "tilt", { { "tilt_x", "fixed" }, { "tilt_y", "fixed" } } },
+            { "rotation", { { "degrees", "fixed" } } },
+            { "slider", { { "position", "int" } } },
+            { "wheel", { { "degrees", "fixed" }, { "clicks", "int" } } },
+            { "button", { { "serial", "uint" }, { "button", "uint" }, { "state", "uint" } } },
+            { "frame", { { "time", "uint" } } },
+        },
+    },
+    ["zwp_tablet_v2"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "name", { { "name", "string" } } },
+            { "id", { { "vid", "uint" }, { "pid", "uint" } } },
+            { "path", { { "path", "string" } } },
+            { "done", {} },
+            { "removed", {} },
+            { "bustype", { { "bustype", "uint" } } },
+        },
+    },
+    ["zwp_text_input_manager_v1"] = {
+        requests = {
+            { "create_text_input", { { "id", "new_id", "zwp_text_input_v1" } } },
+        },
+        events = {},
+    },
+    ["zwp_text_input_manager_v3"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_text_input", { { "id", "new_id", "zwp_text_input_v3" }, { "seat", "object", "wl_seat" } } },
+        },
+        events = {},
+    },
+    ["zwp_text_input_v1"] = {
+        requests = {
+            { "activate", { { "seat", "object", "wl_seat" }, { "surface", "object", "wl_surface" } } },
+            { "deactivate", { { "seat", "object", "wl_seat" } } },
+            { "show_input_panel", {} },
+            { "hide_input_panel", {} },
+            { "reset", {} },
+            { "set_surrounding_text", { { "text", "string" }, { "cursor", "uint" }, { "anchor", "uint" } } },
+            { "set_content_type", { { "hint", "uint" }, { "purpose", "uint" } } },
+            { "set_cursor_rectangle", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "set_preferred_language", { { "language", "string" } } },
+            { "commit_state", { { "serial", "uint" } } },
+            { "invoke_action", { { "button", "uint" }, { "index", "uint" } } },
+        },
+        events = {
+            { "enter", { { "surface", "object", "wl_surface" } } },
+            { "leave", {} },
+            { "modifiers_map", { { "map", "array" } } },
+            { "input_panel_state", { { "state", "uint" } } },
+            { "preedit_string", { { "serial", "uint" }, { "text", "string" }, { "commit", "string" } } },
+            { "preedit_styling", { { "index", "uint" }, { "length", "uint" }, { "style", "uint" } } },
+            { "preedit_cursor", { { "index", "int" } } },
+            { "commit_string", { { "serial", "uint" }, { "text", "string" } } },
+            { "cursor_position", { { "index", "int" }, { "anchor", "int" } } },
+            { "delete_surrounding_text", { { "index", "int" }, { "length", "uint" } } },
+            { "keysym", { { "serial", "uint" }, { "time", "uint" }, { "sym", "uint" }, { "state", "uint" }, { "modifiers", "uint" } } },
+            { "language", { { "serial", "uint" }, { "language", "string" } } },
+            { "text_direction", { { "serial", "uint" }, { "direction", "uint" } } },
+        },
+    },
+    ["zwp_text_input_v3"] = {
+        requests = {
+            { "destroy", {} },
+            { "enable", {} },
+            { "disable", {} },
+            { "set_surrounding_text", { { "text", "string" }, { "cursor", "int" }, { "anchor", "int" } } },
+            { "set_text_change_cause", { { "cause", "uint" } } },
+            { "set_content_type", { { "hint", "uint" }, { "purpose", "uint" } } },
+            { "set_cursor_rectangle", { { "x", "int" }, { "y", "int" }, { "width", "int" }, { "height", "int" } } },
+            { "commit", {} },
+            { "set_available_actions", { { "available_actions", "array" } } },
+            { "show_input_panel", {} },
+            { "hide_input_panel", {} },
+        },
+        events = {
+            { "enter", { { "surface", "object", "wl_surface" } } },
+            { "leave", { { "surface", "object", "wl_surface" } } },
+            { "preedit_string", { { "text", "string" }, { "cursor_begin", "int" }, { "cursor_end", "int" } } },
+            { "commit_string", { { "text", "string" } } },
+            { "delete_surrounding_text", { { "before_length", "uint" }, { "after_length", "uint" } } },
+            { "done", { { "serial", "uint" } } },
+            { "action", { { "action", "uint" }, { "serial", "uint" } } },
+            { "language", { { "language", "string" } } },
+            { "preedit_hint", { { "start", "uint" }, { "end", "uint" }, { "hint", "uint" } } },
+        },
+    },
+    ["zwp_virtual_keyboard_manager_v1"] = {
+        requests = {
+            { "create_virtual_keyboard", { { "seat", "object", "wl_seat" }, { "id", "new_id", "zwp_virtual_keyboard_v1" } } },
+        },
+        events = {},
+    },
+    ["zwp_virtual_keyboard_v1"] = {
+        requests = {
+            { "keymap", { { "format", "uint" }, { "fd", "fd" }, { "size", "uint" } } },
+            { "key", { { "time", "uint" }, { "key", "uint" }, { "state", "uint" } } },
+            { "modifiers", { { "mods_depressed", "uint" }, { "mods_latched", "uint" }, { "mods_locked", "uint" }, { "group", "uint" } } },
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zwp_xwayland_keyboard_grab_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "grab_keyboard", { { "id", "new_id", "zwp_xwayland_keyboard_grab_v1" }, { "surface", "object", "wl_surface" }, { "seat", "object", "wl_seat" } } },
+        },
+        events = {},
+    },
+    ["zwp_xwayland_keyboard_grab_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {},
+    },
+    ["zxdg_decoration_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_toplevel_decoration", { { "id", "new_id", "zxdg_toplevel_decoration_v1" }, { "toplevel", "object", "xdg_toplevel" } } },
+        },
+        events = {},
+    },
+    ["zxdg_exported_v2"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "handle", { { "handle", "string" } } },
+        },
+    },
+    ["zxdg_exporter_v2"] = {
+        requests = {
+            { "destroy", {} },
+            { "export_toplevel", { { "id", "new_id", "zxdg_exported_v2" }, { "surface", "object", "wl_surface" } } },
+        },
+        events = {},
+    },
+    ["zxdg_imported_v2"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_parent_of", { { "surface", "object", "wl_surface" } } },
+        },
+        events = {
+            { "destroyed", {} },
+        },
+    },
+    ["zxdg_importer_v2"] = {
+        requests = {
+            { "destroy", {} },
+            { "import_toplevel", { { "id", "new_id", "zxdg_imported_v2" }, { "handle", "string" } } },
+        },
+        events = {},
+    },
+    ["zxdg_output_manager_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "get_xdg_output", { { "id", "new_id", "zxdg_output_v1" }, { "output", "object", "wl_output" } } },
+        },
+        events = {},
+    },
+    ["zxdg_output_v1"] = {
+        requests = {
+            { "destroy", {} },
+        },
+        events = {
+            { "logical_position", { { "x", "int" }, { "y", "int" } } },
+            { "logical_size", { { "width", "int" }, { "height", "int" } } },
+            { "done", {} },
+            { "name", { { "name", "string" } } },
+            { "description", { { "description", "string" } } },
+        },
+    },
+    ["zxdg_toplevel_decoration_v1"] = {
+        requests = {
+            { "destroy", {} },
+            { "set_mode", { { "mode", "uint" } } },
+            { "unset_mode", {} },
+        },
+        events = {
+            { "configure", { { "mode", "uint" } } },
+        },
+    },
+}
+
+local proto = Proto("wl_proxy", "Wayland (wl-proxy)")
+
+local fields = {
+    endpoint = ProtoField.uint64("wl_proxy.endpoint", "Endpoint"),
+    peer = ProtoField.uint8("wl_proxy.peer", "Peer", base.DEC, { [0] = "client", [1] = "server" }),
+    direction = ProtoField.uint8("wl_proxy.direction", "Direction", base.DEC, { [0] = "received", [1] = "sent" }),
+    fds = ProtoField.uint16("wl_proxy.fds", "File descriptors"),
+    object = ProtoField.uint32("wl_proxy.object", "Object"),
+    opcode = ProtoField.uint16("wl_proxy.opcode", "Opcode"),
+    size = ProtoField.uint16("wl_proxy.size", "Size"),
+    request = ProtoField.bool("wl_proxy.request", "Request"),
+    interface = ProtoField.string("wl_proxy.interface", "Interface"),
+    message = ProtoField.string("wl_proxy.message", "Message"),
+    arg = ProtoField.string("wl_proxy.arg", "Argument"),
+}
+
+proto.fields = {
+    fields.endpoint,
+    fields.peer,
+    fields.direction,
+    fields.fds,
+    fields.object,
+    fields.opcode,
+    fields.size,
+    fields.request,
+    fields.interface,
+    fields.message,
+    fields.arg,
+}
+
+-- The interfaces of the objects of each endpoint.
+local objects = {}
+-- The interfaces of the objects referenced by each frame. These are determined when
+-- the frame is dissected for the first time.
+local frames = {}
+
+function proto.init()
+    objects = {}
+    frames = {}
+end
+
+local function padded(len)
+    return math.floor((len + 3) / 4) * 4
+end
+
+-- Parses the arguments of a message. Returns a list of tables describing the
+-- arguments.
+local function parse_args(tvb, offset, stop, args, resolve)
+    local res = {}
+    for idx, arg in ipairs(args) do
+        local name, ty, interface = arg[1], arg[2], arg[3]
+        local start = offset
+        local text
+        local new_id
+        if ty == "fd" then
+            text = "fd"
+        elseif ty == "string" or ty == "array" or (ty == "new_id" and interface == nil) then
+            if offset + 4 > stop then
+                break
+            end
+            local len = tvb(offset, 4):le_uint()
+            offset = offset + 4
+            if offset + padded(len) > stop then
+                break
+            end
+            if ty == "array" then
+                if len == 0 then
+                    text = "[]"
+                else
+                    text = "[" .. tostring(tvb(offset, len):bytes()) .. "]"
+                end
+            elseif len == 0 then
+                text = "null"
+            elseif len == 1 then
+                text = '""'
+            else
+                text = string.format("%q", tvb(offset, len - 1):string())
+            end
+            offset = offset + padded(len)
+            if ty == "new_id" then
+                if offset + 8 > stop or len < 2 then
+                    break
+                end
+                interface = tvb(start + 4, len - 1):string()
+                local version = tvb(offset, 4):le_uint()
+                new_id = tvb(offset + 4, 4):le_uint()
+                offset = offset + 8
+                text = string.format("%s#%d (version: %d)", interface, new_id, version)
+            end
+        else
+            if offset + 4 > stop then
+                break
+            end
+            local range = tvb(offset, 4)
+            offset = offset + 4
+            if ty == "int" then
+                text = tostring(range:le_int())
+            elseif ty == "uint" then
+                text = tostring(range:le_uint())
+            elseif ty == "fixed" then
+                text = tostring(range:le_int() / 256)
+            else
+                local id = range:le_uint()
+                if ty == "new_id" then
+                    new_id = id
+                end
+                if interface == nil then
+                    interface = resolve(id) or "unknown"
+                end
+                if id == 0 and ty == "object" then
+                    text = "null"
+                else
+                    text = string.format("%s#%d", interface, id)
+                end
+            end
+        end
+        res[idx] = {
+            name = name,
+            text = text,
+            offset = start,
+            len = offset - start,
+            new_id = new_id,
+            interface = interface,
+        }
+    end
+    return res
+end
+
+function proto.dissector(tvb, pinfo, tree)
+    if tvb:len() < 20 then
+        return 0
+    end
+    pinfo.cols.protocol = "wl-proxy"
+    local endpoint = tostring(tvb(0, 8):le_uint64())
+    local peer = tvb(8, 1):uint()
+    local direction = tvb(9, 1):uint()
+    local is_request = (peer == 0) == (direction == 0)
+    local id = tvb(12, 4):le_uint()
+    local opcode = tvb(16, 2):le_uint()
+    local size = tvb(18, 2):le_uint()
+    local stop = math.min(12 + size, tvb:len())
+
+    local endpoint_objects = objects[endpoint]
+    if endpoint_objects == nil then
+        endpoint_objects = { [1] = "wl_display" }
+        objects[endpoint] = endpoint_objects
+    end
+    local frame = frames[pinfo.number]
+    local first = frame == nil
+    if first then
+        frame = { interface = endpoint_objects[id], resolved = {} }
+        frames[pinfo.number] = frame
+    end
+    local function resolve(object)
+        if first then
+            frame.resolved[object] = endpoint_objects[object]
+        end
+        return frame.resolved[object]
+    end
+
+    local interface = frame.interface
+    local description = interfaces[interface or ""]
+    local message
+    if description ~= nil then
+        if is_request then
+            message = description.requests[opcode + 1]
+        else
+            message = description.events[opcode + 1]
+        end
+    end
+    local name = "msg" .. opcode
+    local args = {}
+    if message ~= nil then
+        name = message[1]
+        args = parse_args(tvb, 20, stop, message[2], resolve)
+        if first then
+            for _, arg in ipairs(args) do
+                if arg.new_id ~= nil then
+                    endpoint_objects[arg.new_id] = arg.interface
+                end
+            end
+        end
+    end
+
+    local subtree = tree:add(proto, tvb())
+    subtree:add_le(fields.endpoint, tvb(0, 8))
+    subtree:add(fields.peer, tvb(8, 1))
+    subtree:add(fields.direction, tvb(9, 1))
+    subtree:add_le(fields.fds, tvb(10, 2))
+    subtree:add(fields.request, tvb(9, 1), is_request)
+    subtree:add_le(fields.object, tvb(12, 4))
+    subtree:add_le(fields.opcode, tvb(16, 2))
+    subtree:add_le(fields.size, tvb(18, 2))
+    subtree:add(fields.interface, tvb(12, 4), interface or "unknown")
+    subtree:add(fields.message, tvb(16, 2), name)
+    local texts = {}
+    for idx, arg in ipairs(args) do
+        local text = arg.name .. ": " .. arg.text
+        texts[idx] = text
+        if arg.len > 0 then
+            subtree:add(fields.arg, tvb(arg.offset, arg.len), text)
+        else
+            subtree:add(fields.arg, tvb(10, 2), text)
+        end
+    end
+
+    local source = "server"
+    if peer == 0 then
+        source = "client#" .. endpoint
+    end
+    local arrow = "->"
+    if direction == 1 then
+        arrow = "<="
+    end
+    pinfo.cols.info = string.format(
+        "%s %s %s#%d.%s(%s)",
+        source,
+        arrow,
+        interface or "unknown",
+        id,
+        name,
+        table.concat(texts, ", ")
+    )
+    return tvb:len()
+end
+
+local encaps = wtap_encaps or wtap
+DissectorTable.get("wtap_encap"):add(encaps.USER0, proto)
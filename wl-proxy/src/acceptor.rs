@@ -1,12 +1,15 @@
 //! Wayland connection acceptor.
 
+#[cfg(feature = "protocol-security_context_v1")]
+use crate::security_context::{SecurityContext, SecurityContextListener};
 use {
-    crate::utils::env::{WAYLAND_DISPLAY, XDG_RUNTIME_DIR},
+    crate::utils::env::{LISTEN_FDNAMES, LISTEN_FDS, LISTEN_PID, WAYLAND_DISPLAY, XDG_RUNTIME_DIR},
     error_reporter::Report,
     std::{
-        env::{set_var, var},
+        cell::Cell,
+        env::{remove_var, set_var, var},
         io,
-        os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd},
+        os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd},
         rc::Rc,
     },
     thiserror::Error,
@@ -16,10 +19,18 @@ use {
 #[cfg(test)]
 mod tests;
 
+const SD_LISTEN_FDS_START: c::c_int = 3;
+
 /// A file-system acceptor for wayland connections.
 ///
-/// This represents a socket in the `XDG_RUNTIME_DIR` directory. Its name follows the
-/// usual `wayland-N` scheme.
+/// This usually represents a socket in the `XDG_RUNTIME_DIR` directory whose name
+/// follows the usual `wayland-N` scheme. Acceptors can also be created at explicit
+/// paths, in the abstract socket namespace, or from sockets passed via systemd socket
+/// activation.
+///
+/// An acceptor created with [`Acceptor::with_security_context`] additionally carries
+/// the [`SecurityContext`] of a [`SecurityContextListener`]. Clients that connect to
+/// such an acceptor have this security context attached.
 ///
 /// # Example
 ///
@@ -36,9 +47,17 @@ mod tests;
 /// # }
 /// ```
 pub struct Acceptor {
-    pub(crate) id: u64,
+    pub(crate) id: Cell<u64>,
     pub(crate) socket: OwnedFd,
     display: String,
+    cleanup: Option<Cleanup>,
+    #[cfg(feature = "protocol-security_context_v1")]
+    pub(crate) security_context: Option<Rc<SecurityContextListener>>,
+}
+
+struct Cleanup {
+    path: String,
+    lock_path: String,
     _lock_fd: OwnedFd,
 }
 
@@ -53,8 +72,8 @@ enum AcceptorErrorType {
     XrdNotSet,
     #[error("could not create a socket")]
     CreateSocket(#[source] io::Error),
-    #[error("{} ({:?}) is too long to form a unix socket address", XDG_RUNTIME_DIR, .0)]
-    XrdTooLong(String),
+    #[error("{:?} is too long to form a unix socket address", .0)]
+    PathTooLong(String),
     #[error("could not open the lock file")]
     OpenLockFile(#[source] io::Error),
     #[error("could not lock the lock file")]
@@ -63,12 +82,20 @@ enum AcceptorErrorType {
     SocketStat(#[source] io::Error),
     #[error("could not bind the socket to an address")]
     BindFailed(#[source] io::Error),
-    #[error("all wayland addresses in the range 0..1000 are already in use")]
-    AddressesInUse,
+    #[error("all wayland addresses in the range {0}-1..={0}-{1} are already in use")]
+    AddressesInUse(String, u32),
     #[error("could not start listening for incoming connections")]
     ListenFailed(#[source] io::Error),
     #[error("could not accept new connection")]
     Accept(#[source] io::Error),
+    #[error("{} is not a number", LISTEN_FDS)]
+    ListenFdsNotNumber,
+    #[error("{LISTEN_FDS} contains an invalid number of file descriptors: {0}")]
+    ListenFdsOutOfRange(c::c_int),
+    #[error("could not modify the flags of socket {0}")]
+    SetFlags(c::c_int, #[source] io::Error),
+    #[error("could not retrieve the address of socket {0}")]
+    GetSockName(c::c_int, #[source] io::Error),
 }
 
 impl Acceptor {
@@ -76,13 +103,14 @@ impl Acceptor {
     ///
     /// This will try to allocate the socket `wayland-N` in the `XDG_RUNTIME_DIR`
     /// directory. The function starts with `N = 1` and then increments `N` until it finds
-    /// an unused socket. The maximum value of `N` is determined by the `max_tries`
-    /// parameter.
+    /// an unused socket. The maximum value of `N` is `max_tries - 1`.
     ///
     /// If `non_blocking` is true, the created socket will be non-blocking, which means
     /// that [`Acceptor::accept`] can return `Ok(None)`. In this case you should use a
     /// mechanism such as epoll to wait for new connections on the socket.
     ///
+    /// The socket and its lock file are removed when the acceptor is dropped.
+    ///
     /// # Example
     ///
     /// ```
@@ -98,44 +126,252 @@ impl Acceptor {
     /// # }
     /// ```
     pub fn new(max_tries: u32, non_blocking: bool) -> Result<Rc<Self>, AcceptorError> {
-        Self::create(0, max_tries, non_blocking)
+        Self::with_prefix("wayland", max_tries, non_blocking)
     }
 
-    pub(crate) fn create(
-        id: u64,
+    /// Creates a new acceptor with a custom name prefix.
+    ///
+    /// This is the same as [`Acceptor::new`] except that the sockets are called
+    /// `{prefix}-N` instead of `wayland-N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_proxy::acceptor::Acceptor;
+    /// # fn f() {
+    /// let acceptor = Acceptor::with_prefix("wl-proxy", 1000, false).unwrap();
+    /// assert!(acceptor.display().starts_with("wl-proxy-"));
+    /// # }
+    /// ```
+    pub fn with_prefix(
+        prefix: &str,
         max_tries: u32,
         non_blocking: bool,
     ) -> Result<Rc<Self>, AcceptorError> {
+        Self::create_prefixed(prefix, max_tries, non_blocking).map(Rc::new)
+    }
+
+    /// Creates a new acceptor for sandboxed clients.
+    ///
+    /// This is the same as [`Acceptor::new`] except that the acceptor is associated with
+    /// the security context listener. The listener is kept alive as long as the acceptor
+    /// exists.
+    ///
+    /// When the acceptor is added to a [`State`](crate::state::State), the
+    /// [`SecurityContext`] of the listener is available via
+    /// [`Client::security_context`](crate::client::Client::security_context) for all
+    /// clients accepted by this acceptor, for example, in
+    /// [`StateHandler::new_client`](crate::state::StateHandler::new_client).
+    ///
+    /// The compositor only treats the clients as sandboxed if the state is connected to
+    /// the compositor via the same listener. See
+    /// [`StateBuilder::with_security_context`](crate::state::StateBuilder::with_security_context)
+    /// and [`State::add_acceptor`](crate::state::State::add_acceptor).
+    #[cfg(feature = "protocol-security_context_v1")]
+    pub fn with_security_context(
+        listener: &Rc<SecurityContextListener>,
+        max_tries: u32,
+        non_blocking: bool,
+    ) -> Result<Rc<Self>, AcceptorError> {
+        let mut acceptor = Self::create_prefixed("wayland", max_tries, non_blocking)?;
+        acceptor.security_context = Some(listener.clone());
+        Ok(Rc::new(acceptor))
+    }
+
+    fn create_prefixed(
+        prefix: &str,
+        max_tries: u32,
+        non_blocking: bool,
+    ) -> Result<Self, AcceptorError> {
         let xrd = match var(XDG_RUNTIME_DIR) {
             Ok(d) => d,
             _ => return Err(AcceptorErrorType::XrdNotSet.into()),
         };
-        let mut ty = c::SOCK_STREAM | c::SOCK_CLOEXEC;
-        if non_blocking {
-            ty |= c::SOCK_NONBLOCK;
-        }
-        let socket = uapi::socket(c::AF_UNIX, ty, 0)
-            .map_err(|e| AcceptorErrorType::CreateSocket(e.into()))?;
-        let socket = socket.into();
+        let socket = create_socket(non_blocking)?;
         for i in 1..max_tries {
-            let lock_fd = match bind_socket(&socket, &xrd, i) {
-                Ok(f) => f,
+            let display = format!("{prefix}-{i}");
+            let path = format!("{xrd}/{display}");
+            let cleanup = match bind_socket(&socket, path) {
+                Ok(c) => c,
                 Err(e) => {
-                    log::debug!("Cannot use the wayland-{} socket: {}", i, Report::new(e));
+                    log::debug!("Cannot use the {} socket: {}", display, Report::new(e));
                     continue;
                 }
             };
-            if let Err(e) = uapi::listen(socket.as_raw_fd(), 1024) {
-                return Err(AcceptorErrorType::ListenFailed(e.into()).into());
-            }
-            return Ok(Rc::new(Acceptor {
-                id,
+            return Self::listen(socket, display, Some(cleanup));
+        }
+        let last = max_tries.saturating_sub(1);
+        Err(AcceptorErrorType::AddressesInUse(prefix.to_string(), last).into())
+    }
+
+    /// Creates a new acceptor at an explicit file-system path.
+    ///
+    /// If the path is relative, it is interpreted relative to the `XDG_RUNTIME_DIR`
+    /// directory, the same way that libwayland interprets `WAYLAND_DISPLAY`. An existing
+    /// socket at the path is replaced unless its lock file `{path}.lock` is held by
+    /// another process.
+    ///
+    /// The display name of the acceptor is the path as given.
+    ///
+    /// The socket and its lock file are removed when the acceptor is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_proxy::acceptor::Acceptor;
+    /// # fn f() {
+    /// let acceptor = Acceptor::with_path("/run/user/1000/my-proxy", false).unwrap();
+    /// assert_eq!(acceptor.display(), "/run/user/1000/my-proxy");
+    /// # }
+    /// ```
+    pub fn with_path(path: &str, non_blocking: bool) -> Result<Rc<Self>, AcceptorError> {
+        let full_path = match path.starts_with("/") {
+            true => path.to_string(),
+            false => match var(XDG_RUNTIME_DIR) {
+                Ok(xrd) => format!("{xrd}/{path}"),
+                _ => return Err(AcceptorErrorType::XrdNotSet.into()),
+            },
+        };
+        let socket = create_socket(non_blocking)?;
+        let cleanup = bind_socket(&socket, full_path)?;
+        Self::listen(socket, path.to_string(), Some(cleanup)).map(Rc::new)
+    }
+
+    /// Creates a new acceptor in the Linux abstract socket namespace.
+    ///
+    /// Abstract sockets do not exist in the file system and are removed automatically
+    /// when the acceptor is dropped. The display name of the acceptor is the name
+    /// prefixed with `@`.
+    ///
+    /// Note that libwayland cannot connect to abstract sockets via `WAYLAND_DISPLAY`.
+    /// Such connections have to be passed to clients via `WAYLAND_SOCKET` or
+    /// established by other means.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_proxy::acceptor::Acceptor;
+    /// # fn f() {
+    /// let acceptor = Acceptor::with_abstract_name("my-proxy", false).unwrap();
+    /// assert_eq!(acceptor.display(), "@my-proxy");
+    /// # }
+    /// ```
+    pub fn with_abstract_name(name: &str, non_blocking: bool) -> Result<Rc<Self>, AcceptorError> {
+        let (addr, len) = abstract_addr(name)?;
+        let socket = create_socket(non_blocking)?;
+        // SAFETY: addr is a valid sockaddr_un and len is at most its size.
+        let res = unsafe {
+            c::bind(
+                socket.as_raw_fd(),
+                &addr as *const c::sockaddr_un as *const c::sockaddr,
+                len,
+            )
+        };
+        if res == -1 {
+            return Err(AcceptorErrorType::BindFailed(io::Error::last_os_error()).into());
+        }
+        Self::listen(socket, format!("@{name}"), None).map(Rc::new)
+    }
+
+    /// Adopts the listening sockets passed via systemd socket activation.
+    ///
+    /// If the `LISTEN_PID` environment variable is set to the PID of this process, this
+    /// function takes ownership of the `LISTEN_FDS` file descriptors starting at 3 and
+    /// returns one acceptor per file descriptor. Otherwise, it returns an empty vector.
+    ///
+    /// The display name of each acceptor is the path of its socket or, for abstract
+    /// sockets, the name prefixed with `@`. The socket is not removed when the acceptor
+    /// is dropped.
+    ///
+    /// The `LISTEN_PID`, `LISTEN_FDS`, and `LISTEN_FDNAMES` environment variables are
+    /// removed.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it calls [`remove_var`] and because it takes
+    /// ownership of the passed file descriptors. It must be called at most once and
+    /// the file descriptors must not be used by anything else.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_proxy::acceptor::Acceptor;
+    /// # fn f() {
+    /// let acceptors = unsafe { Acceptor::from_listen_fds(false).unwrap() };
+    /// for acceptor in acceptors {
+    ///     eprintln!("{}", acceptor.display());
+    /// }
+    /// # }
+    /// ```
+    pub unsafe fn from_listen_fds(non_blocking: bool) -> Result<Vec<Rc<Self>>, AcceptorError> {
+        let pid = var(LISTEN_PID).ok();
+        let fds = var(LISTEN_FDS).ok();
+        // SAFETY: The requirement is forwarded to the caller.
+        unsafe {
+            remove_var(LISTEN_PID);
+            remove_var(LISTEN_FDS);
+            remove_var(LISTEN_FDNAMES);
+        }
+        let (Some(pid), Some(fds)) = (pid, fds) else {
+            return Ok(vec![]);
+        };
+        if pid.parse::<c::pid_t>().ok() != Some(uapi::getpid()) {
+            return Ok(vec![]);
+        }
+        let fds: c::c_int = fds
+            .parse()
+            .map_err(|_| AcceptorErrorType::ListenFdsNotNumber)?;
+        let end = match SD_LISTEN_FDS_START.checked_add(fds) {
+            Some(end) if fds >= 0 => end,
+            _ => return Err(AcceptorErrorType::ListenFdsOutOfRange(fds).into()),
+        };
+        let fds: Vec<_> = (SD_LISTEN_FDS_START..end)
+            // SAFETY: The requirement is forwarded to the caller.
+            .map(|fd| unsafe { OwnedFd::from_raw_fd(fd) })
+            .collect();
+        let mut acceptors = vec![];
+        for socket in fds {
+            let fd = socket.as_raw_fd();
+            let set_flags = || {
+                let flags = uapi::fcntl_getfd(fd)?;
+                uapi::fcntl_setfd(fd, flags | c::FD_CLOEXEC)?;
+                let mut flags = uapi::fcntl_getfl(fd)?;
+                match non_blocking {
+                    true => flags |= c::O_NONBLOCK,
+                    false => flags &= !c::O_NONBLOCK,
+                }
+                uapi::fcntl_setfl(fd, flags)
+            };
+            set_flags().map_err(|e| AcceptorErrorType::SetFlags(fd, e.into()))?;
+            let display = socket_display(&socket)?;
+            acceptors.push(Rc::new(Acceptor {
+                id: Cell::new(0),
                 socket,
-                display: format!("wayland-{i}"),
-                _lock_fd: lock_fd,
+                display,
+                cleanup: None,
+                #[cfg(feature = "protocol-security_context_v1")]
+                security_context: None,
             }));
         }
-        Err(AcceptorErrorType::AddressesInUse.into())
+        Ok(acceptors)
+    }
+
+    fn listen(
+        socket: OwnedFd,
+        display: String,
+        cleanup: Option<Cleanup>,
+    ) -> Result<Self, AcceptorError> {
+        if let Err(e) = uapi::listen(socket.as_raw_fd(), 1024) {
+            return Err(AcceptorErrorType::ListenFailed(e.into()).into());
+        }
+        Ok(Acceptor {
+            id: Cell::new(0),
+            socket,
+            display,
+            cleanup,
+            #[cfg(feature = "protocol-security_context_v1")]
+            security_context: None,
+        })
     }
 
     /// Returns the display name of this acceptor, for example, `wayland-1`.
@@ -153,6 +389,15 @@ impl Acceptor {
         &self.display
     }
 
+    /// Returns the security context of this acceptor.
+    ///
+    /// This is `Some` if and only if the acceptor was created with
+    /// [`Acceptor::with_security_context`].
+    #[cfg(feature = "protocol-security_context_v1")]
+    pub fn security_context(&self) -> Option<&SecurityContext> {
+        self.security_context.as_ref().map(|l| l.context())
+    }
+
     /// Returns the socket file descriptor of this acceptor.
     ///
     /// This can be used to asynchronously wait for new connections. The returned file
@@ -246,14 +491,31 @@ impl AsFd for Acceptor {
     }
 }
 
-fn bind_socket(socket: &OwnedFd, xrd: &str, id: u32) -> Result<OwnedFd, AcceptorErrorType> {
+impl Drop for Acceptor {
+    fn drop(&mut self) {
+        if let Some(cleanup) = &self.cleanup {
+            let _ = uapi::unlink(&*cleanup.path);
+            let _ = uapi::unlink(&*cleanup.lock_path);
+        }
+    }
+}
+
+fn create_socket(non_blocking: bool) -> Result<OwnedFd, AcceptorErrorType> {
+    let mut ty = c::SOCK_STREAM | c::SOCK_CLOEXEC;
+    if non_blocking {
+        ty |= c::SOCK_NONBLOCK;
+    }
+    let socket =
+        uapi::socket(c::AF_UNIX, ty, 0).map_err(|e| AcceptorErrorType::CreateSocket(e.into()))?;
+    Ok(socket.into())
+}
+
+fn bind_socket(socket: &OwnedFd, path: String) -> Result<Cleanup, AcceptorErrorType> {
     let mut addr: c::sockaddr_un = uapi::pod_zeroed();
     addr.sun_family = c::AF_UNIX as _;
-    let name = format!("wayland-{}", id);
-    let path = format!("{}/{}", xrd, name);
     let lock_path = format!("{}.lock", path);
     if path.len() + 1 > addr.sun_path.len() {
-        return Err(AcceptorErrorType::XrdTooLong(xrd.to_string()));
+        return Err(AcceptorErrorType::PathTooLong(path));
     }
     let lock_fd = match uapi::open(&*lock_path, c::O_CREAT | c::O_CLOEXEC | c::O_RDWR, 0o644) {
         Ok(l) => l,
@@ -275,5 +537,40 @@ fn bind_socket(socket: &OwnedFd, xrd: &str, id: u32) -> Result<OwnedFd, Acceptor
     if let Err(e) = uapi::bind(socket.as_raw_fd(), &addr) {
         return Err(AcceptorErrorType::BindFailed(e.into()));
     }
-    Ok(lock_fd.into())
+    Ok(Cleanup {
+        path,
+        lock_path,
+        _lock_fd: lock_fd.into(),
+    })
+}
+
+fn socket_display(socket: &OwnedFd) -> Result<String, AcceptorErrorType> {
+    let fd = socket.as_raw_fd();
+    let mut addr: c::sockaddr_un = uapi::pod_zeroed();
+    let len = uapi::getsockname(fd, &mut addr)
+        .map_err(|e| AcceptorErrorType::GetSockName(fd, e.into()))?;
+    let sun_path = uapi::as_bytes(&addr.sun_path[..]);
+    let len = (len as usize)
+        .saturating_sub(size_of_val(&addr.sun_family))
+        .min(sun_path.len());
+    let display = match sun_path[..len] {
+        [0, ref name @ ..] => format!("@{}", String::from_utf8_lossy(name)),
+        ref path => {
+            let path = path.split(|&b| b == 0).next().unwrap_or_default();
+            String::from_utf8_lossy(path).into_owned()
+        }
+    };
+    Ok(display)
+}
+
+fn abstract_addr(name: &str) -> Result<(c::sockaddr_un, c::socklen_t), AcceptorErrorType> {
+    let mut addr: c::sockaddr_un = uapi::pod_zeroed();
+    addr.sun_family = c::AF_UNIX as _;
+    if name.len() + 1 > addr.sun_path.len() {
+        return Err(AcceptorErrorType::PathTooLong(format!("@{name}")));
+    }
+    let sun_path = uapi::as_bytes_mut(&mut addr.sun_path[..]);
+    sun_path[1..name.len() + 1].copy_from_slice(name.as_bytes());
+    let len = size_of_val(&addr.sun_family) + 1 + name.len();
+    Ok((addr, len as c::socklen_t))
 }
@@ -1,6 +1,14 @@
 use {
-    crate::{acceptor::Acceptor, baseline::Baseline, state::State},
-    std::os::fd::AsRawFd,
+    crate::{
+        acceptor::{Acceptor, abstract_addr, socket_display},
+        baseline::Baseline,
+        state::State,
+    },
+    std::{
+        env::{set_var, var},
+        os::fd::AsRawFd,
+        process,
+    },
     uapi::{c, c::pollfd, poll},
 };
 
@@ -33,3 +41,98 @@ fn test() {
     assert!(acceptor.accept().unwrap().is_some());
     assert_eq!(poll(), 0);
 }
+
+#[test]
+fn cleanup() {
+    let acceptor = Acceptor::with_prefix("wl-proxy-cleanup-test", 1000, true).unwrap();
+    assert!(acceptor.display().starts_with("wl-proxy-cleanup-test-"));
+    let path = format!("{}/{}", var("XDG_RUNTIME_DIR").unwrap(), acceptor.display());
+    let lock_path = format!("{path}.lock");
+    assert!(uapi::lstat(&*path).is_ok());
+    assert!(uapi::lstat(&*lock_path).is_ok());
+    assert_eq!(socket_display(&acceptor.socket).unwrap(), path);
+    drop(acceptor);
+    assert!(uapi::lstat(&*path).is_err());
+    assert!(uapi::lstat(&*lock_path).is_err());
+}
+
+#[test]
+fn path() {
+    let path = format!(
+        "{}/wl-proxy-path-test-{}",
+        var("XDG_RUNTIME_DIR").unwrap(),
+        process::id(),
+    );
+    let acceptor = Acceptor::with_path(&path, false).unwrap();
+    assert_eq!(acceptor.display(), path);
+    assert!(Acceptor::with_path(&path, false).is_err());
+
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .build()
+        .unwrap();
+    state.add_acceptor(&acceptor).unwrap();
+    assert!(state.add_acceptor(&acceptor).is_err());
+    assert!(acceptor.accept().unwrap().is_none());
+
+    State::builder(Baseline::ALL_OF_THEM)
+        .with_server_display_name(&path)
+        .build()
+        .unwrap();
+    assert!(acceptor.accept().unwrap().is_some());
+
+    drop(acceptor);
+    state.destroy();
+    drop(state);
+    assert!(uapi::lstat(&*path).is_err());
+}
+
+#[test]
+fn abstract_name() {
+    let name = format!("wl-proxy-abstract-test-{}", process::id());
+    let acceptor = Acceptor::with_abstract_name(&name, true).unwrap();
+    assert_eq!(acceptor.display(), format!("@{name}"));
+    assert_eq!(
+        socket_display(&acceptor.socket).unwrap(),
+        acceptor.display()
+    );
+    assert!(acceptor.accept().unwrap().is_none());
+
+    let (addr, len) = abstract_addr(&name).unwrap();
+    let socket = uapi::socket(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0).unwrap();
+    // SAFETY: addr is a valid sockaddr_un and len is at most its size.
+    let res = unsafe {
+        c::connect(
+            socket.raw(),
+            &addr as *const c::sockaddr_un as *const c::sockaddr,
+            len,
+        )
+    };
+    assert_eq!(res, 0);
+    assert!(acceptor.accept().unwrap().is_some());
+}
+
+#[test]
+fn addresses_in_use() {
+    let prefix = format!("wl-proxy-in-use-test-{}", process::id());
+    let _acceptor = Acceptor::with_prefix(&prefix, 2, true).unwrap();
+    let err = Acceptor::with_prefix(&prefix, 2, true).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        format!("all wayland addresses in the range {prefix}-1..={prefix}-1 are already in use"),
+    );
+}
+
+#[test]
+fn listen_fds_out_of_range() {
+    for fds in ["-1", "2147483647"] {
+        // SAFETY: No other test uses these variables.
+        unsafe {
+            set_var("LISTEN_PID", uapi::getpid().to_string());
+            set_var("LISTEN_FDS", fds);
+        }
+        // SAFETY: The function returns before taking ownership of any file descriptors.
+        let res = unsafe { Acceptor::from_listen_fds(false) };
+        assert!(res.is_err());
+    }
+}
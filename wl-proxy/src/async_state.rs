@@ -0,0 +1,291 @@
+//! Integration with async runtimes.
+//!
+//! This module requires the `tokio` or the `async-io` feature.
+//!
+//! An [`AsyncState`] registers the [poll fd](State::poll_fd) of a [`State`] with the
+//! reactor of an async runtime. This allows a state to be dispatched from within a task
+//! instead of a dedicated thread.
+//!
+//! Since [`State`] is not `Send`, the tasks must run on a single thread, e.g. within a
+//! tokio `LocalSet`.
+
+use {
+    crate::{
+        client::Client,
+        object::Object,
+        state::{State, StateError, StateHandler},
+    },
+    futures_core::Stream,
+    std::{
+        cell::RefCell,
+        collections::VecDeque,
+        io,
+        os::fd::OwnedFd,
+        pin::Pin,
+        rc::{Rc, Weak},
+        task::{Context, Poll, Waker},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A [`State`] whose poll fd is registered with an async runtime.
+pub struct AsyncState {
+    state: Rc<State>,
+    backend: Backend,
+    events: Rc<EventQueue>,
+}
+
+enum Backend {
+    #[cfg(feature = "tokio")]
+    Tokio(tokio::io::unix::AsyncFd<Rc<OwnedFd>>),
+    #[cfg(feature = "async-io")]
+    AsyncIo(async_io::Async<Rc<OwnedFd>>),
+}
+
+/// An error returned by an [`AsyncState`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct AsyncStateError(#[from] AsyncStateErrorKind);
+
+#[derive(Debug, Error)]
+enum AsyncStateErrorKind {
+    #[error("could not register the poll fd with the reactor")]
+    Register(#[source] io::Error),
+    #[error("could not wait for the poll fd to become readable")]
+    Wait(#[source] io::Error),
+    #[error(transparent)]
+    State(StateError),
+}
+
+/// An event emitted by a [`State`].
+///
+/// These events correspond to the functions of [`StateHandler`].
+pub enum StateEvent {
+    /// A new client has connected.
+    ///
+    /// See [`StateHandler::new_client`].
+    NewClient(Rc<Client>),
+    /// The server has sent a wl_display.error event.
+    ///
+    /// See [`StateHandler::display_error`].
+    DisplayError {
+        /// The object that the error was sent on.
+        object: Option<Rc<dyn Object>>,
+        /// The server id of the object.
+        server_id: u32,
+        /// The error code.
+        error: u32,
+        /// The error message.
+        msg: String,
+    },
+}
+
+/// A stream of [`StateEvent`]s.
+///
+/// This stream ends when the state has been destroyed and all events have been
+/// consumed.
+///
+/// Cloning the stream creates a new stream that shares the queue of events. Each event
+/// is yielded by only one of them.
+pub struct StateEvents {
+    state: Rc<State>,
+    events: Rc<EventQueue>,
+    waker: Rc<RefCell<Option<Waker>>>,
+}
+
+#[derive(Default)]
+struct EventQueue {
+    queue: RefCell<VecDeque<StateEvent>>,
+    wakers: RefCell<Vec<Weak<RefCell<Option<Waker>>>>>,
+}
+
+struct QueueingHandler(Rc<EventQueue>);
+
+impl AsyncState {
+    /// Registers the state with the tokio reactor.
+    ///
+    /// This replaces the [`StateHandler`] of the state. Events that would have been
+    /// emitted to the handler are available via [`Self::events`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a tokio runtime with IO
+    /// enabled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::rc::Rc;
+    /// # use wl_proxy::async_state::AsyncState;
+    /// # use wl_proxy::state::State;
+    /// # async fn f(state: &Rc<State>) {
+    /// let state = AsyncState::tokio(state).unwrap();
+    /// loop {
+    ///     state.dispatch().await.unwrap();
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn tokio(state: &Rc<State>) -> Result<Self, AsyncStateError> {
+        use tokio::io::{Interest, unix::AsyncFd};
+        let fd = AsyncFd::with_interest(state.poll_fd().clone(), Interest::READABLE)
+            .map_err(AsyncStateErrorKind::Register)?;
+        Ok(Self::new(state, Backend::Tokio(fd)))
+    }
+
+    /// Registers the state with the async-io reactor.
+    ///
+    /// This replaces the [`StateHandler`] of the state. Events that would have been
+    /// emitted to the handler are available via [`Self::events`].
+    #[cfg(feature = "async-io")]
+    pub fn async_io(state: &Rc<State>) -> Result<Self, AsyncStateError> {
+        let fd =
+            async_io::Async::new(state.poll_fd().clone()).map_err(AsyncStateErrorKind::Register)?;
+        Ok(Self::new(state, Backend::AsyncIo(fd)))
+    }
+
+    fn new(state: &Rc<State>, backend: Backend) -> Self {
+        let events = Rc::new(EventQueue::default());
+        state.set_handler(QueueingHandler(events.clone()));
+        Self {
+            state: state.clone(),
+            backend,
+            events,
+        }
+    }
+
+    /// Returns the underlying state.
+    pub fn state(&self) -> &Rc<State> {
+        &self.state
+    }
+
+    /// Waits for the state to become readable and then dispatches it.
+    ///
+    /// Outgoing messages are flushed before waiting. The return value indicates if any
+    /// work was performed.
+    ///
+    /// This function should be called in a loop. It is cancel-safe.
+    ///
+    /// If this function returns an error, the state has been destroyed.
+    pub async fn dispatch(&self) -> Result<bool, AsyncStateError> {
+        let res = self.dispatch_().await;
+        if self.state.is_destroyed() {
+            self.events.wake();
+        }
+        res
+    }
+
+    async fn dispatch_(&self) -> Result<bool, AsyncStateError> {
+        let state_error = |e| AsyncStateError::from(AsyncStateErrorKind::State(e));
+        self.state.before_poll().map_err(state_error)?;
+        match &self.backend {
+            #[cfg(feature = "tokio")]
+            Backend::Tokio(fd) => {
+                let mut guard = fd.readable().await.map_err(AsyncStateErrorKind::Wait)?;
+                let did_work = self.state.dispatch_available().map_err(state_error)?;
+                if !did_work {
+                    // The reactor uses edge-triggered notifications. Only clear the
+                    // readiness once the epoll fd has been drained.
+                    guard.clear_ready();
+                }
+                Ok(did_work)
+            }
+            #[cfg(feature = "async-io")]
+            Backend::AsyncIo(fd) => {
+                fd.readable().await.map_err(AsyncStateErrorKind::Wait)?;
+                self.state.dispatch_available().map_err(state_error)
+            }
+        }
+    }
+
+    /// Returns a stream of the events emitted by the state.
+    ///
+    /// All streams returned by this function share the same queue. Each event is
+    /// yielded by only one of them. All streams waiting for events are woken when a new
+    /// event becomes available.
+    pub fn events(&self) -> StateEvents {
+        StateEvents::new(&self.state, &self.events)
+    }
+}
+
+impl StateEvents {
+    fn new(state: &Rc<State>, events: &Rc<EventQueue>) -> Self {
+        let waker = Rc::new(RefCell::new(None));
+        events.wakers.borrow_mut().push(Rc::downgrade(&waker));
+        Self {
+            state: state.clone(),
+            events: events.clone(),
+            waker,
+        }
+    }
+}
+
+impl Clone for StateEvents {
+    fn clone(&self) -> Self {
+        Self::new(&self.state, &self.events)
+    }
+}
+
+impl EventQueue {
+    fn push(&self, event: StateEvent) {
+        self.queue.borrow_mut().push_back(event);
+        self.wake();
+    }
+
+    fn wake(&self) {
+        let mut wakers = vec![];
+        self.wakers.borrow_mut().retain(|waker| {
+            let Some(waker) = waker.upgrade() else {
+                return false;
+            };
+            wakers.extend(waker.borrow_mut().take());
+            true
+        });
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl StateHandler for QueueingHandler {
+    fn new_client(&mut self, client: &Rc<Client>) {
+        self.0.push(StateEvent::NewClient(client.clone()));
+    }
+
+    fn display_error(
+        self: Box<Self>,
+        object: Option<&Rc<dyn Object>>,
+        server_id: u32,
+        error: u32,
+        msg: &str,
+    ) {
+        self.0.push(StateEvent::DisplayError {
+            object: object.cloned(),
+            server_id,
+            error,
+            msg: msg.to_string(),
+        });
+    }
+}
+
+impl Stream for StateEvents {
+    type Item = StateEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(event) = self.events.queue.borrow_mut().pop_front() {
+            return Poll::Ready(Some(event));
+        }
+        if self.state.is_destroyed() {
+            return Poll::Ready(None);
+        }
+        let waker = &mut *self.waker.borrow_mut();
+        match waker {
+            Some(w) if w.will_wake(cx.waker()) => {}
+            _ => *waker = Some(cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
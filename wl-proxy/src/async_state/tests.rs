@@ -0,0 +1,170 @@
+use {
+    crate::{
+        async_state::{AsyncState, EventQueue, StateEvent, StateEvents},
+        baseline::Baseline,
+        object::ObjectCoreApi,
+        protocols::wayland::{
+            wl_callback::{WlCallback, WlCallbackHandler},
+            wl_display::{WlDisplay, WlDisplayHandler},
+        },
+        state::State,
+    },
+    futures_core::Stream,
+    std::{
+        cell::Cell,
+        future::{Future, poll_fn},
+        pin::Pin,
+        rc::Rc,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering::Relaxed},
+        },
+        task::{Context, Poll, Wake, Waker},
+    },
+};
+
+struct ServerDisplayHandler;
+
+impl WlDisplayHandler for ServerDisplayHandler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(0);
+        callback.delete_id();
+    }
+}
+
+struct CallbackHandler(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for CallbackHandler {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+fn poll_event(events: &mut StateEvents, cx: &mut Context<'_>) -> Poll<Option<StateEvent>> {
+    Pin::new(events).poll_next(cx)
+}
+
+fn dispatch(state: &AsyncState) -> Pin<Box<dyn Future<Output = ()> + '_>> {
+    Box::pin(async move {
+        state.dispatch().await.unwrap();
+    })
+}
+
+async fn run_until(states: [&AsyncState; 2], mut done: impl FnMut(&mut Context<'_>) -> bool) {
+    let mut futures = states.map(dispatch);
+    poll_fn(|cx| {
+        loop {
+            if done(cx) {
+                return Poll::Ready(());
+            }
+            let mut progress = false;
+            for (future, state) in futures.iter_mut().zip(states) {
+                if future.as_mut().poll(cx).is_ready() {
+                    *future = dispatch(state);
+                    progress = true;
+                }
+            }
+            if !progress {
+                return Poll::Pending;
+            }
+        }
+    })
+    .await
+}
+
+async fn roundtrip(create: fn(&Rc<State>) -> AsyncState) {
+    let server_state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .build()
+        .unwrap();
+    let _destructor = server_state.create_destructor();
+    let acceptor = server_state.create_acceptor(1000).unwrap();
+    let server = create(&server_state);
+    let mut events = server.events();
+
+    let client_state = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_display_name(acceptor.display())
+        .build()
+        .unwrap();
+    let _destructor = client_state.create_destructor();
+    let client = create(&client_state);
+    let done = Rc::new(Cell::new(false));
+    client_state
+        .display()
+        .new_send_sync()
+        .set_handler(CallbackHandler(done.clone()));
+
+    let new_client = Cell::new(false);
+    run_until([&server, &client], |cx| {
+        if let Poll::Ready(event) = poll_event(&mut events, cx) {
+            let Some(StateEvent::NewClient(client)) = event else {
+                panic!();
+            };
+            client.display().set_handler(ServerDisplayHandler);
+            new_client.set(true);
+        }
+        done.get()
+    })
+    .await;
+    assert!(new_client.get());
+
+    server_state.destroy();
+    assert!(server.dispatch().await.is_err());
+    poll_fn(|cx| {
+        assert!(matches!(poll_event(&mut events, cx), Poll::Ready(None)));
+        Poll::Ready(())
+    })
+    .await;
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    rt.block_on(roundtrip(|s| AsyncState::tokio(s).unwrap()));
+}
+
+#[cfg(feature = "async-io")]
+#[test]
+fn async_io() {
+    async_io::block_on(roundtrip(|s| AsyncState::async_io(s).unwrap()));
+}
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Relaxed);
+    }
+}
+
+#[test]
+fn wakers() {
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .build()
+        .unwrap();
+    let _destructor = state.create_destructor();
+    let queue = Rc::new(EventQueue::default());
+    let mut streams = [
+        StateEvents::new(&state, &queue),
+        StateEvents::new(&state, &queue),
+    ];
+    streams[1] = streams[0].clone();
+    let counters = [0, 1].map(|_| Arc::new(CountingWaker(AtomicUsize::new(0))));
+    for (stream, counter) in streams.iter_mut().zip(&counters) {
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+        assert!(poll_event(stream, &mut cx).is_pending());
+    }
+
+    // Every waiting stream is woken.
+    let (client, _fd) = state.connect().unwrap();
+    queue.push(StateEvent::NewClient(client));
+    for counter in &counters {
+        assert_eq!(counter.0.load(Relaxed), 1);
+    }
+}
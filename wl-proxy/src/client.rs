@@ -1,11 +1,21 @@
 //! Wayland clients connected to the proxy.
 
+#[cfg(feature = "protocol-security_context_v1")]
+use crate::security_context::{SecurityContext, SecurityContextListener};
 use {
     crate::{
         endpoint::Endpoint, handler::HandlerHolder, object::Object,
         protocols::wayland::wl_display::WlDisplay, state::State,
     },
-    std::{cell::Cell, rc::Rc},
+    error_reporter::Report,
+    std::{
+        cell::Cell,
+        io,
+        os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd},
+        path::{Path, PathBuf},
+        rc::Rc,
+    },
+    uapi::{Errno, c},
 };
 
 #[cfg(test)]
@@ -24,6 +34,22 @@ pub struct Client {
     pub(crate) display: Rc<WlDisplay>,
     pub(crate) destroyed: Cell<bool>,
     pub(crate) handler: HandlerHolder<dyn ClientHandler>,
+    pub(crate) credentials: Option<ClientCredentials>,
+    #[cfg(feature = "protocol-security_context_v1")]
+    pub(crate) security_context: Option<Rc<SecurityContextListener>>,
+}
+
+/// The credentials of the process that connected to the proxy.
+///
+/// These are determined when the client is created. See [`Client::credentials`] for
+/// details.
+#[derive(Debug)]
+pub struct ClientCredentials {
+    pid: c::pid_t,
+    uid: c::uid_t,
+    gid: c::gid_t,
+    pidfd: Option<OwnedFd>,
+    exe: Option<PathBuf>,
 }
 
 /// A handler for events emitted by a [`Client`].
@@ -68,6 +94,30 @@ impl Client {
         &self.display
     }
 
+    /// Returns the credentials of the process that connected to the proxy.
+    ///
+    /// The pid, uid, and gid are retrieved with `SO_PEERCRED` and describe the process
+    /// at the time it connected to the socket. If the client was created with
+    /// [`State::connect`], these are the credentials of the current process.
+    ///
+    /// This returns `None` if the socket of the client does not support `SO_PEERCRED`.
+    pub fn credentials(&self) -> Option<&ClientCredentials> {
+        self.credentials.as_ref()
+    }
+
+    /// Returns the security context of this client.
+    ///
+    /// If the client was accepted by an acceptor created with
+    /// [`Acceptor::with_security_context`](crate::acceptor::Acceptor::with_security_context),
+    /// this is the security context of that acceptor. Otherwise, if the state was built
+    /// with
+    /// [`StateBuilder::with_security_context`](crate::state::StateBuilder::with_security_context),
+    /// this is the security context of the state. Otherwise this is `None`.
+    #[cfg(feature = "protocol-security_context_v1")]
+    pub fn security_context(&self) -> Option<&SecurityContext> {
+        self.security_context.as_ref().map(|l| l.context())
+    }
+
     /// Disconnects this client.
     ///
     /// The [`ClientHandler::disconnected`] event is not emitted.
@@ -101,3 +151,100 @@ impl Client {
             .set_endpoint_suspended(&self.endpoint, Some(self), suspended);
     }
 }
+
+impl ClientCredentials {
+    /// Retrieves the credentials of the peer of the socket.
+    ///
+    /// This must be called when the client is accepted so that the executable is read
+    /// while the process is still likely to be alive.
+    pub(crate) fn retrieve(socket: BorrowedFd<'_>) -> Option<Self> {
+        match Self::get(socket) {
+            Ok(c) => Some(c),
+            Err(e) if e.raw_os_error() == Some(c::ENOPROTOOPT) => None,
+            Err(e) => {
+                log::warn!(
+                    "Could not retrieve the client credentials: {}",
+                    Report::new(e)
+                );
+                None
+            }
+        }
+    }
+
+    fn get(socket: BorrowedFd<'_>) -> Result<Self, io::Error> {
+        let mut cred: c::ucred = uapi::pod_zeroed();
+        uapi::getsockopt(socket.as_raw_fd(), c::SOL_SOCKET, c::SO_PEERCRED, &mut cred)?;
+        let mut pidfd: c::c_int = -1;
+        let pidfd = match uapi::getsockopt(
+            socket.as_raw_fd(),
+            c::SOL_SOCKET,
+            c::SO_PEERPIDFD,
+            &mut pidfd,
+        ) {
+            // SAFETY: SO_PEERPIDFD returns a new file descriptor.
+            Ok(_) => Some(unsafe { OwnedFd::from_raw_fd(pidfd) }),
+            Err(Errno(c::ENOPROTOOPT | c::ENODATA | c::EINVAL)) => None,
+            Err(e) => return Err(e.into()),
+        };
+        let mut exe = std::fs::read_link(format!("/proc/{}/exe", cred.pid)).ok();
+        if exe.is_some() {
+            // The pid might have been reused between the connect and the readlink
+            // calls. The pidfd becomes readable once the process has terminated.
+            let exited = match &pidfd {
+                Some(pidfd) => {
+                    let mut pfd = [c::pollfd {
+                        fd: pidfd.as_raw_fd(),
+                        events: c::POLLIN,
+                        revents: 0,
+                    }];
+                    !matches!(uapi::poll(&mut pfd, 0), Ok(0))
+                }
+                None => true,
+            };
+            if exited {
+                exe = None;
+            }
+        }
+        Ok(Self {
+            pid: cred.pid,
+            uid: cred.uid,
+            gid: cred.gid,
+            pidfd,
+            exe,
+        })
+    }
+
+    /// Returns the pid of the process.
+    pub fn pid(&self) -> c::pid_t {
+        self.pid
+    }
+
+    /// Returns the effective uid of the process.
+    pub fn uid(&self) -> c::uid_t {
+        self.uid
+    }
+
+    /// Returns the effective gid of the process.
+    pub fn gid(&self) -> c::gid_t {
+        self.gid
+    }
+
+    /// Returns a pidfd referring to the process.
+    ///
+    /// This is retrieved with `SO_PEERPIDFD` and is `None` if the kernel does not
+    /// support this option. Unlike the pid, the pidfd cannot be reused for a different
+    /// process.
+    pub fn pidfd(&self) -> Option<BorrowedFd<'_>> {
+        self.pidfd.as_ref().map(|fd| fd.as_fd())
+    }
+
+    /// Returns the path of the executable of the process.
+    ///
+    /// This is read from `/proc/<pid>/exe` when the client is created. This is `None` if
+    /// the link cannot be read, for example, because the process is in a different pid
+    /// namespace or has already exited, or if the pidfd is not available and the path
+    /// can therefore not be attributed to the process reliably.
+    pub fn exe(&self) -> Option<&Path> {
+        self.exe.as_deref()
+    }
+}
@@ -102,3 +102,15 @@ fn objects() {
     tp.client.proxy_client.objects(&mut objects);
     assert_eq!(objects.len(), 0);
 }
+
+#[test]
+fn credentials() {
+    let tp = test_proxy();
+    let credentials = tp.client.proxy_client.credentials().unwrap();
+    assert_eq!(credentials.pid(), uapi::getpid());
+    assert_eq!(credentials.uid(), uapi::geteuid());
+    assert_eq!(credentials.gid(), uapi::getegid());
+    if credentials.pidfd().is_some() {
+        assert_eq!(credentials.exe(), Some(&*std::env::current_exe().unwrap()));
+    }
+}
@@ -0,0 +1,169 @@
+//! Protocols loaded from XML at runtime.
+//!
+//! This module requires the `dynamic` feature.
+//!
+//! A [`DynamicProtocol`] is parsed from a protocol XML file with the same parser that is
+//! used to generate the [`protocols`](crate::protocols) module. Its interfaces can be
+//! registered with
+//! [`StateBuilder::with_dynamic_protocol`](crate::state::StateBuilder::with_dynamic_protocol).
+//! Afterwards, globals with these interfaces are forwarded and objects with these
+//! interfaces are represented as
+//! [`DynamicObject`]s whose messages are decoded, logged, and forwarded according to
+//! the XML. Globals and objects with these interfaces are limited to the version of
+//! the interface in the XML.
+//!
+//! Dynamic objects are [`UnknownObject`]s whose interface has been described from XML.
+//! See the [`unknown`](crate::unknown) module for details.
+//!
+//! Interfaces that are known to this crate are ignored.
+//!
+//! # Example
+//!
+//! ```
+//! # use wl_proxy::{baseline::Baseline, dynamic::DynamicProtocol, state::State};
+//! let xml = r#"
+//!     <protocol name="example_v1">
+//!       <interface name="wp_example_manager_v1" version="1">
+//!         <request name="destroy" type="destructor"/>
+//!         <request name="get_example">
+//!           <arg name="id" type="new_id" interface="wp_example_v1"/>
+//!           <arg name="surface" type="object" interface="wl_surface"/>
+//!         </request>
+//!       </interface>
+//!       <interface name="wp_example_v1" version="1">
+//!         <event name="fd">
+//!           <arg name="fd" type="fd"/>
+//!         </event>
+//!       </interface>
+//!     </protocol>
+//! "#;
+//! let protocols = DynamicProtocol::parse(xml.as_bytes()).unwrap();
+//! let mut builder = State::builder(Baseline::ALL_OF_THEM).without_server();
+//! for protocol in &protocols {
+//!     builder = builder.with_dynamic_protocol(protocol);
+//! }
+//! let state = builder.build().unwrap();
+//! let manager = state.create_unknown_object("wp_example_manager_v1", 1);
+//! assert_eq!(manager.description().request_opcode("get_example"), Some(1));
+//! ```
+
+use {
+    crate::unknown::{ArgType, UnknownArg, UnknownInterface, UnknownMessage, UnknownObject},
+    generator::{
+        ast::{self, MessageType},
+        parser::{ParserError, parse},
+    },
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// An object whose interface was loaded at runtime.
+pub type DynamicObject = UnknownObject;
+
+/// An argument of a message of a [`DynamicObject`].
+pub type DynArg<'a> = UnknownArg<'a>;
+
+/// A protocol loaded from XML at runtime.
+#[derive(Clone, Debug)]
+pub struct DynamicProtocol {
+    name: String,
+    interfaces: Vec<UnknownInterface>,
+}
+
+/// An error returned when a protocol cannot be loaded.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct DynamicProtocolError(#[from] DynamicProtocolErrorKind);
+
+#[derive(Debug, Error)]
+enum DynamicProtocolErrorKind {
+    #[error("could not read {}", .0.display())]
+    ReadFile(PathBuf, #[source] io::Error),
+    #[error("could not parse the protocol")]
+    Parse(#[source] ParserError),
+}
+
+impl DynamicProtocol {
+    /// Parses the protocols contained in a protocol XML file.
+    pub fn parse(xml: &[u8]) -> Result<Vec<Self>, DynamicProtocolError> {
+        let protocols = parse(xml).map_err(DynamicProtocolErrorKind::Parse)?;
+        Ok(protocols.iter().map(Self::from_ast).collect())
+    }
+
+    /// Loads the protocols contained in a protocol XML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Vec<Self>, DynamicProtocolError> {
+        let path = path.as_ref();
+        let xml = std::fs::read(path)
+            .map_err(|e| DynamicProtocolErrorKind::ReadFile(path.to_path_buf(), e))?;
+        Self::parse(&xml)
+    }
+
+    /// Returns the name of the protocol.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the interfaces of the protocol.
+    pub fn interfaces(&self) -> &[UnknownInterface] {
+        &self.interfaces
+    }
+
+    fn from_ast(protocol: &ast::Protocol) -> Self {
+        let interfaces = protocol
+            .interfaces
+            .iter()
+            .map(|interface| {
+                let mut res = UnknownInterface::new(&interface.name)
+                    .with_version(interface.version)
+                    .with_described(true);
+                for message in &interface.messages {
+                    let message = convert_message(message);
+                    if message.0 {
+                        res = res.with_request(message.1);
+                    } else {
+                        res = res.with_event(message.1);
+                    }
+                }
+                res
+            })
+            .collect();
+        Self {
+            name: protocol.name.clone(),
+            interfaces,
+        }
+    }
+}
+
+fn convert_message(message: &ast::Message) -> (bool, UnknownMessage) {
+    let mut args = vec![];
+    let mut arg_names = vec![];
+    for arg in &message.args {
+        let ty = match arg.ty {
+            ast::ArgType::NewId => ArgType::NewId {
+                interface: arg.interface.clone(),
+            },
+            ast::ArgType::Int => ArgType::Int,
+            ast::ArgType::Uint => ArgType::Uint,
+            ast::ArgType::Fixed => ArgType::Fixed,
+            ast::ArgType::String => ArgType::String {
+                nullable: arg.allow_null,
+            },
+            ast::ArgType::Object => ArgType::Object {
+                nullable: arg.allow_null,
+            },
+            ast::ArgType::Array => ArgType::Array,
+            ast::ArgType::Fd => ArgType::Fd,
+        };
+        args.push(ty);
+        arg_names.push(arg.name.clone());
+    }
+    let destructor = message.ty == Some(MessageType::Destructor);
+    let res = UnknownMessage::from_args(&message.name, args, arg_names).with_destructor(destructor);
+    (message.is_request, res)
+}
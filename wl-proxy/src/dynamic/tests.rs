@@ -0,0 +1,213 @@
+use {
+    crate::{
+        baseline::Baseline,
+        dynamic::{DynArg, DynamicObject, DynamicProtocol},
+        object::{Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::wayland::{
+            wl_callback::{WlCallback, WlCallbackHandler},
+            wl_display::{WlDisplay, WlDisplayHandler},
+            wl_registry::{WlRegistry, WlRegistryHandler},
+        },
+        state::State,
+        test_framework::{install_logger, proxy::dispatch_blocking},
+        unknown::UnknownObjectHandler,
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+const XML: &str = r#"
+<protocol name="example_v1">
+  <interface name="wp_example_manager_v1" version="2">
+    <request name="destroy" type="destructor"/>
+    <request name="get_example">
+      <arg name="id" type="new_id" interface="wp_example_v1"/>
+      <arg name="name" type="string" allow-null="true"/>
+    </request>
+    <request name="get_untyped">
+      <arg name="id" type="new_id"/>
+    </request>
+  </interface>
+  <interface name="wp_example_v1" version="1">
+    <event name="done" type="destructor">
+      <arg name="value" type="uint"/>
+    </event>
+  </interface>
+</protocol>
+"#;
+
+fn protocol() -> DynamicProtocol {
+    let mut protocols = DynamicProtocol::parse(XML.as_bytes()).unwrap();
+    assert_eq!(protocols.len(), 1);
+    protocols.pop().unwrap()
+}
+
+#[test]
+fn parse() {
+    let protocol = protocol();
+    assert_eq!(protocol.name(), "example_v1");
+    let [manager, example] = protocol.interfaces() else {
+        panic!();
+    };
+    assert_eq!(manager.name(), "wp_example_manager_v1");
+    assert_eq!(manager.version(), 2);
+    assert_eq!(example.version(), 1);
+    assert!(manager.is_described());
+    assert_eq!(manager.request_opcode("destroy"), Some(0));
+    assert_eq!(manager.request_opcode("get_untyped"), Some(2));
+    assert_eq!(manager.event_opcode("done"), None);
+    assert!(manager.requests()[0].is_destructor());
+    assert!(!manager.requests()[1].is_destructor());
+    assert_eq!(example.event_opcode("done"), Some(0));
+    assert!(example.events()[0].is_destructor());
+}
+
+#[test]
+fn parse_error() {
+    let res = DynamicProtocol::parse(br#"<protocol><interface/></protocol>"#);
+    assert!(res.is_err());
+    assert!(DynamicProtocol::load("/nonexistent/protocol.xml").is_err());
+}
+
+#[test]
+fn known_interfaces_are_ignored() {
+    let xml = br#"
+        <protocol name="wayland">
+          <interface name="wl_callback" version="1"/>
+        </protocol>
+    "#;
+    let wayland = DynamicProtocol::parse(xml).unwrap();
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .with_dynamic_protocol(&wayland[0])
+        .with_dynamic_protocol(&protocol())
+        .build()
+        .unwrap();
+    assert!(state.unknown_interface("wl_callback").is_none());
+    assert!(state.unknown_interface("wp_example_v1").is_some());
+}
+
+struct ServerDisplayHandler;
+
+impl WlDisplayHandler for ServerDisplayHandler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(0);
+        callback.delete_id();
+    }
+
+    fn handle_get_registry(&mut self, _slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(ServerRegistryHandler);
+        registry.send_unknown_global(1, "wp_example_manager_v1", 1);
+    }
+}
+
+struct ServerRegistryHandler;
+
+impl WlRegistryHandler for ServerRegistryHandler {
+    fn handle_bind(&mut self, _slf: &Rc<WlRegistry>, _name: u32, id: Rc<dyn Object>) {
+        id.downcast::<DynamicObject>()
+            .set_handler(ServerManagerHandler);
+    }
+}
+
+struct ServerManagerHandler;
+
+impl UnknownObjectHandler for ServerManagerHandler {
+    fn handle_request(&mut self, slf: &Rc<DynamicObject>, opcode: u32, args: &[DynArg<'_>]) {
+        let interface = slf.description();
+        let value = if opcode == interface.request_opcode("get_example").unwrap() {
+            assert!(matches!(args[1], DynArg::String(Some("name"))));
+            1
+        } else if opcode == interface.request_opcode("get_untyped").unwrap() {
+            2
+        } else {
+            return;
+        };
+        let DynArg::NewId(example) = &args[0] else {
+            panic!();
+        };
+        let example = example.downcast::<DynamicObject>();
+        assert_eq!(example.interface_name(), "wp_example_v1");
+        example.send_event(0, &[DynArg::Uint(value)]);
+    }
+}
+
+struct ClientExampleHandler(Rc<Cell<u32>>);
+
+impl UnknownObjectHandler for ClientExampleHandler {
+    fn handle_event(&mut self, _slf: &Rc<DynamicObject>, opcode: u32, args: &[DynArg<'_>]) {
+        assert_eq!(opcode, 0);
+        let DynArg::Uint(value) = args[0] else {
+            panic!();
+        };
+        self.0.set(value);
+    }
+}
+
+#[test]
+fn roundtrip() {
+    install_logger();
+    let server = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .with_logging(true)
+        .with_log_prefix("server")
+        .with_dynamic_protocol(&protocol())
+        .build()
+        .unwrap();
+    server.set_default_forward_to_server(false);
+    let _server_destructor = server.create_destructor();
+    let (server_client, client_fd) = server.connect().unwrap();
+    server_client.display().set_handler(ServerDisplayHandler);
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .with_logging(true)
+        .with_log_prefix("client")
+        .with_dynamic_protocol(&protocol())
+        .build()
+        .unwrap();
+    client.set_default_forward_to_client(false);
+    let _client_destructor = client.create_destructor();
+    let display = client.display();
+    let registry = display.new_send_get_registry();
+    let manager = client.create_unknown_object("wp_example_manager_v1", 1);
+    registry.send_bind(1, manager.clone());
+
+    let value = Rc::new(Cell::new(0));
+    let sync = || {
+        struct H(Rc<Cell<bool>>);
+        impl WlCallbackHandler for H {
+            fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+                self.0.set(true);
+            }
+        }
+        let done = Rc::new(Cell::new(false));
+        display.new_send_sync().set_handler(H(done.clone()));
+        while !done.get() {
+            dispatch_blocking([&server, &client]).unwrap();
+        }
+    };
+
+    let example = client.create_unknown_object("wp_example_v1", 1);
+    example.set_handler(ClientExampleHandler(value.clone()));
+    manager
+        .try_send_request(
+            1,
+            &[DynArg::NewId(example.clone()), DynArg::String(Some("name"))],
+        )
+        .unwrap();
+    sync();
+    assert_eq!(value.get(), 1);
+
+    let example = client.create_unknown_object("wp_example_v1", 1);
+    example.set_handler(ClientExampleHandler(value.clone()));
+    manager
+        .try_send_request(2, &[DynArg::NewId(example.clone())])
+        .unwrap();
+    sync();
+    assert_eq!(value.get(), 2);
+
+    assert!(
+        manager
+            .try_send_request(1, &[DynArg::NewId(example)])
+            .is_err()
+    );
+}
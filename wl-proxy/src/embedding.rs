@@ -0,0 +1,1075 @@
+//! Embedding of toplevels of one client into the surfaces of another client.
+//!
+//! This module requires the `protocol-xdg_shell` and `protocol-wlproxy_sync_v1`
+//! features.
+//!
+//! An [`Embedder`] is used when multiple clients are proxied over the same compositor
+//! connection. One client, the embedding client, displays the toplevels of other
+//! clients, the embedded clients, as subsurfaces of its own surfaces.
+//!
+//! The xdg_toplevels of the embedded clients are not forwarded to the compositor.
+//! Instead, the embedder configures them with the sizes chosen via
+//! [`EmbeddedToplevel::configure`], clamped to the minimum and maximum sizes requested
+//! by the toplevel, and makes their surfaces subsurfaces of a parent surface chosen by
+//! the embedding client. Popups of embedded toplevels are not supported and are
+//! dismissed immediately.
+//!
+//! # Synchronization
+//!
+//! The embedding client binds a wlproxy_sync_v1 global announced by the proxy, for
+//! example, via [`GlobalMapper::add_synthetic_global`](crate::global_mapper::GlobalMapper::add_synthetic_global),
+//! and the proxy passes the object to [`Embedder::track_sync`]. The messages of this
+//! object are used as follows:
+//!
+//! - When an embedded client creates a toplevel, the proxy sends
+//!   `sync_with_client(token, 0)`, where `token` identifies the toplevel. The highest
+//!   bit of `token` is always set.
+//! - The embedding client sends `sync_with_proxy(token, parent)`, where `parent` is the
+//!   protocol ID of a wl_surface of the embedding client. The toplevel becomes a
+//!   subsurface of this surface. If `parent` is 0, the toplevel is detached.
+//! - Once the request has been handled, the proxy sends `sync_with_client(token, parent)`.
+//!   If the toplevel could not be embedded, for example, because the token or the
+//!   surface is unknown, the proxy sends `sync_with_client(token, 0)` instead.
+//!
+//! Since messages are ordered, all requests the embedding client sent before
+//! `sync_with_proxy` have been processed by the time the proxy handles it.
+//!
+//! Requests whose `hi` argument does not have the highest bit set are handled by the
+//! [`ObjectSync`] returned by [`Embedder::object_sync`]. The embedding client can
+//! therefore use the same object with an
+//! [`ObjectSyncClient`](crate::object_sync::ObjectSyncClient). See the
+//! [`object_sync`](crate::object_sync#encoding) module for the encoding.
+//!
+//! Proxies that run in the same process as the embedding client can instead use
+//! [`EmbedderHandler`] and [`EmbeddedToplevel::embed`] directly.
+//!
+//! # Input
+//!
+//! The compositor sends input events for the surfaces of all clients to every
+//! wl_pointer, wl_keyboard, and wl_touch object on the shared connection. The handlers
+//! installed by [`Embedder::track_seat`] forward pointer and touch events only to the
+//! client that owns the surface under the pointer or touch point.
+//!
+//! Keyboard focus is routed to an embedded toplevel if it has been focused via
+//! [`Embedder::set_focus`] or by clicking on it, and if the compositor has given the
+//! keyboard focus to the embedding client. Otherwise it is forwarded as sent by the
+//! compositor. Enter and leave events synthesized when the focus is routed reuse the
+//! serial of the last keyboard event sent by the compositor.
+
+use {
+    crate::{
+        client::Client,
+        fixed::Fixed,
+        handler::HandlerHolder,
+        object::{Object, ObjectCoreApi, ObjectRcUtils, ObjectUtils},
+        object_sync::{EMBEDDED_TOPLEVEL, ObjectSync},
+        protocols::{
+            wayland::{
+                wl_keyboard::{WlKeyboard, WlKeyboardHandler, WlKeyboardKeyState},
+                wl_pointer::{
+                    WlPointer, WlPointerAxis, WlPointerAxisRelativeDirection, WlPointerAxisSource,
+                    WlPointerButtonState, WlPointerHandler,
+                },
+                wl_seat::{WlSeat, WlSeatHandler},
+                wl_subcompositor::WlSubcompositor,
+                wl_subsurface::WlSubsurface,
+                wl_surface::{WlSurface, WlSurfaceHandler},
+                wl_touch::{WlTouch, WlTouchHandler},
+            },
+            wlproxy_sync_v1::wlproxy_sync_v1::{WlproxySyncV1, WlproxySyncV1Handler},
+            xdg_shell::{
+                xdg_popup::{XdgPopup, XdgPopupHandler},
+                xdg_positioner::XdgPositioner,
+                xdg_surface::{XdgSurface, XdgSurfaceHandler},
+                xdg_toplevel::{XdgToplevel, XdgToplevelHandler, XdgToplevelState},
+                xdg_wm_base::{XdgWmBase, XdgWmBaseHandler},
+            },
+        },
+        xdg_tracker::{TrackedXdgSurface, XdgTracker, XdgTrackerError},
+    },
+    error_reporter::Report,
+    std::{
+        cell::{Cell, RefCell},
+        rc::{Rc, Weak},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// An embedder for the toplevels of embedded clients.
+///
+/// Usually there is one embedder per embedding client.
+pub struct Embedder {
+    subcompositor: Rc<WlSubcompositor>,
+    xdg: Rc<XdgTracker>,
+    object_sync: Rc<ObjectSync>,
+    next_token: Cell<u32>,
+    toplevels: RefCell<Vec<Rc<EmbeddedToplevel>>>,
+    syncs: RefCell<Vec<Weak<WlproxySyncV1>>>,
+    keyboards: RefCell<Vec<Weak<WlKeyboard>>>,
+    focus: RefCell<Weak<EmbeddedToplevel>>,
+    handler: HandlerHolder<dyn EmbedderHandler>,
+}
+
+/// A handler for events emitted by an [`Embedder`].
+pub trait EmbedderHandler: 'static {
+    /// An embedded client has created a toplevel.
+    fn created(&mut self, toplevel: &Rc<EmbeddedToplevel>) {
+        let _ = toplevel;
+    }
+
+    /// An embedded toplevel has been committed.
+    ///
+    /// The size hints of the toplevel can be retrieved via
+    /// [`EmbeddedToplevel::xdg`].
+    fn committed(&mut self, toplevel: &Rc<EmbeddedToplevel>) {
+        let _ = toplevel;
+    }
+
+    /// An embedded toplevel has been destroyed.
+    fn destroyed(&mut self, toplevel: &Rc<EmbeddedToplevel>) {
+        let _ = toplevel;
+    }
+}
+
+/// A toplevel of an embedded client.
+pub struct EmbeddedToplevel {
+    embedder: Weak<Embedder>,
+    token: u32,
+    wl_surface: Weak<WlSurface>,
+    xdg: Rc<TrackedXdgSurface>,
+    title: RefCell<String>,
+    app_id: RefCell<String>,
+    size: Cell<(i32, i32)>,
+    configured: Cell<bool>,
+    parent: RefCell<Weak<WlSurface>>,
+    subsurface: RefCell<Option<Rc<WlSubsurface>>>,
+    position: Cell<(i32, i32)>,
+    destroyed: Cell<bool>,
+}
+
+/// An error emitted by an [`Embedder`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct EmbeddingError(#[from] EmbeddingErrorKind);
+
+#[derive(Debug, Error)]
+enum EmbeddingErrorKind {
+    #[error("the toplevel has been destroyed")]
+    Destroyed,
+    #[error("the parent surface is not associated with the compositor")]
+    ParentNotMapped,
+    #[error("the parent surface belongs to the client of the toplevel")]
+    SameClient,
+    #[error("there is no toplevel with token {0}")]
+    UnknownToplevel(u32),
+    #[error("the client has no wl_surface with ID {0}")]
+    UnknownSurface(u32),
+    #[error(transparent)]
+    XdgTrackerError(#[from] XdgTrackerError),
+}
+
+struct SyncHandler(Rc<Embedder>);
+
+struct XdgWmBaseRouter(Rc<Embedder>);
+
+struct XdgSurfaceRouter {
+    embedder: Rc<Embedder>,
+    xdg: Rc<TrackedXdgSurface>,
+}
+
+struct XdgToplevelRouter(Rc<EmbeddedToplevel>);
+
+struct XdgPopupRouter;
+
+struct SurfaceRouter {
+    embedder: Rc<Embedder>,
+    xdg: Rc<TrackedXdgSurface>,
+}
+
+struct SeatRouter(Rc<Embedder>);
+
+struct PointerRouter {
+    embedder: Rc<Embedder>,
+    focus: Option<Rc<WlSurface>>,
+    frame: bool,
+}
+
+struct KeyboardRouter {
+    embedder: Rc<Embedder>,
+    server_focus: Option<Rc<WlSurface>>,
+    visible_focus: Option<Rc<WlSurface>>,
+    serial: u32,
+    keys: Vec<u32>,
+    modifiers: [u32; 4],
+}
+
+struct TouchRouter {
+    /// The touch points and whether they belong to the client of the wl_touch.
+    points: Vec<(i32, bool)>,
+    frame: bool,
+}
+
+fn same_client(a: &impl ObjectCoreApi, b: &impl ObjectCoreApi) -> bool {
+    match (a.client(), b.client()) {
+        (Some(a), Some(b)) => Rc::ptr_eq(&a, &b),
+        _ => false,
+    }
+}
+
+impl Embedder {
+    /// Creates a new embedder.
+    ///
+    /// The wl_subcompositor must be associated with the compositor. It is used to create
+    /// the subsurfaces of embedded toplevels.
+    pub fn new(subcompositor: &Rc<WlSubcompositor>) -> Rc<Self> {
+        Rc::new(Self {
+            subcompositor: subcompositor.clone(),
+            xdg: XdgTracker::new(),
+            object_sync: ObjectSync::new(),
+            next_token: Cell::new(EMBEDDED_TOPLEVEL | 1),
+            toplevels: Default::default(),
+            syncs: Default::default(),
+            keyboards: Default::default(),
+            focus: Default::default(),
+            handler: Default::default(),
+        })
+    }
+
+    /// Sets the handler of the embedder.
+    pub fn set_handler(&self, handler: impl EmbedderHandler) {
+        self.handler.set(Some(Box::new(handler)));
+    }
+
+    /// Unsets the handler of the embedder.
+    pub fn unset_handler(&self) {
+        self.handler.set(None);
+    }
+
+    /// Returns the map that handles object synchronization requests of the embedding
+    /// client.
+    pub fn object_sync(&self) -> &Rc<ObjectSync> {
+        &self.object_sync
+    }
+
+    /// Returns the embedded toplevels in the order in which they were created.
+    pub fn toplevels(&self) -> Vec<Rc<EmbeddedToplevel>> {
+        self.toplevels.borrow().clone()
+    }
+
+    /// Returns the embedded toplevel with the given token.
+    pub fn get(&self, token: u32) -> Option<Rc<EmbeddedToplevel>> {
+        self.toplevels
+            .borrow()
+            .iter()
+            .find(|t| t.token == token)
+            .cloned()
+    }
+
+    /// Returns the embedded toplevel that has the keyboard focus.
+    pub fn focus(&self) -> Option<Rc<EmbeddedToplevel>> {
+        self.focus.borrow().upgrade()
+    }
+
+    /// Routes the keyboard focus to an embedded toplevel or back to the embedding
+    /// client.
+    ///
+    /// The affected toplevels are reconfigured with their last size to update the
+    /// activated state.
+    pub fn set_focus(&self, toplevel: Option<&Rc<EmbeddedToplevel>>) {
+        let new = toplevel.map(Rc::downgrade).unwrap_or_default();
+        let old = self.focus.replace(new);
+        if old.ptr_eq(&self.focus.borrow()) {
+            return;
+        }
+        for toplevel in [old.upgrade(), self.focus()].into_iter().flatten() {
+            toplevel.reconfigure();
+        }
+        self.update_keyboards();
+    }
+
+    /// Installs a handler on a wlproxy_sync_v1 object of the embedding client.
+    ///
+    /// The object must not be associated with the compositor. The tokens of all existing
+    /// toplevels are sent to the client. Object synchronization requests on this object
+    /// are handled by [`Self::object_sync`].
+    pub fn track_sync(self: &Rc<Self>, sync: &Rc<WlproxySyncV1>) {
+        sync.set_handler(SyncHandler(self.clone()));
+        let syncs = &mut *self.syncs.borrow_mut();
+        syncs.retain(|s| s.strong_count() > 0);
+        syncs.push(Rc::downgrade(sync));
+        for toplevel in &*self.toplevels.borrow() {
+            sync.send_sync_with_client(toplevel.token, 0);
+        }
+    }
+
+    /// Installs a handler on an xdg_wm_base of an embedded client.
+    ///
+    /// All toplevels created from this object are embedded. The handler replaces the
+    /// handlers of the wl_surfaces used to create xdg_surfaces.
+    pub fn track_xdg_wm_base(self: &Rc<Self>, xdg_wm_base: &Rc<XdgWmBase>) {
+        xdg_wm_base.set_handler(XdgWmBaseRouter(self.clone()));
+    }
+
+    /// Installs a handler on a wl_seat that routes the input events of all wl_pointer,
+    /// wl_keyboard, and wl_touch objects created from it.
+    ///
+    /// This function should be called for the seats of the embedding client and of all
+    /// embedded clients.
+    pub fn track_seat(self: &Rc<Self>, seat: &Rc<WlSeat>) {
+        seat.set_handler(SeatRouter(self.clone()));
+    }
+
+    fn create_toplevel(
+        self: &Rc<Self>,
+        xdg: &Rc<TrackedXdgSurface>,
+        toplevel: &Rc<XdgToplevel>,
+    ) -> Result<Rc<EmbeddedToplevel>, EmbeddingError> {
+        xdg.set_toplevel(toplevel)
+            .map_err(EmbeddingErrorKind::XdgTrackerError)?;
+        let token = self.next_token.get();
+        self.next_token
+            .set(token.wrapping_add(1).max(EMBEDDED_TOPLEVEL));
+        let embedded = Rc::new(EmbeddedToplevel {
+            embedder: Rc::downgrade(self),
+            token,
+            wl_surface: xdg
+                .wl_surface()
+                .as_ref()
+                .map(Rc::downgrade)
+                .unwrap_or_default(),
+            xdg: xdg.clone(),
+            title: Default::default(),
+            app_id: Default::default(),
+            size: Default::default(),
+            configured: Default::default(),
+            parent: Default::default(),
+            subsurface: Default::default(),
+            position: Default::default(),
+            destroyed: Default::default(),
+        });
+        self.toplevels.borrow_mut().push(embedded.clone());
+        for sync in self.syncs() {
+            sync.send_sync_with_client(token, 0);
+        }
+        if let Some(mut handler) = self.handler.try_borrow_mut()
+            && let Some(handler) = &mut *handler
+        {
+            handler.created(&embedded);
+        }
+        Ok(embedded)
+    }
+
+    fn syncs(&self) -> Vec<Rc<WlproxySyncV1>> {
+        self.syncs
+            .borrow()
+            .iter()
+            .filter_map(|s| s.upgrade())
+            .collect()
+    }
+
+    fn get_by_surface(&self, wl_surface: &WlSurface) -> Option<Rc<EmbeddedToplevel>> {
+        self.toplevels
+            .borrow()
+            .iter()
+            .find(|t| t.wl_surface.as_ptr() == wl_surface)
+            .cloned()
+    }
+
+    /// Returns the surface that should have the keyboard focus if the compositor has
+    /// given the keyboard focus to `surface`.
+    fn keyboard_target(&self, surface: &Rc<WlSurface>) -> Rc<WlSurface> {
+        if let Some(toplevel) = self.focus()
+            && let Some(parent) = toplevel.parent()
+            && same_client(&*parent, &**surface)
+            && let Some(wl_surface) = toplevel.wl_surface()
+        {
+            return wl_surface;
+        }
+        surface.clone()
+    }
+
+    fn update_keyboards(&self) {
+        let keyboards: Vec<_> = {
+            let keyboards = &mut *self.keyboards.borrow_mut();
+            keyboards.retain(|k| k.strong_count() > 0);
+            keyboards.iter().filter_map(|k| k.upgrade()).collect()
+        };
+        for keyboard in keyboards {
+            if let Ok(mut handler) = keyboard.try_get_handler_mut::<KeyboardRouter>() {
+                handler.update(&keyboard);
+            }
+        }
+    }
+
+    /// Moves the keyboard focus after a button press on `surface`.
+    fn click(&self, surface: &Rc<WlSurface>) {
+        let clicked = self.toplevels.borrow().iter().find_map(|t| {
+            let parent = t.parent()?;
+            let wl_surface = t.wl_surface()?;
+            if same_client(&*wl_surface, &**surface) {
+                return Some(Some(t.clone()));
+            }
+            if same_client(&*parent, &**surface) {
+                return Some(None);
+            }
+            None
+        });
+        if let Some(toplevel) = clicked {
+            self.set_focus(toplevel.as_ref());
+        }
+    }
+}
+
+impl EmbeddedToplevel {
+    /// Returns the token that identifies this toplevel in wlproxy_sync_v1 messages.
+    ///
+    /// The highest bit of the token is always set.
+    pub fn token(&self) -> u32 {
+        self.token
+    }
+
+    /// Returns the wl_surface of the toplevel.
+    pub fn wl_surface(&self) -> Option<Rc<WlSurface>> {
+        self.wl_surface.upgrade()
+    }
+
+    /// Returns the tracked xdg_surface of the toplevel.
+    pub fn xdg(&self) -> &Rc<TrackedXdgSurface> {
+        &self.xdg
+    }
+
+    /// Returns the title of the toplevel.
+    pub fn title(&self) -> String {
+        self.title.borrow().clone()
+    }
+
+    /// Returns the app ID of the toplevel.
+    pub fn app_id(&self) -> String {
+        self.app_id.borrow().clone()
+    }
+
+    /// Returns whether the toplevel has been destroyed.
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed.get()
+    }
+
+    /// Returns the surface that the toplevel is embedded into.
+    pub fn parent(&self) -> Option<Rc<WlSurface>> {
+        self.parent.borrow().upgrade()
+    }
+
+    /// Makes the toplevel a subsurface of a surface of the embedding client.
+    ///
+    /// If the toplevel is already embedded, it is detached first. The subsurface is
+    /// desynchronized. Like for all subsurfaces, the new position takes effect when the
+    /// parent is committed.
+    pub fn embed(&self, parent: &Rc<WlSurface>) -> Result<(), EmbeddingError> {
+        let Some(embedder) = self.embedder.upgrade() else {
+            return Err(EmbeddingErrorKind::Destroyed.into());
+        };
+        let Some(wl_surface) = self.wl_surface() else {
+            return Err(EmbeddingErrorKind::Destroyed.into());
+        };
+        if self.destroyed.get() {
+            return Err(EmbeddingErrorKind::Destroyed.into());
+        }
+        if parent.server_id().is_none() {
+            return Err(EmbeddingErrorKind::ParentNotMapped.into());
+        }
+        if same_client(&*wl_surface, &**parent) {
+            return Err(EmbeddingErrorKind::SameClient.into());
+        }
+        self.unembed();
+        let subsurface = embedder
+            .subcompositor
+            .new_send_get_subsurface(&wl_surface, parent);
+        subsurface.send_set_desync();
+        let (x, y) = self.position.get();
+        subsurface.send_set_position(x, y);
+        *self.subsurface.borrow_mut() = Some(subsurface);
+        *self.parent.borrow_mut() = Rc::downgrade(parent);
+        Ok(())
+    }
+
+    /// Detaches the toplevel from its parent.
+    ///
+    /// The toplevel keeps the keyboard focus but does not receive keyboard events until
+    /// it is embedded again.
+    pub fn unembed(&self) {
+        if let Some(subsurface) = self.subsurface.take() {
+            subsurface.send_destroy();
+        }
+        self.parent.take();
+        if let Some(embedder) = self.embedder.upgrade() {
+            embedder.update_keyboards();
+        }
+    }
+
+    /// Sets the position of the toplevel relative to its parent.
+    pub fn set_position(&self, x: i32, y: i32) {
+        self.position.set((x, y));
+        if let Some(subsurface) = &*self.subsurface.borrow() {
+            subsurface.send_set_position(x, y);
+        }
+    }
+
+    /// Returns the position of the toplevel relative to its parent.
+    pub fn position(&self) -> (i32, i32) {
+        self.position.get()
+    }
+
+    /// Sends a configure sequence to the toplevel.
+    ///
+    /// Non-zero sizes are clamped to the minimum and maximum sizes requested by the
+    /// toplevel. The activated state is set if the toplevel has the keyboard focus.
+    ///
+    /// Returns the serial of the configure event.
+    pub fn configure(&self, width: i32, height: i32) -> Result<u32, EmbeddingError> {
+        if self.destroyed.get() {
+            return Err(EmbeddingErrorKind::Destroyed.into());
+        }
+        let (min_width, min_height) = self.xdg.min_size();
+        let (max_width, max_height) = self.xdg.max_size();
+        let clamp = |size: i32, min: i32, max: i32| {
+            if size == 0 {
+                return 0;
+            }
+            let mut size = size.max(min);
+            if max > 0 {
+                size = size.min(max);
+            }
+            size
+        };
+        let width = clamp(width, min_width, max_width);
+        let height = clamp(height, min_height, max_height);
+        self.size.set((width, height));
+        self.configured.set(true);
+        let focused = self
+            .embedder
+            .upgrade()
+            .and_then(|e| e.focus())
+            .is_some_and(|f| f.token == self.token);
+        let states: &[XdgToplevelState] = match focused {
+            true => &[XdgToplevelState::ACTIVATED],
+            false => &[],
+        };
+        self.xdg
+            .inject_toplevel_configure(width, height, states)
+            .map_err(|e| EmbeddingErrorKind::XdgTrackerError(e).into())
+    }
+
+    /// Returns the size of the last configure sequence.
+    pub fn size(&self) -> (i32, i32) {
+        self.size.get()
+    }
+
+    /// Asks the client to close the toplevel.
+    pub fn close(&self) {
+        if let Some(toplevel) = self.xdg.toplevel() {
+            toplevel.send_close();
+        }
+    }
+
+    fn reconfigure(&self) {
+        if !self.configured.get() {
+            return;
+        }
+        let (width, height) = self.size.get();
+        if let Err(e) = self.configure(width, height) {
+            log::warn!("Could not reconfigure toplevel: {}", Report::new(e));
+        }
+    }
+
+    fn destroy(self: &Rc<Self>) {
+        if self.destroyed.replace(true) {
+            return;
+        }
+        self.unembed();
+        let Some(embedder) = self.embedder.upgrade() else {
+            return;
+        };
+        embedder
+            .toplevels
+            .borrow_mut()
+            .retain(|t| !Rc::ptr_eq(t, self));
+        if embedder.focus.borrow().as_ptr() == Rc::as_ptr(self) {
+            embedder.set_focus(None);
+        }
+        if let Some(mut handler) = embedder.handler.try_borrow_mut()
+            && let Some(handler) = &mut *handler
+        {
+            handler.destroyed(self);
+        }
+    }
+}
+
+impl WlproxySyncV1Handler for SyncHandler {
+    fn handle_destroy(&mut self, slf: &Rc<WlproxySyncV1>) {
+        slf.delete_id();
+    }
+
+    fn handle_sync_with_proxy(&mut self, slf: &Rc<WlproxySyncV1>, id_hi: u32, id_lo: u32) {
+        if id_hi & EMBEDDED_TOPLEVEL == 0 {
+            self.0.object_sync.handle_sync_with_proxy(slf, id_hi, id_lo);
+            return;
+        }
+        let parent = match self.embed(slf, id_hi, id_lo) {
+            Ok(parent) => parent,
+            Err(e) => {
+                log::warn!("Could not embed toplevel {id_hi}: {}", Report::new(e));
+                0
+            }
+        };
+        slf.send_sync_with_client(id_hi, parent);
+    }
+}
+
+impl SyncHandler {
+    /// Handles `sync_with_proxy(token, parent)` and returns the parent that the toplevel
+    /// is now embedded into.
+    fn embed(&self, slf: &WlproxySyncV1, token: u32, parent: u32) -> Result<u32, EmbeddingError> {
+        let Some(toplevel) = self.0.get(token) else {
+            return Err(EmbeddingErrorKind::UnknownToplevel(token).into());
+        };
+        if parent == 0 {
+            toplevel.unembed();
+            return Ok(0);
+        }
+        let surface = slf
+            .client()
+            .and_then(|c: Rc<Client>| c.endpoint.objects.borrow().get(&parent).cloned())
+            .and_then(|o| o.try_downcast::<WlSurface>());
+        let Some(surface) = surface else {
+            return Err(EmbeddingErrorKind::UnknownSurface(parent).into());
+        };
+        toplevel.embed(&surface)?;
+        Ok(parent)
+    }
+}
+
+impl XdgWmBaseHandler for XdgWmBaseRouter {
+    fn handle_get_xdg_surface(
+        &mut self,
+        _slf: &Rc<XdgWmBase>,
+        id: &Rc<XdgSurface>,
+        surface: &Rc<WlSurface>,
+    ) {
+        let xdg = self.0.xdg.create_xdg_surface(id, surface);
+        id.set_forward_to_server(false);
+        id.set_handler(XdgSurfaceRouter {
+            embedder: self.0.clone(),
+            xdg: xdg.clone(),
+        });
+        surface.set_handler(SurfaceRouter {
+            embedder: self.0.clone(),
+            xdg,
+        });
+    }
+}
+
+impl XdgSurfaceHandler for XdgSurfaceRouter {
+    fn handle_destroy(&mut self, slf: &Rc<XdgSurface>) {
+        self.xdg.destroy();
+        slf.delete_id();
+    }
+
+    fn handle_get_toplevel(&mut self, _slf: &Rc<XdgSurface>, id: &Rc<XdgToplevel>) {
+        id.set_forward_to_server(false);
+        match self.embedder.create_toplevel(&self.xdg, id) {
+            Ok(toplevel) => id.set_handler(XdgToplevelRouter(toplevel)),
+            Err(e) => log::warn!("Could not create toplevel: {}", Report::new(e)),
+        }
+    }
+
+    fn handle_get_popup(
+        &mut self,
+        _slf: &Rc<XdgSurface>,
+        id: &Rc<XdgPopup>,
+        _parent: Option<&Rc<XdgSurface>>,
+        _positioner: &Rc<XdgPositioner>,
+    ) {
+        log::debug!("Dismissing popup of embedded client");
+        id.set_forward_to_server(false);
+        id.set_handler(XdgPopupRouter);
+        id.send_popup_done();
+    }
+
+    fn handle_set_window_geometry(
+        &mut self,
+        _slf: &Rc<XdgSurface>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.xdg.set_window_geometry(x, y, width, height);
+    }
+
+    fn handle_ack_configure(&mut self, _slf: &Rc<XdgSurface>, serial: u32) {
+        if let Err(e) = self.xdg.ack_configure(serial) {
+            log::warn!("Could not ack configure: {}", Report::new(e));
+        }
+    }
+}
+
+impl XdgToplevelHandler for XdgToplevelRouter {
+    fn handle_destroy(&mut self, slf: &Rc<XdgToplevel>) {
+        self.0.destroy();
+        slf.delete_id();
+        slf.unset_handler();
+    }
+
+    fn handle_set_title(&mut self, _slf: &Rc<XdgToplevel>, title: &str) {
+        *self.0.title.borrow_mut() = title.to_string();
+    }
+
+    fn handle_set_app_id(&mut self, _slf: &Rc<XdgToplevel>, app_id: &str) {
+        *self.0.app_id.borrow_mut() = app_id.to_string();
+    }
+
+    fn handle_set_max_size(&mut self, _slf: &Rc<XdgToplevel>, width: i32, height: i32) {
+        self.0.xdg.set_max_size(width, height);
+    }
+
+    fn handle_set_min_size(&mut self, _slf: &Rc<XdgToplevel>, width: i32, height: i32) {
+        self.0.xdg.set_min_size(width, height);
+    }
+}
+
+impl XdgPopupHandler for XdgPopupRouter {
+    fn handle_destroy(&mut self, slf: &Rc<XdgPopup>) {
+        slf.delete_id();
+    }
+}
+
+impl WlSurfaceHandler for SurfaceRouter {
+    fn handle_destroy(&mut self, slf: &Rc<WlSurface>) {
+        if let Some(toplevel) = self.embedder.get_by_surface(slf) {
+            toplevel.destroy();
+        }
+        slf.send_destroy();
+    }
+
+    fn handle_commit(&mut self, slf: &Rc<WlSurface>) {
+        self.xdg.commit();
+        slf.send_commit();
+        let Some(toplevel) = self.embedder.get_by_surface(slf) else {
+            return;
+        };
+        if !toplevel.configured.get()
+            && let Err(e) = toplevel.configure(0, 0)
+        {
+            log::warn!("Could not configure toplevel: {}", Report::new(e));
+        }
+        if let Some(mut handler) = self.embedder.handler.try_borrow_mut()
+            && let Some(handler) = &mut *handler
+        {
+            handler.committed(&toplevel);
+        }
+    }
+}
+
+impl WlSeatHandler for SeatRouter {
+    fn handle_get_pointer(&mut self, slf: &Rc<WlSeat>, id: &Rc<WlPointer>) {
+        id.set_handler(PointerRouter {
+            embedder: self.0.clone(),
+            focus: None,
+            frame: false,
+        });
+        slf.send_get_pointer(id);
+    }
+
+    fn handle_get_keyboard(&mut self, slf: &Rc<WlSeat>, id: &Rc<WlKeyboard>) {
+        id.set_handler(KeyboardRouter {
+            embedder: self.0.clone(),
+            server_focus: None,
+            visible_focus: None,
+            serial: 0,
+            keys: Default::default(),
+            modifiers: Default::default(),
+        });
+        let keyboards = &mut *self.0.keyboards.borrow_mut();
+        keyboards.retain(|k| k.strong_count() > 0);
+        keyboards.push(Rc::downgrade(id));
+        slf.send_get_keyboard(id);
+    }
+
+    fn handle_get_touch(&mut self, slf: &Rc<WlSeat>, id: &Rc<WlTouch>) {
+        id.set_handler(TouchRouter {
+            points: Default::default(),
+            frame: false,
+        });
+        slf.send_get_touch(id);
+    }
+}
+
+impl PointerRouter {
+    fn owned(&mut self, slf: &WlPointer) -> bool {
+        let owned = self.focus.as_ref().is_some_and(|f| same_client(&**f, slf));
+        self.frame |= owned;
+        owned
+    }
+}
+
+impl WlPointerHandler for PointerRouter {
+    fn handle_enter(
+        &mut self,
+        slf: &Rc<WlPointer>,
+        serial: u32,
+        surface: &Rc<WlSurface>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        self.focus = Some(surface.clone());
+        if self.owned(slf) {
+            slf.send_enter(serial, surface, surface_x, surface_y);
+        }
+    }
+
+    fn handle_leave(&mut self, slf: &Rc<WlPointer>, serial: u32, surface: &Rc<WlSurface>) {
+        if self.owned(slf) {
+            slf.send_leave(serial, surface);
+        }
+        self.focus = None;
+    }
+
+    fn handle_motion(
+        &mut self,
+        slf: &Rc<WlPointer>,
+        time: u32,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        if self.owned(slf) {
+            slf.send_motion(time, surface_x, surface_y);
+        }
+    }
+
+    fn handle_button(
+        &mut self,
+        slf: &Rc<WlPointer>,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        if state == WlPointerButtonState::PRESSED
+            && let Some(focus) = &self.focus
+        {
+            self.embedder.click(focus);
+        }
+        if self.owned(slf) {
+            slf.send_button(serial, time, button, state);
+        }
+    }
+
+    fn handle_axis(&mut self, slf: &Rc<WlPointer>, time: u32, axis: WlPointerAxis, value: Fixed) {
+        if self.owned(slf) {
+            slf.send_axis(time, axis, value);
+        }
+    }
+
+    fn handle_frame(&mut self, slf: &Rc<WlPointer>) {
+        if std::mem::take(&mut self.frame) {
+            slf.send_frame();
+        }
+    }
+
+    fn handle_axis_source(&mut self, slf: &Rc<WlPointer>, axis_source: WlPointerAxisSource) {
+        if self.owned(slf) {
+            slf.send_axis_source(axis_source);
+        }
+    }
+
+    fn handle_axis_stop(&mut self, slf: &Rc<WlPointer>, time: u32, axis: WlPointerAxis) {
+        if self.owned(slf) {
+            slf.send_axis_stop(time, axis);
+        }
+    }
+
+    fn handle_axis_discrete(&mut self, slf: &Rc<WlPointer>, axis: WlPointerAxis, discrete: i32) {
+        if self.owned(slf) {
+            slf.send_axis_discrete(axis, discrete);
+        }
+    }
+
+    fn handle_axis_value120(&mut self, slf: &Rc<WlPointer>, axis: WlPointerAxis, value120: i32) {
+        if self.owned(slf) {
+            slf.send_axis_value120(axis, value120);
+        }
+    }
+
+    fn handle_axis_relative_direction(
+        &mut self,
+        slf: &Rc<WlPointer>,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        if self.owned(slf) {
+            slf.send_axis_relative_direction(axis, direction);
+        }
+    }
+
+    fn handle_warp(&mut self, slf: &Rc<WlPointer>, surface_x: Fixed, surface_y: Fixed) {
+        if self.owned(slf) {
+            slf.send_warp(surface_x, surface_y);
+        }
+    }
+}
+
+impl KeyboardRouter {
+    fn update(&mut self, slf: &WlKeyboard) {
+        let new = self
+            .server_focus
+            .as_ref()
+            .map(|s| self.embedder.keyboard_target(s))
+            .filter(|t| same_client(&**t, slf));
+        let unchanged = match (&new, &self.visible_focus) {
+            (Some(new), Some(old)) => Rc::ptr_eq(new, old),
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged {
+            return;
+        }
+        if let Some(old) = self.visible_focus.take()
+            && old.client().is_some()
+        {
+            slf.send_leave(self.serial, &old);
+        }
+        if let Some(new) = &new {
+            slf.send_enter(self.serial, new, uapi::as_bytes(&*self.keys));
+            let [depressed, latched, locked, group] = self.modifiers;
+            slf.send_modifiers(self.serial, depressed, latched, locked, group);
+        }
+        self.visible_focus = new;
+    }
+}
+
+impl WlKeyboardHandler for KeyboardRouter {
+    fn handle_enter(
+        &mut self,
+        slf: &Rc<WlKeyboard>,
+        serial: u32,
+        surface: &Rc<WlSurface>,
+        keys: &[u8],
+    ) {
+        self.serial = serial;
+        self.server_focus = Some(surface.clone());
+        self.keys = keys
+            .chunks_exact(4)
+            .map(|k| u32::from_ne_bytes(k.try_into().unwrap()))
+            .collect();
+        self.update(slf);
+    }
+
+    fn handle_leave(&mut self, slf: &Rc<WlKeyboard>, serial: u32, _surface: &Rc<WlSurface>) {
+        self.serial = serial;
+        self.server_focus = None;
+        self.keys.clear();
+        self.update(slf);
+    }
+
+    fn handle_key(
+        &mut self,
+        slf: &Rc<WlKeyboard>,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        self.serial = serial;
+        self.keys.retain(|k| *k != key);
+        if state == WlKeyboardKeyState::PRESSED {
+            self.keys.push(key);
+        }
+        if self.visible_focus.is_some() {
+            slf.send_key(serial, time, key, state);
+        }
+    }
+
+    fn handle_modifiers(
+        &mut self,
+        slf: &Rc<WlKeyboard>,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        self.serial = serial;
+        self.modifiers = [mods_depressed, mods_latched, mods_locked, group];
+        if self.visible_focus.is_some() {
+            slf.send_modifiers(serial, mods_depressed, mods_latched, mods_locked, group);
+        }
+    }
+}
+
+impl TouchRouter {
+    fn owned(&mut self, id: i32) -> bool {
+        let owned = self.points.iter().any(|&(p, owned)| p == id && owned);
+        self.frame |= owned;
+        owned
+    }
+}
+
+impl WlTouchHandler for TouchRouter {
+    fn handle_down(
+        &mut self,
+        slf: &Rc<WlTouch>,
+        serial: u32,
+        time: u32,
+        surface: &Rc<WlSurface>,
+        id: i32,
+        x: Fixed,
+        y: Fixed,
+    ) {
+        self.points.retain(|p| p.0 != id);
+        self.points.push((id, same_client(&**surface, &**slf)));
+        if self.owned(id) {
+            slf.send_down(serial, time, surface, id, x, y);
+        }
+    }
+
+    fn handle_up(&mut self, slf: &Rc<WlTouch>, serial: u32, time: u32, id: i32) {
+        if self.owned(id) {
+            slf.send_up(serial, time, id);
+        }
+        self.points.retain(|p| p.0 != id);
+    }
+
+    fn handle_motion(&mut self, slf: &Rc<WlTouch>, time: u32, id: i32, x: Fixed, y: Fixed) {
+        if self.owned(id) {
+            slf.send_motion(time, id, x, y);
+        }
+    }
+
+    fn handle_frame(&mut self, slf: &Rc<WlTouch>) {
+        if std::mem::take(&mut self.frame) {
+            slf.send_frame();
+        }
+    }
+
+    fn handle_cancel(&mut self, slf: &Rc<WlTouch>) {
+        if self.points.iter().any(|p| p.1) {
+            slf.send_cancel();
+        }
+        self.points.clear();
+        self.frame = false;
+    }
+
+    fn handle_shape(&mut self, slf: &Rc<WlTouch>, id: i32, major: Fixed, minor: Fixed) {
+        if self.owned(id) {
+            slf.send_shape(id, major, minor);
+        }
+    }
+
+    fn handle_orientation(&mut self, slf: &Rc<WlTouch>, id: i32, orientation: Fixed) {
+        if self.owned(id) {
+            slf.send_orientation(id, orientation);
+        }
+    }
+}
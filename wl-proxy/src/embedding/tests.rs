@@ -0,0 +1,317 @@
+#![cfg(feature = "headless")]
+
+use {
+    crate::{
+        baseline::Baseline,
+        embedding::{EmbeddedToplevel, Embedder, EmbedderHandler},
+        fixed::Fixed,
+        headless::Headless,
+        object::{ConcreteObject, Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::{
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_compositor::WlCompositor,
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_keyboard::{WlKeyboard, WlKeyboardHandler},
+                wl_pointer::{WlPointer, WlPointerHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+                wl_seat::WlSeat,
+                wl_subcompositor::WlSubcompositor,
+                wl_surface::WlSurface,
+            },
+            wlproxy_sync_v1::wlproxy_sync_v1::{WlproxySyncV1, WlproxySyncV1Handler},
+            xdg_shell::{
+                xdg_surface::{XdgSurface, XdgSurfaceHandler},
+                xdg_toplevel::{XdgToplevel, XdgToplevelHandler},
+                xdg_wm_base::XdgWmBase,
+            },
+        },
+        state::State,
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+/// The name under which the proxy announces wlproxy_sync_v1 to the embedding client.
+const SYNC_NAME: u32 = 100;
+
+#[derive(Clone)]
+struct Proxy {
+    embedder: Rc<Embedder>,
+    embedded: bool,
+}
+
+impl WlDisplayHandler for Proxy {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(self.clone());
+        slf.send_get_registry(registry);
+    }
+}
+
+impl WlRegistryHandler for Proxy {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        if let Some(sync) = id.try_downcast::<WlproxySyncV1>() {
+            self.embedder.track_sync(&sync);
+            return;
+        }
+        if let Some(seat) = id.try_downcast::<WlSeat>() {
+            self.embedder.track_seat(&seat);
+        }
+        if self.embedded
+            && let Some(xdg_wm_base) = id.try_downcast::<XdgWmBase>()
+        {
+            self.embedder.track_xdg_wm_base(&xdg_wm_base);
+        }
+        slf.send_bind(name, id);
+    }
+}
+
+#[derive(Clone, Default)]
+struct Events(Rc<RefCell<Vec<String>>>);
+
+impl Events {
+    fn push(&self, event: String) {
+        self.0.borrow_mut().push(event);
+    }
+
+    fn take(&self) -> Vec<String> {
+        self.0.take()
+    }
+}
+
+impl EmbedderHandler for Events {
+    fn created(&mut self, toplevel: &Rc<EmbeddedToplevel>) {
+        self.push(format!("created {}", toplevel.token()));
+    }
+
+    fn destroyed(&mut self, toplevel: &Rc<EmbeddedToplevel>) {
+        self.push(format!("destroyed {}", toplevel.token()));
+    }
+}
+
+impl WlproxySyncV1Handler for Events {
+    fn handle_sync_with_client(&mut self, _slf: &Rc<WlproxySyncV1>, id_hi: u32, id_lo: u32) {
+        self.push(format!("sync {id_hi} {id_lo}"));
+    }
+}
+
+impl XdgSurfaceHandler for Events {
+    fn handle_configure(&mut self, slf: &Rc<XdgSurface>, serial: u32) {
+        self.push("configure".to_string());
+        slf.send_ack_configure(serial);
+    }
+}
+
+impl XdgToplevelHandler for Events {
+    fn handle_configure(&mut self, _slf: &Rc<XdgToplevel>, width: i32, height: i32, states: &[u8]) {
+        self.push(format!("toplevel {width}x{height} {}", states.len() / 4));
+    }
+}
+
+impl WlPointerHandler for Events {
+    fn handle_enter(
+        &mut self,
+        _slf: &Rc<WlPointer>,
+        _serial: u32,
+        _surface: &Rc<WlSurface>,
+        _surface_x: Fixed,
+        _surface_y: Fixed,
+    ) {
+        self.push("pointer.enter".to_string());
+    }
+}
+
+impl WlKeyboardHandler for Events {
+    fn handle_enter(
+        &mut self,
+        _slf: &Rc<WlKeyboard>,
+        _serial: u32,
+        _surface: &Rc<WlSurface>,
+        _keys: &[u8],
+    ) {
+        self.push("keyboard.enter".to_string());
+    }
+
+    fn handle_leave(&mut self, _slf: &Rc<WlKeyboard>, _serial: u32, _surface: &Rc<WlSurface>) {
+        self.push("keyboard.leave".to_string());
+    }
+}
+
+struct Done(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn embedding() {
+    let headless = Headless::new().unwrap();
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let _destructor = proxy.create_destructor();
+    let subcompositor = proxy.create_object::<WlSubcompositor>(1);
+    proxy
+        .display()
+        .new_send_get_registry()
+        .send_bind(3, subcompositor.clone());
+    let embedder = Embedder::new(&subcompositor);
+    let proxy_events = Events::default();
+    embedder.set_handler(proxy_events.clone());
+
+    let connect = |embedded: bool| {
+        let (proxy_client, client_fd) = proxy.connect().unwrap();
+        proxy_client.display.set_handler(Proxy {
+            embedder: embedder.clone(),
+            embedded,
+        });
+        State::builder(Baseline::ALL_OF_THEM)
+            .with_server_fd(&Rc::new(client_fd))
+            .build()
+            .unwrap()
+    };
+    let roundtrip = |client: &Rc<State>| {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([client, &proxy, headless.state()]).unwrap();
+        }
+    };
+    let create_toplevel = |client: &Rc<State>, events: &Events| {
+        let registry = client.display().new_send_get_registry();
+        let compositor = client.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+        registry.send_bind(1, compositor.clone());
+        let wm_base = client.create_object::<XdgWmBase>(XdgWmBase::XML_VERSION);
+        registry.send_bind(4, wm_base.clone());
+        let seat = client.create_object::<WlSeat>(WlSeat::XML_VERSION);
+        registry.send_bind(5, seat.clone());
+        seat.new_send_get_pointer().set_handler(events.clone());
+        seat.new_send_get_keyboard().set_handler(events.clone());
+        let surface = compositor.new_send_create_surface();
+        let xdg_surface = wm_base.new_send_get_xdg_surface(&surface);
+        xdg_surface.set_handler(events.clone());
+        let toplevel = xdg_surface.new_send_get_toplevel();
+        toplevel.set_handler(events.clone());
+        surface.send_commit();
+        (registry, surface, toplevel)
+    };
+
+    // The embedding client maps a regular toplevel.
+    let client_a = connect(false);
+    let _destructor_a = client_a.create_destructor();
+    let events_a = Events::default();
+    let (registry_a, surface_a, _toplevel_a) = create_toplevel(&client_a, &events_a);
+    let sync = client_a.create_object::<WlproxySyncV1>(1);
+    sync.set_handler(events_a.clone());
+    registry_a.send_bind(SYNC_NAME, sync.clone());
+    roundtrip(&client_a);
+    assert_eq!(events_a.take(), ["toplevel 0x0 0", "configure"]);
+    headless.toplevels()[0].configure(800, 600).unwrap();
+    roundtrip(&client_a);
+    surface_a.send_commit();
+    roundtrip(&client_a);
+    assert_eq!(events_a.take(), ["toplevel 800x600 0", "configure"]);
+
+    // The toplevel of the embedded client is configured by the proxy.
+    let client_b = connect(true);
+    let _destructor_b = client_b.create_destructor();
+    let events_b = Events::default();
+    let (_registry_b, surface_b, toplevel_b) = create_toplevel(&client_b, &events_b);
+    toplevel_b.send_set_min_size(200, 0);
+    surface_b.send_commit();
+    roundtrip(&client_b);
+    assert_eq!(events_b.take(), ["toplevel 0x0 0", "configure"]);
+    assert_eq!(headless.toplevels().len(), 1);
+    let embedded = embedder.toplevels()[0].clone();
+    let token = embedded.token();
+    assert_eq!(proxy_events.take(), [format!("created {token}")]);
+
+    // The embedding client chooses the parent.
+    roundtrip(&client_a);
+    assert_eq!(events_a.take(), [format!("sync {token} 0")]);
+    sync.send_sync_with_proxy(token + 1, surface_a.server_id().unwrap());
+    sync.send_sync_with_proxy(token, sync.server_id().unwrap());
+    roundtrip(&client_a);
+    assert_eq!(
+        events_a.take(),
+        [format!("sync {} 0", token + 1), format!("sync {token} 0")],
+    );
+    assert!(embedded.parent().is_none());
+
+    // Other requests are handled as object synchronization requests.
+    let surface_a_id = surface_a.server_id().unwrap();
+    sync.send_sync_with_proxy(1, surface_a_id);
+    roundtrip(&client_a);
+    assert_eq!(events_a.take(), [format!("sync 1 {surface_a_id}")]);
+    let proxy_surface_a = embedder
+        .object_sync()
+        .get_as::<WlSurface>((1 << 32) | surface_a_id as u64)
+        .unwrap();
+    assert!(proxy_surface_a.client().is_some());
+    assert!(embedded.parent().is_none());
+    embedded.set_position(10, 20);
+    sync.send_sync_with_proxy(token, surface_a.server_id().unwrap());
+    surface_a.send_commit();
+    roundtrip(&client_a);
+    assert_eq!(
+        events_a.take(),
+        [format!("sync {token} {}", surface_a.server_id().unwrap())],
+    );
+    let [headless_a, headless_b] = &*headless.surfaces() else {
+        panic!("unexpected surfaces");
+    };
+    let parent = headless_b.parent().unwrap();
+    assert!(Rc::ptr_eq(
+        &parent.wl_surface().unwrap(),
+        &headless_a.wl_surface().unwrap(),
+    ));
+    assert_eq!(headless_b.position(), (10, 20));
+
+    // Configures are clamped to the size hints.
+    embedded.configure(100, 50).unwrap();
+    roundtrip(&client_b);
+    assert_eq!(events_b.take(), ["toplevel 200x50 0", "configure"]);
+
+    // Pointer events are only sent to the client under the pointer.
+    headless.pointer_enter(headless_b, 1.0, 1.0);
+    roundtrip(&client_a);
+    roundtrip(&client_b);
+    assert_eq!(events_a.take(), Vec::<String>::new());
+    assert_eq!(events_b.take(), ["pointer.enter"]);
+
+    // Clicking on the embedded toplevel focuses it.
+    headless.pointer_button(0x110, true);
+    roundtrip(&client_b);
+    assert_eq!(events_b.take(), ["toplevel 200x50 1", "configure"]);
+    assert_eq!(embedder.focus().unwrap().token(), token);
+    headless.keyboard_enter(headless_a);
+    roundtrip(&client_a);
+    roundtrip(&client_b);
+    assert_eq!(events_a.take(), Vec::<String>::new());
+    assert_eq!(events_b.take(), ["keyboard.enter"]);
+    embedder.set_focus(None);
+    roundtrip(&client_a);
+    roundtrip(&client_b);
+    assert_eq!(events_a.take(), ["keyboard.enter"]);
+    assert_eq!(
+        events_b.take(),
+        ["toplevel 200x50 0", "configure", "keyboard.leave"],
+    );
+
+    // Destroying the toplevel removes it from the embedder.
+    toplevel_b.send_destroy();
+    roundtrip(&client_b);
+    assert!(embedder.toplevels().is_empty());
+    assert!(embedded.is_destroyed());
+    assert!(headless_b.parent().is_none());
+    assert_eq!(proxy_events.take(), [format!("destroyed {token}")]);
+}
@@ -1,8 +1,14 @@
+#[cfg(feature = "network")]
+use crate::network::transport::Transport;
+#[cfg(feature = "recovery")]
+use crate::recovery::Journal;
 use {
     crate::{
         client::Client,
+        interceptor::{self, Intercepted},
         object::{Object, ObjectError},
         protocols::ObjectInterface,
+        recording::{Direction, EndpointRecorder},
         state::HandlerLock,
         trans::{self, FlushResult, InputBuffer, OutputSwapchain, TransError},
         utils::free_list::FreeList,
@@ -23,7 +29,7 @@ mod tests;
 
 pub(crate) struct Endpoint {
     pub(crate) id: u64,
-    pub(crate) socket: Rc<OwnedFd>,
+    pub(crate) socket: RefCell<Rc<OwnedFd>>,
     pub(crate) outgoing: RefCell<OutputSwapchain>,
     pub(crate) flush_queued: Cell<bool>,
     pub(crate) unregistered: Cell<bool>,
@@ -36,12 +42,22 @@ pub(crate) struct Endpoint {
     pub(crate) desired_suspended: Cell<bool>,
     pub(crate) unsuspend_queued: Cell<bool>,
     incoming: RefCell<InputState>,
+    recorder: Option<Rc<EndpointRecorder>>,
+    #[cfg(feature = "recovery")]
+    pub(crate) journal: Option<Rc<Journal>>,
+    #[cfg(feature = "network")]
+    network: Option<Box<Transport>>,
 }
 
 #[derive(Default)]
 pub(crate) struct InputState {
     buffer: Box<InputBuffer>,
     fds: VecDeque<Rc<OwnedFd>>,
+    recorded_fds: usize,
+    /// A message that was suspended by the interceptor.
+    held: Option<Vec<u32>>,
+    #[cfg(feature = "network")]
+    message: Vec<u32>,
 }
 
 #[derive(Debug, Error)]
@@ -61,7 +77,7 @@ pub struct MessageError {
     object: u32,
     interface: Option<ObjectInterface>,
     message_id: u32,
-    message_name: Option<&'static str>,
+    message_name: Option<String>,
     pub(crate) source: ObjectError,
 }
 
@@ -70,7 +86,7 @@ impl Display for MessageError {
         write!(f, "could not handle a ")?;
         if let Some(interface) = &self.interface {
             write!(f, "{}#{}.", interface.name(), self.object)?;
-            if let Some(name) = self.message_name {
+            if let Some(name) = &self.message_name {
                 write!(f, "{}", name)?;
             } else {
                 write!(f, "{}", self.message_id)?;
@@ -94,11 +110,28 @@ impl Error for MessageError {
 }
 
 impl Endpoint {
-    pub(crate) fn new(id: u64, socket: &Rc<OwnedFd>) -> Rc<Self> {
-        Rc::new(Endpoint {
+    /// Records the messages sent and received via this endpoint in the journal.
+    #[cfg(feature = "recovery")]
+    pub(crate) fn set_journal(&mut self, journal: &Rc<Journal>) {
+        self.outgoing.get_mut().journal = Some(journal.clone());
+        self.journal = Some(journal.clone());
+    }
+
+    /// Uses the network transport instead of passing file descriptors over the socket.
+    #[cfg(feature = "network")]
+    pub(crate) fn set_network(&mut self, is_server: bool) {
+        self.network = Some(Box::new(Transport::new(is_server)));
+    }
+
+    pub(crate) fn create(
+        id: u64,
+        socket: &Rc<OwnedFd>,
+        recorder: Option<Rc<EndpointRecorder>>,
+    ) -> Self {
+        Endpoint {
             id,
-            socket: socket.clone(),
-            outgoing: Default::default(),
+            socket: RefCell::new(socket.clone()),
+            outgoing: RefCell::new(OutputSwapchain::new(recorder.clone())),
             flush_queued: Default::default(),
             unregistered: Default::default(),
             objects: Default::default(),
@@ -110,7 +143,37 @@ impl Endpoint {
             desired_suspended: Default::default(),
             unsuspend_queued: Default::default(),
             incoming: Default::default(),
-        })
+            recorder,
+            #[cfg(feature = "recovery")]
+            journal: None,
+            #[cfg(feature = "network")]
+            network: None,
+        }
+    }
+
+    pub(crate) fn socket(&self) -> Rc<OwnedFd> {
+        self.socket.borrow().clone()
+    }
+
+    /// Replaces the socket and discards all state associated with the old socket.
+    ///
+    /// The object map is returned to the caller.
+    #[cfg(feature = "recovery")]
+    pub(crate) fn reset(&self, socket: &Rc<OwnedFd>) -> HashMap<u32, Rc<dyn Object>> {
+        *self.socket.borrow_mut() = socket.clone();
+        self.outgoing.borrow_mut().clear();
+        *self.incoming.borrow_mut() = Default::default();
+        self.flush_queued.set(false);
+        self.current_interest.set(0);
+        self.desired_interest.set(0);
+        self.idl.clear();
+        self.idl.acquire();
+        self.idl.acquire();
+        #[cfg(feature = "network")]
+        if let Some(network) = &self.network {
+            network.reset();
+        }
+        self.objects.take()
     }
 
     pub(crate) fn lookup(&self, id: u32) -> Option<Rc<dyn Object>> {
@@ -118,9 +181,13 @@ impl Endpoint {
     }
 
     pub(crate) fn flush(&self) -> Result<FlushResult, EndpointError> {
+        #[cfg(feature = "network")]
+        if let Some(network) = &self.network {
+            return network.flush(self).map_err(EndpointError::Flush);
+        }
         self.outgoing
             .borrow_mut()
-            .flush(self.socket.as_raw_fd())
+            .flush(self.socket.borrow().as_raw_fd())
             .map_err(EndpointError::Flush)
     }
 
@@ -132,6 +199,10 @@ impl Endpoint {
         let incoming = &mut *self.incoming.borrow_mut();
         let buffer = &mut *incoming.buffer;
         let fds = &mut incoming.fds;
+        let recorded_fds = &mut incoming.recorded_fds;
+        let held = &mut incoming.held;
+        #[cfg(feature = "network")]
+        let message = &mut incoming.message;
         let mut may_read_from_socket = true;
         loop {
             if self.suspended.get() {
@@ -142,14 +213,30 @@ impl Endpoint {
             {
                 return Ok(());
             }
-            let msg = trans::read_message(
-                self.socket.as_raw_fd(),
-                &mut may_read_from_socket,
-                buffer,
-                fds,
-            );
-            let Some(msg) = msg.map_err(EndpointError::Read)? else {
-                break;
+            let mut held_msg = held.take();
+            let is_held = held_msg.is_some();
+            let msg: &mut [u32] = match &mut held_msg {
+                Some(msg) => msg,
+                None => {
+                    let socket = self.socket.borrow().as_raw_fd();
+                    #[cfg(feature = "network")]
+                    let msg = match &self.network {
+                        Some(network) => {
+                            network.read_message(socket, &mut may_read_from_socket, message, fds)
+                        }
+                        None => trans::read_message(socket, &mut may_read_from_socket, buffer, fds),
+                    };
+                    #[cfg(not(feature = "network"))]
+                    let msg = trans::read_message(socket, &mut may_read_from_socket, buffer, fds);
+                    let Some(msg) = msg.map_err(EndpointError::Read)? else {
+                        break;
+                    };
+                    if let Some(recorder) = &self.recorder {
+                        recorder.record(Direction::Received, msg, fds.range(*recorded_fds..));
+                        *recorded_fds = fds.len();
+                    }
+                    msg
+                }
             };
             let obj_id = msg[0];
             let obj = self
@@ -158,11 +245,39 @@ impl Endpoint {
                 .get(&obj_id)
                 .cloned()
                 .ok_or(EndpointError::NoReceiver(obj_id))?;
+            let mut replacement = None;
+            if !is_held {
+                #[cfg(feature = "recovery")]
+                if let Some(journal) = &self.journal
+                    && !journal.record_event(self, &obj, msg)
+                {
+                    continue;
+                }
+                let num_fds = fds.len();
+                let intercepted = interceptor::intercept(self, client, &obj, msg, fds);
+                if self.recorder.is_some() {
+                    *recorded_fds = (*recorded_fds + fds.len()).saturating_sub(num_fds);
+                }
+                match intercepted {
+                    Intercepted::Dispatch => {}
+                    Intercepted::Replaced(words) => replacement = Some(words),
+                    Intercepted::Skip => continue,
+                    Intercepted::Hold => {
+                        *held = Some(msg.to_vec());
+                        continue;
+                    }
+                }
+            }
+            let msg = replacement.as_deref().unwrap_or(msg);
+            let num_fds = fds.len();
             let res = if let Some(client) = client {
                 obj.handle_request(client, msg, fds)
             } else {
                 obj.handle_event(self, msg, fds)
             };
+            if self.recorder.is_some() {
+                *recorded_fds -= num_fds - fds.len();
+            }
             if let Err(e) = res {
                 let mut err = Box::new(MessageError {
                     object: obj_id,
@@ -177,7 +292,8 @@ impl Endpoint {
                         obj.get_request_name(err.message_id)
                     } else {
                         obj.get_event_name(err.message_id)
-                    };
+                    }
+                    .map(str::to_owned);
                 }
                 return Err(EndpointError::HandleMessage(err));
             }
@@ -40,6 +40,7 @@ trait RegistryApi {
         interface: ObjectInterface,
         version: u32,
     ) -> Result<(), ObjectError>;
+    fn unknown_global(&self, name: u32, interface: &str, version: u32) -> Result<(), ObjectError>;
     fn global_remove(&self, name: u32) -> Result<(), ObjectError>;
 }
 
@@ -57,6 +58,10 @@ impl RegistryApi for WlRegistry {
         self.try_send_global(name, interface, version)
     }
 
+    fn unknown_global(&self, name: u32, interface: &str, version: u32) -> Result<(), ObjectError> {
+        self.try_send_unknown_global(name, interface, version)
+    }
+
     fn global_remove(&self, name: u32) -> Result<(), ObjectError> {
         self.try_send_global_remove(name)
     }
@@ -131,6 +136,34 @@ impl GlobalMapper {
         self.try_forward_global_impl(registry, server_name, interface, version)
     }
 
+    /// Handles a server-sent global event with an interface that is not known to this
+    /// crate.
+    ///
+    /// This function is similar to [`GlobalMapper::try_forward_unknown_global`] but logs
+    /// a message instead of returning an error if the global could not be sent to the
+    /// client.
+    pub fn forward_unknown_global(
+        &mut self,
+        registry: &WlRegistry,
+        server_name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        self.forward_unknown_global_impl(registry, server_name, interface, version)
+    }
+
+    /// Tries to handle a server-sent global event with an interface that is not known
+    /// to this crate.
+    pub fn try_forward_unknown_global(
+        &mut self,
+        registry: &WlRegistry,
+        server_name: u32,
+        interface: &str,
+        version: u32,
+    ) -> Result<(), ObjectError> {
+        self.try_forward_unknown_global_impl(registry, server_name, interface, version)
+    }
+
     /// Ignores a server-sent global.
     ///
     /// This function should be used so that global_remove events can be filtered
@@ -252,10 +285,40 @@ impl GlobalMapper {
         interface: ObjectInterface,
         version: u32,
     ) -> Result<(), ObjectError> {
+        let client_name = self.map_server_global(server_name);
+        registry.global(client_name, interface, version)
+    }
+
+    fn forward_unknown_global_impl(
+        &mut self,
+        registry: &impl RegistryApi,
+        server_name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        if let Err(e) =
+            self.try_forward_unknown_global_impl(registry, server_name, interface, version)
+        {
+            log::warn!("Could not handle server global: {}", Report::new(e));
+        }
+    }
+
+    fn try_forward_unknown_global_impl(
+        &mut self,
+        registry: &impl RegistryApi,
+        server_name: u32,
+        interface: &str,
+        version: u32,
+    ) -> Result<(), ObjectError> {
+        let client_name = self.map_server_global(server_name);
+        registry.unknown_global(client_name, interface, version)
+    }
+
+    fn map_server_global(&mut self, server_name: u32) -> u32 {
         let client_name = self.client_to_server.len() as u32;
         self.client_to_server.push(Some(server_name));
         self.server_to_client.insert(server_name, Some(client_name));
-        registry.global(client_name, interface, version)
+        client_name
     }
 
     fn forward_global_remove_impl(&mut self, registry: &impl RegistryApi, server_name: u32) {
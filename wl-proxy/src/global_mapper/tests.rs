@@ -23,6 +23,7 @@ impl Eq for ObjEqWrapper {}
 enum RegistryMsg {
     Bind(u32, ObjEqWrapper),
     Global(u32, ObjectInterface, u32),
+    UnknownGlobal(u32, String, u32),
     GlobalRemove(u32),
 }
 
@@ -44,6 +45,15 @@ impl RegistryApi for RefCell<&'_ mut VecDeque<RegistryMsg>> {
         Ok(())
     }
 
+    fn unknown_global(&self, name: u32, interface: &str, version: u32) -> Result<(), ObjectError> {
+        self.borrow_mut().push_back(RegistryMsg::UnknownGlobal(
+            name,
+            interface.to_string(),
+            version,
+        ));
+        Ok(())
+    }
+
     fn global_remove(&self, name: u32) -> Result<(), ObjectError> {
         self.borrow_mut().push_back(RegistryMsg::GlobalRemove(name));
         Ok(())
@@ -88,6 +98,19 @@ impl RegistryApi for RefCell<ErrorRegistry> {
         }
     }
 
+    fn unknown_global(
+        &self,
+        _name: u32,
+        _interface: &str,
+        _version: u32,
+    ) -> Result<(), ObjectError> {
+        if self.borrow().fail_global {
+            Err(ObjectErrorKind::HandlerBorrowed.into())
+        } else {
+            Ok(())
+        }
+    }
+
     fn global_remove(&self, _name: u32) -> Result<(), ObjectError> {
         if self.borrow().fail_global_remove {
             Err(ObjectErrorKind::HandlerBorrowed.into())
@@ -279,6 +302,48 @@ fn test_forward_global_error() {
     mapper.forward_global_impl(&registry, 100, ObjectInterface::WlCompositor, 5);
 }
 
+#[test]
+fn test_forward_unknown_global() {
+    let mut events = VecDeque::new();
+    let mut mapper = GlobalMapper::default();
+
+    mapper.forward_global_impl(
+        &RefCell::new(&mut events),
+        100,
+        ObjectInterface::WlCompositor,
+        5,
+    );
+    mapper.forward_unknown_global_impl(&RefCell::new(&mut events), 101, "wp_example_v1", 2);
+    assert_eq!(
+        events.pop_front(),
+        Some(RegistryMsg::Global(1, ObjectInterface::WlCompositor, 5))
+    );
+    assert_eq!(
+        events.pop_front(),
+        Some(RegistryMsg::UnknownGlobal(
+            2,
+            "wp_example_v1".to_string(),
+            2
+        ))
+    );
+    assert_eq!(mapper.server_to_client.get(&101), Some(&Some(2)));
+    assert_eq!(mapper.client_to_server.get(2), Some(&Some(101)));
+
+    mapper.forward_global_remove_impl(&RefCell::new(&mut events), 101);
+    assert_eq!(events.pop_front(), Some(RegistryMsg::GlobalRemove(2)));
+}
+
+#[test]
+fn test_try_forward_unknown_global_error() {
+    let mut registry = ErrorRegistry::new();
+    registry.fail_global = true;
+    let registry = RefCell::new(registry);
+    let mut mapper = GlobalMapper::default();
+
+    let result = mapper.try_forward_unknown_global_impl(&registry, 100, "wp_example_v1", 2);
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_ignore_global() {
     let mut mapper = GlobalMapper::default();
@@ -0,0 +1,1404 @@
+//! A headless reference compositor.
+//!
+//! This module requires the `headless` feature.
+//!
+//! [`Headless`] is an in-process server that implements enough of the core protocols to
+//! run real clients without a compositor:
+//!
+//! - wl_compositor, wl_surface, and wl_region,
+//! - wl_shm, wl_shm_pool, and wl_buffer,
+//! - wl_subcompositor and wl_subsurface, including synchronized subsurfaces,
+//! - xdg_wm_base, xdg_surface, xdg_toplevel, xdg_popup, and xdg_positioner,
+//! - wl_seat, wl_pointer, and wl_keyboard,
+//! - a single wl_output with a resolution of 1920x1080 at 60 Hz.
+//!
+//! The contents of shm buffers are copied into memory when a surface state is applied
+//! and the buffer is released immediately afterwards. The copies can be inspected via
+//! [`HeadlessSurface::image`]. Frame callbacks are completed when [`Headless::frame`] is
+//! called and input events are generated by functions such as [`Headless::key`].
+//!
+//! The compositor uses a [`State`] without a server. Its clients can be connected via
+//! [`Headless::connect`] or via acceptors added to [`Headless::state`]. The state is
+//! dispatched like any other state.
+//!
+//! # Example
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use wl_proxy::{baseline::Baseline, headless::Headless, state::State};
+//! # fn f() {
+//! let headless = Headless::new().unwrap();
+//! let proxy = State::builder(Baseline::ALL_OF_THEM)
+//!     .with_server_fd(&Rc::new(headless.connect().unwrap()))
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+
+use {
+    crate::{
+        baseline::Baseline,
+        client::Client,
+        fixed::Fixed,
+        object::{ConcreteObject, Object, ObjectCoreApi, ObjectRcUtils, ObjectUtils},
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_buffer::{WlBuffer, WlBufferHandler},
+                wl_callback::WlCallback,
+                wl_compositor::{WlCompositor, WlCompositorHandler},
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_keyboard::{WlKeyboard, WlKeyboardKeyState, WlKeyboardKeymapFormat},
+                wl_output::{
+                    WlOutput, WlOutputHandler, WlOutputMode, WlOutputSubpixel, WlOutputTransform,
+                },
+                wl_pointer::{WlPointer, WlPointerButtonState},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+                wl_seat::{WlSeat, WlSeatCapability, WlSeatHandler},
+                wl_shm::{WlShm, WlShmError, WlShmFormat, WlShmHandler},
+                wl_shm_pool::{WlShmPool, WlShmPoolHandler},
+                wl_subcompositor::{WlSubcompositor, WlSubcompositorError, WlSubcompositorHandler},
+                wl_subsurface::{WlSubsurface, WlSubsurfaceHandler},
+                wl_surface::{WlSurface, WlSurfaceError, WlSurfaceHandler},
+            },
+            xdg_shell::{
+                xdg_popup::{XdgPopup, XdgPopupHandler},
+                xdg_positioner::{XdgPositioner, XdgPositionerHandler},
+                xdg_surface::{XdgSurface, XdgSurfaceError, XdgSurfaceHandler},
+                xdg_toplevel::{XdgToplevel, XdgToplevelHandler},
+                xdg_wm_base::{XdgWmBase, XdgWmBaseError, XdgWmBaseHandler},
+            },
+        },
+        state::{Destructor, State, StateError, StateHandler},
+    },
+    error_reporter::Report,
+    std::{
+        cell::{Cell, RefCell},
+        io, mem,
+        os::fd::{AsRawFd, OwnedFd},
+        rc::{Rc, Weak},
+        time::Instant,
+    },
+    thiserror::Error,
+    uapi::c,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The width of the output in pixels.
+pub const OUTPUT_WIDTH: i32 = 1920;
+/// The height of the output in pixels.
+pub const OUTPUT_HEIGHT: i32 = 1080;
+/// The refresh rate of the output in mHz.
+pub const OUTPUT_REFRESH: i32 = 60000;
+
+const GLOBAL_COMPOSITOR: u32 = 1;
+const GLOBAL_SHM: u32 = 2;
+const GLOBAL_SUBCOMPOSITOR: u32 = 3;
+const GLOBAL_WM_BASE: u32 = 4;
+const GLOBAL_SEAT: u32 = 5;
+const GLOBAL_OUTPUT: u32 = 6;
+
+/// A headless reference compositor.
+///
+/// See the [module documentation](self) for details.
+pub struct Headless {
+    state: Rc<State>,
+    shared: Rc<Shared>,
+    _destructor: Destructor,
+}
+
+/// An error emitted by a [`Headless`] compositor.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct HeadlessError(#[from] HeadlessErrorKind);
+
+#[derive(Debug, Error)]
+enum HeadlessErrorKind {
+    #[error("could not create the state")]
+    CreateState(#[source] StateError),
+    #[error("could not create the keymap")]
+    CreateKeymap(#[source] io::Error),
+    #[error("could not create a connection")]
+    Connect(#[source] StateError),
+}
+
+/// A surface of a [`Headless`] compositor.
+#[derive(Clone)]
+pub struct HeadlessSurface {
+    data: Rc<SurfaceData>,
+}
+
+/// A toplevel of a [`Headless`] compositor.
+#[derive(Clone)]
+pub struct HeadlessToplevel {
+    data: Rc<ToplevelData>,
+}
+
+/// A copy of the contents of a buffer.
+pub struct HeadlessImage {
+    width: i32,
+    height: i32,
+    stride: i32,
+    format: WlShmFormat,
+    data: Vec<u8>,
+}
+
+struct Shared {
+    start: Instant,
+    serial: Cell<u32>,
+    keymap: Rc<OwnedFd>,
+    surfaces: RefCell<Vec<Weak<SurfaceData>>>,
+    frames: RefCell<Vec<Rc<WlCallback>>>,
+    pointers: RefCell<Vec<Weak<WlPointer>>>,
+    keyboards: RefCell<Vec<Weak<WlKeyboard>>>,
+    pointer_focus: RefCell<Weak<WlSurface>>,
+    keyboard_focus: RefCell<Weak<WlSurface>>,
+}
+
+struct SurfaceData {
+    surface: Weak<WlSurface>,
+    pending: RefCell<SurfaceState>,
+    current: RefCell<Current>,
+    role: RefCell<Role>,
+    children: RefCell<Vec<Weak<SurfaceData>>>,
+    commits: Cell<u64>,
+}
+
+#[derive(Default)]
+struct SurfaceState {
+    buffer: Option<Option<Rc<WlBuffer>>>,
+    frames: Vec<Rc<WlCallback>>,
+    scale: Option<i32>,
+    transform: Option<WlOutputTransform>,
+}
+
+struct Current {
+    image: Option<Rc<HeadlessImage>>,
+    scale: i32,
+    transform: WlOutputTransform,
+}
+
+#[derive(Clone, Default)]
+enum Role {
+    #[default]
+    None,
+    Subsurface(Rc<SubsurfaceData>),
+    Xdg(Rc<XdgSurfaceData>),
+}
+
+struct SubsurfaceData {
+    parent: Weak<SurfaceData>,
+    sync: Cell<bool>,
+    position: Cell<(i32, i32)>,
+    pending_position: Cell<Option<(i32, i32)>>,
+    cached: RefCell<Option<SurfaceState>>,
+}
+
+struct XdgSurfaceData {
+    shared: Rc<Shared>,
+    xdg_surface: Weak<XdgSurface>,
+    surface: Weak<SurfaceData>,
+    role: RefCell<XdgRole>,
+    serials: RefCell<Vec<u32>>,
+    acked: Cell<Option<u32>>,
+    initial_configure: Cell<bool>,
+}
+
+#[derive(Clone, Default)]
+enum XdgRole {
+    #[default]
+    None,
+    Toplevel(Rc<ToplevelData>),
+    Popup(Weak<XdgPopup>, [i32; 4]),
+}
+
+struct ToplevelData {
+    xdg: Rc<XdgSurfaceData>,
+    toplevel: Weak<XdgToplevel>,
+    title: RefCell<String>,
+    app_id: RefCell<String>,
+    size: Cell<(i32, i32)>,
+}
+
+#[derive(Clone)]
+struct Handler {
+    shared: Rc<Shared>,
+}
+
+struct SurfaceImpl {
+    shared: Rc<Shared>,
+    data: Rc<SurfaceData>,
+}
+
+struct Pool {
+    fd: Rc<OwnedFd>,
+    size: Rc<Cell<i32>>,
+}
+
+struct Buffer {
+    fd: Rc<OwnedFd>,
+    pool_size: Rc<Cell<i32>>,
+    offset: i32,
+    width: i32,
+    height: i32,
+    stride: i32,
+    format: WlShmFormat,
+}
+
+struct SubsurfaceImpl {
+    surface: Rc<SurfaceData>,
+    data: Rc<SubsurfaceData>,
+}
+
+struct XdgSurfaceImpl {
+    data: Rc<XdgSurfaceData>,
+}
+
+struct ToplevelImpl {
+    data: Rc<ToplevelData>,
+}
+
+struct PopupImpl {
+    xdg: Rc<XdgSurfaceData>,
+}
+
+#[derive(Default)]
+struct Positioner {
+    size: (i32, i32),
+    anchor_rect: [i32; 4],
+    offset: (i32, i32),
+}
+
+impl Headless {
+    /// Creates a new headless compositor.
+    pub fn new() -> Result<Self, HeadlessError> {
+        let keymap = uapi::memfd_create("wl-proxy-keymap", c::MFD_CLOEXEC)
+            .map_err(|e| HeadlessErrorKind::CreateKeymap(e.into()))?;
+        let state = State::builder(Baseline::ALL_OF_THEM)
+            .without_server()
+            .with_log_prefix("headless")
+            .build()
+            .map_err(HeadlessErrorKind::CreateState)?;
+        state.set_default_forward_to_server(false);
+        state.delete_ids_on_destroy.set(true);
+        let shared = Rc::new(Shared {
+            start: Instant::now(),
+            serial: Default::default(),
+            keymap: Rc::new(keymap.into()),
+            surfaces: Default::default(),
+            frames: Default::default(),
+            pointers: Default::default(),
+            keyboards: Default::default(),
+            pointer_focus: Default::default(),
+            keyboard_focus: Default::default(),
+        });
+        state.set_handler(Handler {
+            shared: shared.clone(),
+        });
+        Ok(Self {
+            _destructor: state.create_destructor(),
+            state,
+            shared,
+        })
+    }
+
+    /// Returns the state of the compositor.
+    ///
+    /// The state must be dispatched for the compositor to make progress.
+    pub fn state(&self) -> &Rc<State> {
+        &self.state
+    }
+
+    /// Creates a new client connection.
+    ///
+    /// The returned file descriptor can be used as the server connection of a proxy or
+    /// passed to a client via `WAYLAND_SOCKET`.
+    pub fn connect(&self) -> Result<OwnedFd, HeadlessError> {
+        let (client, fd) = self.state.connect().map_err(HeadlessErrorKind::Connect)?;
+        client.display().set_handler(self.handler());
+        Ok(fd)
+    }
+
+    /// Returns the live surfaces in the order in which they were created.
+    pub fn surfaces(&self) -> Vec<HeadlessSurface> {
+        let mut surfaces = self.shared.surfaces.borrow_mut();
+        surfaces.retain(|s| s.strong_count() > 0);
+        surfaces
+            .iter()
+            .filter_map(|s| s.upgrade())
+            .map(|data| HeadlessSurface { data })
+            .collect()
+    }
+
+    /// Returns the live toplevels in the order in which their surfaces were created.
+    pub fn toplevels(&self) -> Vec<HeadlessToplevel> {
+        self.surfaces()
+            .iter()
+            .filter_map(|s| s.toplevel())
+            .collect()
+    }
+
+    /// Completes the frame callbacks of all applied surface states.
+    ///
+    /// Returns the number of completed callbacks.
+    pub fn frame(&self) -> usize {
+        let time = self.shared.time();
+        let frames = self.shared.frames.take();
+        for callback in &frames {
+            callback.send_done(time);
+            callback.delete_id();
+        }
+        frames.len()
+    }
+
+    /// Moves the keyboard focus to a surface.
+    ///
+    /// All keyboards of the client of the surface receive an enter event without pressed
+    /// keys. The keyboards of the previously focused surface receive a leave event.
+    pub fn keyboard_enter(&self, surface: &HeadlessSurface) {
+        let shared = &self.shared;
+        let Some(wl_surface) = surface.data.surface.upgrade() else {
+            return;
+        };
+        if let Some(old) = shared.keyboard_focus.borrow().upgrade() {
+            let serial = shared.next_serial();
+            for keyboard in shared.keyboards_of(&old) {
+                keyboard.send_leave(serial, &old);
+            }
+        }
+        *shared.keyboard_focus.borrow_mut() = Rc::downgrade(&wl_surface);
+        let serial = shared.next_serial();
+        for keyboard in shared.keyboards_of(&wl_surface) {
+            keyboard.send_enter(serial, &wl_surface, &[]);
+            keyboard.send_modifiers(serial, 0, 0, 0, 0);
+        }
+    }
+
+    /// Sends a key event to the keyboards of the focused surface.
+    ///
+    /// `key` is a Linux evdev key code.
+    pub fn key(&self, key: u32, pressed: bool) {
+        let shared = &self.shared;
+        let Some(focus) = shared.keyboard_focus.borrow().upgrade() else {
+            return;
+        };
+        let state = match pressed {
+            true => WlKeyboardKeyState::PRESSED,
+            false => WlKeyboardKeyState::RELEASED,
+        };
+        let serial = shared.next_serial();
+        let time = shared.time();
+        for keyboard in shared.keyboards_of(&focus) {
+            keyboard.send_key(serial, time, key, state);
+        }
+    }
+
+    /// Moves the pointer focus to a position within a surface.
+    ///
+    /// The coordinates are in the surface-local coordinate space.
+    pub fn pointer_enter(&self, surface: &HeadlessSurface, x: f64, y: f64) {
+        let shared = &self.shared;
+        let Some(wl_surface) = surface.data.surface.upgrade() else {
+            return;
+        };
+        if let Some(old) = shared.pointer_focus.borrow().upgrade() {
+            let serial = shared.next_serial();
+            for pointer in shared.pointers_of(&old) {
+                pointer.send_leave(serial, &old);
+                pointer_frame(&pointer);
+            }
+        }
+        *shared.pointer_focus.borrow_mut() = Rc::downgrade(&wl_surface);
+        let serial = shared.next_serial();
+        let (x, y) = (Fixed::from_f64_lossy(x), Fixed::from_f64_lossy(y));
+        for pointer in shared.pointers_of(&wl_surface) {
+            pointer.send_enter(serial, &wl_surface, x, y);
+            pointer_frame(&pointer);
+        }
+    }
+
+    /// Moves the pointer within the focused surface.
+    pub fn pointer_motion(&self, x: f64, y: f64) {
+        let shared = &self.shared;
+        let Some(focus) = shared.pointer_focus.borrow().upgrade() else {
+            return;
+        };
+        let time = shared.time();
+        let (x, y) = (Fixed::from_f64_lossy(x), Fixed::from_f64_lossy(y));
+        for pointer in shared.pointers_of(&focus) {
+            pointer.send_motion(time, x, y);
+            pointer_frame(&pointer);
+        }
+    }
+
+    /// Sends a button event to the pointers of the focused surface.
+    ///
+    /// `button` is a Linux evdev button code.
+    pub fn pointer_button(&self, button: u32, pressed: bool) {
+        let shared = &self.shared;
+        let Some(focus) = shared.pointer_focus.borrow().upgrade() else {
+            return;
+        };
+        let state = match pressed {
+            true => WlPointerButtonState::PRESSED,
+            false => WlPointerButtonState::RELEASED,
+        };
+        let serial = shared.next_serial();
+        let time = shared.time();
+        for pointer in shared.pointers_of(&focus) {
+            pointer.send_button(serial, time, button, state);
+            pointer_frame(&pointer);
+        }
+    }
+
+    fn handler(&self) -> Handler {
+        Handler {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl HeadlessSurface {
+    /// Returns the wl_surface object, if it still exists.
+    pub fn wl_surface(&self) -> Option<Rc<WlSurface>> {
+        self.data.surface.upgrade()
+    }
+
+    /// Returns the client of the surface, if it is still connected.
+    pub fn client(&self) -> Option<Rc<Client>> {
+        self.wl_surface()?.client()
+    }
+
+    /// Returns the contents of the buffer of the current surface state.
+    ///
+    /// Returns `None` if no buffer is attached or if the surface is not mapped.
+    pub fn image(&self) -> Option<Rc<HeadlessImage>> {
+        self.data.current.borrow().image.clone()
+    }
+
+    /// Returns the buffer scale of the current surface state.
+    pub fn buffer_scale(&self) -> i32 {
+        self.data.current.borrow().scale
+    }
+
+    /// Returns the buffer transform of the current surface state.
+    pub fn buffer_transform(&self) -> WlOutputTransform {
+        self.data.current.borrow().transform
+    }
+
+    /// Returns the number of applied surface states.
+    ///
+    /// The state of a synchronized subsurface is applied when its parent applies its
+    /// own state.
+    pub fn commits(&self) -> u64 {
+        self.data.commits.get()
+    }
+
+    /// Returns the parent of the surface, if it is a subsurface.
+    pub fn parent(&self) -> Option<HeadlessSurface> {
+        let Role::Subsurface(sub) = &*self.data.role.borrow() else {
+            return None;
+        };
+        sub.parent.upgrade().map(|data| HeadlessSurface { data })
+    }
+
+    /// Returns the position of the surface relative to its parent.
+    ///
+    /// Returns `(0, 0)` if the surface is not a subsurface.
+    pub fn position(&self) -> (i32, i32) {
+        match &*self.data.role.borrow() {
+            Role::Subsurface(sub) => sub.position.get(),
+            _ => (0, 0),
+        }
+    }
+
+    /// Returns whether the surface is a synchronized subsurface.
+    ///
+    /// This takes the synchronization mode of the ancestors into account.
+    pub fn is_synchronized(&self) -> bool {
+        match &*self.data.role.borrow() {
+            Role::Subsurface(sub) => sub.is_synchronized(),
+            _ => false,
+        }
+    }
+
+    /// Returns the toplevel of the surface, if it has the xdg_toplevel role.
+    pub fn toplevel(&self) -> Option<HeadlessToplevel> {
+        let Role::Xdg(xdg) = &*self.data.role.borrow() else {
+            return None;
+        };
+        match &*xdg.role.borrow() {
+            XdgRole::Toplevel(data) => Some(HeadlessToplevel { data: data.clone() }),
+            _ => None,
+        }
+    }
+
+    /// Returns the position and size of the popup, if the surface has the xdg_popup
+    /// role.
+    ///
+    /// The position is relative to the parent surface.
+    pub fn popup_geometry(&self) -> Option<[i32; 4]> {
+        let Role::Xdg(xdg) = &*self.data.role.borrow() else {
+            return None;
+        };
+        match &*xdg.role.borrow() {
+            XdgRole::Popup(_, geometry) => Some(*geometry),
+            _ => None,
+        }
+    }
+}
+
+impl HeadlessToplevel {
+    /// Returns the surface of the toplevel, if it still exists.
+    pub fn surface(&self) -> Option<HeadlessSurface> {
+        self.data
+            .xdg
+            .surface
+            .upgrade()
+            .map(|data| HeadlessSurface { data })
+    }
+
+    /// Returns the title of the toplevel.
+    pub fn title(&self) -> String {
+        self.data.title.borrow().clone()
+    }
+
+    /// Returns the app ID of the toplevel.
+    pub fn app_id(&self) -> String {
+        self.data.app_id.borrow().clone()
+    }
+
+    /// Returns whether the toplevel is mapped.
+    pub fn is_mapped(&self) -> bool {
+        self.surface().and_then(|s| s.image()).is_some()
+    }
+
+    /// Returns the size of the last configure event.
+    pub fn size(&self) -> (i32, i32) {
+        self.data.size.get()
+    }
+
+    /// Returns the last serial that was acknowledged by the client.
+    pub fn acked_serial(&self) -> Option<u32> {
+        self.data.xdg.acked.get()
+    }
+
+    /// Sends a configure sequence with the given size.
+    ///
+    /// Returns the serial of the xdg_surface.configure event or `None` if the toplevel
+    /// has been destroyed.
+    pub fn configure(&self, width: i32, height: i32) -> Option<u32> {
+        self.data.configure(width, height)
+    }
+
+    /// Asks the client to close the toplevel.
+    pub fn close(&self) {
+        if let Some(toplevel) = self.data.toplevel.upgrade() {
+            toplevel.send_close();
+        }
+    }
+}
+
+impl HeadlessImage {
+    /// Returns the width of the image in pixels.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Returns the height of the image in pixels.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns the number of bytes per row.
+    pub fn stride(&self) -> i32 {
+        self.stride
+    }
+
+    /// Returns the format of the image.
+    pub fn format(&self) -> WlShmFormat {
+        self.format
+    }
+
+    /// Returns the contents of the image.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the pixel at the given position.
+    ///
+    /// The pixel is read as a native-endian 32-bit integer as described by the
+    /// `ARGB8888` and `XRGB8888` formats.
+    pub fn pixel(&self, x: i32, y: i32) -> Option<u32> {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return None;
+        }
+        let offset = y as usize * self.stride as usize + x as usize * 4;
+        let bytes = self.data.get(offset..offset + 4)?;
+        Some(u32::from_ne_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+impl Shared {
+    fn next_serial(&self) -> u32 {
+        let serial = self.serial.get().wrapping_add(1);
+        self.serial.set(serial);
+        serial
+    }
+
+    fn time(&self) -> u32 {
+        self.start.elapsed().as_millis() as u32
+    }
+
+    fn pointers_of(&self, surface: &WlSurface) -> Vec<Rc<WlPointer>> {
+        objects_of(&mut self.pointers.borrow_mut(), surface)
+    }
+
+    fn keyboards_of(&self, surface: &WlSurface) -> Vec<Rc<WlKeyboard>> {
+        objects_of(&mut self.keyboards.borrow_mut(), surface)
+    }
+}
+
+fn objects_of<T: Object>(objects: &mut Vec<Weak<T>>, surface: &WlSurface) -> Vec<Rc<T>> {
+    objects.retain(|o| o.strong_count() > 0);
+    let Some(client) = surface.client() else {
+        return vec![];
+    };
+    objects
+        .iter()
+        .filter_map(|o| o.upgrade())
+        .filter(|o| o.client().is_some_and(|c| Rc::ptr_eq(&c, &client)))
+        .collect()
+}
+
+fn pointer_frame(pointer: &WlPointer) {
+    if pointer.version() >= WlPointer::MSG__FRAME__SINCE {
+        pointer.send_frame();
+    }
+}
+
+fn surface_data(surface: &WlSurface) -> Option<Rc<SurfaceData>> {
+    let handler = surface.try_get_handler_ref::<SurfaceImpl>().ok()?;
+    Some(handler.data.clone())
+}
+
+fn post_error(object: Rc<dyn Object>, code: u32, message: &str) {
+    if let Some(client) = object.client() {
+        client.display().send_error(object, code, message);
+    }
+}
+
+impl SurfaceState {
+    fn merge(&mut self, newer: SurfaceState) {
+        if newer.buffer.is_some() {
+            self.buffer = newer.buffer;
+        }
+        self.frames.extend(newer.frames);
+        if newer.scale.is_some() {
+            self.scale = newer.scale;
+        }
+        if newer.transform.is_some() {
+            self.transform = newer.transform;
+        }
+    }
+}
+
+impl SurfaceData {
+    fn subsurface(&self) -> Option<Rc<SubsurfaceData>> {
+        match &*self.role.borrow() {
+            Role::Subsurface(sub) => Some(sub.clone()),
+            _ => None,
+        }
+    }
+
+    fn xdg(&self) -> Option<Rc<XdgSurfaceData>> {
+        match &*self.role.borrow() {
+            Role::Xdg(xdg) => Some(xdg.clone()),
+            _ => None,
+        }
+    }
+
+    fn is_ancestor_of(self: &Rc<Self>, other: &Rc<Self>) -> bool {
+        let mut cur = other.clone();
+        loop {
+            if Rc::ptr_eq(self, &cur) {
+                return true;
+            }
+            let Some(parent) = cur.subsurface().and_then(|s| s.parent.upgrade()) else {
+                return false;
+            };
+            cur = parent;
+        }
+    }
+
+    fn apply(self: &Rc<Self>, shared: &Shared, mut state: SurfaceState) {
+        let sub = self.subsurface();
+        if let Some(sub) = &sub
+            && let Some(mut cached) = sub.cached.take()
+        {
+            cached.merge(state);
+            state = cached;
+        }
+        {
+            let current = &mut *self.current.borrow_mut();
+            if let Some(buffer) = state.buffer {
+                current.image = buffer.and_then(|b| read_buffer(&b));
+            }
+            if let Some(scale) = state.scale {
+                current.scale = scale;
+            }
+            if let Some(transform) = state.transform {
+                current.transform = transform;
+            }
+        }
+        shared.frames.borrow_mut().extend(state.frames);
+        self.commits.set(self.commits.get() + 1);
+        let children: Vec<_> = {
+            let mut children = self.children.borrow_mut();
+            children.retain(|c| c.strong_count() > 0);
+            children.iter().filter_map(|c| c.upgrade()).collect()
+        };
+        for child in children {
+            let Some(child_sub) = child.subsurface() else {
+                continue;
+            };
+            if !child_sub.parent.ptr_eq(&Rc::downgrade(self)) {
+                continue;
+            }
+            if let Some(position) = child_sub.pending_position.take() {
+                child_sub.position.set(position);
+            }
+            if let Some(cached) = child_sub.cached.take() {
+                child.apply(shared, cached);
+            }
+        }
+        if let Some(xdg) = self.xdg()
+            && !xdg.initial_configure.get()
+        {
+            xdg.send_initial_configure();
+        }
+    }
+}
+
+impl SubsurfaceData {
+    fn is_synchronized(&self) -> bool {
+        if self.sync.get() {
+            return true;
+        }
+        self.parent
+            .upgrade()
+            .and_then(|p| p.subsurface())
+            .is_some_and(|s| s.is_synchronized())
+    }
+}
+
+impl XdgSurfaceData {
+    fn send_configure(&self) -> Option<u32> {
+        let xdg_surface = self.xdg_surface.upgrade()?;
+        let serial = self.shared.next_serial();
+        self.serials.borrow_mut().push(serial);
+        xdg_surface.send_configure(serial);
+        Some(serial)
+    }
+
+    fn send_initial_configure(&self) {
+        let role = self.role.borrow().clone();
+        match role {
+            XdgRole::None => return,
+            XdgRole::Toplevel(toplevel) => {
+                toplevel.configure(0, 0);
+            }
+            XdgRole::Popup(popup, [x, y, width, height]) => {
+                if let Some(popup) = popup.upgrade() {
+                    popup.send_configure(x, y, width, height);
+                }
+                self.send_configure();
+            }
+        }
+        self.initial_configure.set(true);
+    }
+}
+
+impl ToplevelData {
+    fn configure(&self, width: i32, height: i32) -> Option<u32> {
+        let toplevel = self.toplevel.upgrade()?;
+        self.size.set((width, height));
+        toplevel.send_configure(width, height, &[]);
+        self.xdg.send_configure()
+    }
+}
+
+fn read_buffer(buffer: &WlBuffer) -> Option<Rc<HeadlessImage>> {
+    let res = match buffer.try_get_handler_ref::<Buffer>() {
+        Ok(b) => b.read(),
+        Err(_) => return None,
+    };
+    buffer.send_release();
+    match res {
+        Ok(image) => Some(Rc::new(image)),
+        Err(e) => {
+            log::warn!(
+                "Could not read the contents of a buffer: {}",
+                Report::new(e)
+            );
+            None
+        }
+    }
+}
+
+impl Buffer {
+    fn read(&self) -> io::Result<HeadlessImage> {
+        let len = self.stride as usize * self.height as usize;
+        if self.offset as i64 + len as i64 > self.pool_size.get() as i64 {
+            return Err(io::Error::other("the buffer is out of bounds"));
+        }
+        let mut data = vec![0u8; len];
+        let mut pos = 0;
+        while pos < len {
+            let offset = self.offset as c::off_t + pos as c::off_t;
+            let n = uapi::pread(self.fd.as_raw_fd(), &mut data[pos..], offset)?.len();
+            if n == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            pos += n;
+        }
+        Ok(HeadlessImage {
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+            format: self.format,
+            data,
+        })
+    }
+}
+
+impl StateHandler for Handler {
+    fn new_client(&mut self, client: &Rc<Client>) {
+        client.display().set_handler(self.clone());
+    }
+}
+
+impl WlDisplayHandler for Handler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(self.shared.serial.get());
+        callback.delete_id();
+    }
+
+    fn handle_get_registry(&mut self, _slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        let globals = [
+            (
+                GLOBAL_COMPOSITOR,
+                WlCompositor::INTERFACE,
+                WlCompositor::XML_VERSION,
+            ),
+            (GLOBAL_SHM, WlShm::INTERFACE, WlShm::XML_VERSION),
+            (
+                GLOBAL_SUBCOMPOSITOR,
+                WlSubcompositor::INTERFACE,
+                WlSubcompositor::XML_VERSION,
+            ),
+            (GLOBAL_WM_BASE, XdgWmBase::INTERFACE, XdgWmBase::XML_VERSION),
+            (GLOBAL_SEAT, WlSeat::INTERFACE, WlSeat::XML_VERSION),
+            (GLOBAL_OUTPUT, WlOutput::INTERFACE, WlOutput::XML_VERSION),
+        ];
+        for (name, interface, version) in globals {
+            registry.send_global(name, interface, version);
+        }
+        registry.set_handler(self.clone());
+    }
+}
+
+impl WlRegistryHandler for Handler {
+    fn handle_bind(&mut self, _slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        let expected = match name {
+            GLOBAL_COMPOSITOR => WlCompositor::INTERFACE,
+            GLOBAL_SHM => WlShm::INTERFACE,
+            GLOBAL_SUBCOMPOSITOR => WlSubcompositor::INTERFACE,
+            GLOBAL_WM_BASE => XdgWmBase::INTERFACE,
+            GLOBAL_SEAT => WlSeat::INTERFACE,
+            GLOBAL_OUTPUT => WlOutput::INTERFACE,
+            _ => ObjectInterface::WlDisplay,
+        };
+        if id.interface() != expected {
+            log::warn!(
+                "A client tried to bind global {name} as {}",
+                id.interface_name(),
+            );
+            if let Some(client) = id.client() {
+                client.disconnect();
+            }
+            return;
+        }
+        if let Some(compositor) = id.try_downcast::<WlCompositor>() {
+            compositor.set_handler(self.clone());
+        } else if let Some(shm) = id.try_downcast::<WlShm>() {
+            shm.send_format(WlShmFormat::ARGB8888);
+            shm.send_format(WlShmFormat::XRGB8888);
+            shm.set_handler(self.clone());
+        } else if let Some(subcompositor) = id.try_downcast::<WlSubcompositor>() {
+            subcompositor.set_handler(self.clone());
+        } else if let Some(wm_base) = id.try_downcast::<XdgWmBase>() {
+            wm_base.set_handler(self.clone());
+        } else if let Some(seat) = id.try_downcast::<WlSeat>() {
+            seat.send_capabilities(WlSeatCapability::POINTER | WlSeatCapability::KEYBOARD);
+            if seat.version() >= WlSeat::MSG__NAME__SINCE {
+                seat.send_name("headless");
+            }
+            seat.set_handler(self.clone());
+        } else if let Some(output) = id.try_downcast::<WlOutput>() {
+            send_output_info(&output);
+            output.set_handler(self.clone());
+        }
+    }
+}
+
+fn send_output_info(output: &WlOutput) {
+    output.send_geometry(
+        0,
+        0,
+        0,
+        0,
+        WlOutputSubpixel::UNKNOWN,
+        "wl-proxy",
+        "headless",
+        WlOutputTransform::NORMAL,
+    );
+    output.send_mode(
+        WlOutputMode::CURRENT,
+        OUTPUT_WIDTH,
+        OUTPUT_HEIGHT,
+        OUTPUT_REFRESH,
+    );
+    if output.version() >= WlOutput::MSG__SCALE__SINCE {
+        output.send_scale(1);
+    }
+    if output.version() >= WlOutput::MSG__NAME__SINCE {
+        output.send_name("HEADLESS-1");
+        output.send_description("wl-proxy headless output");
+    }
+    if output.version() >= WlOutput::MSG__DONE__SINCE {
+        output.send_done();
+    }
+}
+
+impl WlCompositorHandler for Handler {
+    fn handle_create_surface(&mut self, _slf: &Rc<WlCompositor>, id: &Rc<WlSurface>) {
+        let data = Rc::new(SurfaceData {
+            surface: Rc::downgrade(id),
+            pending: Default::default(),
+            current: RefCell::new(Current {
+                image: None,
+                scale: 1,
+                transform: WlOutputTransform::NORMAL,
+            }),
+            role: Default::default(),
+            children: Default::default(),
+            commits: Default::default(),
+        });
+        self.shared.surfaces.borrow_mut().push(Rc::downgrade(&data));
+        id.set_handler(SurfaceImpl {
+            shared: self.shared.clone(),
+            data,
+        });
+    }
+}
+
+impl WlSurfaceHandler for SurfaceImpl {
+    fn handle_attach(
+        &mut self,
+        _slf: &Rc<WlSurface>,
+        buffer: Option<&Rc<WlBuffer>>,
+        _x: i32,
+        _y: i32,
+    ) {
+        self.data.pending.borrow_mut().buffer = Some(buffer.cloned());
+    }
+
+    fn handle_frame(&mut self, _slf: &Rc<WlSurface>, callback: &Rc<WlCallback>) {
+        self.data.pending.borrow_mut().frames.push(callback.clone());
+    }
+
+    fn handle_set_buffer_transform(&mut self, slf: &Rc<WlSurface>, transform: WlOutputTransform) {
+        if transform.0 > WlOutputTransform::FLIPPED_270.0 {
+            post_error(
+                slf.clone(),
+                WlSurfaceError::INVALID_TRANSFORM.0,
+                "the buffer transform is invalid",
+            );
+            return;
+        }
+        self.data.pending.borrow_mut().transform = Some(transform);
+    }
+
+    fn handle_set_buffer_scale(&mut self, slf: &Rc<WlSurface>, scale: i32) {
+        if scale < 1 {
+            post_error(
+                slf.clone(),
+                WlSurfaceError::INVALID_SCALE.0,
+                "the buffer scale must be positive",
+            );
+            return;
+        }
+        self.data.pending.borrow_mut().scale = Some(scale);
+    }
+
+    fn handle_commit(&mut self, _slf: &Rc<WlSurface>) {
+        let data = &self.data;
+        if let Some(xdg) = data.xdg() {
+            let Some(xdg_surface) = xdg.xdg_surface.upgrade() else {
+                return;
+            };
+            if matches!(*xdg.role.borrow(), XdgRole::None) {
+                post_error(
+                    xdg_surface.clone(),
+                    XdgSurfaceError::NOT_CONSTRUCTED.0,
+                    "the xdg_surface has no role object",
+                );
+                return;
+            }
+            let has_buffer = matches!(data.pending.borrow().buffer, Some(Some(_)));
+            if has_buffer && xdg.acked.get().is_none() {
+                post_error(
+                    xdg_surface.clone(),
+                    XdgSurfaceError::UNCONFIGURED_BUFFER.0,
+                    "a buffer was attached before the first configure was acknowledged",
+                );
+                return;
+            }
+        }
+        let pending = mem::take(&mut *data.pending.borrow_mut());
+        if let Some(sub) = data.subsurface()
+            && sub.is_synchronized()
+        {
+            sub.cached
+                .borrow_mut()
+                .get_or_insert_default()
+                .merge(pending);
+            return;
+        }
+        data.apply(&self.shared, pending);
+    }
+}
+
+impl WlShmHandler for Handler {
+    fn handle_create_pool(
+        &mut self,
+        slf: &Rc<WlShm>,
+        id: &Rc<WlShmPool>,
+        fd: &Rc<OwnedFd>,
+        size: i32,
+    ) {
+        if size <= 0 {
+            post_error(
+                slf.clone(),
+                WlShmError::INVALID_FD.0,
+                "the pool size must be positive",
+            );
+            return;
+        }
+        id.set_handler(Pool {
+            fd: fd.clone(),
+            size: Rc::new(Cell::new(size)),
+        });
+    }
+}
+
+impl WlShmPoolHandler for Pool {
+    fn handle_create_buffer(
+        &mut self,
+        slf: &Rc<WlShmPool>,
+        id: &Rc<WlBuffer>,
+        offset: i32,
+        width: i32,
+        height: i32,
+        stride: i32,
+        format: WlShmFormat,
+    ) {
+        if format != WlShmFormat::ARGB8888 && format != WlShmFormat::XRGB8888 {
+            post_error(
+                slf.clone(),
+                WlShmError::INVALID_FORMAT.0,
+                "the format is not supported",
+            );
+            return;
+        }
+        let in_bounds = offset >= 0
+            && width > 0
+            && height > 0
+            && stride as i64 >= width as i64 * 4
+            && offset as i64 + stride as i64 * height as i64 <= self.size.get() as i64;
+        if !in_bounds {
+            post_error(
+                slf.clone(),
+                WlShmError::INVALID_STRIDE.0,
+                "the buffer does not fit into the pool",
+            );
+            return;
+        }
+        id.set_handler(Buffer {
+            fd: self.fd.clone(),
+            pool_size: self.size.clone(),
+            offset,
+            width,
+            height,
+            stride,
+            format,
+        });
+    }
+
+    fn handle_resize(&mut self, slf: &Rc<WlShmPool>, size: i32) {
+        if size < self.size.get() {
+            post_error(
+                slf.clone(),
+                WlShmError::INVALID_FD.0,
+                "the pool cannot be shrunk",
+            );
+            return;
+        }
+        self.size.set(size);
+    }
+}
+
+impl WlBufferHandler for Buffer {}
+
+impl WlSubcompositorHandler for Handler {
+    fn handle_get_subsurface(
+        &mut self,
+        slf: &Rc<WlSubcompositor>,
+        id: &Rc<WlSubsurface>,
+        surface: &Rc<WlSurface>,
+        parent: &Rc<WlSurface>,
+    ) {
+        let (Some(surface), Some(parent)) = (surface_data(surface), surface_data(parent)) else {
+            return;
+        };
+        if !matches!(*surface.role.borrow(), Role::None) {
+            post_error(
+                slf.clone(),
+                WlSubcompositorError::BAD_SURFACE.0,
+                "the surface already has a role",
+            );
+            return;
+        }
+        if surface.is_ancestor_of(&parent) {
+            post_error(
+                slf.clone(),
+                WlSubcompositorError::BAD_PARENT.0,
+                "the parent is the surface or one of its descendants",
+            );
+            return;
+        }
+        let data = Rc::new(SubsurfaceData {
+            parent: Rc::downgrade(&parent),
+            sync: Cell::new(true),
+            position: Default::default(),
+            pending_position: Default::default(),
+            cached: Default::default(),
+        });
+        *surface.role.borrow_mut() = Role::Subsurface(data.clone());
+        parent.children.borrow_mut().push(Rc::downgrade(&surface));
+        id.set_handler(SubsurfaceImpl { surface, data });
+    }
+}
+
+impl WlSubsurfaceHandler for SubsurfaceImpl {
+    fn handle_destroy(&mut self, _slf: &Rc<WlSubsurface>) {
+        *self.surface.role.borrow_mut() = Role::None;
+        self.surface.current.borrow_mut().image = None;
+    }
+
+    fn handle_set_position(&mut self, _slf: &Rc<WlSubsurface>, x: i32, y: i32) {
+        self.data.pending_position.set(Some((x, y)));
+    }
+
+    fn handle_set_sync(&mut self, _slf: &Rc<WlSubsurface>) {
+        self.data.sync.set(true);
+    }
+
+    fn handle_set_desync(&mut self, _slf: &Rc<WlSubsurface>) {
+        self.data.sync.set(false);
+    }
+}
+
+impl XdgWmBaseHandler for Handler {
+    fn handle_create_positioner(&mut self, _slf: &Rc<XdgWmBase>, id: &Rc<XdgPositioner>) {
+        id.set_handler(Positioner::default());
+    }
+
+    fn handle_get_xdg_surface(
+        &mut self,
+        slf: &Rc<XdgWmBase>,
+        id: &Rc<XdgSurface>,
+        surface: &Rc<WlSurface>,
+    ) {
+        let Some(surface) = surface_data(surface) else {
+            return;
+        };
+        if !matches!(*surface.role.borrow(), Role::None) {
+            post_error(
+                slf.clone(),
+                XdgWmBaseError::ROLE.0,
+                "the surface already has a role",
+            );
+            return;
+        }
+        let data = Rc::new(XdgSurfaceData {
+            shared: self.shared.clone(),
+            xdg_surface: Rc::downgrade(id),
+            surface: Rc::downgrade(&surface),
+            role: Default::default(),
+            serials: Default::default(),
+            acked: Default::default(),
+            initial_configure: Default::default(),
+        });
+        *surface.role.borrow_mut() = Role::Xdg(data.clone());
+        id.set_handler(XdgSurfaceImpl { data });
+    }
+}
+
+impl XdgPositionerHandler for Positioner {
+    fn handle_set_size(&mut self, _slf: &Rc<XdgPositioner>, width: i32, height: i32) {
+        self.size = (width, height);
+    }
+
+    fn handle_set_anchor_rect(
+        &mut self,
+        _slf: &Rc<XdgPositioner>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.anchor_rect = [x, y, width, height];
+    }
+
+    fn handle_set_offset(&mut self, _slf: &Rc<XdgPositioner>, x: i32, y: i32) {
+        self.offset = (x, y);
+    }
+}
+
+impl Positioner {
+    fn geometry(&self) -> [i32; 4] {
+        let [x, y, ..] = self.anchor_rect;
+        [
+            x + self.offset.0,
+            y + self.offset.1,
+            self.size.0,
+            self.size.1,
+        ]
+    }
+}
+
+impl XdgSurfaceHandler for XdgSurfaceImpl {
+    fn handle_destroy(&mut self, _slf: &Rc<XdgSurface>) {
+        if let Some(surface) = self.data.surface.upgrade() {
+            *surface.role.borrow_mut() = Role::None;
+            surface.current.borrow_mut().image = None;
+        }
+    }
+
+    fn handle_get_toplevel(&mut self, slf: &Rc<XdgSurface>, id: &Rc<XdgToplevel>) {
+        if !matches!(*self.data.role.borrow(), XdgRole::None) {
+            post_error(
+                slf.clone(),
+                XdgSurfaceError::ALREADY_CONSTRUCTED.0,
+                "the xdg_surface already has a role object",
+            );
+            return;
+        }
+        let data = Rc::new(ToplevelData {
+            xdg: self.data.clone(),
+            toplevel: Rc::downgrade(id),
+            title: Default::default(),
+            app_id: Default::default(),
+            size: Default::default(),
+        });
+        *self.data.role.borrow_mut() = XdgRole::Toplevel(data.clone());
+        id.set_handler(ToplevelImpl { data });
+    }
+
+    fn handle_get_popup(
+        &mut self,
+        slf: &Rc<XdgSurface>,
+        id: &Rc<XdgPopup>,
+        _parent: Option<&Rc<XdgSurface>>,
+        positioner: &Rc<XdgPositioner>,
+    ) {
+        if !matches!(*self.data.role.borrow(), XdgRole::None) {
+            post_error(
+                slf.clone(),
+                XdgSurfaceError::ALREADY_CONSTRUCTED.0,
+                "the xdg_surface already has a role object",
+            );
+            return;
+        }
+        let geometry = positioner
+            .try_get_handler_ref::<Positioner>()
+            .map(|p| p.geometry())
+            .unwrap_or_default();
+        *self.data.role.borrow_mut() = XdgRole::Popup(Rc::downgrade(id), geometry);
+        id.set_handler(PopupImpl {
+            xdg: self.data.clone(),
+        });
+    }
+
+    fn handle_ack_configure(&mut self, slf: &Rc<XdgSurface>, serial: u32) {
+        let mut serials = self.data.serials.borrow_mut();
+        let Some(idx) = serials.iter().position(|&s| s == serial) else {
+            drop(serials);
+            post_error(
+                slf.clone(),
+                XdgSurfaceError::INVALID_SERIAL.0,
+                "the serial was not sent in a configure event",
+            );
+            return;
+        };
+        serials.drain(..=idx);
+        self.data.acked.set(Some(serial));
+    }
+}
+
+impl XdgToplevelHandler for ToplevelImpl {
+    fn handle_destroy(&mut self, _slf: &Rc<XdgToplevel>) {
+        let xdg = &self.data.xdg;
+        *xdg.role.borrow_mut() = XdgRole::None;
+        if let Some(surface) = xdg.surface.upgrade() {
+            surface.current.borrow_mut().image = None;
+        }
+    }
+
+    fn handle_set_title(&mut self, _slf: &Rc<XdgToplevel>, title: &str) {
+        *self.data.title.borrow_mut() = title.to_string();
+    }
+
+    fn handle_set_app_id(&mut self, _slf: &Rc<XdgToplevel>, app_id: &str) {
+        *self.data.app_id.borrow_mut() = app_id.to_string();
+    }
+}
+
+impl XdgPopupHandler for PopupImpl {
+    fn handle_destroy(&mut self, _slf: &Rc<XdgPopup>) {
+        *self.xdg.role.borrow_mut() = XdgRole::None;
+        if let Some(surface) = self.xdg.surface.upgrade() {
+            surface.current.borrow_mut().image = None;
+        }
+    }
+}
+
+impl WlSeatHandler for Handler {
+    fn handle_get_pointer(&mut self, _slf: &Rc<WlSeat>, id: &Rc<WlPointer>) {
+        self.shared.pointers.borrow_mut().push(Rc::downgrade(id));
+    }
+
+    fn handle_get_keyboard(&mut self, _slf: &Rc<WlSeat>, id: &Rc<WlKeyboard>) {
+        id.send_keymap(WlKeyboardKeymapFormat::NO_KEYMAP, &self.shared.keymap, 0);
+        if id.version() >= WlKeyboard::MSG__REPEAT_INFO__SINCE {
+            id.send_repeat_info(25, 600);
+        }
+        self.shared.keyboards.borrow_mut().push(Rc::downgrade(id));
+    }
+}
+
+impl WlOutputHandler for Handler {}
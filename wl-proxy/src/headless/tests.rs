@@ -0,0 +1,191 @@
+use {
+    crate::{
+        object::ObjectCoreApi,
+        protocols::{
+            wayland::{
+                wl_buffer::WlBuffer,
+                wl_compositor::WlCompositor,
+                wl_keyboard::{WlKeyboard, WlKeyboardHandler, WlKeyboardKeyState},
+                wl_seat::WlSeat,
+                wl_shm::{WlShm, WlShmFormat},
+                wl_subcompositor::WlSubcompositor,
+                wl_surface::WlSurface,
+            },
+            xdg_shell::{
+                xdg_surface::{XdgSurface, XdgSurfaceHandler},
+                xdg_wm_base::XdgWmBase,
+            },
+        },
+        test_framework::headless::{Done, HeadlessEnv},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        os::fd::OwnedFd,
+        rc::Rc,
+    },
+    uapi::c,
+};
+
+fn buffer(shm: &WlShm, width: i32, height: i32, pixel: u32) -> Rc<WlBuffer> {
+    let fd = uapi::memfd_create("test", c::MFD_CLOEXEC).unwrap();
+    let data: Vec<u8> = (0..width * height)
+        .flat_map(|_| pixel.to_ne_bytes())
+        .collect();
+    uapi::write(fd.raw(), &data[..]).unwrap();
+    let fd: OwnedFd = fd.into();
+    let pool = shm.new_send_create_pool(&Rc::new(fd), data.len() as i32);
+    let buffer = pool.new_send_create_buffer(0, width, height, width * 4, WlShmFormat::ARGB8888);
+    pool.send_destroy();
+    buffer
+}
+
+struct Configure(Rc<Cell<Option<u32>>>);
+
+impl XdgSurfaceHandler for Configure {
+    fn handle_configure(&mut self, _slf: &Rc<XdgSurface>, serial: u32) {
+        self.0.set(Some(serial));
+    }
+}
+
+#[derive(Default, Clone)]
+struct Keys(Rc<RefCell<Vec<String>>>);
+
+impl WlKeyboardHandler for Keys {
+    fn handle_enter(
+        &mut self,
+        _slf: &Rc<WlKeyboard>,
+        _serial: u32,
+        surface: &Rc<WlSurface>,
+        _keys: &[u8],
+    ) {
+        self.0
+            .borrow_mut()
+            .push(format!("enter {}", surface.server_id().unwrap()));
+    }
+
+    fn handle_key(
+        &mut self,
+        _slf: &Rc<WlKeyboard>,
+        _serial: u32,
+        _time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        let pressed = state == WlKeyboardKeyState::PRESSED;
+        self.0.borrow_mut().push(format!("key {key} {pressed}"));
+    }
+}
+
+#[test]
+fn toplevel() {
+    let env = HeadlessEnv::new();
+    let compositor = env.bind::<WlCompositor>(1);
+    let shm = env.bind::<WlShm>(2);
+    let wm_base = env.bind::<XdgWmBase>(4);
+    let surface = compositor.new_send_create_surface();
+    let xdg_surface = wm_base.new_send_get_xdg_surface(&surface);
+    let serial = Rc::new(Cell::new(None));
+    xdg_surface.set_handler(Configure(serial.clone()));
+    let toplevel = xdg_surface.new_send_get_toplevel();
+    toplevel.send_set_title("title");
+    toplevel.send_set_app_id("app-id");
+    surface.send_commit();
+    env.roundtrip();
+
+    let toplevels = env.headless.toplevels();
+    assert_eq!(toplevels.len(), 1);
+    let headless_toplevel = &toplevels[0];
+    assert_eq!(headless_toplevel.title(), "title");
+    assert_eq!(headless_toplevel.app_id(), "app-id");
+    assert!(!headless_toplevel.is_mapped());
+    let serial = serial.get().unwrap();
+
+    xdg_surface.send_ack_configure(serial);
+    let buffer = buffer(&shm, 2, 2, 0xff00ff00);
+    surface.send_attach(Some(&buffer), 0, 0);
+    let frame_done = Rc::new(Cell::new(false));
+    surface
+        .new_send_frame()
+        .set_handler(Done(frame_done.clone()));
+    surface.send_commit();
+    env.roundtrip();
+
+    assert_eq!(headless_toplevel.acked_serial(), Some(serial));
+    assert!(headless_toplevel.is_mapped());
+    let image = headless_toplevel.surface().unwrap().image().unwrap();
+    assert_eq!((image.width(), image.height()), (2, 2));
+    assert_eq!(image.pixel(1, 1), Some(0xff00ff00));
+    assert!(!frame_done.get());
+    assert_eq!(env.headless.frame(), 1);
+    env.roundtrip();
+    assert!(frame_done.get());
+}
+
+#[test]
+fn synchronized_subsurface() {
+    let env = HeadlessEnv::new();
+    let compositor = env.bind::<WlCompositor>(1);
+    let shm = env.bind::<WlShm>(2);
+    let subcompositor = env.bind::<WlSubcompositor>(3);
+    let parent = compositor.new_send_create_surface();
+    let child = compositor.new_send_create_surface();
+    let subsurface = subcompositor.new_send_get_subsurface(&child, &parent);
+    subsurface.send_set_position(10, 20);
+    let buffer = buffer(&shm, 1, 1, 0x12345678);
+    child.send_attach(Some(&buffer), 0, 0);
+    child.send_commit();
+    env.roundtrip();
+
+    let surfaces = env.headless.surfaces();
+    assert_eq!(surfaces.len(), 2);
+    let headless_child = &surfaces[1];
+    assert!(headless_child.is_synchronized());
+    assert_eq!(headless_child.commits(), 0);
+    assert!(headless_child.image().is_none());
+    assert_eq!(headless_child.position(), (0, 0));
+
+    parent.send_commit();
+    env.roundtrip();
+    assert_eq!(headless_child.commits(), 1);
+    assert_eq!(
+        headless_child.image().unwrap().pixel(0, 0),
+        Some(0x12345678)
+    );
+    assert_eq!(headless_child.position(), (10, 20));
+    assert!(Rc::ptr_eq(
+        &headless_child.parent().unwrap().data,
+        &surfaces[0].data,
+    ));
+
+    subsurface.send_set_desync();
+    child.send_attach(None, 0, 0);
+    child.send_commit();
+    env.roundtrip();
+    assert_eq!(headless_child.commits(), 2);
+    assert!(headless_child.image().is_none());
+}
+
+#[test]
+fn keyboard() {
+    let env = HeadlessEnv::new();
+    let compositor = env.bind::<WlCompositor>(1);
+    let seat = env.bind::<WlSeat>(5);
+    let keys = Keys::default();
+    seat.new_send_get_keyboard().set_handler(keys.clone());
+    let surface = compositor.new_send_create_surface();
+    env.roundtrip();
+
+    let headless_surface = &env.headless.surfaces()[0];
+    env.headless.keyboard_enter(headless_surface);
+    env.headless.key(30, true);
+    env.headless.key(30, false);
+    env.roundtrip();
+    assert_eq!(
+        &*keys.0.borrow(),
+        &[
+            format!("enter {}", surface.server_id().unwrap()),
+            "key 30 true".to_string(),
+            "key 30 false".to_string(),
+        ],
+    );
+}
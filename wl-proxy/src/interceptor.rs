@@ -0,0 +1,509 @@
+//! Interception of incoming messages.
+//!
+//! A [`MessageInterceptor`] installed with
+//! [`StateBuilder::with_interceptor`](crate::state::StateBuilder::with_interceptor) is
+//! invoked for every request and event received by the [`State`](crate::state::State)
+//! before the message is dispatched to the handler of its object. This allows blanket
+//! policies to be implemented without installing handlers on all affected objects.
+//!
+//! The interceptor decides what happens to the message by returning an
+//! [`Interception`]:
+//!
+//! - [`Interception::Forward`] dispatches the message as usual.
+//! - [`Interception::Drop`] discards the message.
+//! - [`Interception::Replace`] dispatches a different message in its place. Such
+//!   messages can be created with [`InterceptedMessage::replace`].
+//! - [`Interception::Suspend`] suspends the client or server connection that the message
+//!   was received from. The message is dispatched, without being intercepted again, once
+//!   the connection is unsuspended with
+//!   [`Client::set_suspended`](crate::client::Client::set_suspended) or
+//!   [`State::set_suspended`](crate::state::State::set_suspended).
+//!
+//! Messages that create or destroy objects should not be dropped or replaced with
+//! messages that do not have the same effect. Otherwise the peer and the proxy disagree
+//! about which objects exist.
+//!
+//! # Example
+//!
+//! ```
+//! # use wl_proxy::baseline::Baseline;
+//! # use wl_proxy::interceptor::{InterceptedMessage, Interception};
+//! # use wl_proxy::state::State;
+//! let state = State::builder(Baseline::ALL_OF_THEM)
+//!     .without_server()
+//!     .with_interceptor(|message: &InterceptedMessage<'_>| {
+//!         if message.is_request()
+//!             && message.interface_name() == "xdg_toplevel"
+//!             && message.name() == Some("set_fullscreen")
+//!         {
+//!             return Interception::Drop;
+//!         }
+//!         Interception::Forward
+//!     })
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use {
+    crate::{
+        client::Client,
+        endpoint::Endpoint,
+        fixed::Fixed,
+        object::{Object, ObjectCoreApi, ObjectError, ObjectErrorKind},
+        protocol_helpers::{NonNullString, NullableString, parse_array, parse_string},
+        protocols::ObjectInterface,
+        trans::MAX_MESSAGE_SIZE,
+    },
+    std::{collections::VecDeque, os::fd::OwnedFd, rc::Rc},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// An interceptor for incoming messages.
+///
+/// See the documentation of [the module][self] for more information.
+pub trait MessageInterceptor {
+    /// Intercepts a message before it is dispatched.
+    ///
+    /// The interceptor must not dispatch the state from within this function.
+    fn intercept(&mut self, message: &InterceptedMessage<'_>) -> Interception;
+}
+
+impl<F> MessageInterceptor for F
+where
+    F: FnMut(&InterceptedMessage<'_>) -> Interception,
+{
+    fn intercept(&mut self, message: &InterceptedMessage<'_>) -> Interception {
+        self(message)
+    }
+}
+
+/// The decision of a [`MessageInterceptor`].
+pub enum Interception {
+    /// Dispatches the message as usual.
+    Forward,
+    /// Discards the message.
+    Drop,
+    /// Dispatches a different message instead.
+    Replace(Replacement),
+    /// Suspends the connection and dispatches the message once it is unsuspended.
+    Suspend,
+}
+
+/// A message that replaces an intercepted message.
+///
+/// This type can be constructed with [`InterceptedMessage::replace`].
+pub struct Replacement {
+    words: Vec<u32>,
+    fds: Vec<Rc<OwnedFd>>,
+}
+
+/// A message received by a [`State`](crate::state::State).
+pub struct InterceptedMessage<'a> {
+    endpoint: &'a Endpoint,
+    client: Option<&'a Rc<Client>>,
+    object: &'a Rc<dyn Object>,
+    msg: &'a [u32],
+    fds: &'a VecDeque<Rc<OwnedFd>>,
+}
+
+/// An argument of an [`InterceptedMessage`].
+#[derive(Clone, Debug)]
+pub enum MessageArg<'a> {
+    /// An `int` argument.
+    Int(i32),
+    /// A `uint` argument.
+    ///
+    /// If the signature of the message is not known, for example, for
+    /// [`UnknownObject`](crate::unknown::UnknownObject)s without a description, all
+    /// words of the message are represented as `uint` arguments. If
+    /// [`StateBuilder::with_verbatim_fds`](crate::state::StateBuilder::with_verbatim_fds)
+    /// has been used, they are followed by all file descriptors that have been received
+    /// but not yet consumed.
+    Uint(u32),
+    /// A `fixed` argument.
+    Fixed(Fixed),
+    /// A `string` argument.
+    String(Option<&'a str>),
+    /// An `object` argument.
+    Object(Option<Rc<dyn Object>>),
+    /// A `new_id` argument.
+    ///
+    /// The object does not exist before the message has been dispatched.
+    NewId {
+        /// The interface of the new object.
+        interface: &'a str,
+        /// The version of the new object.
+        version: u32,
+        /// The protocol ID of the new object on the connection of the message.
+        id: u32,
+    },
+    /// An `array` argument.
+    Array(&'a [u8]),
+    /// An `fd` argument.
+    Fd(Rc<OwnedFd>),
+}
+
+struct ArgSpec<'a> {
+    ty: u8,
+    nullable: bool,
+    interface: Option<&'a str>,
+}
+
+fn parse_signature(signature: &str) -> Vec<ArgSpec<'_>> {
+    let mut specs = vec![];
+    let mut rest = signature;
+    let mut nullable = false;
+    while let Some(&ty) = rest.as_bytes().first() {
+        rest = &rest[1..];
+        if ty == b'?' {
+            nullable = true;
+            continue;
+        }
+        let mut interface = None;
+        if ty == b'n'
+            && let Some(tail) = rest.strip_prefix('[')
+            && let Some(end) = tail.find(']')
+        {
+            interface = Some(&tail[..end]);
+            rest = &tail[end + 1..];
+        }
+        specs.push(ArgSpec {
+            ty,
+            nullable,
+            interface,
+        });
+        nullable = false;
+    }
+    specs
+}
+
+fn arg_name(ty: u8) -> &'static str {
+    match ty {
+        b'i' => "int",
+        b'u' => "uint",
+        b'f' => "fixed",
+        b's' => "string",
+        b'o' => "object",
+        b'n' => "new_id",
+        b'a' => "array",
+        _ => "fd",
+    }
+}
+
+impl<'a> InterceptedMessage<'a> {
+    /// Returns the object that the message was sent to.
+    pub fn object(&self) -> &'a Rc<dyn Object> {
+        self.object
+    }
+
+    /// Returns the client that sent the message, if it is a request.
+    pub fn client(&self) -> Option<&'a Rc<Client>> {
+        self.client
+    }
+
+    /// Returns whether the message is a request.
+    ///
+    /// Otherwise it is an event sent by the server.
+    pub fn is_request(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// Returns the interface of the object.
+    pub fn interface(&self) -> ObjectInterface {
+        self.object.interface()
+    }
+
+    /// Returns the interface name of the object.
+    pub fn interface_name(&self) -> &'a str {
+        self.object.interface_name()
+    }
+
+    /// Returns the opcode of the message.
+    pub fn opcode(&self) -> u32 {
+        self.msg[1] & 0xffff
+    }
+
+    /// Returns the name of the message, if the interface is known.
+    pub fn name(&self) -> Option<&'a str> {
+        match self.is_request() {
+            true => self.object.get_request_name(self.opcode()),
+            false => self.object.get_event_name(self.opcode()),
+        }
+    }
+
+    /// Returns the raw words of the message, including the header.
+    pub fn words(&self) -> &'a [u32] {
+        self.msg
+    }
+
+    fn signature(&self, opcode: u32) -> Option<&'a str> {
+        match self.is_request() {
+            true => self.object.get_request_signature(opcode),
+            false => self.object.get_event_signature(opcode),
+        }
+    }
+
+    fn lookup(&self, id: u32) -> Result<Rc<dyn Object>, ObjectError> {
+        self.endpoint.lookup(id).ok_or_else(|| match self.client {
+            Some(client) => ObjectError(ObjectErrorKind::NoClientObject(client.endpoint.id, id)),
+            None => ObjectError(ObjectErrorKind::NoServerObject(id)),
+        })
+    }
+
+    /// Decodes the arguments of the message.
+    pub fn args(&self) -> Result<Vec<MessageArg<'a>>, ObjectError> {
+        let msg = self.msg;
+        let Some(signature) = self.signature(self.opcode()) else {
+            let mut args: Vec<_> = msg[2..].iter().map(|&w| MessageArg::Uint(w)).collect();
+            if self.is_verbatim() {
+                args.extend(self.fds.iter().cloned().map(MessageArg::Fd));
+            }
+            return Ok(args);
+        };
+        let mut args = vec![];
+        let mut offset = 2;
+        let mut fds = self.fds.iter();
+        for spec in parse_signature(signature) {
+            let name = arg_name(spec.ty);
+            let arg = match spec.ty {
+                b's' => {
+                    let s;
+                    if spec.nullable {
+                        (s, offset) = parse_string::<NullableString>(msg, offset, name)?;
+                    } else {
+                        let ns;
+                        (ns, offset) = parse_string::<NonNullString>(msg, offset, name)?;
+                        s = Some(ns);
+                    }
+                    MessageArg::String(s)
+                }
+                b'a' => {
+                    let a;
+                    (a, offset) = parse_array(msg, offset, name)?;
+                    MessageArg::Array(a)
+                }
+                b'h' => {
+                    let Some(fd) = fds.next() else {
+                        return Err(ObjectError(ObjectErrorKind::MissingFd(name)));
+                    };
+                    MessageArg::Fd(fd.clone())
+                }
+                b'n' if spec.interface.is_none() => {
+                    let interface;
+                    (interface, offset) = parse_string::<NonNullString>(msg, offset, name)?;
+                    let Some(&[version, id]) = msg.get(offset..offset + 2) else {
+                        return Err(ObjectError(ObjectErrorKind::MissingArgument(name)));
+                    };
+                    offset += 2;
+                    MessageArg::NewId {
+                        interface,
+                        version,
+                        id,
+                    }
+                }
+                ty => {
+                    let Some(&word) = msg.get(offset) else {
+                        return Err(ObjectError(ObjectErrorKind::MissingArgument(name)));
+                    };
+                    offset += 1;
+                    match ty {
+                        b'i' => MessageArg::Int(word as i32),
+                        b'u' => MessageArg::Uint(word),
+                        b'f' => MessageArg::Fixed(Fixed::from_wire(word as i32)),
+                        b'o' if word == 0 && spec.nullable => MessageArg::Object(None),
+                        b'o' => MessageArg::Object(Some(self.lookup(word)?)),
+                        _ => MessageArg::NewId {
+                            interface: spec.interface.unwrap_or_default(),
+                            version: self.object.version(),
+                            id: word,
+                        },
+                    }
+                }
+            };
+            args.push(arg);
+        }
+        if offset != msg.len() {
+            return Err(ObjectError(ObjectErrorKind::TrailingBytes));
+        }
+        Ok(args)
+    }
+
+    /// Creates a message that replaces this message.
+    ///
+    /// The replacement is sent to the same object. If the signature of the message is
+    /// known, the arguments must match it. Objects must be associated with the
+    /// connection that the message was received from.
+    pub fn replace(
+        &self,
+        opcode: u32,
+        args: &[MessageArg<'_>],
+    ) -> Result<Interception, ObjectError> {
+        let specs = self.signature(opcode).map(parse_signature);
+        if let Some(specs) = &specs {
+            if specs.len() != args.len() || specs.iter().zip(args).any(|(s, a)| !s.matches(a)) {
+                return Err(ObjectError(ObjectErrorKind::SignatureMismatch));
+            }
+        } else if self.object.interface() != ObjectInterface::Unknown {
+            return Err(ObjectError(ObjectErrorKind::UnknownMessageId(opcode)));
+        }
+        let mut words = vec![self.msg[0], 0];
+        let mut fds = vec![];
+        for (idx, arg) in args.iter().enumerate() {
+            match arg {
+                MessageArg::Int(v) => words.push(*v as u32),
+                MessageArg::Uint(v) => words.push(*v),
+                MessageArg::Fixed(v) => words.push(v.to_wire() as u32),
+                MessageArg::String(None) => words.push(0),
+                MessageArg::String(Some(s)) => push_string(&mut words, s),
+                MessageArg::Object(None) => words.push(0),
+                MessageArg::Object(Some(o)) => words.push(self.object_id(o)?),
+                MessageArg::NewId {
+                    interface,
+                    version,
+                    id,
+                } => {
+                    if specs.as_ref().is_some_and(|s| s[idx].interface.is_none()) {
+                        push_string(&mut words, interface);
+                        words.push(*version);
+                    }
+                    words.push(*id);
+                }
+                MessageArg::Array(a) => push_array(&mut words, a),
+                MessageArg::Fd(fd) => fds.push(fd.clone()),
+            }
+        }
+        let size = words.len() * 4;
+        if size > MAX_MESSAGE_SIZE {
+            return Err(ObjectError(ObjectErrorKind::MessageTooLarge(
+                MAX_MESSAGE_SIZE,
+            )));
+        }
+        words[1] = ((size as u32) << 16) | opcode;
+        Ok(Interception::Replace(Replacement { words, fds }))
+    }
+
+    fn object_id(&self, object: &Rc<dyn Object>) -> Result<u32, ObjectError> {
+        let Some(client) = self.client else {
+            return object
+                .server_id()
+                .ok_or(ObjectError(ObjectErrorKind::ArgNoServerId("object")));
+        };
+        match (object.client(), object.client_id()) {
+            (Some(c), Some(id)) if Rc::ptr_eq(&c, client) => Ok(id),
+            _ => Err(ObjectError(ObjectErrorKind::ArgNoClientId(
+                "object",
+                client.endpoint.id,
+            ))),
+        }
+    }
+
+    fn num_fds(&self) -> usize {
+        match self.signature(self.opcode()) {
+            Some(s) => s.bytes().filter(|&b| b == b'h').count(),
+            None if self.is_verbatim() => self.fds.len(),
+            None => 0,
+        }
+    }
+
+    /// Returns whether the message belongs to an undescribed unknown object and is
+    /// forwarded with all pending file descriptors.
+    ///
+    /// See [`StateBuilder::with_verbatim_fds`](crate::state::StateBuilder::with_verbatim_fds).
+    fn is_verbatim(&self) -> bool {
+        self.object.interface() == ObjectInterface::Unknown
+            && self.object.core().state.unknown_interfaces.verbatim_fds
+    }
+}
+
+impl ArgSpec<'_> {
+    fn matches(&self, arg: &MessageArg<'_>) -> bool {
+        match (self.ty, arg) {
+            (b'i', MessageArg::Int(_)) => true,
+            (b'u', MessageArg::Uint(_)) => true,
+            (b'f', MessageArg::Fixed(_)) => true,
+            (b's', MessageArg::String(s)) => self.nullable || s.is_some(),
+            (b'o', MessageArg::Object(o)) => self.nullable || o.is_some(),
+            (b'n', MessageArg::NewId { interface, .. }) => {
+                self.interface.is_none_or(|i| i == *interface)
+            }
+            (b'a', MessageArg::Array(_)) => true,
+            (b'h', MessageArg::Fd(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+fn push_string(words: &mut Vec<u32>, s: &str) {
+    let len = s.len() + 1;
+    words.push(len as u32);
+    let start = words.len();
+    words.resize(start + len.div_ceil(4), 0);
+    uapi::as_bytes_mut(&mut words[start..])[..s.len()].copy_from_slice(s.as_bytes());
+}
+
+fn push_array(words: &mut Vec<u32>, a: &[u8]) {
+    words.push(a.len() as u32);
+    let start = words.len();
+    words.resize(start + a.len().div_ceil(4), 0);
+    uapi::as_bytes_mut(&mut words[start..])[..a.len()].copy_from_slice(a);
+}
+
+/// The action taken by an endpoint after a message has been intercepted.
+pub(crate) enum Intercepted {
+    Dispatch,
+    Replaced(Vec<u32>),
+    Skip,
+    Hold,
+}
+
+/// Invokes the interceptor of the state of `object`, if any.
+///
+/// The file descriptors of dropped and replaced messages are removed from `fds` and the
+/// file descriptors of replacements are added to its front.
+pub(crate) fn intercept(
+    endpoint: &Endpoint,
+    client: Option<&Rc<Client>>,
+    object: &Rc<dyn Object>,
+    msg: &[u32],
+    fds: &mut VecDeque<Rc<OwnedFd>>,
+) -> Intercepted {
+    let state = object.state();
+    let Some(interceptor) = &state.interceptor else {
+        return Intercepted::Dispatch;
+    };
+    let Ok(mut interceptor) = interceptor.try_borrow_mut() else {
+        return Intercepted::Dispatch;
+    };
+    let message = InterceptedMessage {
+        endpoint,
+        client,
+        object,
+        msg,
+        fds,
+    };
+    let num_fds = message.num_fds();
+    match interceptor.intercept(&message) {
+        Interception::Forward => Intercepted::Dispatch,
+        Interception::Drop => {
+            fds.drain(..num_fds.min(fds.len()));
+            Intercepted::Skip
+        }
+        Interception::Replace(replacement) => {
+            fds.drain(..num_fds.min(fds.len()));
+            for fd in replacement.fds.into_iter().rev() {
+                fds.push_front(fd);
+            }
+            Intercepted::Replaced(replacement.words)
+        }
+        Interception::Suspend => {
+            drop(interceptor);
+            match client {
+                Some(client) => client.set_suspended(true),
+                None => state.set_suspended(true),
+            }
+            Intercepted::Hold
+        }
+    }
+}
@@ -0,0 +1,140 @@
+#![cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+
+use {
+    crate::{
+        baseline::Baseline,
+        headless::Headless,
+        interceptor::{InterceptedMessage, Interception, MessageArg},
+        object::ConcreteObject,
+        protocols::{
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_compositor::WlCompositor,
+            },
+            xdg_shell::xdg_wm_base::XdgWmBase,
+        },
+        state::State,
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+struct Done(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+fn intercept(log: &RefCell<Vec<String>>, message: &InterceptedMessage<'_>) -> Interception {
+    if message.interface_name() != "xdg_toplevel" {
+        return Interception::Forward;
+    }
+    let name = message.name().unwrap();
+    let args = message.args().unwrap();
+    if !message.is_request() {
+        if let [
+            MessageArg::Int(width),
+            MessageArg::Int(height),
+            MessageArg::Array(_),
+        ] = &*args
+        {
+            log.borrow_mut().push(format!("{name} {width}x{height}"));
+        }
+        return Interception::Forward;
+    }
+    let [MessageArg::String(Some(arg))] = &*args else {
+        return Interception::Forward;
+    };
+    log.borrow_mut().push(format!("{name} {arg}"));
+    match *arg {
+        "drop" => Interception::Drop,
+        "replace" => message
+            .replace(message.opcode(), &[MessageArg::String(Some("replaced"))])
+            .unwrap(),
+        "suspend" => Interception::Suspend,
+        _ => Interception::Forward,
+    }
+}
+
+#[test]
+fn interceptor() {
+    let headless = Headless::new().unwrap();
+    let log = Rc::new(RefCell::new(vec![]));
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .with_interceptor({
+            let log = log.clone();
+            move |message: &InterceptedMessage<'_>| intercept(&log, message)
+        })
+        .build()
+        .unwrap();
+    let (proxy_client, client_fd) = proxy.connect().unwrap();
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .build()
+        .unwrap();
+    let _destructors = [proxy.create_destructor(), client.create_destructor()];
+    let roundtrip = || {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([&client, &proxy, headless.state()]).unwrap();
+        }
+    };
+    let registry = client.display().new_send_get_registry();
+    let compositor = client.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+    registry.send_bind(1, compositor.clone());
+    let wm_base = client.create_object::<XdgWmBase>(XdgWmBase::XML_VERSION);
+    registry.send_bind(4, wm_base.clone());
+    let surface = compositor.new_send_create_surface();
+    let toplevel = wm_base
+        .new_send_get_xdg_surface(&surface)
+        .new_send_get_toplevel();
+    toplevel.send_set_title("title");
+    toplevel.send_set_app_id("drop");
+    surface.send_commit();
+    roundtrip();
+    let headless_toplevel = headless.toplevels()[0].clone();
+    assert_eq!(headless_toplevel.title(), "title");
+    assert_eq!(headless_toplevel.app_id(), "");
+    assert_eq!(
+        log.take(),
+        ["set_title title", "set_app_id drop", "configure 0x0"],
+    );
+
+    // Requests can be replaced.
+    toplevel.send_set_title("replace");
+    roundtrip();
+    assert_eq!(headless_toplevel.title(), "replaced");
+    assert_eq!(log.take(), ["set_title replace"]);
+
+    // Suspended requests are dispatched once the client is unsuspended.
+    toplevel.send_set_title("suspend");
+    let done = Rc::new(Cell::new(false));
+    client
+        .display()
+        .new_send_sync()
+        .set_handler(Done(done.clone()));
+    for _ in 0..3 {
+        client.dispatch_available().unwrap();
+        proxy.dispatch_available().unwrap();
+        headless.state().dispatch_available().unwrap();
+    }
+    assert!(!done.get());
+    assert_eq!(headless_toplevel.title(), "replaced");
+    assert_eq!(log.take(), ["set_title suspend"]);
+    proxy_client.set_suspended(false);
+    while !done.get() {
+        dispatch_blocking([&client, &proxy, headless.state()]).unwrap();
+    }
+    assert_eq!(headless_toplevel.title(), "suspend");
+    assert_eq!(log.take(), Vec::<String>::new());
+}
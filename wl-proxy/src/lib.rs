@@ -151,8 +151,9 @@
 //! For example, this can happen if a client-created object is handled internally by the
 //! proxy without ever forwarding it to the compositor.
 //!
-//! Each object can only be associated with one client at a time. Multiplexing must be
-//! implemented manually.
+//! Each object can only be associated with one client at a time. The
+//! [`multiplexer`] module can be used to share a compositor object between multiple
+//! client objects.
 //!
 //! # Handlers
 //!
@@ -222,6 +223,12 @@
 //! dropped before the state is destroyed. Most commonly, the handler should be unset
 //! when the object is logically destroyed.
 //!
+//! Policies that apply to many objects at once can instead be implemented by installing
+//! a [`MessageInterceptor`](interceptor::MessageInterceptor) via
+//! [`StateBuilder::with_interceptor`](state::StateBuilder::with_interceptor). It sees
+//! every incoming message before it is passed to the handler. See the [`interceptor`]
+//! module for details.
+//!
 //! # Sending Messages
 //!
 //! Objects define functions that can be used to send messages. Whether the message is
@@ -318,7 +325,12 @@
 //! synchronization messages.
 //!
 //! This [wlproxy_sync_v1](https://github.com/mahkoh/wl-proxy/blob/master/protocols/wlproxy/wlproxy-sync-v1.xml)
-//! protocol can be used for this.
+//! protocol can be used for this. The `object_sync` module, which requires the
+//! `protocol-wlproxy_sync_v1` feature, uses it to match objects of A with proxy objects.
+//!
+//! The `embedding` module, which requires the `protocol-xdg_shell` and
+//! `protocol-wlproxy_sync_v1` features, implements this pattern for toplevels of B that
+//! are embedded as subsurfaces of surfaces of A.
 //!
 //! # Logging
 //!
@@ -339,11 +351,20 @@
 //! ```
 //!
 //! By default, logging is controlled by the `WL_PROXY_DEBUG` environment variable. If it
-//! is set to 1, messages are logged, otherwise messages are not logged. Messages are
-//! always written to STDERR.
+//! is set to 1, all messages are logged. Otherwise, if it is set and neither empty nor
+//! 0, it is interpreted as a filter that selects the messages to log, e.g.
+//! `WL_PROXY_DEBUG=xdg_*,-wl_pointer.motion`. See
+//! [`StateBuilder::with_log_filter`](state::StateBuilder::with_log_filter) for the
+//! syntax. By default, messages are written to STDERR.
 //!
 //! Applications can disable or enable logging programmatically via
-//! [`StateBuilder::with_logging`](state::StateBuilder::with_logging).
+//! [`StateBuilder::with_logging`](state::StateBuilder::with_logging) and
+//! [`StateBuilder::with_log_filter`](state::StateBuilder::with_log_filter).
+//!
+//! Applications can receive logged messages in a structured form by installing a
+//! [`LogSink`](logging::LogSink) via
+//! [`StateBuilder::with_log_sink`](state::StateBuilder::with_log_sink). See the
+//! [`logging`] module for details.
 //!
 //! Messages can have a prefix that is taken from the `WL_PROXY_PREFIX` environment
 //! variable. Applications can augment this prefix via
@@ -366,7 +387,13 @@
 //! enabled to aid debuggability.
 
 pub mod acceptor;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub mod async_state;
 pub mod client;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+#[cfg(all(feature = "protocol-xdg_shell", feature = "protocol-wlproxy_sync_v1"))]
+pub mod embedding;
 mod endpoint;
 pub mod fixed;
 mod protocol_helpers;
@@ -376,12 +403,36 @@ pub mod protocols;
 pub mod baseline;
 pub mod global_mapper;
 pub mod handler;
+#[cfg(feature = "headless")]
+pub mod headless;
+pub mod interceptor;
+#[cfg(feature = "logging")]
+pub mod logging;
+pub mod multiplexer;
+#[cfg(feature = "network")]
+pub mod network;
 pub mod object;
+#[cfg(feature = "protocol-wlproxy_sync_v1")]
+pub mod object_sync;
+#[cfg(feature = "policy")]
+pub mod policy;
 mod poll;
+pub mod recording;
+#[cfg(feature = "recovery")]
+pub mod recovery;
+#[cfg(feature = "protocol-security_context_v1")]
+pub mod security_context;
+pub mod serial_mapper;
+#[cfg(feature = "shm")]
+pub mod shm;
 pub mod simple;
 pub mod state;
+pub mod surface_tracker;
 #[cfg(test)]
 mod test_framework;
 mod trans;
+pub mod unknown;
 mod utils;
 mod wire;
+#[cfg(feature = "protocol-xdg_shell")]
+pub mod xdg_tracker;
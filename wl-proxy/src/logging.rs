@@ -0,0 +1,350 @@
+//! Structured logging of messages.
+//!
+//! This module requires the `logging` feature.
+//!
+//! By default, logged messages are written to STDERR in the format described in the
+//! [crate documentation](crate#logging). If a [`LogSink`] has been installed with
+//! [`StateBuilder::with_log_sink`], messages are instead passed to the sink as
+//! [`LogRecord`]s. This can be used to emit messages in a different format, to forward
+//! them to another logging framework, or to filter them.
+//!
+//! The [`Display`] implementation of [`LogRecord`] produces the default format.
+//!
+//! # Example
+//!
+//! ```
+//! # use wl_proxy::baseline::Baseline;
+//! # use wl_proxy::logging::LogRecord;
+//! # use wl_proxy::state::State;
+//! let state = State::builder(Baseline::ALL_OF_THEM)
+//!     .with_log_sink(|record: &LogRecord<'_>| {
+//!         if record.interface() != "wl_callback" {
+//!             eprintln!("{record}");
+//!         }
+//!     })
+//!     .build();
+//! ```
+//!
+//! [`StateBuilder::with_log_sink`]: crate::state::StateBuilder::with_log_sink
+
+use {
+    crate::{fixed::Fixed, recording::Direction, utils::glob::glob},
+    debug_fn::debug_fn,
+    std::{
+        fmt::{self, Debug, Display, Formatter},
+        os::fd::RawFd,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A filter for logged messages.
+///
+/// See [`StateBuilder::with_log_filter`](crate::state::StateBuilder::with_log_filter) for
+/// the syntax.
+pub(crate) struct LogFilter {
+    directives: Vec<Directive>,
+    default: bool,
+}
+
+struct Directive {
+    enable: bool,
+    interface: String,
+    message: Option<String>,
+}
+
+impl LogFilter {
+    pub(crate) fn parse(filter: &str) -> Self {
+        let mut directives = vec![];
+        for directive in filter.split(',') {
+            let directive = directive.trim();
+            let (enable, directive) = match directive.strip_prefix('-') {
+                Some(d) => (false, d),
+                None => (true, directive),
+            };
+            if directive.is_empty() {
+                continue;
+            }
+            let (interface, message) = match directive.split_once('.') {
+                Some((i, m)) => (i, Some(m.to_string())),
+                None => (directive, None),
+            };
+            let interface = match interface {
+                "1" => "*",
+                _ => interface,
+            };
+            directives.push(Directive {
+                enable,
+                interface: interface.to_string(),
+                message,
+            });
+        }
+        Self {
+            default: directives.iter().all(|d| !d.enable),
+            directives,
+        }
+    }
+
+    pub(crate) fn matches(&self, interface: &str, message: &str) -> bool {
+        for directive in self.directives.iter().rev() {
+            if glob(&directive.interface, interface)
+                && directive
+                    .message
+                    .as_deref()
+                    .is_none_or(|m| glob(m, message))
+            {
+                return directive.enable;
+            }
+        }
+        self.default
+    }
+}
+
+/// A sink for logged messages.
+///
+/// See [`StateBuilder::with_log_sink`](crate::state::StateBuilder::with_log_sink).
+pub trait LogSink {
+    /// Handles a logged message.
+    ///
+    /// The sink must not send messages from within this function. Such messages are not
+    /// logged.
+    fn log(&mut self, record: &LogRecord<'_>);
+}
+
+impl<F> LogSink for F
+where
+    F: FnMut(&LogRecord<'_>),
+{
+    fn log(&mut self, record: &LogRecord<'_>) {
+        self(record)
+    }
+}
+
+/// A logged message.
+pub struct LogRecord<'a> {
+    timestamp: SystemTime,
+    prefix: &'a str,
+    direction: Direction,
+    client_id: Option<u64>,
+    interface: &'a str,
+    object_id: u32,
+    message: &'a str,
+    args: &'a [LogArg<'a>],
+}
+
+/// An argument of a [`LogRecord`].
+pub struct LogArg<'a> {
+    name: Option<&'a str>,
+    value: LogValue<'a>,
+}
+
+/// The value of a [`LogArg`].
+pub enum LogValue<'a> {
+    /// An `int` argument.
+    Int(i32),
+    /// A `uint` argument.
+    Uint(u32),
+    /// An `int` or `uint` argument that refers to an enum.
+    Enum {
+        /// The raw value.
+        value: u32,
+        /// Formats the value using the names of the enum entries.
+        debug: &'a dyn Debug,
+    },
+    /// A `fixed` argument.
+    Fixed(Fixed),
+    /// A `string` argument.
+    ///
+    /// This is `None` if the argument is a null string.
+    String(Option<&'a str>),
+    /// An `object` argument.
+    Object {
+        /// The interface of the object, if it is known.
+        interface: Option<&'a str>,
+        /// The id of the object, or 0 for a null object.
+        id: u32,
+    },
+    /// A `new_id` argument.
+    NewId {
+        /// The interface of the new object.
+        interface: &'a str,
+        /// The id of the new object.
+        id: u32,
+        /// The version of the new object, if the message does not determine the
+        /// interface of the object.
+        version: Option<u32>,
+    },
+    /// An `array` argument.
+    Array(&'a [u8]),
+    /// A `fd` argument.
+    Fd(RawFd),
+}
+
+impl<'a> LogRecord<'a> {
+    pub(crate) fn new(
+        prefix: &'a str,
+        direction: Direction,
+        client_id: Option<u64>,
+        interface: &'a str,
+        object_id: u32,
+        message: &'a str,
+        args: &'a [LogArg<'a>],
+    ) -> Self {
+        Self {
+            timestamp: SystemTime::now(),
+            prefix,
+            direction,
+            client_id,
+            interface,
+            object_id,
+            message,
+            args,
+        }
+    }
+
+    /// Returns the time at which the message was logged.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// Returns the log prefix of the state.
+    ///
+    /// See [`StateBuilder::with_log_prefix`](crate::state::StateBuilder::with_log_prefix).
+    pub fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    /// Returns whether the message was received or sent by the state.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the id of the client that the message was received from or sent to.
+    ///
+    /// This is `None` if the message was received from or sent to the server.
+    pub fn client_id(&self) -> Option<u64> {
+        self.client_id
+    }
+
+    /// Returns the interface of the object.
+    pub fn interface(&self) -> &'a str {
+        self.interface
+    }
+
+    /// Returns the id of the object in the connection.
+    pub fn object_id(&self) -> u32 {
+        self.object_id
+    }
+
+    /// Returns the name of the message.
+    ///
+    /// If the message is not described, this has the form `msgN` where `N` is the
+    /// opcode of the message.
+    pub fn message(&self) -> &'a str {
+        self.message
+    }
+
+    /// Returns the arguments of the message.
+    pub fn args(&self) -> &'a [LogArg<'a>] {
+        self.args
+    }
+}
+
+impl<'a> LogArg<'a> {
+    pub(crate) fn new(name: Option<&'a str>, value: LogValue<'a>) -> Self {
+        Self { name, value }
+    }
+
+    /// Returns the name of the argument, if the message is described.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    /// Returns the value of the argument.
+    pub fn value(&self) -> &LogValue<'a> {
+        &self.value
+    }
+}
+
+impl Display for LogRecord<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let time = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros() as u32;
+        let millis = time / 1_000;
+        let micros = time % 1_000;
+        write!(f, "[{millis:7}.{micros:03}] ")?;
+        if self.prefix.len() > 0 {
+            write!(f, "{{{}}} ", self.prefix)?;
+        }
+        match self.client_id {
+            Some(id) => write!(f, "client#{:<4}", id)?,
+            None => f.write_str("server     ")?,
+        }
+        let arrow = match self.direction {
+            Direction::Received => "->",
+            Direction::Sent => "<=",
+        };
+        write!(
+            f,
+            " {arrow} {}#{}.{}(",
+            self.interface, self.object_id, self.message
+        )?;
+        for (idx, arg) in self.args.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            if let Some(name) = arg.name {
+                write!(f, "{}: ", name)?;
+            }
+            Display::fmt(&arg.value, f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl Display for LogValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            LogValue::Int(v) => write!(f, "{}", v),
+            LogValue::Uint(v) => write!(f, "{}", v),
+            LogValue::Enum { debug, .. } => write!(f, "{:?}", debug),
+            LogValue::Fixed(v) => write!(f, "{}", v),
+            LogValue::String(Some(s)) => write!(f, "{:?}", s),
+            LogValue::String(None) => f.write_str("null"),
+            LogValue::Object { interface, id } => {
+                write!(f, "{}#{}", interface.unwrap_or("unknown"), id)
+            }
+            LogValue::NewId {
+                interface,
+                id,
+                version,
+            } => {
+                write!(f, "{}#{}", interface, id)?;
+                if let Some(version) = version {
+                    write!(f, " (version: {})", version)?;
+                }
+                Ok(())
+            }
+            LogValue::Array(a) => write!(f, "{}", debug_array(a)),
+            LogValue::Fd(fd) => write!(f, "{}", fd),
+        }
+    }
+}
+
+fn debug_array(array: &[u8]) -> impl Display + use<'_> {
+    debug_fn(move |fmt| {
+        fmt.write_str("0x")?;
+        if array.is_empty() {
+            return fmt.write_str("0");
+        }
+        for b in array {
+            write!(fmt, "{:02x}", b)?;
+        }
+        Ok(())
+    })
+}
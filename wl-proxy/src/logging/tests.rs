@@ -0,0 +1,211 @@
+use {
+    crate::{
+        baseline::Baseline,
+        logging::{LogArg, LogFilter, LogRecord, LogValue},
+        object::ObjectCoreApi,
+        protocols::wayland::{
+            wl_callback::WlCallback,
+            wl_display::{WlDisplay, WlDisplayHandler},
+            wl_shm::WlShmFormat,
+        },
+        recording::Direction,
+        state::State,
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::{cell::RefCell, rc::Rc},
+};
+
+#[test]
+fn display() {
+    let format = WlShmFormat::XRGB8888;
+    let args = [
+        LogArg::new(
+            Some("id"),
+            LogValue::NewId {
+                interface: "wl_registry",
+                id: 2,
+                version: None,
+            },
+        ),
+        LogArg::new(Some("s"), LogValue::String(None)),
+        LogArg::new(Some("a"), LogValue::Array(&[1, 255])),
+        LogArg::new(
+            Some("format"),
+            LogValue::Enum {
+                value: format.0,
+                debug: &format,
+            },
+        ),
+        LogArg::new(
+            None,
+            LogValue::Object {
+                interface: None,
+                id: 3,
+            },
+        ),
+    ];
+    let record = LogRecord::new(
+        "prefix",
+        Direction::Received,
+        Some(5),
+        "wl_display",
+        1,
+        "get_registry",
+        &args,
+    );
+    let text = record.to_string();
+    assert!(text.starts_with("["));
+    assert!(text.ends_with(
+        "] {prefix} client#5    -> wl_display#1.get_registry(id: wl_registry#2, s: null, \
+         a: 0x01ff, format: XRGB8888, unknown#3)"
+    ));
+    let record = LogRecord::new("", Direction::Sent, None, "wl_display", 1, "sync", &[]);
+    assert!(
+        record
+            .to_string()
+            .ends_with("] server      <= wl_display#1.sync()")
+    );
+}
+
+struct ServerDisplayHandler;
+
+impl WlDisplayHandler for ServerDisplayHandler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(1);
+        callback.delete_id();
+    }
+}
+
+#[test]
+fn sink() {
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let records2 = records.clone();
+    let server = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .with_log_prefix("server")
+        .with_log_sink(move |record: &LogRecord<'_>| {
+            let args: Vec<_> = record
+                .args()
+                .iter()
+                .map(|arg| (arg.name().unwrap().to_string(), arg.value().to_string()))
+                .collect();
+            records2.borrow_mut().push((
+                record.prefix().to_string(),
+                record.direction(),
+                record.client_id().is_some(),
+                record.interface().to_string(),
+                record.message().to_string(),
+                args,
+            ));
+        })
+        .build()
+        .unwrap();
+    server.set_default_forward_to_server(false);
+    let _server_destructor = server.create_destructor();
+    let (server_client, client_fd) = server.connect().unwrap();
+    server_client.display().set_handler(ServerDisplayHandler);
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .with_logging(false)
+        .build()
+        .unwrap();
+    let _client_destructor = client.create_destructor();
+    let callback = client.display().new_send_sync();
+    let id = callback.server_id().unwrap();
+    while records.borrow().len() < 3 {
+        dispatch_blocking([&server, &client]).unwrap();
+    }
+    let arg = |name: &str, value: String| (name.to_string(), value);
+    let record = |direction, interface: &str, message: &str, args| {
+        (
+            "server".to_string(),
+            direction,
+            true,
+            interface.to_string(),
+            message.to_string(),
+            args,
+        )
+    };
+    assert_eq!(
+        *records.borrow(),
+        [
+            record(
+                Direction::Received,
+                "wl_display",
+                "sync",
+                vec![arg("callback", format!("wl_callback#{id}"))],
+            ),
+            record(
+                Direction::Sent,
+                "wl_callback",
+                "done",
+                vec![arg("callback_data", "1".to_string())],
+            ),
+            record(
+                Direction::Sent,
+                "wl_display",
+                "delete_id",
+                vec![arg("id", id.to_string())],
+            ),
+        ],
+    );
+}
+
+#[test]
+fn sink_filter() {
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let records2 = records.clone();
+    let server = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .with_log_filter("-wl_callback")
+        .with_log_sink(move |record: &LogRecord<'_>| {
+            records2
+                .borrow_mut()
+                .push(format!("{}.{}", record.interface(), record.message()));
+        })
+        .build()
+        .unwrap();
+    server.set_default_forward_to_server(false);
+    let _server_destructor = server.create_destructor();
+    let (server_client, client_fd) = server.connect().unwrap();
+    server_client.display().set_handler(ServerDisplayHandler);
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .with_logging(false)
+        .build()
+        .unwrap();
+    let _client_destructor = client.create_destructor();
+    client.display().new_send_sync();
+    while records.borrow().len() < 2 {
+        dispatch_blocking([&server, &client]).unwrap();
+    }
+    assert_eq!(
+        *records.borrow(),
+        ["wl_display.sync", "wl_display.delete_id"]
+    );
+}
+
+#[test]
+fn filter() {
+    let filter = LogFilter::parse("xdg_*,-xdg_surface.ack_configure");
+    assert!(filter.matches("xdg_toplevel", "set_title"));
+    assert!(filter.matches("xdg_surface", "get_toplevel"));
+    assert!(!filter.matches("xdg_surface", "ack_configure"));
+    assert!(!filter.matches("wl_surface", "commit"));
+
+    let filter = LogFilter::parse("-wl_pointer.motion, -wl_surface.frame");
+    assert!(!filter.matches("wl_pointer", "motion"));
+    assert!(!filter.matches("wl_surface", "frame"));
+    assert!(filter.matches("wl_pointer", "button"));
+    assert!(filter.matches("xdg_toplevel", "configure"));
+
+    let filter = LogFilter::parse("1,-wl_*.*_id,wl_display.delete_id");
+    assert!(filter.matches("wl_surface", "commit"));
+    assert!(!filter.matches("wl_pointer", "frame_id"));
+    assert!(filter.matches("wl_display", "delete_id"));
+
+    let filter = LogFilter::parse("wl_*_v*.get");
+    assert!(filter.matches("wl_foo_v1", "get"));
+    assert!(!filter.matches("wl_foo", "get"));
+    assert!(!filter.matches("wl_foo_v1", "get_bar"));
+}
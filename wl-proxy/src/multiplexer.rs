@@ -0,0 +1,642 @@
+//! Sharing of compositor objects between multiple clients.
+//!
+//! Each object can only be associated with one client at a time. A proxy that serves
+//! multiple clients over a single compositor connection, for example, a proxy that
+//! embeds one client into another, can use a [`Multiplexer`] to share a single
+//! compositor object between multiple client objects.
+//!
+//! The multiplexer owns the compositor object and forwards its events to all client
+//! objects that have been added via [`Multiplexer::add`]. The state described by these
+//! events is cached and replayed to client objects that are added later. Requests that
+//! create new objects are sent via the compositor object.
+//!
+//! The multiplexer is reference counted. When the last client object is released or
+//! dropped, the compositor object is released and the multiplexer becomes unusable.
+//!
+//! Multiplexing is supported for the following interfaces:
+//!
+//! - wl_output
+//! - wl_seat
+//! - zwp_linux_dmabuf_feedback_v1 (requires the `protocol-linux_dmabuf_v1` feature)
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use std::rc::Rc;
+//! # use wl_proxy::multiplexer::Multiplexer;
+//! # use wl_proxy::object::{Object, ObjectRcUtils};
+//! # use wl_proxy::protocols::wayland::wl_output::WlOutput;
+//! # use wl_proxy::protocols::wayland::wl_registry::{WlRegistry, WlRegistryHandler};
+//! #
+//! struct Registry {
+//!     // The wl_output that was bound by the proxy.
+//!     output: Rc<Multiplexer<WlOutput>>,
+//! }
+//!
+//! impl WlRegistryHandler for Registry {
+//!     fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+//!         if let Some(output) = id.try_downcast::<WlOutput>() {
+//!             // Instead of binding a new wl_output, share the existing one.
+//!             self.output.add(&output).unwrap();
+//!             return;
+//!         }
+//!         slf.send_bind(name, id);
+//!     }
+//! }
+//! ```
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+use crate::protocols::linux_dmabuf_v1::zwp_linux_dmabuf_feedback_v1::{
+    ZwpLinuxDmabufFeedbackV1, ZwpLinuxDmabufFeedbackV1Handler, ZwpLinuxDmabufFeedbackV1TrancheFlags,
+};
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+use std::os::fd::OwnedFd;
+use {
+    crate::{
+        object::{ConcreteObject, ObjectCoreApi},
+        protocols::wayland::{
+            wl_keyboard::WlKeyboard,
+            wl_output::{
+                WlOutput, WlOutputHandler, WlOutputMode, WlOutputSubpixel, WlOutputTransform,
+            },
+            wl_pointer::WlPointer,
+            wl_seat::{WlSeat, WlSeatCapability, WlSeatHandler},
+            wl_touch::WlTouch,
+        },
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::{Rc, Weak},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) trait MultiplexedPrivate: ConcreteObject + Sized {
+    type Cache: Default;
+
+    fn track_server(mux: Weak<Multiplexer<Self>>, server: &Rc<Self>)
+    where
+        Self: Multiplexed;
+    fn track_client(mux: Rc<Multiplexer<Self>>, client: &Rc<Self>)
+    where
+        Self: Multiplexed;
+    fn replay(cache: &Self::Cache, client: &Rc<Self>);
+    fn release(server: &Rc<Self>);
+}
+
+/// An object that can be shared via a [`Multiplexer`].
+#[expect(private_bounds)]
+pub trait Multiplexed: MultiplexedPrivate {}
+
+/// A compositor object shared by multiple client objects.
+///
+/// See the [module documentation](self) for details.
+pub struct Multiplexer<T: Multiplexed> {
+    server: Rc<T>,
+    cache: RefCell<T::Cache>,
+    clients: RefCell<Vec<(u64, Weak<T>)>>,
+    destroyed: Cell<bool>,
+}
+
+/// An error emitted by a [`Multiplexer`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct MultiplexerError(#[from] MultiplexerErrorKind);
+
+#[derive(Debug, Error)]
+enum MultiplexerErrorKind {
+    #[error("the multiplexer has been destroyed")]
+    Destroyed,
+    #[error("the client object has version {0} but the server object has version {1}")]
+    Version(u32, u32),
+}
+
+struct ServerObject<T: Multiplexed>(Weak<Multiplexer<T>>);
+
+struct ClientObject<T: Multiplexed> {
+    mux: Rc<Multiplexer<T>>,
+    id: u64,
+}
+
+impl<T: Multiplexed> Multiplexer<T> {
+    /// Creates a new multiplexer for an object associated with the compositor.
+    ///
+    /// This function replaces the handler of the object. Events received before this
+    /// function is called are not replayed to client objects.
+    pub fn new(server: &Rc<T>) -> Rc<Self> {
+        let slf = Rc::new(Self {
+            server: server.clone(),
+            cache: Default::default(),
+            clients: Default::default(),
+            destroyed: Cell::new(false),
+        });
+        T::track_server(Rc::downgrade(&slf), server);
+        slf
+    }
+
+    /// Returns the object associated with the compositor.
+    pub fn server(&self) -> &Rc<T> {
+        &self.server
+    }
+
+    /// Returns the client objects that are currently sharing the compositor object.
+    pub fn clients(&self) -> Vec<Rc<T>> {
+        self.clients
+            .borrow()
+            .iter()
+            .filter_map(|(_, c)| c.upgrade())
+            .filter(|c| c.client_id().is_some())
+            .collect()
+    }
+
+    /// Returns whether the compositor object has been released.
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed.get()
+    }
+
+    /// Adds a client object.
+    ///
+    /// The client object must not be associated with the compositor. Its version must
+    /// not be larger than the version of the compositor object. This function replaces
+    /// the handler of the object and sends the cached state to the client.
+    ///
+    /// If the handler of the object is later replaced or unset, the object is removed
+    /// from the multiplexer.
+    pub fn add(self: &Rc<Self>, client: &Rc<T>) -> Result<(), MultiplexerError> {
+        if self.destroyed.get() {
+            return Err(MultiplexerErrorKind::Destroyed.into());
+        }
+        if client.version() > self.server.version() {
+            return Err(
+                MultiplexerErrorKind::Version(client.version(), self.server.version()).into(),
+            );
+        }
+        let id = client.unique_id();
+        self.clients.borrow_mut().push((id, Rc::downgrade(client)));
+        T::track_client(self.clone(), client);
+        T::replay(&self.cache.borrow(), client);
+        Ok(())
+    }
+
+    /// Removes a client object.
+    ///
+    /// If this was the last client object, the compositor object is released.
+    ///
+    /// This function does not unset the handler of the client object.
+    pub fn remove(&self, client: &T) {
+        self.remove_id(client.unique_id());
+    }
+
+    fn remove_id(&self, id: u64) {
+        let clients = &mut *self.clients.borrow_mut();
+        let len = clients.len();
+        clients.retain(|(i, _)| *i != id);
+        if clients.len() == len || clients.len() > 0 || self.destroyed.replace(true) {
+            return;
+        }
+        T::release(&self.server);
+        self.server.unset_handler();
+        self.cache.take();
+    }
+
+    fn for_each_client(&self, mut f: impl FnMut(&Rc<T>)) {
+        for client in self.clients() {
+            f(&client);
+        }
+    }
+}
+
+impl<T: Multiplexed> Drop for ClientObject<T> {
+    fn drop(&mut self) {
+        self.mux.remove_id(self.id);
+    }
+}
+
+impl<T: Multiplexed> ClientObject<T> {
+    fn release(&self, slf: &Rc<T>) {
+        self.mux.remove_id(self.id);
+        ObjectCoreApi::delete_id(&**slf);
+        slf.unset_handler();
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct OutputCache {
+    geometry: Option<OutputGeometry>,
+    modes: Vec<OutputMode>,
+    scale: Option<i32>,
+    name: Option<String>,
+    description: Option<String>,
+}
+
+struct OutputGeometry {
+    x: i32,
+    y: i32,
+    physical_width: i32,
+    physical_height: i32,
+    subpixel: WlOutputSubpixel,
+    make: String,
+    model: String,
+    transform: WlOutputTransform,
+}
+
+#[derive(Copy, Clone)]
+struct OutputMode {
+    flags: WlOutputMode,
+    width: i32,
+    height: i32,
+    refresh: i32,
+}
+
+impl Multiplexed for WlOutput {}
+
+impl MultiplexedPrivate for WlOutput {
+    type Cache = OutputCache;
+
+    fn track_server(mux: Weak<Multiplexer<Self>>, server: &Rc<Self>) {
+        server.set_handler(ServerObject(mux));
+    }
+
+    fn track_client(mux: Rc<Multiplexer<Self>>, client: &Rc<Self>) {
+        let id = client.unique_id();
+        client.set_handler(ClientObject { mux, id });
+    }
+
+    fn replay(cache: &Self::Cache, client: &Rc<Self>) {
+        if let Some(g) = &cache.geometry {
+            client.send_geometry(
+                g.x,
+                g.y,
+                g.physical_width,
+                g.physical_height,
+                g.subpixel,
+                &g.make,
+                &g.model,
+                g.transform,
+            );
+        }
+        for m in &cache.modes {
+            client.send_mode(m.flags, m.width, m.height, m.refresh);
+        }
+        if client.version() >= WlOutput::MSG__SCALE__SINCE
+            && let Some(scale) = cache.scale
+        {
+            client.send_scale(scale);
+        }
+        if client.version() >= WlOutput::MSG__NAME__SINCE {
+            if let Some(name) = &cache.name {
+                client.send_name(name);
+            }
+            if let Some(description) = &cache.description {
+                client.send_description(description);
+            }
+        }
+        if client.version() >= WlOutput::MSG__DONE__SINCE {
+            client.send_done();
+        }
+    }
+
+    fn release(server: &Rc<Self>) {
+        if server.version() >= WlOutput::MSG__RELEASE__SINCE {
+            let _ = server.try_send_release();
+        }
+    }
+}
+
+impl WlOutputHandler for ServerObject<WlOutput> {
+    fn handle_geometry(
+        &mut self,
+        _slf: &Rc<WlOutput>,
+        x: i32,
+        y: i32,
+        physical_width: i32,
+        physical_height: i32,
+        subpixel: WlOutputSubpixel,
+        make: &str,
+        model: &str,
+        transform: WlOutputTransform,
+    ) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.cache.borrow_mut().geometry = Some(OutputGeometry {
+            x,
+            y,
+            physical_width,
+            physical_height,
+            subpixel,
+            make: make.to_string(),
+            model: model.to_string(),
+            transform,
+        });
+        mux.for_each_client(|c| {
+            c.send_geometry(
+                x,
+                y,
+                physical_width,
+                physical_height,
+                subpixel,
+                make,
+                model,
+                transform,
+            );
+        });
+    }
+
+    fn handle_mode(
+        &mut self,
+        _slf: &Rc<WlOutput>,
+        flags: WlOutputMode,
+        width: i32,
+        height: i32,
+        refresh: i32,
+    ) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        {
+            let modes = &mut mux.cache.borrow_mut().modes;
+            if flags.contains(WlOutputMode::CURRENT) {
+                for mode in &mut *modes {
+                    mode.flags.remove(WlOutputMode::CURRENT);
+                }
+            }
+            modes.retain(|m| (m.width, m.height, m.refresh) != (width, height, refresh));
+            modes.push(OutputMode {
+                flags,
+                width,
+                height,
+                refresh,
+            });
+        }
+        mux.for_each_client(|c| c.send_mode(flags, width, height, refresh));
+    }
+
+    fn handle_done(&mut self, _slf: &Rc<WlOutput>) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.for_each_client(|c| {
+            if c.version() >= WlOutput::MSG__DONE__SINCE {
+                c.send_done();
+            }
+        });
+    }
+
+    fn handle_scale(&mut self, _slf: &Rc<WlOutput>, factor: i32) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.cache.borrow_mut().scale = Some(factor);
+        mux.for_each_client(|c| {
+            if c.version() >= WlOutput::MSG__SCALE__SINCE {
+                c.send_scale(factor);
+            }
+        });
+    }
+
+    fn handle_name(&mut self, _slf: &Rc<WlOutput>, name: &str) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.cache.borrow_mut().name = Some(name.to_string());
+        mux.for_each_client(|c| {
+            if c.version() >= WlOutput::MSG__NAME__SINCE {
+                c.send_name(name);
+            }
+        });
+    }
+
+    fn handle_description(&mut self, _slf: &Rc<WlOutput>, description: &str) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.cache.borrow_mut().description = Some(description.to_string());
+        mux.for_each_client(|c| {
+            if c.version() >= WlOutput::MSG__DESCRIPTION__SINCE {
+                c.send_description(description);
+            }
+        });
+    }
+}
+
+impl WlOutputHandler for ClientObject<WlOutput> {
+    fn handle_release(&mut self, slf: &Rc<WlOutput>) {
+        self.release(slf);
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct SeatCache {
+    capabilities: Option<WlSeatCapability>,
+    name: Option<String>,
+}
+
+impl Multiplexed for WlSeat {}
+
+impl MultiplexedPrivate for WlSeat {
+    type Cache = SeatCache;
+
+    fn track_server(mux: Weak<Multiplexer<Self>>, server: &Rc<Self>) {
+        server.set_handler(ServerObject(mux));
+    }
+
+    fn track_client(mux: Rc<Multiplexer<Self>>, client: &Rc<Self>) {
+        let id = client.unique_id();
+        client.set_handler(ClientObject { mux, id });
+    }
+
+    fn replay(cache: &Self::Cache, client: &Rc<Self>) {
+        if let Some(capabilities) = cache.capabilities {
+            client.send_capabilities(capabilities);
+        }
+        if client.version() >= WlSeat::MSG__NAME__SINCE
+            && let Some(name) = &cache.name
+        {
+            client.send_name(name);
+        }
+    }
+
+    fn release(server: &Rc<Self>) {
+        if server.version() >= WlSeat::MSG__RELEASE__SINCE {
+            let _ = server.try_send_release();
+        }
+    }
+}
+
+impl WlSeatHandler for ServerObject<WlSeat> {
+    fn handle_capabilities(&mut self, _slf: &Rc<WlSeat>, capabilities: WlSeatCapability) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.cache.borrow_mut().capabilities = Some(capabilities);
+        mux.for_each_client(|c| c.send_capabilities(capabilities));
+    }
+
+    fn handle_name(&mut self, _slf: &Rc<WlSeat>, name: &str) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.cache.borrow_mut().name = Some(name.to_string());
+        mux.for_each_client(|c| {
+            if c.version() >= WlSeat::MSG__NAME__SINCE {
+                c.send_name(name);
+            }
+        });
+    }
+}
+
+impl WlSeatHandler for ClientObject<WlSeat> {
+    fn handle_get_pointer(&mut self, _slf: &Rc<WlSeat>, id: &Rc<WlPointer>) {
+        self.mux.server.send_get_pointer(id);
+    }
+
+    fn handle_get_keyboard(&mut self, _slf: &Rc<WlSeat>, id: &Rc<WlKeyboard>) {
+        self.mux.server.send_get_keyboard(id);
+    }
+
+    fn handle_get_touch(&mut self, _slf: &Rc<WlSeat>, id: &Rc<WlTouch>) {
+        self.mux.server.send_get_touch(id);
+    }
+
+    fn handle_release(&mut self, slf: &Rc<WlSeat>) {
+        self.release(slf);
+    }
+}
+
+/// The events of a zwp_linux_dmabuf_feedback_v1 object.
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+enum FeedbackEvent {
+    FormatTable(Rc<OwnedFd>, u32),
+    MainDevice(Vec<u8>),
+    TrancheDone,
+    TrancheTargetDevice(Vec<u8>),
+    TrancheFormats(Vec<u8>),
+    TrancheFlags(ZwpLinuxDmabufFeedbackV1TrancheFlags),
+}
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+#[derive(Default)]
+pub(crate) struct FeedbackCache {
+    /// The events of the last complete feedback.
+    current: Option<Vec<FeedbackEvent>>,
+    /// The events received since the last done event.
+    pending: Vec<FeedbackEvent>,
+}
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+impl FeedbackEvent {
+    fn send(&self, feedback: &ZwpLinuxDmabufFeedbackV1) {
+        match self {
+            FeedbackEvent::FormatTable(fd, size) => feedback.send_format_table(fd, *size),
+            FeedbackEvent::MainDevice(device) => feedback.send_main_device(device),
+            FeedbackEvent::TrancheDone => feedback.send_tranche_done(),
+            FeedbackEvent::TrancheTargetDevice(device) => {
+                feedback.send_tranche_target_device(device)
+            }
+            FeedbackEvent::TrancheFormats(indices) => feedback.send_tranche_formats(indices),
+            FeedbackEvent::TrancheFlags(flags) => feedback.send_tranche_flags(*flags),
+        }
+    }
+}
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+impl Multiplexed for ZwpLinuxDmabufFeedbackV1 {}
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+impl MultiplexedPrivate for ZwpLinuxDmabufFeedbackV1 {
+    type Cache = FeedbackCache;
+
+    fn track_server(mux: Weak<Multiplexer<Self>>, server: &Rc<Self>) {
+        server.set_handler(ServerObject(mux));
+    }
+
+    fn track_client(mux: Rc<Multiplexer<Self>>, client: &Rc<Self>) {
+        let id = client.unique_id();
+        client.set_handler(ClientObject { mux, id });
+    }
+
+    fn replay(cache: &Self::Cache, client: &Rc<Self>) {
+        if let Some(current) = &cache.current {
+            for event in current {
+                event.send(client);
+            }
+            client.send_done();
+        }
+        for event in &cache.pending {
+            event.send(client);
+        }
+    }
+
+    fn release(server: &Rc<Self>) {
+        let _ = server.try_send_destroy();
+    }
+}
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+impl ServerObject<ZwpLinuxDmabufFeedbackV1> {
+    fn event(&self, event: FeedbackEvent) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        mux.for_each_client(|c| event.send(c));
+        mux.cache.borrow_mut().pending.push(event);
+    }
+}
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+impl ZwpLinuxDmabufFeedbackV1Handler for ServerObject<ZwpLinuxDmabufFeedbackV1> {
+    fn handle_done(&mut self, _slf: &Rc<ZwpLinuxDmabufFeedbackV1>) {
+        let Some(mux) = self.0.upgrade() else {
+            return;
+        };
+        {
+            let cache = &mut *mux.cache.borrow_mut();
+            cache.current = Some(std::mem::take(&mut cache.pending));
+        }
+        mux.for_each_client(|c| c.send_done());
+    }
+
+    fn handle_format_table(
+        &mut self,
+        _slf: &Rc<ZwpLinuxDmabufFeedbackV1>,
+        fd: &Rc<OwnedFd>,
+        size: u32,
+    ) {
+        self.event(FeedbackEvent::FormatTable(fd.clone(), size));
+    }
+
+    fn handle_main_device(&mut self, _slf: &Rc<ZwpLinuxDmabufFeedbackV1>, device: &[u8]) {
+        self.event(FeedbackEvent::MainDevice(device.to_vec()));
+    }
+
+    fn handle_tranche_done(&mut self, _slf: &Rc<ZwpLinuxDmabufFeedbackV1>) {
+        self.event(FeedbackEvent::TrancheDone);
+    }
+
+    fn handle_tranche_target_device(&mut self, _slf: &Rc<ZwpLinuxDmabufFeedbackV1>, device: &[u8]) {
+        self.event(FeedbackEvent::TrancheTargetDevice(device.to_vec()));
+    }
+
+    fn handle_tranche_formats(&mut self, _slf: &Rc<ZwpLinuxDmabufFeedbackV1>, indices: &[u8]) {
+        self.event(FeedbackEvent::TrancheFormats(indices.to_vec()));
+    }
+
+    fn handle_tranche_flags(
+        &mut self,
+        _slf: &Rc<ZwpLinuxDmabufFeedbackV1>,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        self.event(FeedbackEvent::TrancheFlags(flags));
+    }
+}
+
+#[cfg(feature = "protocol-linux_dmabuf_v1")]
+impl ZwpLinuxDmabufFeedbackV1Handler for ClientObject<ZwpLinuxDmabufFeedbackV1> {
+    fn handle_destroy(&mut self, slf: &Rc<ZwpLinuxDmabufFeedbackV1>) {
+        self.release(slf);
+    }
+}
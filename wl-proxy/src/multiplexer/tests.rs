@@ -0,0 +1,264 @@
+#[cfg(feature = "headless")]
+use {
+    crate::{
+        baseline::Baseline,
+        fixed::Fixed,
+        headless::Headless,
+        multiplexer::Multiplexer,
+        object::{ConcreteObject, Object, ObjectRcUtils},
+        protocols::wayland::{
+            wl_callback::{WlCallback, WlCallbackHandler},
+            wl_compositor::WlCompositor,
+            wl_display::{WlDisplay, WlDisplayHandler},
+            wl_output::{
+                WlOutput, WlOutputHandler, WlOutputMode, WlOutputSubpixel, WlOutputTransform,
+            },
+            wl_pointer::{WlPointer, WlPointerHandler},
+            wl_registry::{WlRegistry, WlRegistryHandler},
+            wl_seat::{WlSeat, WlSeatCapability, WlSeatHandler},
+            wl_surface::WlSurface,
+        },
+        state::State,
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+#[cfg(feature = "headless")]
+#[derive(Clone)]
+struct Proxy {
+    output: Rc<Multiplexer<WlOutput>>,
+    seat: Rc<Multiplexer<WlSeat>>,
+}
+
+#[cfg(feature = "headless")]
+impl WlDisplayHandler for Proxy {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(self.clone());
+        slf.send_get_registry(registry);
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlRegistryHandler for Proxy {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        if let Some(output) = id.try_downcast::<WlOutput>() {
+            self.output.add(&output).unwrap();
+            return;
+        }
+        if let Some(seat) = id.try_downcast::<WlSeat>() {
+            self.seat.add(&seat).unwrap();
+            return;
+        }
+        slf.send_bind(name, id);
+    }
+}
+
+#[cfg(feature = "headless")]
+struct Done(Rc<Cell<bool>>);
+
+#[cfg(feature = "headless")]
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+/// Records the events received by a client.
+#[cfg(feature = "headless")]
+#[derive(Clone, Default)]
+struct Client(Rc<RefCell<Vec<String>>>);
+
+#[cfg(feature = "headless")]
+impl Client {
+    fn take(&self) -> Vec<String> {
+        self.0.take()
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlOutputHandler for Client {
+    fn handle_geometry(
+        &mut self,
+        _slf: &Rc<WlOutput>,
+        _x: i32,
+        _y: i32,
+        _physical_width: i32,
+        _physical_height: i32,
+        _subpixel: WlOutputSubpixel,
+        _make: &str,
+        _model: &str,
+        _transform: WlOutputTransform,
+    ) {
+        self.0.borrow_mut().push("geometry".to_string());
+    }
+
+    fn handle_mode(
+        &mut self,
+        _slf: &Rc<WlOutput>,
+        _flags: WlOutputMode,
+        width: i32,
+        height: i32,
+        _refresh: i32,
+    ) {
+        self.0.borrow_mut().push(format!("mode {width}x{height}"));
+    }
+
+    fn handle_done(&mut self, _slf: &Rc<WlOutput>) {
+        self.0.borrow_mut().push("done".to_string());
+    }
+
+    fn handle_scale(&mut self, _slf: &Rc<WlOutput>, factor: i32) {
+        self.0.borrow_mut().push(format!("scale {factor}"));
+    }
+
+    fn handle_name(&mut self, _slf: &Rc<WlOutput>, name: &str) {
+        self.0.borrow_mut().push(format!("name {name}"));
+    }
+
+    fn handle_description(&mut self, _slf: &Rc<WlOutput>, _description: &str) {
+        self.0.borrow_mut().push("description".to_string());
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlSeatHandler for Client {
+    fn handle_capabilities(&mut self, _slf: &Rc<WlSeat>, capabilities: WlSeatCapability) {
+        self.0
+            .borrow_mut()
+            .push(format!("capabilities {}", capabilities.0));
+    }
+
+    fn handle_name(&mut self, _slf: &Rc<WlSeat>, name: &str) {
+        self.0.borrow_mut().push(format!("seat {name}"));
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlPointerHandler for Client {
+    fn handle_enter(
+        &mut self,
+        _slf: &Rc<WlPointer>,
+        _serial: u32,
+        _surface: &Rc<WlSurface>,
+        _surface_x: Fixed,
+        _surface_y: Fixed,
+    ) {
+        self.0.borrow_mut().push("pointer.enter".to_string());
+    }
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn output_and_seat() {
+    let headless = Headless::new().unwrap();
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let _destructor = proxy.create_destructor();
+
+    // The proxy binds the globals once.
+    let registry = proxy.display().new_send_get_registry();
+    let output = proxy.create_object::<WlOutput>(WlOutput::XML_VERSION);
+    registry.send_bind(6, output.clone());
+    let seat = proxy.create_object::<WlSeat>(WlSeat::XML_VERSION);
+    registry.send_bind(5, seat.clone());
+    let handler = Proxy {
+        output: Multiplexer::new(&output),
+        seat: Multiplexer::new(&seat),
+    };
+    let done = Rc::new(Cell::new(false));
+    proxy
+        .display()
+        .new_send_sync()
+        .set_handler(Done(done.clone()));
+    while !done.get() {
+        dispatch_blocking([&proxy, headless.state()]).unwrap();
+    }
+
+    let connect = || {
+        let (proxy_client, client_fd) = proxy.connect().unwrap();
+        proxy_client.display.set_handler(handler.clone());
+        let client = State::builder(Baseline::ALL_OF_THEM)
+            .with_server_fd(&Rc::new(client_fd))
+            .build()
+            .unwrap();
+        let registry = client.display().new_send_get_registry();
+        (client, registry)
+    };
+    let roundtrip = |client: &Rc<State>| {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([client, &proxy, headless.state()]).unwrap();
+        }
+    };
+
+    // Both clients receive the cached state according to their versions.
+    let (client_a, registry_a) = connect();
+    let _destructor_a = client_a.create_destructor();
+    let events_a = Client::default();
+    let output_a = client_a.create_object::<WlOutput>(4);
+    output_a.set_handler(events_a.clone());
+    registry_a.send_bind(6, output_a.clone());
+    let seat_a = client_a.create_object::<WlSeat>(WlSeat::XML_VERSION);
+    seat_a.set_handler(events_a.clone());
+    registry_a.send_bind(5, seat_a.clone());
+    roundtrip(&client_a);
+    assert_eq!(
+        events_a.take(),
+        [
+            "geometry",
+            "mode 1920x1080",
+            "scale 1",
+            "name HEADLESS-1",
+            "description",
+            "done",
+            "capabilities 3",
+            "seat headless",
+        ],
+    );
+
+    let (client_b, registry_b) = connect();
+    let _destructor_b = client_b.create_destructor();
+    let events_b = Client::default();
+    let output_b = client_b.create_object::<WlOutput>(3);
+    output_b.set_handler(events_b.clone());
+    registry_b.send_bind(6, output_b.clone());
+    roundtrip(&client_b);
+    assert_eq!(
+        events_b.take(),
+        ["geometry", "mode 1920x1080", "scale 1", "done"],
+    );
+    assert_eq!(handler.output.clients().len(), 2);
+
+    // Objects created via a shared wl_seat are associated with the compositor.
+    let compositor = client_a.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+    registry_a.send_bind(1, compositor.clone());
+    let surface = compositor.new_send_create_surface();
+    seat_a.new_send_get_pointer().set_handler(events_a.clone());
+    surface.send_commit();
+    roundtrip(&client_a);
+    headless.pointer_enter(&headless.surfaces()[0], 1.0, 1.0);
+    roundtrip(&client_a);
+    assert_eq!(events_a.take(), ["pointer.enter"]);
+
+    // The compositor object is released with the last client object.
+    output_a.send_release();
+    roundtrip(&client_a);
+    assert_eq!(handler.output.clients().len(), 1);
+    assert!(!handler.output.is_destroyed());
+    output_b.send_release();
+    roundtrip(&client_b);
+    assert!(handler.output.clients().is_empty());
+    assert!(handler.output.is_destroyed());
+    let late = proxy.create_object::<WlOutput>(1);
+    assert!(handler.output.add(&late).is_err());
+}
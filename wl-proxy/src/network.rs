@@ -0,0 +1,229 @@
+//! Wayland connections over TCP and vsock.
+//!
+//! This module requires the `network` feature.
+//!
+//! Wayland connections normally use unix sockets and pass file descriptors between the
+//! peers. This module allows a proxy on one host to use a proxy on another host as its
+//! server:
+//!
+//! - On the host that runs the compositor, a state accepts connections with a
+//!   [`NetworkListener`] and adds them via [`State::add_network_client`].
+//! - On the host that runs the application, a state connects with [`connect`] and uses
+//!   the connection via [`StateBuilder::with_network_server`].
+//!
+//! Messages sent over such connections are serialized into the byte stream. File
+//! descriptors cannot be sent and are replaced as follows:
+//!
+//! - File descriptors that refer to regular files, such as memfds and keymaps, are
+//!   copied. The receiver creates a memfd with the same contents.
+//! - The file descriptors of wl_shm pools are copied when the pool is created. After
+//!   that, the contents of the pool are kept in sync whenever a buffer from the pool is
+//!   committed. If the buffer was committed before, only the damaged parts of the
+//!   buffer are considered. Only the parts of the buffer that differ from the contents
+//!   that were previously sent are transmitted.
+//! - All other file descriptors, such as pipes and dmabufs, are replaced by
+//!   `/dev/null`. Proxies should hide globals that depend on such file descriptors
+//!   from their clients.
+//!
+//! Replication is unidirectional. Changes that the receiver of a file makes to the file
+//! are not sent back.
+//!
+//! # Example
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use wl_proxy::{baseline::Baseline, network::{self, NetworkAddress, NetworkListener}, state::State};
+//! # fn f() {
+//! // On the host that runs the compositor:
+//! let listener = NetworkListener::bind(&"127.0.0.1:7000".parse().unwrap()).unwrap();
+//! let compositor_proxy = State::builder(Baseline::ALL_OF_THEM).build().unwrap();
+//! // On the host that runs the application:
+//! let connection = network::connect(&"127.0.0.1:7000".parse().unwrap()).unwrap();
+//! let application_proxy = State::builder(Baseline::ALL_OF_THEM)
+//!     .with_network_server(&Rc::new(connection))
+//!     .build()
+//!     .unwrap();
+//! // On the host that runs the compositor:
+//! let connection = listener.accept().unwrap();
+//! compositor_proxy.add_network_client(&Rc::new(connection)).unwrap();
+//! # }
+//! ```
+//!
+//! [`State::add_network_client`]: crate::state::State::add_network_client
+//! [`StateBuilder::with_network_server`]: crate::state::StateBuilder::with_network_server
+
+use {
+    std::{
+        fmt::{Display, Formatter},
+        io,
+        net::{SocketAddr, TcpListener, TcpStream},
+        os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd},
+        str::FromStr,
+    },
+    thiserror::Error,
+    uapi::c,
+};
+
+#[cfg(test)]
+mod tests;
+pub(crate) mod transport;
+
+/// The address of a network peer.
+///
+/// Addresses can be parsed from strings of the form `HOST:PORT` for TCP and
+/// `vsock:CID:PORT` for vsock.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum NetworkAddress {
+    /// A TCP address.
+    Tcp(SocketAddr),
+    /// A vsock address.
+    Vsock {
+        /// The context ID.
+        cid: u32,
+        /// The port.
+        port: u32,
+    },
+}
+
+/// A socket that listens for network connections.
+pub struct NetworkListener {
+    socket: OwnedFd,
+    address: NetworkAddress,
+}
+
+/// An error emitted by the functions in this module.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct NetworkError(#[from] NetworkErrorKind);
+
+#[derive(Debug, Error)]
+enum NetworkErrorKind {
+    #[error("could not parse the address {0:?}")]
+    ParseAddress(String),
+    #[error("could not connect to {0}")]
+    Connect(NetworkAddress, #[source] io::Error),
+    #[error("could not listen on {0}")]
+    Listen(NetworkAddress, #[source] io::Error),
+    #[error("could not accept a connection")]
+    Accept(#[source] io::Error),
+}
+
+impl Display for NetworkAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkAddress::Tcp(addr) => Display::fmt(addr, f),
+            NetworkAddress::Vsock { cid, port } => write!(f, "vsock:{cid}:{port}"),
+        }
+    }
+}
+
+impl FromStr for NetworkAddress {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || NetworkErrorKind::ParseAddress(s.to_string());
+        if let Some(vsock) = s.strip_prefix("vsock:") {
+            let (cid, port) = vsock.split_once(':').ok_or_else(err)?;
+            return Ok(NetworkAddress::Vsock {
+                cid: cid.parse().map_err(|_| err())?,
+                port: port.parse().map_err(|_| err())?,
+            });
+        }
+        let addr = s.parse().map_err(|_| err())?;
+        Ok(NetworkAddress::Tcp(addr))
+    }
+}
+
+/// Connects to a network address.
+///
+/// The returned file descriptor can be used with
+/// [`StateBuilder::with_network_server`](crate::state::StateBuilder::with_network_server).
+pub fn connect(address: &NetworkAddress) -> Result<OwnedFd, NetworkError> {
+    let res = match *address {
+        NetworkAddress::Tcp(addr) => TcpStream::connect(addr).and_then(|stream| {
+            stream.set_nodelay(true)?;
+            Ok(stream.into())
+        }),
+        NetworkAddress::Vsock { cid, port } => vsock_socket().and_then(|socket| {
+            uapi::connect(socket.raw(), &vsock_addr(cid, port))?;
+            Ok(socket.into())
+        }),
+    };
+    res.map_err(|e| NetworkErrorKind::Connect(*address, e).into())
+}
+
+impl NetworkListener {
+    /// Starts listening for connections on an address.
+    ///
+    /// If the address uses port 0, the port is chosen by the operating system. The
+    /// actual address can be retrieved with [`Self::address`].
+    pub fn bind(address: &NetworkAddress) -> Result<Self, NetworkError> {
+        let res = match *address {
+            NetworkAddress::Tcp(addr) => TcpListener::bind(addr).and_then(|listener| {
+                let address = NetworkAddress::Tcp(listener.local_addr()?);
+                Ok((listener.into(), address))
+            }),
+            NetworkAddress::Vsock { cid, port } => vsock_socket().and_then(|socket| {
+                uapi::bind(socket.raw(), &vsock_addr(cid, port))?;
+                uapi::listen(socket.raw(), 128)?;
+                let mut addr: c::sockaddr_vm = uapi::pod_zeroed();
+                uapi::getsockname(socket.raw(), &mut addr)?;
+                let address = NetworkAddress::Vsock {
+                    cid: addr.svm_cid,
+                    port: addr.svm_port,
+                };
+                Ok((socket.into(), address))
+            }),
+        };
+        let (socket, address) = res.map_err(|e| NetworkErrorKind::Listen(*address, e))?;
+        Ok(Self { socket, address })
+    }
+
+    /// Returns the address of the listener.
+    pub fn address(&self) -> &NetworkAddress {
+        &self.address
+    }
+
+    /// Accepts a connection.
+    ///
+    /// This function blocks until a connection is available. The returned file
+    /// descriptor can be used with
+    /// [`State::add_network_client`](crate::state::State::add_network_client).
+    pub fn accept(&self) -> Result<OwnedFd, NetworkError> {
+        let (socket, _) =
+            uapi::accept4(self.socket.as_raw_fd(), None::<&mut [u8]>, c::SOCK_CLOEXEC)
+                .map_err(|e| NetworkErrorKind::Accept(e.into()))?;
+        if let NetworkAddress::Tcp(_) = self.address {
+            uapi::setsockopt(
+                socket.raw(),
+                c::IPPROTO_TCP,
+                c::TCP_NODELAY,
+                &(1 as c::c_int),
+            )
+            .map_err(|e| NetworkErrorKind::Accept(e.into()))?;
+        }
+        Ok(socket.into())
+    }
+}
+
+impl AsFd for NetworkListener {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.socket.as_fd()
+    }
+}
+
+fn vsock_socket() -> io::Result<uapi::OwnedFd> {
+    Ok(uapi::socket(
+        c::AF_VSOCK,
+        c::SOCK_STREAM | c::SOCK_CLOEXEC,
+        0,
+    )?)
+}
+
+fn vsock_addr(cid: u32, port: u32) -> c::sockaddr_vm {
+    let mut addr: c::sockaddr_vm = uapi::pod_zeroed();
+    addr.svm_family = c::AF_VSOCK as _;
+    addr.svm_cid = cid;
+    addr.svm_port = port;
+    addr
+}
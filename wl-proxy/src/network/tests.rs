@@ -0,0 +1,230 @@
+use crate::network::NetworkAddress;
+#[cfg(feature = "headless")]
+use {
+    crate::{
+        baseline::Baseline,
+        client::ClientHandler,
+        headless::Headless,
+        interceptor::{InterceptedMessage, Interception},
+        network::{self, NetworkListener},
+        protocols::wayland::{
+            wl_buffer::WlBuffer,
+            wl_compositor::WlCompositor,
+            wl_keyboard::{WlKeyboard, WlKeyboardHandler, WlKeyboardKeymapFormat},
+            wl_seat::WlSeat,
+            wl_shm::{WlShm, WlShmFormat},
+        },
+        state::State,
+        test_framework::{
+            headless::{Done, HeadlessEnv},
+            install_logger,
+            proxy::dispatch_blocking,
+        },
+    },
+    std::{
+        cell::{Cell, RefCell},
+        os::fd::{AsRawFd, OwnedFd},
+        rc::Rc,
+    },
+    uapi::c,
+};
+
+#[test]
+fn parse_address() {
+    let tcp: NetworkAddress = "127.0.0.1:7000".parse().unwrap();
+    assert_eq!(tcp, NetworkAddress::Tcp("127.0.0.1:7000".parse().unwrap()));
+    assert_eq!(tcp.to_string(), "127.0.0.1:7000");
+    let tcp: NetworkAddress = "[::1]:7000".parse().unwrap();
+    assert_eq!(tcp.to_string(), "[::1]:7000");
+    let vsock: NetworkAddress = "vsock:3:7000".parse().unwrap();
+    assert_eq!(vsock, NetworkAddress::Vsock { cid: 3, port: 7000 });
+    assert_eq!(vsock.to_string(), "vsock:3:7000");
+    assert!("vsock:3".parse::<NetworkAddress>().is_err());
+    assert!("vsock:x:7000".parse::<NetworkAddress>().is_err());
+    assert!("localhost".parse::<NetworkAddress>().is_err());
+}
+
+#[cfg(feature = "headless")]
+fn env() -> HeadlessEnv {
+    install_logger();
+    let headless = Headless::new().unwrap();
+    let remote = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let listener = NetworkListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    let connection = network::connect(listener.address()).unwrap();
+    let local = State::builder(Baseline::ALL_OF_THEM)
+        .with_network_server(&Rc::new(connection))
+        .build()
+        .unwrap();
+    let connection = listener.accept().unwrap();
+    remote.add_network_client(&Rc::new(connection)).unwrap();
+    let (_, client_fd) = local.connect().unwrap();
+    HeadlessEnv::with_proxies(headless, vec![remote, local], client_fd)
+}
+
+#[cfg(feature = "headless")]
+#[derive(Default, Clone)]
+struct Keymap(Rc<RefCell<Option<Rc<OwnedFd>>>>);
+
+#[cfg(feature = "headless")]
+impl WlKeyboardHandler for Keymap {
+    fn handle_keymap(
+        &mut self,
+        _slf: &Rc<WlKeyboard>,
+        _format: WlKeyboardKeymapFormat,
+        fd: &Rc<OwnedFd>,
+        _size: u32,
+    ) {
+        *self.0.borrow_mut() = Some(fd.clone());
+    }
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn shm_replication() {
+    const WIDTH: i32 = 32;
+    const HEIGHT: i32 = 32;
+    let pixel_offset = |x: i32, y: i32| (y * WIDTH * 4 + x * 4) as c::off_t;
+
+    let env = env();
+    let compositor = env.bind::<WlCompositor>(1);
+    let shm = env.bind::<WlShm>(2);
+    let surface = compositor.new_send_create_surface();
+    let fd = uapi::memfd_create("test", c::MFD_CLOEXEC).unwrap();
+    let data: Vec<u8> = (0..WIDTH * HEIGHT)
+        .flat_map(|_| 0xff0000ffu32.to_ne_bytes())
+        .collect();
+    uapi::write(fd.raw(), &data[..]).unwrap();
+    let fd: OwnedFd = fd.into();
+    let pool = shm.new_send_create_pool(&Rc::new(fd.try_clone().unwrap()), data.len() as i32);
+    let buffer: Rc<WlBuffer> =
+        pool.new_send_create_buffer(0, WIDTH, HEIGHT, WIDTH * 4, WlShmFormat::ARGB8888);
+    pool.send_destroy();
+    surface.send_attach(Some(&buffer), 0, 0);
+    surface.send_commit();
+    env.roundtrip();
+
+    let headless_surface = &env.headless.surfaces()[0];
+    let image = headless_surface.image().unwrap();
+    assert_eq!((image.width(), image.height()), (WIDTH, HEIGHT));
+    assert_eq!(image.pixel(0, 0), Some(0xff0000ff));
+    assert_eq!(image.pixel(WIDTH - 1, HEIGHT - 1), Some(0xff0000ff));
+
+    // Only damaged parts of the buffer are replicated.
+    let green = 0xff00ff00u32.to_ne_bytes();
+    uapi::pwrite(fd.as_raw_fd(), &green, pixel_offset(5, 7)).unwrap();
+    uapi::pwrite(fd.as_raw_fd(), &green, pixel_offset(20, 20)).unwrap();
+    surface.send_attach(Some(&buffer), 0, 0);
+    surface.send_damage_buffer(5, 7, 1, 1);
+    surface.send_commit();
+    env.roundtrip();
+    let image = headless_surface.image().unwrap();
+    assert_eq!(image.pixel(5, 7), Some(0xff00ff00));
+    assert_eq!(image.pixel(20, 20), Some(0xff0000ff));
+
+    // Surface damage covers the rest.
+    surface.send_attach(Some(&buffer), 0, 0);
+    surface.send_damage(0, 0, WIDTH, HEIGHT);
+    surface.send_commit();
+    env.roundtrip();
+    let image = headless_surface.image().unwrap();
+    assert_eq!(image.pixel(5, 7), Some(0xff00ff00));
+    assert_eq!(image.pixel(20, 20), Some(0xff00ff00));
+    assert_eq!(image.pixel(0, 0), Some(0xff0000ff));
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn keymap() {
+    let env = env();
+    let seat = env.bind::<WlSeat>(5);
+    let keymap = Keymap::default();
+    seat.new_send_get_keyboard().set_handler(keymap.clone());
+    env.roundtrip();
+
+    let fd = keymap.0.borrow().clone().unwrap();
+    let stat = uapi::fstat(fd.as_raw_fd()).unwrap();
+    assert_eq!(stat.st_mode & c::S_IFMT, c::S_IFREG);
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn invalid_pool_sizes() {
+    for resize in [false, true] {
+        let env = env();
+        let shm = env.bind::<WlShm>(2);
+        let fd = uapi::memfd_create("test", c::MFD_CLOEXEC).unwrap();
+        uapi::ftruncate(fd.raw(), 4096).unwrap();
+        let fd = Rc::new(fd.into());
+        match resize {
+            false => {
+                shm.new_send_create_pool(&fd, -1);
+            }
+            true => {
+                shm.new_send_create_pool(&fd, 4096).send_resize(-1);
+            }
+        }
+        let done = Rc::new(Cell::new(false));
+        env.client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        let mut states = vec![&env.client];
+        states.extend(&env.proxies);
+        states.push(env.headless.state());
+        // The size is rejected by the compositor instead of aborting the proxy.
+        loop {
+            if dispatch_blocking(&states).is_err() {
+                break;
+            }
+            assert!(!done.get());
+        }
+        assert!(env.proxies[1].is_not_destroyed());
+    }
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn oversized_message() {
+    struct Disconnected(Rc<Cell<bool>>);
+
+    impl ClientHandler for Disconnected {
+        fn disconnected(self: Box<Self>) {
+            self.0.set(true);
+        }
+    }
+
+    install_logger();
+    let intercepted = Rc::new(Cell::new(false));
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .with_interceptor({
+            let intercepted = intercepted.clone();
+            move |_: &InterceptedMessage<'_>| {
+                intercepted.set(true);
+                Interception::Forward
+            }
+        })
+        .build()
+        .unwrap();
+    let _destructor = state.create_destructor();
+    let (a, b) = uapi::socketpair(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0).unwrap();
+    let client = state.add_network_client(&Rc::new(a.into())).unwrap();
+    let disconnected = Rc::new(Cell::new(false));
+    client.set_handler(Disconnected(disconnected.clone()));
+
+    // A wl_display.sync request whose size exceeds the maximum message size.
+    let len = 8192u32;
+    let mut frame = vec![];
+    for word in [0, len, 1, len << 16] {
+        frame.extend_from_slice(&word.to_le_bytes());
+    }
+    frame.resize(8 + len as usize, 0);
+    uapi::write(b.raw(), &frame[..]).unwrap();
+    while !disconnected.get() {
+        state.dispatch_blocking().unwrap();
+    }
+    assert!(!intercepted.get());
+}
@@ -0,0 +1,627 @@
+use {
+    crate::{
+        endpoint::Endpoint,
+        object::{Object, ObjectCoreApi},
+        protocols::{ObjectInterface, wayland::wl_output::WlOutputTransform},
+        trans::{FlushResult, MAX_MESSAGE_SIZE, TransError},
+    },
+    isnt::std_1::{primitive::IsntSliceExt, vec::IsntVecExt},
+    std::{
+        cell::RefCell,
+        collections::{HashMap, VecDeque},
+        io, mem,
+        os::fd::{AsRawFd, OwnedFd, RawFd},
+        rc::Rc,
+    },
+    uapi::{Errno, c},
+};
+
+const TAG_MESSAGE: u32 = 0;
+const TAG_FILE: u32 = 1;
+const TAG_RESIZE: u32 = 2;
+const TAG_WRITE: u32 = 3;
+const TAG_FORGET: u32 = 4;
+const TAG_PLACEHOLDER: u32 = 5;
+
+const HEADER_SIZE: usize = 8;
+/// The maximum number of bytes in a single write frame.
+const MAX_WRITE: usize = 64 * 1024;
+const MAX_FRAME_SIZE: usize = MAX_WRITE + 16;
+/// The granularity at which file contents are compared.
+const BLOCK_SIZE: usize = 64;
+/// The maximum size of a wl_shm pool that is replicated.
+///
+/// The sender keeps a copy of the contents of each pool. Larger pools are replaced by
+/// `/dev/null`.
+const MAX_POOL_SIZE: usize = 256 * 1024 * 1024;
+const READ_SIZE: usize = 128 * 1024;
+
+/// A transport that serializes file descriptors into the byte stream.
+///
+/// Messages are sent as frames. File descriptors that refer to regular files are
+/// replaced by a copy of the file that is created by the receiver. The contents of
+/// wl_shm pools are kept in sync by sending the changed parts of the committed
+/// buffers. All other file descriptors, and wl_shm pools with an invalid size or a size
+/// larger than 256 MiB, are replaced by `/dev/null`.
+pub(crate) struct Transport {
+    sends_requests: bool,
+    sender: RefCell<Sender>,
+    receiver: RefCell<Receiver>,
+}
+
+#[derive(Default)]
+struct Sender {
+    bytes: Vec<u8>,
+    written: usize,
+    next_id: u32,
+    forgotten: Rc<RefCell<Vec<u32>>>,
+    pools: HashMap<u64, Rc<Mirror>>,
+    buffers: HashMap<u64, Buffer>,
+    surfaces: HashMap<u64, Surface>,
+}
+
+/// A file whose contents are replicated on the other side.
+struct Mirror {
+    id: u32,
+    fd: Rc<OwnedFd>,
+    /// The contents that the other side is known to have.
+    shadow: RefCell<Vec<u8>>,
+    forgotten: Rc<RefCell<Vec<u32>>>,
+}
+
+struct Buffer {
+    mirror: Rc<Mirror>,
+    offset: usize,
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+#[derive(Default)]
+struct Surface {
+    pending_buffer: Option<Option<u64>>,
+    buffer: Option<u64>,
+    pending_scale: Option<i32>,
+    scale: i32,
+    pending_transform: Option<u32>,
+    transform: u32,
+    surface_damage: Vec<[i32; 4]>,
+    buffer_damage: Vec<[i32; 4]>,
+}
+
+#[derive(Default)]
+struct Receiver {
+    bytes: Vec<u8>,
+    start: usize,
+    files: HashMap<u32, Rc<OwnedFd>>,
+}
+
+impl Transport {
+    /// Creates a new transport.
+    ///
+    /// `sends_requests` must be true for server connections. Only requests are used to
+    /// track shm pools.
+    pub(crate) fn new(sends_requests: bool) -> Self {
+        Self {
+            sends_requests,
+            sender: Default::default(),
+            receiver: Default::default(),
+        }
+    }
+
+    pub(crate) fn flush(&self, endpoint: &Endpoint) -> Result<FlushResult, TransError> {
+        let sender = &mut *self.sender.borrow_mut();
+        let mut res = Ok(());
+        endpoint.outgoing.borrow_mut().drain(|msg, fds| {
+            if res.is_ok() {
+                res = sender.encode(endpoint, self.sends_requests, msg, fds);
+            }
+        });
+        res.map_err(TransError::ReplicateFile)?;
+        sender.forget();
+        sender.write(endpoint.socket.borrow().as_raw_fd())
+    }
+
+    pub(crate) fn read_message<'a>(
+        &self,
+        socket: RawFd,
+        may_read_from_socket: &mut bool,
+        message: &'a mut Vec<u32>,
+        fds: &mut VecDeque<Rc<OwnedFd>>,
+    ) -> Result<Option<&'a mut [u32]>, TransError> {
+        let receiver = &mut *self.receiver.borrow_mut();
+        loop {
+            if receiver.next_frame(message, fds)? {
+                return Ok(Some(message));
+            }
+            if !mem::take(may_read_from_socket) {
+                return Ok(None);
+            }
+            receiver.read(socket)?;
+        }
+    }
+
+    /// Discards all state associated with the current connection.
+    #[cfg(feature = "recovery")]
+    pub(crate) fn reset(&self) {
+        *self.sender.borrow_mut() = Default::default();
+        *self.receiver.borrow_mut() = Default::default();
+    }
+}
+
+impl Sender {
+    fn encode(
+        &mut self,
+        endpoint: &Endpoint,
+        is_request: bool,
+        msg: &[u32],
+        fds: Vec<Rc<OwnedFd>>,
+    ) -> io::Result<()> {
+        let mut fds = fds.into_iter();
+        if is_request && let Some(object) = endpoint.lookup(msg[0]) {
+            self.track(endpoint, &object, msg, &mut fds)?;
+        }
+        for fd in fds {
+            self.send_file(&fd, None)?;
+        }
+        self.frame(TAG_MESSAGE, msg.len() * 4, |bytes| {
+            for word in msg {
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+        });
+        Ok(())
+    }
+
+    /// Updates the replication state and sends the file descriptors of requests that
+    /// create or update shm pools.
+    fn track(
+        &mut self,
+        endpoint: &Endpoint,
+        object: &Rc<dyn Object>,
+        msg: &[u32],
+        fds: &mut impl Iterator<Item = Rc<OwnedFd>>,
+    ) -> io::Result<()> {
+        use ObjectInterface as I;
+        let uid = |id: u32| match id {
+            0 => None,
+            _ => endpoint.lookup(id).map(|o| o.unique_id()),
+        };
+        let this = object.unique_id();
+        let opcode = msg[1] & 0xffff;
+        let args = &msg[2..];
+        match (object.core().interface, opcode) {
+            (I::WlShm, 0) => {
+                if let Some(fd) = fds.next() {
+                    let Some(size) = pool_size(args[1]) else {
+                        log::warn!("Cannot replicate a wl_shm pool of size {}", args[1] as i32);
+                        self.frame(TAG_PLACEHOLDER, 0, |_| ());
+                        return Ok(());
+                    };
+                    let mirror = self.send_file(&fd, Some(size))?;
+                    if let Some(pool) = uid(args[0]) {
+                        self.pools.insert(pool, mirror);
+                    }
+                }
+            }
+            (I::WlShmPool, 0) => {
+                let [offset, width, height, stride] =
+                    [args[1], args[2], args[3], args[4]].map(|v| v as i32);
+                if let Some(mirror) = self.pools.get(&this)
+                    && let Some(buffer) = uid(args[0])
+                    && offset >= 0
+                    && width > 0
+                    && height > 0
+                    && stride > 0
+                {
+                    let buffer_info = Buffer {
+                        mirror: mirror.clone(),
+                        offset: offset as usize,
+                        width: width as usize,
+                        height: height as usize,
+                        stride: stride as usize,
+                    };
+                    self.buffers.insert(buffer, buffer_info);
+                }
+            }
+            (I::WlShmPool, 1) => {
+                self.pools.remove(&this);
+            }
+            (I::WlShmPool, 2) => {
+                if let Some(mirror) = self.pools.get(&this).cloned() {
+                    let old_size = mirror.shadow.borrow().len();
+                    let Some(size) = pool_size(args[0]).filter(|&s| s >= old_size) else {
+                        log::warn!("Cannot resize a wl_shm pool to {}", args[0] as i32);
+                        self.pools.remove(&this);
+                        return Ok(());
+                    };
+                    mirror.shadow.borrow_mut().resize(size, 0);
+                    self.frame(TAG_RESIZE, 12, |bytes| {
+                        bytes.extend_from_slice(&mirror.id.to_le_bytes());
+                        bytes.extend_from_slice(&(size as u64).to_le_bytes());
+                    });
+                }
+            }
+            (I::WlBuffer, 0) => {
+                self.buffers.remove(&this);
+            }
+            (I::WlSurface, 0) => {
+                self.surfaces.remove(&this);
+            }
+            (I::WlSurface, 1) => {
+                self.surface(this).pending_buffer = Some(uid(args[0]));
+            }
+            (I::WlSurface, 2) => {
+                let rect = [args[0], args[1], args[2], args[3]].map(|v| v as i32);
+                self.surface(this).surface_damage.push(rect);
+            }
+            (I::WlSurface, 6) => self.commit(this)?,
+            (I::WlSurface, 7) => {
+                self.surface(this).pending_transform = Some(args[0]);
+            }
+            (I::WlSurface, 8) => {
+                self.surface(this).pending_scale = Some(args[0] as i32);
+            }
+            (I::WlSurface, 9) => {
+                let rect = [args[0], args[1], args[2], args[3]].map(|v| v as i32);
+                self.surface(this).buffer_damage.push(rect);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn surface(&mut self, id: u64) -> &mut Surface {
+        self.surfaces.entry(id).or_insert_with(|| Surface {
+            scale: 1,
+            ..Default::default()
+        })
+    }
+
+    /// Sends the parts of the committed buffer that have changed.
+    ///
+    /// If the same buffer is committed again, only the damaged parts of the buffer are
+    /// compared with the contents that were sent previously. Otherwise the entire buffer
+    /// is compared.
+    fn commit(&mut self, surface_id: u64) -> io::Result<()> {
+        let surface = self.surface(surface_id);
+        let mut full = false;
+        if let Some(buffer) = surface.pending_buffer.take() {
+            full = buffer != surface.buffer;
+            surface.buffer = buffer;
+        }
+        if let Some(scale) = surface.pending_scale.take() {
+            surface.scale = scale.max(1);
+        }
+        if let Some(transform) = surface.pending_transform.take() {
+            surface.transform = transform;
+        }
+        let buffer_id = surface.buffer;
+        let mut damage = mem::take(&mut surface.buffer_damage);
+        let surface_damage = mem::take(&mut surface.surface_damage);
+        if surface_damage.is_not_empty() {
+            if surface.transform != WlOutputTransform::NORMAL.0 {
+                full = true;
+            }
+            let scale = surface.scale;
+            damage.extend(
+                surface_damage
+                    .iter()
+                    .map(|r| r.map(|v| v.saturating_mul(scale))),
+            );
+        }
+        let Some(buffer) = buffer_id.and_then(|b| self.buffers.get(&b)) else {
+            return Ok(());
+        };
+        let Buffer {
+            ref mirror,
+            offset,
+            width,
+            height,
+            stride,
+        } = *buffer;
+        let mirror = mirror.clone();
+        let pool_size = mirror.shadow.borrow().len();
+        let bpp = match width.checked_mul(4).is_some_and(|w| stride >= w) {
+            true => 4,
+            false => 0,
+        };
+        if full || bpp == 0 {
+            damage = vec![[0, 0, width as i32, height as i32]];
+        }
+        for [x, y, w, h] in damage {
+            let x1 = x.max(0);
+            let y1 = y.max(0);
+            let x2 = x.saturating_add(w).min(width as i32);
+            let y2 = y.saturating_add(h).min(height as i32);
+            if x1 >= x2 || y1 >= y2 {
+                continue;
+            }
+            let (x1, y1, x2, y2) = (x1 as usize, y1 as usize, x2 as usize, y2 as usize);
+            let row_len = match bpp {
+                0 => stride,
+                _ => (x2 - x1).saturating_mul(bpp),
+            };
+            let Some(row_start) = x1.checked_mul(bpp).and_then(|x| x.checked_add(offset)) else {
+                continue;
+            };
+            let start = |y: usize| y.checked_mul(stride)?.checked_add(row_start);
+            if row_len == stride {
+                if let Some(start) = start(y1) {
+                    let len = (y2 - y1).saturating_mul(row_len);
+                    self.sync(&mirror, start, len)?;
+                }
+            } else {
+                for y in y1..y2 {
+                    match start(y) {
+                        Some(start) if start < pool_size => {
+                            self.sync(&mirror, start, row_len)?;
+                        }
+                        _ => break,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends a file descriptor as a file frame followed by its contents.
+    ///
+    /// If `size` is `None`, the file is only copied once. Otherwise the returned mirror
+    /// can be used to send updates.
+    fn send_file(&mut self, fd: &Rc<OwnedFd>, size: Option<usize>) -> io::Result<Rc<Mirror>> {
+        let stat = uapi::fstat(fd.as_raw_fd()).map_err(io::Error::from)?;
+        if stat.st_mode & c::S_IFMT != c::S_IFREG {
+            log::warn!("Cannot replicate a file descriptor that is not a regular file");
+            self.frame(TAG_PLACEHOLDER, 0, |_| ());
+            return Ok(self.mirror(fd, 0));
+        }
+        let size = size.unwrap_or(stat.st_size as usize);
+        let mirror = self.mirror(fd, size);
+        self.frame(TAG_FILE, 12, |bytes| {
+            bytes.extend_from_slice(&mirror.id.to_le_bytes());
+            bytes.extend_from_slice(&(size as u64).to_le_bytes());
+        });
+        self.sync(&mirror, 0, size)?;
+        Ok(mirror)
+    }
+
+    fn mirror(&mut self, fd: &Rc<OwnedFd>, size: usize) -> Rc<Mirror> {
+        self.next_id = self.next_id.wrapping_add(1);
+        Rc::new(Mirror {
+            id: self.next_id,
+            fd: fd.clone(),
+            shadow: RefCell::new(vec![0; size]),
+            forgotten: self.forgotten.clone(),
+        })
+    }
+
+    /// Sends the blocks of a range of a file whose contents differ from the shadow.
+    ///
+    /// The range is compared in chunks of at most `MAX_WRITE` bytes.
+    fn sync(&mut self, mirror: &Mirror, start: usize, len: usize) -> io::Result<()> {
+        let shadow = &mut *mirror.shadow.borrow_mut();
+        let end = start.saturating_add(len).min(shadow.len());
+        let mut current = vec![];
+        let mut chunk_start = start;
+        while chunk_start < end {
+            let chunk_end = chunk_start.saturating_add(MAX_WRITE).min(end);
+            current.clear();
+            current.resize(chunk_end - chunk_start, 0);
+            let mut pos = 0;
+            while pos < current.len() {
+                let offset = (chunk_start + pos) as c::off_t;
+                let n = uapi::pread(mirror.fd.as_raw_fd(), &mut current[pos..], offset)
+                    .map_err(io::Error::from)?
+                    .len();
+                if n == 0 {
+                    break;
+                }
+                pos += n;
+            }
+            let old = &mut shadow[chunk_start..chunk_end];
+            let mut changed: Option<(usize, usize)> = None;
+            let mut block = 0;
+            while block < current.len() {
+                let block_end = (block + BLOCK_SIZE).min(current.len());
+                if current[block..block_end] != old[block..block_end] {
+                    let lo = changed.map_or(block, |(lo, _)| lo);
+                    changed = Some((lo, block_end));
+                } else if let Some((lo, hi)) = changed.take() {
+                    self.write_range(mirror.id, chunk_start, lo, &current[lo..hi]);
+                }
+                block = block_end;
+            }
+            if let Some((lo, hi)) = changed {
+                self.write_range(mirror.id, chunk_start, lo, &current[lo..hi]);
+            }
+            old.copy_from_slice(&current);
+            chunk_start = chunk_end;
+        }
+        Ok(())
+    }
+
+    fn write_range(&mut self, id: u32, base: usize, offset: usize, data: &[u8]) {
+        self.frame(TAG_WRITE, 12 + data.len(), |bytes| {
+            bytes.extend_from_slice(&id.to_le_bytes());
+            bytes.extend_from_slice(&((base + offset) as u64).to_le_bytes());
+            bytes.extend_from_slice(data);
+        });
+    }
+
+    fn forget(&mut self) {
+        let forgotten = mem::take(&mut *self.forgotten.borrow_mut());
+        for id in forgotten {
+            self.frame(TAG_FORGET, 4, |bytes| {
+                bytes.extend_from_slice(&id.to_le_bytes());
+            });
+        }
+    }
+
+    fn frame(&mut self, tag: u32, len: usize, f: impl FnOnce(&mut Vec<u8>)) {
+        self.bytes.extend_from_slice(&tag.to_le_bytes());
+        self.bytes.extend_from_slice(&(len as u32).to_le_bytes());
+        let start = self.bytes.len();
+        f(&mut self.bytes);
+        debug_assert_eq!(self.bytes.len() - start, len);
+    }
+
+    fn write(&mut self, socket: RawFd) -> Result<FlushResult, TransError> {
+        while self.written < self.bytes.len() {
+            let flags = c::MSG_NOSIGNAL | c::MSG_DONTWAIT;
+            match uapi::send(socket, &self.bytes[self.written..], flags) {
+                Ok(n) => self.written += n,
+                Err(Errno(c::EAGAIN)) => {
+                    self.bytes.drain(..mem::take(&mut self.written));
+                    return Ok(FlushResult::Blocked);
+                }
+                Err(Errno(c::ECONNRESET | c::EPIPE)) => return Err(TransError::Closed),
+                Err(e) => return Err(TransError::WriteToSocket(e.into())),
+            }
+        }
+        self.bytes.clear();
+        self.written = 0;
+        Ok(FlushResult::Done)
+    }
+}
+
+impl Drop for Mirror {
+    fn drop(&mut self) {
+        self.forgotten.borrow_mut().push(self.id);
+    }
+}
+
+impl Receiver {
+    fn read(&mut self, socket: RawFd) -> Result<(), TransError> {
+        if self.start > 0 {
+            self.bytes.drain(..mem::take(&mut self.start));
+        }
+        let len = self.bytes.len();
+        self.bytes.resize(len + READ_SIZE, 0);
+        let res = uapi::recv(socket, &mut self.bytes[len..], c::MSG_DONTWAIT).map(|b| b.len());
+        match res {
+            Ok(0) => {
+                self.bytes.truncate(len);
+                Err(TransError::Closed)
+            }
+            Ok(n) => {
+                self.bytes.truncate(len + n);
+                Ok(())
+            }
+            Err(Errno(c::EAGAIN)) => {
+                self.bytes.truncate(len);
+                Ok(())
+            }
+            Err(e) => {
+                self.bytes.truncate(len);
+                Err(TransError::ReadFromSocket(e.into()))
+            }
+        }
+    }
+
+    /// Processes buffered frames until a message frame has been processed.
+    ///
+    /// Returns `false` if more data is needed.
+    fn next_frame(
+        &mut self,
+        message: &mut Vec<u32>,
+        fds: &mut VecDeque<Rc<OwnedFd>>,
+    ) -> Result<bool, TransError> {
+        loop {
+            let available = &self.bytes[self.start..];
+            if available.len() < HEADER_SIZE {
+                return Ok(false);
+            }
+            let tag = u32::from_le_bytes(available[..4].try_into().unwrap());
+            let len = u32::from_le_bytes(available[4..8].try_into().unwrap()) as usize;
+            if len > MAX_FRAME_SIZE {
+                return Err(TransError::InvalidFrame);
+            }
+            if available.len() < HEADER_SIZE + len {
+                return Ok(false);
+            }
+            let payload = &available[HEADER_SIZE..HEADER_SIZE + len];
+            self.start += HEADER_SIZE + len;
+            let word = |i: usize| -> Result<u32, TransError> {
+                let bytes = payload
+                    .get(i * 4..i * 4 + 4)
+                    .ok_or(TransError::InvalidFrame)?;
+                Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+            };
+            let long = |i: usize| -> Result<u64, TransError> {
+                Ok(word(i)? as u64 | (word(i + 1)? as u64) << 32)
+            };
+            match tag {
+                TAG_MESSAGE => {
+                    if len < 8 || len % 4 != 0 || len > MAX_MESSAGE_SIZE {
+                        return Err(TransError::InvalidFrame);
+                    }
+                    message.clear();
+                    for i in 0..len / 4 {
+                        message.push(word(i)?);
+                    }
+                    if (message[1] >> 16) as usize != len {
+                        return Err(TransError::InvalidFrame);
+                    }
+                    return Ok(true);
+                }
+                TAG_FILE => {
+                    let id = word(0)?;
+                    let size = long(1)?;
+                    let fd = create_file(size).map_err(TransError::ReplicateFile)?;
+                    let fd = Rc::new(fd);
+                    self.files.insert(id, fd.clone());
+                    fds.push_back(fd);
+                }
+                TAG_RESIZE => {
+                    let fd = self.file(word(0)?)?;
+                    let size = long(1)?;
+                    uapi::ftruncate(fd.as_raw_fd(), size as c::off_t)
+                        .map_err(|e| TransError::ReplicateFile(e.into()))?;
+                }
+                TAG_WRITE => {
+                    let fd = self.file(word(0)?)?;
+                    let offset = long(1)?;
+                    let mut data = &payload[12..];
+                    let mut offset = offset as c::off_t;
+                    while data.is_not_empty() {
+                        let n = uapi::pwrite(fd.as_raw_fd(), data, offset)
+                            .map_err(|e| TransError::ReplicateFile(e.into()))?;
+                        data = &data[n..];
+                        offset += n as c::off_t;
+                    }
+                }
+                TAG_FORGET => {
+                    self.files.remove(&word(0)?);
+                }
+                TAG_PLACEHOLDER => {
+                    let fd = uapi::open("/dev/null", c::O_RDWR | c::O_CLOEXEC, 0)
+                        .map_err(|e| TransError::ReplicateFile(e.into()))?;
+                    fds.push_back(Rc::new(fd.into()));
+                }
+                _ => return Err(TransError::InvalidFrame),
+            }
+        }
+    }
+
+    fn file(&self, id: u32) -> Result<Rc<OwnedFd>, TransError> {
+        self.files
+            .get(&id)
+            .cloned()
+            .ok_or(TransError::UnknownFile(id))
+    }
+}
+
+/// Returns the size of a wl_shm pool if it can be replicated.
+fn pool_size(size: u32) -> Option<usize> {
+    let size = size as i32;
+    if size <= 0 || size as usize > MAX_POOL_SIZE {
+        return None;
+    }
+    Some(size as usize)
+}
+
+fn create_file(size: u64) -> io::Result<OwnedFd> {
+    let fd = uapi::memfd_create("wl-proxy-network", c::MFD_CLOEXEC)?;
+    uapi::ftruncate(fd.raw(), size as c::off_t)?;
+    Ok(fd.into())
+}
@@ -41,8 +41,10 @@ pub(crate) trait ObjectPrivate: Any {
         msg: &[u32],
         fds: &mut VecDeque<Rc<OwnedFd>>,
     ) -> Result<(), ObjectError>;
-    fn get_request_name(&self, id: u32) -> Option<&'static str>;
-    fn get_event_name(&self, id: u32) -> Option<&'static str>;
+    fn get_request_name(&self, id: u32) -> Option<&str>;
+    fn get_event_name(&self, id: u32) -> Option<&str>;
+    fn get_request_signature(&self, id: u32) -> Option<&str>;
+    fn get_event_signature(&self, id: u32) -> Option<&str>;
     fn create_zombie(&self) -> Rc<dyn Object>;
 }
 
@@ -105,6 +107,15 @@ pub trait ObjectCoreApi {
     /// Returns the [`ObjectInterface`] of this object.
     fn interface(&self) -> ObjectInterface;
 
+    /// Returns the name of the interface of this object.
+    ///
+    /// For objects with a known interface, this is the same as
+    /// `self.interface().name()`. For [`UnknownObject`](crate::unknown::UnknownObject)s,
+    /// this is the name of the interface the object was created with.
+    fn interface_name(&self) -> &str {
+        self.interface().name()
+    }
+
     /// Returns the version of this object.
     fn version(&self) -> u32;
 
@@ -164,6 +175,13 @@ impl ObjectCoreApi for ObjectCore {
         self.interface
     }
 
+    fn interface_name(&self) -> &str {
+        match &self.interface_name {
+            Some(name) => name,
+            None => self.interface.name(),
+        }
+    }
+
     fn version(&self) -> u32 {
         self.version
     }
@@ -237,6 +255,10 @@ where
         self.core().interface()
     }
 
+    fn interface_name(&self) -> &str {
+        self.core().interface_name()
+    }
+
     fn version(&self) -> u32 {
         self.core().version()
     }
@@ -355,7 +377,7 @@ impl ObjectRcUtils for Rc<dyn Object> {
         let Some(t) = self.try_downcast() else {
             panic!(
                 "Tried to downcast {} to {}",
-                self.interface().name(),
+                self.interface_name(),
                 T::INTERFACE_NAME,
             );
         };
@@ -370,6 +392,7 @@ pub struct ObjectCore {
     pub(crate) state: Rc<State>,
     id: u64,
     pub(crate) interface: ObjectInterface,
+    pub(crate) interface_name: Option<Rc<str>>,
     pub(crate) version: u32,
     pub(crate) forward_to_client: Cell<bool>,
     pub(crate) forward_to_server: Cell<bool>,
@@ -407,7 +430,7 @@ pub(crate) enum IdError {
     ClientIdInUse(u32),
 }
 
-const MIN_SERVER_ID: u32 = 0xff000000;
+pub(crate) const MIN_SERVER_ID: u32 = 0xff000000;
 
 impl ObjectCore {
     pub(crate) fn new(
@@ -415,6 +438,31 @@ impl ObjectCore {
         slf: Weak<dyn Object>,
         interface: ObjectInterface,
         version: u32,
+    ) -> Self {
+        Self::new_(state, slf, interface, None, version)
+    }
+
+    pub(crate) fn new_unknown(
+        state: &Rc<State>,
+        slf: Weak<dyn Object>,
+        interface_name: &Rc<str>,
+        version: u32,
+    ) -> Self {
+        Self::new_(
+            state,
+            slf,
+            ObjectInterface::Unknown,
+            Some(interface_name.clone()),
+            version,
+        )
+    }
+
+    fn new_(
+        state: &Rc<State>,
+        slf: Weak<dyn Object>,
+        interface: ObjectInterface,
+        interface_name: Option<Rc<str>>,
+        version: u32,
     ) -> Self {
         let object_id = state.next_object_id.get();
         state.next_object_id.set(object_id + 1);
@@ -423,6 +471,7 @@ impl ObjectCore {
             state: state.clone(),
             id: object_id,
             interface,
+            interface_name,
             version,
             forward_to_client: Cell::new(state.forward_to_client.get()),
             forward_to_server: Cell::new(state.forward_to_server.get()),
@@ -555,12 +604,19 @@ impl ObjectCore {
             client.endpoint.idl.release(idl);
         } else {
             self.awaiting_delete_id.set(true);
+            if self.state.delete_ids_on_destroy.get() {
+                self.delete_id();
+            }
         }
     }
 
     pub(crate) fn handle_server_destroy(&self) {
         let id = self.server_obj_id.get().unwrap();
         if id < MIN_SERVER_ID {
+            #[cfg(feature = "recovery")]
+            if let Some(journal) = &self.state.server.as_ref().unwrap().journal {
+                journal.destroy(self.id);
+            }
             return;
         }
         self.server_obj_id.take();
@@ -637,6 +693,10 @@ pub(crate) enum ObjectErrorKind {
     HandlerBorrowed,
     #[error("the client is not waiting for a delete_id message")]
     NotAwaitingDeleteId,
+    #[error("the arguments do not match the signature of the message")]
+    SignatureMismatch,
+    #[error("the message is larger than {0} bytes")]
+    MessageTooLarge(usize),
 }
 
 #[derive(Debug, Error)]
@@ -650,7 +710,7 @@ fn display_error<'a>(
 ) -> impl Display + use<'a> {
     debug_fn(move |f| {
         if let Some(object) = object {
-            let interface = object.interface().name();
+            let interface = object.interface_name();
             let unique_id = object.unique_id();
             write!(
                 f,
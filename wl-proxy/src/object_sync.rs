@@ -0,0 +1,246 @@
+//! Matching of client objects with proxy objects via wlproxy_sync_v1.
+//!
+//! This module requires the `protocol-wlproxy_sync_v1` feature.
+//!
+//! Applications that run a proxy and one of its clients in the same process sometimes
+//! need to know which proxy object corresponds to an object created by their own
+//! client, for example, to embed the surfaces of another client into one of their own
+//! surfaces. The objects live on different connections and the proxy objects are only
+//! created once the proxy has received the requests of the client.
+//!
+//! The client binds a wlproxy_sync_v1 global announced by the proxy and wraps the object
+//! in an [`ObjectSyncClient`]. The proxy passes its side of the object to
+//! [`ObjectSync::track_sync`]. Both sides share the same [`ObjectSync`].
+//!
+//! [`ObjectSyncClient::sync`] sends `sync_with_proxy(hi, lo)` where `hi` is a counter
+//! and `lo` is the protocol ID of the object. Since messages are ordered, the proxy
+//! object exists when the proxy handles this request. The proxy records the object
+//! under the 64-bit token formed by `hi` and `lo` and echoes the arguments via
+//! `sync_with_client`. Once the client has received the echo, the proxy object can be
+//! retrieved via [`ObjectSync::get`].
+//!
+//! # Encoding
+//!
+//! wlproxy_sync_v1 is also used by the `Embedder` of the embedding module to embed
+//! toplevels. The same object can serve both purposes since the two uses are
+//! distinguished by the highest bit of `hi`:
+//!
+//! - If the bit is clear, `hi` is a counter chosen by the client and `lo` is the
+//!   protocol ID of an object. The counter is never 0.
+//! - If the bit is set, `hi` is the token of an embedded toplevel and `lo` is the
+//!   protocol ID of a parent surface or 0. See the embedding module for details.
+//!
+//! In both cases, the proxy answers every `sync_with_proxy` request with a
+//! `sync_with_client` event.
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use wl_proxy::object_sync::{ObjectSync, ObjectSyncClient};
+//! # use wl_proxy::protocols::wayland::wl_surface::WlSurface;
+//! # fn f(map: &Rc<ObjectSync>, client: &Rc<ObjectSyncClient>, surface: &Rc<WlSurface>) {
+//! let map = map.clone();
+//! client
+//!     .sync_with(&**surface, move |token| {
+//!         let proxy_surface = map.get_as::<WlSurface>(token).unwrap();
+//!         // ...
+//!     })
+//!     .unwrap();
+//! # }
+//! ```
+
+use {
+    crate::{
+        object::{ConcreteObject, Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::wlproxy_sync_v1::wlproxy_sync_v1::{WlproxySyncV1, WlproxySyncV1Handler},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        collections::HashMap,
+        rc::{Rc, Weak},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A map from sync tokens to proxy objects.
+pub struct ObjectSync {
+    objects: RefCell<HashMap<u64, Weak<dyn Object>>>,
+}
+
+/// The client side of a wlproxy_sync_v1 object.
+pub struct ObjectSyncClient {
+    sync: Rc<WlproxySyncV1>,
+    next: Cell<u32>,
+    pending: RefCell<HashMap<u64, Option<SyncCallback>>>,
+}
+
+/// An error emitted by an [`ObjectSyncClient`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct ObjectSyncError(#[from] ObjectSyncErrorKind);
+
+#[derive(Debug, Error)]
+enum ObjectSyncErrorKind {
+    #[error("the object is not associated with the proxy")]
+    NotAssociated,
+}
+
+type SyncCallback = Box<dyn FnOnce(u64)>;
+
+struct ProxyHandler(Rc<ObjectSync>);
+
+struct ClientHandler(Weak<ObjectSyncClient>);
+
+/// The bit of `hi` that is set for the tokens of embedded toplevels.
+pub(crate) const EMBEDDED_TOPLEVEL: u32 = 1 << 31;
+
+fn token(hi: u32, lo: u32) -> u64 {
+    ((hi as u64) << 32) | lo as u64
+}
+
+impl ObjectSync {
+    /// Creates a new map.
+    pub fn new() -> Rc<Self> {
+        Rc::new(Self {
+            objects: Default::default(),
+        })
+    }
+
+    /// Installs a handler on a wlproxy_sync_v1 object on the proxy side.
+    ///
+    /// The object must not be associated with the compositor.
+    pub fn track_sync(self: &Rc<Self>, sync: &Rc<WlproxySyncV1>) {
+        sync.set_handler(ProxyHandler(self.clone()));
+    }
+
+    /// Returns the proxy object that has been recorded under a token.
+    ///
+    /// Returns `None` if the proxy has not yet handled the token or if the object no
+    /// longer exists.
+    pub fn get(&self, token: u64) -> Option<Rc<dyn Object>> {
+        self.objects.borrow().get(&token)?.upgrade()
+    }
+
+    /// Returns the proxy object that has been recorded under a token, if it has the
+    /// type `T`.
+    pub fn get_as<T>(&self, token: u64) -> Option<Rc<T>>
+    where
+        T: ConcreteObject,
+    {
+        self.get(token)?.try_downcast()
+    }
+
+    /// Forgets a token.
+    pub fn remove(&self, token: u64) -> Option<Rc<dyn Object>> {
+        self.objects.borrow_mut().remove(&token)?.upgrade()
+    }
+
+    pub(crate) fn handle_sync_with_proxy(&self, slf: &Rc<WlproxySyncV1>, hi: u32, lo: u32) {
+        let object = slf
+            .client()
+            .and_then(|c| c.endpoint.objects.borrow().get(&lo).cloned());
+        let objects = &mut *self.objects.borrow_mut();
+        objects.retain(|_, o| o.strong_count() > 0);
+        match object {
+            Some(object) => {
+                objects.insert(token(hi, lo), Rc::downgrade(&object));
+            }
+            None => {
+                log::warn!("Client requested sync of unknown object {lo}");
+            }
+        }
+        slf.send_sync_with_client(hi, lo);
+    }
+}
+
+impl ObjectSyncClient {
+    /// Wraps the client side of a wlproxy_sync_v1 object.
+    ///
+    /// This installs a handler on the object.
+    pub fn new(sync: &Rc<WlproxySyncV1>) -> Rc<Self> {
+        let slf = Rc::new(Self {
+            sync: sync.clone(),
+            next: Cell::new(1),
+            pending: Default::default(),
+        });
+        sync.set_handler(ClientHandler(Rc::downgrade(&slf)));
+        slf
+    }
+
+    /// Returns the wlproxy_sync_v1 object.
+    pub fn sync_object(&self) -> &Rc<WlproxySyncV1> {
+        &self.sync
+    }
+
+    /// Asks the proxy to record the proxy object of a client object.
+    ///
+    /// The object must be associated with the proxy. Returns the token under which the
+    /// object will be recorded. The token can be used with [`ObjectSync::get`] once
+    /// [`Self::is_synced`] returns true.
+    pub fn sync<T>(&self, object: &T) -> Result<u64, ObjectSyncError>
+    where
+        T: Object + ?Sized,
+    {
+        self.sync_(object, None)
+    }
+
+    /// Like [`Self::sync`] but invokes a callback with the token once the proxy has
+    /// recorded the object.
+    pub fn sync_with<T>(
+        &self,
+        object: &T,
+        f: impl FnOnce(u64) + 'static,
+    ) -> Result<u64, ObjectSyncError>
+    where
+        T: Object + ?Sized,
+    {
+        self.sync_(object, Some(Box::new(f)))
+    }
+
+    fn sync_<T>(&self, object: &T, f: Option<SyncCallback>) -> Result<u64, ObjectSyncError>
+    where
+        T: Object + ?Sized,
+    {
+        let Some(id) = object.server_id() else {
+            return Err(ObjectSyncErrorKind::NotAssociated.into());
+        };
+        let hi = self.next.get();
+        self.next.set(((hi + 1) % EMBEDDED_TOPLEVEL).max(1));
+        let token = token(hi, id);
+        self.pending.borrow_mut().insert(token, f);
+        self.sync.send_sync_with_proxy(hi, id);
+        Ok(token)
+    }
+
+    /// Returns whether the proxy has recorded the object of a token.
+    ///
+    /// This also returns true for tokens that were never requested.
+    pub fn is_synced(&self, token: u64) -> bool {
+        !self.pending.borrow().contains_key(&token)
+    }
+}
+
+impl WlproxySyncV1Handler for ProxyHandler {
+    fn handle_destroy(&mut self, slf: &Rc<WlproxySyncV1>) {
+        slf.delete_id();
+    }
+
+    fn handle_sync_with_proxy(&mut self, slf: &Rc<WlproxySyncV1>, id_hi: u32, id_lo: u32) {
+        self.0.handle_sync_with_proxy(slf, id_hi, id_lo);
+    }
+}
+
+impl WlproxySyncV1Handler for ClientHandler {
+    fn handle_sync_with_client(&mut self, _slf: &Rc<WlproxySyncV1>, id_hi: u32, id_lo: u32) {
+        let Some(client) = self.0.upgrade() else {
+            return;
+        };
+        let token = token(id_hi, id_lo);
+        let f = client.pending.borrow_mut().remove(&token);
+        if let Some(Some(f)) = f {
+            f(token);
+        }
+    }
+}
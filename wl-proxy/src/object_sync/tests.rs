@@ -0,0 +1,122 @@
+#![cfg(feature = "headless")]
+
+use {
+    crate::{
+        baseline::Baseline,
+        headless::Headless,
+        object::{ConcreteObject, Object, ObjectCoreApi, ObjectRcUtils},
+        object_sync::{ObjectSync, ObjectSyncClient},
+        protocols::{
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_compositor::WlCompositor,
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+                wl_surface::WlSurface,
+            },
+            wlproxy_sync_v1::wlproxy_sync_v1::WlproxySyncV1,
+        },
+        state::State,
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+#[derive(Clone)]
+struct Proxy(Rc<ObjectSync>);
+
+impl WlDisplayHandler for Proxy {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(self.clone());
+        slf.send_get_registry(registry);
+    }
+}
+
+impl WlRegistryHandler for Proxy {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        if let Some(sync) = id.try_downcast::<WlproxySyncV1>() {
+            self.0.track_sync(&sync);
+            return;
+        }
+        slf.send_bind(name, id);
+    }
+}
+
+struct Done(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn lookup() {
+    let headless = Headless::new().unwrap();
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let (proxy_client, client_fd) = proxy.connect().unwrap();
+    let map = ObjectSync::new();
+    proxy_client.display.set_handler(Proxy(map.clone()));
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .build()
+        .unwrap();
+    let _destructors = [proxy.create_destructor(), client.create_destructor()];
+    let roundtrip = || {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([&client, &proxy, headless.state()]).unwrap();
+        }
+    };
+    let registry = client.display().new_send_get_registry();
+    let compositor = client.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+    registry.send_bind(1, compositor.clone());
+    let sync = client.create_object::<WlproxySyncV1>(1);
+    registry.send_bind(100, sync.clone());
+    let sync = ObjectSyncClient::new(&sync);
+
+    let surface = compositor.new_send_create_surface();
+    let synced = Rc::new(RefCell::new(None));
+    let token = sync
+        .sync_with(&*surface, {
+            let map = map.clone();
+            let synced = synced.clone();
+            move |token| *synced.borrow_mut() = map.get_as::<WlSurface>(token)
+        })
+        .unwrap();
+    assert!(!sync.is_synced(token));
+    roundtrip();
+    assert!(sync.is_synced(token));
+    let proxy_surface = synced.take().unwrap();
+    assert_eq!(proxy_surface.client_id(), surface.server_id());
+    assert!(map.get_as::<WlCompositor>(token).is_none());
+
+    // Tokens are unique even if the same object is synced multiple times.
+    let token2 = sync.sync(&*surface).unwrap();
+    assert_ne!(token, token2);
+    roundtrip();
+    assert!(Rc::ptr_eq(
+        &map.get_as::<WlSurface>(token2).unwrap(),
+        &proxy_surface
+    ));
+
+    // Destroyed objects are no longer returned.
+    drop(proxy_surface);
+    surface.send_destroy();
+    roundtrip();
+    assert!(map.get(token).is_none());
+
+    // Objects that have not been sent to the proxy cannot be synced.
+    let unsent = client.create_object::<WlSurface>(1);
+    assert!(sync.sync(&*unsent).is_err());
+}
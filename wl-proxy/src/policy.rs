@@ -0,0 +1,440 @@
+//! Declarative access control for globals.
+//!
+//! This module requires the `policy` feature.
+//!
+//! A [`Policy`] maps the identity of a client to the globals that the client is allowed
+//! to see. It is loaded from a TOML file and is usually installed on the wl_display of
+//! each client via [`PolicyDisplayHandler`]. This allows a single, long-running proxy to
+//! sandbox many different applications with different rules.
+//!
+//! # Format
+//!
+//! ```toml
+//! # Applies to clients that are not matched by any rule. If this section is omitted,
+//! # such clients can see all globals.
+//! [default]
+//! deny = ["zwlr_screencopy_manager_v1", "ext_image_copy_capture_manager_v1"]
+//!
+//! # Rules are checked in order and the first matching rule applies.
+//! [[rule]]
+//! exe = "/usr/bin/mpv"
+//! deny = ["wp_fifo_manager_v1"]
+//!
+//! [[rule]]
+//! sandbox-engine = "flatpak"
+//! app-id = "org.mozilla.*"
+//! allow = ["wl_*", "wp_*", "xdg_*", "zwp_linux_dmabuf_v1"]
+//! max-versions = { zwp_linux_dmabuf_v1 = 4 }
+//! ```
+//!
+//! A rule matches a client if all of its `exe`, `app-id`, `sandbox-engine`, and
+//! `instance-id` fields match the [`ClientIdentity`] of the client. Fields that are
+//! omitted match every client. All of these fields and the interface names in `allow`
+//! and `deny` can contain `*` wildcards that match any sequence of characters.
+//!
+//! A global is visible to the client if its interface matches one of the `allow`
+//! patterns and none of the `deny` patterns. If `allow` is omitted, it defaults to
+//! `["*"]`. The version of visible globals is limited by `max-versions`.
+//!
+//! The executable of a client is not always known, e.g. if the kernel does not support
+//! `SO_PEERPIDFD` or if the client is connected over the network. If all other fields of
+//! a rule with an `exe` field match such a client, the client cannot see any globals.
+//! Such clients therefore never fall through to later rules or to the `[default]` rule.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use wl_proxy::baseline::Baseline;
+//! # use wl_proxy::policy::{Policy, PolicyDisplayHandler};
+//! # use wl_proxy::simple::{ServerConnection, SharedProxy};
+//! let policy = Arc::new(Policy::load("/etc/wl-proxy/policy.toml").unwrap());
+//! let proxy = SharedProxy::new(Baseline::ALL_OF_THEM, ServerConnection::PerClient).unwrap();
+//! proxy.run(move || PolicyDisplayHandler::new(&policy));
+//! ```
+
+use {
+    crate::{
+        client::Client,
+        global_mapper::GlobalMapper,
+        object::{Object, ObjectCoreApi},
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_display::{WlDisplay, WlDisplayError, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+            },
+        },
+        utils::glob::glob,
+    },
+    serde::Deserialize,
+    std::{
+        collections::HashMap,
+        io,
+        path::{Path, PathBuf},
+        rc::Rc,
+        sync::Arc,
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A set of rules that determine the globals visible to clients.
+///
+/// See the [module documentation](self) for the format.
+#[derive(Debug)]
+pub struct Policy {
+    default: Arc<Rule>,
+    deny_all: Arc<Rule>,
+    rules: Vec<(Matcher, Arc<Rule>)>,
+}
+
+/// The rule that applies to a client.
+///
+/// This can be retrieved with [`Policy::rule`].
+#[derive(Debug)]
+pub struct Rule {
+    allow: Vec<String>,
+    deny: Vec<String>,
+    max_versions: HashMap<String, u32>,
+}
+
+#[derive(Debug, Default)]
+struct Matcher {
+    exe: Option<String>,
+    app_id: Option<String>,
+    sandbox_engine: Option<String>,
+    instance_id: Option<String>,
+}
+
+/// The identity of a client that is used to select a [`Rule`].
+#[derive(Clone, Debug, Default)]
+pub struct ClientIdentity {
+    /// The path of the executable of the client.
+    ///
+    /// This is `None` if the executable is unknown.
+    pub exe: Option<PathBuf>,
+    /// The application ID of the client.
+    pub app_id: Option<String>,
+    /// The name of the sandbox engine of the client, e.g. `flatpak`.
+    pub sandbox_engine: Option<String>,
+    /// The ID of the sandbox instance of the client.
+    pub instance_id: Option<String>,
+}
+
+/// An error returned when loading a [`Policy`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct PolicyError(#[from] PolicyErrorKind);
+
+#[derive(Debug, Error)]
+enum PolicyErrorKind {
+    #[error("could not read {}", .0.display())]
+    Read(PathBuf, #[source] io::Error),
+    #[error("could not parse the policy")]
+    Parse(#[source] toml::de::Error),
+    #[error("the default rule must not contain {}", .0)]
+    DefaultMatcher(&'static str),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    default: Option<RuleFile>,
+    #[serde(default)]
+    rule: Vec<RuleFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct RuleFile {
+    exe: Option<String>,
+    app_id: Option<String>,
+    sandbox_engine: Option<String>,
+    instance_id: Option<String>,
+    allow: Option<Vec<String>>,
+    #[serde(default)]
+    deny: Vec<String>,
+    #[serde(default)]
+    max_versions: HashMap<String, u32>,
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self {
+            allow: vec!["*".to_string()],
+            deny: vec![],
+            max_versions: Default::default(),
+        }
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            default: Default::default(),
+            deny_all: Arc::new(Rule {
+                allow: vec![],
+                deny: vec![],
+                max_versions: Default::default(),
+            }),
+            rules: Default::default(),
+        }
+    }
+}
+
+impl Policy {
+    /// Parses a policy from a TOML string.
+    pub fn parse(s: &str) -> Result<Self, PolicyError> {
+        let file: PolicyFile = toml::from_str(s).map_err(PolicyErrorKind::Parse)?;
+        let mut slf = Self::default();
+        if let Some(default) = file.default {
+            let (matcher, rule) = default.split();
+            for (name, field) in [
+                ("exe", &matcher.exe),
+                ("app-id", &matcher.app_id),
+                ("sandbox-engine", &matcher.sandbox_engine),
+                ("instance-id", &matcher.instance_id),
+            ] {
+                if field.is_some() {
+                    return Err(PolicyErrorKind::DefaultMatcher(name).into());
+                }
+            }
+            slf.default = Arc::new(rule);
+        }
+        for rule in file.rule {
+            let (matcher, rule) = rule.split();
+            slf.rules.push((matcher, Arc::new(rule)));
+        }
+        Ok(slf)
+    }
+
+    /// Loads a policy from a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PolicyError> {
+        let path = path.as_ref();
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| PolicyErrorKind::Read(path.to_path_buf(), e))?;
+        Self::parse(&s)
+    }
+
+    /// Returns the rule that applies to a client with the given identity.
+    ///
+    /// If the first rule that might match the client has an `exe` field but the
+    /// executable of the client is unknown, a rule that denies all globals is returned.
+    pub fn rule(&self, identity: &ClientIdentity) -> &Arc<Rule> {
+        for (matcher, rule) in &self.rules {
+            match matcher.matches(identity) {
+                Match::Yes => return rule,
+                Match::No => {}
+                Match::UnknownExe => return &self.deny_all,
+            }
+        }
+        &self.default
+    }
+}
+
+impl RuleFile {
+    fn split(self) -> (Matcher, Rule) {
+        let matcher = Matcher {
+            exe: self.exe,
+            app_id: self.app_id,
+            sandbox_engine: self.sandbox_engine,
+            instance_id: self.instance_id,
+        };
+        let rule = Rule {
+            allow: self.allow.unwrap_or_else(|| vec!["*".to_string()]),
+            deny: self.deny,
+            max_versions: self.max_versions,
+        };
+        (matcher, rule)
+    }
+}
+
+enum Match {
+    Yes,
+    No,
+    UnknownExe,
+}
+
+impl Matcher {
+    fn matches(&self, identity: &ClientIdentity) -> Match {
+        for (pattern, value) in [
+            (&self.app_id, identity.app_id.as_deref()),
+            (&self.sandbox_engine, identity.sandbox_engine.as_deref()),
+            (&self.instance_id, identity.instance_id.as_deref()),
+        ] {
+            let Some(pattern) = pattern else {
+                continue;
+            };
+            if value.is_none_or(|v| !glob(pattern, v)) {
+                return Match::No;
+            }
+        }
+        let Some(pattern) = &self.exe else {
+            return Match::Yes;
+        };
+        let Some(exe) = &identity.exe else {
+            return Match::UnknownExe;
+        };
+        match exe.to_str() {
+            Some(exe) if glob(pattern, exe) => Match::Yes,
+            _ => Match::No,
+        }
+    }
+}
+
+impl Rule {
+    /// Returns the maximum version of globals with this interface.
+    ///
+    /// Returns `None` if such globals must not be visible to the client.
+    pub fn max_version(&self, interface: &str) -> Option<u32> {
+        let allowed = self.allow.iter().any(|p| glob(p, interface))
+            && self.deny.iter().all(|p| !glob(p, interface));
+        if !allowed {
+            return None;
+        }
+        Some(
+            self.max_versions
+                .get(interface)
+                .copied()
+                .unwrap_or(u32::MAX),
+        )
+    }
+}
+
+impl ClientIdentity {
+    /// Creates the identity of a client.
+    ///
+    /// The executable is taken from [`Client::credentials`]. If the
+    /// `protocol-security_context_v1` feature is enabled, the remaining fields are taken
+    /// from `Client::security_context`. Otherwise they are `None`.
+    pub fn from_client(client: &Client) -> Self {
+        #[cfg_attr(not(feature = "protocol-security_context_v1"), expect(unused_mut))]
+        let mut identity = Self {
+            exe: client
+                .credentials()
+                .and_then(|c| c.exe())
+                .map(|e| e.to_path_buf()),
+            ..Default::default()
+        };
+        #[cfg(feature = "protocol-security_context_v1")]
+        if let Some(context) = client.security_context() {
+            identity.app_id = context.app_id().map(|s| s.to_string());
+            identity.sandbox_engine = context.sandbox_engine().map(|s| s.to_string());
+            identity.instance_id = context.instance_id().map(|s| s.to_string());
+        }
+        identity
+    }
+}
+
+/// A [`WlDisplayHandler`] that applies a [`Policy`] to the registries of a client.
+///
+/// When the client creates a registry, the rule for the client is determined with
+/// [`ClientIdentity::from_client`] and a [`PolicyRegistryHandler`] is installed on the
+/// registry.
+pub struct PolicyDisplayHandler {
+    policy: Arc<Policy>,
+}
+
+impl PolicyDisplayHandler {
+    /// Creates a new handler.
+    pub fn new(policy: &Arc<Policy>) -> Self {
+        Self {
+            policy: policy.clone(),
+        }
+    }
+}
+
+impl WlDisplayHandler for PolicyDisplayHandler {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        let identity = match registry.client() {
+            Some(client) => ClientIdentity::from_client(&client),
+            None => ClientIdentity::default(),
+        };
+        let rule = self.policy.rule(&identity);
+        registry.set_handler(PolicyRegistryHandler::new(rule));
+        slf.send_get_registry(registry);
+    }
+}
+
+/// A [`WlRegistryHandler`] that applies a [`Rule`] to the globals of a registry.
+///
+/// Globals that are not visible to the client are filtered with a [`GlobalMapper`].
+/// If the client tries to bind a global that is not visible to it or with a higher
+/// version than allowed by the rule, a protocol error is sent to the client.
+pub struct PolicyRegistryHandler {
+    rule: Arc<Rule>,
+    mapper: GlobalMapper,
+}
+
+impl PolicyRegistryHandler {
+    /// Creates a new handler.
+    pub fn new(rule: &Arc<Rule>) -> Self {
+        Self {
+            rule: rule.clone(),
+            mapper: Default::default(),
+        }
+    }
+}
+
+impl WlRegistryHandler for PolicyRegistryHandler {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        let interface = id.interface_name();
+        let version = id.version();
+        let message = match self.rule.max_version(interface) {
+            None => format!("invalid global {interface} ({name})"),
+            Some(max) if version > max => {
+                format!(
+                    "invalid version for global {interface} ({name}): have {max}, wanted {version}"
+                )
+            }
+            Some(_) => {
+                self.mapper.forward_bind(slf, name, &id);
+                return;
+            }
+        };
+        if let Some(client) = slf.client() {
+            client
+                .display()
+                .send_error(slf.clone(), WlDisplayError::INVALID_OBJECT.0, &message);
+        }
+    }
+
+    fn handle_global(
+        &mut self,
+        slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: ObjectInterface,
+        version: u32,
+    ) {
+        match self.rule.max_version(interface.name()) {
+            Some(max) => {
+                self.mapper
+                    .forward_global(slf, name, interface, version.min(max));
+            }
+            None => self.mapper.ignore_global(name),
+        }
+    }
+
+    fn handle_global_remove(&mut self, slf: &Rc<WlRegistry>, name: u32) {
+        self.mapper.forward_global_remove(slf, name);
+    }
+
+    fn handle_unknown_global(
+        &mut self,
+        slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        match self.rule.max_version(interface) {
+            Some(max) => {
+                self.mapper
+                    .forward_unknown_global(slf, name, interface, version.min(max));
+            }
+            None => self.mapper.ignore_global(name),
+        }
+    }
+}
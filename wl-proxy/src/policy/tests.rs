@@ -0,0 +1,202 @@
+use {
+    crate::{
+        policy::{ClientIdentity, Policy},
+        test_framework::proxy::test_proxy_no_log,
+    },
+    std::{path::PathBuf, sync::Arc},
+};
+
+const POLICY: &str = r#"
+    [default]
+    deny = ["zwlr_screencopy_manager_v1"]
+
+    [[rule]]
+    exe = "/usr/bin/mpv"
+    deny = ["wp_fifo_manager_v1"]
+
+    [[rule]]
+    sandbox-engine = "flatpak"
+    app-id = "org.mozilla.*"
+    allow = ["wl_*", "zwp_linux_dmabuf_v1"]
+    max-versions = { zwp_linux_dmabuf_v1 = 4 }
+"#;
+
+#[test]
+fn rules() {
+    let policy = Policy::parse(POLICY).unwrap();
+
+    let foot = ClientIdentity {
+        exe: Some(PathBuf::from("/usr/bin/foot")),
+        ..Default::default()
+    };
+    let rule = policy.rule(&foot);
+    assert_eq!(rule.max_version("wl_compositor"), Some(u32::MAX));
+    assert_eq!(rule.max_version("wp_fifo_manager_v1"), Some(u32::MAX));
+    assert_eq!(rule.max_version("zwlr_screencopy_manager_v1"), None);
+
+    let mpv = ClientIdentity {
+        exe: Some(PathBuf::from("/usr/bin/mpv")),
+        ..Default::default()
+    };
+    let rule = policy.rule(&mpv);
+    assert_eq!(rule.max_version("wp_fifo_manager_v1"), None);
+    assert_eq!(
+        rule.max_version("zwlr_screencopy_manager_v1"),
+        Some(u32::MAX)
+    );
+
+    let firefox = ClientIdentity {
+        exe: Some(PathBuf::from("/app/lib/firefox/firefox")),
+        app_id: Some("org.mozilla.firefox".to_string()),
+        sandbox_engine: Some("flatpak".to_string()),
+        ..Default::default()
+    };
+    let rule = policy.rule(&firefox);
+    assert_eq!(rule.max_version("wl_compositor"), Some(u32::MAX));
+    assert_eq!(rule.max_version("zwp_linux_dmabuf_v1"), Some(4));
+    assert_eq!(rule.max_version("xdg_wm_base"), None);
+
+    let unsandboxed_firefox = ClientIdentity {
+        sandbox_engine: None,
+        ..firefox
+    };
+    assert!(Arc::ptr_eq(
+        policy.rule(&unsandboxed_firefox),
+        policy.rule(&foot),
+    ));
+}
+
+#[test]
+fn unknown_exe() {
+    let policy = Policy::parse(POLICY).unwrap();
+
+    let rule = policy.rule(&ClientIdentity::default());
+    assert_eq!(rule.max_version("wl_compositor"), None);
+    assert_eq!(rule.max_version("wp_fifo_manager_v1"), None);
+    assert_eq!(rule.max_version("zwlr_screencopy_manager_v1"), None);
+
+    let policy = Policy::parse(
+        r#"
+            [[rule]]
+            app-id = "org.mozilla.*"
+            exe = "/app/lib/firefox/firefox"
+            allow = ["wl_*"]
+
+            [[rule]]
+            app-id = "org.mozilla.*"
+            deny = ["wl_shm"]
+        "#,
+    )
+    .unwrap();
+    let other = ClientIdentity {
+        app_id: Some("org.gnome.Nautilus".to_string()),
+        ..Default::default()
+    };
+    let rule = policy.rule(&other);
+    assert_eq!(rule.max_version("wl_shm"), Some(u32::MAX));
+    let firefox = ClientIdentity {
+        app_id: Some("org.mozilla.firefox".to_string()),
+        ..Default::default()
+    };
+    let rule = policy.rule(&firefox);
+    assert_eq!(rule.max_version("wl_compositor"), None);
+    assert_eq!(rule.max_version("wl_shm"), None);
+}
+
+#[test]
+fn empty() {
+    let policy = Policy::parse("").unwrap();
+    let rule = policy.rule(&ClientIdentity::default());
+    assert_eq!(
+        rule.max_version("zwlr_screencopy_manager_v1"),
+        Some(u32::MAX)
+    );
+}
+
+#[test]
+fn errors() {
+    assert!(Policy::parse("[default]\nexe = \"/usr/bin/mpv\"").is_err());
+    assert!(Policy::parse("[[rule]]\nunknown = 1").is_err());
+    assert!(Policy::parse("[[rule]]\nmax-versions = { wl_seat = -1 }").is_err());
+}
+
+#[test]
+fn identity() {
+    let tp = test_proxy_no_log();
+    let identity = ClientIdentity::from_client(&tp.client.proxy_client);
+    if tp
+        .client
+        .proxy_client
+        .credentials()
+        .unwrap()
+        .pidfd()
+        .is_some()
+    {
+        assert_eq!(identity.exe, Some(std::env::current_exe().unwrap()));
+    }
+    assert!(identity.app_id.is_none());
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn bind_errors() {
+    use {
+        crate::{
+            object::{ConcreteObject, Object},
+            policy::PolicyDisplayHandler,
+            protocols::wayland::{wl_compositor::WlCompositor, wl_seat::WlSeat},
+            state::StateHandler,
+            test_framework::{headless::HeadlessEnv, proxy::dispatch_blocking},
+        },
+        std::{cell::RefCell, rc::Rc},
+    };
+
+    struct Error(Rc<RefCell<String>>);
+
+    impl StateHandler for Error {
+        fn display_error(
+            self: Box<Self>,
+            _object: Option<&Rc<dyn Object>>,
+            _server_id: u32,
+            _error: u32,
+            msg: &str,
+        ) {
+            *self.0.borrow_mut() = msg.to_string();
+        }
+    }
+
+    let policy =
+        Policy::parse("[default]\ndeny = [\"wl_seat\"]\nmax-versions = { wl_compositor = 1 }")
+            .unwrap();
+    let policy = Arc::new(policy);
+    let bind = |f: &dyn Fn(&HeadlessEnv)| {
+        let env = HeadlessEnv::with_proxy_client(|client| {
+            client
+                .display()
+                .set_handler(PolicyDisplayHandler::new(&policy));
+        });
+        let error = Rc::new(RefCell::new(String::new()));
+        env.client.set_handler(Error(error.clone()));
+        f(&env);
+        let mut states = vec![&env.client];
+        states.extend(&env.proxies);
+        states.push(env.headless.state());
+        while dispatch_blocking(&states).is_ok() {}
+        error.take()
+    };
+    assert_eq!(
+        bind(&|env| {
+            env.bind::<WlSeat>(5);
+        }),
+        "invalid global wl_seat (5)",
+    );
+    assert_eq!(
+        bind(&|env| {
+            env.bind::<WlCompositor>(1);
+        }),
+        format!(
+            "invalid version for global wl_compositor (1): have 1, wanted {}",
+            WlCompositor::XML_VERSION,
+        ),
+    );
+}
@@ -36,36 +36,13 @@ pub(crate) mod prelude {
 
 #[cfg(feature = "logging")]
 mod logging {
-    pub use std::os::fd::AsRawFd;
-    use {debug_fn::debug_fn, std::fmt::Display, uapi::c};
-
-    pub(crate) fn debug_array(array: &[u8]) -> impl Display + use<'_> {
-        debug_fn(move |fmt| {
-            fmt.write_str("0x")?;
-            if array.is_empty() {
-                return fmt.write_str("0");
-            }
-            for b in array {
-                write!(fmt, "{:02x}", b)?;
-            }
-            Ok(())
-        })
-    }
-
-    #[inline]
-    pub(crate) fn time_since_epoch() -> (u32, u16) {
-        let mut ts = c::timespec {
-            tv_sec: 0,
-            tv_nsec: 0,
-        };
-        let _ = uapi::clock_gettime(c::CLOCK_REALTIME, &mut ts);
-        let sec = ts.tv_sec as u64;
-        let nsec = ts.tv_nsec as u64;
-        let time = sec.wrapping_mul(1_000_000).wrapping_add(nsec / 1_000) as u32;
-        let millis = time / 1_000;
-        let micros = (time % 1_000) as u16;
-        (millis, micros)
-    }
+    pub use {
+        crate::{
+            logging::{LogArg, LogValue},
+            recording::Direction,
+        },
+        std::os::fd::AsRawFd,
+    };
 }
 
 #[cold]
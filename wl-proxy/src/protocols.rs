@@ -208,6 +208,8 @@ pub mod cosmic_overlap_notify_unstable_v1;
 #[cfg(feature = "protocol-cosmic_workspace_unstable_v2")]
 pub mod cosmic_workspace_unstable_v2;
 
+pub(crate) use all_types::create_object_for_interface;
+
 #[allow(unused_imports)]
 mod all_types {
     #[cfg(feature = "protocol-hyprland_ctm_control_v1")]
@@ -1240,10 +1242,11 @@ mod all_types {
 
     use crate::protocol_helpers::prelude::*;
 
-    pub(super) fn create_object_for_interface(state: &Rc<State>, interface: &str, version: u32) -> Result<Rc<dyn Object>, ObjectError> {
-        ObjectInterface::from_str(interface)
-            .ok_or(ObjectError(ObjectErrorKind::UnsupportedInterface(interface.to_string())))
-            .and_then(|i| i.create_object(state, version))
+    pub(crate) fn create_object_for_interface(state: &Rc<State>, interface: &str, version: u32) -> Result<Rc<dyn Object>, ObjectError> {
+        let Some(i) = ObjectInterface::from_str(interface) else {
+            return crate::unknown::create_unknown_object(state, interface, version);
+        };
+        i.create_object(state, version)
     }
 
     impl ObjectInterface {
@@ -4310,6 +4313,9 @@ mod all_types {
                     }
                     Ok(ZcosmicWorkspaceManagerV2::new(state, version))
                 }
+                Self::Unknown => {
+                    Err(ObjectError(ObjectErrorKind::UnsupportedInterface(self.name().to_string())))
+                }
             }
         }
     }
@@ -4317,6 +4323,7 @@ mod all_types {
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, linearize::Linearize)]
 #[linearize(const)]
+#[non_exhaustive]
 pub enum ObjectInterface {
     /// hyprland_ctm_control_manager_v1
     #[cfg(feature = "protocol-hyprland_ctm_control_v1")]
@@ -5153,6 +5160,10 @@ pub enum ObjectInterface {
     /// zcosmic_workspace_manager_v2
     #[cfg(feature = "protocol-cosmic_workspace_unstable_v2")]
     ZcosmicWorkspaceManagerV2,
+    /// An interface that is not known to this crate.
+    ///
+    /// See [`UnknownObject`](crate::unknown::UnknownObject).
+    Unknown,
 }
 
 impl ObjectInterface {
@@ -5707,6 +5718,7 @@ impl ObjectInterface {
             Self::ZcosmicWorkspaceHandleV2 => "zcosmic_workspace_handle_v2",
             #[cfg(feature = "protocol-cosmic_workspace_unstable_v2")]
             Self::ZcosmicWorkspaceManagerV2 => "zcosmic_workspace_manager_v2",
+            Self::Unknown => "unknown",
         }
     }
 
@@ -6261,6 +6273,7 @@ impl ObjectInterface {
             Self::ZcosmicWorkspaceHandleV2 => 2,
             #[cfg(feature = "protocol-cosmic_workspace_unstable_v2")]
             Self::ZcosmicWorkspaceManagerV2 => 2,
+            Self::Unknown => 0,
         }
     }
 }
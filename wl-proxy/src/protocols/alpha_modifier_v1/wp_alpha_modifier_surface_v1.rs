@@ -75,10 +75,15 @@ impl WpAlphaModifierSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_alpha_modifier_surface_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_alpha_modifier_surface_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -155,10 +160,16 @@ impl WpAlphaModifierSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_alpha_modifier_surface_v1#{}.set_multiplier(factor: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_alpha_modifier_surface_v1",
+                    id,
+                    "set_multiplier",
+                    &[
+                        LogArg::new(Some("factor"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -313,10 +324,15 @@ impl ObjectPrivate for WpAlphaModifierSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_alpha_modifier_surface_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_alpha_modifier_surface_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -337,10 +353,16 @@ impl ObjectPrivate for WpAlphaModifierSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_alpha_modifier_surface_v1#{}.set_multiplier(factor: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_alpha_modifier_surface_v1",
+                            id,
+                            "set_multiplier",
+                            &[
+                                LogArg::new(Some("factor"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -391,6 +413,20 @@ impl ObjectPrivate for WpAlphaModifierSurfaceV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -74,10 +74,15 @@ impl WpColorManagementOutputV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_output_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_output_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -139,10 +144,15 @@ impl WpColorManagementOutputV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_management_output_v1#{}.image_description_changed()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_management_output_v1",
+                    id,
+                    "image_description_changed",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -243,10 +253,16 @@ impl WpColorManagementOutputV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_output_v1#{}.get_image_description(image_description: wp_image_description_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_output_v1",
+                    id,
+                    "get_image_description",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -548,10 +564,15 @@ impl ObjectPrivate for WpColorManagementOutputV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_output_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_output_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -572,10 +593,16 @@ impl ObjectPrivate for WpColorManagementOutputV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_output_v1#{}.get_image_description(image_description: wp_image_description_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_output_v1",
+                            id,
+                            "get_image_description",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -615,10 +642,15 @@ impl ObjectPrivate for WpColorManagementOutputV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_management_output_v1#{}.image_description_changed()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_management_output_v1",
+                            id,
+                            "image_description_changed",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -656,6 +688,23 @@ impl ObjectPrivate for WpColorManagementOutputV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_image_description_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -71,10 +71,15 @@ impl WpColorManagementSurfaceFeedbackV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_surface_feedback_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_surface_feedback_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -143,10 +148,16 @@ impl WpColorManagementSurfaceFeedbackV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_management_surface_feedback_v1#{}.preferred_changed(identity: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_management_surface_feedback_v1",
+                    id,
+                    "preferred_changed",
+                    &[
+                        LogArg::new(Some("identity"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -251,10 +262,16 @@ impl WpColorManagementSurfaceFeedbackV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_surface_feedback_v1#{}.get_preferred(image_description: wp_image_description_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_surface_feedback_v1",
+                    id,
+                    "get_preferred",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -457,10 +474,16 @@ impl WpColorManagementSurfaceFeedbackV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_surface_feedback_v1#{}.get_preferred_parametric(image_description: wp_image_description_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_surface_feedback_v1",
+                    id,
+                    "get_preferred_parametric",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -593,10 +616,17 @@ impl WpColorManagementSurfaceFeedbackV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_management_surface_feedback_v1#{}.preferred_changed2(identity_hi: {}, identity_lo: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_management_surface_feedback_v1",
+                    id,
+                    "preferred_changed2",
+                    &[
+                        LogArg::new(Some("identity_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("identity_lo"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -870,10 +900,15 @@ impl ObjectPrivate for WpColorManagementSurfaceFeedbackV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_surface_feedback_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_surface_feedback_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -894,10 +929,16 @@ impl ObjectPrivate for WpColorManagementSurfaceFeedbackV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_surface_feedback_v1#{}.get_preferred(image_description: wp_image_description_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_surface_feedback_v1",
+                            id,
+                            "get_preferred",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -922,10 +963,16 @@ impl ObjectPrivate for WpColorManagementSurfaceFeedbackV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_surface_feedback_v1#{}.get_preferred_parametric(image_description: wp_image_description_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_surface_feedback_v1",
+                            id,
+                            "get_preferred_parametric",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -967,10 +1014,16 @@ impl ObjectPrivate for WpColorManagementSurfaceFeedbackV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_management_surface_feedback_v1#{}.preferred_changed(identity: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_management_surface_feedback_v1",
+                            id,
+                            "preferred_changed",
+                            &[
+                                LogArg::new(Some("identity"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -991,10 +1044,17 @@ impl ObjectPrivate for WpColorManagementSurfaceFeedbackV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_management_surface_feedback_v1#{}.preferred_changed2(identity_hi: {}, identity_lo: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_management_surface_feedback_v1",
+                            id,
+                            "preferred_changed2",
+                            &[
+                                LogArg::new(Some("identity_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("identity_lo"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -1034,6 +1094,25 @@ impl ObjectPrivate for WpColorManagementSurfaceFeedbackV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_image_description_v1]",
+            2 => "n[wp_image_description_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "uu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
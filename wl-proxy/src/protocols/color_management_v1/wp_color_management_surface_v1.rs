@@ -72,10 +72,15 @@ impl WpColorManagementSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_surface_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_surface_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -186,10 +191,17 @@ impl WpColorManagementSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: WpColorManagerV1RenderIntent) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_surface_v1#{}.set_image_description(image_description: wp_image_description_v1#{}, render_intent: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_surface_v1",
+                    id,
+                    "set_image_description",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::Object { interface: Some("wp_image_description_v1"), id: arg0 }),
+                        LogArg::new(Some("render_intent"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -295,10 +307,15 @@ impl WpColorManagementSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_management_surface_v1#{}.unset_image_description()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_management_surface_v1",
+                    id,
+                    "unset_image_description",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -493,10 +510,15 @@ impl ObjectPrivate for WpColorManagementSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_surface_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_surface_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -519,10 +541,17 @@ impl ObjectPrivate for WpColorManagementSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: WpColorManagerV1RenderIntent) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_surface_v1#{}.set_image_description(image_description: wp_image_description_v1#{}, render_intent: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_surface_v1",
+                            id,
+                            "set_image_description",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::Object { interface: Some("wp_image_description_v1"), id: arg0 }),
+                                LogArg::new(Some("render_intent"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -549,10 +578,15 @@ impl ObjectPrivate for WpColorManagementSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_management_surface_v1#{}.unset_image_description()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_management_surface_v1",
+                            id,
+                            "unset_image_description",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -604,6 +638,21 @@ impl ObjectPrivate for WpColorManagementSurfaceV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "ou",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
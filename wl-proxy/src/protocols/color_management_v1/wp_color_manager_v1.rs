@@ -74,10 +74,15 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -158,10 +163,17 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.get_output(id: wp_color_management_output_v1#{}, output: wl_output#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "get_output",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_management_output_v1", id: arg0, version: None }),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -303,10 +315,17 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.get_surface(id: wp_color_management_surface_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "get_surface",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_management_surface_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -455,10 +474,17 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.get_surface_feedback(id: wp_color_management_surface_feedback_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "get_surface_feedback",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_management_surface_feedback_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -595,10 +621,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.create_icc_creator(obj: wp_image_description_creator_icc_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "create_icc_creator",
+                    &[
+                        LogArg::new(Some("obj"), LogValue::NewId { interface: "wp_image_description_creator_icc_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -728,10 +760,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.create_parametric_creator(obj: wp_image_description_creator_params_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "create_parametric_creator",
+                    &[
+                        LogArg::new(Some("obj"), LogValue::NewId { interface: "wp_image_description_creator_params_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -897,10 +935,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.create_windows_scrgb(image_description: wp_image_description_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "create_windows_scrgb",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -1132,10 +1176,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1RenderIntent) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_manager_v1#{}.supported_intent(render_intent: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "supported_intent",
+                    &[
+                        LogArg::new(Some("render_intent"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1212,10 +1262,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1Feature) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_manager_v1#{}.supported_feature(feature: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "supported_feature",
+                    &[
+                        LogArg::new(Some("feature"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1293,10 +1349,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1TransferFunction) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_manager_v1#{}.supported_tf_named(tf: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "supported_tf_named",
+                    &[
+                        LogArg::new(Some("tf"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1375,10 +1437,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1Primaries) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_manager_v1#{}.supported_primaries_named(primaries: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "supported_primaries_named",
+                    &[
+                        LogArg::new(Some("primaries"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1443,10 +1511,15 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_manager_v1#{}.done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -1524,10 +1597,17 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.get_image_description(image_description: wp_image_description_v1#{}, reference: wp_image_description_reference_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "get_image_description",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                        LogArg::new(Some("reference"), LogValue::Object { interface: Some("wp_image_description_reference_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -1677,10 +1757,16 @@ impl WpColorManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_manager_v1#{}.create_windows_bt2100(image_description: wp_image_description_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_manager_v1",
+                    id,
+                    "create_windows_bt2100",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -2328,10 +2414,15 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -2353,10 +2444,17 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.get_output(id: wp_color_management_output_v1#{}, output: wl_output#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "get_output",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_management_output_v1", id: arg0, version: None }),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2391,10 +2489,17 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.get_surface(id: wp_color_management_surface_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "get_surface",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_management_surface_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2429,10 +2534,17 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.get_surface_feedback(id: wp_color_management_surface_feedback_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "get_surface_feedback",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_management_surface_feedback_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2466,10 +2578,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.create_icc_creator(obj: wp_image_description_creator_icc_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "create_icc_creator",
+                            &[
+                                LogArg::new(Some("obj"), LogValue::NewId { interface: "wp_image_description_creator_icc_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2494,10 +2612,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.create_parametric_creator(obj: wp_image_description_creator_params_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "create_parametric_creator",
+                            &[
+                                LogArg::new(Some("obj"), LogValue::NewId { interface: "wp_image_description_creator_params_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2522,10 +2646,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.create_windows_scrgb(image_description: wp_image_description_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "create_windows_scrgb",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2551,10 +2681,17 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.get_image_description(image_description: wp_image_description_v1#{}, reference: wp_image_description_reference_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "get_image_description",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                                LogArg::new(Some("reference"), LogValue::Object { interface: Some("wp_image_description_reference_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2588,10 +2725,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_manager_v1#{}.create_windows_bt2100(image_description: wp_image_description_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "create_windows_bt2100",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2634,10 +2777,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorManagerV1RenderIntent) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_manager_v1#{}.supported_intent(render_intent: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "supported_intent",
+                            &[
+                                LogArg::new(Some("render_intent"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -2658,10 +2807,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorManagerV1Feature) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_manager_v1#{}.supported_feature(feature: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "supported_feature",
+                            &[
+                                LogArg::new(Some("feature"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -2682,10 +2837,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorManagerV1TransferFunction) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_manager_v1#{}.supported_tf_named(tf: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "supported_tf_named",
+                            &[
+                                LogArg::new(Some("tf"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -2706,10 +2867,16 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorManagerV1Primaries) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_manager_v1#{}.supported_primaries_named(primaries: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "supported_primaries_named",
+                            &[
+                                LogArg::new(Some("primaries"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -2727,10 +2894,15 @@ impl ObjectPrivate for WpColorManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_manager_v1#{}.done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_manager_v1",
+                            id,
+                            "done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -2779,6 +2951,34 @@ impl ObjectPrivate for WpColorManagerV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_color_management_output_v1]o",
+            2 => "n[wp_color_management_surface_v1]o",
+            3 => "n[wp_color_management_surface_feedback_v1]o",
+            4 => "n[wp_image_description_creator_icc_v1]",
+            5 => "n[wp_image_description_creator_params_v1]",
+            6 => "n[wp_image_description_v1]",
+            7 => "n[wp_image_description_v1]o",
+            8 => "n[wp_image_description_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "u",
+            2 => "u",
+            3 => "u",
+            4 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -117,10 +117,16 @@ impl WpImageDescriptionCreatorIccV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_icc_v1#{}.create(image_description: wp_image_description_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_icc_v1",
+                    id,
+                    "create",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -318,10 +324,18 @@ impl WpImageDescriptionCreatorIccV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_icc_v1#{}.set_icc_file(icc_profile: {}, offset: {}, length: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_icc_v1",
+                    id,
+                    "set_icc_file",
+                    &[
+                        LogArg::new(Some("icc_profile"), LogValue::Fd(arg0)),
+                        LogArg::new(Some("offset"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("length"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0.as_raw_fd(), arg1, arg2);
         }
@@ -566,10 +580,16 @@ impl ObjectPrivate for WpImageDescriptionCreatorIccV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_icc_v1#{}.create(image_description: wp_image_description_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_icc_v1",
+                            id,
+                            "create",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -600,10 +620,18 @@ impl ObjectPrivate for WpImageDescriptionCreatorIccV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_icc_v1#{}.set_icc_file(icc_profile: {}, offset: {}, length: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_icc_v1",
+                            id,
+                            "set_icc_file",
+                            &[
+                                LogArg::new(Some("icc_profile"), LogValue::Fd(arg0)),
+                                LogArg::new(Some("offset"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("length"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0.as_raw_fd(), arg1, arg2);
                 }
@@ -654,6 +682,20 @@ impl ObjectPrivate for WpImageDescriptionCreatorIccV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[wp_image_description_v1]",
+            1 => "huu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
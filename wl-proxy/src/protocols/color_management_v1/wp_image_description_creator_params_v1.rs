@@ -151,10 +151,16 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.create(image_description: wp_image_description_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "create",
+                    &[
+                        LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -350,10 +356,16 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: WpColorManagerV1TransferFunction) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_tf_named(tf: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_tf_named",
+                    &[
+                        LogArg::new(Some("tf"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -450,10 +462,16 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_tf_power(eexp: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_tf_power",
+                    &[
+                        LogArg::new(Some("eexp"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -548,10 +566,16 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: WpColorManagerV1Primaries) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_primaries_named(primaries: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_primaries_named",
+                    &[
+                        LogArg::new(Some("primaries"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -669,10 +693,23 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_primaries",
+                    &[
+                        LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                        LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                        LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                        LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                        LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                        LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
         }
@@ -837,10 +874,18 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_luminances(min_lum: {}, max_lum: {}, reference_lum: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_luminances",
+                    &[
+                        LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("reference_lum"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -1038,10 +1083,23 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_mastering_display_primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_mastering_display_primaries",
+                    &[
+                        LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                        LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                        LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                        LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                        LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                        LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
         }
@@ -1219,10 +1277,17 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_mastering_luminance(min_lum: {}, max_lum: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_mastering_luminance",
+                    &[
+                        LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -1329,10 +1394,16 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_max_cll(max_cll: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_max_cll",
+                    &[
+                        LogArg::new(Some("max_cll"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -1406,10 +1477,16 @@ impl WpImageDescriptionCreatorParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_creator_params_v1#{}.set_max_fall(max_fall: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_creator_params_v1",
+                    id,
+                    "set_max_fall",
+                    &[
+                        LogArg::new(Some("max_fall"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -2000,10 +2077,16 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.create(image_description: wp_image_description_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "create",
+                            &[
+                                LogArg::new(Some("image_description"), LogValue::NewId { interface: "wp_image_description_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2030,10 +2113,16 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1TransferFunction) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_tf_named(tf: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_tf_named",
+                            &[
+                                LogArg::new(Some("tf"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2053,10 +2142,16 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_tf_power(eexp: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_tf_power",
+                            &[
+                                LogArg::new(Some("eexp"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2077,10 +2172,16 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1Primaries) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_primaries_named(primaries: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_primaries_named",
+                            &[
+                                LogArg::new(Some("primaries"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2115,10 +2216,23 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_primaries",
+                            &[
+                                LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                                LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                                LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                                LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                                LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                                LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
                 }
@@ -2140,10 +2254,18 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_luminances(min_lum: {}, max_lum: {}, reference_lum: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_luminances",
+                            &[
+                                LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("reference_lum"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -2178,10 +2300,23 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_mastering_display_primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_mastering_display_primaries",
+                            &[
+                                LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                                LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                                LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                                LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                                LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                                LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
                 }
@@ -2202,10 +2337,17 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_mastering_luminance(min_lum: {}, max_lum: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_mastering_luminance",
+                            &[
+                                LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2225,10 +2367,16 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_max_cll(max_cll: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_max_cll",
+                            &[
+                                LogArg::new(Some("max_cll"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2248,10 +2396,16 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_creator_params_v1#{}.set_max_fall(max_fall: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_creator_params_v1",
+                            id,
+                            "set_max_fall",
+                            &[
+                                LogArg::new(Some("max_fall"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2310,6 +2464,28 @@ impl ObjectPrivate for WpImageDescriptionCreatorParamsV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[wp_image_description_v1]",
+            1 => "u",
+            2 => "u",
+            3 => "u",
+            4 => "iiiiiiii",
+            5 => "uuu",
+            6 => "iiiiiiii",
+            7 => "uu",
+            8 => "u",
+            9 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -90,10 +90,15 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -170,10 +175,17 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.icc_file(icc: {}, icc_size: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "icc_file",
+                    &[
+                        LogArg::new(Some("icc"), LogValue::Fd(arg0)),
+                        LogArg::new(Some("icc_size"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0.as_raw_fd(), arg1);
         }
@@ -285,10 +297,23 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "primaries",
+                    &[
+                        LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                        LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                        LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                        LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                        LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                        LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
         }
@@ -390,10 +415,16 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1Primaries) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.primaries_named(primaries: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "primaries_named",
+                    &[
+                        LogArg::new(Some("primaries"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -469,10 +500,16 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.tf_power(eexp: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "tf_power",
+                    &[
+                        LogArg::new(Some("eexp"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -548,10 +585,16 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorManagerV1TransferFunction) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.tf_named(tf: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "tf_named",
+                    &[
+                        LogArg::new(Some("tf"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -636,10 +679,18 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.luminances(min_lum: {}, max_lum: {}, reference_lum: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "luminances",
+                    &[
+                        LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("reference_lum"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -759,10 +810,23 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.target_primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "target_primaries",
+                    &[
+                        LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                        LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                        LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                        LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                        LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                        LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
         }
@@ -882,10 +946,17 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.target_luminance(min_lum: {}, max_lum: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "target_luminance",
+                    &[
+                        LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -973,10 +1044,16 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.target_max_cll(max_cll: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "target_max_cll",
+                    &[
+                        LogArg::new(Some("max_cll"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1053,10 +1130,16 @@ impl WpImageDescriptionInfoV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_info_v1#{}.target_max_fall(max_fall: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_info_v1",
+                    id,
+                    "target_max_fall",
+                    &[
+                        LogArg::new(Some("max_fall"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1526,10 +1609,15 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1554,10 +1642,17 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.icc_file(icc: {}, icc_size: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "icc_file",
+                            &[
+                                LogArg::new(Some("icc"), LogValue::Fd(arg0)),
+                                LogArg::new(Some("icc_size"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0.as_raw_fd(), arg1);
                 }
@@ -1592,10 +1687,23 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "primaries",
+                            &[
+                                LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                                LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                                LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                                LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                                LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                                LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
                 }
@@ -1616,10 +1724,16 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorManagerV1Primaries) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.primaries_named(primaries: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "primaries_named",
+                            &[
+                                LogArg::new(Some("primaries"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1639,10 +1753,16 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.tf_power(eexp: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "tf_power",
+                            &[
+                                LogArg::new(Some("eexp"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1663,10 +1783,16 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorManagerV1TransferFunction) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.tf_named(tf: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "tf_named",
+                            &[
+                                LogArg::new(Some("tf"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1688,10 +1814,18 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.luminances(min_lum: {}, max_lum: {}, reference_lum: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "luminances",
+                            &[
+                                LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("reference_lum"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -1726,10 +1860,23 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.target_primaries(r_x: {}, r_y: {}, g_x: {}, g_y: {}, b_x: {}, b_y: {}, w_x: {}, w_y: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "target_primaries",
+                            &[
+                                LogArg::new(Some("r_x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("r_y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("g_x"), LogValue::Int(arg2)),
+                                LogArg::new(Some("g_y"), LogValue::Int(arg3)),
+                                LogArg::new(Some("b_x"), LogValue::Int(arg4)),
+                                LogArg::new(Some("b_y"), LogValue::Int(arg5)),
+                                LogArg::new(Some("w_x"), LogValue::Int(arg6)),
+                                LogArg::new(Some("w_y"), LogValue::Int(arg7)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
                 }
@@ -1750,10 +1897,17 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.target_luminance(min_lum: {}, max_lum: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "target_luminance",
+                            &[
+                                LogArg::new(Some("min_lum"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("max_lum"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -1773,10 +1927,16 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.target_max_cll(max_cll: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "target_max_cll",
+                            &[
+                                LogArg::new(Some("max_cll"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1796,10 +1956,16 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_info_v1#{}.target_max_fall(max_fall: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_info_v1",
+                            id,
+                            "target_max_fall",
+                            &[
+                                LogArg::new(Some("max_fall"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1843,6 +2009,29 @@ impl ObjectPrivate for WpImageDescriptionInfoV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "hu",
+            2 => "iiiiiiii",
+            3 => "u",
+            4 => "u",
+            5 => "u",
+            6 => "uuu",
+            7 => "iiiiiiii",
+            8 => "uu",
+            9 => "u",
+            10 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
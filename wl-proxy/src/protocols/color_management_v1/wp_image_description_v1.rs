@@ -91,10 +91,15 @@ impl WpImageDescriptionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -176,10 +181,17 @@ impl WpImageDescriptionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpImageDescriptionV1Cause, arg1: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_v1#{}.failed(cause: {:?}, msg: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_v1",
+                    id,
+                    "failed",
+                    &[
+                        LogArg::new(Some("cause"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("msg"), LogValue::String(Some(arg1))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -273,10 +285,16 @@ impl WpImageDescriptionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_v1#{}.ready(identity: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_v1",
+                    id,
+                    "ready",
+                    &[
+                        LogArg::new(Some("identity"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -363,10 +381,16 @@ impl WpImageDescriptionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_image_description_v1#{}.get_information(information: wp_image_description_info_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_image_description_v1",
+                    id,
+                    "get_information",
+                    &[
+                        LogArg::new(Some("information"), LogValue::NewId { interface: "wp_image_description_info_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -510,10 +534,17 @@ impl WpImageDescriptionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_image_description_v1#{}.ready2(identity_hi: {}, identity_lo: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_image_description_v1",
+                    id,
+                    "ready2",
+                    &[
+                        LogArg::new(Some("identity_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("identity_lo"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -796,10 +827,15 @@ impl ObjectPrivate for WpImageDescriptionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -820,10 +856,16 @@ impl ObjectPrivate for WpImageDescriptionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_image_description_v1#{}.get_information(information: wp_image_description_info_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_image_description_v1",
+                            id,
+                            "get_information",
+                            &[
+                                LogArg::new(Some("information"), LogValue::NewId { interface: "wp_image_description_info_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -871,10 +913,17 @@ impl ObjectPrivate for WpImageDescriptionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpImageDescriptionV1Cause, arg1: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_v1#{}.failed(cause: {:?}, msg: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_v1",
+                            id,
+                            "failed",
+                            &[
+                                LogArg::new(Some("cause"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("msg"), LogValue::String(Some(arg1))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -894,10 +943,16 @@ impl ObjectPrivate for WpImageDescriptionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_v1#{}.ready(identity: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_v1",
+                            id,
+                            "ready",
+                            &[
+                                LogArg::new(Some("identity"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -918,10 +973,17 @@ impl ObjectPrivate for WpImageDescriptionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_image_description_v1#{}.ready2(identity_hi: {}, identity_lo: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_image_description_v1",
+                            id,
+                            "ready2",
+                            &[
+                                LogArg::new(Some("identity_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("identity_lo"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -961,6 +1023,25 @@ impl ObjectPrivate for WpImageDescriptionV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_image_description_info_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "us",
+            1 => "u",
+            2 => "uu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
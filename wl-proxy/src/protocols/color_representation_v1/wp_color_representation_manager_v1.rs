@@ -72,10 +72,15 @@ impl WpColorRepresentationManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_representation_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_representation_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -159,10 +164,17 @@ impl WpColorRepresentationManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_representation_manager_v1#{}.get_surface(id: wp_color_representation_surface_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_representation_manager_v1",
+                    id,
+                    "get_surface",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_representation_surface_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -299,10 +311,16 @@ impl WpColorRepresentationManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorRepresentationSurfaceV1AlphaMode) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_representation_manager_v1#{}.supported_alpha_mode(alpha_mode: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_representation_manager_v1",
+                    id,
+                    "supported_alpha_mode",
+                    &[
+                        LogArg::new(Some("alpha_mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -385,10 +403,17 @@ impl WpColorRepresentationManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WpColorRepresentationSurfaceV1Coefficients, arg1: WpColorRepresentationSurfaceV1Range) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_representation_manager_v1#{}.supported_coefficients_and_ranges(coefficients: {:?}, range: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_representation_manager_v1",
+                    id,
+                    "supported_coefficients_and_ranges",
+                    &[
+                        LogArg::new(Some("coefficients"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("range"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -457,10 +482,15 @@ impl WpColorRepresentationManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_color_representation_manager_v1#{}.done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_color_representation_manager_v1",
+                    id,
+                    "done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -672,10 +702,15 @@ impl ObjectPrivate for WpColorRepresentationManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_representation_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_representation_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -697,10 +732,17 @@ impl ObjectPrivate for WpColorRepresentationManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_representation_manager_v1#{}.get_surface(id: wp_color_representation_surface_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_representation_manager_v1",
+                            id,
+                            "get_surface",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_color_representation_surface_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -752,10 +794,16 @@ impl ObjectPrivate for WpColorRepresentationManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorRepresentationSurfaceV1AlphaMode) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_representation_manager_v1#{}.supported_alpha_mode(alpha_mode: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_representation_manager_v1",
+                            id,
+                            "supported_alpha_mode",
+                            &[
+                                LogArg::new(Some("alpha_mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -778,10 +826,17 @@ impl ObjectPrivate for WpColorRepresentationManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WpColorRepresentationSurfaceV1Coefficients, arg1: WpColorRepresentationSurfaceV1Range) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_representation_manager_v1#{}.supported_coefficients_and_ranges(coefficients: {:?}, range: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_representation_manager_v1",
+                            id,
+                            "supported_coefficients_and_ranges",
+                            &[
+                                LogArg::new(Some("coefficients"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("range"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -799,10 +854,15 @@ impl ObjectPrivate for WpColorRepresentationManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_color_representation_manager_v1#{}.done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_color_representation_manager_v1",
+                            id,
+                            "done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -842,6 +902,25 @@ impl ObjectPrivate for WpColorRepresentationManagerV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_color_representation_surface_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "uu",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
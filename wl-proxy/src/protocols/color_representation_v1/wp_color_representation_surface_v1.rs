@@ -82,10 +82,15 @@ impl WpColorRepresentationSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_representation_surface_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_representation_surface_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -164,10 +169,16 @@ impl WpColorRepresentationSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: WpColorRepresentationSurfaceV1AlphaMode) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_representation_surface_v1#{}.set_alpha_mode(alpha_mode: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_representation_surface_v1",
+                    id,
+                    "set_alpha_mode",
+                    &[
+                        LogArg::new(Some("alpha_mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -275,10 +286,17 @@ impl WpColorRepresentationSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: WpColorRepresentationSurfaceV1Coefficients, arg1: WpColorRepresentationSurfaceV1Range) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_representation_surface_v1#{}.set_coefficients_and_range(coefficients: {:?}, range: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_representation_surface_v1",
+                    id,
+                    "set_coefficients_and_range",
+                    &[
+                        LogArg::new(Some("coefficients"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("range"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -392,10 +410,16 @@ impl WpColorRepresentationSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: WpColorRepresentationSurfaceV1ChromaLocation) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_color_representation_surface_v1#{}.set_chroma_location(chroma_location: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_color_representation_surface_v1",
+                    id,
+                    "set_chroma_location",
+                    &[
+                        LogArg::new(Some("chroma_location"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -648,10 +672,15 @@ impl ObjectPrivate for WpColorRepresentationSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_representation_surface_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_representation_surface_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -673,10 +702,16 @@ impl ObjectPrivate for WpColorRepresentationSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: WpColorRepresentationSurfaceV1AlphaMode) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_representation_surface_v1#{}.set_alpha_mode(alpha_mode: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_representation_surface_v1",
+                            id,
+                            "set_alpha_mode",
+                            &[
+                                LogArg::new(Some("alpha_mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -699,10 +734,17 @@ impl ObjectPrivate for WpColorRepresentationSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: WpColorRepresentationSurfaceV1Coefficients, arg1: WpColorRepresentationSurfaceV1Range) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_representation_surface_v1#{}.set_coefficients_and_range(coefficients: {:?}, range: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_representation_surface_v1",
+                            id,
+                            "set_coefficients_and_range",
+                            &[
+                                LogArg::new(Some("coefficients"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("range"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -723,10 +765,16 @@ impl ObjectPrivate for WpColorRepresentationSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: WpColorRepresentationSurfaceV1ChromaLocation) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_color_representation_surface_v1#{}.set_chroma_location(chroma_location: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_color_representation_surface_v1",
+                            id,
+                            "set_chroma_location",
+                            &[
+                                LogArg::new(Some("chroma_location"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -779,6 +827,22 @@ impl ObjectPrivate for WpColorRepresentationSurfaceV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "u",
+            2 => "uu",
+            3 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
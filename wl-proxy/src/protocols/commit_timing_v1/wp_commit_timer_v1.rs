@@ -98,10 +98,18 @@ impl WpCommitTimerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_commit_timer_v1#{}.set_timestamp(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_commit_timer_v1",
+                    id,
+                    "set_timestamp",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -184,10 +192,15 @@ impl WpCommitTimerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_commit_timer_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_commit_timer_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -339,10 +352,18 @@ impl ObjectPrivate for WpCommitTimerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_commit_timer_v1#{}.set_timestamp(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_commit_timer_v1",
+                            id,
+                            "set_timestamp",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -360,10 +381,15 @@ impl ObjectPrivate for WpCommitTimerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_commit_timer_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_commit_timer_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -415,6 +441,20 @@ impl ObjectPrivate for WpCommitTimerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuu",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
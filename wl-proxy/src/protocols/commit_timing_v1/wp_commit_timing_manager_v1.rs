@@ -89,10 +89,15 @@ impl WpCommitTimingManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_commit_timing_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_commit_timing_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -174,10 +179,17 @@ impl WpCommitTimingManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_commit_timing_manager_v1#{}.get_timer(id: wp_commit_timer_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_commit_timing_manager_v1",
+                    id,
+                    "get_timer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_commit_timer_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -370,10 +382,15 @@ impl ObjectPrivate for WpCommitTimingManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_commit_timing_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_commit_timing_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -395,10 +412,17 @@ impl ObjectPrivate for WpCommitTimingManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_commit_timing_manager_v1#{}.get_timer(id: wp_commit_timer_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_commit_timing_manager_v1",
+                            id,
+                            "get_timer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_commit_timer_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -463,6 +487,20 @@ impl ObjectPrivate for WpCommitTimingManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_commit_timer_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
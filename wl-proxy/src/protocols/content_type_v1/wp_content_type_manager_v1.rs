@@ -74,10 +74,15 @@ impl WpContentTypeManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_content_type_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_content_type_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -158,10 +163,17 @@ impl WpContentTypeManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_content_type_manager_v1#{}.get_surface_content_type(id: wp_content_type_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_content_type_manager_v1",
+                    id,
+                    "get_surface_content_type",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_content_type_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -353,10 +365,15 @@ impl ObjectPrivate for WpContentTypeManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_content_type_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_content_type_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -378,10 +395,17 @@ impl ObjectPrivate for WpContentTypeManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_content_type_manager_v1#{}.get_surface_content_type(id: wp_content_type_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_content_type_manager_v1",
+                            id,
+                            "get_surface_content_type",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_content_type_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -446,6 +470,20 @@ impl ObjectPrivate for WpContentTypeManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_content_type_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
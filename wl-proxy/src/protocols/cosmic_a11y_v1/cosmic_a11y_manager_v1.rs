@@ -82,10 +82,16 @@ impl CosmicA11yManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: CosmicA11yManagerV1ActiveState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= cosmic_a11y_manager_v1#{}.magnifier(active: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "cosmic_a11y_manager_v1",
+                    id,
+                    "magnifier",
+                    &[
+                        LogArg::new(Some("active"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -158,10 +164,16 @@ impl CosmicA11yManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: CosmicA11yManagerV1ActiveState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_a11y_manager_v1#{}.set_magnifier(active: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_a11y_manager_v1",
+                    id,
+                    "set_magnifier",
+                    &[
+                        LogArg::new(Some("active"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -250,10 +262,17 @@ impl CosmicA11yManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= cosmic_a11y_manager_v1#{}.screen_filter(inverted: {:?}, filter: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "cosmic_a11y_manager_v1",
+                    id,
+                    "screen_filter",
+                    &[
+                        LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -349,10 +368,17 @@ impl CosmicA11yManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_a11y_manager_v1#{}.set_screen_filter(inverted: {:?}, filter: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_a11y_manager_v1",
+                    id,
+                    "set_screen_filter",
+                    &[
+                        LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -453,10 +479,18 @@ impl CosmicA11yManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter, arg2: CosmicA11yManagerV1ActiveState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= cosmic_a11y_manager_v1#{}.screen_filter2(inverted: {:?}, filter: {:?}, filter_state: {:?})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "cosmic_a11y_manager_v1",
+                    id,
+                    "screen_filter2",
+                    &[
+                        LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                        LogArg::new(Some("filter_state"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -556,10 +590,18 @@ impl CosmicA11yManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter, arg2: CosmicA11yManagerV1ActiveState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_a11y_manager_v1#{}.set_screen_filter2(inverted: {:?}, filter: {:?}, filter_state: {:?})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_a11y_manager_v1",
+                    id,
+                    "set_screen_filter2",
+                    &[
+                        LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                        LogArg::new(Some("filter_state"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -869,10 +911,16 @@ impl ObjectPrivate for CosmicA11yManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: CosmicA11yManagerV1ActiveState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_a11y_manager_v1#{}.set_magnifier(active: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_a11y_manager_v1",
+                            id,
+                            "set_magnifier",
+                            &[
+                                LogArg::new(Some("active"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -895,10 +943,17 @@ impl ObjectPrivate for CosmicA11yManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_a11y_manager_v1#{}.set_screen_filter(inverted: {:?}, filter: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_a11y_manager_v1",
+                            id,
+                            "set_screen_filter",
+                            &[
+                                LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -923,10 +978,18 @@ impl ObjectPrivate for CosmicA11yManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter, arg2: CosmicA11yManagerV1ActiveState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_a11y_manager_v1#{}.set_screen_filter2(inverted: {:?}, filter: {:?}, filter_state: {:?})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_a11y_manager_v1",
+                            id,
+                            "set_screen_filter2",
+                            &[
+                                LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                                LogArg::new(Some("filter_state"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -964,10 +1027,16 @@ impl ObjectPrivate for CosmicA11yManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: CosmicA11yManagerV1ActiveState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> cosmic_a11y_manager_v1#{}.magnifier(active: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "cosmic_a11y_manager_v1",
+                            id,
+                            "magnifier",
+                            &[
+                                LogArg::new(Some("active"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -990,10 +1059,17 @@ impl ObjectPrivate for CosmicA11yManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> cosmic_a11y_manager_v1#{}.screen_filter(inverted: {:?}, filter: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "cosmic_a11y_manager_v1",
+                            id,
+                            "screen_filter",
+                            &[
+                                LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -1018,10 +1094,18 @@ impl ObjectPrivate for CosmicA11yManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: CosmicA11yManagerV1ActiveState, arg1: CosmicA11yManagerV1Filter, arg2: CosmicA11yManagerV1ActiveState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> cosmic_a11y_manager_v1#{}.screen_filter2(inverted: {:?}, filter: {:?}, filter_state: {:?})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "cosmic_a11y_manager_v1",
+                            id,
+                            "screen_filter2",
+                            &[
+                                LogArg::new(Some("inverted"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("filter"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                                LogArg::new(Some("filter_state"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -1062,6 +1146,26 @@ impl ObjectPrivate for CosmicA11yManagerV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "uu",
+            2 => "uuu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "uu",
+            2 => "uuu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -73,10 +73,15 @@ impl CosmicCornerRadiusLayerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_layer_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_layer_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -160,10 +165,19 @@ impl CosmicCornerRadiusLayerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_layer_v1#{}.set_radius(top_left: {}, top_right: {}, bottom_right: {}, bottom_left: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_layer_v1",
+                    id,
+                    "set_radius",
+                    &[
+                        LogArg::new(Some("top_left"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("top_right"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("bottom_right"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("bottom_left"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -243,10 +257,15 @@ impl CosmicCornerRadiusLayerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_layer_v1#{}.unset_radius()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_layer_v1",
+                    id,
+                    "unset_radius",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -331,10 +350,19 @@ impl CosmicCornerRadiusLayerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_layer_v1#{}.set_padding(top: {}, right: {}, bottom: {}, left: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_layer_v1",
+                    id,
+                    "set_padding",
+                    &[
+                        LogArg::new(Some("top"), LogValue::Int(arg0)),
+                        LogArg::new(Some("right"), LogValue::Int(arg1)),
+                        LogArg::new(Some("bottom"), LogValue::Int(arg2)),
+                        LogArg::new(Some("left"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -416,10 +444,15 @@ impl CosmicCornerRadiusLayerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_layer_v1#{}.unset_padding()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_layer_v1",
+                    id,
+                    "unset_padding",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -641,10 +674,15 @@ impl ObjectPrivate for CosmicCornerRadiusLayerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_layer_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_layer_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -668,10 +706,19 @@ impl ObjectPrivate for CosmicCornerRadiusLayerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_layer_v1#{}.set_radius(top_left: {}, top_right: {}, bottom_right: {}, bottom_left: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_layer_v1",
+                            id,
+                            "set_radius",
+                            &[
+                                LogArg::new(Some("top_left"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("top_right"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("bottom_right"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("bottom_left"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -689,10 +736,15 @@ impl ObjectPrivate for CosmicCornerRadiusLayerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_layer_v1#{}.unset_radius()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_layer_v1",
+                            id,
+                            "unset_radius",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -719,10 +771,19 @@ impl ObjectPrivate for CosmicCornerRadiusLayerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_layer_v1#{}.set_padding(top: {}, right: {}, bottom: {}, left: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_layer_v1",
+                            id,
+                            "set_padding",
+                            &[
+                                LogArg::new(Some("top"), LogValue::Int(arg0)),
+                                LogArg::new(Some("right"), LogValue::Int(arg1)),
+                                LogArg::new(Some("bottom"), LogValue::Int(arg2)),
+                                LogArg::new(Some("left"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -740,10 +801,15 @@ impl ObjectPrivate for CosmicCornerRadiusLayerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_layer_v1#{}.unset_padding()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_layer_v1",
+                            id,
+                            "unset_padding",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -797,6 +863,23 @@ impl ObjectPrivate for CosmicCornerRadiusLayerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "uuuu",
+            2 => "",
+            3 => "iiii",
+            4 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -68,10 +68,15 @@ impl CosmicCornerRadiusManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -157,10 +162,17 @@ impl CosmicCornerRadiusManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_manager_v1#{}.get_corner_radius(id: cosmic_corner_radius_toplevel_v1#{}, toplevel: xdg_toplevel#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_manager_v1",
+                    id,
+                    "get_corner_radius",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "cosmic_corner_radius_toplevel_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -309,10 +321,17 @@ impl CosmicCornerRadiusManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_manager_v1#{}.get_corner_radius_surface(id: cosmic_corner_radius_toplevel_v1#{}, surface: xdg_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_manager_v1",
+                    id,
+                    "get_corner_radius_surface",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "cosmic_corner_radius_toplevel_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("xdg_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -463,10 +482,17 @@ impl CosmicCornerRadiusManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_manager_v1#{}.get_corner_radius_layer(id: cosmic_corner_radius_layer_v1#{}, layer: zwlr_layer_surface_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_manager_v1",
+                    id,
+                    "get_corner_radius_layer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "cosmic_corner_radius_layer_v1", id: arg0, version: None }),
+                        LogArg::new(Some("layer"), LogValue::Object { interface: Some("zwlr_layer_surface_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -730,10 +756,15 @@ impl ObjectPrivate for CosmicCornerRadiusManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -755,10 +786,17 @@ impl ObjectPrivate for CosmicCornerRadiusManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_manager_v1#{}.get_corner_radius(id: cosmic_corner_radius_toplevel_v1#{}, toplevel: xdg_toplevel#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_manager_v1",
+                            id,
+                            "get_corner_radius",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "cosmic_corner_radius_toplevel_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -793,10 +831,17 @@ impl ObjectPrivate for CosmicCornerRadiusManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_manager_v1#{}.get_corner_radius_surface(id: cosmic_corner_radius_toplevel_v1#{}, surface: xdg_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_manager_v1",
+                            id,
+                            "get_corner_radius_surface",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "cosmic_corner_radius_toplevel_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("xdg_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -831,10 +876,17 @@ impl ObjectPrivate for CosmicCornerRadiusManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_manager_v1#{}.get_corner_radius_layer(id: cosmic_corner_radius_layer_v1#{}, layer: zwlr_layer_surface_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_manager_v1",
+                            id,
+                            "get_corner_radius_layer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "cosmic_corner_radius_layer_v1", id: arg0, version: None }),
+                                LogArg::new(Some("layer"), LogValue::Object { interface: Some("zwlr_layer_surface_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -901,6 +953,22 @@ impl ObjectPrivate for CosmicCornerRadiusManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[cosmic_corner_radius_toplevel_v1]o",
+            2 => "n[cosmic_corner_radius_toplevel_v1]o",
+            3 => "n[cosmic_corner_radius_layer_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -73,10 +73,15 @@ impl CosmicCornerRadiusToplevelV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_toplevel_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_toplevel_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -159,10 +164,19 @@ impl CosmicCornerRadiusToplevelV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_toplevel_v1#{}.set_radius(top_left: {}, top_right: {}, bottom_right: {}, bottom_left: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_toplevel_v1",
+                    id,
+                    "set_radius",
+                    &[
+                        LogArg::new(Some("top_left"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("top_right"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("bottom_right"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("bottom_left"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -241,10 +255,15 @@ impl CosmicCornerRadiusToplevelV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= cosmic_corner_radius_toplevel_v1#{}.unset_radius()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "cosmic_corner_radius_toplevel_v1",
+                    id,
+                    "unset_radius",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -403,10 +422,15 @@ impl ObjectPrivate for CosmicCornerRadiusToplevelV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_toplevel_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_toplevel_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -430,10 +454,19 @@ impl ObjectPrivate for CosmicCornerRadiusToplevelV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_toplevel_v1#{}.set_radius(top_left: {}, top_right: {}, bottom_right: {}, bottom_left: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_toplevel_v1",
+                            id,
+                            "set_radius",
+                            &[
+                                LogArg::new(Some("top_left"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("top_right"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("bottom_right"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("bottom_left"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -451,10 +484,15 @@ impl ObjectPrivate for CosmicCornerRadiusToplevelV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> cosmic_corner_radius_toplevel_v1#{}.unset_radius()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "cosmic_corner_radius_toplevel_v1",
+                            id,
+                            "unset_radius",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -506,6 +544,21 @@ impl ObjectPrivate for CosmicCornerRadiusToplevelV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "uuuu",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
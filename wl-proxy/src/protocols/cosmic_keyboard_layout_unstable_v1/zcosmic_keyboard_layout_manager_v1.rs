@@ -91,10 +91,17 @@ impl ZcosmicKeyboardLayoutManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_keyboard_layout_manager_v1#{}.get_keyboard_layout(keyboard_layout: zcosmic_keyboard_layout_v1#{}, keyboard: wl_keyboard#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_keyboard_layout_manager_v1",
+                    id,
+                    "get_keyboard_layout",
+                    &[
+                        LogArg::new(Some("keyboard_layout"), LogValue::NewId { interface: "zcosmic_keyboard_layout_v1", id: arg0, version: None }),
+                        LogArg::new(Some("keyboard"), LogValue::Object { interface: Some("wl_keyboard"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -195,10 +202,15 @@ impl ZcosmicKeyboardLayoutManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_keyboard_layout_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_keyboard_layout_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -326,10 +338,17 @@ impl ObjectPrivate for ZcosmicKeyboardLayoutManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_keyboard_layout_manager_v1#{}.get_keyboard_layout(keyboard_layout: zcosmic_keyboard_layout_v1#{}, keyboard: wl_keyboard#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_keyboard_layout_manager_v1",
+                            id,
+                            "get_keyboard_layout",
+                            &[
+                                LogArg::new(Some("keyboard_layout"), LogValue::NewId { interface: "zcosmic_keyboard_layout_v1", id: arg0, version: None }),
+                                LogArg::new(Some("keyboard"), LogValue::Object { interface: Some("wl_keyboard"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -361,10 +380,15 @@ impl ObjectPrivate for ZcosmicKeyboardLayoutManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_keyboard_layout_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_keyboard_layout_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -416,6 +440,20 @@ impl ObjectPrivate for ZcosmicKeyboardLayoutManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zcosmic_keyboard_layout_v1]o",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
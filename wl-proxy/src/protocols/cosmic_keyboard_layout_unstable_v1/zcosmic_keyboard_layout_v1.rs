@@ -78,10 +78,16 @@ impl ZcosmicKeyboardLayoutV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_keyboard_layout_v1#{}.group(group: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_keyboard_layout_v1",
+                    id,
+                    "group",
+                    &[
+                        LogArg::new(Some("group"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -151,10 +157,16 @@ impl ZcosmicKeyboardLayoutV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_keyboard_layout_v1#{}.set_group(group: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_keyboard_layout_v1",
+                    id,
+                    "set_group",
+                    &[
+                        LogArg::new(Some("group"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -214,10 +226,15 @@ impl ZcosmicKeyboardLayoutV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_keyboard_layout_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_keyboard_layout_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -365,10 +382,16 @@ impl ObjectPrivate for ZcosmicKeyboardLayoutV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_keyboard_layout_v1#{}.set_group(group: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_keyboard_layout_v1",
+                            id,
+                            "set_group",
+                            &[
+                                LogArg::new(Some("group"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -386,10 +409,15 @@ impl ObjectPrivate for ZcosmicKeyboardLayoutV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_keyboard_layout_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_keyboard_layout_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -427,10 +455,16 @@ impl ObjectPrivate for ZcosmicKeyboardLayoutV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_keyboard_layout_v1#{}.group(group: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_keyboard_layout_v1",
+                            id,
+                            "group",
+                            &[
+                                LogArg::new(Some("group"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -468,6 +502,23 @@ impl ObjectPrivate for ZcosmicKeyboardLayoutV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
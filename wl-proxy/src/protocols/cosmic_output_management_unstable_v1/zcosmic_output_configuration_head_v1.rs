@@ -88,10 +88,16 @@ impl ZcosmicOutputConfigurationHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_configuration_head_v1#{}.set_scale_1000(scale_1000: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_configuration_head_v1",
+                    id,
+                    "set_scale_1000",
+                    &[
+                        LogArg::new(Some("scale_1000"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -159,10 +165,15 @@ impl ZcosmicOutputConfigurationHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_configuration_head_v1#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_configuration_head_v1",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -234,10 +245,16 @@ impl ZcosmicOutputConfigurationHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: ZcosmicOutputHeadV1AdaptiveSyncStateExt) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_configuration_head_v1#{}.set_adaptive_sync_ext(state: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_configuration_head_v1",
+                    id,
+                    "set_adaptive_sync_ext",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -414,10 +431,16 @@ impl ObjectPrivate for ZcosmicOutputConfigurationHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_configuration_head_v1#{}.set_scale_1000(scale_1000: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_configuration_head_v1",
+                            id,
+                            "set_scale_1000",
+                            &[
+                                LogArg::new(Some("scale_1000"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -435,10 +458,15 @@ impl ObjectPrivate for ZcosmicOutputConfigurationHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_configuration_head_v1#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_configuration_head_v1",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -460,10 +488,16 @@ impl ObjectPrivate for ZcosmicOutputConfigurationHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: ZcosmicOutputHeadV1AdaptiveSyncStateExt) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_configuration_head_v1#{}.set_adaptive_sync_ext(state: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_configuration_head_v1",
+                            id,
+                            "set_adaptive_sync_ext",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -515,6 +549,21 @@ impl ObjectPrivate for ZcosmicOutputConfigurationHeadV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "i",
+            1 => "",
+            2 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
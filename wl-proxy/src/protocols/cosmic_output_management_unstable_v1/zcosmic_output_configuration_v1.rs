@@ -114,10 +114,18 @@ impl ZcosmicOutputConfigurationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_configuration_v1#{}.mirror_head(id: zwlr_output_configuration_head_v1#{}, head: zwlr_output_head_v1#{}, mirroring: zwlr_output_head_v1#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_configuration_v1",
+                    id,
+                    "mirror_head",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_output_configuration_head_v1", id: arg0, version: None }),
+                        LogArg::new(Some("head"), LogValue::Object { interface: Some("zwlr_output_head_v1"), id: arg1 }),
+                        LogArg::new(Some("mirroring"), LogValue::Object { interface: Some("zwlr_output_head_v1"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2_id);
         }
@@ -274,10 +282,15 @@ impl ZcosmicOutputConfigurationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_output_configuration_v1#{}.finished()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_output_configuration_v1",
+                    id,
+                    "finished",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -336,10 +349,15 @@ impl ZcosmicOutputConfigurationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_configuration_v1#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_configuration_v1",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -517,10 +535,18 @@ impl ObjectPrivate for ZcosmicOutputConfigurationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_configuration_v1#{}.mirror_head(id: zwlr_output_configuration_head_v1#{}, head: zwlr_output_head_v1#{}, mirroring: zwlr_output_head_v1#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_configuration_v1",
+                            id,
+                            "mirror_head",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_output_configuration_head_v1", id: arg0, version: None }),
+                                LogArg::new(Some("head"), LogValue::Object { interface: Some("zwlr_output_head_v1"), id: arg1 }),
+                                LogArg::new(Some("mirroring"), LogValue::Object { interface: Some("zwlr_output_head_v1"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -561,10 +587,15 @@ impl ObjectPrivate for ZcosmicOutputConfigurationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_configuration_v1#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_configuration_v1",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -600,10 +631,15 @@ impl ObjectPrivate for ZcosmicOutputConfigurationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_output_configuration_v1#{}.finished()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_output_configuration_v1",
+                            id,
+                            "finished",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -641,6 +677,23 @@ impl ObjectPrivate for ZcosmicOutputConfigurationV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwlr_output_configuration_head_v1]oo",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
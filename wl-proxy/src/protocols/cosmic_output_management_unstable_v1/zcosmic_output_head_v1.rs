@@ -87,10 +87,16 @@ impl ZcosmicOutputHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_output_head_v1#{}.scale_1000(scale_1000: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_output_head_v1",
+                    id,
+                    "scale_1000",
+                    &[
+                        LogArg::new(Some("scale_1000"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -168,10 +174,16 @@ impl ZcosmicOutputHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: Option<&str>) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_output_head_v1#{}.mirroring(name: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_output_head_v1",
+                    id,
+                    "mirroring",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -239,10 +251,15 @@ impl ZcosmicOutputHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_head_v1#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_head_v1",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -310,10 +327,16 @@ impl ZcosmicOutputHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZcosmicOutputHeadV1AdaptiveSyncAvailability) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_output_head_v1#{}.adaptive_sync_available(available: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_output_head_v1",
+                    id,
+                    "adaptive_sync_available",
+                    &[
+                        LogArg::new(Some("available"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -386,10 +409,16 @@ impl ZcosmicOutputHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZcosmicOutputHeadV1AdaptiveSyncStateExt) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_output_head_v1#{}.adaptive_sync_ext(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_output_head_v1",
+                    id,
+                    "adaptive_sync_ext",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -463,10 +492,16 @@ impl ZcosmicOutputHeadV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_output_head_v1#{}.xwayland_primary(state: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_output_head_v1",
+                    id,
+                    "xwayland_primary",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -709,10 +744,15 @@ impl ObjectPrivate for ZcosmicOutputHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_head_v1#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_head_v1",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -751,10 +791,16 @@ impl ObjectPrivate for ZcosmicOutputHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_output_head_v1#{}.scale_1000(scale_1000: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_output_head_v1",
+                            id,
+                            "scale_1000",
+                            &[
+                                LogArg::new(Some("scale_1000"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -775,10 +821,16 @@ impl ObjectPrivate for ZcosmicOutputHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: Option<&str>) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_output_head_v1#{}.mirroring(name: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_output_head_v1",
+                            id,
+                            "mirroring",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -799,10 +851,16 @@ impl ObjectPrivate for ZcosmicOutputHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZcosmicOutputHeadV1AdaptiveSyncAvailability) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_output_head_v1#{}.adaptive_sync_available(available: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_output_head_v1",
+                            id,
+                            "adaptive_sync_available",
+                            &[
+                                LogArg::new(Some("available"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -823,10 +881,16 @@ impl ObjectPrivate for ZcosmicOutputHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZcosmicOutputHeadV1AdaptiveSyncStateExt) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_output_head_v1#{}.adaptive_sync_ext(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_output_head_v1",
+                            id,
+                            "adaptive_sync_ext",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -846,10 +910,16 @@ impl ObjectPrivate for ZcosmicOutputHeadV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_output_head_v1#{}.xwayland_primary(state: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_output_head_v1",
+                            id,
+                            "xwayland_primary",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -890,6 +960,26 @@ impl ObjectPrivate for ZcosmicOutputHeadV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "i",
+            1 => "?s",
+            2 => "u",
+            3 => "u",
+            4 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -101,10 +101,17 @@ impl ZcosmicOutputManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_manager_v1#{}.get_head(extended: zcosmic_output_head_v1#{}, head: zwlr_output_head_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_manager_v1",
+                    id,
+                    "get_head",
+                    &[
+                        LogArg::new(Some("extended"), LogValue::NewId { interface: "zcosmic_output_head_v1", id: arg0, version: None }),
+                        LogArg::new(Some("head"), LogValue::Object { interface: Some("zwlr_output_head_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -264,10 +271,17 @@ impl ZcosmicOutputManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_manager_v1#{}.get_configuration(extended: zcosmic_output_configuration_v1#{}, config: zwlr_output_configuration_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_manager_v1",
+                    id,
+                    "get_configuration",
+                    &[
+                        LogArg::new(Some("extended"), LogValue::NewId { interface: "zcosmic_output_configuration_v1", id: arg0, version: None }),
+                        LogArg::new(Some("config"), LogValue::Object { interface: Some("zwlr_output_configuration_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -406,10 +420,17 @@ impl ZcosmicOutputManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_manager_v1#{}.get_configuration_head(extended: zcosmic_output_configuration_head_v1#{}, config_head: zwlr_output_configuration_head_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_manager_v1",
+                    id,
+                    "get_configuration_head",
+                    &[
+                        LogArg::new(Some("extended"), LogValue::NewId { interface: "zcosmic_output_configuration_head_v1", id: arg0, version: None }),
+                        LogArg::new(Some("config_head"), LogValue::Object { interface: Some("zwlr_output_configuration_head_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -521,10 +542,15 @@ impl ZcosmicOutputManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_manager_v1#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_manager_v1",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -598,10 +624,16 @@ impl ZcosmicOutputManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_output_manager_v1#{}.set_xwayland_primary(head: zcosmic_output_head_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_output_manager_v1",
+                    id,
+                    "set_xwayland_primary",
+                    &[
+                        LogArg::new(Some("head"), LogValue::Object { interface: Some("zcosmic_output_head_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -848,10 +880,17 @@ impl ObjectPrivate for ZcosmicOutputManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_manager_v1#{}.get_head(extended: zcosmic_output_head_v1#{}, head: zwlr_output_head_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_manager_v1",
+                            id,
+                            "get_head",
+                            &[
+                                LogArg::new(Some("extended"), LogValue::NewId { interface: "zcosmic_output_head_v1", id: arg0, version: None }),
+                                LogArg::new(Some("head"), LogValue::Object { interface: Some("zwlr_output_head_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -886,10 +925,17 @@ impl ObjectPrivate for ZcosmicOutputManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_manager_v1#{}.get_configuration(extended: zcosmic_output_configuration_v1#{}, config: zwlr_output_configuration_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_manager_v1",
+                            id,
+                            "get_configuration",
+                            &[
+                                LogArg::new(Some("extended"), LogValue::NewId { interface: "zcosmic_output_configuration_v1", id: arg0, version: None }),
+                                LogArg::new(Some("config"), LogValue::Object { interface: Some("zwlr_output_configuration_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -924,10 +970,17 @@ impl ObjectPrivate for ZcosmicOutputManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_manager_v1#{}.get_configuration_head(extended: zcosmic_output_configuration_head_v1#{}, config_head: zwlr_output_configuration_head_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_manager_v1",
+                            id,
+                            "get_configuration_head",
+                            &[
+                                LogArg::new(Some("extended"), LogValue::NewId { interface: "zcosmic_output_configuration_head_v1", id: arg0, version: None }),
+                                LogArg::new(Some("config_head"), LogValue::Object { interface: Some("zwlr_output_configuration_head_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -959,10 +1012,15 @@ impl ObjectPrivate for ZcosmicOutputManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_manager_v1#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_manager_v1",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -983,10 +1041,16 @@ impl ObjectPrivate for ZcosmicOutputManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_output_manager_v1#{}.set_xwayland_primary(head: zcosmic_output_head_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_output_manager_v1",
+                            id,
+                            "set_xwayland_primary",
+                            &[
+                                LogArg::new(Some("head"), LogValue::Object { interface: Some("zcosmic_output_head_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1054,6 +1118,23 @@ impl ObjectPrivate for ZcosmicOutputManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zcosmic_output_head_v1]o",
+            1 => "n[zcosmic_output_configuration_v1]o",
+            2 => "n[zcosmic_output_configuration_head_v1]o",
+            3 => "",
+            4 => "?o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -105,10 +105,20 @@ impl ZcosmicOverlapNotificationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: i32, arg3: i32, arg4: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_overlap_notification_v1#{}.toplevel_enter(toplevel: ext_foreign_toplevel_handle_v1#{}, x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_overlap_notification_v1",
+                    id,
+                    "toplevel_enter",
+                    &[
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg0 }),
+                        LogArg::new(Some("x"), LogValue::Int(arg1)),
+                        LogArg::new(Some("y"), LogValue::Int(arg2)),
+                        LogArg::new(Some("width"), LogValue::Int(arg3)),
+                        LogArg::new(Some("height"), LogValue::Int(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id, arg1, arg2, arg3, arg4);
         }
@@ -208,10 +218,16 @@ impl ZcosmicOverlapNotificationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_overlap_notification_v1#{}.toplevel_leave(toplevel: ext_foreign_toplevel_handle_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_overlap_notification_v1",
+                    id,
+                    "toplevel_leave",
+                    &[
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -318,10 +334,23 @@ impl ZcosmicOverlapNotificationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: &str, arg2: u32, arg3: ZwlrLayerShellV1Layer, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_overlap_notification_v1#{}.layer_enter(identifier: {:?}, namespace: {:?}, exclusive: {}, layer: {:?}, x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_overlap_notification_v1",
+                    id,
+                    "layer_enter",
+                    &[
+                        LogArg::new(Some("identifier"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("namespace"), LogValue::String(Some(arg1))),
+                        LogArg::new(Some("exclusive"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("layer"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                        LogArg::new(Some("x"), LogValue::Int(arg4)),
+                        LogArg::new(Some("y"), LogValue::Int(arg5)),
+                        LogArg::new(Some("width"), LogValue::Int(arg6)),
+                        LogArg::new(Some("height"), LogValue::Int(arg7)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
         }
@@ -427,10 +456,16 @@ impl ZcosmicOverlapNotificationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_overlap_notification_v1#{}.layer_leave(identifier: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_overlap_notification_v1",
+                    id,
+                    "layer_leave",
+                    &[
+                        LogArg::new(Some("identifier"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -488,10 +523,15 @@ impl ZcosmicOverlapNotificationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_overlap_notification_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_overlap_notification_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -758,10 +798,15 @@ impl ObjectPrivate for ZcosmicOverlapNotificationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_overlap_notification_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_overlap_notification_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -807,10 +852,20 @@ impl ObjectPrivate for ZcosmicOverlapNotificationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: i32, arg3: i32, arg4: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_overlap_notification_v1#{}.toplevel_enter(toplevel: ext_foreign_toplevel_handle_v1#{}, x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_overlap_notification_v1",
+                            id,
+                            "toplevel_enter",
+                            &[
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg0 }),
+                                LogArg::new(Some("x"), LogValue::Int(arg1)),
+                                LogArg::new(Some("y"), LogValue::Int(arg2)),
+                                LogArg::new(Some("width"), LogValue::Int(arg3)),
+                                LogArg::new(Some("height"), LogValue::Int(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -839,10 +894,16 @@ impl ObjectPrivate for ZcosmicOverlapNotificationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_overlap_notification_v1#{}.toplevel_leave(toplevel: ext_foreign_toplevel_handle_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_overlap_notification_v1",
+                            id,
+                            "toplevel_leave",
+                            &[
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -903,10 +964,23 @@ impl ObjectPrivate for ZcosmicOverlapNotificationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str, arg1: &str, arg2: u32, arg3: ZwlrLayerShellV1Layer, arg4: i32, arg5: i32, arg6: i32, arg7: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_overlap_notification_v1#{}.layer_enter(identifier: {:?}, namespace: {:?}, exclusive: {}, layer: {:?}, x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_overlap_notification_v1",
+                            id,
+                            "layer_enter",
+                            &[
+                                LogArg::new(Some("identifier"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("namespace"), LogValue::String(Some(arg1))),
+                                LogArg::new(Some("exclusive"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("layer"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                                LogArg::new(Some("x"), LogValue::Int(arg4)),
+                                LogArg::new(Some("y"), LogValue::Int(arg5)),
+                                LogArg::new(Some("width"), LogValue::Int(arg6)),
+                                LogArg::new(Some("height"), LogValue::Int(arg7)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
                 }
@@ -927,10 +1001,16 @@ impl ObjectPrivate for ZcosmicOverlapNotificationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_overlap_notification_v1#{}.layer_leave(identifier: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_overlap_notification_v1",
+                            id,
+                            "layer_leave",
+                            &[
+                                LogArg::new(Some("identifier"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -970,6 +1050,25 @@ impl ObjectPrivate for ZcosmicOverlapNotificationV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "oiiii",
+            1 => "o",
+            2 => "ssuuiiii",
+            3 => "s",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -101,10 +101,17 @@ impl ZcosmicOverlapNotifyV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_overlap_notify_v1#{}.notify_on_overlap(overlap_notification: zcosmic_overlap_notification_v1#{}, layer_surface: zwlr_layer_surface_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_overlap_notify_v1",
+                    id,
+                    "notify_on_overlap",
+                    &[
+                        LogArg::new(Some("overlap_notification"), LogValue::NewId { interface: "zcosmic_overlap_notification_v1", id: arg0, version: None }),
+                        LogArg::new(Some("layer_surface"), LogValue::Object { interface: Some("zwlr_layer_surface_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -288,10 +295,17 @@ impl ObjectPrivate for ZcosmicOverlapNotifyV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_overlap_notify_v1#{}.notify_on_overlap(overlap_notification: zcosmic_overlap_notification_v1#{}, layer_surface: zwlr_layer_surface_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_overlap_notify_v1",
+                            id,
+                            "notify_on_overlap",
+                            &[
+                                LogArg::new(Some("overlap_notification"), LogValue::NewId { interface: "zcosmic_overlap_notification_v1", id: arg0, version: None }),
+                                LogArg::new(Some("layer_surface"), LogValue::Object { interface: Some("zwlr_layer_surface_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -355,6 +369,19 @@ impl ObjectPrivate for ZcosmicOverlapNotifyV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zcosmic_overlap_notification_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -80,10 +80,15 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_handle_v2#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -161,10 +166,16 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZcosmicWorkspaceHandleV2WorkspaceCapabilities) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_workspace_handle_v2#{}.capabilities(capabilities: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "capabilities",
+                    &[
+                        LogArg::new(Some("capabilities"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -247,10 +258,16 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZcosmicWorkspaceHandleV2TilingState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_workspace_handle_v2#{}.tiling_state(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "tiling_state",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -323,10 +340,16 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZcosmicWorkspaceHandleV2State) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zcosmic_workspace_handle_v2#{}.state(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "state",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -397,10 +420,16 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_handle_v2#{}.rename(name: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "rename",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -473,10 +502,16 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: ZcosmicWorkspaceHandleV2TilingState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_handle_v2#{}.set_tiling_state(state: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "set_tiling_state",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -570,10 +605,17 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_handle_v2#{}.move_before(other_workspace: ext_workspace_handle_v1#{}, axis: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "move_before",
+                    &[
+                        LogArg::new(Some("other_workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                        LogArg::new(Some("axis"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -671,10 +713,17 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_handle_v2#{}.move_after(other_workspace: ext_workspace_handle_v1#{}, axis: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "move_after",
+                    &[
+                        LogArg::new(Some("other_workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                        LogArg::new(Some("axis"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -741,10 +790,15 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_handle_v2#{}.pin()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "pin",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -800,10 +854,15 @@ impl ZcosmicWorkspaceHandleV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_handle_v2#{}.unpin()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_handle_v2",
+                    id,
+                    "unpin",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -1160,10 +1219,15 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_handle_v2#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1185,10 +1249,16 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_handle_v2#{}.rename(name: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "rename",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1209,10 +1279,16 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: ZcosmicWorkspaceHandleV2TilingState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_handle_v2#{}.set_tiling_state(state: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "set_tiling_state",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1233,10 +1309,17 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_handle_v2#{}.move_before(other_workspace: ext_workspace_handle_v1#{}, axis: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "move_before",
+                            &[
+                                LogArg::new(Some("other_workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                                LogArg::new(Some("axis"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -1266,10 +1349,17 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_handle_v2#{}.move_after(other_workspace: ext_workspace_handle_v1#{}, axis: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "move_after",
+                            &[
+                                LogArg::new(Some("other_workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                                LogArg::new(Some("axis"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -1296,10 +1386,15 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_handle_v2#{}.pin()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "pin",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1317,10 +1412,15 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_handle_v2#{}.unpin()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "unpin",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1358,10 +1458,16 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZcosmicWorkspaceHandleV2WorkspaceCapabilities) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_workspace_handle_v2#{}.capabilities(capabilities: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "capabilities",
+                            &[
+                                LogArg::new(Some("capabilities"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1382,10 +1488,16 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZcosmicWorkspaceHandleV2TilingState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_workspace_handle_v2#{}.tiling_state(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "tiling_state",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1406,10 +1518,16 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZcosmicWorkspaceHandleV2State) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zcosmic_workspace_handle_v2#{}.state(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zcosmic_workspace_handle_v2",
+                            id,
+                            "state",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1454,6 +1572,30 @@ impl ObjectPrivate for ZcosmicWorkspaceHandleV2 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "s",
+            2 => "u",
+            3 => "ou",
+            4 => "ou",
+            5 => "",
+            6 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "u",
+            2 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
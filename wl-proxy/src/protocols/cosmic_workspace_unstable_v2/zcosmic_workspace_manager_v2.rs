@@ -98,10 +98,17 @@ impl ZcosmicWorkspaceManagerV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_manager_v2#{}.get_cosmic_workspace(cosmic_workspace: zcosmic_workspace_handle_v2#{}, workspace: ext_workspace_handle_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_manager_v2",
+                    id,
+                    "get_cosmic_workspace",
+                    &[
+                        LogArg::new(Some("cosmic_workspace"), LogValue::NewId { interface: "zcosmic_workspace_handle_v2", id: arg0, version: None }),
+                        LogArg::new(Some("workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -217,10 +224,15 @@ impl ZcosmicWorkspaceManagerV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zcosmic_workspace_manager_v2#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zcosmic_workspace_manager_v2",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -358,10 +370,17 @@ impl ObjectPrivate for ZcosmicWorkspaceManagerV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_manager_v2#{}.get_cosmic_workspace(cosmic_workspace: zcosmic_workspace_handle_v2#{}, workspace: ext_workspace_handle_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_manager_v2",
+                            id,
+                            "get_cosmic_workspace",
+                            &[
+                                LogArg::new(Some("cosmic_workspace"), LogValue::NewId { interface: "zcosmic_workspace_handle_v2", id: arg0, version: None }),
+                                LogArg::new(Some("workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -393,10 +412,15 @@ impl ObjectPrivate for ZcosmicWorkspaceManagerV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zcosmic_workspace_manager_v2#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zcosmic_workspace_manager_v2",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -448,6 +472,20 @@ impl ObjectPrivate for ZcosmicWorkspaceManagerV2 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zcosmic_workspace_handle_v2]o",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
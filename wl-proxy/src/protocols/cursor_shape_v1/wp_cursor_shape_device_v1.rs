@@ -69,10 +69,15 @@ impl WpCursorShapeDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_cursor_shape_device_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_cursor_shape_device_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -158,10 +163,17 @@ impl WpCursorShapeDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: WpCursorShapeDeviceV1Shape) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_cursor_shape_device_v1#{}.set_shape(serial: {}, shape: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_cursor_shape_device_v1",
+                    id,
+                    "set_shape",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("shape"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -333,10 +345,15 @@ impl ObjectPrivate for WpCursorShapeDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_cursor_shape_device_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_cursor_shape_device_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -359,10 +376,17 @@ impl ObjectPrivate for WpCursorShapeDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: WpCursorShapeDeviceV1Shape) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_cursor_shape_device_v1#{}.set_shape(serial: {}, shape: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_cursor_shape_device_v1",
+                            id,
+                            "set_shape",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("shape"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -413,6 +437,20 @@ impl ObjectPrivate for WpCursorShapeDeviceV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "uu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
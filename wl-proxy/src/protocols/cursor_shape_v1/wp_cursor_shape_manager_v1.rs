@@ -74,10 +74,15 @@ impl WpCursorShapeManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_cursor_shape_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_cursor_shape_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -157,10 +162,17 @@ impl WpCursorShapeManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_cursor_shape_manager_v1#{}.get_pointer(cursor_shape_device: wp_cursor_shape_device_v1#{}, pointer: wl_pointer#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_cursor_shape_manager_v1",
+                    id,
+                    "get_pointer",
+                    &[
+                        LogArg::new(Some("cursor_shape_device"), LogValue::NewId { interface: "wp_cursor_shape_device_v1", id: arg0, version: None }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -299,10 +311,17 @@ impl WpCursorShapeManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_cursor_shape_manager_v1#{}.get_tablet_tool_v2(cursor_shape_device: wp_cursor_shape_device_v1#{}, tablet_tool: zwp_tablet_tool_v2#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_cursor_shape_manager_v1",
+                    id,
+                    "get_tablet_tool_v2",
+                    &[
+                        LogArg::new(Some("cursor_shape_device"), LogValue::NewId { interface: "wp_cursor_shape_device_v1", id: arg0, version: None }),
+                        LogArg::new(Some("tablet_tool"), LogValue::Object { interface: Some("zwp_tablet_tool_v2"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -526,10 +545,15 @@ impl ObjectPrivate for WpCursorShapeManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_cursor_shape_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_cursor_shape_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -551,10 +575,17 @@ impl ObjectPrivate for WpCursorShapeManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_cursor_shape_manager_v1#{}.get_pointer(cursor_shape_device: wp_cursor_shape_device_v1#{}, pointer: wl_pointer#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_cursor_shape_manager_v1",
+                            id,
+                            "get_pointer",
+                            &[
+                                LogArg::new(Some("cursor_shape_device"), LogValue::NewId { interface: "wp_cursor_shape_device_v1", id: arg0, version: None }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -589,10 +620,17 @@ impl ObjectPrivate for WpCursorShapeManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_cursor_shape_manager_v1#{}.get_tablet_tool_v2(cursor_shape_device: wp_cursor_shape_device_v1#{}, tablet_tool: zwp_tablet_tool_v2#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_cursor_shape_manager_v1",
+                            id,
+                            "get_tablet_tool_v2",
+                            &[
+                                LogArg::new(Some("cursor_shape_device"), LogValue::NewId { interface: "wp_cursor_shape_device_v1", id: arg0, version: None }),
+                                LogArg::new(Some("tablet_tool"), LogValue::Object { interface: Some("zwp_tablet_tool_v2"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -658,6 +696,21 @@ impl ObjectPrivate for WpCursorShapeManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_cursor_shape_device_v1]o",
+            2 => "n[wp_cursor_shape_device_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -69,10 +69,16 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wl_drm#{}.authenticate(id: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "authenticate",
+                    &[
+                        LogArg::new(Some("id"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -158,10 +164,21 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: i32, arg3: i32, arg4: u32, arg5: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wl_drm#{}.create_buffer(id: wl_buffer#{}, name: {}, width: {}, height: {}, stride: {}, format: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "create_buffer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                        LogArg::new(Some("name"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                        LogArg::new(Some("stride"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("format"), LogValue::Uint(arg5)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2, arg3, arg4, arg5);
         }
@@ -343,10 +360,26 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: i32, arg3: i32, arg4: u32, arg5: i32, arg6: i32, arg7: i32, arg8: i32, arg9: i32, arg10: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wl_drm#{}.create_planar_buffer(id: wl_buffer#{}, name: {}, width: {}, height: {}, format: {}, offset0: {}, stride0: {}, offset1: {}, stride1: {}, offset2: {}, stride2: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "create_planar_buffer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                        LogArg::new(Some("name"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                        LogArg::new(Some("format"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("offset0"), LogValue::Int(arg5)),
+                        LogArg::new(Some("stride0"), LogValue::Int(arg6)),
+                        LogArg::new(Some("offset1"), LogValue::Int(arg7)),
+                        LogArg::new(Some("stride1"), LogValue::Int(arg8)),
+                        LogArg::new(Some("offset2"), LogValue::Int(arg9)),
+                        LogArg::new(Some("stride2"), LogValue::Int(arg10)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
         }
@@ -535,10 +568,16 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wl_drm#{}.device(name: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "device",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -599,10 +638,16 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wl_drm#{}.format(format: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "format",
+                    &[
+                        LogArg::new(Some("format"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -654,10 +699,15 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wl_drm#{}.authenticated()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "authenticated",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -712,10 +762,16 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wl_drm#{}.capabilities(value: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "capabilities",
+                    &[
+                        LogArg::new(Some("value"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -819,10 +875,26 @@ impl WlDrm {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: i32, arg3: i32, arg4: u32, arg5: i32, arg6: i32, arg7: i32, arg8: i32, arg9: i32, arg10: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wl_drm#{}.create_prime_buffer(id: wl_buffer#{}, name: {}, width: {}, height: {}, format: {}, offset0: {}, stride0: {}, offset1: {}, stride1: {}, offset2: {}, stride2: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wl_drm",
+                    id,
+                    "create_prime_buffer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                        LogArg::new(Some("name"), LogValue::Fd(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                        LogArg::new(Some("format"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("offset0"), LogValue::Int(arg5)),
+                        LogArg::new(Some("stride0"), LogValue::Int(arg6)),
+                        LogArg::new(Some("offset1"), LogValue::Int(arg7)),
+                        LogArg::new(Some("stride1"), LogValue::Int(arg8)),
+                        LogArg::new(Some("offset2"), LogValue::Int(arg9)),
+                        LogArg::new(Some("stride2"), LogValue::Int(arg10)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1.as_raw_fd(), arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
         }
@@ -1263,10 +1335,16 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wl_drm#{}.authenticate(id: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "authenticate",
+                            &[
+                                LogArg::new(Some("id"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1293,10 +1371,21 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: i32, arg3: i32, arg4: u32, arg5: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wl_drm#{}.create_buffer(id: wl_buffer#{}, name: {}, width: {}, height: {}, stride: {}, format: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "create_buffer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                                LogArg::new(Some("name"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                                LogArg::new(Some("stride"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("format"), LogValue::Uint(arg5)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5);
                 }
@@ -1339,10 +1428,26 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: i32, arg3: i32, arg4: u32, arg5: i32, arg6: i32, arg7: i32, arg8: i32, arg9: i32, arg10: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wl_drm#{}.create_planar_buffer(id: wl_buffer#{}, name: {}, width: {}, height: {}, format: {}, offset0: {}, stride0: {}, offset1: {}, stride1: {}, offset2: {}, stride2: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "create_planar_buffer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                                LogArg::new(Some("name"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                                LogArg::new(Some("format"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("offset0"), LogValue::Int(arg5)),
+                                LogArg::new(Some("stride0"), LogValue::Int(arg6)),
+                                LogArg::new(Some("offset1"), LogValue::Int(arg7)),
+                                LogArg::new(Some("stride1"), LogValue::Int(arg8)),
+                                LogArg::new(Some("offset2"), LogValue::Int(arg9)),
+                                LogArg::new(Some("stride2"), LogValue::Int(arg10)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
                 }
@@ -1388,10 +1493,26 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: i32, arg3: i32, arg4: u32, arg5: i32, arg6: i32, arg7: i32, arg8: i32, arg9: i32, arg10: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wl_drm#{}.create_prime_buffer(id: wl_buffer#{}, name: {}, width: {}, height: {}, format: {}, offset0: {}, stride0: {}, offset1: {}, stride1: {}, offset2: {}, stride2: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "create_prime_buffer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                                LogArg::new(Some("name"), LogValue::Fd(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                                LogArg::new(Some("format"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("offset0"), LogValue::Int(arg5)),
+                                LogArg::new(Some("stride0"), LogValue::Int(arg6)),
+                                LogArg::new(Some("offset1"), LogValue::Int(arg7)),
+                                LogArg::new(Some("stride1"), LogValue::Int(arg8)),
+                                LogArg::new(Some("offset2"), LogValue::Int(arg9)),
+                                LogArg::new(Some("stride2"), LogValue::Int(arg10)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1.as_raw_fd(), arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10);
                 }
@@ -1434,10 +1555,16 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wl_drm#{}.device(name: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "device",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1457,10 +1584,16 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wl_drm#{}.format(format: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "format",
+                            &[
+                                LogArg::new(Some("format"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1478,10 +1611,15 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wl_drm#{}.authenticated()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "authenticated",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1501,10 +1639,16 @@ impl ObjectPrivate for WlDrm {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wl_drm#{}.capabilities(value: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wl_drm",
+                            id,
+                            "capabilities",
+                            &[
+                                LogArg::new(Some("value"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1547,6 +1691,28 @@ impl ObjectPrivate for WlDrm {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "n[wl_buffer]uiiuu",
+            2 => "n[wl_buffer]uiiuiiiiii",
+            3 => "n[wl_buffer]hiiuiiiiii",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "s",
+            1 => "u",
+            2 => "",
+            3 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -113,10 +113,16 @@ impl WpDrmLeaseDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_drm_lease_device_v1#{}.create_lease_request(id: wp_drm_lease_request_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_drm_lease_device_v1",
+                    id,
+                    "create_lease_request",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_drm_lease_request_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -214,10 +220,15 @@ impl WpDrmLeaseDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_drm_lease_device_v1#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_drm_lease_device_v1",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -293,10 +304,16 @@ impl WpDrmLeaseDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_drm_lease_device_v1#{}.drm_fd(fd: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_drm_lease_device_v1",
+                    id,
+                    "drm_fd",
+                    &[
+                        LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0.as_raw_fd());
         }
@@ -386,10 +403,16 @@ impl WpDrmLeaseDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_drm_lease_device_v1#{}.connector(id: wp_drm_lease_connector_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_drm_lease_device_v1",
+                    id,
+                    "connector",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_drm_lease_connector_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -508,10 +531,15 @@ impl WpDrmLeaseDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_drm_lease_device_v1#{}.done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_drm_lease_device_v1",
+                    id,
+                    "done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -572,10 +600,15 @@ impl WpDrmLeaseDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_drm_lease_device_v1#{}.released()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_drm_lease_device_v1",
+                    id,
+                    "released",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -821,10 +854,16 @@ impl ObjectPrivate for WpDrmLeaseDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_drm_lease_device_v1#{}.create_lease_request(id: wp_drm_lease_request_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_drm_lease_device_v1",
+                            id,
+                            "create_lease_request",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_drm_lease_request_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -847,10 +886,15 @@ impl ObjectPrivate for WpDrmLeaseDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_drm_lease_device_v1#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_drm_lease_device_v1",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -889,10 +933,16 @@ impl ObjectPrivate for WpDrmLeaseDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_drm_lease_device_v1#{}.drm_fd(fd: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_drm_lease_device_v1",
+                            id,
+                            "drm_fd",
+                            &[
+                                LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0.as_raw_fd());
                 }
@@ -912,10 +962,16 @@ impl ObjectPrivate for WpDrmLeaseDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_drm_lease_device_v1#{}.connector(id: wp_drm_lease_connector_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_drm_lease_device_v1",
+                            id,
+                            "connector",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_drm_lease_connector_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -938,10 +994,15 @@ impl ObjectPrivate for WpDrmLeaseDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_drm_lease_device_v1#{}.done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_drm_lease_device_v1",
+                            id,
+                            "done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -959,10 +1020,15 @@ impl ObjectPrivate for WpDrmLeaseDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_drm_lease_device_v1#{}.released()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_drm_lease_device_v1",
+                            id,
+                            "released",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1004,6 +1070,26 @@ impl ObjectPrivate for WpDrmLeaseDeviceV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[wp_drm_lease_request_v1]",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "h",
+            1 => "n[wp_drm_lease_connector_v1]",
+            2 => "",
+            3 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
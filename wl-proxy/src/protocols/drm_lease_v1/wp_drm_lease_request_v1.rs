@@ -94,10 +94,16 @@ impl WpDrmLeaseRequestV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_drm_lease_request_v1#{}.request_connector(connector: wp_drm_lease_connector_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_drm_lease_request_v1",
+                    id,
+                    "request_connector",
+                    &[
+                        LogArg::new(Some("connector"), LogValue::Object { interface: Some("wp_drm_lease_connector_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -186,10 +192,16 @@ impl WpDrmLeaseRequestV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_drm_lease_request_v1#{}.submit(id: wp_drm_lease_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_drm_lease_request_v1",
+                    id,
+                    "submit",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_drm_lease_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -391,10 +403,16 @@ impl ObjectPrivate for WpDrmLeaseRequestV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_drm_lease_request_v1#{}.request_connector(connector: wp_drm_lease_connector_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_drm_lease_request_v1",
+                            id,
+                            "request_connector",
+                            &[
+                                LogArg::new(Some("connector"), LogValue::Object { interface: Some("wp_drm_lease_connector_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -423,10 +441,16 @@ impl ObjectPrivate for WpDrmLeaseRequestV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_drm_lease_request_v1#{}.submit(id: wp_drm_lease_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_drm_lease_request_v1",
+                            id,
+                            "submit",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_drm_lease_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -483,6 +507,20 @@ impl ObjectPrivate for WpDrmLeaseRequestV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "o",
+            1 => "n[wp_drm_lease_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
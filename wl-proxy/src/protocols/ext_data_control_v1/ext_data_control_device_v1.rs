@@ -94,10 +94,16 @@ impl ExtDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_device_v1#{}.set_selection(source: ext_data_control_source_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_device_v1",
+                    id,
+                    "set_selection",
+                    &[
+                        LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_data_control_source_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -163,10 +169,15 @@ impl ExtDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_device_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_device_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -243,10 +254,16 @@ impl ExtDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_data_control_device_v1#{}.data_offer(id: ext_data_control_offer_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_data_control_device_v1",
+                    id,
+                    "data_offer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "ext_data_control_offer_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -383,10 +400,16 @@ impl ExtDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_data_control_device_v1#{}.selection(id: ext_data_control_offer_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_data_control_device_v1",
+                    id,
+                    "selection",
+                    &[
+                        LogArg::new(Some("id"), LogValue::Object { interface: Some("ext_data_control_offer_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -458,10 +481,15 @@ impl ExtDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_data_control_device_v1#{}.finished()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_data_control_device_v1",
+                    id,
+                    "finished",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -545,10 +573,16 @@ impl ExtDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_data_control_device_v1#{}.primary_selection(id: ext_data_control_offer_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_data_control_device_v1",
+                    id,
+                    "primary_selection",
+                    &[
+                        LogArg::new(Some("id"), LogValue::Object { interface: Some("ext_data_control_offer_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -647,10 +681,16 @@ impl ExtDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_device_v1#{}.set_primary_selection(source: ext_data_control_source_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_device_v1",
+                    id,
+                    "set_primary_selection",
+                    &[
+                        LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_data_control_source_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -986,10 +1026,16 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_device_v1#{}.set_selection(source: ext_data_control_source_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_device_v1",
+                            id,
+                            "set_selection",
+                            &[
+                                LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_data_control_source_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1021,10 +1067,15 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_device_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_device_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1045,10 +1096,16 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_device_v1#{}.set_primary_selection(source: ext_data_control_source_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_device_v1",
+                            id,
+                            "set_primary_selection",
+                            &[
+                                LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_data_control_source_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1099,10 +1156,16 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_data_control_device_v1#{}.data_offer(id: ext_data_control_offer_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_data_control_device_v1",
+                            id,
+                            "data_offer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "ext_data_control_offer_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1127,10 +1190,16 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_data_control_device_v1#{}.selection(id: ext_data_control_offer_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_data_control_device_v1",
+                            id,
+                            "selection",
+                            &[
+                                LogArg::new(Some("id"), LogValue::Object { interface: Some("ext_data_control_offer_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1162,10 +1231,15 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_data_control_device_v1#{}.finished()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_data_control_device_v1",
+                            id,
+                            "finished",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1185,10 +1259,16 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_data_control_device_v1#{}.primary_selection(id: ext_data_control_offer_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_data_control_device_v1",
+                            id,
+                            "primary_selection",
+                            &[
+                                LogArg::new(Some("id"), LogValue::Object { interface: Some("ext_data_control_offer_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1244,6 +1324,27 @@ impl ObjectPrivate for ExtDataControlDeviceV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "?o",
+            1 => "",
+            2 => "?o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[ext_data_control_offer_v1]",
+            1 => "?o",
+            2 => "",
+            3 => "?o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -83,10 +83,16 @@ impl ExtDataControlManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_manager_v1#{}.create_data_source(id: ext_data_control_source_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_manager_v1",
+                    id,
+                    "create_data_source",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "ext_data_control_source_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -197,10 +203,17 @@ impl ExtDataControlManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_manager_v1#{}.get_data_device(id: ext_data_control_device_v1#{}, seat: wl_seat#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_manager_v1",
+                    id,
+                    "get_data_device",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "ext_data_control_device_v1", id: arg0, version: None }),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -304,10 +317,15 @@ impl ExtDataControlManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -464,10 +482,16 @@ impl ObjectPrivate for ExtDataControlManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_manager_v1#{}.create_data_source(id: ext_data_control_source_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_manager_v1",
+                            id,
+                            "create_data_source",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "ext_data_control_source_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -493,10 +517,17 @@ impl ObjectPrivate for ExtDataControlManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_manager_v1#{}.get_data_device(id: ext_data_control_device_v1#{}, seat: wl_seat#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_manager_v1",
+                            id,
+                            "get_data_device",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "ext_data_control_device_v1", id: arg0, version: None }),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -528,10 +559,15 @@ impl ObjectPrivate for ExtDataControlManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -584,6 +620,21 @@ impl ObjectPrivate for ExtDataControlManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[ext_data_control_source_v1]",
+            1 => "n[ext_data_control_device_v1]o",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
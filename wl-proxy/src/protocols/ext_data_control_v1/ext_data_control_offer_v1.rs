@@ -93,10 +93,17 @@ impl ExtDataControlOfferV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_offer_v1#{}.receive(mime_type: {:?}, fd: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_offer_v1",
+                    id,
+                    "receive",
+                    &[
+                        LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1.as_raw_fd());
         }
@@ -168,10 +175,15 @@ impl ExtDataControlOfferV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_offer_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_offer_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -237,10 +249,16 @@ impl ExtDataControlOfferV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_data_control_offer_v1#{}.offer(mime_type: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_data_control_offer_v1",
+                    id,
+                    "offer",
+                    &[
+                        LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -412,10 +430,17 @@ impl ObjectPrivate for ExtDataControlOfferV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_offer_v1#{}.receive(mime_type: {:?}, fd: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_offer_v1",
+                            id,
+                            "receive",
+                            &[
+                                LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1.as_raw_fd());
                 }
@@ -433,10 +458,15 @@ impl ObjectPrivate for ExtDataControlOfferV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_offer_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_offer_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -475,10 +505,16 @@ impl ObjectPrivate for ExtDataControlOfferV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_data_control_offer_v1#{}.offer(mime_type: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_data_control_offer_v1",
+                            id,
+                            "offer",
+                            &[
+                                LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -516,6 +552,23 @@ impl ObjectPrivate for ExtDataControlOfferV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "sh",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "s",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
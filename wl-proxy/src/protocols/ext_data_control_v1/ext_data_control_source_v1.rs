@@ -84,10 +84,16 @@ impl ExtDataControlSourceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_source_v1#{}.offer(mime_type: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_source_v1",
+                    id,
+                    "offer",
+                    &[
+                        LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -150,10 +156,15 @@ impl ExtDataControlSourceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_data_control_source_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_data_control_source_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -223,10 +234,17 @@ impl ExtDataControlSourceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_data_control_source_v1#{}.send(mime_type: {:?}, fd: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_data_control_source_v1",
+                    id,
+                    "send",
+                    &[
+                        LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1.as_raw_fd());
         }
@@ -293,10 +311,15 @@ impl ExtDataControlSourceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_data_control_source_v1#{}.cancelled()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_data_control_source_v1",
+                    id,
+                    "cancelled",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -475,10 +498,16 @@ impl ObjectPrivate for ExtDataControlSourceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_source_v1#{}.offer(mime_type: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_source_v1",
+                            id,
+                            "offer",
+                            &[
+                                LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -496,10 +525,15 @@ impl ObjectPrivate for ExtDataControlSourceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_data_control_source_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_data_control_source_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -542,10 +576,17 @@ impl ObjectPrivate for ExtDataControlSourceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_data_control_source_v1#{}.send(mime_type: {:?}, fd: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_data_control_source_v1",
+                            id,
+                            "send",
+                            &[
+                                LogArg::new(Some("mime_type"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1.as_raw_fd());
                 }
@@ -563,10 +604,15 @@ impl ObjectPrivate for ExtDataControlSourceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_data_control_source_v1#{}.cancelled()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_data_control_source_v1",
+                            id,
+                            "cancelled",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -605,6 +651,24 @@ impl ObjectPrivate for ExtDataControlSourceV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "s",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "sh",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -71,10 +71,15 @@ impl ExtIdleNotifierV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_idle_notifier_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_idle_notifier_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -163,10 +168,18 @@ impl ExtIdleNotifierV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_idle_notifier_v1#{}.get_idle_notification(id: ext_idle_notification_v1#{}, timeout: {}, seat: wl_seat#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_idle_notifier_v1",
+                    id,
+                    "get_idle_notification",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "ext_idle_notification_v1", id: arg0, version: None }),
+                        LogArg::new(Some("timeout"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id);
         }
@@ -337,10 +350,18 @@ impl ExtIdleNotifierV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_idle_notifier_v1#{}.get_input_idle_notification(id: ext_idle_notification_v1#{}, timeout: {}, seat: wl_seat#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_idle_notifier_v1",
+                    id,
+                    "get_input_idle_notification",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "ext_idle_notification_v1", id: arg0, version: None }),
+                        LogArg::new(Some("timeout"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id);
         }
@@ -609,10 +630,15 @@ impl ObjectPrivate for ExtIdleNotifierV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_idle_notifier_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_idle_notifier_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -635,10 +661,18 @@ impl ObjectPrivate for ExtIdleNotifierV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_idle_notifier_v1#{}.get_idle_notification(id: ext_idle_notification_v1#{}, timeout: {}, seat: wl_seat#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_idle_notifier_v1",
+                            id,
+                            "get_idle_notification",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "ext_idle_notification_v1", id: arg0, version: None }),
+                                LogArg::new(Some("timeout"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -674,10 +708,18 @@ impl ObjectPrivate for ExtIdleNotifierV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_idle_notifier_v1#{}.get_input_idle_notification(id: ext_idle_notification_v1#{}, timeout: {}, seat: wl_seat#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_idle_notifier_v1",
+                            id,
+                            "get_input_idle_notification",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "ext_idle_notification_v1", id: arg0, version: None }),
+                                LogArg::new(Some("timeout"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -743,6 +785,21 @@ impl ObjectPrivate for ExtIdleNotifierV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[ext_idle_notification_v1]uo",
+            2 => "n[ext_idle_notification_v1]uo",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
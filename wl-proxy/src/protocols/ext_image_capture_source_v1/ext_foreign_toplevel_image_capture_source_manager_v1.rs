@@ -93,10 +93,17 @@ impl ExtForeignToplevelImageCaptureSourceManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_foreign_toplevel_image_capture_source_manager_v1#{}.create_source(source: ext_image_capture_source_v1#{}, toplevel_handle: ext_foreign_toplevel_handle_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_foreign_toplevel_image_capture_source_manager_v1",
+                    id,
+                    "create_source",
+                    &[
+                        LogArg::new(Some("source"), LogValue::NewId { interface: "ext_image_capture_source_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel_handle"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -204,10 +211,15 @@ impl ExtForeignToplevelImageCaptureSourceManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_foreign_toplevel_image_capture_source_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_foreign_toplevel_image_capture_source_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -344,10 +356,17 @@ impl ObjectPrivate for ExtForeignToplevelImageCaptureSourceManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_foreign_toplevel_image_capture_source_manager_v1#{}.create_source(source: ext_image_capture_source_v1#{}, toplevel_handle: ext_foreign_toplevel_handle_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_foreign_toplevel_image_capture_source_manager_v1",
+                            id,
+                            "create_source",
+                            &[
+                                LogArg::new(Some("source"), LogValue::NewId { interface: "ext_image_capture_source_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel_handle"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -379,10 +398,15 @@ impl ObjectPrivate for ExtForeignToplevelImageCaptureSourceManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_foreign_toplevel_image_capture_source_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_foreign_toplevel_image_capture_source_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -434,6 +458,20 @@ impl ObjectPrivate for ExtForeignToplevelImageCaptureSourceManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[ext_image_capture_source_v1]o",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
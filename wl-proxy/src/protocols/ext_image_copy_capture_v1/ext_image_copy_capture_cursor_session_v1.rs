@@ -72,10 +72,15 @@ impl ExtImageCopyCaptureCursorSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_cursor_session_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_cursor_session_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -153,10 +158,16 @@ impl ExtImageCopyCaptureCursorSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_cursor_session_v1#{}.get_capture_session(session: ext_image_copy_capture_session_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_cursor_session_v1",
+                    id,
+                    "get_capture_session",
+                    &[
+                        LogArg::new(Some("session"), LogValue::NewId { interface: "ext_image_copy_capture_session_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -269,10 +280,15 @@ impl ExtImageCopyCaptureCursorSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_cursor_session_v1#{}.enter()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_cursor_session_v1",
+                    id,
+                    "enter",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -332,10 +348,15 @@ impl ExtImageCopyCaptureCursorSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_cursor_session_v1#{}.leave()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_cursor_session_v1",
+                    id,
+                    "leave",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -409,10 +430,17 @@ impl ExtImageCopyCaptureCursorSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_cursor_session_v1#{}.position(x: {}, y: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_cursor_session_v1",
+                    id,
+                    "position",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -504,10 +532,17 @@ impl ExtImageCopyCaptureCursorSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_cursor_session_v1#{}.hotspot(x: {}, y: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_cursor_session_v1",
+                    id,
+                    "hotspot",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -770,10 +805,15 @@ impl ObjectPrivate for ExtImageCopyCaptureCursorSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_cursor_session_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_cursor_session_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -794,10 +834,16 @@ impl ObjectPrivate for ExtImageCopyCaptureCursorSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_cursor_session_v1#{}.get_capture_session(session: ext_image_copy_capture_session_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_cursor_session_v1",
+                            id,
+                            "get_capture_session",
+                            &[
+                                LogArg::new(Some("session"), LogValue::NewId { interface: "ext_image_copy_capture_session_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -837,10 +883,15 @@ impl ObjectPrivate for ExtImageCopyCaptureCursorSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_cursor_session_v1#{}.enter()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_cursor_session_v1",
+                            id,
+                            "enter",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -858,10 +909,15 @@ impl ObjectPrivate for ExtImageCopyCaptureCursorSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_cursor_session_v1#{}.leave()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_cursor_session_v1",
+                            id,
+                            "leave",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -884,10 +940,17 @@ impl ObjectPrivate for ExtImageCopyCaptureCursorSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_cursor_session_v1#{}.position(x: {}, y: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_cursor_session_v1",
+                            id,
+                            "position",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -910,10 +973,17 @@ impl ObjectPrivate for ExtImageCopyCaptureCursorSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_cursor_session_v1#{}.hotspot(x: {}, y: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_cursor_session_v1",
+                            id,
+                            "hotspot",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -954,6 +1024,26 @@ impl ObjectPrivate for ExtImageCopyCaptureCursorSessionV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[ext_image_copy_capture_session_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "",
+            2 => "ii",
+            3 => "ii",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
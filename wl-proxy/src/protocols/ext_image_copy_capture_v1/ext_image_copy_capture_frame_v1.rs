@@ -77,10 +77,15 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_frame_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -156,10 +161,16 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_frame_v1#{}.attach_buffer(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "attach_buffer",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -267,10 +278,19 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_frame_v1#{}.damage_buffer(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "damage_buffer",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -369,10 +389,15 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_frame_v1#{}.capture()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "capture",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -445,10 +470,16 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WlOutputTransform) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_frame_v1#{}.transform(transform: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "transform",
+                    &[
+                        LogArg::new(Some("transform"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -537,10 +568,19 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_frame_v1#{}.damage(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "damage",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -644,10 +684,18 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_frame_v1#{}.presentation_time(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "presentation_time",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -727,10 +775,15 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_frame_v1#{}.ready()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "ready",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -799,10 +852,16 @@ impl ExtImageCopyCaptureFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ExtImageCopyCaptureFrameV1FailureReason) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_image_copy_capture_frame_v1#{}.failed(reason: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_image_copy_capture_frame_v1",
+                    id,
+                    "failed",
+                    &[
+                        LogArg::new(Some("reason"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1173,10 +1232,15 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_frame_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1197,10 +1261,16 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_frame_v1#{}.attach_buffer(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "attach_buffer",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1236,10 +1306,19 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_frame_v1#{}.damage_buffer(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "damage_buffer",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1257,10 +1336,15 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_frame_v1#{}.capture()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "capture",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1298,10 +1382,16 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WlOutputTransform) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_frame_v1#{}.transform(transform: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "transform",
+                            &[
+                                LogArg::new(Some("transform"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1328,10 +1418,19 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_frame_v1#{}.damage(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "damage",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1353,10 +1452,18 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_frame_v1#{}.presentation_time(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "presentation_time",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -1374,10 +1481,15 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_frame_v1#{}.ready()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "ready",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1398,10 +1510,16 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ExtImageCopyCaptureFrameV1FailureReason) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_image_copy_capture_frame_v1#{}.failed(reason: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_image_copy_capture_frame_v1",
+                            id,
+                            "failed",
+                            &[
+                                LogArg::new(Some("reason"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1445,6 +1563,29 @@ impl ObjectPrivate for ExtImageCopyCaptureFrameV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "o",
+            2 => "iiii",
+            3 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "iiii",
+            2 => "uuu",
+            3 => "",
+            4 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
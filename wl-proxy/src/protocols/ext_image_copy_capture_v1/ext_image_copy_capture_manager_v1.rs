@@ -103,10 +103,18 @@ impl ExtImageCopyCaptureManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: ExtImageCopyCaptureManagerV1Options) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_manager_v1#{}.create_session(session: ext_image_copy_capture_session_v1#{}, source: ext_image_capture_source_v1#{}, options: {:?})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_manager_v1",
+                    id,
+                    "create_session",
+                    &[
+                        LogArg::new(Some("session"), LogValue::NewId { interface: "ext_image_copy_capture_session_v1", id: arg0, version: None }),
+                        LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_image_capture_source_v1"), id: arg1 }),
+                        LogArg::new(Some("options"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2);
         }
@@ -274,10 +282,18 @@ impl ExtImageCopyCaptureManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_manager_v1#{}.create_pointer_cursor_session(session: ext_image_copy_capture_cursor_session_v1#{}, source: ext_image_capture_source_v1#{}, pointer: wl_pointer#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_manager_v1",
+                    id,
+                    "create_pointer_cursor_session",
+                    &[
+                        LogArg::new(Some("session"), LogValue::NewId { interface: "ext_image_copy_capture_cursor_session_v1", id: arg0, version: None }),
+                        LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_image_capture_source_v1"), id: arg1 }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2_id);
         }
@@ -395,10 +411,15 @@ impl ExtImageCopyCaptureManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_image_copy_capture_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_image_copy_capture_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -580,10 +601,18 @@ impl ObjectPrivate for ExtImageCopyCaptureManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: ExtImageCopyCaptureManagerV1Options) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_manager_v1#{}.create_session(session: ext_image_copy_capture_session_v1#{}, source: ext_image_capture_source_v1#{}, options: {:?})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_manager_v1",
+                            id,
+                            "create_session",
+                            &[
+                                LogArg::new(Some("session"), LogValue::NewId { interface: "ext_image_copy_capture_session_v1", id: arg0, version: None }),
+                                LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_image_capture_source_v1"), id: arg1 }),
+                                LogArg::new(Some("options"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -619,10 +648,18 @@ impl ObjectPrivate for ExtImageCopyCaptureManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_manager_v1#{}.create_pointer_cursor_session(session: ext_image_copy_capture_cursor_session_v1#{}, source: ext_image_capture_source_v1#{}, pointer: wl_pointer#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_manager_v1",
+                            id,
+                            "create_pointer_cursor_session",
+                            &[
+                                LogArg::new(Some("session"), LogValue::NewId { interface: "ext_image_copy_capture_cursor_session_v1", id: arg0, version: None }),
+                                LogArg::new(Some("source"), LogValue::Object { interface: Some("ext_image_capture_source_v1"), id: arg1 }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -663,10 +700,15 @@ impl ObjectPrivate for ExtImageCopyCaptureManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_image_copy_capture_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_image_copy_capture_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -719,6 +761,21 @@ impl ObjectPrivate for ExtImageCopyCaptureManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[ext_image_copy_capture_session_v1]ou",
+            1 => "n[ext_image_copy_capture_cursor_session_v1]oo",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
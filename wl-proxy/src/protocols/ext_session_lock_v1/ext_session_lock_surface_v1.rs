@@ -89,10 +89,15 @@ impl ExtSessionLockSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_session_lock_surface_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_session_lock_surface_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -186,10 +191,16 @@ impl ExtSessionLockSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_session_lock_surface_v1#{}.ack_configure(serial: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_session_lock_surface_v1",
+                    id,
+                    "ack_configure",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -296,10 +307,18 @@ impl ExtSessionLockSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_session_lock_surface_v1#{}.configure(serial: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_session_lock_surface_v1",
+                    id,
+                    "configure",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("width"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("height"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -505,10 +524,15 @@ impl ObjectPrivate for ExtSessionLockSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_session_lock_surface_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_session_lock_surface_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -529,10 +553,16 @@ impl ObjectPrivate for ExtSessionLockSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_session_lock_surface_v1#{}.ack_configure(serial: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_session_lock_surface_v1",
+                            id,
+                            "ack_configure",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -571,10 +601,18 @@ impl ObjectPrivate for ExtSessionLockSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_session_lock_surface_v1#{}.configure(serial: {}, width: {}, height: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_session_lock_surface_v1",
+                            id,
+                            "configure",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("width"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("height"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -612,6 +650,23 @@ impl ObjectPrivate for ExtSessionLockSurfaceV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
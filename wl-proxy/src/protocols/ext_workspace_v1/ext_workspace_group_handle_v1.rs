@@ -101,10 +101,16 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ExtWorkspaceGroupHandleV1GroupCapabilities) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_workspace_group_handle_v1#{}.capabilities(capabilities: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "capabilities",
+                    &[
+                        LogArg::new(Some("capabilities"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -192,10 +198,16 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_workspace_group_handle_v1#{}.output_enter(output: wl_output#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "output_enter",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -272,10 +284,16 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_workspace_group_handle_v1#{}.output_leave(output: wl_output#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "output_leave",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -352,10 +370,16 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_workspace_group_handle_v1#{}.workspace_enter(workspace: ext_workspace_handle_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "workspace_enter",
+                    &[
+                        LogArg::new(Some("workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -431,10 +455,16 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_workspace_group_handle_v1#{}.workspace_leave(workspace: ext_workspace_handle_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "workspace_leave",
+                    &[
+                        LogArg::new(Some("workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -500,10 +530,15 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ext_workspace_group_handle_v1#{}.removed()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "removed",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -574,10 +609,16 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_workspace_group_handle_v1#{}.create_workspace(workspace: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "create_workspace",
+                    &[
+                        LogArg::new(Some("workspace"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -644,10 +685,15 @@ impl ExtWorkspaceGroupHandleV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ext_workspace_group_handle_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ext_workspace_group_handle_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -987,10 +1033,16 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_workspace_group_handle_v1#{}.create_workspace(workspace: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "create_workspace",
+                            &[
+                                LogArg::new(Some("workspace"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1008,10 +1060,15 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ext_workspace_group_handle_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1050,10 +1107,16 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ExtWorkspaceGroupHandleV1GroupCapabilities) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_workspace_group_handle_v1#{}.capabilities(capabilities: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "capabilities",
+                            &[
+                                LogArg::new(Some("capabilities"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1073,10 +1136,16 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_workspace_group_handle_v1#{}.output_enter(output: wl_output#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "output_enter",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1105,10 +1174,16 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_workspace_group_handle_v1#{}.output_leave(output: wl_output#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "output_leave",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1137,10 +1212,16 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_workspace_group_handle_v1#{}.workspace_enter(workspace: ext_workspace_handle_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "workspace_enter",
+                            &[
+                                LogArg::new(Some("workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1169,10 +1250,16 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_workspace_group_handle_v1#{}.workspace_leave(workspace: ext_workspace_handle_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "workspace_leave",
+                            &[
+                                LogArg::new(Some("workspace"), LogValue::Object { interface: Some("ext_workspace_handle_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1199,10 +1286,15 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ext_workspace_group_handle_v1#{}.removed()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ext_workspace_group_handle_v1",
+                            id,
+                            "removed",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1245,6 +1337,28 @@ impl ObjectPrivate for ExtWorkspaceGroupHandleV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "s",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            1 => "o",
+            2 => "o",
+            3 => "o",
+            4 => "o",
+            5 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
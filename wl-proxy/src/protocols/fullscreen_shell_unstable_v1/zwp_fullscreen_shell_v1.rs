@@ -103,10 +103,15 @@ impl ZwpFullscreenShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_fullscreen_shell_v1#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_fullscreen_shell_v1",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -182,10 +187,16 @@ impl ZwpFullscreenShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZwpFullscreenShellV1Capability) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_fullscreen_shell_v1#{}.capability(capability: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_fullscreen_shell_v1",
+                    id,
+                    "capability",
+                    &[
+                        LogArg::new(Some("capability"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -303,10 +314,18 @@ impl ZwpFullscreenShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: ZwpFullscreenShellV1PresentMethod, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_fullscreen_shell_v1#{}.present_surface(surface: wl_surface#{}, method: {:?}, output: wl_output#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_fullscreen_shell_v1",
+                    id,
+                    "present_surface",
+                    &[
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                        LogArg::new(Some("method"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id);
         }
@@ -470,10 +489,19 @@ impl ZwpFullscreenShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: i32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_fullscreen_shell_v1#{}.present_surface_for_mode(surface: wl_surface#{}, output: wl_output#{}, framerate: {}, feedback: zwp_fullscreen_shell_mode_feedback_v1#{})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_fullscreen_shell_v1",
+                    id,
+                    "present_surface_for_mode",
+                    &[
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg1 }),
+                        LogArg::new(Some("framerate"), LogValue::Int(arg2)),
+                        LogArg::new(Some("feedback"), LogValue::NewId { interface: "zwp_fullscreen_shell_mode_feedback_v1", id: arg3, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2, arg3_id);
         }
@@ -926,10 +954,15 @@ impl ObjectPrivate for ZwpFullscreenShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_fullscreen_shell_v1#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_fullscreen_shell_v1",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -953,10 +986,18 @@ impl ObjectPrivate for ZwpFullscreenShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: ZwpFullscreenShellV1PresentMethod, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_fullscreen_shell_v1#{}.present_surface(surface: wl_surface#{}, method: {:?}, output: wl_output#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_fullscreen_shell_v1",
+                            id,
+                            "present_surface",
+                            &[
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                                LogArg::new(Some("method"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -1008,10 +1049,19 @@ impl ObjectPrivate for ZwpFullscreenShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: i32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_fullscreen_shell_v1#{}.present_surface_for_mode(surface: wl_surface#{}, output: wl_output#{}, framerate: {}, feedback: zwp_fullscreen_shell_mode_feedback_v1#{})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_fullscreen_shell_v1",
+                            id,
+                            "present_surface_for_mode",
+                            &[
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg1 }),
+                                LogArg::new(Some("framerate"), LogValue::Int(arg2)),
+                                LogArg::new(Some("feedback"), LogValue::NewId { interface: "zwp_fullscreen_shell_mode_feedback_v1", id: arg3, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1072,10 +1122,16 @@ impl ObjectPrivate for ZwpFullscreenShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZwpFullscreenShellV1Capability) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_fullscreen_shell_v1#{}.capability(capability: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_fullscreen_shell_v1",
+                            id,
+                            "capability",
+                            &[
+                                LogArg::new(Some("capability"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1114,6 +1170,24 @@ impl ObjectPrivate for ZwpFullscreenShellV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "?ou?o",
+            2 => "ooin[zwp_fullscreen_shell_mode_feedback_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
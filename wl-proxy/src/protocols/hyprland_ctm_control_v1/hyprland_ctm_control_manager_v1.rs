@@ -133,10 +133,25 @@ impl HyprlandCtmControlManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed, arg3: Fixed, arg4: Fixed, arg5: Fixed, arg6: Fixed, arg7: Fixed, arg8: Fixed, arg9: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_ctm_control_manager_v1#{}.set_ctm_for_output(output: wl_output#{}, mat0: {}, mat1: {}, mat2: {}, mat3: {}, mat4: {}, mat5: {}, mat6: {}, mat7: {}, mat8: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_ctm_control_manager_v1",
+                    id,
+                    "set_ctm_for_output",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                        LogArg::new(Some("mat0"), LogValue::Fixed(arg1)),
+                        LogArg::new(Some("mat1"), LogValue::Fixed(arg2)),
+                        LogArg::new(Some("mat2"), LogValue::Fixed(arg3)),
+                        LogArg::new(Some("mat3"), LogValue::Fixed(arg4)),
+                        LogArg::new(Some("mat4"), LogValue::Fixed(arg5)),
+                        LogArg::new(Some("mat5"), LogValue::Fixed(arg6)),
+                        LogArg::new(Some("mat6"), LogValue::Fixed(arg7)),
+                        LogArg::new(Some("mat7"), LogValue::Fixed(arg8)),
+                        LogArg::new(Some("mat8"), LogValue::Fixed(arg9)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
         }
@@ -243,10 +258,15 @@ impl HyprlandCtmControlManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_ctm_control_manager_v1#{}.commit()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_ctm_control_manager_v1",
+                    id,
+                    "commit",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -301,10 +321,15 @@ impl HyprlandCtmControlManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_ctm_control_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_ctm_control_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -365,10 +390,15 @@ impl HyprlandCtmControlManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_ctm_control_manager_v1#{}.blocked()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_ctm_control_manager_v1",
+                    id,
+                    "blocked",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -595,10 +625,25 @@ impl ObjectPrivate for HyprlandCtmControlManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed, arg3: Fixed, arg4: Fixed, arg5: Fixed, arg6: Fixed, arg7: Fixed, arg8: Fixed, arg9: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_ctm_control_manager_v1#{}.set_ctm_for_output(output: wl_output#{}, mat0: {}, mat1: {}, mat2: {}, mat3: {}, mat4: {}, mat5: {}, mat6: {}, mat7: {}, mat8: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_ctm_control_manager_v1",
+                            id,
+                            "set_ctm_for_output",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                                LogArg::new(Some("mat0"), LogValue::Fixed(arg1)),
+                                LogArg::new(Some("mat1"), LogValue::Fixed(arg2)),
+                                LogArg::new(Some("mat2"), LogValue::Fixed(arg3)),
+                                LogArg::new(Some("mat3"), LogValue::Fixed(arg4)),
+                                LogArg::new(Some("mat4"), LogValue::Fixed(arg5)),
+                                LogArg::new(Some("mat5"), LogValue::Fixed(arg6)),
+                                LogArg::new(Some("mat6"), LogValue::Fixed(arg7)),
+                                LogArg::new(Some("mat7"), LogValue::Fixed(arg8)),
+                                LogArg::new(Some("mat8"), LogValue::Fixed(arg9)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
                 }
@@ -625,10 +670,15 @@ impl ObjectPrivate for HyprlandCtmControlManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_ctm_control_manager_v1#{}.commit()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_ctm_control_manager_v1",
+                            id,
+                            "commit",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -646,10 +696,15 @@ impl ObjectPrivate for HyprlandCtmControlManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_ctm_control_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_ctm_control_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -685,10 +740,15 @@ impl ObjectPrivate for HyprlandCtmControlManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_ctm_control_manager_v1#{}.blocked()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_ctm_control_manager_v1",
+                            id,
+                            "blocked",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -727,6 +787,24 @@ impl ObjectPrivate for HyprlandCtmControlManagerV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "offfffffff",
+            1 => "",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -89,10 +89,18 @@ impl HyprlandGlobalShortcutV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_global_shortcut_v1#{}.pressed(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_global_shortcut_v1",
+                    id,
+                    "pressed",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -181,10 +189,18 @@ impl HyprlandGlobalShortcutV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_global_shortcut_v1#{}.released(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_global_shortcut_v1",
+                    id,
+                    "released",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -251,10 +267,15 @@ impl HyprlandGlobalShortcutV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_global_shortcut_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_global_shortcut_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -417,10 +438,15 @@ impl ObjectPrivate for HyprlandGlobalShortcutV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_global_shortcut_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_global_shortcut_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -460,10 +486,18 @@ impl ObjectPrivate for HyprlandGlobalShortcutV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_global_shortcut_v1#{}.pressed(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_global_shortcut_v1",
+                            id,
+                            "pressed",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -485,10 +519,18 @@ impl ObjectPrivate for HyprlandGlobalShortcutV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_global_shortcut_v1#{}.released(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_global_shortcut_v1",
+                            id,
+                            "released",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -526,6 +568,23 @@ impl ObjectPrivate for HyprlandGlobalShortcutV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuu",
+            1 => "uuu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -104,10 +104,20 @@ impl HyprlandGlobalShortcutsManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &str, arg2: &str, arg3: &str, arg4: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_global_shortcuts_manager_v1#{}.register_shortcut(shortcut: hyprland_global_shortcut_v1#{}, id: {:?}, app_id: {:?}, description: {:?}, trigger_description: {:?})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_global_shortcuts_manager_v1",
+                    id,
+                    "register_shortcut",
+                    &[
+                        LogArg::new(Some("shortcut"), LogValue::NewId { interface: "hyprland_global_shortcut_v1", id: arg0, version: None }),
+                        LogArg::new(Some("id"), LogValue::String(Some(arg1))),
+                        LogArg::new(Some("app_id"), LogValue::String(Some(arg2))),
+                        LogArg::new(Some("description"), LogValue::String(Some(arg3))),
+                        LogArg::new(Some("trigger_description"), LogValue::String(Some(arg4))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2, arg3, arg4);
         }
@@ -259,10 +269,15 @@ impl HyprlandGlobalShortcutsManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_global_shortcuts_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_global_shortcuts_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -418,10 +433,20 @@ impl ObjectPrivate for HyprlandGlobalShortcutsManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str, arg2: &str, arg3: &str, arg4: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_global_shortcuts_manager_v1#{}.register_shortcut(shortcut: hyprland_global_shortcut_v1#{}, id: {:?}, app_id: {:?}, description: {:?}, trigger_description: {:?})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_global_shortcuts_manager_v1",
+                            id,
+                            "register_shortcut",
+                            &[
+                                LogArg::new(Some("shortcut"), LogValue::NewId { interface: "hyprland_global_shortcut_v1", id: arg0, version: None }),
+                                LogArg::new(Some("id"), LogValue::String(Some(arg1))),
+                                LogArg::new(Some("app_id"), LogValue::String(Some(arg2))),
+                                LogArg::new(Some("description"), LogValue::String(Some(arg3))),
+                                LogArg::new(Some("trigger_description"), LogValue::String(Some(arg4))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -444,10 +469,15 @@ impl ObjectPrivate for HyprlandGlobalShortcutsManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_global_shortcuts_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_global_shortcuts_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -499,6 +529,20 @@ impl ObjectPrivate for HyprlandGlobalShortcutsManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[hyprland_global_shortcut_v1]ssss",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
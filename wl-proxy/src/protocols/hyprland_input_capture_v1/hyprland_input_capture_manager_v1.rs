@@ -86,10 +86,17 @@ impl HyprlandInputCaptureManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_input_capture_manager_v1#{}.create_session(session: hyprland_input_capture_v1#{}, handle: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_input_capture_manager_v1",
+                    id,
+                    "create_session",
+                    &[
+                        LogArg::new(Some("session"), LogValue::NewId { interface: "hyprland_input_capture_v1", id: arg0, version: None }),
+                        LogArg::new(Some("handle"), LogValue::String(Some(arg1))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -254,10 +261,17 @@ impl ObjectPrivate for HyprlandInputCaptureManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_input_capture_manager_v1#{}.create_session(session: hyprland_input_capture_v1#{}, handle: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_input_capture_manager_v1",
+                            id,
+                            "create_session",
+                            &[
+                                LogArg::new(Some("session"), LogValue::NewId { interface: "hyprland_input_capture_v1", id: arg0, version: None }),
+                                LogArg::new(Some("handle"), LogValue::String(Some(arg1))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -312,6 +326,19 @@ impl ObjectPrivate for HyprlandInputCaptureManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[hyprland_input_capture_v1]s",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
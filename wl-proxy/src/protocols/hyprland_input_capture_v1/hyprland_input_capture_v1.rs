@@ -69,10 +69,15 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_input_capture_v1#{}.clear_barriers()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "clear_barriers",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -154,10 +159,21 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_input_capture_v1#{}.add_barrier(zone_set: {}, id: {}, x1: {}, y1: {}, x2: {}, y2: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "add_barrier",
+                    &[
+                        LogArg::new(Some("zone_set"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("id"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("x1"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("y1"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("x2"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("y2"), LogValue::Uint(arg5)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4, arg5);
         }
@@ -236,10 +252,15 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_input_capture_v1#{}.enable()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "enable",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -291,10 +312,15 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_input_capture_v1#{}.disable()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "disable",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -365,10 +391,18 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_input_capture_v1#{}.release(activation_id: {}, x: {}, y: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "release",
+                    &[
+                        LogArg::new(Some("activation_id"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("x"), LogValue::Fixed(arg1)),
+                        LogArg::new(Some("y"), LogValue::Fixed(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -448,10 +482,16 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_input_capture_v1#{}.eis_fd(fd: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "eis_fd",
+                    &[
+                        LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0.as_raw_fd());
         }
@@ -510,10 +550,15 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_input_capture_v1#{}.disabled()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "disabled",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -587,10 +632,19 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_input_capture_v1#{}.activated(activation_id: {}, x: {}, y: {}, barrier_id: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "activated",
+                    &[
+                        LogArg::new(Some("activation_id"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("x"), LogValue::Fixed(arg1)),
+                        LogArg::new(Some("y"), LogValue::Fixed(arg2)),
+                        LogArg::new(Some("barrier_id"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -671,10 +725,16 @@ impl HyprlandInputCaptureV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_input_capture_v1#{}.deactivated(activation_id: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_input_capture_v1",
+                    id,
+                    "deactivated",
+                    &[
+                        LogArg::new(Some("activation_id"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -982,10 +1042,15 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_input_capture_v1#{}.clear_barriers()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "clear_barriers",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1010,10 +1075,21 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_input_capture_v1#{}.add_barrier(zone_set: {}, id: {}, x1: {}, y1: {}, x2: {}, y2: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "add_barrier",
+                            &[
+                                LogArg::new(Some("zone_set"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("id"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("x1"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("y1"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("x2"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("y2"), LogValue::Uint(arg5)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5);
                 }
@@ -1031,10 +1107,15 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_input_capture_v1#{}.enable()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "enable",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1052,10 +1133,15 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_input_capture_v1#{}.disable()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "disable",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1079,10 +1165,18 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_input_capture_v1#{}.release(activation_id: {}, x: {}, y: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "release",
+                            &[
+                                LogArg::new(Some("activation_id"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("x"), LogValue::Fixed(arg1)),
+                                LogArg::new(Some("y"), LogValue::Fixed(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -1121,10 +1215,16 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_input_capture_v1#{}.eis_fd(fd: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "eis_fd",
+                            &[
+                                LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0.as_raw_fd());
                 }
@@ -1142,10 +1242,15 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_input_capture_v1#{}.disabled()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "disabled",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1170,10 +1275,19 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_input_capture_v1#{}.activated(activation_id: {}, x: {}, y: {}, barrier_id: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "activated",
+                            &[
+                                LogArg::new(Some("activation_id"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("x"), LogValue::Fixed(arg1)),
+                                LogArg::new(Some("y"), LogValue::Fixed(arg2)),
+                                LogArg::new(Some("barrier_id"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1193,10 +1307,16 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_input_capture_v1#{}.deactivated(activation_id: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_input_capture_v1",
+                            id,
+                            "deactivated",
+                            &[
+                                LogArg::new(Some("activation_id"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1240,6 +1360,29 @@ impl ObjectPrivate for HyprlandInputCaptureV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "uuuuuu",
+            2 => "",
+            3 => "",
+            4 => "uff",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "h",
+            1 => "",
+            2 => "uffu",
+            3 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -107,10 +107,19 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WlShmFormat, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_toplevel_export_frame_v1#{}.buffer(format: {:?}, width: {}, height: {}, stride: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "buffer",
+                    &[
+                        LogArg::new(Some("format"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("width"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("height"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("stride"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -209,10 +218,17 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_export_frame_v1#{}.copy(buffer: wl_buffer#{}, ignore_damage: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "copy",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                        LogArg::new(Some("ignore_damage"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -317,10 +333,19 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_toplevel_export_frame_v1#{}.damage(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "damage",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("y"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("width"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("height"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -411,10 +436,16 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: HyprlandToplevelExportFrameV1Flags) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_toplevel_export_frame_v1#{}.flags(flags: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "flags",
+                    &[
+                        LogArg::new(Some("flags"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -503,10 +534,18 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_toplevel_export_frame_v1#{}.ready(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "ready",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -586,10 +625,15 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_toplevel_export_frame_v1#{}.failed()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "failed",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -641,10 +685,15 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_export_frame_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -719,10 +768,18 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_toplevel_export_frame_v1#{}.linux_dmabuf(format: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "linux_dmabuf",
+                    &[
+                        LogArg::new(Some("format"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("width"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("height"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -794,10 +851,15 @@ impl HyprlandToplevelExportFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= hyprland_toplevel_export_frame_v1#{}.buffer_done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "hyprland_toplevel_export_frame_v1",
+                    id,
+                    "buffer_done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -1155,10 +1217,17 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_export_frame_v1#{}.copy(buffer: wl_buffer#{}, ignore_damage: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "copy",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                                LogArg::new(Some("ignore_damage"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -1185,10 +1254,15 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_export_frame_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1230,10 +1304,19 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WlShmFormat, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_toplevel_export_frame_v1#{}.buffer(format: {:?}, width: {}, height: {}, stride: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "buffer",
+                            &[
+                                LogArg::new(Some("format"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("width"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("height"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("stride"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1256,10 +1339,19 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_toplevel_export_frame_v1#{}.damage(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "damage",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("y"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("width"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("height"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1280,10 +1372,16 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: HyprlandToplevelExportFrameV1Flags) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_toplevel_export_frame_v1#{}.flags(flags: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "flags",
+                            &[
+                                LogArg::new(Some("flags"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1305,10 +1403,18 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_toplevel_export_frame_v1#{}.ready(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "ready",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -1326,10 +1432,15 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_toplevel_export_frame_v1#{}.failed()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "failed",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1351,10 +1462,18 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_toplevel_export_frame_v1#{}.linux_dmabuf(format: {}, width: {}, height: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "linux_dmabuf",
+                            &[
+                                LogArg::new(Some("format"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("width"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("height"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -1372,10 +1491,15 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> hyprland_toplevel_export_frame_v1#{}.buffer_done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "hyprland_toplevel_export_frame_v1",
+                            id,
+                            "buffer_done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1419,6 +1543,29 @@ impl ObjectPrivate for HyprlandToplevelExportFrameV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "oi",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuuu",
+            1 => "uuuu",
+            2 => "u",
+            3 => "uuu",
+            4 => "",
+            5 => "uuu",
+            6 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -100,10 +100,18 @@ impl HyprlandToplevelExportManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_export_manager_v1#{}.capture_toplevel(frame: hyprland_toplevel_export_frame_v1#{}, overlay_cursor: {}, handle: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_export_manager_v1",
+                    id,
+                    "capture_toplevel",
+                    &[
+                        LogArg::new(Some("frame"), LogValue::NewId { interface: "hyprland_toplevel_export_frame_v1", id: arg0, version: None }),
+                        LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                        LogArg::new(Some("handle"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2);
         }
@@ -244,10 +252,15 @@ impl HyprlandToplevelExportManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_export_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_export_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -329,10 +342,18 @@ impl HyprlandToplevelExportManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_export_manager_v1#{}.capture_toplevel_with_wlr_toplevel_handle(frame: hyprland_toplevel_export_frame_v1#{}, overlay_cursor: {}, handle: zwlr_foreign_toplevel_handle_v1#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_export_manager_v1",
+                    id,
+                    "capture_toplevel_with_wlr_toplevel_handle",
+                    &[
+                        LogArg::new(Some("frame"), LogValue::NewId { interface: "hyprland_toplevel_export_frame_v1", id: arg0, version: None }),
+                        LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                        LogArg::new(Some("handle"), LogValue::Object { interface: Some("zwlr_foreign_toplevel_handle_v1"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id);
         }
@@ -569,10 +590,18 @@ impl ObjectPrivate for HyprlandToplevelExportManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_export_manager_v1#{}.capture_toplevel(frame: hyprland_toplevel_export_frame_v1#{}, overlay_cursor: {}, handle: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_export_manager_v1",
+                            id,
+                            "capture_toplevel",
+                            &[
+                                LogArg::new(Some("frame"), LogValue::NewId { interface: "hyprland_toplevel_export_frame_v1", id: arg0, version: None }),
+                                LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                                LogArg::new(Some("handle"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -595,10 +624,15 @@ impl ObjectPrivate for HyprlandToplevelExportManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_export_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_export_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -622,10 +656,18 @@ impl ObjectPrivate for HyprlandToplevelExportManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_export_manager_v1#{}.capture_toplevel_with_wlr_toplevel_handle(frame: hyprland_toplevel_export_frame_v1#{}, overlay_cursor: {}, handle: zwlr_foreign_toplevel_handle_v1#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_export_manager_v1",
+                            id,
+                            "capture_toplevel_with_wlr_toplevel_handle",
+                            &[
+                                LogArg::new(Some("frame"), LogValue::NewId { interface: "hyprland_toplevel_export_frame_v1", id: arg0, version: None }),
+                                LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                                LogArg::new(Some("handle"), LogValue::Object { interface: Some("zwlr_foreign_toplevel_handle_v1"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -691,6 +733,21 @@ impl ObjectPrivate for HyprlandToplevelExportManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[hyprland_toplevel_export_frame_v1]iu",
+            1 => "",
+            2 => "n[hyprland_toplevel_export_frame_v1]io",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -92,10 +92,17 @@ impl HyprlandToplevelMappingManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_mapping_manager_v1#{}.get_window_for_toplevel(handle: hyprland_toplevel_window_mapping_handle_v1#{}, toplevel: ext_foreign_toplevel_handle_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_mapping_manager_v1",
+                    id,
+                    "get_window_for_toplevel",
+                    &[
+                        LogArg::new(Some("handle"), LogValue::NewId { interface: "hyprland_toplevel_window_mapping_handle_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -222,10 +229,17 @@ impl HyprlandToplevelMappingManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_mapping_manager_v1#{}.get_window_for_toplevel_wlr(handle: hyprland_toplevel_window_mapping_handle_v1#{}, toplevel: zwlr_foreign_toplevel_handle_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_mapping_manager_v1",
+                    id,
+                    "get_window_for_toplevel_wlr",
+                    &[
+                        LogArg::new(Some("handle"), LogValue::NewId { interface: "hyprland_toplevel_window_mapping_handle_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("zwlr_foreign_toplevel_handle_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -329,10 +343,15 @@ impl HyprlandToplevelMappingManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= hyprland_toplevel_mapping_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "hyprland_toplevel_mapping_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -496,10 +515,17 @@ impl ObjectPrivate for HyprlandToplevelMappingManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_mapping_manager_v1#{}.get_window_for_toplevel(handle: hyprland_toplevel_window_mapping_handle_v1#{}, toplevel: ext_foreign_toplevel_handle_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_mapping_manager_v1",
+                            id,
+                            "get_window_for_toplevel",
+                            &[
+                                LogArg::new(Some("handle"), LogValue::NewId { interface: "hyprland_toplevel_window_mapping_handle_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("ext_foreign_toplevel_handle_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -534,10 +560,17 @@ impl ObjectPrivate for HyprlandToplevelMappingManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_mapping_manager_v1#{}.get_window_for_toplevel_wlr(handle: hyprland_toplevel_window_mapping_handle_v1#{}, toplevel: zwlr_foreign_toplevel_handle_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_mapping_manager_v1",
+                            id,
+                            "get_window_for_toplevel_wlr",
+                            &[
+                                LogArg::new(Some("handle"), LogValue::NewId { interface: "hyprland_toplevel_window_mapping_handle_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("zwlr_foreign_toplevel_handle_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -569,10 +602,15 @@ impl ObjectPrivate for HyprlandToplevelMappingManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> hyprland_toplevel_mapping_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "hyprland_toplevel_mapping_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -625,6 +663,21 @@ impl ObjectPrivate for HyprlandToplevelMappingManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[hyprland_toplevel_window_mapping_handle_v1]o",
+            1 => "n[hyprland_toplevel_window_mapping_handle_v1]o",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -86,10 +86,15 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -161,10 +166,17 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.commit_string(serial: {}, text: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "commit_string",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("text"), LogValue::String(Some(arg1))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -259,10 +271,18 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &str, arg2: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.preedit_string(serial: {}, text: {:?}, commit: {:?})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "preedit_string",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("text"), LogValue::String(Some(arg1))),
+                        LogArg::new(Some("commit"), LogValue::String(Some(arg2))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -358,10 +378,18 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.preedit_styling(index: {}, length: {}, style: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "preedit_styling",
+                    &[
+                        LogArg::new(Some("index"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("length"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("style"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -448,10 +476,16 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.preedit_cursor(index: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "preedit_cursor",
+                    &[
+                        LogArg::new(Some("index"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -532,10 +566,17 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.delete_surrounding_text(index: {}, length: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "delete_surrounding_text",
+                    &[
+                        LogArg::new(Some("index"), LogValue::Int(arg0)),
+                        LogArg::new(Some("length"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -624,10 +665,17 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.cursor_position(index: {}, anchor: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "cursor_position",
+                    &[
+                        LogArg::new(Some("index"), LogValue::Int(arg0)),
+                        LogArg::new(Some("anchor"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -705,10 +753,16 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.modifiers_map(map: {})\n", id, debug_array(arg0));
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "modifiers_map",
+                    &[
+                        LogArg::new(Some("map"), LogValue::Array(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -792,10 +846,20 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.keysym(serial: {}, time: {}, sym: {}, state: {}, modifiers: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "keysym",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("sym"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("state"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("modifiers"), LogValue::Uint(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -891,10 +955,16 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.grab_keyboard(keyboard: wl_keyboard#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "grab_keyboard",
+                    &[
+                        LogArg::new(Some("keyboard"), LogValue::NewId { interface: "wl_keyboard", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -1017,10 +1087,19 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.key(serial: {}, time: {}, key: {}, state: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "key",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("key"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("state"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -1125,10 +1204,20 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.modifiers(serial: {}, mods_depressed: {}, mods_latched: {}, mods_locked: {}, group: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "modifiers",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("mods_depressed"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("mods_latched"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("mods_locked"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("group"), LogValue::Uint(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -1216,10 +1305,17 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.language(serial: {}, language: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "language",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("language"), LogValue::String(Some(arg1))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -1288,10 +1384,17 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_context_v1#{}.text_direction(serial: {}, direction: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "text_direction",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("direction"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -1374,10 +1477,18 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_context_v1#{}.surrounding_text(text: {:?}, cursor: {}, anchor: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "surrounding_text",
+                    &[
+                        LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("cursor"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("anchor"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -1447,10 +1558,15 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_context_v1#{}.reset()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "reset",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -1509,10 +1625,17 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_context_v1#{}.content_type(hint: {}, purpose: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "content_type",
+                    &[
+                        LogArg::new(Some("hint"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("purpose"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -1581,10 +1704,17 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_context_v1#{}.invoke_action(button: {}, index: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "invoke_action",
+                    &[
+                        LogArg::new(Some("button"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("index"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -1649,10 +1779,16 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_context_v1#{}.commit_state(serial: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "commit_state",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1713,10 +1849,16 @@ impl ZwpInputMethodContextV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_context_v1#{}.preferred_language(language: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_context_v1",
+                    id,
+                    "preferred_language",
+                    &[
+                        LogArg::new(Some("language"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2357,10 +2499,15 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -2386,10 +2533,17 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.commit_string(serial: {}, text: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "commit_string",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("text"), LogValue::String(Some(arg1))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2416,10 +2570,18 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str, arg2: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.preedit_string(serial: {}, text: {:?}, commit: {:?})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "preedit_string",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("text"), LogValue::String(Some(arg1))),
+                                LogArg::new(Some("commit"), LogValue::String(Some(arg2))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -2441,10 +2603,18 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.preedit_styling(index: {}, length: {}, style: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "preedit_styling",
+                            &[
+                                LogArg::new(Some("index"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("length"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("style"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -2465,10 +2635,16 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.preedit_cursor(index: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "preedit_cursor",
+                            &[
+                                LogArg::new(Some("index"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2490,10 +2666,17 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.delete_surrounding_text(index: {}, length: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "delete_surrounding_text",
+                            &[
+                                LogArg::new(Some("index"), LogValue::Int(arg0)),
+                                LogArg::new(Some("length"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2516,10 +2699,17 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.cursor_position(index: {}, anchor: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "cursor_position",
+                            &[
+                                LogArg::new(Some("index"), LogValue::Int(arg0)),
+                                LogArg::new(Some("anchor"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2540,10 +2730,16 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.modifiers_map(map: {})\n", client_id, id, debug_array(arg0));
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "modifiers_map",
+                            &[
+                                LogArg::new(Some("map"), LogValue::Array(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2567,10 +2763,20 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.keysym(serial: {}, time: {}, sym: {}, state: {}, modifiers: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "keysym",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("sym"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("state"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("modifiers"), LogValue::Uint(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -2590,10 +2796,16 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.grab_keyboard(keyboard: wl_keyboard#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "grab_keyboard",
+                            &[
+                                LogArg::new(Some("keyboard"), LogValue::NewId { interface: "wl_keyboard", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2621,10 +2833,19 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.key(serial: {}, time: {}, key: {}, state: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "key",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("key"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("state"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -2648,10 +2869,20 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.modifiers(serial: {}, mods_depressed: {}, mods_latched: {}, mods_locked: {}, group: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "modifiers",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("mods_depressed"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("mods_latched"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("mods_locked"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("group"), LogValue::Uint(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -2676,10 +2907,17 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.language(serial: {}, language: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "language",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("language"), LogValue::String(Some(arg1))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2700,10 +2938,17 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_context_v1#{}.text_direction(serial: {}, direction: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "text_direction",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("direction"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2749,10 +2994,18 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_context_v1#{}.surrounding_text(text: {:?}, cursor: {}, anchor: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "surrounding_text",
+                            &[
+                                LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("cursor"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("anchor"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -2770,10 +3023,15 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_context_v1#{}.reset()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "reset",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -2794,10 +3052,17 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_context_v1#{}.content_type(hint: {}, purpose: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "content_type",
+                            &[
+                                LogArg::new(Some("hint"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("purpose"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -2818,10 +3083,17 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_context_v1#{}.invoke_action(button: {}, index: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "invoke_action",
+                            &[
+                                LogArg::new(Some("button"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("index"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -2841,10 +3113,16 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_context_v1#{}.commit_state(serial: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "commit_state",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -2865,10 +3143,16 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_context_v1#{}.preferred_language(language: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_context_v1",
+                            id,
+                            "preferred_language",
+                            &[
+                                LogArg::new(Some("language"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -2923,6 +3207,40 @@ impl ObjectPrivate for ZwpInputMethodContextV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "us",
+            2 => "uss",
+            3 => "uuu",
+            4 => "i",
+            5 => "iu",
+            6 => "ii",
+            7 => "a",
+            8 => "uuuuu",
+            9 => "n[wl_keyboard]",
+            10 => "uuuu",
+            11 => "uuuuu",
+            12 => "us",
+            13 => "uu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "suu",
+            1 => "",
+            2 => "uu",
+            3 => "uu",
+            4 => "u",
+            5 => "s",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -88,10 +88,16 @@ impl ZwpInputMethodV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v1#{}.activate(id: zwp_input_method_context_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v1",
+                    id,
+                    "activate",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_method_context_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -198,10 +204,16 @@ impl ZwpInputMethodV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v1#{}.deactivate(context: zwp_input_method_context_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v1",
+                    id,
+                    "deactivate",
+                    &[
+                        LogArg::new(Some("context"), LogValue::Object { interface: Some("zwp_input_method_context_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -367,10 +379,16 @@ impl ObjectPrivate for ZwpInputMethodV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v1#{}.activate(id: zwp_input_method_context_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v1",
+                            id,
+                            "activate",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_method_context_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -395,10 +413,16 @@ impl ObjectPrivate for ZwpInputMethodV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v1#{}.deactivate(context: zwp_input_method_context_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v1",
+                            id,
+                            "deactivate",
+                            &[
+                                LogArg::new(Some("context"), LogValue::Object { interface: Some("zwp_input_method_context_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -442,6 +466,20 @@ impl ObjectPrivate for ZwpInputMethodV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwp_input_method_context_v1]",
+            1 => "o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
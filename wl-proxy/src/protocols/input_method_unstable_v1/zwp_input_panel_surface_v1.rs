@@ -84,10 +84,17 @@ impl ZwpInputPanelSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_panel_surface_v1#{}.set_toplevel(output: wl_output#{}, position: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_panel_surface_v1",
+                    id,
+                    "set_toplevel",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                        LogArg::new(Some("position"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -155,10 +162,15 @@ impl ZwpInputPanelSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_panel_surface_v1#{}.set_overlay_panel()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_panel_surface_v1",
+                    id,
+                    "set_overlay_panel",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -297,10 +309,17 @@ impl ObjectPrivate for ZwpInputPanelSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_panel_surface_v1#{}.set_toplevel(output: wl_output#{}, position: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_panel_surface_v1",
+                            id,
+                            "set_toplevel",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                                LogArg::new(Some("position"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -327,10 +346,15 @@ impl ObjectPrivate for ZwpInputPanelSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_panel_surface_v1#{}.set_overlay_panel()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_panel_surface_v1",
+                            id,
+                            "set_overlay_panel",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -381,6 +405,20 @@ impl ObjectPrivate for ZwpInputPanelSurfaceV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "ou",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -89,10 +89,18 @@ impl ZwpInputMethodKeyboardGrabV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: WlKeyboardKeymapFormat, arg1: i32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_keyboard_grab_v2#{}.keymap(format: {:?}, fd: {}, size: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_keyboard_grab_v2",
+                    id,
+                    "keymap",
+                    &[
+                        LogArg::new(Some("format"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                        LogArg::new(Some("size"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1.as_raw_fd(), arg2);
         }
@@ -184,10 +192,19 @@ impl ZwpInputMethodKeyboardGrabV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: WlKeyboardKeyState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_keyboard_grab_v2#{}.key(serial: {}, time: {}, key: {}, state: {:?})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_keyboard_grab_v2",
+                    id,
+                    "key",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("key"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -287,10 +304,20 @@ impl ZwpInputMethodKeyboardGrabV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_keyboard_grab_v2#{}.modifiers(serial: {}, mods_depressed: {}, mods_latched: {}, mods_locked: {}, group: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_keyboard_grab_v2",
+                    id,
+                    "modifiers",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("mods_depressed"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("mods_latched"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("mods_locked"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("group"), LogValue::Uint(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -362,10 +389,15 @@ impl ZwpInputMethodKeyboardGrabV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_keyboard_grab_v2#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_keyboard_grab_v2",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -443,10 +475,17 @@ impl ZwpInputMethodKeyboardGrabV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_keyboard_grab_v2#{}.repeat_info(rate: {}, delay: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_keyboard_grab_v2",
+                    id,
+                    "repeat_info",
+                    &[
+                        LogArg::new(Some("rate"), LogValue::Int(arg0)),
+                        LogArg::new(Some("delay"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -703,10 +742,15 @@ impl ObjectPrivate for ZwpInputMethodKeyboardGrabV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_keyboard_grab_v2#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_keyboard_grab_v2",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -750,10 +794,18 @@ impl ObjectPrivate for ZwpInputMethodKeyboardGrabV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: WlKeyboardKeymapFormat, arg1: i32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_keyboard_grab_v2#{}.keymap(format: {:?}, fd: {}, size: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_keyboard_grab_v2",
+                            id,
+                            "keymap",
+                            &[
+                                LogArg::new(Some("format"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                                LogArg::new(Some("size"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1.as_raw_fd(), arg2);
                 }
@@ -777,10 +829,19 @@ impl ObjectPrivate for ZwpInputMethodKeyboardGrabV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: WlKeyboardKeyState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_keyboard_grab_v2#{}.key(serial: {}, time: {}, key: {}, state: {:?})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_keyboard_grab_v2",
+                            id,
+                            "key",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("key"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -804,10 +865,20 @@ impl ObjectPrivate for ZwpInputMethodKeyboardGrabV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_keyboard_grab_v2#{}.modifiers(serial: {}, mods_depressed: {}, mods_latched: {}, mods_locked: {}, group: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_keyboard_grab_v2",
+                            id,
+                            "modifiers",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("mods_depressed"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("mods_latched"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("mods_locked"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("group"), LogValue::Uint(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -830,10 +901,17 @@ impl ObjectPrivate for ZwpInputMethodKeyboardGrabV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_keyboard_grab_v2#{}.repeat_info(rate: {}, delay: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_keyboard_grab_v2",
+                            id,
+                            "repeat_info",
+                            &[
+                                LogArg::new(Some("rate"), LogValue::Int(arg0)),
+                                LogArg::new(Some("delay"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -873,6 +951,25 @@ impl ObjectPrivate for ZwpInputMethodKeyboardGrabV2 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uhu",
+            1 => "uuuu",
+            2 => "uuuuu",
+            3 => "ii",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
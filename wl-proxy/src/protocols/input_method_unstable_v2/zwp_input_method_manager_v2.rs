@@ -96,10 +96,17 @@ impl ZwpInputMethodManagerV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_manager_v2#{}.get_input_method(seat: wl_seat#{}, input_method: zwp_input_method_v2#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_manager_v2",
+                    id,
+                    "get_input_method",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                        LogArg::new(Some("input_method"), LogValue::NewId { interface: "zwp_input_method_v2", id: arg1, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -207,10 +214,15 @@ impl ZwpInputMethodManagerV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_manager_v2#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_manager_v2",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -347,10 +359,17 @@ impl ObjectPrivate for ZwpInputMethodManagerV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_manager_v2#{}.get_input_method(seat: wl_seat#{}, input_method: zwp_input_method_v2#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_manager_v2",
+                            id,
+                            "get_input_method",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                                LogArg::new(Some("input_method"), LogValue::NewId { interface: "zwp_input_method_v2", id: arg1, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -382,10 +401,15 @@ impl ObjectPrivate for ZwpInputMethodManagerV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_manager_v2#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_manager_v2",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -437,6 +461,20 @@ impl ObjectPrivate for ZwpInputMethodManagerV2 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "on[zwp_input_method_v2]",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
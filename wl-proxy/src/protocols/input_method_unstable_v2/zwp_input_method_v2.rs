@@ -102,10 +102,15 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v2#{}.activate()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "activate",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -183,10 +188,15 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v2#{}.deactivate()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "deactivate",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -292,10 +302,18 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v2#{}.surrounding_text(text: {:?}, cursor: {}, anchor: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "surrounding_text",
+                    &[
+                        LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("cursor"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("anchor"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -416,10 +434,16 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZwpTextInputV3ChangeCause) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v2#{}.text_change_cause(cause: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "text_change_cause",
+                    &[
+                        LogArg::new(Some("cause"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -513,10 +537,17 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZwpTextInputV3ContentHint, arg1: ZwpTextInputV3ContentPurpose) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v2#{}.content_type(hint: {:?}, purpose: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "content_type",
+                    &[
+                        LogArg::new(Some("hint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("purpose"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -602,10 +633,15 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v2#{}.done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -694,10 +730,16 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_v2#{}.commit_string(text: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "commit_string",
+                    &[
+                        LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -810,10 +852,18 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str, arg1: i32, arg2: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_v2#{}.set_preedit_string(text: {:?}, cursor_begin: {}, cursor_end: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "set_preedit_string",
+                    &[
+                        LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("cursor_begin"), LogValue::Int(arg1)),
+                        LogArg::new(Some("cursor_end"), LogValue::Int(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -932,10 +982,17 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_v2#{}.delete_surrounding_text(before_length: {}, after_length: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "delete_surrounding_text",
+                    &[
+                        LogArg::new(Some("before_length"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("after_length"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -1043,10 +1100,16 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_v2#{}.commit(serial: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "commit",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -1156,10 +1219,17 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_v2#{}.get_input_popup_surface(id: zwp_input_popup_surface_v2#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "get_input_popup_surface",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_popup_surface_v2", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -1303,10 +1373,16 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_v2#{}.grab_keyboard(keyboard: zwp_input_method_keyboard_grab_v2#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "grab_keyboard",
+                    &[
+                        LogArg::new(Some("keyboard"), LogValue::NewId { interface: "zwp_input_method_keyboard_grab_v2", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -1439,10 +1515,15 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_method_v2#{}.unavailable()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "unavailable",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -1505,10 +1586,15 @@ impl ZwpInputMethodV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_method_v2#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_method_v2",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -2115,10 +2201,16 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_v2#{}.commit_string(text: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "commit_string",
+                            &[
+                                LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2149,10 +2241,18 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: i32, arg2: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_v2#{}.set_preedit_string(text: {:?}, cursor_begin: {}, cursor_end: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "set_preedit_string",
+                            &[
+                                LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("cursor_begin"), LogValue::Int(arg1)),
+                                LogArg::new(Some("cursor_end"), LogValue::Int(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -2173,10 +2273,17 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_v2#{}.delete_surrounding_text(before_length: {}, after_length: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "delete_surrounding_text",
+                            &[
+                                LogArg::new(Some("before_length"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("after_length"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2196,10 +2303,16 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_v2#{}.commit(serial: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "commit",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2220,10 +2333,17 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_v2#{}.get_input_popup_surface(id: zwp_input_popup_surface_v2#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "get_input_popup_surface",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_popup_surface_v2", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -2257,10 +2377,16 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_v2#{}.grab_keyboard(keyboard: zwp_input_method_keyboard_grab_v2#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "grab_keyboard",
+                            &[
+                                LogArg::new(Some("keyboard"), LogValue::NewId { interface: "zwp_input_method_keyboard_grab_v2", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -2283,10 +2409,15 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_method_v2#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -2322,10 +2453,15 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v2#{}.activate()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "activate",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -2343,10 +2479,15 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v2#{}.deactivate()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "deactivate",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -2375,10 +2516,18 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v2#{}.surrounding_text(text: {:?}, cursor: {}, anchor: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "surrounding_text",
+                            &[
+                                LogArg::new(Some("text"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("cursor"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("anchor"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -2399,10 +2548,16 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZwpTextInputV3ChangeCause) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v2#{}.text_change_cause(cause: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "text_change_cause",
+                            &[
+                                LogArg::new(Some("cause"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -2425,10 +2580,17 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZwpTextInputV3ContentHint, arg1: ZwpTextInputV3ContentPurpose) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v2#{}.content_type(hint: {:?}, purpose: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "content_type",
+                            &[
+                                LogArg::new(Some("hint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("purpose"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -2446,10 +2608,15 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v2#{}.done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -2467,10 +2634,15 @@ impl ObjectPrivate for ZwpInputMethodV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_method_v2#{}.unavailable()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_method_v2",
+                            id,
+                            "unavailable",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -2519,6 +2691,34 @@ impl ObjectPrivate for ZwpInputMethodV2 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "s",
+            1 => "sii",
+            2 => "uu",
+            3 => "u",
+            4 => "n[zwp_input_popup_surface_v2]o",
+            5 => "n[zwp_input_method_keyboard_grab_v2]",
+            6 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "",
+            2 => "suu",
+            3 => "u",
+            4 => "uu",
+            5 => "",
+            6 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -102,10 +102,19 @@ impl ZwpInputPopupSurfaceV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_input_popup_surface_v2#{}.text_input_rectangle(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_input_popup_surface_v2",
+                    id,
+                    "text_input_rectangle",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -175,10 +184,15 @@ impl ZwpInputPopupSurfaceV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_popup_surface_v2#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_popup_surface_v2",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -308,10 +322,15 @@ impl ObjectPrivate for ZwpInputPopupSurfaceV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_popup_surface_v2#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_popup_surface_v2",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -356,10 +375,19 @@ impl ObjectPrivate for ZwpInputPopupSurfaceV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_input_popup_surface_v2#{}.text_input_rectangle(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_input_popup_surface_v2",
+                            id,
+                            "text_input_rectangle",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -396,6 +424,22 @@ impl ObjectPrivate for ZwpInputPopupSurfaceV2 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "iiii",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
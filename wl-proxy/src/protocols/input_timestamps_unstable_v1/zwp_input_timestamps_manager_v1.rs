@@ -70,10 +70,15 @@ impl ZwpInputTimestampsManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_timestamps_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_timestamps_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -159,10 +164,17 @@ impl ZwpInputTimestampsManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_timestamps_manager_v1#{}.get_keyboard_timestamps(id: zwp_input_timestamps_v1#{}, keyboard: wl_keyboard#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_timestamps_manager_v1",
+                    id,
+                    "get_keyboard_timestamps",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_timestamps_v1", id: arg0, version: None }),
+                        LogArg::new(Some("keyboard"), LogValue::Object { interface: Some("wl_keyboard"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -317,10 +329,17 @@ impl ZwpInputTimestampsManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_timestamps_manager_v1#{}.get_pointer_timestamps(id: zwp_input_timestamps_v1#{}, pointer: wl_pointer#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_timestamps_manager_v1",
+                    id,
+                    "get_pointer_timestamps",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_timestamps_v1", id: arg0, version: None }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -475,10 +494,17 @@ impl ZwpInputTimestampsManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_input_timestamps_manager_v1#{}.get_touch_timestamps(id: zwp_input_timestamps_v1#{}, touch: wl_touch#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_input_timestamps_manager_v1",
+                    id,
+                    "get_touch_timestamps",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_timestamps_v1", id: arg0, version: None }),
+                        LogArg::new(Some("touch"), LogValue::Object { interface: Some("wl_touch"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -761,10 +787,15 @@ impl ObjectPrivate for ZwpInputTimestampsManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_timestamps_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_timestamps_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -786,10 +817,17 @@ impl ObjectPrivate for ZwpInputTimestampsManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_timestamps_manager_v1#{}.get_keyboard_timestamps(id: zwp_input_timestamps_v1#{}, keyboard: wl_keyboard#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_timestamps_manager_v1",
+                            id,
+                            "get_keyboard_timestamps",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_timestamps_v1", id: arg0, version: None }),
+                                LogArg::new(Some("keyboard"), LogValue::Object { interface: Some("wl_keyboard"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -824,10 +862,17 @@ impl ObjectPrivate for ZwpInputTimestampsManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_timestamps_manager_v1#{}.get_pointer_timestamps(id: zwp_input_timestamps_v1#{}, pointer: wl_pointer#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_timestamps_manager_v1",
+                            id,
+                            "get_pointer_timestamps",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_timestamps_v1", id: arg0, version: None }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -862,10 +907,17 @@ impl ObjectPrivate for ZwpInputTimestampsManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_input_timestamps_manager_v1#{}.get_touch_timestamps(id: zwp_input_timestamps_v1#{}, touch: wl_touch#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_input_timestamps_manager_v1",
+                            id,
+                            "get_touch_timestamps",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_input_timestamps_v1", id: arg0, version: None }),
+                                LogArg::new(Some("touch"), LogValue::Object { interface: Some("wl_touch"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -932,6 +984,22 @@ impl ObjectPrivate for ZwpInputTimestampsManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[zwp_input_timestamps_v1]o",
+            2 => "n[zwp_input_timestamps_v1]o",
+            3 => "n[zwp_input_timestamps_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -117,10 +117,18 @@ impl IviApplication {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ivi_application#{}.surface_create(ivi_id: {}, surface: wl_surface#{}, id: ivi_surface#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ivi_application",
+                    id,
+                    "surface_create",
+                    &[
+                        LogArg::new(Some("ivi_id"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "ivi_surface", id: arg2, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1_id, arg2_id);
         }
@@ -378,10 +386,18 @@ impl ObjectPrivate for IviApplication {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ivi_application#{}.surface_create(ivi_id: {}, surface: wl_surface#{}, id: ivi_surface#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ivi_application",
+                            id,
+                            "surface_create",
+                            &[
+                                LogArg::new(Some("ivi_id"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "ivi_surface", id: arg2, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -445,6 +461,19 @@ impl ObjectPrivate for IviApplication {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uon[ivi_surface]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
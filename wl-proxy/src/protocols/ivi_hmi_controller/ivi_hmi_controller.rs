@@ -63,10 +63,15 @@ impl IviHmiController {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ivi_hmi_controller#{}.UI_ready()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ivi_hmi_controller",
+                    id,
+                    "UI_ready",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -143,10 +148,17 @@ impl IviHmiController {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ivi_hmi_controller#{}.workspace_control(seat: wl_seat#{}, serial: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ivi_hmi_controller",
+                    id,
+                    "workspace_control",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                        LogArg::new(Some("serial"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -228,10 +240,16 @@ impl IviHmiController {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ivi_hmi_controller#{}.switch_mode(layout_mode: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ivi_hmi_controller",
+                    id,
+                    "switch_mode",
+                    &[
+                        LogArg::new(Some("layout_mode"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -314,10 +332,16 @@ impl IviHmiController {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= ivi_hmi_controller#{}.home(home: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "ivi_hmi_controller",
+                    id,
+                    "home",
+                    &[
+                        LogArg::new(Some("home"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -399,10 +423,16 @@ impl IviHmiController {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= ivi_hmi_controller#{}.workspace_end_control(is_controlled: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "ivi_hmi_controller",
+                    id,
+                    "workspace_end_control",
+                    &[
+                        LogArg::new(Some("is_controlled"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -623,10 +653,15 @@ impl ObjectPrivate for IviHmiController {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ivi_hmi_controller#{}.UI_ready()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ivi_hmi_controller",
+                            id,
+                            "UI_ready",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -647,10 +682,17 @@ impl ObjectPrivate for IviHmiController {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ivi_hmi_controller#{}.workspace_control(seat: wl_seat#{}, serial: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ivi_hmi_controller",
+                            id,
+                            "workspace_control",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                                LogArg::new(Some("serial"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -679,10 +721,16 @@ impl ObjectPrivate for IviHmiController {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ivi_hmi_controller#{}.switch_mode(layout_mode: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ivi_hmi_controller",
+                            id,
+                            "switch_mode",
+                            &[
+                                LogArg::new(Some("layout_mode"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -702,10 +750,16 @@ impl ObjectPrivate for IviHmiController {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> ivi_hmi_controller#{}.home(home: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "ivi_hmi_controller",
+                            id,
+                            "home",
+                            &[
+                                LogArg::new(Some("home"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -743,10 +797,16 @@ impl ObjectPrivate for IviHmiController {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> ivi_hmi_controller#{}.workspace_end_control(is_controlled: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "ivi_hmi_controller",
+                            id,
+                            "workspace_end_control",
+                            &[
+                                LogArg::new(Some("is_controlled"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -786,6 +846,25 @@ impl ObjectPrivate for IviHmiController {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "ou",
+            2 => "u",
+            3 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "i",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
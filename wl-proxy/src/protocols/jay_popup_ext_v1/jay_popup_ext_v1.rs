@@ -68,10 +68,15 @@ impl JayPopupExtV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= jay_popup_ext_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "jay_popup_ext_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -158,10 +163,17 @@ impl JayPopupExtV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= jay_popup_ext_v1#{}.move(seat: wl_seat#{}, serial: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "jay_popup_ext_v1",
+                    id,
+                    "move",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                        LogArg::new(Some("serial"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -288,10 +300,18 @@ impl JayPopupExtV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: XdgToplevelResizeEdge) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= jay_popup_ext_v1#{}.resize(seat: wl_seat#{}, serial: {}, edges: {:?})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "jay_popup_ext_v1",
+                    id,
+                    "resize",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                        LogArg::new(Some("serial"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("edges"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2);
         }
@@ -536,10 +556,15 @@ impl ObjectPrivate for JayPopupExtV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> jay_popup_ext_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "jay_popup_ext_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -561,10 +586,17 @@ impl ObjectPrivate for JayPopupExtV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> jay_popup_ext_v1#{}.move(seat: wl_seat#{}, serial: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "jay_popup_ext_v1",
+                            id,
+                            "move",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                                LogArg::new(Some("serial"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -596,10 +628,18 @@ impl ObjectPrivate for JayPopupExtV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: XdgToplevelResizeEdge) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> jay_popup_ext_v1#{}.resize(seat: wl_seat#{}, serial: {}, edges: {:?})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "jay_popup_ext_v1",
+                            id,
+                            "resize",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                                LogArg::new(Some("serial"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("edges"), LogValue::Enum { value: arg2.0, debug: &arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -660,6 +700,21 @@ impl ObjectPrivate for JayPopupExtV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "ou",
+            2 => "ouu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -86,10 +86,15 @@ impl JayTrayItemV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= jay_tray_item_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "jay_tray_item_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -157,10 +162,16 @@ impl JayTrayItemV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= jay_tray_item_v1#{}.ack_configure(serial: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "jay_tray_item_v1",
+                    id,
+                    "ack_configure",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -268,10 +279,19 @@ impl JayTrayItemV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: JayTrayItemV1KeyboardFocusHint) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= jay_tray_item_v1#{}.get_popup(popup: xdg_popup#{}, seat: wl_seat#{}, serial: {}, focus_hint: {:?})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "jay_tray_item_v1",
+                    id,
+                    "get_popup",
+                    &[
+                        LogArg::new(Some("popup"), LogValue::Object { interface: Some("xdg_popup"), id: arg0 }),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg1 }),
+                        LogArg::new(Some("serial"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("focus_hint"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2, arg3);
         }
@@ -389,10 +409,17 @@ impl JayTrayItemV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= jay_tray_item_v1#{}.configure_size(width: {}, height: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "jay_tray_item_v1",
+                    id,
+                    "configure_size",
+                    &[
+                        LogArg::new(Some("width"), LogValue::Int(arg0)),
+                        LogArg::new(Some("height"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -488,10 +515,16 @@ impl JayTrayItemV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: XdgPositionerAnchor) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= jay_tray_item_v1#{}.preferred_anchor(anchor: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "jay_tray_item_v1",
+                    id,
+                    "preferred_anchor",
+                    &[
+                        LogArg::new(Some("anchor"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -574,10 +607,16 @@ impl JayTrayItemV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: XdgPositionerGravity) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= jay_tray_item_v1#{}.preferred_gravity(gravity: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "jay_tray_item_v1",
+                    id,
+                    "preferred_gravity",
+                    &[
+                        LogArg::new(Some("gravity"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -657,10 +696,16 @@ impl JayTrayItemV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= jay_tray_item_v1#{}.configure(serial: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "jay_tray_item_v1",
+                    id,
+                    "configure",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -980,10 +1025,15 @@ impl ObjectPrivate for JayTrayItemV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> jay_tray_item_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "jay_tray_item_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1004,10 +1054,16 @@ impl ObjectPrivate for JayTrayItemV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> jay_tray_item_v1#{}.ack_configure(serial: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "jay_tray_item_v1",
+                            id,
+                            "ack_configure",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1031,10 +1087,19 @@ impl ObjectPrivate for JayTrayItemV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: JayTrayItemV1KeyboardFocusHint) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> jay_tray_item_v1#{}.get_popup(popup: xdg_popup#{}, seat: wl_seat#{}, serial: {}, focus_hint: {:?})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "jay_tray_item_v1",
+                            id,
+                            "get_popup",
+                            &[
+                                LogArg::new(Some("popup"), LogValue::Object { interface: Some("xdg_popup"), id: arg0 }),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg1 }),
+                                LogArg::new(Some("serial"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("focus_hint"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1092,10 +1157,17 @@ impl ObjectPrivate for JayTrayItemV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> jay_tray_item_v1#{}.configure_size(width: {}, height: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "jay_tray_item_v1",
+                            id,
+                            "configure_size",
+                            &[
+                                LogArg::new(Some("width"), LogValue::Int(arg0)),
+                                LogArg::new(Some("height"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -1116,10 +1188,16 @@ impl ObjectPrivate for JayTrayItemV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: XdgPositionerAnchor) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> jay_tray_item_v1#{}.preferred_anchor(anchor: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "jay_tray_item_v1",
+                            id,
+                            "preferred_anchor",
+                            &[
+                                LogArg::new(Some("anchor"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1140,10 +1218,16 @@ impl ObjectPrivate for JayTrayItemV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: XdgPositionerGravity) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> jay_tray_item_v1#{}.preferred_gravity(gravity: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "jay_tray_item_v1",
+                            id,
+                            "preferred_gravity",
+                            &[
+                                LogArg::new(Some("gravity"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1163,10 +1247,16 @@ impl ObjectPrivate for JayTrayItemV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> jay_tray_item_v1#{}.configure(serial: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "jay_tray_item_v1",
+                            id,
+                            "configure",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1208,6 +1298,27 @@ impl ObjectPrivate for JayTrayItemV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "u",
+            2 => "oouu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "ii",
+            1 => "u",
+            2 => "u",
+            3 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
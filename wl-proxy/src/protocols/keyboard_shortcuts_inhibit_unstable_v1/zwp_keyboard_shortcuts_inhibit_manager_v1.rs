@@ -67,10 +67,15 @@ impl ZwpKeyboardShortcutsInhibitManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_keyboard_shortcuts_inhibit_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_keyboard_shortcuts_inhibit_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -160,10 +165,18 @@ impl ZwpKeyboardShortcutsInhibitManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_keyboard_shortcuts_inhibit_manager_v1#{}.inhibit_shortcuts(id: zwp_keyboard_shortcuts_inhibitor_v1#{}, surface: wl_surface#{}, seat: wl_seat#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_keyboard_shortcuts_inhibit_manager_v1",
+                    id,
+                    "inhibit_shortcuts",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_keyboard_shortcuts_inhibitor_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2_id);
         }
@@ -371,10 +384,15 @@ impl ObjectPrivate for ZwpKeyboardShortcutsInhibitManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_keyboard_shortcuts_inhibit_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_keyboard_shortcuts_inhibit_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -397,10 +415,18 @@ impl ObjectPrivate for ZwpKeyboardShortcutsInhibitManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_keyboard_shortcuts_inhibit_manager_v1#{}.inhibit_shortcuts(id: zwp_keyboard_shortcuts_inhibitor_v1#{}, surface: wl_surface#{}, seat: wl_seat#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_keyboard_shortcuts_inhibit_manager_v1",
+                            id,
+                            "inhibit_shortcuts",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_keyboard_shortcuts_inhibitor_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -474,6 +500,20 @@ impl ObjectPrivate for ZwpKeyboardShortcutsInhibitManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[zwp_keyboard_shortcuts_inhibitor_v1]oo",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -83,10 +83,15 @@ impl ZwpLinuxBufferParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_buffer_params_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_buffer_params_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -188,10 +193,21 @@ impl ZwpLinuxBufferParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_buffer_params_v1#{}.add(fd: {}, plane_idx: {}, offset: {}, stride: {}, modifier_hi: {}, modifier_lo: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_buffer_params_v1",
+                    id,
+                    "add",
+                    &[
+                        LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                        LogArg::new(Some("plane_idx"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("offset"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("stride"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("modifier_hi"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("modifier_lo"), LogValue::Uint(arg5)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0.as_raw_fd(), arg1, arg2, arg3, arg4, arg5);
         }
@@ -368,10 +384,19 @@ impl ZwpLinuxBufferParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: u32, arg3: ZwpLinuxBufferParamsV1Flags) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_buffer_params_v1#{}.create(width: {}, height: {}, format: {}, flags: {:?})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_buffer_params_v1",
+                    id,
+                    "create",
+                    &[
+                        LogArg::new(Some("width"), LogValue::Int(arg0)),
+                        LogArg::new(Some("height"), LogValue::Int(arg1)),
+                        LogArg::new(Some("format"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("flags"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -521,10 +546,16 @@ impl ZwpLinuxBufferParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_linux_buffer_params_v1#{}.created(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_linux_buffer_params_v1",
+                    id,
+                    "created",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -628,10 +659,15 @@ impl ZwpLinuxBufferParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_linux_buffer_params_v1#{}.failed()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_linux_buffer_params_v1",
+                    id,
+                    "failed",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -740,10 +776,20 @@ impl ZwpLinuxBufferParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: i32, arg3: u32, arg4: ZwpLinuxBufferParamsV1Flags) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_buffer_params_v1#{}.create_immed(buffer_id: wl_buffer#{}, width: {}, height: {}, format: {}, flags: {:?})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_buffer_params_v1",
+                    id,
+                    "create_immed",
+                    &[
+                        LogArg::new(Some("buffer_id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                        LogArg::new(Some("width"), LogValue::Int(arg1)),
+                        LogArg::new(Some("height"), LogValue::Int(arg2)),
+                        LogArg::new(Some("format"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("flags"), LogValue::Enum { value: arg4.0, debug: &arg4 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2, arg3, arg4);
         }
@@ -967,10 +1013,16 @@ impl ZwpLinuxBufferParamsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_buffer_params_v1#{}.set_sampling_device(device: {})\n", id, debug_array(arg0));
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_buffer_params_v1",
+                    id,
+                    "set_sampling_device",
+                    &[
+                        LogArg::new(Some("device"), LogValue::Array(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -1382,10 +1434,15 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_buffer_params_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_buffer_params_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1414,10 +1471,21 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_buffer_params_v1#{}.add(fd: {}, plane_idx: {}, offset: {}, stride: {}, modifier_hi: {}, modifier_lo: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_buffer_params_v1",
+                            id,
+                            "add",
+                            &[
+                                LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                                LogArg::new(Some("plane_idx"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("offset"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("stride"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("modifier_hi"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("modifier_lo"), LogValue::Uint(arg5)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0.as_raw_fd(), arg1, arg2, arg3, arg4, arg5);
                 }
@@ -1443,10 +1511,19 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: u32, arg3: ZwpLinuxBufferParamsV1Flags) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_buffer_params_v1#{}.create(width: {}, height: {}, format: {}, flags: {:?})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_buffer_params_v1",
+                            id,
+                            "create",
+                            &[
+                                LogArg::new(Some("width"), LogValue::Int(arg0)),
+                                LogArg::new(Some("height"), LogValue::Int(arg1)),
+                                LogArg::new(Some("format"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("flags"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1473,10 +1550,20 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: i32, arg3: u32, arg4: ZwpLinuxBufferParamsV1Flags) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_buffer_params_v1#{}.create_immed(buffer_id: wl_buffer#{}, width: {}, height: {}, format: {}, flags: {:?})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_buffer_params_v1",
+                            id,
+                            "create_immed",
+                            &[
+                                LogArg::new(Some("buffer_id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                                LogArg::new(Some("width"), LogValue::Int(arg1)),
+                                LogArg::new(Some("height"), LogValue::Int(arg2)),
+                                LogArg::new(Some("format"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("flags"), LogValue::Enum { value: arg4.0, debug: &arg4 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -1502,10 +1589,16 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_buffer_params_v1#{}.set_sampling_device(device: {})\n", client_id, id, debug_array(arg0));
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_buffer_params_v1",
+                            id,
+                            "set_sampling_device",
+                            &[
+                                LogArg::new(Some("device"), LogValue::Array(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1542,10 +1635,16 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_linux_buffer_params_v1#{}.created(buffer: wl_buffer#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_linux_buffer_params_v1",
+                            id,
+                            "created",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1568,10 +1667,15 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_linux_buffer_params_v1#{}.failed()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_linux_buffer_params_v1",
+                            id,
+                            "failed",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1613,6 +1717,27 @@ impl ObjectPrivate for ZwpLinuxBufferParamsV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "huuuuu",
+            2 => "iiuu",
+            3 => "n[wl_buffer]iiuu",
+            4 => "a",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[wl_buffer]",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
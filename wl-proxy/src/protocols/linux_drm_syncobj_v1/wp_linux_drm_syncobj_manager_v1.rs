@@ -71,10 +71,15 @@ impl WpLinuxDrmSyncobjManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_linux_drm_syncobj_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_linux_drm_syncobj_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -162,10 +167,17 @@ impl WpLinuxDrmSyncobjManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_linux_drm_syncobj_manager_v1#{}.get_surface(id: wp_linux_drm_syncobj_surface_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_linux_drm_syncobj_manager_v1",
+                    id,
+                    "get_surface",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_linux_drm_syncobj_surface_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -319,10 +331,17 @@ impl WpLinuxDrmSyncobjManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_linux_drm_syncobj_manager_v1#{}.import_timeline(id: wp_linux_drm_syncobj_timeline_v1#{}, fd: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_linux_drm_syncobj_manager_v1",
+                    id,
+                    "import_timeline",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wp_linux_drm_syncobj_timeline_v1", id: arg0, version: None }),
+                        LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1.as_raw_fd());
         }
@@ -547,10 +566,15 @@ impl ObjectPrivate for WpLinuxDrmSyncobjManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_linux_drm_syncobj_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_linux_drm_syncobj_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -572,10 +596,17 @@ impl ObjectPrivate for WpLinuxDrmSyncobjManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_linux_drm_syncobj_manager_v1#{}.get_surface(id: wp_linux_drm_syncobj_surface_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_linux_drm_syncobj_manager_v1",
+                            id,
+                            "get_surface",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_linux_drm_syncobj_surface_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -613,10 +644,17 @@ impl ObjectPrivate for WpLinuxDrmSyncobjManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_linux_drm_syncobj_manager_v1#{}.import_timeline(id: wp_linux_drm_syncobj_timeline_v1#{}, fd: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_linux_drm_syncobj_manager_v1",
+                            id,
+                            "import_timeline",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wp_linux_drm_syncobj_timeline_v1", id: arg0, version: None }),
+                                LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1.as_raw_fd());
                 }
@@ -673,6 +711,21 @@ impl ObjectPrivate for WpLinuxDrmSyncobjManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wp_linux_drm_syncobj_surface_v1]o",
+            2 => "n[wp_linux_drm_syncobj_timeline_v1]h",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
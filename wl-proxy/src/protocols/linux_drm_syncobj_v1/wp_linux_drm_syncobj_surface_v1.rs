@@ -96,10 +96,15 @@ impl WpLinuxDrmSyncobjSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_linux_drm_syncobj_surface_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_linux_drm_syncobj_surface_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -201,10 +206,18 @@ impl WpLinuxDrmSyncobjSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_linux_drm_syncobj_surface_v1#{}.set_acquire_point(timeline: wp_linux_drm_syncobj_timeline_v1#{}, point_hi: {}, point_lo: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_linux_drm_syncobj_surface_v1",
+                    id,
+                    "set_acquire_point",
+                    &[
+                        LogArg::new(Some("timeline"), LogValue::Object { interface: Some("wp_linux_drm_syncobj_timeline_v1"), id: arg0 }),
+                        LogArg::new(Some("point_hi"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("point_lo"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2);
         }
@@ -357,10 +370,18 @@ impl WpLinuxDrmSyncobjSurfaceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_linux_drm_syncobj_surface_v1#{}.set_release_point(timeline: wp_linux_drm_syncobj_timeline_v1#{}, point_hi: {}, point_lo: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_linux_drm_syncobj_surface_v1",
+                    id,
+                    "set_release_point",
+                    &[
+                        LogArg::new(Some("timeline"), LogValue::Object { interface: Some("wp_linux_drm_syncobj_timeline_v1"), id: arg0 }),
+                        LogArg::new(Some("point_hi"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("point_lo"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2);
         }
@@ -649,10 +670,15 @@ impl ObjectPrivate for WpLinuxDrmSyncobjSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_linux_drm_syncobj_surface_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_linux_drm_syncobj_surface_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -675,10 +701,18 @@ impl ObjectPrivate for WpLinuxDrmSyncobjSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_linux_drm_syncobj_surface_v1#{}.set_acquire_point(timeline: wp_linux_drm_syncobj_timeline_v1#{}, point_hi: {}, point_lo: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_linux_drm_syncobj_surface_v1",
+                            id,
+                            "set_acquire_point",
+                            &[
+                                LogArg::new(Some("timeline"), LogValue::Object { interface: Some("wp_linux_drm_syncobj_timeline_v1"), id: arg0 }),
+                                LogArg::new(Some("point_hi"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("point_lo"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -709,10 +743,18 @@ impl ObjectPrivate for WpLinuxDrmSyncobjSurfaceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_linux_drm_syncobj_surface_v1#{}.set_release_point(timeline: wp_linux_drm_syncobj_timeline_v1#{}, point_hi: {}, point_lo: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_linux_drm_syncobj_surface_v1",
+                            id,
+                            "set_release_point",
+                            &[
+                                LogArg::new(Some("timeline"), LogValue::Object { interface: Some("wp_linux_drm_syncobj_timeline_v1"), id: arg0 }),
+                                LogArg::new(Some("point_hi"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("point_lo"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -773,6 +815,21 @@ impl ObjectPrivate for WpLinuxDrmSyncobjSurfaceV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "ouu",
+            2 => "ouu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
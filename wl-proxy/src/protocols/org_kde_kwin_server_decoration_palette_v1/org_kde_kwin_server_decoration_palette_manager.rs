@@ -87,10 +87,17 @@ impl OrgKdeKwinServerDecorationPaletteManager {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_server_decoration_palette_manager#{}.create(id: org_kde_kwin_server_decoration_palette#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_server_decoration_palette_manager",
+                    id,
+                    "create",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "org_kde_kwin_server_decoration_palette", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -238,10 +245,17 @@ impl ObjectPrivate for OrgKdeKwinServerDecorationPaletteManager {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_server_decoration_palette_manager#{}.create(id: org_kde_kwin_server_decoration_palette#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_server_decoration_palette_manager",
+                            id,
+                            "create",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "org_kde_kwin_server_decoration_palette", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -305,6 +319,19 @@ impl ObjectPrivate for OrgKdeKwinServerDecorationPaletteManager {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[org_kde_kwin_server_decoration_palette]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -60,10 +60,15 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.commit()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "commit",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -123,10 +128,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_left(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_left",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -192,10 +203,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_top_left(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_top_left",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -261,10 +278,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_top(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_top",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -330,10 +353,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_top_right(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_top_right",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -399,10 +428,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_right(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_right",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -468,10 +503,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_bottom_right(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_bottom_right",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -537,10 +578,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_bottom(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_bottom",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -606,10 +653,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.attach_bottom_left(buffer: wl_buffer#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "attach_bottom_left",
+                    &[
+                        LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -670,10 +723,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.set_left_offset(offset: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "set_left_offset",
+                    &[
+                        LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -734,10 +793,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.set_top_offset(offset: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "set_top_offset",
+                    &[
+                        LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -798,10 +863,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.set_right_offset(offset: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "set_right_offset",
+                    &[
+                        LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -862,10 +933,16 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.set_bottom_offset(offset: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "set_bottom_offset",
+                    &[
+                        LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -923,10 +1000,15 @@ impl OrgKdeKwinShadow {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= org_kde_kwin_shadow#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "org_kde_kwin_shadow",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -1310,10 +1392,15 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.commit()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "commit",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1333,10 +1420,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_left(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_left",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1365,10 +1458,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_top_left(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_top_left",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1397,10 +1496,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_top(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_top",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1429,10 +1534,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_top_right(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_top_right",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1461,10 +1572,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_right(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_right",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1493,10 +1610,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_bottom_right(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_bottom_right",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1525,10 +1648,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_bottom(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_bottom",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1557,10 +1686,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.attach_bottom_left(buffer: wl_buffer#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "attach_bottom_left",
+                            &[
+                                LogArg::new(Some("buffer"), LogValue::Object { interface: Some("wl_buffer"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1590,10 +1725,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.set_left_offset(offset: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "set_left_offset",
+                            &[
+                                LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1614,10 +1755,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.set_top_offset(offset: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "set_top_offset",
+                            &[
+                                LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1638,10 +1785,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.set_right_offset(offset: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "set_right_offset",
+                            &[
+                                LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1662,10 +1815,16 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.set_bottom_offset(offset: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "set_bottom_offset",
+                            &[
+                                LogArg::new(Some("offset"), LogValue::Fixed(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1683,10 +1842,15 @@ impl ObjectPrivate for OrgKdeKwinShadow {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> org_kde_kwin_shadow#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "org_kde_kwin_shadow",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1750,6 +1914,32 @@ impl ObjectPrivate for OrgKdeKwinShadow {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "o",
+            2 => "o",
+            3 => "o",
+            4 => "o",
+            5 => "o",
+            6 => "o",
+            7 => "o",
+            8 => "o",
+            9 => "f",
+            10 => "f",
+            11 => "f",
+            12 => "f",
+            13 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
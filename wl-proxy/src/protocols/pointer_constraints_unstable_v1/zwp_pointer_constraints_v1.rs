@@ -80,10 +80,15 @@ impl ZwpPointerConstraintsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_constraints_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_constraints_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -219,10 +224,20 @@ impl ZwpPointerConstraintsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: ZwpPointerConstraintsV1Lifetime) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_constraints_v1#{}.lock_pointer(id: zwp_locked_pointer_v1#{}, surface: wl_surface#{}, pointer: wl_pointer#{}, region: wl_region#{}, lifetime: {:?})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_constraints_v1",
+                    id,
+                    "lock_pointer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_locked_pointer_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg2 }),
+                        LogArg::new(Some("region"), LogValue::Object { interface: Some("wl_region"), id: arg3 }),
+                        LogArg::new(Some("lifetime"), LogValue::Enum { value: arg4.0, debug: &arg4 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2_id, arg3_id, arg4);
         }
@@ -519,10 +534,20 @@ impl ZwpPointerConstraintsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: ZwpPointerConstraintsV1Lifetime) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_constraints_v1#{}.confine_pointer(id: zwp_confined_pointer_v1#{}, surface: wl_surface#{}, pointer: wl_pointer#{}, region: wl_region#{}, lifetime: {:?})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_constraints_v1",
+                    id,
+                    "confine_pointer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_confined_pointer_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg2 }),
+                        LogArg::new(Some("region"), LogValue::Object { interface: Some("wl_region"), id: arg3 }),
+                        LogArg::new(Some("lifetime"), LogValue::Enum { value: arg4.0, debug: &arg4 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2_id, arg3_id, arg4);
         }
@@ -877,10 +902,15 @@ impl ObjectPrivate for ZwpPointerConstraintsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_constraints_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_constraints_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -906,10 +936,20 @@ impl ObjectPrivate for ZwpPointerConstraintsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: ZwpPointerConstraintsV1Lifetime) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_constraints_v1#{}.lock_pointer(id: zwp_locked_pointer_v1#{}, surface: wl_surface#{}, pointer: wl_pointer#{}, region: wl_region#{}, lifetime: {:?})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_constraints_v1",
+                            id,
+                            "lock_pointer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_locked_pointer_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg2 }),
+                                LogArg::new(Some("region"), LogValue::Object { interface: Some("wl_region"), id: arg3 }),
+                                LogArg::new(Some("lifetime"), LogValue::Enum { value: arg4.0, debug: &arg4 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -971,10 +1011,20 @@ impl ObjectPrivate for ZwpPointerConstraintsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: ZwpPointerConstraintsV1Lifetime) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_constraints_v1#{}.confine_pointer(id: zwp_confined_pointer_v1#{}, surface: wl_surface#{}, pointer: wl_pointer#{}, region: wl_region#{}, lifetime: {:?})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_constraints_v1",
+                            id,
+                            "confine_pointer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_confined_pointer_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg2 }),
+                                LogArg::new(Some("region"), LogValue::Object { interface: Some("wl_region"), id: arg3 }),
+                                LogArg::new(Some("lifetime"), LogValue::Enum { value: arg4.0, debug: &arg4 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -1063,6 +1113,21 @@ impl ObjectPrivate for ZwpPointerConstraintsV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[zwp_locked_pointer_v1]oo?ou",
+            2 => "n[zwp_confined_pointer_v1]oo?ou",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -79,10 +79,15 @@ impl ZwpPointerGesturePinchV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_gesture_pinch_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_gesture_pinch_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -163,10 +168,19 @@ impl ZwpPointerGesturePinchV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_pointer_gesture_pinch_v1#{}.begin(serial: {}, time: {}, surface: wl_surface#{}, fingers: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_pointer_gesture_pinch_v1",
+                    id,
+                    "begin",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg2 }),
+                        LogArg::new(Some("fingers"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2_id, arg3);
         }
@@ -275,10 +289,20 @@ impl ZwpPointerGesturePinchV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed, arg3: Fixed, arg4: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_pointer_gesture_pinch_v1#{}.update(time: {}, dx: {}, dy: {}, scale: {}, rotation: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_pointer_gesture_pinch_v1",
+                    id,
+                    "update",
+                    &[
+                        LogArg::new(Some("time"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("dx"), LogValue::Fixed(arg1)),
+                        LogArg::new(Some("dy"), LogValue::Fixed(arg2)),
+                        LogArg::new(Some("scale"), LogValue::Fixed(arg3)),
+                        LogArg::new(Some("rotation"), LogValue::Fixed(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -388,10 +412,18 @@ impl ZwpPointerGesturePinchV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_pointer_gesture_pinch_v1#{}.end(serial: {}, time: {}, cancelled: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_pointer_gesture_pinch_v1",
+                    id,
+                    "end",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("cancelled"), LogValue::Int(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -633,10 +665,15 @@ impl ObjectPrivate for ZwpPointerGesturePinchV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_gesture_pinch_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_gesture_pinch_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -677,10 +714,19 @@ impl ObjectPrivate for ZwpPointerGesturePinchV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_pointer_gesture_pinch_v1#{}.begin(serial: {}, time: {}, surface: wl_surface#{}, fingers: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_pointer_gesture_pinch_v1",
+                            id,
+                            "begin",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg2 }),
+                                LogArg::new(Some("fingers"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -717,10 +763,20 @@ impl ObjectPrivate for ZwpPointerGesturePinchV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed, arg3: Fixed, arg4: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_pointer_gesture_pinch_v1#{}.update(time: {}, dx: {}, dy: {}, scale: {}, rotation: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_pointer_gesture_pinch_v1",
+                            id,
+                            "update",
+                            &[
+                                LogArg::new(Some("time"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("dx"), LogValue::Fixed(arg1)),
+                                LogArg::new(Some("dy"), LogValue::Fixed(arg2)),
+                                LogArg::new(Some("scale"), LogValue::Fixed(arg3)),
+                                LogArg::new(Some("rotation"), LogValue::Fixed(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -743,10 +799,18 @@ impl ObjectPrivate for ZwpPointerGesturePinchV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_pointer_gesture_pinch_v1#{}.end(serial: {}, time: {}, cancelled: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_pointer_gesture_pinch_v1",
+                            id,
+                            "end",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("cancelled"), LogValue::Int(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -785,6 +849,24 @@ impl ObjectPrivate for ZwpPointerGesturePinchV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuou",
+            1 => "uffff",
+            2 => "uui",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -79,10 +79,15 @@ impl ZwpPointerGestureSwipeV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_gesture_swipe_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_gesture_swipe_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -163,10 +168,19 @@ impl ZwpPointerGestureSwipeV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_pointer_gesture_swipe_v1#{}.begin(serial: {}, time: {}, surface: wl_surface#{}, fingers: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_pointer_gesture_swipe_v1",
+                    id,
+                    "begin",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg2 }),
+                        LogArg::new(Some("fingers"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2_id, arg3);
         }
@@ -260,10 +274,18 @@ impl ZwpPointerGestureSwipeV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_pointer_gesture_swipe_v1#{}.update(time: {}, dx: {}, dy: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_pointer_gesture_swipe_v1",
+                    id,
+                    "update",
+                    &[
+                        LogArg::new(Some("time"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("dx"), LogValue::Fixed(arg1)),
+                        LogArg::new(Some("dy"), LogValue::Fixed(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -358,10 +380,18 @@ impl ZwpPointerGestureSwipeV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_pointer_gesture_swipe_v1#{}.end(serial: {}, time: {}, cancelled: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_pointer_gesture_swipe_v1",
+                    id,
+                    "end",
+                    &[
+                        LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("cancelled"), LogValue::Int(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -590,10 +620,15 @@ impl ObjectPrivate for ZwpPointerGestureSwipeV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_gesture_swipe_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_gesture_swipe_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -634,10 +669,19 @@ impl ObjectPrivate for ZwpPointerGestureSwipeV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_pointer_gesture_swipe_v1#{}.begin(serial: {}, time: {}, surface: wl_surface#{}, fingers: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_pointer_gesture_swipe_v1",
+                            id,
+                            "begin",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg2 }),
+                                LogArg::new(Some("fingers"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -670,10 +714,18 @@ impl ObjectPrivate for ZwpPointerGestureSwipeV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: Fixed, arg2: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_pointer_gesture_swipe_v1#{}.update(time: {}, dx: {}, dy: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_pointer_gesture_swipe_v1",
+                            id,
+                            "update",
+                            &[
+                                LogArg::new(Some("time"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("dx"), LogValue::Fixed(arg1)),
+                                LogArg::new(Some("dy"), LogValue::Fixed(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -696,10 +748,18 @@ impl ObjectPrivate for ZwpPointerGestureSwipeV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_pointer_gesture_swipe_v1#{}.end(serial: {}, time: {}, cancelled: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_pointer_gesture_swipe_v1",
+                            id,
+                            "end",
+                            &[
+                                LogArg::new(Some("serial"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("time"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("cancelled"), LogValue::Int(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -738,6 +798,24 @@ impl ObjectPrivate for ZwpPointerGestureSwipeV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuou",
+            1 => "uff",
+            2 => "uui",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
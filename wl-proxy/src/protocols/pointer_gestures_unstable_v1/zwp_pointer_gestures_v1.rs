@@ -107,10 +107,17 @@ impl ZwpPointerGesturesV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_gestures_v1#{}.get_swipe_gesture(id: zwp_pointer_gesture_swipe_v1#{}, pointer: wl_pointer#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_gestures_v1",
+                    id,
+                    "get_swipe_gesture",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_pointer_gesture_swipe_v1", id: arg0, version: None }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -241,10 +248,17 @@ impl ZwpPointerGesturesV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_gestures_v1#{}.get_pinch_gesture(id: zwp_pointer_gesture_pinch_v1#{}, pointer: wl_pointer#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_gestures_v1",
+                    id,
+                    "get_pinch_gesture",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_pointer_gesture_pinch_v1", id: arg0, version: None }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -351,10 +365,15 @@ impl ZwpPointerGesturesV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_gestures_v1#{}.release()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_gestures_v1",
+                    id,
+                    "release",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -433,10 +452,17 @@ impl ZwpPointerGesturesV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_pointer_gestures_v1#{}.get_hold_gesture(id: zwp_pointer_gesture_hold_v1#{}, pointer: wl_pointer#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_pointer_gestures_v1",
+                    id,
+                    "get_hold_gesture",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_pointer_gesture_hold_v1", id: arg0, version: None }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -685,10 +711,17 @@ impl ObjectPrivate for ZwpPointerGesturesV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_gestures_v1#{}.get_swipe_gesture(id: zwp_pointer_gesture_swipe_v1#{}, pointer: wl_pointer#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_gestures_v1",
+                            id,
+                            "get_swipe_gesture",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_pointer_gesture_swipe_v1", id: arg0, version: None }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -723,10 +756,17 @@ impl ObjectPrivate for ZwpPointerGesturesV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_gestures_v1#{}.get_pinch_gesture(id: zwp_pointer_gesture_pinch_v1#{}, pointer: wl_pointer#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_gestures_v1",
+                            id,
+                            "get_pinch_gesture",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_pointer_gesture_pinch_v1", id: arg0, version: None }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -758,10 +798,15 @@ impl ObjectPrivate for ZwpPointerGesturesV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_gestures_v1#{}.release()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_gestures_v1",
+                            id,
+                            "release",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -783,10 +828,17 @@ impl ObjectPrivate for ZwpPointerGesturesV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_pointer_gestures_v1#{}.get_hold_gesture(id: zwp_pointer_gesture_hold_v1#{}, pointer: wl_pointer#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_pointer_gestures_v1",
+                            id,
+                            "get_hold_gesture",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_pointer_gesture_hold_v1", id: arg0, version: None }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -853,6 +905,22 @@ impl ObjectPrivate for ZwpPointerGesturesV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwp_pointer_gesture_swipe_v1]o",
+            1 => "n[zwp_pointer_gesture_pinch_v1]o",
+            2 => "",
+            3 => "n[zwp_pointer_gesture_hold_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
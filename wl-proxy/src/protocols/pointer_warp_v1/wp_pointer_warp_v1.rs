@@ -78,10 +78,15 @@ impl WpPointerWarpV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_pointer_warp_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_pointer_warp_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -179,10 +184,20 @@ impl WpPointerWarpV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: Fixed, arg3: Fixed, arg4: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_pointer_warp_v1#{}.warp_pointer(surface: wl_surface#{}, pointer: wl_pointer#{}, x: {}, y: {}, serial: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_pointer_warp_v1",
+                    id,
+                    "warp_pointer",
+                    &[
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                        LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                        LogArg::new(Some("x"), LogValue::Fixed(arg2)),
+                        LogArg::new(Some("y"), LogValue::Fixed(arg3)),
+                        LogArg::new(Some("serial"), LogValue::Uint(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2, arg3, arg4);
         }
@@ -360,10 +375,15 @@ impl ObjectPrivate for WpPointerWarpV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_pointer_warp_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_pointer_warp_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -390,10 +410,20 @@ impl ObjectPrivate for WpPointerWarpV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: Fixed, arg3: Fixed, arg4: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_pointer_warp_v1#{}.warp_pointer(surface: wl_surface#{}, pointer: wl_pointer#{}, x: {}, y: {}, serial: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_pointer_warp_v1",
+                            id,
+                            "warp_pointer",
+                            &[
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                                LogArg::new(Some("pointer"), LogValue::Object { interface: Some("wl_pointer"), id: arg1 }),
+                                LogArg::new(Some("x"), LogValue::Fixed(arg2)),
+                                LogArg::new(Some("y"), LogValue::Fixed(arg3)),
+                                LogArg::new(Some("serial"), LogValue::Uint(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -462,6 +492,20 @@ impl ObjectPrivate for WpPointerWarpV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "ooffu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -101,10 +101,16 @@ impl WpPresentationFeedback {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_presentation_feedback#{}.sync_output(output: wl_output#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_presentation_feedback",
+                    id,
+                    "sync_output",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -247,10 +253,22 @@ impl WpPresentationFeedback {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: u32, arg6: WpPresentationFeedbackKind) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_presentation_feedback#{}.presented(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, refresh: {}, seq_hi: {}, seq_lo: {}, flags: {:?})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_presentation_feedback",
+                    id,
+                    "presented",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("refresh"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("seq_hi"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("seq_lo"), LogValue::Uint(arg5)),
+                        LogArg::new(Some("flags"), LogValue::Enum { value: arg6.0, debug: &arg6 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
         }
@@ -380,10 +398,15 @@ impl WpPresentationFeedback {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wp_presentation_feedback#{}.discarded()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wp_presentation_feedback",
+                    id,
+                    "discarded",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -627,10 +650,16 @@ impl ObjectPrivate for WpPresentationFeedback {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_presentation_feedback#{}.sync_output(output: wl_output#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_presentation_feedback",
+                            id,
+                            "sync_output",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -666,10 +695,22 @@ impl ObjectPrivate for WpPresentationFeedback {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: u32, arg6: WpPresentationFeedbackKind) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_presentation_feedback#{}.presented(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, refresh: {}, seq_hi: {}, seq_lo: {}, flags: {:?})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_presentation_feedback",
+                            id,
+                            "presented",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("refresh"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("seq_hi"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("seq_lo"), LogValue::Uint(arg5)),
+                                LogArg::new(Some("flags"), LogValue::Enum { value: arg6.0, debug: &arg6 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6);
                 }
@@ -688,10 +729,15 @@ impl ObjectPrivate for WpPresentationFeedback {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wp_presentation_feedback#{}.discarded()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wp_presentation_feedback",
+                            id,
+                            "discarded",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -728,6 +774,21 @@ impl ObjectPrivate for WpPresentationFeedback {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "o",
+            1 => "uuuuuuu",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
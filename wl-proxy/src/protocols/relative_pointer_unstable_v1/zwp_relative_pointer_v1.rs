@@ -68,10 +68,15 @@ impl ZwpRelativePointerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_relative_pointer_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_relative_pointer_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -183,10 +188,21 @@ impl ZwpRelativePointerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: Fixed, arg3: Fixed, arg4: Fixed, arg5: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwp_relative_pointer_v1#{}.relative_motion(utime_hi: {}, utime_lo: {}, dx: {}, dy: {}, dx_unaccel: {}, dy_unaccel: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwp_relative_pointer_v1",
+                    id,
+                    "relative_motion",
+                    &[
+                        LogArg::new(Some("utime_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("utime_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("dx"), LogValue::Fixed(arg2)),
+                        LogArg::new(Some("dy"), LogValue::Fixed(arg3)),
+                        LogArg::new(Some("dx_unaccel"), LogValue::Fixed(arg4)),
+                        LogArg::new(Some("dy_unaccel"), LogValue::Fixed(arg5)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4, arg5);
         }
@@ -404,10 +420,15 @@ impl ObjectPrivate for ZwpRelativePointerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_relative_pointer_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_relative_pointer_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -454,10 +475,21 @@ impl ObjectPrivate for ZwpRelativePointerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: Fixed, arg3: Fixed, arg4: Fixed, arg5: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwp_relative_pointer_v1#{}.relative_motion(utime_hi: {}, utime_lo: {}, dx: {}, dy: {}, dx_unaccel: {}, dy_unaccel: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwp_relative_pointer_v1",
+                            id,
+                            "relative_motion",
+                            &[
+                                LogArg::new(Some("utime_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("utime_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("dx"), LogValue::Fixed(arg2)),
+                                LogArg::new(Some("dy"), LogValue::Fixed(arg3)),
+                                LogArg::new(Some("dx_unaccel"), LogValue::Fixed(arg4)),
+                                LogArg::new(Some("dy_unaccel"), LogValue::Fixed(arg5)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4, arg5);
                 }
@@ -494,6 +526,22 @@ impl ObjectPrivate for ZwpRelativePointerV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuffff",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -70,10 +70,15 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_input_device_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -134,10 +139,15 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_input_device_v1#{}.removed()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "removed",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -206,10 +216,16 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverInputDeviceV1Type) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_input_device_v1#{}.type(type: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "type",
+                    &[
+                        LogArg::new(Some("type"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -282,10 +298,16 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_input_device_v1#{}.name(name: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "name",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -357,10 +379,16 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_input_device_v1#{}.assign_to_seat(name: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "assign_to_seat",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -440,10 +468,17 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_input_device_v1#{}.set_repeat_info(rate: {}, delay: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "set_repeat_info",
+                    &[
+                        LogArg::new(Some("rate"), LogValue::Int(arg0)),
+                        LogArg::new(Some("delay"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -526,10 +561,16 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_input_device_v1#{}.set_scroll_factor(factor: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "set_scroll_factor",
+                    &[
+                        LogArg::new(Some("factor"), LogValue::Fixed(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -617,10 +658,16 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_input_device_v1#{}.map_to_output(output: wl_output#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "map_to_output",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -714,10 +761,19 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_input_device_v1#{}.map_to_rectangle(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "map_to_rectangle",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -802,10 +858,15 @@ impl RiverInputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_input_device_v1#{}.done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_input_device_v1",
+                    id,
+                    "done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -1164,10 +1225,15 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_input_device_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1189,10 +1255,16 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_input_device_v1#{}.assign_to_seat(name: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "assign_to_seat",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1215,10 +1287,17 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_input_device_v1#{}.set_repeat_info(rate: {}, delay: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "set_repeat_info",
+                            &[
+                                LogArg::new(Some("rate"), LogValue::Int(arg0)),
+                                LogArg::new(Some("delay"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -1239,10 +1318,16 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_input_device_v1#{}.set_scroll_factor(factor: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "set_scroll_factor",
+                            &[
+                                LogArg::new(Some("factor"), LogValue::Fixed(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1262,10 +1347,16 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_input_device_v1#{}.map_to_output(output: wl_output#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "map_to_output",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1306,10 +1397,19 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_input_device_v1#{}.map_to_rectangle(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "map_to_rectangle",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -1344,10 +1444,15 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_input_device_v1#{}.removed()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "removed",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1368,10 +1473,16 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverInputDeviceV1Type) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_input_device_v1#{}.type(type: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "type",
+                            &[
+                                LogArg::new(Some("type"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1392,10 +1503,16 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_input_device_v1#{}.name(name: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "name",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1413,10 +1530,15 @@ impl ObjectPrivate for RiverInputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_input_device_v1#{}.done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_input_device_v1",
+                            id,
+                            "done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1461,6 +1583,30 @@ impl ObjectPrivate for RiverInputDeviceV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "s",
+            2 => "ii",
+            3 => "f",
+            4 => "?o",
+            5 => "iiii",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "u",
+            2 => "s",
+            3 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
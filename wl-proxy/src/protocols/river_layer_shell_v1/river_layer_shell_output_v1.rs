@@ -73,10 +73,15 @@ impl RiverLayerShellOutputV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_layer_shell_output_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_layer_shell_output_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -165,10 +170,19 @@ impl RiverLayerShellOutputV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_layer_shell_output_v1#{}.non_exclusive_area(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_layer_shell_output_v1",
+                    id,
+                    "non_exclusive_area",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -253,10 +267,15 @@ impl RiverLayerShellOutputV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_layer_shell_output_v1#{}.set_default()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_layer_shell_output_v1",
+                    id,
+                    "set_default",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -433,10 +452,15 @@ impl ObjectPrivate for RiverLayerShellOutputV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_layer_shell_output_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_layer_shell_output_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -455,10 +479,15 @@ impl ObjectPrivate for RiverLayerShellOutputV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_layer_shell_output_v1#{}.set_default()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_layer_shell_output_v1",
+                            id,
+                            "set_default",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -502,10 +531,19 @@ impl ObjectPrivate for RiverLayerShellOutputV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_layer_shell_output_v1#{}.non_exclusive_area(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_layer_shell_output_v1",
+                            id,
+                            "non_exclusive_area",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -543,6 +581,23 @@ impl ObjectPrivate for RiverLayerShellOutputV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "iiii",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -74,10 +74,15 @@ impl RiverLayerShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_layer_shell_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_layer_shell_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -156,10 +161,17 @@ impl RiverLayerShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_layer_shell_v1#{}.get_output(id: river_layer_shell_output_v1#{}, output: river_output_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_layer_shell_v1",
+                    id,
+                    "get_output",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "river_layer_shell_output_v1", id: arg0, version: None }),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("river_output_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -290,10 +302,17 @@ impl RiverLayerShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_layer_shell_v1#{}.get_seat(id: river_layer_shell_seat_v1#{}, seat: river_seat_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_layer_shell_v1",
+                    id,
+                    "get_seat",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "river_layer_shell_seat_v1", id: arg0, version: None }),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -508,10 +527,15 @@ impl ObjectPrivate for RiverLayerShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_layer_shell_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_layer_shell_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -533,10 +557,17 @@ impl ObjectPrivate for RiverLayerShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_layer_shell_v1#{}.get_output(id: river_layer_shell_output_v1#{}, output: river_output_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_layer_shell_v1",
+                            id,
+                            "get_output",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "river_layer_shell_output_v1", id: arg0, version: None }),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("river_output_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -571,10 +602,17 @@ impl ObjectPrivate for RiverLayerShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_layer_shell_v1#{}.get_seat(id: river_layer_shell_seat_v1#{}, seat: river_seat_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_layer_shell_v1",
+                            id,
+                            "get_seat",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "river_layer_shell_seat_v1", id: arg0, version: None }),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -640,6 +678,21 @@ impl ObjectPrivate for RiverLayerShellV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[river_layer_shell_output_v1]o",
+            2 => "n[river_layer_shell_seat_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
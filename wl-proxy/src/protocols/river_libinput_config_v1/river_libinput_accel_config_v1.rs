@@ -68,10 +68,15 @@ impl RiverLibinputAccelConfigV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_accel_config_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_accel_config_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -153,10 +158,19 @@ impl RiverLibinputAccelConfigV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputAccelConfigV1AccelType, arg2: &[u8], arg3: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_accel_config_v1#{}.set_points(result: river_libinput_result_v1#{}, type: {:?}, step: {}, points: {})\n", id, arg0, arg1, debug_array(arg2), debug_array(arg3));
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_accel_config_v1",
+                    id,
+                    "set_points",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("type"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                        LogArg::new(Some("step"), LogValue::Array(arg2)),
+                        LogArg::new(Some("points"), LogValue::Array(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2, arg3);
         }
@@ -363,10 +377,15 @@ impl ObjectPrivate for RiverLibinputAccelConfigV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_accel_config_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_accel_config_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -399,10 +418,19 @@ impl ObjectPrivate for RiverLibinputAccelConfigV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputAccelConfigV1AccelType, arg2: &[u8], arg3: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_accel_config_v1#{}.set_points(result: river_libinput_result_v1#{}, type: {:?}, step: {}, points: {})\n", client_id, id, arg0, arg1, debug_array(arg2), debug_array(arg3));
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_accel_config_v1",
+                            id,
+                            "set_points",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("type"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                                LogArg::new(Some("step"), LogValue::Array(arg2)),
+                                LogArg::new(Some("points"), LogValue::Array(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -458,6 +486,20 @@ impl ObjectPrivate for RiverLibinputAccelConfigV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[river_libinput_result_v1]uaa",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -72,10 +72,15 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -136,10 +141,15 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.removed()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "removed",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -213,10 +223,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.input_device(device: river_input_device_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "input_device",
+                    &[
+                        LogArg::new(Some("device"), LogValue::Object { interface: Some("river_input_device_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -287,10 +303,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1SendEventsModes) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.send_events_support(modes: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "send_events_support",
+                    &[
+                        LogArg::new(Some("modes"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -359,10 +381,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1SendEventsModes) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.send_events_default(mode: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "send_events_default",
+                    &[
+                        LogArg::new(Some("mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -431,10 +459,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1SendEventsModes) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.send_events_current(mode: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "send_events_current",
+                    &[
+                        LogArg::new(Some("mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -510,10 +544,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1SendEventsModes) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_send_events(result: river_libinput_result_v1#{}, mode: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_send_events",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("mode"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -629,10 +670,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.tap_support(finger_count: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "tap_support",
+                    &[
+                        LogArg::new(Some("finger_count"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -702,10 +749,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1TapState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.tap_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "tap_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -774,10 +827,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1TapState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.tap_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "tap_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -854,10 +913,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1TapState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_tap(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_tap",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -975,10 +1041,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1TapButtonMap) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.tap_button_map_default(button_map: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "tap_button_map_default",
+                    &[
+                        LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1047,10 +1119,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1TapButtonMap) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.tap_button_map_current(button_map: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "tap_button_map_current",
+                    &[
+                        LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1128,10 +1206,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1TapButtonMap) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_tap_button_map(result: river_libinput_result_v1#{}, button_map: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_tap_button_map",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("button_map"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -1252,10 +1337,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DragState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.drag_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "drag_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1324,10 +1415,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DragState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.drag_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "drag_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1403,10 +1500,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DragState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_drag(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_drag",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -1521,10 +1625,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DragLockState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.drag_lock_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "drag_lock_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1593,10 +1703,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DragLockState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.drag_lock_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "drag_lock_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1676,10 +1792,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DragLockState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_drag_lock(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_drag_lock",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -1807,10 +1930,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.three_finger_drag_support(finger_count: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "three_finger_drag_support",
+                    &[
+                        LogArg::new(Some("finger_count"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1880,10 +2009,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ThreeFingerDragState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.three_finger_drag_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "three_finger_drag_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1952,10 +2087,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ThreeFingerDragState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.three_finger_drag_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "three_finger_drag_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2031,10 +2172,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ThreeFingerDragState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_three_finger_drag(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_three_finger_drag",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -2149,10 +2297,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.calibration_matrix_support(supported: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "calibration_matrix_support",
+                    &[
+                        LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2221,10 +2375,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.calibration_matrix_default(matrix: {})\n", client_id, id, debug_array(arg0));
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "calibration_matrix_default",
+                    &[
+                        LogArg::new(Some("matrix"), LogValue::Array(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2293,10 +2453,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.calibration_matrix_current(matrix: {})\n", client_id, id, debug_array(arg0));
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "calibration_matrix_current",
+                    &[
+                        LogArg::new(Some("matrix"), LogValue::Array(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2372,10 +2538,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_calibration_matrix(result: river_libinput_result_v1#{}, matrix: {})\n", id, arg0, debug_array(arg1));
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_calibration_matrix",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("matrix"), LogValue::Array(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -2490,10 +2663,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1AccelProfiles) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.accel_profiles_support(profiles: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "accel_profiles_support",
+                    &[
+                        LogArg::new(Some("profiles"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2562,10 +2741,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1AccelProfile) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.accel_profile_default(profile: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "accel_profile_default",
+                    &[
+                        LogArg::new(Some("profile"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2634,10 +2819,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1AccelProfile) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.accel_profile_current(profile: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "accel_profile_current",
+                    &[
+                        LogArg::new(Some("profile"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2713,10 +2904,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1AccelProfile) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_accel_profile(result: river_libinput_result_v1#{}, profile: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_accel_profile",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("profile"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -2831,10 +3029,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.accel_speed_default(speed: {})\n", client_id, id, debug_array(arg0));
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "accel_speed_default",
+                    &[
+                        LogArg::new(Some("speed"), LogValue::Array(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2903,10 +3107,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.accel_speed_current(speed: {})\n", client_id, id, debug_array(arg0));
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "accel_speed_current",
+                    &[
+                        LogArg::new(Some("speed"), LogValue::Array(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -2984,10 +3194,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_accel_speed(result: river_libinput_result_v1#{}, speed: {})\n", id, arg0, debug_array(arg1));
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_accel_speed",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("speed"), LogValue::Array(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -3120,10 +3337,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.apply_accel_config(result: river_libinput_result_v1#{}, config: river_libinput_accel_config_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "apply_accel_config",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("config"), LogValue::Object { interface: Some("river_libinput_accel_config_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -3238,10 +3462,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.natural_scroll_support(supported: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "natural_scroll_support",
+                    &[
+                        LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3310,10 +3540,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1NaturalScrollState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.natural_scroll_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "natural_scroll_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3382,10 +3618,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1NaturalScrollState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.natural_scroll_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "natural_scroll_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3461,10 +3703,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1NaturalScrollState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_natural_scroll(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_natural_scroll",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -3579,10 +3828,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.left_handed_support(supported: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "left_handed_support",
+                    &[
+                        LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3651,10 +3906,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1LeftHandedState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.left_handed_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "left_handed_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3723,10 +3984,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1LeftHandedState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.left_handed_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "left_handed_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3802,10 +4069,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1LeftHandedState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_left_handed(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_left_handed",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -3920,10 +4194,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ClickMethods) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.click_method_support(methods: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "click_method_support",
+                    &[
+                        LogArg::new(Some("methods"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3992,10 +4272,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ClickMethod) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.click_method_default(method: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "click_method_default",
+                    &[
+                        LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4064,10 +4350,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ClickMethod) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.click_method_current(method: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "click_method_current",
+                    &[
+                        LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4143,10 +4435,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ClickMethod) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_click_method(result: river_libinput_result_v1#{}, method: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_click_method",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("method"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -4262,10 +4561,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ClickfingerButtonMap) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.clickfinger_button_map_default(button_map: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "clickfinger_button_map_default",
+                    &[
+                        LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4336,10 +4641,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ClickfingerButtonMap) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.clickfinger_button_map_current(button_map: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "clickfinger_button_map_current",
+                    &[
+                        LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4417,10 +4728,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ClickfingerButtonMap) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_clickfinger_button_map(result: river_libinput_result_v1#{}, button_map: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_clickfinger_button_map",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("button_map"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -4539,10 +4857,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.middle_emulation_support(supported: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "middle_emulation_support",
+                    &[
+                        LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4612,10 +4936,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1MiddleEmulationState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.middle_emulation_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "middle_emulation_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4684,10 +5014,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1MiddleEmulationState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.middle_emulation_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "middle_emulation_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4763,10 +5099,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1MiddleEmulationState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_middle_emulation(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_middle_emulation",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -4881,10 +5224,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ScrollMethods) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.scroll_method_support(methods: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "scroll_method_support",
+                    &[
+                        LogArg::new(Some("methods"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -4953,10 +5302,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ScrollMethod) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.scroll_method_default(method: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "scroll_method_default",
+                    &[
+                        LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5025,10 +5380,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ScrollMethod) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.scroll_method_current(method: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "scroll_method_current",
+                    &[
+                        LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5104,10 +5465,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ScrollMethod) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_scroll_method(result: river_libinput_result_v1#{}, method: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_scroll_method",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("method"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -5223,10 +5591,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.scroll_button_default(button: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "scroll_button_default",
+                    &[
+                        LogArg::new(Some("button"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5297,10 +5671,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.scroll_button_current(button: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "scroll_button_current",
+                    &[
+                        LogArg::new(Some("button"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5378,10 +5758,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_scroll_button(result: river_libinput_result_v1#{}, button: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_scroll_button",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("button"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -5500,10 +5887,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ScrollButtonLockState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.scroll_button_lock_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "scroll_button_lock_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5574,10 +5967,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1ScrollButtonLockState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.scroll_button_lock_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "scroll_button_lock_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5655,10 +6054,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ScrollButtonLockState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_scroll_button_lock(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_scroll_button_lock",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -5777,10 +6183,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.dwt_support(supported: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "dwt_support",
+                    &[
+                        LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5850,10 +6262,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DwtState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.dwt_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "dwt_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5922,10 +6340,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DwtState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.dwt_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "dwt_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -6001,10 +6425,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DwtState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_dwt(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_dwt",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -6120,10 +6551,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.dwtp_support(supported: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "dwtp_support",
+                    &[
+                        LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -6193,10 +6630,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DwtpState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.dwtp_default(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "dwtp_default",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -6265,10 +6708,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverLibinputDeviceV1DwtpState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.dwtp_current(state: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "dwtp_current",
+                    &[
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -6344,10 +6793,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DwtpState) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_dwtp(result: river_libinput_result_v1#{}, state: {:?})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_dwtp",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -6462,10 +6918,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.rotation_support(supported: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "rotation_support",
+                    &[
+                        LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -6534,10 +6996,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.rotation_default(angle: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "rotation_default",
+                    &[
+                        LogArg::new(Some("angle"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -6606,10 +7074,16 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.rotation_current(angle: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "rotation_current",
+                    &[
+                        LogArg::new(Some("angle"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -6686,10 +7160,17 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_libinput_device_v1#{}.set_rotation(result: river_libinput_result_v1#{}, angle: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "set_rotation",
+                    &[
+                        LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                        LogArg::new(Some("angle"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -6801,10 +7282,15 @@ impl RiverLibinputDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_libinput_device_v1#{}.done()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_libinput_device_v1",
+                    id,
+                    "done",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -8852,10 +9338,15 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -8878,10 +9369,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1SendEventsModes) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_send_events(result: river_libinput_result_v1#{}, mode: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_send_events",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("mode"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -8908,10 +9406,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1TapState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_tap(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_tap",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -8938,10 +9443,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1TapButtonMap) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_tap_button_map(result: river_libinput_result_v1#{}, button_map: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_tap_button_map",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("button_map"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -8968,10 +9480,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DragState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_drag(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_drag",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -8998,10 +9517,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DragLockState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_drag_lock(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_drag_lock",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9028,10 +9554,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ThreeFingerDragState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_three_finger_drag(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_three_finger_drag",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9061,10 +9594,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_calibration_matrix(result: river_libinput_result_v1#{}, matrix: {})\n", client_id, id, arg0, debug_array(arg1));
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_calibration_matrix",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("matrix"), LogValue::Array(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9091,10 +9631,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1AccelProfile) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_accel_profile(result: river_libinput_result_v1#{}, profile: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_accel_profile",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("profile"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9124,10 +9671,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_accel_speed(result: river_libinput_result_v1#{}, speed: {})\n", client_id, id, arg0, debug_array(arg1));
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_accel_speed",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("speed"), LogValue::Array(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9153,10 +9707,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.apply_accel_config(result: river_libinput_result_v1#{}, config: river_libinput_accel_config_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "apply_accel_config",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("config"), LogValue::Object { interface: Some("river_libinput_accel_config_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9192,10 +9753,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1NaturalScrollState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_natural_scroll(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_natural_scroll",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9222,10 +9790,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1LeftHandedState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_left_handed(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_left_handed",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9252,10 +9827,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ClickMethod) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_click_method(result: river_libinput_result_v1#{}, method: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_click_method",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("method"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9282,10 +9864,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ClickfingerButtonMap) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_clickfinger_button_map(result: river_libinput_result_v1#{}, button_map: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_clickfinger_button_map",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("button_map"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9312,10 +9901,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1MiddleEmulationState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_middle_emulation(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_middle_emulation",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9342,10 +9938,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ScrollMethod) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_scroll_method(result: river_libinput_result_v1#{}, method: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_scroll_method",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("method"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9371,10 +9974,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_scroll_button(result: river_libinput_result_v1#{}, button: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_scroll_button",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("button"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9401,10 +10011,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1ScrollButtonLockState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_scroll_button_lock(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_scroll_button_lock",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9431,10 +10048,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DwtState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_dwt(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_dwt",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9461,10 +10085,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverLibinputDeviceV1DwtpState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_dwtp(result: river_libinput_result_v1#{}, state: {:?})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_dwtp",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9490,10 +10121,17 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_libinput_device_v1#{}.set_rotation(result: river_libinput_result_v1#{}, angle: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "set_rotation",
+                            &[
+                                LogArg::new(Some("result"), LogValue::NewId { interface: "river_libinput_result_v1", id: arg0, version: None }),
+                                LogArg::new(Some("angle"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -9533,10 +10171,15 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.removed()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "removed",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -9556,10 +10199,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.input_device(device: river_input_device_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "input_device",
+                            &[
+                                LogArg::new(Some("device"), LogValue::Object { interface: Some("river_input_device_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9589,10 +10238,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1SendEventsModes) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.send_events_support(modes: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "send_events_support",
+                            &[
+                                LogArg::new(Some("modes"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9613,10 +10268,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1SendEventsModes) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.send_events_default(mode: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "send_events_default",
+                            &[
+                                LogArg::new(Some("mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9637,10 +10298,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1SendEventsModes) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.send_events_current(mode: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "send_events_current",
+                            &[
+                                LogArg::new(Some("mode"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9661,10 +10328,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.tap_support(finger_count: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "tap_support",
+                            &[
+                                LogArg::new(Some("finger_count"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9685,10 +10358,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1TapState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.tap_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "tap_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9709,10 +10388,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1TapState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.tap_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "tap_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9733,10 +10418,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1TapButtonMap) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.tap_button_map_default(button_map: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "tap_button_map_default",
+                            &[
+                                LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9757,10 +10448,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1TapButtonMap) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.tap_button_map_current(button_map: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "tap_button_map_current",
+                            &[
+                                LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9781,10 +10478,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DragState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.drag_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "drag_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9805,10 +10508,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DragState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.drag_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "drag_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9829,10 +10538,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DragLockState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.drag_lock_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "drag_lock_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9853,10 +10568,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DragLockState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.drag_lock_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "drag_lock_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9877,10 +10598,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.three_finger_drag_support(finger_count: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "three_finger_drag_support",
+                            &[
+                                LogArg::new(Some("finger_count"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9901,10 +10628,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ThreeFingerDragState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.three_finger_drag_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "three_finger_drag_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9925,10 +10658,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ThreeFingerDragState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.three_finger_drag_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "three_finger_drag_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9949,10 +10688,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.calibration_matrix_support(supported: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "calibration_matrix_support",
+                            &[
+                                LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9973,10 +10718,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.calibration_matrix_default(matrix: {})\n", id, debug_array(arg0));
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "calibration_matrix_default",
+                            &[
+                                LogArg::new(Some("matrix"), LogValue::Array(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -9997,10 +10748,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.calibration_matrix_current(matrix: {})\n", id, debug_array(arg0));
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "calibration_matrix_current",
+                            &[
+                                LogArg::new(Some("matrix"), LogValue::Array(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10021,10 +10778,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1AccelProfiles) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.accel_profiles_support(profiles: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "accel_profiles_support",
+                            &[
+                                LogArg::new(Some("profiles"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10045,10 +10808,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1AccelProfile) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.accel_profile_default(profile: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "accel_profile_default",
+                            &[
+                                LogArg::new(Some("profile"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10069,10 +10838,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1AccelProfile) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.accel_profile_current(profile: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "accel_profile_current",
+                            &[
+                                LogArg::new(Some("profile"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10093,10 +10868,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.accel_speed_default(speed: {})\n", id, debug_array(arg0));
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "accel_speed_default",
+                            &[
+                                LogArg::new(Some("speed"), LogValue::Array(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10117,10 +10898,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.accel_speed_current(speed: {})\n", id, debug_array(arg0));
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "accel_speed_current",
+                            &[
+                                LogArg::new(Some("speed"), LogValue::Array(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10141,10 +10928,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.natural_scroll_support(supported: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "natural_scroll_support",
+                            &[
+                                LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10165,10 +10958,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1NaturalScrollState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.natural_scroll_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "natural_scroll_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10189,10 +10988,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1NaturalScrollState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.natural_scroll_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "natural_scroll_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10213,10 +11018,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.left_handed_support(supported: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "left_handed_support",
+                            &[
+                                LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10237,10 +11048,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1LeftHandedState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.left_handed_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "left_handed_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10261,10 +11078,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1LeftHandedState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.left_handed_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "left_handed_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10285,10 +11108,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ClickMethods) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.click_method_support(methods: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "click_method_support",
+                            &[
+                                LogArg::new(Some("methods"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10309,10 +11138,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ClickMethod) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.click_method_default(method: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "click_method_default",
+                            &[
+                                LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10333,10 +11168,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ClickMethod) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.click_method_current(method: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "click_method_current",
+                            &[
+                                LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10357,10 +11198,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ClickfingerButtonMap) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.clickfinger_button_map_default(button_map: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "clickfinger_button_map_default",
+                            &[
+                                LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10381,10 +11228,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ClickfingerButtonMap) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.clickfinger_button_map_current(button_map: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "clickfinger_button_map_current",
+                            &[
+                                LogArg::new(Some("button_map"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10405,10 +11258,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.middle_emulation_support(supported: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "middle_emulation_support",
+                            &[
+                                LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10429,10 +11288,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1MiddleEmulationState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.middle_emulation_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "middle_emulation_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10453,10 +11318,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1MiddleEmulationState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.middle_emulation_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "middle_emulation_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10477,10 +11348,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ScrollMethods) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.scroll_method_support(methods: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "scroll_method_support",
+                            &[
+                                LogArg::new(Some("methods"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10501,10 +11378,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ScrollMethod) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.scroll_method_default(method: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "scroll_method_default",
+                            &[
+                                LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10525,10 +11408,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ScrollMethod) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.scroll_method_current(method: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "scroll_method_current",
+                            &[
+                                LogArg::new(Some("method"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10548,10 +11437,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.scroll_button_default(button: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "scroll_button_default",
+                            &[
+                                LogArg::new(Some("button"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10571,10 +11466,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.scroll_button_current(button: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "scroll_button_current",
+                            &[
+                                LogArg::new(Some("button"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10595,10 +11496,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ScrollButtonLockState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.scroll_button_lock_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "scroll_button_lock_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10619,10 +11526,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1ScrollButtonLockState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.scroll_button_lock_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "scroll_button_lock_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10643,10 +11556,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.dwt_support(supported: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "dwt_support",
+                            &[
+                                LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10667,10 +11586,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DwtState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.dwt_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "dwt_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10691,10 +11616,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DwtState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.dwt_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "dwt_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10715,10 +11646,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.dwtp_support(supported: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "dwtp_support",
+                            &[
+                                LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10739,10 +11676,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DwtpState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.dwtp_default(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "dwtp_default",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10763,10 +11706,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverLibinputDeviceV1DwtpState) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.dwtp_current(state: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "dwtp_current",
+                            &[
+                                LogArg::new(Some("state"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10787,10 +11736,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.rotation_support(supported: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "rotation_support",
+                            &[
+                                LogArg::new(Some("supported"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10810,10 +11765,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.rotation_default(angle: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "rotation_default",
+                            &[
+                                LogArg::new(Some("angle"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10833,10 +11794,16 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.rotation_current(angle: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "rotation_current",
+                            &[
+                                LogArg::new(Some("angle"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -10854,10 +11821,15 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_libinput_device_v1#{}.done()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_libinput_device_v1",
+                            id,
+                            "done",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -10970,6 +11942,98 @@ impl ObjectPrivate for RiverLibinputDeviceV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[river_libinput_result_v1]u",
+            2 => "n[river_libinput_result_v1]u",
+            3 => "n[river_libinput_result_v1]u",
+            4 => "n[river_libinput_result_v1]u",
+            5 => "n[river_libinput_result_v1]u",
+            6 => "n[river_libinput_result_v1]u",
+            7 => "n[river_libinput_result_v1]a",
+            8 => "n[river_libinput_result_v1]u",
+            9 => "n[river_libinput_result_v1]a",
+            10 => "n[river_libinput_result_v1]o",
+            11 => "n[river_libinput_result_v1]u",
+            12 => "n[river_libinput_result_v1]u",
+            13 => "n[river_libinput_result_v1]u",
+            14 => "n[river_libinput_result_v1]u",
+            15 => "n[river_libinput_result_v1]u",
+            16 => "n[river_libinput_result_v1]u",
+            17 => "n[river_libinput_result_v1]u",
+            18 => "n[river_libinput_result_v1]u",
+            19 => "n[river_libinput_result_v1]u",
+            20 => "n[river_libinput_result_v1]u",
+            21 => "n[river_libinput_result_v1]u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "o",
+            2 => "u",
+            3 => "u",
+            4 => "u",
+            5 => "i",
+            6 => "u",
+            7 => "u",
+            8 => "u",
+            9 => "u",
+            10 => "u",
+            11 => "u",
+            12 => "u",
+            13 => "u",
+            14 => "i",
+            15 => "u",
+            16 => "u",
+            17 => "i",
+            18 => "a",
+            19 => "a",
+            20 => "u",
+            21 => "u",
+            22 => "u",
+            23 => "a",
+            24 => "a",
+            25 => "i",
+            26 => "u",
+            27 => "u",
+            28 => "i",
+            29 => "u",
+            30 => "u",
+            31 => "u",
+            32 => "u",
+            33 => "u",
+            34 => "u",
+            35 => "u",
+            36 => "i",
+            37 => "u",
+            38 => "u",
+            39 => "u",
+            40 => "u",
+            41 => "u",
+            42 => "u",
+            43 => "u",
+            44 => "u",
+            45 => "u",
+            46 => "i",
+            47 => "u",
+            48 => "u",
+            49 => "i",
+            50 => "u",
+            51 => "u",
+            52 => "i",
+            53 => "u",
+            54 => "u",
+            55 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
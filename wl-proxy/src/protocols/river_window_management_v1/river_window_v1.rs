@@ -78,10 +78,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -150,10 +155,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.closed()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "closed",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -220,10 +230,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.close()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "close",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -301,10 +316,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.get_node(id: river_node_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "get_node",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "river_node_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -436,10 +457,19 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.dimensions_hint(min_width: {}, min_height: {}, max_width: {}, max_height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "dimensions_hint",
+                    &[
+                        LogArg::new(Some("min_width"), LogValue::Int(arg0)),
+                        LogArg::new(Some("min_height"), LogValue::Int(arg1)),
+                        LogArg::new(Some("max_width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("max_height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3);
         }
@@ -552,10 +582,17 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.dimensions(width: {}, height: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "dimensions",
+                    &[
+                        LogArg::new(Some("width"), LogValue::Int(arg0)),
+                        LogArg::new(Some("height"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -670,10 +707,17 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.propose_dimensions(width: {}, height: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "propose_dimensions",
+                    &[
+                        LogArg::new(Some("width"), LogValue::Int(arg0)),
+                        LogArg::new(Some("height"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -767,10 +811,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.hide()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "hide",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -837,10 +886,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.show()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "show",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -919,10 +973,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: Option<&str>) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.app_id(app_id: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "app_id",
+                    &[
+                        LogArg::new(Some("app_id"), LogValue::String(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1009,10 +1069,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: Option<&str>) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.title(title: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "title",
+                    &[
+                        LogArg::new(Some("title"), LogValue::String(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1111,10 +1177,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.parent(parent: river_window_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "parent",
+                    &[
+                        LogArg::new(Some("parent"), LogValue::Object { interface: Some("river_window_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -1202,10 +1274,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverWindowV1DecorationHint) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.decoration_hint(hint: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "decoration_hint",
+                    &[
+                        LogArg::new(Some("hint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -1276,10 +1354,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.use_csd()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "use_csd",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -1345,10 +1428,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.use_ssd()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "use_ssd",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -1467,10 +1555,21 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: RiverWindowV1Edges, arg1: i32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.set_borders(edges: {:?}, width: {}, r: {}, g: {}, b: {}, a: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "set_borders",
+                    &[
+                        LogArg::new(Some("edges"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("width"), LogValue::Int(arg1)),
+                        LogArg::new(Some("r"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("g"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("b"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("a"), LogValue::Uint(arg5)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4, arg5);
         }
@@ -1601,10 +1700,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: RiverWindowV1Edges) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.set_tiled(edges: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "set_tiled",
+                    &[
+                        LogArg::new(Some("edges"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -1704,10 +1809,17 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.get_decoration_above(id: river_decoration_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "get_decoration_above",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "river_decoration_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -1854,10 +1966,17 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.get_decoration_below(id: river_decoration_v1#{}, surface: wl_surface#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "get_decoration_below",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "river_decoration_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -2004,10 +2123,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.pointer_move_requested(seat: river_seat_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "pointer_move_requested",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -2113,10 +2238,17 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: RiverWindowV1Edges) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.pointer_resize_requested(seat: river_seat_v1#{}, edges: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "pointer_resize_requested",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg0 }),
+                        LogArg::new(Some("edges"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id, arg1);
         }
@@ -2201,10 +2333,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.inform_resize_start()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "inform_resize_start",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -2269,10 +2406,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.inform_resize_end()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "inform_resize_end",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -2352,10 +2494,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: RiverWindowV1Capabilities) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.set_capabilities(caps: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "set_capabilities",
+                    &[
+                        LogArg::new(Some("caps"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -2456,10 +2604,17 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.show_window_menu_requested(x: {}, y: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "show_window_menu_requested",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -2542,10 +2697,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.maximize_requested()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "maximize_requested",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -2611,10 +2771,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.unmaximize_requested()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "unmaximize_requested",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -2679,10 +2844,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.inform_maximized()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "inform_maximized",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -2747,10 +2917,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.inform_unmaximized()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "inform_unmaximized",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -2836,10 +3011,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.fullscreen_requested(output: river_output_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "fullscreen_requested",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("river_output_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -2915,10 +3096,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.exit_fullscreen_requested()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "exit_fullscreen_requested",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -2984,10 +3170,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.inform_fullscreen()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "inform_fullscreen",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -3057,10 +3248,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.inform_not_fullscreen()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "inform_not_fullscreen",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -3167,10 +3363,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.fullscreen(output: river_output_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "fullscreen",
+                    &[
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("river_output_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -3274,10 +3476,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.exit_fullscreen()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "exit_fullscreen",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -3352,10 +3559,15 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.minimize_requested()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "minimize_requested",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -3447,10 +3659,19 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.set_clip_box(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "set_clip_box",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -3555,10 +3776,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.unreliable_pid(unreliable_pid: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "unreliable_pid",
+                    &[
+                        LogArg::new(Some("unreliable_pid"), LogValue::Int(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3664,10 +3891,19 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.set_content_clip_box(x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "set_content_clip_box",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Int(arg0)),
+                        LogArg::new(Some("y"), LogValue::Int(arg1)),
+                        LogArg::new(Some("width"), LogValue::Int(arg2)),
+                        LogArg::new(Some("height"), LogValue::Int(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -3771,10 +4007,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverOutputV1PresentationMode) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.presentation_hint(hint: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "presentation_hint",
+                    &[
+                        LogArg::new(Some("hint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3863,10 +4105,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.identifier(identifier: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "identifier",
+                    &[
+                        LogArg::new(Some("identifier"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -3967,10 +4215,17 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_window_v1#{}.set_dimension_bounds(max_width: {}, max_height: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "set_dimension_bounds",
+                    &[
+                        LogArg::new(Some("max_width"), LogValue::Int(arg0)),
+                        LogArg::new(Some("max_height"), LogValue::Int(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -4065,10 +4320,16 @@ impl RiverWindowV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_window_v1#{}.capture_sessions(count: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_window_v1",
+                    id,
+                    "capture_sessions",
+                    &[
+                        LogArg::new(Some("count"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -5652,10 +5913,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -5674,10 +5940,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.close()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "close",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -5697,10 +5968,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.get_node(id: river_node_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "get_node",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "river_node_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -5728,10 +6005,17 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.propose_dimensions(width: {}, height: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "propose_dimensions",
+                            &[
+                                LogArg::new(Some("width"), LogValue::Int(arg0)),
+                                LogArg::new(Some("height"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -5749,10 +6033,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.hide()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "hide",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -5770,10 +6059,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.show()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "show",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -5791,10 +6085,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.use_csd()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "use_csd",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -5812,10 +6111,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.use_ssd()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "use_ssd",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -5842,10 +6146,21 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: RiverWindowV1Edges, arg1: i32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.set_borders(edges: {:?}, width: {}, r: {}, g: {}, b: {}, a: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "set_borders",
+                            &[
+                                LogArg::new(Some("edges"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("width"), LogValue::Int(arg1)),
+                                LogArg::new(Some("r"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("g"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("b"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("a"), LogValue::Uint(arg5)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5);
                 }
@@ -5866,10 +6181,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: RiverWindowV1Edges) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.set_tiled(edges: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "set_tiled",
+                            &[
+                                LogArg::new(Some("edges"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -5890,10 +6211,17 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.get_decoration_above(id: river_decoration_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "get_decoration_above",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "river_decoration_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -5928,10 +6256,17 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.get_decoration_below(id: river_decoration_v1#{}, surface: wl_surface#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "get_decoration_below",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "river_decoration_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -5963,10 +6298,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.inform_resize_start()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "inform_resize_start",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -5984,10 +6324,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.inform_resize_end()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "inform_resize_end",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -6008,10 +6353,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: RiverWindowV1Capabilities) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.set_capabilities(caps: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "set_capabilities",
+                            &[
+                                LogArg::new(Some("caps"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -6029,10 +6380,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.inform_maximized()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "inform_maximized",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -6050,10 +6406,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.inform_unmaximized()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "inform_unmaximized",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -6071,10 +6432,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.inform_fullscreen()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "inform_fullscreen",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -6092,10 +6458,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.inform_not_fullscreen()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "inform_not_fullscreen",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -6115,10 +6486,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.fullscreen(output: river_output_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "fullscreen",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("river_output_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -6145,10 +6522,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.exit_fullscreen()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "exit_fullscreen",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -6175,10 +6557,19 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.set_clip_box(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "set_clip_box",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -6205,10 +6596,19 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.set_content_clip_box(x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "set_content_clip_box",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                                LogArg::new(Some("width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -6231,10 +6631,17 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_window_v1#{}.set_dimension_bounds(max_width: {}, max_height: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "set_dimension_bounds",
+                            &[
+                                LogArg::new(Some("max_width"), LogValue::Int(arg0)),
+                                LogArg::new(Some("max_height"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -6269,10 +6676,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.closed()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "closed",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -6299,10 +6711,19 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32, arg2: i32, arg3: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.dimensions_hint(min_width: {}, min_height: {}, max_width: {}, max_height: {})\n", id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "dimensions_hint",
+                            &[
+                                LogArg::new(Some("min_width"), LogValue::Int(arg0)),
+                                LogArg::new(Some("min_height"), LogValue::Int(arg1)),
+                                LogArg::new(Some("max_width"), LogValue::Int(arg2)),
+                                LogArg::new(Some("max_height"), LogValue::Int(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -6325,10 +6746,17 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.dimensions(width: {}, height: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "dimensions",
+                            &[
+                                LogArg::new(Some("width"), LogValue::Int(arg0)),
+                                LogArg::new(Some("height"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -6349,10 +6777,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: Option<&str>) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.app_id(app_id: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "app_id",
+                            &[
+                                LogArg::new(Some("app_id"), LogValue::String(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6373,10 +6807,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: Option<&str>) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.title(title: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "title",
+                            &[
+                                LogArg::new(Some("title"), LogValue::String(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6396,10 +6836,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.parent(parent: river_window_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "parent",
+                            &[
+                                LogArg::new(Some("parent"), LogValue::Object { interface: Some("river_window_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6434,10 +6880,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverWindowV1DecorationHint) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.decoration_hint(hint: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "decoration_hint",
+                            &[
+                                LogArg::new(Some("hint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6457,10 +6909,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.pointer_move_requested(seat: river_seat_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "pointer_move_requested",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6491,10 +6949,17 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: RiverWindowV1Edges) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.pointer_resize_requested(seat: river_seat_v1#{}, edges: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "pointer_resize_requested",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg0 }),
+                                LogArg::new(Some("edges"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -6526,10 +6991,17 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.show_window_menu_requested(x: {}, y: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "show_window_menu_requested",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Int(arg0)),
+                                LogArg::new(Some("y"), LogValue::Int(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -6547,10 +7019,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.maximize_requested()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "maximize_requested",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -6568,10 +7045,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.unmaximize_requested()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "unmaximize_requested",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -6591,10 +7073,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.fullscreen_requested(output: river_output_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "fullscreen_requested",
+                            &[
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("river_output_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6626,10 +7114,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.exit_fullscreen_requested()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "exit_fullscreen_requested",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -6647,10 +7140,15 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.minimize_requested()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "minimize_requested",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -6671,10 +7169,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.unreliable_pid(unreliable_pid: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "unreliable_pid",
+                            &[
+                                LogArg::new(Some("unreliable_pid"), LogValue::Int(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6695,10 +7199,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverOutputV1PresentationMode) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.presentation_hint(hint: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "presentation_hint",
+                            &[
+                                LogArg::new(Some("hint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6719,10 +7229,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.identifier(identifier: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "identifier",
+                            &[
+                                LogArg::new(Some("identifier"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6742,10 +7258,16 @@ impl ObjectPrivate for RiverWindowV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_window_v1#{}.capture_sessions(count: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_window_v1",
+                            id,
+                            "capture_sessions",
+                            &[
+                                LogArg::new(Some("count"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -6823,6 +7345,63 @@ impl ObjectPrivate for RiverWindowV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "",
+            2 => "n[river_node_v1]",
+            3 => "ii",
+            4 => "",
+            5 => "",
+            6 => "",
+            7 => "",
+            8 => "uiuuuu",
+            9 => "u",
+            10 => "n[river_decoration_v1]o",
+            11 => "n[river_decoration_v1]o",
+            12 => "",
+            13 => "",
+            14 => "u",
+            15 => "",
+            16 => "",
+            17 => "",
+            18 => "",
+            19 => "o",
+            20 => "",
+            21 => "iiii",
+            22 => "iiii",
+            23 => "ii",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "iiii",
+            2 => "ii",
+            3 => "?s",
+            4 => "?s",
+            5 => "?o",
+            6 => "u",
+            7 => "o",
+            8 => "ou",
+            9 => "ii",
+            10 => "",
+            11 => "",
+            12 => "?o",
+            13 => "",
+            14 => "",
+            15 => "i",
+            16 => "u",
+            17 => "s",
+            18 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -68,10 +68,15 @@ impl RiverXkbBindingsSeatV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_xkb_bindings_seat_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_xkb_bindings_seat_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -143,10 +148,15 @@ impl RiverXkbBindingsSeatV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_xkb_bindings_seat_v1#{}.ensure_next_key_eaten()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_xkb_bindings_seat_v1",
+                    id,
+                    "ensure_next_key_eaten",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -229,10 +239,15 @@ impl RiverXkbBindingsSeatV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_xkb_bindings_seat_v1#{}.cancel_ensure_next_key_eaten()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_xkb_bindings_seat_v1",
+                    id,
+                    "cancel_ensure_next_key_eaten",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -303,10 +318,15 @@ impl RiverXkbBindingsSeatV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_xkb_bindings_seat_v1#{}.ate_unbound_key()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_xkb_bindings_seat_v1",
+                    id,
+                    "ate_unbound_key",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -379,10 +399,16 @@ impl RiverXkbBindingsSeatV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: RiverSeatV1Modifiers) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_xkb_bindings_seat_v1#{}.modifiers_watch(modifiers: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_xkb_bindings_seat_v1",
+                    id,
+                    "modifiers_watch",
+                    &[
+                        LogArg::new(Some("modifiers"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -486,10 +512,17 @@ impl RiverXkbBindingsSeatV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: RiverSeatV1Modifiers, arg1: RiverSeatV1Modifiers) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= river_xkb_bindings_seat_v1#{}.modifiers_update(old: {:?}, new: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "river_xkb_bindings_seat_v1",
+                    id,
+                    "modifiers_update",
+                    &[
+                        LogArg::new(Some("old"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("new"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -786,10 +819,15 @@ impl ObjectPrivate for RiverXkbBindingsSeatV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_xkb_bindings_seat_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_xkb_bindings_seat_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -808,10 +846,15 @@ impl ObjectPrivate for RiverXkbBindingsSeatV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_xkb_bindings_seat_v1#{}.ensure_next_key_eaten()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_xkb_bindings_seat_v1",
+                            id,
+                            "ensure_next_key_eaten",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -829,10 +872,15 @@ impl ObjectPrivate for RiverXkbBindingsSeatV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_xkb_bindings_seat_v1#{}.cancel_ensure_next_key_eaten()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_xkb_bindings_seat_v1",
+                            id,
+                            "cancel_ensure_next_key_eaten",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -853,10 +901,16 @@ impl ObjectPrivate for RiverXkbBindingsSeatV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: RiverSeatV1Modifiers) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_xkb_bindings_seat_v1#{}.modifiers_watch(modifiers: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_xkb_bindings_seat_v1",
+                            id,
+                            "modifiers_watch",
+                            &[
+                                LogArg::new(Some("modifiers"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -891,10 +945,15 @@ impl ObjectPrivate for RiverXkbBindingsSeatV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_xkb_bindings_seat_v1#{}.ate_unbound_key()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_xkb_bindings_seat_v1",
+                            id,
+                            "ate_unbound_key",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -917,10 +976,17 @@ impl ObjectPrivate for RiverXkbBindingsSeatV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: RiverSeatV1Modifiers, arg1: RiverSeatV1Modifiers) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> river_xkb_bindings_seat_v1#{}.modifiers_update(old: {:?}, new: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "river_xkb_bindings_seat_v1",
+                            id,
+                            "modifiers_update",
+                            &[
+                                LogArg::new(Some("old"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("new"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -961,6 +1027,26 @@ impl ObjectPrivate for RiverXkbBindingsSeatV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "",
+            2 => "",
+            3 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "uu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
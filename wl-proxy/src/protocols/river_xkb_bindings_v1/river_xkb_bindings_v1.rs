@@ -69,10 +69,15 @@ impl RiverXkbBindingsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_xkb_bindings_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_xkb_bindings_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -162,10 +167,19 @@ impl RiverXkbBindingsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: RiverSeatV1Modifiers) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_xkb_bindings_v1#{}.get_xkb_binding(seat: river_seat_v1#{}, id: river_xkb_binding_v1#{}, keysym: {}, modifiers: {:?})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_xkb_bindings_v1",
+                    id,
+                    "get_xkb_binding",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg0 }),
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "river_xkb_binding_v1", id: arg1, version: None }),
+                        LogArg::new(Some("keysym"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("modifiers"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2, arg3);
         }
@@ -327,10 +341,17 @@ impl RiverXkbBindingsV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= river_xkb_bindings_v1#{}.get_seat(id: river_xkb_bindings_seat_v1#{}, seat: river_seat_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "river_xkb_bindings_v1",
+                    id,
+                    "get_seat",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "river_xkb_bindings_seat_v1", id: arg0, version: None }),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -562,10 +583,15 @@ impl ObjectPrivate for RiverXkbBindingsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_xkb_bindings_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_xkb_bindings_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -590,10 +616,19 @@ impl ObjectPrivate for RiverXkbBindingsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: RiverSeatV1Modifiers) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_xkb_bindings_v1#{}.get_xkb_binding(seat: river_seat_v1#{}, id: river_xkb_binding_v1#{}, keysym: {}, modifiers: {:?})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_xkb_bindings_v1",
+                            id,
+                            "get_xkb_binding",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg0 }),
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "river_xkb_binding_v1", id: arg1, version: None }),
+                                LogArg::new(Some("keysym"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("modifiers"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -628,10 +663,17 @@ impl ObjectPrivate for RiverXkbBindingsV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> river_xkb_bindings_v1#{}.get_seat(id: river_xkb_bindings_seat_v1#{}, seat: river_seat_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "river_xkb_bindings_v1",
+                            id,
+                            "get_seat",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "river_xkb_bindings_seat_v1", id: arg0, version: None }),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("river_seat_v1"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -697,6 +739,21 @@ impl ObjectPrivate for RiverXkbBindingsV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "on[river_xkb_binding_v1]uu",
+            2 => "n[river_xkb_bindings_seat_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
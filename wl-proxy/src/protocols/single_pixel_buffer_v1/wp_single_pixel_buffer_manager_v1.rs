@@ -70,10 +70,15 @@ impl WpSinglePixelBufferManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_single_pixel_buffer_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_single_pixel_buffer_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -171,10 +176,20 @@ impl WpSinglePixelBufferManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wp_single_pixel_buffer_manager_v1#{}.create_u32_rgba_buffer(id: wl_buffer#{}, r: {}, g: {}, b: {}, a: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wp_single_pixel_buffer_manager_v1",
+                    id,
+                    "create_u32_rgba_buffer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                        LogArg::new(Some("r"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("g"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("b"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("a"), LogValue::Uint(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2, arg3, arg4);
         }
@@ -439,10 +454,15 @@ impl ObjectPrivate for WpSinglePixelBufferManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_single_pixel_buffer_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_single_pixel_buffer_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -467,10 +487,20 @@ impl ObjectPrivate for WpSinglePixelBufferManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wp_single_pixel_buffer_manager_v1#{}.create_u32_rgba_buffer(id: wl_buffer#{}, r: {}, g: {}, b: {}, a: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wp_single_pixel_buffer_manager_v1",
+                            id,
+                            "create_u32_rgba_buffer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wl_buffer", id: arg0, version: None }),
+                                LogArg::new(Some("r"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("g"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("b"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("a"), LogValue::Uint(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -526,6 +556,20 @@ impl ObjectPrivate for WpSinglePixelBufferManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wl_buffer]uuuu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
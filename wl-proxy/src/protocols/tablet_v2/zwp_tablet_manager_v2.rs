@@ -94,10 +94,17 @@ impl ZwpTabletManagerV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_tablet_manager_v2#{}.get_tablet_seat(tablet_seat: zwp_tablet_seat_v2#{}, seat: wl_seat#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_tablet_manager_v2",
+                    id,
+                    "get_tablet_seat",
+                    &[
+                        LogArg::new(Some("tablet_seat"), LogValue::NewId { interface: "zwp_tablet_seat_v2", id: arg0, version: None }),
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -204,10 +211,15 @@ impl ZwpTabletManagerV2 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_tablet_manager_v2#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_tablet_manager_v2",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -342,10 +354,17 @@ impl ObjectPrivate for ZwpTabletManagerV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_tablet_manager_v2#{}.get_tablet_seat(tablet_seat: zwp_tablet_seat_v2#{}, seat: wl_seat#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_tablet_manager_v2",
+                            id,
+                            "get_tablet_seat",
+                            &[
+                                LogArg::new(Some("tablet_seat"), LogValue::NewId { interface: "zwp_tablet_seat_v2", id: arg0, version: None }),
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -377,10 +396,15 @@ impl ObjectPrivate for ZwpTabletManagerV2 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_tablet_manager_v2#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_tablet_manager_v2",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -432,6 +456,20 @@ impl ObjectPrivate for ZwpTabletManagerV2 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwp_tablet_seat_v2]o",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
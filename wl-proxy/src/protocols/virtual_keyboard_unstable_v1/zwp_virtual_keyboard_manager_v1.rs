@@ -96,10 +96,17 @@ impl ZwpVirtualKeyboardManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_virtual_keyboard_manager_v1#{}.create_virtual_keyboard(seat: wl_seat#{}, id: zwp_virtual_keyboard_v1#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_virtual_keyboard_manager_v1",
+                    id,
+                    "create_virtual_keyboard",
+                    &[
+                        LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_virtual_keyboard_v1", id: arg1, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -279,10 +286,17 @@ impl ObjectPrivate for ZwpVirtualKeyboardManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_virtual_keyboard_manager_v1#{}.create_virtual_keyboard(seat: wl_seat#{}, id: zwp_virtual_keyboard_v1#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_virtual_keyboard_manager_v1",
+                            id,
+                            "create_virtual_keyboard",
+                            &[
+                                LogArg::new(Some("seat"), LogValue::Object { interface: Some("wl_seat"), id: arg0 }),
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwp_virtual_keyboard_v1", id: arg1, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -346,6 +360,19 @@ impl ObjectPrivate for ZwpVirtualKeyboardManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "on[zwp_virtual_keyboard_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -92,10 +92,18 @@ impl ZwpVirtualKeyboardV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_virtual_keyboard_v1#{}.keymap(format: {}, fd: {}, size: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_virtual_keyboard_v1",
+                    id,
+                    "keymap",
+                    &[
+                        LogArg::new(Some("format"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                        LogArg::new(Some("size"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1.as_raw_fd(), arg2);
         }
@@ -190,10 +198,18 @@ impl ZwpVirtualKeyboardV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_virtual_keyboard_v1#{}.key(time: {}, key: {}, state: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_virtual_keyboard_v1",
+                    id,
+                    "key",
+                    &[
+                        LogArg::new(Some("time"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("key"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("state"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2);
         }
@@ -295,10 +311,19 @@ impl ZwpVirtualKeyboardV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_virtual_keyboard_v1#{}.modifiers(mods_depressed: {}, mods_latched: {}, mods_locked: {}, group: {})\n", id, arg0, arg1, arg2, arg3);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_virtual_keyboard_v1",
+                    id,
+                    "modifiers",
+                    &[
+                        LogArg::new(Some("mods_depressed"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("mods_latched"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("mods_locked"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("group"), LogValue::Uint(arg3)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3);
         }
@@ -373,10 +398,15 @@ impl ZwpVirtualKeyboardV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_virtual_keyboard_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_virtual_keyboard_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -587,10 +617,18 @@ impl ObjectPrivate for ZwpVirtualKeyboardV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_virtual_keyboard_v1#{}.keymap(format: {}, fd: {}, size: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_virtual_keyboard_v1",
+                            id,
+                            "keymap",
+                            &[
+                                LogArg::new(Some("format"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                                LogArg::new(Some("size"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1.as_raw_fd(), arg2);
                 }
@@ -612,10 +650,18 @@ impl ObjectPrivate for ZwpVirtualKeyboardV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_virtual_keyboard_v1#{}.key(time: {}, key: {}, state: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_virtual_keyboard_v1",
+                            id,
+                            "key",
+                            &[
+                                LogArg::new(Some("time"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("key"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("state"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -638,10 +684,19 @@ impl ObjectPrivate for ZwpVirtualKeyboardV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_virtual_keyboard_v1#{}.modifiers(mods_depressed: {}, mods_latched: {}, mods_locked: {}, group: {})\n", client_id, id, arg0, arg1, arg2, arg3);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_virtual_keyboard_v1",
+                            id,
+                            "modifiers",
+                            &[
+                                LogArg::new(Some("mods_depressed"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("mods_latched"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("mods_locked"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("group"), LogValue::Uint(arg3)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3);
                 }
@@ -659,10 +714,15 @@ impl ObjectPrivate for ZwpVirtualKeyboardV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_virtual_keyboard_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_virtual_keyboard_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -716,6 +776,22 @@ impl ObjectPrivate for ZwpVirtualKeyboardV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uhu",
+            1 => "uuu",
+            2 => "uuuu",
+            3 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
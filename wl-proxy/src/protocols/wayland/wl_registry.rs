@@ -107,12 +107,19 @@ impl WlRegistry {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1_interface: &str, arg1_id: u32, arg1_version: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wl_registry#{}.bind(name: {}, id: {}#{} (version: {}))\n", id, arg0, arg1_interface, arg1_id, arg1_version);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wl_registry",
+                    id,
+                    "bind",
+                    &[
+                        LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("id"), LogValue::NewId { interface: arg1_interface, id: arg1_id, version: Some(arg1_version) }),
+                    ],
+                );
             }
-            log(&self.core.state, id, arg0, arg1.interface.name(), arg1_id, arg1.version);
+            log(&self.core.state, id, arg0, arg1.interface_name(), arg1_id, arg1.version);
         }
         let Some(endpoint) = &self.core.state.server else {
             return Ok(());
@@ -128,7 +135,7 @@ impl WlRegistry {
             0,
             arg0,
         ]);
-        fmt.string(arg1.interface.name());
+        fmt.string(arg1.interface_name());
         fmt.words([
             arg1.version,
             arg1_id,
@@ -203,10 +210,18 @@ impl WlRegistry {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wl_registry#{}.global(name: {}, interface: {:?}, version: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_registry",
+                    id,
+                    "global",
+                    &[
+                        LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("interface"), LogValue::String(Some(arg1))),
+                        LogArg::new(Some("version"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -298,10 +313,16 @@ impl WlRegistry {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wl_registry#{}.global_remove(name: {})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_registry",
+                    id,
+                    "global_remove",
+                    &[
+                        LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -348,6 +369,109 @@ impl WlRegistry {
             log_send("wl_registry.global_remove", &e);
         }
     }
+
+    /// announce global object with an unknown interface
+    ///
+    /// This is the same as [`Self::try_send_global`] but the interface is passed as a
+    /// string. This can be used to forward globals whose interface is not known to this
+    /// crate.
+    ///
+    /// See [`UnknownObject`](crate::unknown::UnknownObject).
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: numeric name of the global object
+    /// - `interface`: interface implemented by the object
+    /// - `version`: interface version
+    #[inline]
+    pub fn try_send_unknown_global(
+        &self,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) -> Result<(), ObjectError> {
+        let (
+            arg0,
+            arg1,
+            arg2,
+        ) = (
+            name,
+            interface,
+            version,
+        );
+        let core = self.core();
+        let client_ref = core.client.borrow();
+        let Some(client) = &*client_ref else {
+            return Err(ObjectError(ObjectErrorKind::ReceiverNoClient));
+        };
+        let id = core.client_obj_id.get().unwrap_or(0);
+        #[cfg(feature = "logging")]
+        if self.core.state.log {
+            #[cold]
+            fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str, arg2: u32) {
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wl_registry",
+                    id,
+                    "global",
+                    &[
+                        LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("interface"), LogValue::String(Some(arg1))),
+                        LogArg::new(Some("version"), LogValue::Uint(arg2)),
+                    ],
+                );
+            }
+            log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
+        }
+        let endpoint = &client.endpoint;
+        if !endpoint.flush_queued.replace(true) {
+            self.core.state.add_flushable_endpoint(endpoint, Some(client));
+        }
+        let mut outgoing_ref = endpoint.outgoing.borrow_mut();
+        let outgoing = &mut *outgoing_ref;
+        let mut fmt = outgoing.formatter();
+        fmt.words([
+            id,
+            0,
+            arg0,
+        ]);
+        fmt.string(arg1);
+        fmt.words([
+            arg2,
+        ]);
+        Ok(())
+    }
+
+    /// announce global object with an unknown interface
+    ///
+    /// This is the same as [`Self::send_global`] but the interface is passed as a
+    /// string. This can be used to forward globals whose interface is not known to this
+    /// crate.
+    ///
+    /// See [`UnknownObject`](crate::unknown::UnknownObject).
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: numeric name of the global object
+    /// - `interface`: interface implemented by the object
+    /// - `version`: interface version
+    #[inline]
+    pub fn send_unknown_global(
+        &self,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        let res = self.try_send_unknown_global(
+            name,
+            interface,
+            version,
+        );
+        if let Err(e) = res {
+            log_send("wl_registry.global", &e);
+        }
+    }
 }
 
 /// A message handler for [`WlRegistry`] proxies.
@@ -454,6 +578,39 @@ pub trait WlRegistryHandler: Any {
             log_forward("wl_registry.global_remove", &e);
         }
     }
+
+    /// announce global object with an unknown interface
+    ///
+    /// This handler is invoked instead of [`Self::handle_global`] if the interface of
+    /// the global is not known to this crate. This only happens if the state was
+    /// configured to forward such globals. See
+    /// [`StateBuilder::with_unknown_interfaces`](crate::state::StateBuilder::with_unknown_interfaces).
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: numeric name of the global object
+    /// - `interface`: interface implemented by the object
+    /// - `version`: interface version
+    #[inline]
+    fn handle_unknown_global(
+        &mut self,
+        slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        if !slf.core.forward_to_client.get() {
+            return;
+        }
+        let res = slf.try_send_unknown_global(
+            name,
+            interface,
+            version,
+        );
+        if let Err(e) = res {
+            log_forward("wl_registry.global", &e);
+        }
+    }
 }
 
 impl ObjectPrivate for WlRegistry {
@@ -506,10 +663,17 @@ impl ObjectPrivate for WlRegistry {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1_interface: &str, arg1_id: u32, arg1_version: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wl_registry#{}.bind(name: {}, id: {}#{} (version: {}))\n", client_id, id, arg0, arg1_interface, arg1_id, arg1_version);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wl_registry",
+                            id,
+                            "bind",
+                            &[
+                                LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("id"), LogValue::NewId { interface: arg1_interface, id: arg1_id, version: Some(arg1_version) }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1_interface, arg1, arg1_version);
                 }
@@ -559,14 +723,32 @@ impl ObjectPrivate for WlRegistry {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: &str, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wl_registry#{}.global(name: {}, interface: {:?}, version: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wl_registry",
+                            id,
+                            "global",
+                            &[
+                                LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("interface"), LogValue::String(Some(arg1))),
+                                LogArg::new(Some("version"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
                 let Some(arg1) = ObjectInterface::from_str(arg1) else {
+                    let Some(max_version) = self.core.state.unknown_interfaces.max_version(arg1) else {
+                        return Ok(());
+                    };
+                    self.names.borrow_mut().insert(arg0);
+                    let arg2 = max_version.min(arg2);
+                    if let Some(handler) = handler {
+                        (**handler).handle_unknown_global(&self, arg0, arg1, arg2);
+                    } else {
+                        DefaultHandler.handle_unknown_global(&self, arg0, arg1, arg2);
+                    }
                     return Ok(());
                 };
                 let max_version = self.core.state.baseline.1[arg1];
@@ -591,10 +773,16 @@ impl ObjectPrivate for WlRegistry {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wl_registry#{}.global_remove(name: {})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wl_registry",
+                            id,
+                            "global_remove",
+                            &[
+                                LogArg::new(Some("name"), LogValue::Uint(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -635,6 +823,23 @@ impl ObjectPrivate for WlRegistry {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "un",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "usu",
+            1 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
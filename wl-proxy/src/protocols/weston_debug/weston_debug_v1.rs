@@ -85,10 +85,15 @@ impl WestonDebugV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_debug_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_debug_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -160,10 +165,17 @@ impl WestonDebugV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: Option<&str>) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= weston_debug_v1#{}.available(name: {:?}, description: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "weston_debug_v1",
+                    id,
+                    "available",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("description"), LogValue::String(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -263,10 +275,18 @@ impl WestonDebugV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str, arg1: i32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_debug_v1#{}.subscribe(name: {:?}, streamfd: {}, stream: weston_debug_stream_v1#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_debug_v1",
+                    id,
+                    "subscribe",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("streamfd"), LogValue::Fd(arg1)),
+                        LogArg::new(Some("stream"), LogValue::NewId { interface: "weston_debug_stream_v1", id: arg2, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1.as_raw_fd(), arg2_id);
         }
@@ -523,10 +543,15 @@ impl ObjectPrivate for WestonDebugV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_debug_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_debug_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -556,10 +581,18 @@ impl ObjectPrivate for WestonDebugV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: i32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_debug_v1#{}.subscribe(name: {:?}, streamfd: {}, stream: weston_debug_stream_v1#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_debug_v1",
+                            id,
+                            "subscribe",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("streamfd"), LogValue::Fd(arg1)),
+                                LogArg::new(Some("stream"), LogValue::NewId { interface: "weston_debug_stream_v1", id: arg2, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1.as_raw_fd(), arg2);
                 }
@@ -604,10 +637,17 @@ impl ObjectPrivate for WestonDebugV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str, arg1: Option<&str>) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> weston_debug_v1#{}.available(name: {:?}, description: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "weston_debug_v1",
+                            id,
+                            "available",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("description"), LogValue::String(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -645,6 +685,23 @@ impl ObjectPrivate for WestonDebugV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "shn[weston_debug_stream_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "s?s",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -92,10 +92,18 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.move_surface(surface: wl_surface#{}, x: {}, y: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "move_surface",
+                    &[
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                        LogArg::new(Some("x"), LogValue::Int(arg1)),
+                        LogArg::new(Some("y"), LogValue::Int(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2);
         }
@@ -180,10 +188,20 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: i32, arg4: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.move_pointer(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, x: {}, y: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "move_pointer",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("x"), LogValue::Int(arg3)),
+                        LogArg::new(Some("y"), LogValue::Int(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -276,10 +294,20 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: i32, arg4: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.send_button(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, button: {}, state: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "send_button",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("button"), LogValue::Int(arg3)),
+                        LogArg::new(Some("state"), LogValue::Uint(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -372,10 +400,20 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.send_axis(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, axis: {}, value: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "send_axis",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("axis"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("value"), LogValue::Fixed(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -460,10 +498,16 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.activate_surface(surface: wl_surface#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "activate_surface",
+                    &[
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -540,10 +584,20 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.send_key(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, key: {}, state: {})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "send_key",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("key"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("state"), LogValue::Uint(arg4)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4);
         }
@@ -620,10 +674,16 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.device_release(device: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "device_release",
+                    &[
+                        LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -684,10 +744,16 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.device_add(device: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "device_add",
+                    &[
+                        LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -754,10 +820,17 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: Fixed, arg1: Fixed) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= weston_test#{}.pointer_position(x: {}, y: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "pointer_position",
+                    &[
+                        LogArg::new(Some("x"), LogValue::Fixed(arg0)),
+                        LogArg::new(Some("y"), LogValue::Fixed(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -844,10 +917,22 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: i32, arg4: Fixed, arg5: Fixed, arg6: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.send_touch(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, touch_id: {}, x: {}, y: {}, touch_type: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "send_touch",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("touch_id"), LogValue::Int(arg3)),
+                        LogArg::new(Some("x"), LogValue::Fixed(arg4)),
+                        LogArg::new(Some("y"), LogValue::Fixed(arg5)),
+                        LogArg::new(Some("touch_type"), LogValue::Uint(arg6)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
         }
@@ -942,10 +1027,17 @@ impl WestonTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: WestonTestBreakpoint, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_test#{}.client_break(breakpoint: {:?}, resource_id: {})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_test",
+                    id,
+                    "client_break",
+                    &[
+                        LogArg::new(Some("breakpoint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                        LogArg::new(Some("resource_id"), LogValue::Uint(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -1354,10 +1446,18 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.move_surface(surface: wl_surface#{}, x: {}, y: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "move_surface",
+                            &[
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                                LogArg::new(Some("x"), LogValue::Int(arg1)),
+                                LogArg::new(Some("y"), LogValue::Int(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -1392,10 +1492,20 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: i32, arg4: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.move_pointer(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, x: {}, y: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "move_pointer",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("x"), LogValue::Int(arg3)),
+                                LogArg::new(Some("y"), LogValue::Int(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -1420,10 +1530,20 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: i32, arg4: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.send_button(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, button: {}, state: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "send_button",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("button"), LogValue::Int(arg3)),
+                                LogArg::new(Some("state"), LogValue::Uint(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -1448,10 +1568,20 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.send_axis(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, axis: {}, value: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "send_axis",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("axis"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("value"), LogValue::Fixed(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -1471,10 +1601,16 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.activate_surface(surface: wl_surface#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "activate_surface",
+                            &[
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1512,10 +1648,20 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.send_key(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, key: {}, state: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "send_key",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("key"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("state"), LogValue::Uint(arg4)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -1536,10 +1682,16 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.device_release(device: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "device_release",
+                            &[
+                                LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1560,10 +1712,16 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.device_add(device: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "device_add",
+                            &[
+                                LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1592,10 +1750,22 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: i32, arg4: Fixed, arg5: Fixed, arg6: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.send_touch(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {}, touch_id: {}, x: {}, y: {}, touch_type: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "send_touch",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("touch_id"), LogValue::Int(arg3)),
+                                LogArg::new(Some("x"), LogValue::Fixed(arg4)),
+                                LogArg::new(Some("y"), LogValue::Fixed(arg5)),
+                                LogArg::new(Some("touch_type"), LogValue::Uint(arg6)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6);
                 }
@@ -1617,10 +1787,17 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: WestonTestBreakpoint, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_test#{}.client_break(breakpoint: {:?}, resource_id: {})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "client_break",
+                            &[
+                                LogArg::new(Some("breakpoint"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                                LogArg::new(Some("resource_id"), LogValue::Uint(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -1660,10 +1837,17 @@ impl ObjectPrivate for WestonTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: Fixed, arg1: Fixed) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> weston_test#{}.pointer_position(x: {}, y: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "weston_test",
+                            id,
+                            "pointer_position",
+                            &[
+                                LogArg::new(Some("x"), LogValue::Fixed(arg0)),
+                                LogArg::new(Some("y"), LogValue::Fixed(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -1709,6 +1893,31 @@ impl ObjectPrivate for WestonTest {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "oii",
+            1 => "uuuii",
+            2 => "uuuiu",
+            3 => "uuuuf",
+            4 => "?o",
+            5 => "uuuuu",
+            6 => "s",
+            7 => "s",
+            8 => "uuuiffu",
+            9 => "uu",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "ff",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -94,10 +94,15 @@ impl WestonTouchCalibration {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_touch_calibration#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_touch_calibration",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -189,10 +194,18 @@ impl WestonTouchCalibration {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &str, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_touch_calibration#{}.create_calibrator(surface: wl_surface#{}, device: {:?}, cal: weston_touch_calibrator#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_touch_calibration",
+                    id,
+                    "create_calibrator",
+                    &[
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                        LogArg::new(Some("device"), LogValue::String(Some(arg1))),
+                        LogArg::new(Some("cal"), LogValue::NewId { interface: "weston_touch_calibrator", id: arg2, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id);
         }
@@ -367,10 +380,17 @@ impl WestonTouchCalibration {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str, arg1: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= weston_touch_calibration#{}.save(device: {:?}, matrix: {})\n", id, arg0, debug_array(arg1));
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "weston_touch_calibration",
+                    id,
+                    "save",
+                    &[
+                        LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("matrix"), LogValue::Array(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0, arg1);
         }
@@ -475,10 +495,17 @@ impl WestonTouchCalibration {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= weston_touch_calibration#{}.touch_device(device: {:?}, head: {:?})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "weston_touch_calibration",
+                    id,
+                    "touch_device",
+                    &[
+                        LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                        LogArg::new(Some("head"), LogValue::String(Some(arg1))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1);
         }
@@ -719,10 +746,15 @@ impl ObjectPrivate for WestonTouchCalibration {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_touch_calibration#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_touch_calibration",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -752,10 +784,18 @@ impl ObjectPrivate for WestonTouchCalibration {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &str, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_touch_calibration#{}.create_calibrator(surface: wl_surface#{}, device: {:?}, cal: weston_touch_calibrator#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_touch_calibration",
+                            id,
+                            "create_calibrator",
+                            &[
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg0 }),
+                                LogArg::new(Some("device"), LogValue::String(Some(arg1))),
+                                LogArg::new(Some("cal"), LogValue::NewId { interface: "weston_touch_calibrator", id: arg2, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -792,10 +832,17 @@ impl ObjectPrivate for WestonTouchCalibration {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str, arg1: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> weston_touch_calibration#{}.save(device: {:?}, matrix: {})\n", client_id, id, arg0, debug_array(arg1));
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "weston_touch_calibration",
+                            id,
+                            "save",
+                            &[
+                                LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("matrix"), LogValue::Array(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -835,10 +882,17 @@ impl ObjectPrivate for WestonTouchCalibration {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str, arg1: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> weston_touch_calibration#{}.touch_device(device: {:?}, head: {:?})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "weston_touch_calibration",
+                            id,
+                            "touch_device",
+                            &[
+                                LogArg::new(Some("device"), LogValue::String(Some(arg0))),
+                                LogArg::new(Some("head"), LogValue::String(Some(arg1))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1);
                 }
@@ -877,6 +931,24 @@ impl ObjectPrivate for WestonTouchCalibration {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "osn[weston_touch_calibrator]",
+            2 => "sa",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "ss",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
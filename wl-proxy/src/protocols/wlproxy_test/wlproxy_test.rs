@@ -60,10 +60,15 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -119,10 +124,16 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.recv_fd(fd: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "recv_fd",
+                    &[
+                        LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0.as_raw_fd());
         }
@@ -192,10 +203,17 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: &[u8]) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.echo_array(echo: wlproxy_test_array_echo#{}, array: {})\n", id, arg0, debug_array(arg1));
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "echo_array",
+                    &[
+                        LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_array_echo", id: arg0, version: None }),
+                        LogArg::new(Some("array"), LogValue::Array(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1);
         }
@@ -305,10 +323,18 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.echo_fd(echo: wlproxy_test_fd_echo#{}, fd1: {}, fd2: {})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "echo_fd",
+                    &[
+                        LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_fd_echo", id: arg0, version: None }),
+                        LogArg::new(Some("fd1"), LogValue::Fd(arg1)),
+                        LogArg::new(Some("fd2"), LogValue::Fd(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1.as_raw_fd(), arg2.as_raw_fd());
         }
@@ -406,10 +432,15 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.send_many_events()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "send_many_events",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -457,10 +488,15 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wlproxy_test#{}.many_event()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "many_event",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -518,10 +554,16 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.count_hops(id: wlproxy_test_hops#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "count_hops",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wlproxy_test_hops", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -609,10 +651,16 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.create_dummy(id: wlproxy_test_dummy#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "create_dummy",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wlproxy_test_dummy", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -709,10 +757,17 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.echo_object(echo: wlproxy_test_object_echo#{}, object: unknown#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "echo_object",
+                    &[
+                        LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_object_echo", id: arg0, version: None }),
+                        LogArg::new(Some("object"), LogValue::Object { interface: None, id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -800,10 +855,15 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.send_object()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "send_object",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -865,10 +925,16 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wlproxy_test#{}.sent_object(echo: wlproxy_test_server_sent#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "sent_object",
+                    &[
+                        LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_server_sent", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -954,10 +1020,16 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.create_server_sent(echo: wlproxy_test_server_sent#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "create_server_sent",
+                    &[
+                        LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_server_sent", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -1045,10 +1117,16 @@ impl WlproxyTest {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= wlproxy_test#{}.create_non_forward(id: wlproxy_test_non_forward#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "wlproxy_test",
+                    id,
+                    "create_non_forward",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "wlproxy_test_non_forward", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -1408,10 +1486,15 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1434,10 +1517,16 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.recv_fd(fd: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "recv_fd",
+                            &[
+                                LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0.as_raw_fd());
                 }
@@ -1462,10 +1551,17 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: &[u8]) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.echo_array(echo: wlproxy_test_array_echo#{}, array: {})\n", client_id, id, arg0, debug_array(arg1));
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "echo_array",
+                            &[
+                                LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_array_echo", id: arg0, version: None }),
+                                LogArg::new(Some("array"), LogValue::Array(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -1498,10 +1594,18 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.echo_fd(echo: wlproxy_test_fd_echo#{}, fd1: {}, fd2: {})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "echo_fd",
+                            &[
+                                LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_fd_echo", id: arg0, version: None }),
+                                LogArg::new(Some("fd1"), LogValue::Fd(arg1)),
+                                LogArg::new(Some("fd2"), LogValue::Fd(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1.as_raw_fd(), arg2.as_raw_fd());
                 }
@@ -1524,10 +1628,15 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.send_many_events()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "send_many_events",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1547,10 +1656,16 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.count_hops(id: wlproxy_test_hops#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "count_hops",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wlproxy_test_hops", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1575,10 +1690,16 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.create_dummy(id: wlproxy_test_dummy#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "create_dummy",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wlproxy_test_dummy", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1604,10 +1725,17 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.echo_object(echo: wlproxy_test_object_echo#{}, object: unknown#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "echo_object",
+                            &[
+                                LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_object_echo", id: arg0, version: None }),
+                                LogArg::new(Some("object"), LogValue::Object { interface: None, id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -1634,10 +1762,15 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.send_object()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "send_object",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1657,10 +1790,16 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.create_server_sent(echo: wlproxy_test_server_sent#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "create_server_sent",
+                            &[
+                                LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_server_sent", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1685,10 +1824,16 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> wlproxy_test#{}.create_non_forward(id: wlproxy_test_non_forward#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "create_non_forward",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "wlproxy_test_non_forward", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1728,10 +1873,15 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wlproxy_test#{}.many_event()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "many_event",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1751,10 +1901,16 @@ impl ObjectPrivate for WlproxyTest {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wlproxy_test#{}.sent_object(echo: wlproxy_test_server_sent#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wlproxy_test",
+                            id,
+                            "sent_object",
+                            &[
+                                LogArg::new(Some("echo"), LogValue::NewId { interface: "wlproxy_test_server_sent", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1807,6 +1963,33 @@ impl ObjectPrivate for WlproxyTest {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "h",
+            2 => "n[wlproxy_test_array_echo]a",
+            3 => "n[wlproxy_test_fd_echo]hh",
+            4 => "",
+            5 => "n[wlproxy_test_hops]",
+            6 => "n[wlproxy_test_dummy]",
+            7 => "n[wlproxy_test_object_echo]o",
+            8 => "",
+            9 => "n[wlproxy_test_server_sent]",
+            10 => "n[wlproxy_test_non_forward]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[wlproxy_test_server_sent]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -75,10 +75,17 @@ impl WlproxyTestFdEcho {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: i32, arg1: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= wlproxy_test_fd_echo#{}.fd(fd1: {}, fd2: {})\n", client_id, id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "wlproxy_test_fd_echo",
+                    id,
+                    "fd",
+                    &[
+                        LogArg::new(Some("fd1"), LogValue::Fd(arg0)),
+                        LogArg::new(Some("fd2"), LogValue::Fd(arg1)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0.as_raw_fd(), arg1.as_raw_fd());
         }
@@ -214,10 +221,17 @@ impl ObjectPrivate for WlproxyTestFdEcho {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: i32, arg1: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> wlproxy_test_fd_echo#{}.fd(fd1: {}, fd2: {})\n", id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "wlproxy_test_fd_echo",
+                            id,
+                            "fd",
+                            &[
+                                LogArg::new(Some("fd1"), LogValue::Fd(arg0)),
+                                LogArg::new(Some("fd2"), LogValue::Fd(arg1)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0.as_raw_fd(), arg1.as_raw_fd());
                 }
@@ -252,6 +266,19 @@ impl ObjectPrivate for WlproxyTestFdEcho {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "hh",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -94,10 +94,16 @@ impl ZwlrDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_data_control_device_v1#{}.set_selection(source: zwlr_data_control_source_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_data_control_device_v1",
+                    id,
+                    "set_selection",
+                    &[
+                        LogArg::new(Some("source"), LogValue::Object { interface: Some("zwlr_data_control_source_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -163,10 +169,15 @@ impl ZwlrDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_data_control_device_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_data_control_device_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -243,10 +254,16 @@ impl ZwlrDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_data_control_device_v1#{}.data_offer(id: zwlr_data_control_offer_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_data_control_device_v1",
+                    id,
+                    "data_offer",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_data_control_offer_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -382,10 +399,16 @@ impl ZwlrDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_data_control_device_v1#{}.selection(id: zwlr_data_control_offer_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_data_control_device_v1",
+                    id,
+                    "selection",
+                    &[
+                        LogArg::new(Some("id"), LogValue::Object { interface: Some("zwlr_data_control_offer_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -456,10 +479,15 @@ impl ZwlrDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_data_control_device_v1#{}.finished()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_data_control_device_v1",
+                    id,
+                    "finished",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -541,10 +569,16 @@ impl ZwlrDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_data_control_device_v1#{}.primary_selection(id: zwlr_data_control_offer_v1#{})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_data_control_device_v1",
+                    id,
+                    "primary_selection",
+                    &[
+                        LogArg::new(Some("id"), LogValue::Object { interface: Some("zwlr_data_control_offer_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0_id);
         }
@@ -641,10 +675,16 @@ impl ZwlrDataControlDeviceV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_data_control_device_v1#{}.set_primary_selection(source: zwlr_data_control_source_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_data_control_device_v1",
+                    id,
+                    "set_primary_selection",
+                    &[
+                        LogArg::new(Some("source"), LogValue::Object { interface: Some("zwlr_data_control_source_v1"), id: arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -977,10 +1017,16 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_data_control_device_v1#{}.set_selection(source: zwlr_data_control_source_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_data_control_device_v1",
+                            id,
+                            "set_selection",
+                            &[
+                                LogArg::new(Some("source"), LogValue::Object { interface: Some("zwlr_data_control_source_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1012,10 +1058,15 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_data_control_device_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_data_control_device_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1036,10 +1087,16 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_data_control_device_v1#{}.set_primary_selection(source: zwlr_data_control_source_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_data_control_device_v1",
+                            id,
+                            "set_primary_selection",
+                            &[
+                                LogArg::new(Some("source"), LogValue::Object { interface: Some("zwlr_data_control_source_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1090,10 +1147,16 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_data_control_device_v1#{}.data_offer(id: zwlr_data_control_offer_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_data_control_device_v1",
+                            id,
+                            "data_offer",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_data_control_offer_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1118,10 +1181,16 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_data_control_device_v1#{}.selection(id: zwlr_data_control_offer_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_data_control_device_v1",
+                            id,
+                            "selection",
+                            &[
+                                LogArg::new(Some("id"), LogValue::Object { interface: Some("zwlr_data_control_offer_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1153,10 +1222,15 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_data_control_device_v1#{}.finished()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_data_control_device_v1",
+                            id,
+                            "finished",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1176,10 +1250,16 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_data_control_device_v1#{}.primary_selection(id: zwlr_data_control_offer_v1#{})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_data_control_device_v1",
+                            id,
+                            "primary_selection",
+                            &[
+                                LogArg::new(Some("id"), LogValue::Object { interface: Some("zwlr_data_control_offer_v1"), id: arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1235,6 +1315,27 @@ impl ObjectPrivate for ZwlrDataControlDeviceV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "?o",
+            1 => "",
+            2 => "?o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwlr_data_control_offer_v1]",
+            1 => "?o",
+            2 => "",
+            3 => "?o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
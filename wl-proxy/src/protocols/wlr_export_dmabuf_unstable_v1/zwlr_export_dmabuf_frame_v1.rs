@@ -134,10 +134,25 @@ impl ZwlrExportDmabufFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: ZwlrExportDmabufFrameV1Flags, arg6: u32, arg7: u32, arg8: u32, arg9: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_export_dmabuf_frame_v1#{}.frame(width: {}, height: {}, offset_x: {}, offset_y: {}, buffer_flags: {}, flags: {:?}, format: {}, mod_high: {}, mod_low: {}, num_objects: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_export_dmabuf_frame_v1",
+                    id,
+                    "frame",
+                    &[
+                        LogArg::new(Some("width"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("height"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("offset_x"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("offset_y"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("buffer_flags"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("flags"), LogValue::Enum { value: arg5.0, debug: &arg5 }),
+                        LogArg::new(Some("format"), LogValue::Uint(arg6)),
+                        LogArg::new(Some("mod_high"), LogValue::Uint(arg7)),
+                        LogArg::new(Some("mod_low"), LogValue::Uint(arg8)),
+                        LogArg::new(Some("num_objects"), LogValue::Uint(arg9)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
         }
@@ -272,10 +287,21 @@ impl ZwlrExportDmabufFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_export_dmabuf_frame_v1#{}.object(index: {}, fd: {}, size: {}, offset: {}, stride: {}, plane_index: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_export_dmabuf_frame_v1",
+                    id,
+                    "object",
+                    &[
+                        LogArg::new(Some("index"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                        LogArg::new(Some("size"), LogValue::Uint(arg2)),
+                        LogArg::new(Some("offset"), LogValue::Uint(arg3)),
+                        LogArg::new(Some("stride"), LogValue::Uint(arg4)),
+                        LogArg::new(Some("plane_index"), LogValue::Uint(arg5)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1.as_raw_fd(), arg2, arg3, arg4, arg5);
         }
@@ -387,10 +413,18 @@ impl ZwlrExportDmabufFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_export_dmabuf_frame_v1#{}.ready(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", client_id, id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_export_dmabuf_frame_v1",
+                    id,
+                    "ready",
+                    &[
+                        LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                        LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                        LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0, arg1, arg2);
         }
@@ -486,10 +520,16 @@ impl ZwlrExportDmabufFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: ZwlrExportDmabufFrameV1CancelReason) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= zwlr_export_dmabuf_frame_v1#{}.cancel(reason: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "zwlr_export_dmabuf_frame_v1",
+                    id,
+                    "cancel",
+                    &[
+                        LogArg::new(Some("reason"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -558,10 +598,15 @@ impl ZwlrExportDmabufFrameV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_export_dmabuf_frame_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_export_dmabuf_frame_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -841,10 +886,15 @@ impl ObjectPrivate for ZwlrExportDmabufFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_export_dmabuf_frame_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_export_dmabuf_frame_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -892,10 +942,25 @@ impl ObjectPrivate for ZwlrExportDmabufFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32, arg5: ZwlrExportDmabufFrameV1Flags, arg6: u32, arg7: u32, arg8: u32, arg9: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_export_dmabuf_frame_v1#{}.frame(width: {}, height: {}, offset_x: {}, offset_y: {}, buffer_flags: {}, flags: {:?}, format: {}, mod_high: {}, mod_low: {}, num_objects: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_export_dmabuf_frame_v1",
+                            id,
+                            "frame",
+                            &[
+                                LogArg::new(Some("width"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("height"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("offset_x"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("offset_y"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("buffer_flags"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("flags"), LogValue::Enum { value: arg5.0, debug: &arg5 }),
+                                LogArg::new(Some("format"), LogValue::Uint(arg6)),
+                                LogArg::new(Some("mod_high"), LogValue::Uint(arg7)),
+                                LogArg::new(Some("mod_low"), LogValue::Uint(arg8)),
+                                LogArg::new(Some("num_objects"), LogValue::Uint(arg9)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
                 }
@@ -923,10 +988,21 @@ impl ObjectPrivate for ZwlrExportDmabufFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: u32, arg3: u32, arg4: u32, arg5: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_export_dmabuf_frame_v1#{}.object(index: {}, fd: {}, size: {}, offset: {}, stride: {}, plane_index: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_export_dmabuf_frame_v1",
+                            id,
+                            "object",
+                            &[
+                                LogArg::new(Some("index"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("fd"), LogValue::Fd(arg1)),
+                                LogArg::new(Some("size"), LogValue::Uint(arg2)),
+                                LogArg::new(Some("offset"), LogValue::Uint(arg3)),
+                                LogArg::new(Some("stride"), LogValue::Uint(arg4)),
+                                LogArg::new(Some("plane_index"), LogValue::Uint(arg5)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1.as_raw_fd(), arg2, arg3, arg4, arg5);
                 }
@@ -948,10 +1024,18 @@ impl ObjectPrivate for ZwlrExportDmabufFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_export_dmabuf_frame_v1#{}.ready(tv_sec_hi: {}, tv_sec_lo: {}, tv_nsec: {})\n", id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_export_dmabuf_frame_v1",
+                            id,
+                            "ready",
+                            &[
+                                LogArg::new(Some("tv_sec_hi"), LogValue::Uint(arg0)),
+                                LogArg::new(Some("tv_sec_lo"), LogValue::Uint(arg1)),
+                                LogArg::new(Some("tv_nsec"), LogValue::Uint(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0, arg1, arg2);
                 }
@@ -972,10 +1056,16 @@ impl ObjectPrivate for ZwlrExportDmabufFrameV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: ZwlrExportDmabufFrameV1CancelReason) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> zwlr_export_dmabuf_frame_v1#{}.cancel(reason: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "zwlr_export_dmabuf_frame_v1",
+                            id,
+                            "cancel",
+                            &[
+                                LogArg::new(Some("reason"), LogValue::Enum { value: arg0.0, debug: &arg0 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1015,6 +1105,25 @@ impl ObjectPrivate for ZwlrExportDmabufFrameV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "uuuuuuuuuu",
+            1 => "uhuuuu",
+            2 => "uuu",
+            3 => "u",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -95,10 +95,18 @@ impl ZwlrExportDmabufManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_export_dmabuf_manager_v1#{}.capture_output(frame: zwlr_export_dmabuf_frame_v1#{}, overlay_cursor: {}, output: wl_output#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_export_dmabuf_manager_v1",
+                    id,
+                    "capture_output",
+                    &[
+                        LogArg::new(Some("frame"), LogValue::NewId { interface: "zwlr_export_dmabuf_frame_v1", id: arg0, version: None }),
+                        LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id);
         }
@@ -212,10 +220,15 @@ impl ZwlrExportDmabufManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_export_dmabuf_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_export_dmabuf_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -354,10 +367,18 @@ impl ObjectPrivate for ZwlrExportDmabufManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_export_dmabuf_manager_v1#{}.capture_output(frame: zwlr_export_dmabuf_frame_v1#{}, overlay_cursor: {}, output: wl_output#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_export_dmabuf_manager_v1",
+                            id,
+                            "capture_output",
+                            &[
+                                LogArg::new(Some("frame"), LogValue::NewId { interface: "zwlr_export_dmabuf_frame_v1", id: arg0, version: None }),
+                                LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -389,10 +410,15 @@ impl ObjectPrivate for ZwlrExportDmabufManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_export_dmabuf_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_export_dmabuf_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -444,6 +470,20 @@ impl ObjectPrivate for ZwlrExportDmabufManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwlr_export_dmabuf_frame_v1]io",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
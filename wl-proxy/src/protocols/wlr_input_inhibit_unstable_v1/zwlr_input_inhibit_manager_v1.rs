@@ -89,10 +89,16 @@ impl ZwlrInputInhibitManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_input_inhibit_manager_v1#{}.get_inhibitor(id: zwlr_input_inhibitor_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_input_inhibit_manager_v1",
+                    id,
+                    "get_inhibitor",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_input_inhibitor_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -237,10 +243,16 @@ impl ObjectPrivate for ZwlrInputInhibitManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_input_inhibit_manager_v1#{}.get_inhibitor(id: zwlr_input_inhibitor_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_input_inhibit_manager_v1",
+                            id,
+                            "get_inhibitor",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_input_inhibitor_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -295,6 +307,19 @@ impl ObjectPrivate for ZwlrInputInhibitManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwlr_input_inhibitor_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
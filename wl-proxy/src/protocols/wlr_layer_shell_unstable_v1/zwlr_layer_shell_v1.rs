@@ -137,10 +137,20 @@ impl ZwlrLayerShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: ZwlrLayerShellV1Layer, arg4: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_layer_shell_v1#{}.get_layer_surface(id: zwlr_layer_surface_v1#{}, surface: wl_surface#{}, output: wl_output#{}, layer: {:?}, namespace: {:?})\n", id, arg0, arg1, arg2, arg3, arg4);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_layer_shell_v1",
+                    id,
+                    "get_layer_surface",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_layer_surface_v1", id: arg0, version: None }),
+                        LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                        LogArg::new(Some("layer"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                        LogArg::new(Some("namespace"), LogValue::String(Some(arg4))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2_id, arg3, arg4);
         }
@@ -335,10 +345,15 @@ impl ZwlrLayerShellV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_layer_shell_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_layer_shell_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -520,10 +535,20 @@ impl ObjectPrivate for ZwlrLayerShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: u32, arg3: ZwlrLayerShellV1Layer, arg4: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_layer_shell_v1#{}.get_layer_surface(id: zwlr_layer_surface_v1#{}, surface: wl_surface#{}, output: wl_output#{}, layer: {:?}, namespace: {:?})\n", client_id, id, arg0, arg1, arg2, arg3, arg4);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_layer_shell_v1",
+                            id,
+                            "get_layer_surface",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zwlr_layer_surface_v1", id: arg0, version: None }),
+                                LogArg::new(Some("surface"), LogValue::Object { interface: Some("wl_surface"), id: arg1 }),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                                LogArg::new(Some("layer"), LogValue::Enum { value: arg3.0, debug: &arg3 }),
+                                LogArg::new(Some("namespace"), LogValue::String(Some(arg4))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4);
                 }
@@ -569,10 +594,15 @@ impl ObjectPrivate for ZwlrLayerShellV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_layer_shell_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_layer_shell_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -624,6 +654,20 @@ impl ObjectPrivate for ZwlrLayerShellV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwlr_layer_surface_v1]o?ous",
+            1 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
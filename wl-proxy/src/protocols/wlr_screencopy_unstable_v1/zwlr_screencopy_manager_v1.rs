@@ -96,10 +96,18 @@ impl ZwlrScreencopyManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_screencopy_manager_v1#{}.capture_output(frame: zwlr_screencopy_frame_v1#{}, overlay_cursor: {}, output: wl_output#{})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_screencopy_manager_v1",
+                    id,
+                    "capture_output",
+                    &[
+                        LogArg::new(Some("frame"), LogValue::NewId { interface: "zwlr_screencopy_frame_v1", id: arg0, version: None }),
+                        LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id);
         }
@@ -260,10 +268,22 @@ impl ZwlrScreencopyManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: i32, arg2: u32, arg3: i32, arg4: i32, arg5: i32, arg6: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_screencopy_manager_v1#{}.capture_output_region(frame: zwlr_screencopy_frame_v1#{}, overlay_cursor: {}, output: wl_output#{}, x: {}, y: {}, width: {}, height: {})\n", id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_screencopy_manager_v1",
+                    id,
+                    "capture_output_region",
+                    &[
+                        LogArg::new(Some("frame"), LogValue::NewId { interface: "zwlr_screencopy_frame_v1", id: arg0, version: None }),
+                        LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                        LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                        LogArg::new(Some("x"), LogValue::Int(arg3)),
+                        LogArg::new(Some("y"), LogValue::Int(arg4)),
+                        LogArg::new(Some("width"), LogValue::Int(arg5)),
+                        LogArg::new(Some("height"), LogValue::Int(arg6)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2_id, arg3, arg4, arg5, arg6);
         }
@@ -429,10 +449,15 @@ impl ZwlrScreencopyManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwlr_screencopy_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwlr_screencopy_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -620,10 +645,18 @@ impl ObjectPrivate for ZwlrScreencopyManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_screencopy_manager_v1#{}.capture_output(frame: zwlr_screencopy_frame_v1#{}, overlay_cursor: {}, output: wl_output#{})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_screencopy_manager_v1",
+                            id,
+                            "capture_output",
+                            &[
+                                LogArg::new(Some("frame"), LogValue::NewId { interface: "zwlr_screencopy_frame_v1", id: arg0, version: None }),
+                                LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -668,10 +701,22 @@ impl ObjectPrivate for ZwlrScreencopyManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: i32, arg2: u32, arg3: i32, arg4: i32, arg5: i32, arg6: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_screencopy_manager_v1#{}.capture_output_region(frame: zwlr_screencopy_frame_v1#{}, overlay_cursor: {}, output: wl_output#{}, x: {}, y: {}, width: {}, height: {})\n", client_id, id, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_screencopy_manager_v1",
+                            id,
+                            "capture_output_region",
+                            &[
+                                LogArg::new(Some("frame"), LogValue::NewId { interface: "zwlr_screencopy_frame_v1", id: arg0, version: None }),
+                                LogArg::new(Some("overlay_cursor"), LogValue::Int(arg1)),
+                                LogArg::new(Some("output"), LogValue::Object { interface: Some("wl_output"), id: arg2 }),
+                                LogArg::new(Some("x"), LogValue::Int(arg3)),
+                                LogArg::new(Some("y"), LogValue::Int(arg4)),
+                                LogArg::new(Some("width"), LogValue::Int(arg5)),
+                                LogArg::new(Some("height"), LogValue::Int(arg6)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2, arg3, arg4, arg5, arg6);
                 }
@@ -703,10 +748,15 @@ impl ObjectPrivate for ZwlrScreencopyManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwlr_screencopy_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwlr_screencopy_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -759,6 +809,21 @@ impl ObjectPrivate for ZwlrScreencopyManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "n[zwlr_screencopy_frame_v1]io",
+            1 => "n[zwlr_screencopy_frame_v1]ioiiii",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
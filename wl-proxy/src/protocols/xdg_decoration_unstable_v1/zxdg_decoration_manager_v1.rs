@@ -89,10 +89,15 @@ impl ZxdgDecorationManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zxdg_decoration_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zxdg_decoration_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -191,10 +196,17 @@ impl ZxdgDecorationManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zxdg_decoration_manager_v1#{}.get_toplevel_decoration(id: zxdg_toplevel_decoration_v1#{}, toplevel: xdg_toplevel#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zxdg_decoration_manager_v1",
+                    id,
+                    "get_toplevel_decoration",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "zxdg_toplevel_decoration_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -458,10 +470,15 @@ impl ObjectPrivate for ZxdgDecorationManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zxdg_decoration_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zxdg_decoration_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -483,10 +500,17 @@ impl ObjectPrivate for ZxdgDecorationManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zxdg_decoration_manager_v1#{}.get_toplevel_decoration(id: zxdg_toplevel_decoration_v1#{}, toplevel: xdg_toplevel#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zxdg_decoration_manager_v1",
+                            id,
+                            "get_toplevel_decoration",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "zxdg_toplevel_decoration_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -551,6 +575,20 @@ impl ObjectPrivate for ZxdgDecorationManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[zxdg_toplevel_decoration_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -78,10 +78,15 @@ impl XdgWmDialogV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_wm_dialog_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_wm_dialog_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -163,10 +168,17 @@ impl XdgWmDialogV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_wm_dialog_v1#{}.get_xdg_dialog(id: xdg_dialog_v1#{}, toplevel: xdg_toplevel#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_wm_dialog_v1",
+                    id,
+                    "get_xdg_dialog",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_dialog_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -362,10 +374,15 @@ impl ObjectPrivate for XdgWmDialogV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_wm_dialog_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_wm_dialog_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -387,10 +404,17 @@ impl ObjectPrivate for XdgWmDialogV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_wm_dialog_v1#{}.get_xdg_dialog(id: xdg_dialog_v1#{}, toplevel: xdg_toplevel#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_wm_dialog_v1",
+                            id,
+                            "get_xdg_dialog",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_dialog_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -455,6 +479,20 @@ impl ObjectPrivate for XdgWmDialogV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[xdg_dialog_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
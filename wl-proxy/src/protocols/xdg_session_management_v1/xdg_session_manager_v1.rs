@@ -77,10 +77,15 @@ impl XdgSessionManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_session_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_session_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -179,10 +184,18 @@ impl XdgSessionManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: XdgSessionManagerV1Reason, arg2: Option<&str>) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_session_manager_v1#{}.get_session(id: xdg_session_v1#{}, reason: {:?}, session_id: {:?})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_session_manager_v1",
+                    id,
+                    "get_session",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_session_v1", id: arg0, version: None }),
+                        LogArg::new(Some("reason"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                        LogArg::new(Some("session_id"), LogValue::String(arg2)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1, arg2);
         }
@@ -464,10 +477,15 @@ impl ObjectPrivate for XdgSessionManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_session_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_session_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -498,10 +516,18 @@ impl ObjectPrivate for XdgSessionManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: XdgSessionManagerV1Reason, arg2: Option<&str>) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_session_manager_v1#{}.get_session(id: xdg_session_v1#{}, reason: {:?}, session_id: {:?})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_session_manager_v1",
+                            id,
+                            "get_session",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_session_v1", id: arg0, version: None }),
+                                LogArg::new(Some("reason"), LogValue::Enum { value: arg1.0, debug: &arg1 }),
+                                LogArg::new(Some("session_id"), LogValue::String(arg2)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -557,6 +583,20 @@ impl ObjectPrivate for XdgSessionManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[xdg_session_v1]u?s",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
@@ -75,10 +75,15 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_session_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -135,10 +140,15 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_session_v1#{}.remove()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "remove",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -241,10 +251,18 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_session_v1#{}.add_toplevel(id: xdg_toplevel_session_v1#{}, toplevel: xdg_toplevel#{}, name: {:?})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "add_toplevel",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_toplevel_session_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                        LogArg::new(Some("name"), LogValue::String(Some(arg2))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2);
         }
@@ -468,10 +486,18 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32, arg2: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_session_v1#{}.restore_toplevel(id: xdg_toplevel_session_v1#{}, toplevel: xdg_toplevel#{}, name: {:?})\n", id, arg0, arg1, arg2);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "restore_toplevel",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_toplevel_session_v1", id: arg0, version: None }),
+                        LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                        LogArg::new(Some("name"), LogValue::String(Some(arg2))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id, arg2);
         }
@@ -670,10 +696,16 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_session_v1#{}.remove_toplevel(name: {:?})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "remove_toplevel",
+                    &[
+                        LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0);
         }
@@ -754,10 +786,16 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= xdg_session_v1#{}.created(session_id: {:?})\n", client_id, id, arg0);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "created",
+                    &[
+                        LogArg::new(Some("session_id"), LogValue::String(Some(arg0))),
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id, arg0);
         }
@@ -821,10 +859,15 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= xdg_session_v1#{}.restored()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "restored",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -880,10 +923,15 @@ impl XdgSessionV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, client_id: u64, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} <= xdg_session_v1#{}.replaced()\n", client_id, id);
-                state.log(args);
+                state.log(
+                    Some(client_id),
+                    Direction::Sent,
+                    "xdg_session_v1",
+                    id,
+                    "replaced",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, client.endpoint.id, id);
         }
@@ -1210,10 +1258,15 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_session_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1232,10 +1285,15 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_session_v1#{}.remove()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "remove",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -1265,10 +1323,18 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_session_v1#{}.add_toplevel(id: xdg_toplevel_session_v1#{}, toplevel: xdg_toplevel#{}, name: {:?})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "add_toplevel",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_toplevel_session_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                                LogArg::new(Some("name"), LogValue::String(Some(arg2))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -1311,10 +1377,18 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32, arg2: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_session_v1#{}.restore_toplevel(id: xdg_toplevel_session_v1#{}, toplevel: xdg_toplevel#{}, name: {:?})\n", client_id, id, arg0, arg1, arg2);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "restore_toplevel",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_toplevel_session_v1", id: arg0, version: None }),
+                                LogArg::new(Some("toplevel"), LogValue::Object { interface: Some("xdg_toplevel"), id: arg1 }),
+                                LogArg::new(Some("name"), LogValue::String(Some(arg2))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1, arg2);
                 }
@@ -1349,10 +1423,16 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_session_v1#{}.remove_toplevel(name: {:?})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "remove_toplevel",
+                            &[
+                                LogArg::new(Some("name"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -1390,10 +1470,16 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32, arg0: &str) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> xdg_session_v1#{}.created(session_id: {:?})\n", id, arg0);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "created",
+                            &[
+                                LogArg::new(Some("session_id"), LogValue::String(Some(arg0))),
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0], arg0);
                 }
@@ -1411,10 +1497,15 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> xdg_session_v1#{}.restored()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "restored",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1432,10 +1523,15 @@ impl ObjectPrivate for XdgSessionV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      -> xdg_session_v1#{}.replaced()\n", id);
-                        state.log(args);
+                        state.log(
+                            None,
+                            Direction::Received,
+                            "xdg_session_v1",
+                            id,
+                            "replaced",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, msg[0]);
                 }
@@ -1478,6 +1574,28 @@ impl ObjectPrivate for XdgSessionV1 {
         Some(name)
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "",
+            2 => "n[xdg_toplevel_session_v1]os",
+            3 => "n[xdg_toplevel_session_v1]os",
+            4 => "s",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "s",
+            1 => "",
+            2 => "",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
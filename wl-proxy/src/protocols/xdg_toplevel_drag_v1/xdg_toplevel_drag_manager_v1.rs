@@ -101,10 +101,15 @@ impl XdgToplevelDragManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_toplevel_drag_manager_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_toplevel_drag_manager_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -192,10 +197,17 @@ impl XdgToplevelDragManagerV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32, arg1: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= xdg_toplevel_drag_manager_v1#{}.get_xdg_toplevel_drag(id: xdg_toplevel_drag_v1#{}, data_source: wl_data_source#{})\n", id, arg0, arg1);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "xdg_toplevel_drag_manager_v1",
+                    id,
+                    "get_xdg_toplevel_drag",
+                    &[
+                        LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_toplevel_drag_v1", id: arg0, version: None }),
+                        LogArg::new(Some("data_source"), LogValue::Object { interface: Some("wl_data_source"), id: arg1 }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id, arg1_id);
         }
@@ -412,10 +424,15 @@ impl ObjectPrivate for XdgToplevelDragManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_toplevel_drag_manager_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_toplevel_drag_manager_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -437,10 +454,17 @@ impl ObjectPrivate for XdgToplevelDragManagerV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32, arg1: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> xdg_toplevel_drag_manager_v1#{}.get_xdg_toplevel_drag(id: xdg_toplevel_drag_v1#{}, data_source: wl_data_source#{})\n", client_id, id, arg0, arg1);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "xdg_toplevel_drag_manager_v1",
+                            id,
+                            "get_xdg_toplevel_drag",
+                            &[
+                                LogArg::new(Some("id"), LogValue::NewId { interface: "xdg_toplevel_drag_v1", id: arg0, version: None }),
+                                LogArg::new(Some("data_source"), LogValue::Object { interface: Some("wl_data_source"), id: arg1 }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0, arg1);
                 }
@@ -505,6 +529,20 @@ impl ObjectPrivate for XdgToplevelDragManagerV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "n[xdg_toplevel_drag_v1]o",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
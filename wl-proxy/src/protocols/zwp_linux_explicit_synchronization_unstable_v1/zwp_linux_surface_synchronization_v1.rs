@@ -103,10 +103,15 @@ impl ZwpLinuxSurfaceSynchronizationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_surface_synchronization_v1#{}.destroy()\n", id);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_surface_synchronization_v1",
+                    id,
+                    "destroy",
+                    &[
+                    ],
+                );
             }
             log(&self.core.state, id);
         }
@@ -198,10 +203,16 @@ impl ZwpLinuxSurfaceSynchronizationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: i32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_surface_synchronization_v1#{}.set_acquire_fence(fd: {})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_surface_synchronization_v1",
+                    id,
+                    "set_acquire_fence",
+                    &[
+                        LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0.as_raw_fd());
         }
@@ -313,10 +324,16 @@ impl ZwpLinuxSurfaceSynchronizationV1 {
         if self.core.state.log {
             #[cold]
             fn log(state: &State, id: u32, arg0: u32) {
-                let (millis, micros) = time_since_epoch();
-                let prefix = &state.log_prefix;
-                let args = format_args!("[{millis:7}.{micros:03}] {prefix}server      <= zwp_linux_surface_synchronization_v1#{}.get_release(release: zwp_linux_buffer_release_v1#{})\n", id, arg0);
-                state.log(args);
+                state.log(
+                    None,
+                    Direction::Sent,
+                    "zwp_linux_surface_synchronization_v1",
+                    id,
+                    "get_release",
+                    &[
+                        LogArg::new(Some("release"), LogValue::NewId { interface: "zwp_linux_buffer_release_v1", id: arg0, version: None }),
+                    ],
+                );
             }
             log(&self.core.state, id, arg0_id);
         }
@@ -590,10 +607,15 @@ impl ObjectPrivate for ZwpLinuxSurfaceSynchronizationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_surface_synchronization_v1#{}.destroy()\n", client_id, id);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_surface_synchronization_v1",
+                            id,
+                            "destroy",
+                            &[
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0]);
                 }
@@ -616,10 +638,16 @@ impl ObjectPrivate for ZwpLinuxSurfaceSynchronizationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: i32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_surface_synchronization_v1#{}.set_acquire_fence(fd: {})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_surface_synchronization_v1",
+                            id,
+                            "set_acquire_fence",
+                            &[
+                                LogArg::new(Some("fd"), LogValue::Fd(arg0)),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0.as_raw_fd());
                 }
@@ -639,10 +667,16 @@ impl ObjectPrivate for ZwpLinuxSurfaceSynchronizationV1 {
                 if self.core.state.log {
                     #[cold]
                     fn log(state: &State, client_id: u64, id: u32, arg0: u32) {
-                        let (millis, micros) = time_since_epoch();
-                        let prefix = &state.log_prefix;
-                        let args = format_args!("[{millis:7}.{micros:03}] {prefix}client#{:<4} -> zwp_linux_surface_synchronization_v1#{}.get_release(release: zwp_linux_buffer_release_v1#{})\n", client_id, id, arg0);
-                        state.log(args);
+                        state.log(
+                            Some(client_id),
+                            Direction::Received,
+                            "zwp_linux_surface_synchronization_v1",
+                            id,
+                            "get_release",
+                            &[
+                                LogArg::new(Some("release"), LogValue::NewId { interface: "zwp_linux_buffer_release_v1", id: arg0, version: None }),
+                            ],
+                        );
                     }
                     log(&self.core.state, client.endpoint.id, msg[0], arg0);
                 }
@@ -699,6 +733,21 @@ impl ObjectPrivate for ZwpLinuxSurfaceSynchronizationV1 {
         None
     }
 
+    fn get_request_signature(&self, id: u32) -> Option<&'static str> {
+        let signature = match id {
+            0 => "",
+            1 => "h",
+            2 => "n[zwp_linux_buffer_release_v1]",
+            _ => return None,
+        };
+        Some(signature)
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&'static str> {
+        let _ = id;
+        None
+    }
+
     fn create_zombie(&self) -> Rc<dyn Object> {
         let slf = Self::new(&self.core.state, self.core.version);
         slf.core.make_zombie();
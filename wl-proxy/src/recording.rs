@@ -0,0 +1,666 @@
+//! Wire-level recording and replay of wayland connections.
+//!
+//! A state built with [`StateBuilder::with_recorder`] writes every message that it
+//! receives or sends to a binary capture. Each message is recorded with a timestamp,
+//! the endpoint it was received from or sent to, its raw words, and metadata about the
+//! file descriptors attached to it. The contents of memfds and regular files are
+//! recorded if they are not larger than 1 MiB.
+//!
+//! A capture can be loaded with [`Capture::parse`] or [`Capture::load`] and then be
+//! replayed into a state built with [`StateBuilder::without_server`] via
+//! [`Capture::replay`]. This allows bugs observed in a real session to be reproduced
+//! deterministically in unit tests.
+//!
+//! # Example
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use wl_proxy::baseline::Baseline;
+//! # use wl_proxy::recording::Capture;
+//! # use wl_proxy::state::State;
+//! # fn f(bytes: &[u8]) {
+//! let capture = Capture::parse(bytes).unwrap();
+//! let state = State::builder(Baseline::ALL_OF_THEM)
+//!     .without_server()
+//!     .build()
+//!     .unwrap();
+//! capture
+//!     .replay(&state, |_endpoint, client| {
+//!         // install handlers on the client
+//!     })
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! # Wireshark
+//!
+//! A state built with [`StateBuilder::with_pcapng_recorder`] instead writes the messages
+//! in the pcapng format that can be opened with Wireshark and other tools. Each
+//! endpoint is represented as a separate interface named `server` or `client#N`. The
+//! packets use the `USER0` link type. Their data starts with a 12 byte header
+//! containing the endpoint id (u64), the peer (u8, 0 for clients and 1 for the server),
+//! the direction (u8, 0 for received and 1 for sent messages), and the number of
+//! attached file descriptors (u16). The header is followed by the words of the message.
+//! All integers in the packet data are little-endian, independent of the byte order of
+//! the machine that wrote the file.
+//!
+//! The repository contains a Wireshark dissector for these packets in
+//! [`wireshark/wl-proxy.lua`](https://github.com/mahkoh/wl-proxy/blob/master/wireshark/wl-proxy.lua).
+//! It is generated from the same protocol files as this crate. After copying it to the
+//! Wireshark plugin directory, messages can be filtered by interface and message name,
+//! e.g. `wl_proxy.interface == "xdg_toplevel" && wl_proxy.message == "configure"`.
+//!
+//! [`StateBuilder::with_recorder`]: crate::state::StateBuilder::with_recorder
+//! [`StateBuilder::with_pcapng_recorder`]: crate::state::StateBuilder::with_pcapng_recorder
+//! [`StateBuilder::without_server`]: crate::state::StateBuilder::without_server
+
+use {
+    crate::{
+        client::Client,
+        state::{State, StateError},
+    },
+    error_reporter::Report,
+    std::{
+        cell::{Cell, RefCell},
+        collections::{HashMap, hash_map::Entry},
+        io::{self, BufWriter, Write},
+        mem::MaybeUninit,
+        os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd},
+        path::{Path, PathBuf},
+        rc::Rc,
+        slice,
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    },
+    thiserror::Error,
+    uapi::{Errno, Msghdr, MsghdrMut, c, sockaddr_none_mut, sockaddr_none_ref},
+};
+
+mod pcapng;
+#[cfg(test)]
+mod tests;
+
+const MAGIC: [u8; 8] = *b"WLPRXCAP";
+const VERSION: u32 = 1;
+const MAX_FD_CONTENTS: u64 = 1 << 20;
+
+/// The peer of an endpoint.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Peer {
+    /// The endpoint is a connection to a client.
+    Client,
+    /// The endpoint is the connection to the server.
+    Server,
+}
+
+/// The direction of a recorded message.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// The message was received from the peer.
+    Received,
+    /// The message was sent to the peer.
+    Sent,
+}
+
+/// The type of a recorded file descriptor.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FdKind {
+    /// A memfd.
+    Memfd,
+    /// A regular file.
+    File,
+    /// A pipe.
+    Pipe,
+    /// A socket.
+    Socket,
+    /// Any other kind of file descriptor.
+    Other,
+}
+
+/// A capture written by a state built with
+/// [`StateBuilder::with_recorder`](crate::state::StateBuilder::with_recorder).
+#[derive(Clone, Debug)]
+pub struct Capture {
+    start_time: SystemTime,
+    records: Vec<CaptureRecord>,
+}
+
+/// A message contained in a [`Capture`].
+#[derive(Clone, Debug)]
+pub struct CaptureRecord {
+    timestamp: Duration,
+    endpoint: u64,
+    peer: Peer,
+    direction: Direction,
+    words: Vec<u32>,
+    fds: Vec<CapturedFd>,
+}
+
+/// A file descriptor attached to a [`CaptureRecord`].
+#[derive(Clone, Debug)]
+pub struct CapturedFd {
+    kind: FdKind,
+    size: u64,
+    contents: Option<Vec<u8>>,
+}
+
+/// An error returned when a capture cannot be loaded or replayed.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct CaptureError(#[from] CaptureErrorKind);
+
+#[derive(Debug, Error)]
+enum CaptureErrorKind {
+    #[error("could not read {}", .0.display())]
+    ReadFile(PathBuf, #[source] io::Error),
+    #[error("the capture does not start with the capture magic")]
+    InvalidMagic,
+    #[error("unsupported capture version {0}")]
+    UnsupportedVersion(u32),
+    #[error("the capture is truncated")]
+    Truncated,
+    #[error("invalid {0} {1}")]
+    InvalidValue(&'static str, u64),
+    #[error(transparent)]
+    State(StateError),
+    #[error("could not create a file descriptor")]
+    CreateFd(#[source] io::Error),
+    #[error("could not write to a client socket")]
+    WriteToSocket(#[source] io::Error),
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum RecordingFormat {
+    Capture,
+    Pcapng,
+}
+
+pub(crate) struct Recorder {
+    writer: RefCell<Option<BufWriter<Box<dyn Write>>>>,
+    format: RecordingFormat,
+    start: Instant,
+    start_time: u64,
+    next_interface: Cell<u32>,
+}
+
+pub(crate) struct EndpointRecorder {
+    recorder: Rc<Recorder>,
+    endpoint: u64,
+    peer: Peer,
+    interface: u32,
+}
+
+impl Recorder {
+    pub(crate) fn new(writer: Box<dyn Write>, format: RecordingFormat) -> Rc<Self> {
+        let start_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        let slf = Rc::new(Self {
+            writer: RefCell::new(Some(BufWriter::new(writer))),
+            format,
+            start: Instant::now(),
+            start_time,
+            next_interface: Cell::new(0),
+        });
+        slf.write(|w| match format {
+            RecordingFormat::Capture => {
+                w.write_all(&MAGIC)?;
+                w.write_all(&VERSION.to_le_bytes())?;
+                w.write_all(&start_time.to_le_bytes())
+            }
+            RecordingFormat::Pcapng => pcapng::write_section_header(w),
+        });
+        slf
+    }
+
+    pub(crate) fn endpoint(self: &Rc<Self>, endpoint: u64, peer: Peer) -> Rc<EndpointRecorder> {
+        let interface = self.next_interface.get();
+        if self.format == RecordingFormat::Pcapng {
+            self.next_interface.set(interface + 1);
+            self.write(|w| pcapng::write_interface(w, endpoint, peer));
+        }
+        Rc::new(EndpointRecorder {
+            recorder: self.clone(),
+            endpoint,
+            peer,
+            interface,
+        })
+    }
+
+    pub(crate) fn flush(&self) {
+        self.write(|w| w.flush());
+    }
+
+    fn write(&self, f: impl FnOnce(&mut BufWriter<Box<dyn Write>>) -> io::Result<()>) {
+        let writer = &mut *self.writer.borrow_mut();
+        if let Some(w) = writer
+            && let Err(e) = f(w)
+        {
+            log::error!("Could not write to the recording: {}", Report::new(e));
+            *writer = None;
+        }
+    }
+}
+
+impl EndpointRecorder {
+    pub(crate) fn record<'a>(
+        &self,
+        direction: Direction,
+        words: &[u32],
+        fds: impl ExactSizeIterator<Item = &'a Rc<OwnedFd>>,
+    ) {
+        let timestamp = self.recorder.start.elapsed().as_nanos() as u64;
+        if self.recorder.format == RecordingFormat::Pcapng {
+            self.recorder.write(|w| {
+                pcapng::write_packet(
+                    w,
+                    self.interface,
+                    self.recorder.start_time.wrapping_add(timestamp),
+                    self.endpoint,
+                    self.peer,
+                    direction,
+                    fds.len(),
+                    words,
+                )
+            });
+            return;
+        }
+        self.recorder.write(|w| {
+            w.write_all(&timestamp.to_le_bytes())?;
+            w.write_all(&self.endpoint.to_le_bytes())?;
+            w.write_all(&[self.peer as u8, direction as u8])?;
+            w.write_all(&(fds.len() as u16).to_le_bytes())?;
+            w.write_all(&(words.len() as u32).to_le_bytes())?;
+            for word in words {
+                w.write_all(&word.to_le_bytes())?;
+            }
+            for fd in fds {
+                write_fd(w, fd.as_raw_fd())?;
+            }
+            Ok(())
+        });
+    }
+}
+
+fn write_fd(w: &mut impl Write, fd: RawFd) -> io::Result<()> {
+    let mut kind = FdKind::Other;
+    let mut size = 0;
+    let mut contents = None;
+    if let Ok(stat) = uapi::fstat(fd) {
+        kind = match stat.st_mode & c::S_IFMT {
+            c::S_IFREG if uapi::fcntl_get_seals(fd).is_ok() => FdKind::Memfd,
+            c::S_IFREG => FdKind::File,
+            c::S_IFIFO => FdKind::Pipe,
+            c::S_IFSOCK => FdKind::Socket,
+            _ => FdKind::Other,
+        };
+        if matches!(kind, FdKind::Memfd | FdKind::File) {
+            size = stat.st_size as u64;
+            if size <= MAX_FD_CONTENTS {
+                contents = read_contents(fd, size as usize);
+            }
+        }
+    }
+    w.write_all(&[kind as u8])?;
+    w.write_all(&size.to_le_bytes())?;
+    match &contents {
+        Some(contents) => {
+            w.write_all(&[1])?;
+            w.write_all(&(contents.len() as u64).to_le_bytes())?;
+            w.write_all(contents)
+        }
+        None => w.write_all(&[0]),
+    }
+}
+
+fn read_contents(fd: RawFd, size: usize) -> Option<Vec<u8>> {
+    let mut buf = vec![0u8; size];
+    let mut pos = 0;
+    while pos < size {
+        match uapi::pread(fd, &mut buf[pos..], pos as c::off_t) {
+            Ok([]) => break,
+            Ok(n) => pos += n.len(),
+            Err(Errno(c::EINTR)) => {}
+            Err(_) => return None,
+        }
+    }
+    buf.truncate(pos);
+    Some(buf)
+}
+
+impl Capture {
+    /// Parses a capture.
+    pub fn parse(bytes: &[u8]) -> Result<Self, CaptureError> {
+        let mut reader = Reader(bytes);
+        if reader.bytes(MAGIC.len())? != MAGIC {
+            return Err(CaptureErrorKind::InvalidMagic.into());
+        }
+        let version = reader.u32()?;
+        if version != VERSION {
+            return Err(CaptureErrorKind::UnsupportedVersion(version).into());
+        }
+        let start_time = UNIX_EPOCH + Duration::from_nanos(reader.u64()?);
+        let mut records = vec![];
+        while reader.0.len() > 0 {
+            records.push(reader.record()?);
+        }
+        Ok(Self {
+            start_time,
+            records,
+        })
+    }
+
+    /// Loads a capture from a file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CaptureError> {
+        let path = path.as_ref();
+        let bytes =
+            std::fs::read(path).map_err(|e| CaptureErrorKind::ReadFile(path.to_path_buf(), e))?;
+        Self::parse(&bytes)
+    }
+
+    /// Returns the time at which the recording started.
+    pub fn start_time(&self) -> SystemTime {
+        self.start_time
+    }
+
+    /// Returns the recorded messages.
+    pub fn records(&self) -> &[CaptureRecord] {
+        &self.records
+    }
+
+    /// Replays the messages that were received from clients.
+    ///
+    /// For each client endpoint in the capture, a new connection is created with
+    /// [`State::connect`] and the callback is invoked with the recorded endpoint id and
+    /// the new client. The recorded messages are then written to these connections in
+    /// their original order and the state is dispatched after each message until it
+    /// becomes idle.
+    ///
+    /// File descriptors are recreated from their metadata. Memfds and regular files are
+    /// replaced by memfds with the recorded contents. All other file descriptors are
+    /// replaced by `/dev/null`.
+    ///
+    /// Messages sent by the state are discarded. Messages exchanged with the server are
+    /// ignored. The state should usually be built with
+    /// [`StateBuilder::without_server`](crate::state::StateBuilder::without_server).
+    pub fn replay(
+        &self,
+        state: &Rc<State>,
+        mut new_client: impl FnMut(u64, &Rc<Client>),
+    ) -> Result<(), CaptureError> {
+        let mut connections = HashMap::<_, Option<Rc<OwnedFd>>>::new();
+        for record in &self.records {
+            if record.peer != Peer::Client || record.direction != Direction::Received {
+                continue;
+            }
+            let socket = match connections.entry(record.endpoint) {
+                Entry::Occupied(o) => o.get().clone(),
+                Entry::Vacant(v) => {
+                    let (client, socket) = state.connect().map_err(CaptureErrorKind::State)?;
+                    new_client(record.endpoint, &client);
+                    v.insert(Some(Rc::new(socket))).clone()
+                }
+            };
+            let Some(socket) = socket else {
+                continue;
+            };
+            let fds = record
+                .fds
+                .iter()
+                .map(|fd| fd.create())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(CaptureErrorKind::CreateFd)?;
+            let fds: Vec<_> = fds.iter().map(|fd| fd.as_raw_fd()).collect();
+            let mut bytes = uapi::as_bytes(&record.words[..]);
+            let mut fds = &fds[..];
+            while bytes.len() > 0 {
+                match send(socket.as_raw_fd(), bytes, fds) {
+                    Ok(n) => {
+                        bytes = &bytes[n..];
+                        fds = &[];
+                    }
+                    Err(Errno(c::EAGAIN)) => dispatch(state, &connections)?,
+                    Err(Errno(c::EPIPE | c::ECONNRESET)) => {
+                        connections.insert(record.endpoint, None);
+                        break;
+                    }
+                    Err(e) => {
+                        return Err(CaptureErrorKind::WriteToSocket(e.into()).into());
+                    }
+                }
+            }
+            dispatch(state, &connections)?;
+        }
+        Ok(())
+    }
+}
+
+fn send(socket: RawFd, bytes: &[u8], fds: &[RawFd]) -> Result<usize, Errno> {
+    let mut control_buf = vec![MaybeUninit::<u8>::uninit(); uapi::cmsg_space(size_of_val(fds))];
+    let mut control = None;
+    if fds.len() > 0 {
+        let mut hdr: c::cmsghdr = uapi::pod_zeroed();
+        hdr.cmsg_level = c::SOL_SOCKET;
+        hdr.cmsg_type = c::SCM_RIGHTS;
+        let mut buf = &mut control_buf[..];
+        uapi::cmsg_write(&mut buf, hdr, fds).unwrap();
+        control = Some(&control_buf[..]);
+    }
+    let msghdr = Msghdr {
+        iov: slice::from_ref(&bytes),
+        control,
+        name: sockaddr_none_ref(),
+    };
+    uapi::sendmsg(socket, &msghdr, c::MSG_NOSIGNAL | c::MSG_DONTWAIT)
+}
+
+fn dispatch(
+    state: &Rc<State>,
+    connections: &HashMap<u64, Option<Rc<OwnedFd>>>,
+) -> Result<(), CaptureError> {
+    loop {
+        for socket in connections.values().flatten() {
+            discard_incoming(socket.as_raw_fd());
+        }
+        if !state
+            .dispatch_available()
+            .map_err(CaptureErrorKind::State)?
+        {
+            return Ok(());
+        }
+    }
+}
+
+fn discard_incoming(socket: RawFd) {
+    let mut buf = [0u8; 4096];
+    let mut control_buf = [0u8; 128];
+    loop {
+        let mut iovec = &mut buf[..];
+        let mut header = MsghdrMut {
+            iov: slice::from_mut(&mut iovec),
+            control: Some(&mut control_buf[..]),
+            name: sockaddr_none_mut(),
+            flags: 0,
+        };
+        match uapi::recvmsg(socket, &mut header, c::MSG_CMSG_CLOEXEC | c::MSG_DONTWAIT) {
+            Ok((data, _, mut control)) => {
+                while control.len() > 0 {
+                    let Ok((_, hdr, data)) = uapi::cmsg_read(&mut control) else {
+                        break;
+                    };
+                    if hdr.cmsg_level == c::SOL_SOCKET && hdr.cmsg_type == c::SCM_RIGHTS {
+                        for fd in uapi::pod_iter::<RawFd, _>(data).unwrap() {
+                            // SAFETY: The kernel guarantees that fd is valid
+                            drop(unsafe { OwnedFd::from_raw_fd(fd) });
+                        }
+                    }
+                }
+                if data.is_empty() {
+                    return;
+                }
+            }
+            Err(_) => return,
+        }
+    }
+}
+
+impl CaptureRecord {
+    /// Returns the time at which the message was recorded, relative to
+    /// [`Capture::start_time`].
+    pub fn timestamp(&self) -> Duration {
+        self.timestamp
+    }
+
+    /// Returns the id of the endpoint that the message was received from or sent to.
+    ///
+    /// Each client of a state has a unique endpoint id.
+    pub fn endpoint(&self) -> u64 {
+        self.endpoint
+    }
+
+    /// Returns the peer of the endpoint.
+    pub fn peer(&self) -> Peer {
+        self.peer
+    }
+
+    /// Returns the direction of the message.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the raw words of the message, including the header.
+    pub fn words(&self) -> &[u32] {
+        &self.words
+    }
+
+    /// Returns the file descriptors attached to the message.
+    pub fn fds(&self) -> &[CapturedFd] {
+        &self.fds
+    }
+}
+
+impl CapturedFd {
+    /// Returns the type of the file descriptor.
+    pub fn kind(&self) -> FdKind {
+        self.kind
+    }
+
+    /// Returns the size of the file if the file descriptor is a memfd or a regular file.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the contents of the file if they were recorded.
+    pub fn contents(&self) -> Option<&[u8]> {
+        self.contents.as_deref()
+    }
+
+    fn create(&self) -> Result<OwnedFd, io::Error> {
+        if !matches!(self.kind, FdKind::Memfd | FdKind::File) {
+            let fd = uapi::open("/dev/null", c::O_RDWR | c::O_CLOEXEC, 0)?;
+            return Ok(fd.into());
+        }
+        let fd = uapi::memfd_create("wl-proxy-replay", c::MFD_CLOEXEC)?;
+        uapi::ftruncate(fd.raw(), self.size as c::off_t)?;
+        if let Some(contents) = &self.contents {
+            let mut pos = 0;
+            while pos < contents.len() {
+                pos += uapi::pwrite(fd.raw(), &contents[pos..], pos as c::off_t)?;
+            }
+        }
+        Ok(fd.into())
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], CaptureError> {
+        if self.0.len() < n {
+            return Err(CaptureErrorKind::Truncated.into());
+        }
+        let (res, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(res)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CaptureError> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, CaptureError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, CaptureError> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, CaptureError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, CaptureError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn record(&mut self) -> Result<CaptureRecord, CaptureError> {
+        let timestamp = Duration::from_nanos(self.u64()?);
+        let endpoint = self.u64()?;
+        let peer = match self.u8()? {
+            0 => Peer::Client,
+            1 => Peer::Server,
+            n => return Err(CaptureErrorKind::InvalidValue("peer", n as u64).into()),
+        };
+        let direction = match self.u8()? {
+            0 => Direction::Received,
+            1 => Direction::Sent,
+            n => return Err(CaptureErrorKind::InvalidValue("direction", n as u64).into()),
+        };
+        let num_fds = self.u16()?;
+        let num_words = self.u32()?;
+        let mut words = Vec::with_capacity(num_words.min(1024) as usize);
+        for _ in 0..num_words {
+            words.push(self.u32()?);
+        }
+        let mut fds = Vec::with_capacity(num_fds as usize);
+        for _ in 0..num_fds {
+            fds.push(self.fd()?);
+        }
+        Ok(CaptureRecord {
+            timestamp,
+            endpoint,
+            peer,
+            direction,
+            words,
+            fds,
+        })
+    }
+
+    fn fd(&mut self) -> Result<CapturedFd, CaptureError> {
+        let kind = match self.u8()? {
+            0 => FdKind::Memfd,
+            1 => FdKind::File,
+            2 => FdKind::Pipe,
+            3 => FdKind::Socket,
+            4 => FdKind::Other,
+            n => return Err(CaptureErrorKind::InvalidValue("fd kind", n as u64).into()),
+        };
+        let size = self.u64()?;
+        let contents = match self.u8()? {
+            0 => None,
+            1 => {
+                let len = self.u64()?;
+                let len = usize::try_from(len)
+                    .map_err(|_| CaptureErrorKind::InvalidValue("fd contents length", len))?;
+                Some(self.bytes(len)?.to_vec())
+            }
+            n => return Err(CaptureErrorKind::InvalidValue("fd contents", n as u64).into()),
+        };
+        Ok(CapturedFd {
+            kind,
+            size,
+            contents,
+        })
+    }
+}
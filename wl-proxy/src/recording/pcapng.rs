@@ -0,0 +1,108 @@
+use {
+    crate::recording::{Direction, Peer},
+    std::io::{self, Write},
+};
+
+/// LINKTYPE_USER0
+const LINK_TYPE: u16 = 147;
+
+const SECTION_HEADER_BLOCK: u32 = 0x0a0d0d0a;
+const INTERFACE_DESCRIPTION_BLOCK: u32 = 1;
+const ENHANCED_PACKET_BLOCK: u32 = 6;
+
+const BYTE_ORDER_MAGIC: u32 = 0x1a2b3c4d;
+
+const OPT_ENDOFOPT: u16 = 0;
+const IF_NAME: u16 = 2;
+const IF_TSRESOL: u16 = 9;
+const EPB_FLAGS: u16 = 2;
+
+const EPB_FLAGS_INBOUND: u32 = 1;
+const EPB_FLAGS_OUTBOUND: u32 = 2;
+
+/// Timestamps have nanosecond resolution.
+const TSRESOL_NANOS: u8 = 9;
+
+pub(super) fn write_section_header(w: &mut impl Write) -> io::Result<()> {
+    let mut body = vec![];
+    body.extend_from_slice(&BYTE_ORDER_MAGIC.to_ne_bytes());
+    body.extend_from_slice(&1u16.to_ne_bytes());
+    body.extend_from_slice(&0u16.to_ne_bytes());
+    body.extend_from_slice(&(-1i64).to_ne_bytes());
+    write_block(w, SECTION_HEADER_BLOCK, &body)
+}
+
+pub(super) fn write_interface(w: &mut impl Write, endpoint: u64, peer: Peer) -> io::Result<()> {
+    let name = match peer {
+        Peer::Client => format!("client#{endpoint}"),
+        Peer::Server => "server".to_string(),
+    };
+    let mut body = vec![];
+    body.extend_from_slice(&LINK_TYPE.to_ne_bytes());
+    body.extend_from_slice(&0u16.to_ne_bytes());
+    body.extend_from_slice(&0u32.to_ne_bytes());
+    push_option(&mut body, IF_NAME, name.as_bytes());
+    push_option(&mut body, IF_TSRESOL, &[TSRESOL_NANOS]);
+    push_option(&mut body, OPT_ENDOFOPT, &[]);
+    write_block(w, INTERFACE_DESCRIPTION_BLOCK, &body)
+}
+
+/// Writes a message.
+///
+/// The packet data starts with a 12 byte header containing the endpoint id (u64), the
+/// peer (u8), the direction (u8), and the number of attached file descriptors (u16).
+/// This is followed by the words of the message. Unlike the pcapng blocks themselves,
+/// the packet data is always little-endian so that dissectors do not have to know the
+/// byte order of the section.
+#[expect(clippy::too_many_arguments)]
+pub(super) fn write_packet(
+    w: &mut impl Write,
+    interface: u32,
+    timestamp: u64,
+    endpoint: u64,
+    peer: Peer,
+    direction: Direction,
+    num_fds: usize,
+    words: &[u32],
+) -> io::Result<()> {
+    let mut data = vec![];
+    data.extend_from_slice(&endpoint.to_le_bytes());
+    data.extend_from_slice(&[peer as u8, direction as u8]);
+    data.extend_from_slice(&(num_fds as u16).to_le_bytes());
+    for word in words {
+        data.extend_from_slice(&word.to_le_bytes());
+    }
+    let flags = match direction {
+        Direction::Received => EPB_FLAGS_INBOUND,
+        Direction::Sent => EPB_FLAGS_OUTBOUND,
+    };
+    let mut body = vec![];
+    body.extend_from_slice(&interface.to_ne_bytes());
+    body.extend_from_slice(&((timestamp >> 32) as u32).to_ne_bytes());
+    body.extend_from_slice(&(timestamp as u32).to_ne_bytes());
+    body.extend_from_slice(&(data.len() as u32).to_ne_bytes());
+    body.extend_from_slice(&(data.len() as u32).to_ne_bytes());
+    push_padded(&mut body, &data);
+    push_option(&mut body, EPB_FLAGS, &flags.to_ne_bytes());
+    push_option(&mut body, OPT_ENDOFOPT, &[]);
+    write_block(w, ENHANCED_PACKET_BLOCK, &body)
+}
+
+fn push_option(body: &mut Vec<u8>, code: u16, value: &[u8]) {
+    body.extend_from_slice(&code.to_ne_bytes());
+    body.extend_from_slice(&(value.len() as u16).to_ne_bytes());
+    push_padded(body, value);
+}
+
+fn push_padded(body: &mut Vec<u8>, value: &[u8]) {
+    body.extend_from_slice(value);
+    body.resize(body.len().next_multiple_of(4), 0);
+}
+
+fn write_block(w: &mut impl Write, ty: u32, body: &[u8]) -> io::Result<()> {
+    let len = (body.len() + 12) as u32;
+    w.write_all(&ty.to_ne_bytes())?;
+    w.write_all(&len.to_ne_bytes())?;
+    w.write_all(body)?;
+    w.write_all(&len.to_ne_bytes())
+}
@@ -0,0 +1,254 @@
+use {
+    crate::{
+        baseline::Baseline,
+        object::{Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::{
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+            },
+            wlproxy_test::wlproxy_test::{WlproxyTest, WlproxyTestHandler},
+        },
+        recording::{Capture, Direction, FdKind, Peer},
+        state::State,
+        test_framework::{
+            install_logger,
+            proxy::{dispatch_blocking, test_proxy_client},
+            server::test_server,
+        },
+    },
+    std::{
+        cell::{Cell, RefCell},
+        io::{self, Write},
+        os::fd::{AsRawFd, OwnedFd},
+        rc::Rc,
+    },
+    uapi::c,
+};
+
+const CONTENTS: &[u8] = b"hello";
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+struct CallbackHandler(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for CallbackHandler {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+fn record() -> Capture {
+    Capture::parse(&record_bytes(false)).unwrap()
+}
+
+fn record_bytes(pcapng: bool) -> Vec<u8> {
+    install_logger();
+    let server = test_server(false);
+    let buffer = SharedBuffer::default();
+    let mut builder = State::builder(Baseline::ALL_OF_THEM).with_server_fd(&server);
+    if pcapng {
+        builder = builder.with_pcapng_recorder(buffer.clone());
+    } else {
+        builder = builder.with_recorder(buffer.clone());
+    }
+    let proxy_state = builder.build().unwrap();
+    let _destructor = proxy_state.create_destructor();
+    let client = test_proxy_client(&proxy_state, false);
+    let memfd = uapi::memfd_create("test", c::MFD_CLOEXEC).unwrap();
+    uapi::write(memfd.raw(), CONTENTS).unwrap();
+    client.test.send_recv_fd(&Rc::new(memfd.into()));
+    let done = Rc::new(Cell::new(false));
+    client
+        .display
+        .new_send_sync()
+        .set_handler(CallbackHandler(done.clone()));
+    while !done.get() {
+        dispatch_blocking([&client.state, &proxy_state]).unwrap();
+    }
+    buffer.0.take()
+}
+
+#[test]
+fn capture() {
+    let capture = record();
+    let records = capture.records();
+    for (peer, direction) in [
+        (Peer::Client, Direction::Received),
+        (Peer::Client, Direction::Sent),
+        (Peer::Server, Direction::Received),
+        (Peer::Server, Direction::Sent),
+    ] {
+        assert!(
+            records
+                .iter()
+                .any(|r| r.peer() == peer && r.direction() == direction)
+        );
+    }
+    assert!(
+        records
+            .windows(2)
+            .all(|r| r[0].timestamp() <= r[1].timestamp())
+    );
+    let with_fds: Vec<_> = records.iter().filter(|r| r.fds().len() > 0).collect();
+    let [received, sent] = &with_fds[..] else {
+        panic!();
+    };
+    assert_eq!(received.peer(), Peer::Client);
+    assert_eq!(received.direction(), Direction::Received);
+    assert_eq!(sent.peer(), Peer::Server);
+    assert_eq!(sent.direction(), Direction::Sent);
+    for record in [received, sent] {
+        let fd = &record.fds()[0];
+        assert_eq!(fd.kind(), FdKind::Memfd);
+        assert_eq!(fd.size(), CONTENTS.len() as u64);
+        assert_eq!(fd.contents(), Some(CONTENTS));
+    }
+    assert_eq!(received.words()[1..], sent.words()[1..]);
+}
+
+#[test]
+fn pcapng() {
+    let bytes = record_bytes(true);
+    let u16 = |b: &[u8]| u16::from_ne_bytes(b[..2].try_into().unwrap());
+    let u32 = |b: &[u8]| u32::from_ne_bytes(b[..4].try_into().unwrap());
+    // The packet data is always little-endian.
+    let le_u16 = |b: &[u8]| u16::from_le_bytes(b[..2].try_into().unwrap());
+    let le_u32 = |b: &[u8]| u32::from_le_bytes(b[..4].try_into().unwrap());
+    let mut blocks = vec![];
+    let mut rest = &bytes[..];
+    while rest.len() > 0 {
+        let len = u32(&rest[4..]) as usize;
+        assert_eq!(len % 4, 0);
+        assert_eq!(u32(&rest[len - 4..]) as usize, len);
+        blocks.push((u32(rest), &rest[8..len - 4]));
+        rest = &rest[len..];
+    }
+    assert_eq!(blocks[0].0, 0x0a0d0d0a);
+    assert_eq!(u32(blocks[0].1), 0x1a2b3c4d);
+    let mut interfaces = vec![];
+    let mut packets = vec![];
+    for (ty, body) in &blocks[1..] {
+        match ty {
+            1 => {
+                assert_eq!(u16(body), 147);
+                assert_eq!(u16(&body[8..]), 2);
+                let len = u16(&body[10..]) as usize;
+                interfaces.push(str::from_utf8(&body[12..12 + len]).unwrap());
+            }
+            6 => {
+                let interface = u32(body) as usize;
+                assert!(interface < interfaces.len());
+                let len = u32(&body[12..]) as usize;
+                let data = &body[20..20 + len];
+                let words: Vec<_> = data[12..].chunks(4).map(le_u32).collect();
+                assert_eq!(words.len() * 4, (words[1] >> 16) as usize);
+                packets.push((
+                    interfaces[interface],
+                    data[8],
+                    data[9],
+                    le_u16(&data[10..]),
+                    words,
+                ));
+            }
+            _ => panic!(),
+        }
+    }
+    assert_eq!(interfaces.len(), 2);
+    assert_eq!(interfaces[0], "server");
+    assert!(interfaces[1].starts_with("client#"));
+    for (interface, peer, _, _, _) in &packets {
+        let expected = match *interface {
+            "server" => Peer::Server,
+            _ => Peer::Client,
+        };
+        assert_eq!(*peer, expected as u8);
+    }
+    let with_fds: Vec<_> = packets.iter().filter(|p| p.3 > 0).collect();
+    let [received, sent] = &with_fds[..] else {
+        panic!();
+    };
+    assert_eq!(
+        (received.1, received.2),
+        (Peer::Client as u8, Direction::Received as u8)
+    );
+    assert_eq!(
+        (sent.1, sent.2),
+        (Peer::Server as u8, Direction::Sent as u8)
+    );
+    assert_eq!(received.4[1..], sent.4[1..]);
+}
+
+#[test]
+fn parse_error() {
+    assert!(Capture::parse(b"").is_err());
+    assert!(Capture::parse(b"WLPRXCAQ\x01\0\0\0\0\0\0\0\0\0\0\0").is_err());
+    assert!(Capture::parse(b"WLPRXCAP\x02\0\0\0\0\0\0\0\0\0\0\0").is_err());
+    assert!(Capture::parse(b"WLPRXCAP\x01\0\0\0\0\0\0\0\0\0\0\0").is_ok());
+    assert!(Capture::parse(b"WLPRXCAP\x01\0\0\0\0\0\0\0\0\0\0\0\0").is_err());
+    assert!(Capture::load("/nonexistent/capture").is_err());
+}
+
+struct ReplayDisplayHandler(Rc<RefCell<Option<Vec<u8>>>>);
+
+impl WlDisplayHandler for ReplayDisplayHandler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(0);
+        callback.delete_id();
+    }
+
+    fn handle_get_registry(&mut self, _slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(ReplayDisplayHandler(self.0.clone()));
+    }
+}
+
+impl WlRegistryHandler for ReplayDisplayHandler {
+    fn handle_bind(&mut self, _slf: &Rc<WlRegistry>, _name: u32, id: Rc<dyn Object>) {
+        id.downcast::<WlproxyTest>()
+            .set_handler(ReplayDisplayHandler(self.0.clone()));
+    }
+}
+
+impl WlproxyTestHandler for ReplayDisplayHandler {
+    fn handle_recv_fd(&mut self, _slf: &Rc<WlproxyTest>, fd: &Rc<OwnedFd>) {
+        let mut buf = [0; 16];
+        let n = uapi::pread(fd.as_raw_fd(), &mut buf[..], 0).unwrap().len();
+        *self.0.borrow_mut() = Some(buf[..n].to_vec());
+    }
+}
+
+#[test]
+fn replay() {
+    let capture = record();
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .build()
+        .unwrap();
+    state.set_default_forward_to_server(false);
+    let _destructor = state.create_destructor();
+    let contents = Rc::new(RefCell::new(None));
+    let mut clients = 0;
+    capture
+        .replay(&state, |_, client| {
+            clients += 1;
+            client
+                .display()
+                .set_handler(ReplayDisplayHandler(contents.clone()));
+        })
+        .unwrap();
+    assert_eq!(clients, 1);
+    assert_eq!(contents.borrow().as_deref(), Some(CONTENTS));
+}
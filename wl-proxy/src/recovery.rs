@@ -0,0 +1,1083 @@
+//! Recovery from compositor restarts.
+//!
+//! This module requires the `recovery` feature.
+//!
+//! By default, a [`State`] is destroyed when the server hangs up the connection and all
+//! of its clients are disconnected. If recovery has been enabled via
+//! [`StateBuilder::with_recovery`] or [`StateBuilder::with_recovery_connector`], the
+//! state instead connects to the server again and recreates the objects of its clients.
+//! This allows long-running clients to outlive a compositor crash.
+//!
+//! While recovery is enabled, the state records the requests that create the following
+//! objects as well as the latest state of these objects:
+//!
+//! - wl_registry objects and all objects created via wl_registry.bind,
+//! - wl_surface and wl_region objects,
+//! - wl_shm_pool objects and the wl_buffer objects created from them,
+//! - wl_pointer, wl_keyboard, and wl_touch objects,
+//! - xdg_surface and xdg_toplevel objects, if the `protocol-xdg_shell` feature is
+//!   enabled.
+//!
+//! After reconnecting, these objects are recreated in the order in which they were
+//! originally created and receive new server IDs. Clients are not affected by this.
+//!
+//! The globals of the new server are matched with the globals of the old server by
+//! their interface. Global names are translated so that clients can continue to use the
+//! names they already know. Globals that no longer exist are removed via
+//! wl_registry.global_remove and new globals are announced to existing registries.
+//!
+//! Surfaces are restored with the buffer, buffer transform, buffer scale, and regions of
+//! their last commit. Toplevels are restored with their title, app ID, parent, size
+//! limits, and maximized and fullscreen states. The first configure event of a restored
+//! toplevel is acknowledged by the proxy and the last buffer of the surface is committed
+//! again. Acknowledgements of configure events sent by the old server are dropped.
+//!
+//! Pending operations are completed: wl_callback objects receive a done event,
+//! xdg_popup objects receive a popup_done event, buffers that were in use by the old
+//! server are released, and objects whose destruction has not been acknowledged by the
+//! old server are deleted.
+//!
+//! All other objects cannot be recreated and lose their server IDs. Requests sent on
+//! such objects fail. Proxies can use [`StateHandler::server_recovered`] to recreate
+//! such objects themselves.
+//!
+//! [`StateBuilder::with_recovery`]: crate::state::StateBuilder::with_recovery
+//! [`StateBuilder::with_recovery_connector`]: crate::state::StateBuilder::with_recovery_connector
+//! # Detaching
+//!
+//! The same mechanism can be used to move the clients of a state to a different server
+//! at runtime. [`State::detach`] replaces the server connection by a connection to a
+//! built-in stub server. The stub server advertises the globals of the previous server,
+//! completes wl_display.sync requests and frame callbacks, releases buffers, and
+//! configures toplevels. All other requests are ignored. [`State::reattach`] connects
+//! to a server via a display name and recreates the objects on that server.
+//!
+//! # Blocking
+//!
+//! Recovery happens synchronously while the state dispatches events. Until the state
+//! has reconnected and recreated the objects, or until the timeout has expired, the
+//! thread is blocked. This includes reconnection attempts, which are retried every
+//! 100 milliseconds, and the roundtrips to the new server. No other events are
+//! dispatched during this time. This also applies to states that are driven by an
+//! `AsyncState`, whose future does not yield while recovery is in progress.
+//!
+//! [`StateHandler::server_recovered`]: crate::state::StateHandler::server_recovered
+//! [`State::detach`]: crate::state::State::detach
+//! [`State::reattach`]: crate::state::State::reattach
+
+#[cfg(feature = "protocol-xdg_shell")]
+use crate::protocols::xdg_shell::{
+    xdg_surface::XdgSurface, xdg_toplevel::XdgToplevel, xdg_wm_base::XdgWmBase,
+};
+use {
+    crate::{
+        endpoint::Endpoint,
+        object::{MIN_SERVER_ID, Object, ObjectCoreApi, ObjectRcUtils},
+        protocol_helpers::{NullableString, parse_string},
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_buffer::WlBuffer,
+                wl_compositor::WlCompositor,
+                wl_display::WlDisplay,
+                wl_keyboard::WlKeyboard,
+                wl_output::WlOutputTransform,
+                wl_pointer::WlPointer,
+                wl_region::WlRegion,
+                wl_registry::WlRegistry,
+                wl_seat::WlSeat,
+                wl_shm::{WlShm, WlShmFormat},
+                wl_shm_pool::WlShmPool,
+                wl_surface::WlSurface,
+                wl_touch::WlTouch,
+            },
+        },
+        recovery::stub::StubGlobal,
+        state::{RemoteDestructor, State},
+    },
+    error_reporter::Report,
+    std::{
+        cell::{Cell, RefCell},
+        collections::{BTreeMap, HashMap, HashSet, VecDeque},
+        io,
+        os::fd::OwnedFd,
+        rc::{Rc, Weak},
+        thread,
+        time::{Duration, Instant},
+    },
+};
+
+pub(crate) mod stub;
+#[cfg(test)]
+mod tests;
+
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+pub(crate) type Connector = Box<dyn FnMut() -> io::Result<OwnedFd>>;
+
+pub(crate) struct Recovery {
+    pub(crate) journal: Rc<Journal>,
+    pub(crate) timeout: Duration,
+    pub(crate) pending: Cell<bool>,
+    pub(crate) stub: RefCell<Option<RemoteDestructor>>,
+    connector: RefCell<Connector>,
+}
+
+/// The recorded creation requests and state of recreatable server objects.
+#[derive(Default)]
+pub(crate) struct Journal {
+    endpoint: RefCell<Weak<Endpoint>>,
+    replaying: Cell<bool>,
+    next_seq: Cell<u64>,
+    entries: RefCell<HashMap<u64, Entry>>,
+    names: RefCell<Names>,
+    probe: Cell<u64>,
+    probed: RefCell<Vec<(u32, Global)>>,
+    muted_registries: RefCell<HashSet<u64>>,
+    known_names: RefCell<HashSet<u32>>,
+    unconfigured: RefCell<HashMap<u64, u64>>,
+}
+
+struct Entry {
+    seq: u64,
+    object: Weak<dyn Object>,
+    parent: Weak<dyn Object>,
+    destroyed: bool,
+    kind: Kind,
+}
+
+enum Kind {
+    Registry,
+    Bind(u32),
+    Surface(Box<Surface>),
+    Region(Vec<RegionOp>),
+    ShmPool(Rc<OwnedFd>, i32),
+    Buffer(Buffer),
+    SeatDevice,
+    #[cfg(feature = "protocol-xdg_shell")]
+    XdgSurface(XdgSurfaceState),
+    #[cfg(feature = "protocol-xdg_shell")]
+    XdgToplevel(Box<ToplevelState>),
+}
+
+#[derive(Copy, Clone)]
+struct RegionOp {
+    add: bool,
+    rect: [i32; 4],
+}
+
+#[derive(Default)]
+struct Surface {
+    pending: SurfaceState,
+    committed: SurfaceState,
+}
+
+#[derive(Default)]
+struct SurfaceState {
+    buffer: Option<Option<Weak<dyn Object>>>,
+    transform: Option<u32>,
+    scale: Option<i32>,
+    opaque_region: Option<Option<Rc<[RegionOp]>>>,
+    input_region: Option<Option<Rc<[RegionOp]>>>,
+}
+
+struct Buffer {
+    offset: i32,
+    width: i32,
+    height: i32,
+    stride: i32,
+    format: u32,
+    busy: bool,
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+struct XdgSurfaceState {
+    surface: Weak<dyn Object>,
+    geometry: Option<[i32; 4]>,
+    popup: bool,
+    serials: Option<Vec<u32>>,
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+#[derive(Default)]
+struct ToplevelState {
+    parent: Option<Weak<dyn Object>>,
+    title: Option<String>,
+    app_id: Option<String>,
+    max_size: Option<[i32; 2]>,
+    min_size: Option<[i32; 2]>,
+    maximized: bool,
+    fullscreen: bool,
+}
+
+#[derive(Clone)]
+struct Global {
+    interface: Rc<str>,
+    version: u32,
+}
+
+/// The mapping between global names of the server and global names seen by clients.
+///
+/// The mapping is the identity until the first recovery.
+#[derive(Default)]
+struct Names {
+    remapped: bool,
+    next: u32,
+    to_client: HashMap<u32, u32>,
+    to_server: HashMap<u32, u32>,
+    globals: BTreeMap<u32, Global>,
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+enum Role {
+    Toplevel(Rc<dyn Object>),
+    Hidden,
+}
+
+impl Recovery {
+    pub(crate) fn new(timeout: Duration, connector: Connector) -> Self {
+        Self {
+            journal: Default::default(),
+            timeout,
+            pending: Default::default(),
+            stub: Default::default(),
+            connector: RefCell::new(connector),
+        }
+    }
+
+    pub(crate) fn set_connector(&self, connector: Connector) {
+        *self.connector.borrow_mut() = connector;
+    }
+
+    /// Returns the deadline of an operation that starts now.
+    ///
+    /// Returns `None` if the timeout is too large to be represented.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        Instant::now().checked_add(self.timeout)
+    }
+
+    /// Connects to the server, retrying until the deadline has passed.
+    ///
+    /// This blocks the thread between attempts.
+    pub(crate) fn connect(&self, deadline: Option<Instant>) -> io::Result<OwnedFd> {
+        let connector = &mut *self.connector.borrow_mut();
+        loop {
+            let e = match connector() {
+                Ok(fd) => return Ok(fd),
+                Err(e) => e,
+            };
+            let mut interval = RETRY_INTERVAL;
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(e);
+                }
+                interval = interval.min(deadline - now);
+            }
+            thread::sleep(interval);
+        }
+    }
+}
+
+impl Names {
+    fn client_name(&mut self, server: u32) -> u32 {
+        if !self.remapped {
+            self.next = self.next.max(server.saturating_add(1));
+            return server;
+        }
+        if let Some(&name) = self.to_client.get(&server) {
+            return name;
+        }
+        let name = self.next;
+        self.next += 1;
+        self.to_client.insert(server, name);
+        self.to_server.insert(name, server);
+        name
+    }
+
+    fn server_name(&self, client: u32) -> u32 {
+        self.to_server.get(&client).copied().unwrap_or(client)
+    }
+
+    /// Matches the globals of a new server with the known globals.
+    ///
+    /// Returns the names of the globals that no longer exist and the names of the globals
+    /// that still exist.
+    fn remap(&mut self, probed: &[(u32, Global)]) -> (Vec<u32>, HashSet<u32>) {
+        let mut old = HashMap::<Rc<str>, VecDeque<u32>>::new();
+        for (&name, global) in &self.globals {
+            old.entry(global.interface.clone())
+                .or_default()
+                .push_back(name);
+        }
+        self.to_client.clear();
+        self.to_server.clear();
+        self.globals.clear();
+        let mut matched = HashSet::new();
+        for (server, global) in probed {
+            let name = match old.get_mut(&global.interface).and_then(|n| n.pop_front()) {
+                Some(name) => {
+                    matched.insert(name);
+                    name
+                }
+                None => {
+                    let name = self.next;
+                    self.next += 1;
+                    name
+                }
+            };
+            self.to_client.insert(*server, name);
+            self.to_server.insert(name, *server);
+            self.globals.insert(name, global.clone());
+        }
+        self.remapped = true;
+        (old.into_values().flatten().collect(), matched)
+    }
+}
+
+impl SurfaceState {
+    fn apply(&mut self, pending: &mut SurfaceState) {
+        macro_rules! apply {
+            ($($field:ident,)*) => {
+                $(
+                    if let Some(v) = pending.$field.take() {
+                        self.$field = Some(v);
+                    }
+                )*
+            };
+        }
+        apply! {
+            buffer,
+            transform,
+            scale,
+            opaque_region,
+            input_region,
+        }
+    }
+
+    fn buffer(&self) -> Option<Rc<dyn Object>> {
+        let buffer = self.buffer.as_ref()?.as_ref()?.upgrade()?;
+        buffer.server_id().is_some().then_some(buffer)
+    }
+}
+
+impl Journal {
+    pub(crate) fn set_endpoint(&self, endpoint: &Rc<Endpoint>) {
+        *self.endpoint.borrow_mut() = Rc::downgrade(endpoint);
+    }
+
+    /// Returns the known globals of the server with the names seen by clients.
+    pub(crate) fn globals(&self) -> Vec<StubGlobal> {
+        let names = self.names.borrow();
+        names
+            .globals
+            .iter()
+            .filter_map(|(&name, global)| {
+                let interface = ObjectInterface::from_str(&global.interface)?;
+                Some((name, interface, global.version))
+            })
+            .collect()
+    }
+
+    /// Records a request sent to the server.
+    ///
+    /// Returns `false` if the request must not be sent.
+    pub(crate) fn record_request<'a>(
+        &self,
+        msg: &mut [u32],
+        mut fds: impl Iterator<Item = &'a Rc<OwnedFd>>,
+    ) -> bool {
+        use ObjectInterface as I;
+        if self.replaying.get() {
+            return true;
+        }
+        let Some(endpoint) = self.endpoint.borrow().upgrade() else {
+            return true;
+        };
+        let Some(object) = endpoint.lookup(msg[0]) else {
+            return true;
+        };
+        let lookup = |id: u32| match id {
+            0 => None,
+            _ => endpoint.lookup(id),
+        };
+        let weak = |id: u32| lookup(id).map(|o| Rc::downgrade(&o));
+        let opcode = msg[1] & 0xffff;
+        let args = &msg[2..];
+        match (object.core().interface, opcode) {
+            (I::WlDisplay, 1) => self.create(&object, lookup(args[0]), Kind::Registry),
+            (I::WlRegistry, 0) => {
+                let name = args[0];
+                let id = args[args.len() - 1];
+                self.create(&object, lookup(id), Kind::Bind(name));
+                msg[2] = self.names.borrow().server_name(name);
+            }
+            (I::WlCompositor, 0) => {
+                self.create(&object, lookup(args[0]), Kind::Surface(Default::default()))
+            }
+            (I::WlCompositor, 1) => {
+                self.create(&object, lookup(args[0]), Kind::Region(Default::default()))
+            }
+            (I::WlRegion, 1 | 2) => {
+                let op = RegionOp {
+                    add: opcode == 1,
+                    rect: [args[0], args[1], args[2], args[3]].map(|v| v as i32),
+                };
+                self.update(&object, |entry| {
+                    if let Kind::Region(ops) = &mut entry.kind {
+                        ops.push(op);
+                    }
+                });
+            }
+            (I::WlShm, 0) => {
+                if let Some(fd) = fds.next() {
+                    let kind = Kind::ShmPool(fd.clone(), args[1] as i32);
+                    self.create(&object, lookup(args[0]), kind);
+                }
+            }
+            (I::WlShmPool, 0) => {
+                let buffer = Buffer {
+                    offset: args[1] as i32,
+                    width: args[2] as i32,
+                    height: args[3] as i32,
+                    stride: args[4] as i32,
+                    format: args[5],
+                    busy: false,
+                };
+                self.create(&object, lookup(args[0]), Kind::Buffer(buffer));
+            }
+            (I::WlShmPool, 2) => self.update(&object, |entry| {
+                if let Kind::ShmPool(_, size) = &mut entry.kind {
+                    *size = args[0] as i32;
+                }
+            }),
+            (I::WlSurface, 1) => {
+                let buffer = weak(args[0]);
+                self.update_surface(&object, |s| s.pending.buffer = Some(buffer));
+            }
+            (I::WlSurface, 4 | 5) => {
+                let region = lookup(args[0]).map(|region| {
+                    match self.entries.borrow().get(&region.unique_id()) {
+                        Some(Entry {
+                            kind: Kind::Region(ops),
+                            ..
+                        }) => ops.as_slice().into(),
+                        _ => Rc::from([]),
+                    }
+                });
+                self.update_surface(&object, |s| match opcode {
+                    4 => s.pending.opaque_region = Some(region),
+                    _ => s.pending.input_region = Some(region),
+                });
+            }
+            (I::WlSurface, 6) => return self.commit(&object),
+            (I::WlSurface, 7) => {
+                self.update_surface(&object, |s| s.pending.transform = Some(args[0]));
+            }
+            (I::WlSurface, 8) => {
+                self.update_surface(&object, |s| s.pending.scale = Some(args[0] as i32));
+            }
+            (I::WlSeat, 0..=2) => self.create(&object, lookup(args[0]), Kind::SeatDevice),
+            #[cfg(feature = "protocol-xdg_shell")]
+            (I::XdgWmBase, 2) => {
+                let state = XdgSurfaceState {
+                    surface: weak(args[1]).unwrap_or_else(|| Weak::<WlSurface>::new()),
+                    geometry: None,
+                    popup: false,
+                    serials: None,
+                };
+                self.create(&object, lookup(args[0]), Kind::XdgSurface(state));
+            }
+            #[cfg(feature = "protocol-xdg_shell")]
+            (I::XdgSurface, 1) => self.create(
+                &object,
+                lookup(args[0]),
+                Kind::XdgToplevel(Default::default()),
+            ),
+            #[cfg(feature = "protocol-xdg_shell")]
+            (I::XdgSurface, 2 | 3) => self.update(&object, |entry| {
+                if let Kind::XdgSurface(x) = &mut entry.kind {
+                    match opcode {
+                        2 => x.popup = true,
+                        _ => {
+                            x.geometry =
+                                Some([args[0], args[1], args[2], args[3]].map(|v| v as i32))
+                        }
+                    }
+                }
+            }),
+            #[cfg(feature = "protocol-xdg_shell")]
+            (I::XdgSurface, 4) => return self.ack_configure(&object, args[0]),
+            #[cfg(feature = "protocol-xdg_shell")]
+            (I::XdgToplevel, 1..=3 | 7..=12) => {
+                let parent = args.first().and_then(|&id| weak(id));
+                let string = match opcode {
+                    2 | 3 => parse_string::<NullableString>(msg, 2, "string")
+                        .ok()
+                        .and_then(|(s, _)| s.map(|s| s.to_owned())),
+                    _ => None,
+                };
+                let size = [0, 1].map(|i| args.get(i).copied().unwrap_or(0) as i32);
+                self.update(&object, |entry| {
+                    let Kind::XdgToplevel(t) = &mut entry.kind else {
+                        return;
+                    };
+                    match opcode {
+                        1 => t.parent = parent,
+                        2 => t.title = string,
+                        3 => t.app_id = string,
+                        7 => t.max_size = Some(size),
+                        8 => t.min_size = Some(size),
+                        9 | 10 => t.maximized = opcode == 9,
+                        _ => t.fullscreen = opcode == 11,
+                    }
+                });
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Records an event received from the server.
+    ///
+    /// Returns `false` if the event must not be dispatched.
+    pub(crate) fn record_event(
+        &self,
+        endpoint: &Endpoint,
+        object: &Rc<dyn Object>,
+        msg: &mut [u32],
+    ) -> bool {
+        use ObjectInterface as I;
+        let id = object.unique_id();
+        match (object.core().interface, msg[1] & 0xffff) {
+            (I::WlDisplay, 1) => {
+                if let Some(object) = endpoint.lookup(msg[2]) {
+                    self.entries.borrow_mut().remove(&object.unique_id());
+                }
+            }
+            (I::WlRegistry, 0) => {
+                let Ok((Some(interface), offset)) =
+                    parse_string::<NullableString>(msg, 3, "interface")
+                else {
+                    return true;
+                };
+                let global = Global {
+                    interface: interface.into(),
+                    version: msg.get(offset).copied().unwrap_or(0),
+                };
+                if id == self.probe.get() {
+                    self.probed.borrow_mut().push((msg[2], global));
+                    return false;
+                }
+                let names = &mut *self.names.borrow_mut();
+                let name = names.client_name(msg[2]);
+                names.globals.insert(name, global);
+                msg[2] = name;
+                if self.muted_registries.borrow().contains(&id)
+                    && self.known_names.borrow().contains(&name)
+                {
+                    return false;
+                }
+            }
+            (I::WlRegistry, 1) => {
+                if id == self.probe.get() {
+                    return false;
+                }
+                let names = &mut *self.names.borrow_mut();
+                let name = match names.remapped {
+                    true => names.to_client.get(&msg[2]).copied().unwrap_or(msg[2]),
+                    false => msg[2],
+                };
+                names.globals.remove(&name);
+                msg[2] = name;
+            }
+            (I::WlBuffer, 0) => self.update(object, |entry| {
+                if let Kind::Buffer(b) = &mut entry.kind {
+                    b.busy = false;
+                }
+            }),
+            #[cfg(feature = "protocol-xdg_shell")]
+            (I::XdgSurface, 0) => {
+                if self.unconfigured.borrow_mut().remove(&id).is_some() {
+                    self.restore_toplevel(object, msg[2]);
+                    return false;
+                }
+                self.update(object, |entry| {
+                    if let Kind::XdgSurface(x) = &mut entry.kind
+                        && let Some(serials) = &mut x.serials
+                    {
+                        serials.push(msg[2]);
+                    }
+                });
+            }
+            #[cfg(feature = "protocol-xdg_shell")]
+            (I::XdgToplevel, 0) => {
+                let xdg_surface = match self.entries.borrow().get(&id) {
+                    Some(entry) => entry.parent.upgrade(),
+                    None => None,
+                };
+                if let Some(xdg_surface) = xdg_surface
+                    && self
+                        .unconfigured
+                        .borrow()
+                        .contains_key(&xdg_surface.unique_id())
+                {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Marks the object as destroyed by a request.
+    pub(crate) fn destroy(&self, unique_id: u64) {
+        if let Some(entry) = self.entries.borrow_mut().get_mut(&unique_id) {
+            entry.destroyed = true;
+        }
+    }
+
+    fn create(&self, parent: &Rc<dyn Object>, object: Option<Rc<dyn Object>>, kind: Kind) {
+        let Some(object) = object else {
+            return;
+        };
+        let seq = self.next_seq.get();
+        self.next_seq.set(seq + 1);
+        let entry = Entry {
+            seq,
+            object: Rc::downgrade(&object),
+            parent: Rc::downgrade(parent),
+            destroyed: false,
+            kind,
+        };
+        self.entries.borrow_mut().insert(object.unique_id(), entry);
+    }
+
+    fn update(&self, object: &Rc<dyn Object>, f: impl FnOnce(&mut Entry)) {
+        if let Some(entry) = self.entries.borrow_mut().get_mut(&object.unique_id()) {
+            f(entry);
+        }
+    }
+
+    fn update_surface(&self, object: &Rc<dyn Object>, f: impl FnOnce(&mut Surface)) {
+        self.update(object, |entry| {
+            if let Kind::Surface(s) = &mut entry.kind {
+                f(s);
+            }
+        });
+    }
+
+    fn commit(&self, object: &Rc<dyn Object>) -> bool {
+        let id = object.unique_id();
+        if self.unconfigured.borrow().values().any(|&s| s == id) {
+            log::debug!("Dropping a commit of a surface that has not yet been configured");
+            return false;
+        }
+        let entries = &mut *self.entries.borrow_mut();
+        let Some(Entry {
+            kind: Kind::Surface(s),
+            ..
+        }) = entries.get_mut(&id)
+        else {
+            return true;
+        };
+        let buffer = match &s.pending.buffer {
+            Some(Some(buffer)) => buffer.upgrade(),
+            _ => None,
+        };
+        s.committed.apply(&mut s.pending);
+        if let Some(buffer) = buffer
+            && let Some(Entry {
+                kind: Kind::Buffer(b),
+                ..
+            }) = entries.get_mut(&buffer.unique_id())
+        {
+            b.busy = true;
+        }
+        true
+    }
+
+    #[cfg(feature = "protocol-xdg_shell")]
+    fn ack_configure(&self, object: &Rc<dyn Object>, serial: u32) -> bool {
+        let entries = &mut *self.entries.borrow_mut();
+        if let Some(Entry {
+            kind: Kind::XdgSurface(x),
+            ..
+        }) = entries.get_mut(&object.unique_id())
+            && let Some(serials) = &x.serials
+        {
+            if !serials.contains(&serial) {
+                log::debug!(
+                    "Dropping an ack_configure request for a configure event of the old server"
+                );
+                return false;
+            }
+            x.serials = None;
+        }
+        true
+    }
+
+    /// Completes pending operations of objects of the old server.
+    ///
+    /// This must be called before the server endpoint is reset.
+    pub(crate) fn retire(&self, state: &State, endpoint: &Endpoint) {
+        use ObjectInterface as I;
+        let objects: Vec<_> = endpoint
+            .objects
+            .borrow()
+            .iter()
+            .map(|(id, object)| (*id, object.clone()))
+            .collect();
+        for (id, object) in objects {
+            if id == 1 || id >= MIN_SERVER_ID {
+                continue;
+            }
+            let destroyed = object.core().awaiting_delete_id.get()
+                || matches!(
+                    self.entries.borrow().get(&object.unique_id()),
+                    Some(Entry {
+                        destroyed: true,
+                        ..
+                    }),
+                );
+            match object.core().interface {
+                I::WlCallback => inject(endpoint, &object, 0, &[0]),
+                #[cfg(feature = "protocol-xdg_shell")]
+                I::XdgPopup if !destroyed => {
+                    inject(endpoint, &object, 1, &[]);
+                    continue;
+                }
+                _ if destroyed => {}
+                _ => continue,
+            }
+            self.entries.borrow_mut().remove(&object.unique_id());
+            if endpoint.objects.borrow().contains_key(&id) {
+                state.handle_delete_id(endpoint, id);
+            }
+        }
+    }
+
+    /// Starts collecting the globals of the new server.
+    pub(crate) fn probe(&self, state: &Rc<State>) {
+        let registry = state.create_object::<WlRegistry>(1);
+        self.probe.set(registry.unique_id());
+        self.probed.borrow_mut().clear();
+        self.replaying.set(true);
+        state.display().send_get_registry(&registry);
+        self.replaying.set(false);
+    }
+
+    /// Recreates the recorded objects that are still alive.
+    ///
+    /// `live` contains the unique IDs of the objects that had server IDs when the
+    /// connection was lost.
+    pub(crate) fn replay(&self, endpoint: &Endpoint, live: &HashSet<u64>) {
+        let probed = self.probed.take();
+        let (removed, matched) = self.names.borrow_mut().remap(&probed);
+        *self.known_names.borrow_mut() = matched;
+        let mut entries = self.entries.take();
+        entries.retain(|id, entry| {
+            live.contains(id) && !entry.destroyed && entry.object.strong_count() > 0
+        });
+        let mut order: Vec<_> = entries.iter().map(|(id, e)| (e.seq, *id)).collect();
+        order.sort_unstable();
+        self.replaying.set(true);
+        let mut registries = vec![];
+        #[cfg(feature = "protocol-xdg_shell")]
+        let mut roles = HashMap::new();
+        for (_, id) in &order {
+            let entry = &entries[id];
+            let (Some(object), Some(parent)) = (entry.object.upgrade(), entry.parent.upgrade())
+            else {
+                continue;
+            };
+            if parent.server_id().is_none() {
+                continue;
+            }
+            match &entry.kind {
+                Kind::Registry => {
+                    parent
+                        .downcast::<WlDisplay>()
+                        .send_get_registry(&object.downcast());
+                    self.muted_registries.borrow_mut().insert(*id);
+                    registries.push(object.clone());
+                }
+                Kind::Bind(name) => {
+                    let names = self.names.borrow();
+                    let Some(global) = names.globals.get(name) else {
+                        continue;
+                    };
+                    if global.version < object.version()
+                        || &*global.interface != object.interface_name()
+                    {
+                        continue;
+                    }
+                    let name = names.server_name(*name);
+                    drop(names);
+                    parent
+                        .downcast::<WlRegistry>()
+                        .send_bind(name, object.clone());
+                }
+                Kind::Surface(_) => {
+                    parent
+                        .downcast::<WlCompositor>()
+                        .send_create_surface(&object.downcast());
+                }
+                Kind::Region(ops) => {
+                    let region = object.downcast::<WlRegion>();
+                    parent
+                        .downcast::<WlCompositor>()
+                        .send_create_region(&region);
+                    apply_region_ops(&region, ops);
+                }
+                Kind::ShmPool(fd, size) => {
+                    parent
+                        .downcast::<WlShm>()
+                        .send_create_pool(&object.downcast(), fd, *size);
+                }
+                Kind::Buffer(b) => {
+                    parent.downcast::<WlShmPool>().send_create_buffer(
+                        &object.downcast(),
+                        b.offset,
+                        b.width,
+                        b.height,
+                        b.stride,
+                        WlShmFormat(b.format),
+                    );
+                }
+                Kind::SeatDevice => {
+                    let seat = parent.downcast::<WlSeat>();
+                    if let Some(pointer) = object.try_downcast::<WlPointer>() {
+                        seat.send_get_pointer(&pointer);
+                    } else if let Some(keyboard) = object.try_downcast::<WlKeyboard>() {
+                        seat.send_get_keyboard(&keyboard);
+                    } else if let Some(touch) = object.try_downcast::<WlTouch>() {
+                        seat.send_get_touch(&touch);
+                    }
+                }
+                #[cfg(feature = "protocol-xdg_shell")]
+                Kind::XdgSurface(x) => {
+                    let Some(surface) = x.surface.upgrade() else {
+                        continue;
+                    };
+                    roles.insert(surface.unique_id(), Role::Hidden);
+                    if x.popup || surface.server_id().is_none() {
+                        continue;
+                    }
+                    let xdg_surface = object.downcast::<XdgSurface>();
+                    parent
+                        .downcast::<XdgWmBase>()
+                        .send_get_xdg_surface(&xdg_surface, &surface.downcast());
+                    if let Some([x, y, width, height]) = x.geometry {
+                        xdg_surface.send_set_window_geometry(x, y, width, height);
+                    }
+                }
+                #[cfg(feature = "protocol-xdg_shell")]
+                Kind::XdgToplevel(t) => {
+                    let toplevel = object.downcast::<XdgToplevel>();
+                    parent.downcast::<XdgSurface>().send_get_toplevel(&toplevel);
+                    restore_toplevel_state(&toplevel, t);
+                    if let Some(Entry {
+                        kind: Kind::XdgSurface(x),
+                        ..
+                    }) = entries.get(&parent.unique_id())
+                        && let Some(surface) = x.surface.upgrade()
+                    {
+                        roles.insert(surface.unique_id(), Role::Toplevel(parent));
+                    }
+                }
+            }
+        }
+        let mut keep = HashSet::new();
+        for (_, id) in &order {
+            let entry = &entries[id];
+            let Kind::Surface(s) = &entry.kind else {
+                continue;
+            };
+            let (Some(surface), Some(compositor)) =
+                (entry.object.upgrade(), entry.parent.upgrade())
+            else {
+                continue;
+            };
+            if surface.server_id().is_none() {
+                continue;
+            }
+            let surface = surface.downcast::<WlSurface>();
+            let compositor = compositor.downcast::<WlCompositor>();
+            let buffer = s.committed.buffer();
+            #[cfg(feature = "protocol-xdg_shell")]
+            match roles.remove(id) {
+                Some(Role::Toplevel(xdg_surface)) => {
+                    restore_surface_state(&compositor, &surface, &s.committed);
+                    surface.send_commit();
+                    let xdg_id = xdg_surface.unique_id();
+                    self.unconfigured.borrow_mut().insert(xdg_id, *id);
+                    if let Some(Entry {
+                        kind: Kind::XdgSurface(x),
+                        ..
+                    }) = entries.get_mut(&xdg_id)
+                    {
+                        x.serials = Some(vec![]);
+                    }
+                    keep.extend(buffer.map(|b| b.unique_id()));
+                    continue;
+                }
+                Some(Role::Hidden) => continue,
+                None => {}
+            }
+            restore_surface_state(&compositor, &surface, &s.committed);
+            if let Some(buffer) = buffer {
+                attach_buffer(&surface, &buffer.downcast());
+                keep.insert(buffer.unique_id());
+            }
+            surface.send_commit();
+        }
+        let mut released = vec![];
+        for (id, entry) in &mut entries {
+            if let Kind::Buffer(b) = &mut entry.kind
+                && b.busy
+                && !keep.contains(id)
+                && let Some(buffer) = entry.object.upgrade()
+            {
+                b.busy = false;
+                released.push(buffer);
+            }
+        }
+        self.replaying.set(false);
+        self.entries.borrow_mut().extend(entries);
+        for buffer in released {
+            inject(endpoint, &buffer, 0, &[]);
+        }
+        for registry in &registries {
+            for &name in &removed {
+                inject(endpoint, registry, 1, &[name]);
+            }
+        }
+    }
+
+    /// Stops suppressing duplicate globals on recreated registries.
+    pub(crate) fn finish(&self) {
+        self.muted_registries.borrow_mut().clear();
+        self.known_names.borrow_mut().clear();
+    }
+
+    #[cfg(feature = "protocol-xdg_shell")]
+    fn restore_toplevel(&self, object: &Rc<dyn Object>, serial: u32) {
+        let buffer = {
+            let entries = &*self.entries.borrow();
+            let surface = match entries.get(&object.unique_id()) {
+                Some(Entry {
+                    kind: Kind::XdgSurface(x),
+                    ..
+                }) => x.surface.upgrade(),
+                _ => None,
+            };
+            surface.map(|surface| {
+                let buffer = match entries.get(&surface.unique_id()) {
+                    Some(Entry {
+                        kind: Kind::Surface(s),
+                        ..
+                    }) => s.committed.buffer(),
+                    _ => None,
+                };
+                (surface, buffer)
+            })
+        };
+        self.replaying.set(true);
+        object.downcast::<XdgSurface>().send_ack_configure(serial);
+        if let Some((surface, buffer)) = buffer
+            && surface.server_id().is_some()
+        {
+            let surface = surface.downcast::<WlSurface>();
+            if let Some(buffer) = buffer {
+                attach_buffer(&surface, &buffer.downcast());
+            }
+            surface.send_commit();
+        }
+        self.replaying.set(false);
+    }
+}
+
+/// Dispatches an event that was not sent by the server.
+fn inject(endpoint: &Endpoint, object: &Rc<dyn Object>, opcode: u32, args: &[u32]) {
+    let mut msg = Vec::with_capacity(2 + args.len());
+    msg.push(object.server_id().unwrap_or(0));
+    msg.push((((2 + args.len()) * 4) as u32) << 16 | opcode);
+    msg.extend_from_slice(args);
+    if let Err(e) = object
+        .clone()
+        .handle_event(endpoint, &msg, &mut VecDeque::new())
+    {
+        log::warn!("Could not dispatch a synthetic event: {}", Report::new(e));
+    }
+}
+
+fn apply_region_ops(region: &WlRegion, ops: &[RegionOp]) {
+    for op in ops {
+        let [x, y, width, height] = op.rect;
+        match op.add {
+            true => region.send_add(x, y, width, height),
+            false => region.send_subtract(x, y, width, height),
+        }
+    }
+}
+
+fn restore_surface_state(compositor: &Rc<WlCompositor>, surface: &WlSurface, s: &SurfaceState) {
+    if let Some(transform) = s.transform {
+        surface.send_set_buffer_transform(WlOutputTransform(transform));
+    }
+    if let Some(scale) = s.scale {
+        surface.send_set_buffer_scale(scale);
+    }
+    let region = |ops: &[RegionOp]| {
+        let region = compositor.new_send_create_region();
+        apply_region_ops(&region, ops);
+        region
+    };
+    if let Some(Some(ops)) = &s.opaque_region {
+        let region = region(ops);
+        surface.send_set_opaque_region(Some(&region));
+        region.send_destroy();
+    }
+    if let Some(Some(ops)) = &s.input_region {
+        let region = region(ops);
+        surface.send_set_input_region(Some(&region));
+        region.send_destroy();
+    }
+}
+
+fn attach_buffer(surface: &WlSurface, buffer: &Rc<WlBuffer>) {
+    surface.send_attach(Some(buffer), 0, 0);
+    if surface.version() >= 4 {
+        surface.send_damage_buffer(0, 0, i32::MAX, i32::MAX);
+    } else {
+        surface.send_damage(0, 0, i32::MAX, i32::MAX);
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+fn restore_toplevel_state(toplevel: &XdgToplevel, t: &ToplevelState) {
+    if let Some(parent) = t.parent.as_ref().and_then(|p| p.upgrade())
+        && parent.server_id().is_some()
+    {
+        toplevel.send_set_parent(Some(&parent.downcast()));
+    }
+    if let Some(title) = &t.title {
+        toplevel.send_set_title(title);
+    }
+    if let Some(app_id) = &t.app_id {
+        toplevel.send_set_app_id(app_id);
+    }
+    if let Some([width, height]) = t.max_size {
+        toplevel.send_set_max_size(width, height);
+    }
+    if let Some([width, height]) = t.min_size {
+        toplevel.send_set_min_size(width, height);
+    }
+    if t.maximized {
+        toplevel.send_set_maximized();
+    }
+    if t.fullscreen {
+        toplevel.send_set_fullscreen(None);
+    }
+}
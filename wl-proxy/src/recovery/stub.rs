@@ -0,0 +1,244 @@
+//! A server that keeps clients alive while a state is detached.
+
+use {
+    crate::{
+        baseline::Baseline,
+        client::ClientHandler,
+        object::{Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_buffer::WlBuffer,
+                wl_callback::WlCallback,
+                wl_compositor::{WlCompositor, WlCompositorHandler},
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+                wl_surface::{WlSurface, WlSurfaceHandler},
+            },
+        },
+        state::{RemoteDestructor, State, StateError},
+    },
+    error_reporter::Report,
+    std::{
+        cell::RefCell,
+        io,
+        os::fd::OwnedFd,
+        rc::Rc,
+        sync::mpsc,
+        thread,
+        time::{Duration, Instant},
+    },
+};
+#[cfg(feature = "protocol-xdg_shell")]
+use {
+    crate::{
+        object::ObjectUtils,
+        protocols::xdg_shell::{
+            xdg_surface::{XdgSurface, XdgSurfaceHandler},
+            xdg_toplevel::XdgToplevel,
+            xdg_wm_base::{XdgWmBase, XdgWmBaseHandler},
+        },
+    },
+    std::cell::Cell,
+};
+
+/// The interval at which frame callbacks are completed.
+const FRAME_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) type StubGlobal = (u32, ObjectInterface, u32);
+
+/// Starts a stub server in a new thread.
+///
+/// The stub advertises the given globals. It completes wl_display.sync requests
+/// immediately, completes frame callbacks at a low rate, releases buffers as soon as
+/// they are committed, and sends the initial configure events of toplevels. All other
+/// requests are ignored.
+pub(crate) fn spawn(globals: Vec<StubGlobal>) -> io::Result<(OwnedFd, RemoteDestructor)> {
+    let (send, recv) = mpsc::channel();
+    thread::Builder::new()
+        .name("wl-proxy-stub".to_string())
+        .spawn(move || {
+            let stub = Stub {
+                globals: globals.into(),
+                ..Default::default()
+            };
+            let state = match create(&stub) {
+                Ok((state, fd, destructor)) => {
+                    let _ = send.send(Ok((fd, destructor)));
+                    state
+                }
+                Err(e) => {
+                    let _ = send.send(Err(io::Error::other(Report::new(e).to_string())));
+                    return;
+                }
+            };
+            run(&state, &stub);
+        })?;
+    recv.recv().map_err(io::Error::other)?
+}
+
+type Created = (Rc<State>, OwnedFd, RemoteDestructor);
+
+fn create(stub: &Stub) -> Result<Created, StateError> {
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .with_log_prefix("stub")
+        .build()?;
+    state.set_default_forward_to_server(false);
+    state.delete_ids_on_destroy.set(true);
+    let destructor = state.create_remote_destructor()?;
+    let (client, fd) = state.connect()?;
+    client.set_handler(Disconnect {
+        state: state.clone(),
+    });
+    client.display().set_handler(stub.clone());
+    Ok((state, fd, destructor))
+}
+
+fn run(state: &Rc<State>, stub: &Stub) {
+    let start = Instant::now();
+    let mut next_frame = start + FRAME_INTERVAL;
+    while state.is_not_destroyed() {
+        let timeout = next_frame.saturating_duration_since(Instant::now());
+        if let Err(e) = state.dispatch(Some(timeout)) {
+            if !e.is_destroyed() {
+                log::warn!("The stub server failed: {}", Report::new(e));
+            }
+            break;
+        }
+        let now = Instant::now();
+        if now < next_frame {
+            continue;
+        }
+        next_frame = now + FRAME_INTERVAL;
+        let time = (now - start).as_millis() as u32;
+        for callback in stub.frames.take() {
+            callback.send_done(time);
+            callback.delete_id();
+        }
+    }
+    state.destroy();
+}
+
+struct Disconnect {
+    state: Rc<State>,
+}
+
+impl ClientHandler for Disconnect {
+    fn disconnected(self: Box<Self>) {
+        self.state.destroy();
+    }
+}
+
+#[derive(Clone, Default)]
+struct Stub {
+    globals: Rc<[StubGlobal]>,
+    frames: Rc<RefCell<Vec<Rc<WlCallback>>>>,
+    #[cfg(feature = "protocol-xdg_shell")]
+    serial: Rc<Cell<u32>>,
+}
+
+#[derive(Default)]
+struct Surface {
+    stub: Stub,
+    pending: Option<Rc<WlBuffer>>,
+    #[cfg(feature = "protocol-xdg_shell")]
+    toplevel: Option<(Rc<XdgSurface>, Rc<XdgToplevel>)>,
+    #[cfg(feature = "protocol-xdg_shell")]
+    configured: bool,
+}
+
+impl WlDisplayHandler for Stub {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(0);
+        callback.delete_id();
+    }
+
+    fn handle_get_registry(&mut self, _slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        for &(name, interface, version) in &*self.globals {
+            registry.send_global(name, interface, version);
+        }
+        registry.set_handler(self.clone());
+    }
+}
+
+impl WlRegistryHandler for Stub {
+    fn handle_bind(&mut self, _slf: &Rc<WlRegistry>, _name: u32, id: Rc<dyn Object>) {
+        if let Some(compositor) = id.try_downcast::<WlCompositor>() {
+            compositor.set_handler(self.clone());
+        }
+        #[cfg(feature = "protocol-xdg_shell")]
+        if let Some(wm_base) = id.try_downcast::<XdgWmBase>() {
+            wm_base.set_handler(self.clone());
+        }
+    }
+}
+
+impl WlCompositorHandler for Stub {
+    fn handle_create_surface(&mut self, _slf: &Rc<WlCompositor>, id: &Rc<WlSurface>) {
+        id.set_handler(Surface {
+            stub: self.clone(),
+            ..Default::default()
+        });
+    }
+}
+
+impl WlSurfaceHandler for Surface {
+    fn handle_attach(
+        &mut self,
+        _slf: &Rc<WlSurface>,
+        buffer: Option<&Rc<WlBuffer>>,
+        _x: i32,
+        _y: i32,
+    ) {
+        self.pending = buffer.cloned();
+    }
+
+    fn handle_frame(&mut self, _slf: &Rc<WlSurface>, callback: &Rc<WlCallback>) {
+        self.stub.frames.borrow_mut().push(callback.clone());
+    }
+
+    fn handle_commit(&mut self, _slf: &Rc<WlSurface>) {
+        if let Some(buffer) = self.pending.take() {
+            buffer.send_release();
+        }
+        #[cfg(feature = "protocol-xdg_shell")]
+        if let Some((xdg_surface, toplevel)) = &self.toplevel
+            && !self.configured
+        {
+            let serial = self.stub.serial.get().wrapping_add(1);
+            self.stub.serial.set(serial);
+            toplevel.send_configure(0, 0, &[]);
+            xdg_surface.send_configure(serial);
+            self.configured = true;
+        }
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+impl XdgWmBaseHandler for Stub {
+    fn handle_get_xdg_surface(
+        &mut self,
+        _slf: &Rc<XdgWmBase>,
+        id: &Rc<XdgSurface>,
+        surface: &Rc<WlSurface>,
+    ) {
+        id.set_handler(XdgSurfaceImpl {
+            surface: surface.clone(),
+        });
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+struct XdgSurfaceImpl {
+    surface: Rc<WlSurface>,
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+impl XdgSurfaceHandler for XdgSurfaceImpl {
+    fn handle_get_toplevel(&mut self, slf: &Rc<XdgSurface>, id: &Rc<XdgToplevel>) {
+        if let Ok(mut surface) = self.surface.try_get_handler_mut::<Surface>() {
+            surface.toplevel = Some((slf.clone(), id.clone()));
+        }
+    }
+}
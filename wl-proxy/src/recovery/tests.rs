@@ -0,0 +1,407 @@
+use {
+    crate::{
+        acceptor::Acceptor,
+        baseline::Baseline,
+        client::{Client, ClientHandler},
+        object::{Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_compositor::{WlCompositor, WlCompositorHandler},
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+                wl_shm::WlShm,
+                wl_surface::{WlSurface, WlSurfaceHandler},
+            },
+        },
+        recovery::{Global, Names, Recovery},
+        state::{RemoteDestructor, State, StateHandler},
+        test_framework::{install_logger, proxy::dispatch_blocking},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        io,
+        os::fd::OwnedFd,
+        rc::Rc,
+        sync::mpsc::{self, Receiver, Sender},
+        thread,
+        time::Duration,
+    },
+};
+
+struct Compositor {
+    destructor: RemoteDestructor,
+    log: Receiver<String>,
+}
+
+fn test_compositor(globals: &'static [(u32, ObjectInterface)]) -> (OwnedFd, Compositor) {
+    let (send, recv) = mpsc::channel();
+    let (send_log, log) = mpsc::channel();
+    thread::spawn(move || {
+        let state = State::builder(Baseline::ALL_OF_THEM)
+            .without_server()
+            .build()
+            .unwrap();
+        state.set_default_forward_to_server(false);
+        let (client, fd) = state.connect().unwrap();
+        send.send((fd, state.create_remote_destructor().unwrap()))
+            .unwrap();
+        client.set_handler(ClientHandlerImpl {
+            state: state.clone(),
+        });
+        client.display().set_handler(Handler {
+            globals,
+            log: send_log,
+        });
+        while state.is_not_destroyed() {
+            if state.dispatch_blocking().is_err() {
+                break;
+            }
+        }
+    });
+    let (fd, destructor) = recv.recv().unwrap();
+    (fd, Compositor { destructor, log })
+}
+
+fn display_compositor(globals: &'static [(u32, ObjectInterface)]) -> (String, Compositor) {
+    let (send, recv) = mpsc::channel();
+    let (send_log, log) = mpsc::channel();
+    thread::spawn(move || {
+        let state = State::builder(Baseline::ALL_OF_THEM)
+            .without_server()
+            .build()
+            .unwrap();
+        state.set_default_forward_to_server(false);
+        let acceptor = Acceptor::new(1000, true).unwrap();
+        state.add_acceptor(&acceptor).unwrap();
+        send.send((
+            acceptor.display().to_string(),
+            state.create_remote_destructor().unwrap(),
+        ))
+        .unwrap();
+        state.set_handler(Handler {
+            globals,
+            log: send_log,
+        });
+        while state.is_not_destroyed() {
+            if state.dispatch_blocking().is_err() {
+                break;
+            }
+        }
+    });
+    let (display, destructor) = recv.recv().unwrap();
+    (display, Compositor { destructor, log })
+}
+
+struct ClientHandlerImpl {
+    state: Rc<State>,
+}
+
+impl ClientHandler for ClientHandlerImpl {
+    fn disconnected(self: Box<Self>) {
+        self.state.destroy();
+    }
+}
+
+#[derive(Clone)]
+struct Handler {
+    globals: &'static [(u32, ObjectInterface)],
+    log: Sender<String>,
+}
+
+impl StateHandler for Handler {
+    fn new_client(&mut self, client: &Rc<Client>) {
+        client.display().set_handler(self.clone());
+    }
+}
+
+impl WlDisplayHandler for Handler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(0);
+        callback.delete_id();
+    }
+
+    fn handle_get_registry(&mut self, _slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        for &(name, interface) in self.globals {
+            registry.send_global(name, interface, 6);
+        }
+        registry.set_handler(self.clone());
+    }
+}
+
+impl WlRegistryHandler for Handler {
+    fn handle_bind(&mut self, _slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        let _ = self
+            .log
+            .send(format!("bind {name} {}", id.interface_name()));
+        if let Some(compositor) = id.try_downcast::<WlCompositor>() {
+            compositor.set_handler(self.clone());
+        }
+    }
+}
+
+impl WlCompositorHandler for Handler {
+    fn handle_create_surface(&mut self, _slf: &Rc<WlCompositor>, id: &Rc<WlSurface>) {
+        let _ = self.log.send("create_surface".to_string());
+        id.set_handler(self.clone());
+    }
+}
+
+impl WlSurfaceHandler for Handler {
+    fn handle_commit(&mut self, _slf: &Rc<WlSurface>) {
+        let _ = self.log.send("commit".to_string());
+    }
+
+    fn handle_set_buffer_scale(&mut self, _slf: &Rc<WlSurface>, scale: i32) {
+        let _ = self.log.send(format!("scale {scale}"));
+    }
+}
+
+#[derive(Default)]
+struct Globals(Rc<RefCell<Vec<(u32, ObjectInterface)>>>);
+
+impl WlRegistryHandler for Globals {
+    fn handle_global(
+        &mut self,
+        _slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: ObjectInterface,
+        _version: u32,
+    ) {
+        self.0.borrow_mut().push((name, interface));
+    }
+}
+
+struct Done(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+struct Recovered(Rc<Cell<bool>>);
+
+impl StateHandler for Recovered {
+    fn server_recovered(&mut self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn recover() {
+    install_logger();
+    const FIRST: &[(u32, ObjectInterface)] = &[(1, ObjectInterface::WlCompositor)];
+    const SECOND: &[(u32, ObjectInterface)] = &[
+        (7, ObjectInterface::WlShm),
+        (8, ObjectInterface::WlCompositor),
+    ];
+    let (fd, first) = test_compositor(FIRST);
+    let second = Rc::new(RefCell::new(None));
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(fd))
+        .with_recovery_connector(Duration::from_secs(5), {
+            let second = second.clone();
+            move || {
+                let (fd, compositor) = test_compositor(SECOND);
+                *second.borrow_mut() = Some(compositor);
+                Ok(fd)
+            }
+        })
+        .build()
+        .unwrap();
+    let _proxy_destructor = proxy.create_destructor();
+    let recovered = Rc::new(Cell::new(false));
+    proxy.set_handler(Recovered(recovered.clone()));
+
+    let (_, client_fd) = proxy.connect().unwrap();
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .build()
+        .unwrap();
+    let _client_destructor = client.create_destructor();
+    client.set_default_forward_to_client(false);
+    let roundtrip = || {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([&client, &proxy]).unwrap();
+        }
+    };
+    let globals = Globals::default();
+    let seen = globals.0.clone();
+    let registry = client.display().new_send_get_registry();
+    registry.set_handler(globals);
+    roundtrip();
+    assert_eq!(&*seen.borrow(), &[(1, ObjectInterface::WlCompositor)]);
+
+    let compositor = client.create_object::<WlCompositor>(6);
+    registry.send_bind(1, compositor.clone());
+    let surface = compositor.new_send_create_surface();
+    surface.send_set_buffer_scale(2);
+    surface.send_commit();
+    roundtrip();
+    let log: Vec<_> = first.log.try_iter().collect();
+    assert_eq!(
+        log,
+        [
+            "bind 1 wl_compositor",
+            "create_surface",
+            "scale 2",
+            "commit"
+        ],
+    );
+
+    drop(first.destructor);
+    while !recovered.get() {
+        dispatch_blocking([&client, &proxy]).unwrap();
+    }
+    roundtrip();
+    assert!(proxy.is_not_destroyed());
+    assert_eq!(
+        &*seen.borrow(),
+        &[
+            (1, ObjectInterface::WlCompositor),
+            (2, ObjectInterface::WlShm),
+        ],
+    );
+    let second = second.borrow_mut().take().unwrap();
+    let log: Vec<_> = second.log.try_iter().collect();
+    assert_eq!(
+        log,
+        [
+            "bind 8 wl_compositor",
+            "create_surface",
+            "scale 2",
+            "commit"
+        ],
+    );
+
+    let shm = client.create_object::<WlShm>(1);
+    registry.send_bind(2, shm);
+    surface.send_commit();
+    roundtrip();
+    let log: Vec<_> = second.log.try_iter().collect();
+    assert_eq!(log, ["bind 7 wl_shm", "commit"]);
+}
+
+#[test]
+fn remap() {
+    let global = |interface: &str| Global {
+        interface: interface.into(),
+        version: 1,
+    };
+    let mut names = Names::default();
+    assert_eq!(names.client_name(3), 3);
+    assert_eq!(names.client_name(1), 1);
+    names.globals.insert(1, global("wl_compositor"));
+    names.globals.insert(3, global("wl_seat"));
+    let (removed, matched) = names.remap(&[(5, global("wl_shm")), (9, global("wl_compositor"))]);
+    assert_eq!(removed, [3]);
+    assert_eq!(matched.into_iter().collect::<Vec<_>>(), [1]);
+    assert_eq!(names.server_name(1), 9);
+    assert_eq!(names.server_name(4), 5);
+    assert_eq!(names.client_name(5), 4);
+    assert_eq!(names.client_name(10), 5);
+    assert_eq!(names.server_name(5), 10);
+}
+
+#[test]
+fn timeout() {
+    let fail = || -> Box<dyn FnMut() -> io::Result<OwnedFd>> {
+        Box::new(|| Err(io::Error::other("no connector")))
+    };
+    let recovery = Recovery::new(Duration::MAX, fail());
+    assert!(recovery.deadline().is_none());
+    let recovery = Recovery::new(Duration::ZERO, fail());
+    assert!(recovery.connect(recovery.deadline()).is_err());
+}
+
+#[test]
+fn detach() {
+    install_logger();
+    const FIRST: &[(u32, ObjectInterface)] = &[(1, ObjectInterface::WlCompositor)];
+    const SECOND: &[(u32, ObjectInterface)] = &[
+        (7, ObjectInterface::WlShm),
+        (8, ObjectInterface::WlCompositor),
+    ];
+    let (fd, first) = test_compositor(FIRST);
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(fd))
+        .with_recovery_connector(Duration::from_secs(5), || {
+            Err(io::Error::other("no connector"))
+        })
+        .build()
+        .unwrap();
+    let _proxy_destructor = proxy.create_destructor();
+    assert!(!proxy.is_detached());
+
+    let (_, client_fd) = proxy.connect().unwrap();
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .build()
+        .unwrap();
+    let _client_destructor = client.create_destructor();
+    client.set_default_forward_to_client(false);
+    let wait = |done: &Cell<bool>| {
+        while !done.get() {
+            dispatch_blocking([&client, &proxy]).unwrap();
+        }
+    };
+    let roundtrip = || {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        wait(&done);
+    };
+    let globals = Globals::default();
+    let seen = globals.0.clone();
+    let registry = client.display().new_send_get_registry();
+    registry.set_handler(globals);
+    let compositor = client.create_object::<WlCompositor>(6);
+    registry.send_bind(1, compositor.clone());
+    let surface = compositor.new_send_create_surface();
+    surface.send_set_buffer_scale(2);
+    surface.send_commit();
+    roundtrip();
+    assert_eq!(first.log.try_iter().count(), 4);
+
+    proxy.detach().unwrap();
+    assert!(proxy.is_detached());
+    assert!(proxy.detach().is_err());
+    let done = Rc::new(Cell::new(false));
+    surface.new_send_frame().set_handler(Done(done.clone()));
+    surface.send_commit();
+    wait(&done);
+    assert_eq!(first.log.try_iter().count(), 0);
+
+    let (display, second) = display_compositor(SECOND);
+    proxy.reattach(&display).unwrap();
+    assert!(!proxy.is_detached());
+    roundtrip();
+    assert_eq!(
+        &*seen.borrow(),
+        &[
+            (1, ObjectInterface::WlCompositor),
+            (2, ObjectInterface::WlShm),
+        ],
+    );
+    let log: Vec<_> = second.log.try_iter().collect();
+    assert_eq!(
+        log,
+        [
+            "bind 8 wl_compositor",
+            "create_surface",
+            "scale 2",
+            "commit"
+        ],
+    );
+    drop(first.destructor);
+    drop(second.destructor);
+}
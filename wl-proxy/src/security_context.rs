@@ -0,0 +1,319 @@
+//! Sandboxed server connections via wp_security_context_v1.
+//!
+//! This module requires the `protocol-security_context_v1` feature.
+//!
+//! A [`SecurityContextListener`] registers a listening socket with the compositor via
+//! `wp_security_context_manager_v1.create_listener`. Connections to this socket are
+//! treated by the compositor as belonging to the sandbox described by the
+//! [`SecurityContext`].
+//!
+//! An acceptor created with [`Acceptor::with_security_context`] accepts the connections
+//! of sandboxed clients. The [`SecurityContext`] of the listener is attached to each
+//! client accepted by it and is available to the proxy via [`Client::security_context`],
+//! for example, in [`StateHandler::new_client`].
+//!
+//! A state built with [`StateBuilder::with_security_context`] connects to the server via
+//! such a listener. This allows a proxy to sit in front of a sandboxed application while
+//! still making the compositor aware of the identity of the sandbox. The acceptors
+//! created with [`State::create_acceptor`] for such a state use the same listener.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use std::rc::Rc;
+//! # use wl_proxy::acceptor::Acceptor;
+//! # use wl_proxy::baseline::Baseline;
+//! # use wl_proxy::client::Client;
+//! # use wl_proxy::security_context::{SecurityContext, SecurityContextListener};
+//! # use wl_proxy::state::{State, StateHandler};
+//! let context = SecurityContext::default()
+//!     .with_sandbox_engine("org.example.launcher")
+//!     .with_app_id("org.example.app")
+//!     .with_instance_id("1");
+//! let listener =
+//!     SecurityContextListener::new(State::builder(Baseline::ALL_OF_THEM), &context).unwrap();
+//! let state = State::builder(Baseline::ALL_OF_THEM)
+//!     .with_security_context(&listener)
+//!     .build()
+//!     .unwrap();
+//! let acceptor = Acceptor::with_security_context(&listener, 1000, true).unwrap();
+//! state.add_acceptor(&acceptor).unwrap();
+//! state.set_handler(Handler);
+//! // spawn the sandbox with WAYLAND_DISPLAY=acceptor.display()
+//!
+//! struct Handler;
+//!
+//! impl StateHandler for Handler {
+//!     fn new_client(&mut self, client: &Rc<Client>) {
+//!         let context = client.security_context().unwrap();
+//!         eprintln!("{:?} connected", context.app_id());
+//!     }
+//! }
+//! ```
+//!
+//! [`Acceptor::with_security_context`]: crate::acceptor::Acceptor::with_security_context
+//! [`StateBuilder::with_security_context`]: crate::state::StateBuilder::with_security_context
+//! [`State::create_acceptor`]: crate::state::State::create_acceptor
+//! [`Client::security_context`]: crate::client::Client::security_context
+//! [`StateHandler::new_client`]: crate::state::StateHandler::new_client
+
+use {
+    crate::{
+        protocols::{
+            ObjectInterface,
+            security_context_v1::wp_security_context_manager_v1::WpSecurityContextManagerV1,
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+            },
+        },
+        state::{State, StateBuilder, StateError},
+        utils::env::XDG_RUNTIME_DIR,
+    },
+    run_on_drop::on_drop,
+    std::{
+        cell::Cell,
+        env::var,
+        io::{self, PipeWriter, pipe},
+        os::fd::OwnedFd,
+        process,
+        rc::Rc,
+        sync::atomic::{AtomicU64, Ordering::Relaxed},
+    },
+    thiserror::Error,
+    uapi::c,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The metadata of a sandbox.
+///
+/// See the wp_security_context_v1 protocol for the meaning of the fields.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SecurityContext {
+    sandbox_engine: Option<String>,
+    app_id: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl SecurityContext {
+    /// Sets the name of the sandbox engine, e.g. `org.flatpak`.
+    pub fn with_sandbox_engine(mut self, sandbox_engine: &str) -> Self {
+        self.sandbox_engine = Some(sandbox_engine.to_string());
+        self
+    }
+
+    /// Sets the application ID.
+    pub fn with_app_id(mut self, app_id: &str) -> Self {
+        self.app_id = Some(app_id.to_string());
+        self
+    }
+
+    /// Sets the ID of the sandbox instance.
+    pub fn with_instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = Some(instance_id.to_string());
+        self
+    }
+
+    /// Returns the name of the sandbox engine.
+    pub fn sandbox_engine(&self) -> Option<&str> {
+        self.sandbox_engine.as_deref()
+    }
+
+    /// Returns the application ID.
+    pub fn app_id(&self) -> Option<&str> {
+        self.app_id.as_deref()
+    }
+
+    /// Returns the ID of the sandbox instance.
+    pub fn instance_id(&self) -> Option<&str> {
+        self.instance_id.as_deref()
+    }
+}
+
+/// A listening socket registered with the compositor.
+///
+/// The compositor stops accepting connections on the socket when this object is
+/// dropped. Connections that have already been established are not affected.
+pub struct SecurityContextListener {
+    context: SecurityContext,
+    path: String,
+    _close_fd: PipeWriter,
+}
+
+/// An error emitted by a [`SecurityContextListener`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct SecurityContextError(#[from] SecurityContextErrorKind);
+
+#[derive(Debug, Error)]
+enum SecurityContextErrorKind {
+    #[error("{} is not set", XDG_RUNTIME_DIR)]
+    XrdNotSet,
+    #[error("the socket path is too long")]
+    SocketPathTooLong,
+    #[error("could not create a socket")]
+    CreateSocket(#[source] io::Error),
+    #[error("could not bind the socket to an address")]
+    Bind(#[source] io::Error),
+    #[error("could not start listening for incoming connections")]
+    Listen(#[source] io::Error),
+    #[error("could not connect to {0}")]
+    Connect(String, #[source] io::Error),
+    #[error("could not create a pipe")]
+    CreatePipe(#[source] io::Error),
+    #[error("could not connect to the compositor")]
+    CreateState(#[source] StateError),
+    #[error("could not dispatch the compositor connection")]
+    Dispatch(#[source] StateError),
+    #[error("the compositor does not support wp_security_context_manager_v1")]
+    NotSupported,
+}
+
+impl SecurityContextListener {
+    /// Registers a new listening socket with the compositor.
+    ///
+    /// The builder is used to create a temporary connection to the compositor that is
+    /// used to send the `create_listener` request. This connection is closed before this
+    /// function returns.
+    ///
+    /// The socket is created in the `XDG_RUNTIME_DIR` directory and is removed when this
+    /// object is dropped.
+    pub fn new(
+        builder: StateBuilder,
+        context: &SecurityContext,
+    ) -> Result<Rc<Self>, SecurityContextError> {
+        let state = builder
+            .build()
+            .map_err(SecurityContextErrorKind::CreateState)?;
+        let _destroy = on_drop(|| state.destroy());
+        let display = state.display();
+        let registry = display.new_send_get_registry();
+        let global = Rc::new(Cell::new(None));
+        registry.set_handler(Registry(global.clone()));
+        roundtrip(&state)?;
+        let Some((name, version)) = global.get() else {
+            return Err(SecurityContextErrorKind::NotSupported.into());
+        };
+        let manager = state.create_object::<WpSecurityContextManagerV1>(version.min(1));
+        registry.send_bind(name, manager.clone());
+        let (listen_fd, path) = create_socket()?;
+        let unlink = on_drop(|| {
+            let _ = uapi::unlink(&*path);
+        });
+        let (close_read, close_write) = pipe().map_err(SecurityContextErrorKind::CreatePipe)?;
+        let close_read: OwnedFd = close_read.into();
+        let security_context =
+            manager.new_send_create_listener(&Rc::new(listen_fd), &Rc::new(close_read));
+        if let Some(sandbox_engine) = &context.sandbox_engine {
+            security_context.send_set_sandbox_engine(sandbox_engine);
+        }
+        if let Some(app_id) = &context.app_id {
+            security_context.send_set_app_id(app_id);
+        }
+        if let Some(instance_id) = &context.instance_id {
+            security_context.send_set_instance_id(instance_id);
+        }
+        security_context.send_commit();
+        security_context.send_destroy();
+        manager.send_destroy();
+        roundtrip(&state)?;
+        unlink.forget();
+        Ok(Rc::new(Self {
+            context: context.clone(),
+            path,
+            _close_fd: close_write,
+        }))
+    }
+
+    /// Returns the security context of this listener.
+    pub fn context(&self) -> &SecurityContext {
+        &self.context
+    }
+
+    /// Creates a new connection to the compositor via this listener.
+    pub fn connect(&self) -> Result<OwnedFd, SecurityContextError> {
+        let socket = uapi::socket(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0)
+            .map_err(|e| SecurityContextErrorKind::CreateSocket(e.into()))?;
+        let addr = socket_addr(&self.path)?;
+        uapi::connect(socket.raw(), &addr)
+            .map_err(|e| SecurityContextErrorKind::Connect(self.path.clone(), e.into()))?;
+        Ok(socket.into())
+    }
+}
+
+impl Drop for SecurityContextListener {
+    fn drop(&mut self) {
+        let _ = uapi::unlink(&*self.path);
+    }
+}
+
+fn create_socket() -> Result<(OwnedFd, String), SecurityContextError> {
+    static ID: AtomicU64 = AtomicU64::new(0);
+    let Ok(xrd) = var(XDG_RUNTIME_DIR) else {
+        return Err(SecurityContextErrorKind::XrdNotSet.into());
+    };
+    let path = format!(
+        "{xrd}/wl-proxy-security-context-{}-{}",
+        process::id(),
+        ID.fetch_add(1, Relaxed),
+    );
+    let addr = socket_addr(&path)?;
+    let socket = uapi::socket(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0)
+        .map_err(|e| SecurityContextErrorKind::CreateSocket(e.into()))?;
+    let _ = uapi::unlink(&*path);
+    uapi::bind(socket.raw(), &addr).map_err(|e| SecurityContextErrorKind::Bind(e.into()))?;
+    if let Err(e) = uapi::listen(socket.raw(), 128) {
+        let _ = uapi::unlink(&*path);
+        return Err(SecurityContextErrorKind::Listen(e.into()).into());
+    }
+    Ok((socket.into(), path))
+}
+
+fn socket_addr(path: &str) -> Result<c::sockaddr_un, SecurityContextError> {
+    let mut addr: c::sockaddr_un = uapi::pod_zeroed();
+    addr.sun_family = c::AF_UNIX as _;
+    if path.len() + 1 > addr.sun_path.len() {
+        return Err(SecurityContextErrorKind::SocketPathTooLong.into());
+    }
+    let sun_path = uapi::as_bytes_mut(&mut addr.sun_path[..]);
+    sun_path[..path.len()].copy_from_slice(path.as_bytes());
+    Ok(addr)
+}
+
+fn roundtrip(state: &Rc<State>) -> Result<(), SecurityContextError> {
+    let done = Rc::new(Cell::new(false));
+    let callback = state.display().new_send_sync();
+    callback.set_handler(Callback(done.clone()));
+    while !done.get() {
+        state
+            .dispatch_blocking()
+            .map_err(SecurityContextErrorKind::Dispatch)?;
+    }
+    Ok(())
+}
+
+struct Registry(Rc<Cell<Option<(u32, u32)>>>);
+
+impl WlRegistryHandler for Registry {
+    fn handle_global(
+        &mut self,
+        _slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: ObjectInterface,
+        version: u32,
+    ) {
+        if interface == ObjectInterface::WpSecurityContextManagerV1 {
+            self.0.set(Some((name, version)));
+        }
+    }
+}
+
+struct Callback(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for Callback {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
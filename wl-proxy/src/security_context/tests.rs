@@ -0,0 +1,266 @@
+use {
+    crate::{
+        acceptor::Acceptor,
+        baseline::Baseline,
+        client::{Client, ClientHandler},
+        object::{Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::{
+            ObjectInterface,
+            security_context_v1::{
+                wp_security_context_manager_v1::{
+                    WpSecurityContextManagerV1, WpSecurityContextManagerV1Handler,
+                },
+                wp_security_context_v1::{WpSecurityContextV1, WpSecurityContextV1Handler},
+            },
+            wayland::{
+                wl_callback::WlCallback,
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+            },
+        },
+        security_context::{SecurityContext, SecurityContextListener},
+        state::{State, StateHandler},
+        test_framework::{install_logger, server::test_server},
+    },
+    std::{
+        cell::RefCell,
+        os::fd::{AsRawFd, OwnedFd},
+        rc::Rc,
+        sync::mpsc::{self, Sender},
+        thread,
+    },
+    uapi::c,
+};
+
+type Committed = (SecurityContext, OwnedFd);
+
+fn test_compositor() -> (Rc<OwnedFd>, mpsc::Receiver<Committed>) {
+    let (send, recv) = mpsc::channel();
+    let (send_committed, recv_committed) = mpsc::channel();
+    thread::spawn(move || {
+        let state = State::builder(Baseline::ALL_OF_THEM)
+            .without_server()
+            .build()
+            .unwrap();
+        state.set_default_forward_to_server(false);
+        let (client, fd) = state.connect().unwrap();
+        send.send(fd).unwrap();
+        client.set_handler(ClientHandlerImpl {
+            state: state.clone(),
+        });
+        client.display().set_handler(DisplayHandler {
+            committed: send_committed,
+        });
+        while state.is_not_destroyed() {
+            state.dispatch_blocking().unwrap();
+        }
+    });
+    (Rc::new(recv.recv().unwrap()), recv_committed)
+}
+
+struct ClientHandlerImpl {
+    state: Rc<State>,
+}
+
+impl ClientHandler for ClientHandlerImpl {
+    fn disconnected(self: Box<Self>) {
+        self.state.destroy();
+    }
+}
+
+struct DisplayHandler {
+    committed: Sender<Committed>,
+}
+
+impl WlDisplayHandler for DisplayHandler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(0);
+        callback.delete_id();
+    }
+
+    fn handle_get_registry(&mut self, _slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.send_global(1, ObjectInterface::WpSecurityContextManagerV1, 1);
+        registry.set_handler(RegistryHandler {
+            committed: self.committed.clone(),
+        });
+    }
+}
+
+struct RegistryHandler {
+    committed: Sender<Committed>,
+}
+
+impl WlRegistryHandler for RegistryHandler {
+    fn handle_bind(&mut self, _slf: &Rc<WlRegistry>, _name: u32, id: Rc<dyn Object>) {
+        let id = id.downcast::<WpSecurityContextManagerV1>();
+        id.set_handler(ManagerHandler {
+            committed: self.committed.clone(),
+        });
+    }
+}
+
+struct ManagerHandler {
+    committed: Sender<Committed>,
+}
+
+impl WpSecurityContextManagerV1Handler for ManagerHandler {
+    fn handle_create_listener(
+        &mut self,
+        _slf: &Rc<WpSecurityContextManagerV1>,
+        id: &Rc<WpSecurityContextV1>,
+        listen_fd: &Rc<OwnedFd>,
+        _close_fd: &Rc<OwnedFd>,
+    ) {
+        id.set_handler(ContextHandler {
+            committed: self.committed.clone(),
+            context: Default::default(),
+            listen_fd: listen_fd.try_clone().unwrap(),
+        });
+    }
+}
+
+struct ContextHandler {
+    committed: Sender<Committed>,
+    context: SecurityContext,
+    listen_fd: OwnedFd,
+}
+
+impl WpSecurityContextV1Handler for ContextHandler {
+    fn handle_set_sandbox_engine(&mut self, _slf: &Rc<WpSecurityContextV1>, name: &str) {
+        self.context.sandbox_engine = Some(name.to_string());
+    }
+
+    fn handle_set_app_id(&mut self, _slf: &Rc<WpSecurityContextV1>, app_id: &str) {
+        self.context.app_id = Some(app_id.to_string());
+    }
+
+    fn handle_set_instance_id(&mut self, _slf: &Rc<WpSecurityContextV1>, instance_id: &str) {
+        self.context.instance_id = Some(instance_id.to_string());
+    }
+
+    fn handle_commit(&mut self, _slf: &Rc<WpSecurityContextV1>) {
+        let committed = (self.context.clone(), self.listen_fd.try_clone().unwrap());
+        self.committed.send(committed).unwrap();
+    }
+}
+
+#[test]
+fn context() {
+    let context = SecurityContext::default()
+        .with_sandbox_engine("engine")
+        .with_app_id("app");
+    assert_eq!(context.sandbox_engine(), Some("engine"));
+    assert_eq!(context.app_id(), Some("app"));
+    assert_eq!(context.instance_id(), None);
+}
+
+#[test]
+fn create_listener() {
+    install_logger();
+    let (server, committed) = test_compositor();
+    let context = SecurityContext::default()
+        .with_sandbox_engine("engine")
+        .with_app_id("app")
+        .with_instance_id("instance");
+    let builder = State::builder(Baseline::ALL_OF_THEM).with_server_fd(&server);
+    let listener = SecurityContextListener::new(builder, &context).unwrap();
+    assert_eq!(listener.context(), &context);
+    let (received, listen_fd) = committed.recv().unwrap();
+    assert_eq!(received, context);
+
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .with_security_context(&listener)
+        .build()
+        .unwrap();
+    let destructor = state.create_destructor();
+    uapi::accept4(
+        listen_fd.as_raw_fd(),
+        uapi::sockaddr_none_mut(),
+        c::SOCK_CLOEXEC,
+    )
+    .unwrap();
+
+    let path = listener.path.clone();
+    assert!(uapi::lstat(&*path).is_ok());
+    drop(listener);
+    drop(destructor);
+    drop(state);
+    assert!(uapi::lstat(&*path).is_err());
+}
+
+#[test]
+fn not_supported() {
+    install_logger();
+    let server = test_server(false);
+    let builder = State::builder(Baseline::ALL_OF_THEM).with_server_fd(&server);
+    let res = SecurityContextListener::new(builder, &SecurityContext::default());
+    assert!(res.is_err());
+}
+
+struct NewClient(Rc<RefCell<Vec<Option<SecurityContext>>>>);
+
+impl StateHandler for NewClient {
+    fn new_client(&mut self, client: &Rc<Client>) {
+        self.0.borrow_mut().push(client.security_context().cloned());
+    }
+}
+
+#[test]
+fn acceptor() {
+    install_logger();
+    let (server, _committed) = test_compositor();
+    let context = SecurityContext::default().with_app_id("app");
+    let builder = State::builder(Baseline::ALL_OF_THEM).with_server_fd(&server);
+    let listener = SecurityContextListener::new(builder, &context).unwrap();
+
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .build()
+        .unwrap();
+    let _destructor = state.create_destructor();
+    let contexts = Rc::new(RefCell::new(vec![]));
+    state.set_handler(NewClient(contexts.clone()));
+    let acceptor = Acceptor::with_security_context(&listener, 1000, true).unwrap();
+    assert_eq!(acceptor.security_context(), Some(&context));
+    state.add_acceptor(&acceptor).unwrap();
+    let plain = state.create_acceptor(1000).unwrap();
+    assert_eq!(plain.security_context(), None);
+
+    // Only clients accepted by the acceptor have the security context attached.
+    for display in [acceptor.display(), plain.display()] {
+        let _client = State::builder(Baseline::ALL_OF_THEM)
+            .with_server_display_name(display)
+            .build()
+            .unwrap();
+        state.dispatch_blocking().unwrap();
+    }
+    let (client, _fd) = state.connect().unwrap();
+    assert_eq!(client.security_context(), None);
+    assert_eq!(contexts.take(), [Some(context), None]);
+}
+
+#[test]
+fn acceptor_mismatch() {
+    install_logger();
+    let listener = || {
+        let (server, committed) = test_compositor();
+        let builder = State::builder(Baseline::ALL_OF_THEM).with_server_fd(&server);
+        let listener = SecurityContextListener::new(builder, &SecurityContext::default());
+        (listener.unwrap(), committed.recv().unwrap())
+    };
+    let (listener1, _committed1) = listener();
+    let (listener2, _committed2) = listener();
+
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .with_security_context(&listener1)
+        .build()
+        .unwrap();
+    let _destructor = state.create_destructor();
+    let acceptor = state.create_acceptor(1000).unwrap();
+    assert!(Rc::ptr_eq(
+        acceptor.security_context.as_ref().unwrap(),
+        &listener1,
+    ));
+    let acceptor = Acceptor::with_security_context(&listener2, 1000, true).unwrap();
+    assert!(state.add_acceptor(&acceptor).is_err());
+}
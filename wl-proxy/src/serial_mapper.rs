@@ -0,0 +1,680 @@
+//! A mapper for serials exchanged between client and server.
+//!
+//! Proxies that synthesize events carrying serials, for example, pointer enter events
+//! for surfaces that are managed by the proxy, have to invent serials. If these
+//! serials are sent to the client as is, they can collide with serials sent by the
+//! server and the client might send requests with serials that the server never
+//! issued.
+//!
+//! [`SerialMapper`] solves this by giving the client its own serial space. Serials
+//! sent by the server are translated via [`SerialMapper::map_server_serial`] and
+//! synthetic serials are allocated via [`SerialMapper::allocate`]. When the client
+//! sends a serial back, [`SerialMapper::map_client_serial`] returns the server serial
+//! or `None` if the serial was synthetic or is no longer known.
+//!
+//! The mapper only remembers a bounded number of serials. Once this number is exceeded,
+//! the oldest serials are forgotten.
+//!
+//! # Forwarding handlers
+//!
+//! The `track_*` functions install handlers that forward all messages and translate
+//! all serials of the respective objects and of the objects created from them. This
+//! covers wl_seat, wl_data_device_manager, wl_shell, xdg_wm_base, and
+//! zwp_text_input_manager_v3. Requests with serials that cannot be translated are
+//! dropped.
+//!
+//! Proxies that install their own handlers on some of these objects have to translate
+//! the serials of those objects themselves.
+
+#[cfg(feature = "protocol-xdg_shell")]
+use crate::protocols::xdg_shell::{
+    xdg_popup::{XdgPopup, XdgPopupHandler},
+    xdg_positioner::{XdgPositioner, XdgPositionerHandler},
+    xdg_surface::{XdgSurface, XdgSurfaceHandler},
+    xdg_toplevel::{XdgToplevel, XdgToplevelHandler, XdgToplevelResizeEdge},
+    xdg_wm_base::{XdgWmBase, XdgWmBaseHandler},
+};
+#[cfg(feature = "protocol-text_input_unstable_v3")]
+use crate::{
+    object::ObjectUtils,
+    protocols::text_input_unstable_v3::{
+        zwp_text_input_manager_v3::{ZwpTextInputManagerV3, ZwpTextInputManagerV3Handler},
+        zwp_text_input_v3::{ZwpTextInputV3, ZwpTextInputV3Action, ZwpTextInputV3Handler},
+    },
+};
+use {
+    crate::{
+        fixed::Fixed,
+        protocols::wayland::{
+            wl_data_device::{WlDataDevice, WlDataDeviceHandler},
+            wl_data_device_manager::{WlDataDeviceManager, WlDataDeviceManagerHandler},
+            wl_data_offer::{WlDataOffer, WlDataOfferHandler},
+            wl_data_source::WlDataSource,
+            wl_keyboard::{WlKeyboard, WlKeyboardHandler, WlKeyboardKeyState},
+            wl_pointer::{WlPointer, WlPointerButtonState, WlPointerHandler},
+            wl_seat::{WlSeat, WlSeatHandler},
+            wl_shell::{WlShell, WlShellHandler},
+            wl_shell_surface::{
+                WlShellSurface, WlShellSurfaceHandler, WlShellSurfaceResize,
+                WlShellSurfaceTransient,
+            },
+            wl_surface::WlSurface,
+            wl_touch::{WlTouch, WlTouchHandler},
+        },
+    },
+    std::{
+        cell::{Cell, RefCell},
+        collections::{HashMap, VecDeque},
+        rc::Rc,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A mapper for serials exchanged between client and server.
+///
+/// Usually there is one mapper per client.
+pub struct SerialMapper {
+    capacity: usize,
+    next: Cell<u32>,
+    /// The serials known to the mapper, from oldest to newest.
+    history: RefCell<VecDeque<(u32, Option<u32>)>>,
+    client_to_server: RefCell<HashMap<u32, Option<u32>>>,
+    server_to_client: RefCell<HashMap<u32, u32>>,
+}
+
+struct SeatForwarder(Rc<SerialMapper>);
+
+struct DataDeviceManagerForwarder(Rc<SerialMapper>);
+
+struct ShellForwarder(Rc<SerialMapper>);
+
+#[cfg(feature = "protocol-xdg_shell")]
+struct XdgWmBaseForwarder(Rc<SerialMapper>);
+
+#[cfg(feature = "protocol-text_input_unstable_v3")]
+struct TextInputManagerV3Forwarder(Rc<SerialMapper>);
+
+#[cfg(feature = "protocol-text_input_unstable_v3")]
+struct TextInputV3Forwarder {
+    mapper: Rc<SerialMapper>,
+    client_commits: u32,
+    server_commits: u32,
+    /// The number of client commits after each commit sent to the server, indexed by
+    /// the number of server commits.
+    history: VecDeque<(u32, u32)>,
+}
+
+/// The number of text input commits remembered for the translation of done events.
+#[cfg(feature = "protocol-text_input_unstable_v3")]
+const TEXT_INPUT_HISTORY: usize = 64;
+
+impl SerialMapper {
+    /// The number of serials remembered by mappers created via [`SerialMapper::new`].
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Creates a new mapper that remembers [`Self::DEFAULT_CAPACITY`] serials.
+    pub fn new() -> Rc<Self> {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates a new mapper that remembers `capacity` serials.
+    pub fn with_capacity(capacity: usize) -> Rc<Self> {
+        Rc::new(Self {
+            capacity: capacity.max(1),
+            next: Cell::new(1),
+            history: Default::default(),
+            client_to_server: Default::default(),
+            server_to_client: Default::default(),
+        })
+    }
+
+    /// Allocates a synthetic serial.
+    ///
+    /// The serial is not associated with any server serial. Requests carrying this
+    /// serial should not be forwarded to the server.
+    pub fn allocate(&self) -> u32 {
+        self.insert(None)
+    }
+
+    /// Translates a serial sent by the server.
+    ///
+    /// The returned serial should be sent to the client instead. If the same server
+    /// serial is translated multiple times, the same client serial is returned as long
+    /// as the serial is remembered.
+    pub fn map_server_serial(&self, server_serial: u32) -> u32 {
+        if let Some(&client_serial) = self.server_to_client.borrow().get(&server_serial) {
+            return client_serial;
+        }
+        self.insert(Some(server_serial))
+    }
+
+    /// Translates a serial sent by the client.
+    ///
+    /// Returns `None` if the serial was allocated via [`Self::allocate`], if the serial
+    /// was never sent to the client, or if the serial has been forgotten.
+    pub fn map_client_serial(&self, client_serial: u32) -> Option<u32> {
+        self.client_to_server
+            .borrow()
+            .get(&client_serial)
+            .copied()
+            .flatten()
+    }
+
+    fn insert(&self, server_serial: Option<u32>) -> u32 {
+        let client_serial = self.next.get();
+        self.next.set(client_serial.wrapping_add(1));
+        let history = &mut *self.history.borrow_mut();
+        let client_to_server = &mut *self.client_to_server.borrow_mut();
+        let server_to_client = &mut *self.server_to_client.borrow_mut();
+        while history.len() >= self.capacity {
+            let Some((client, server)) = history.pop_front() else {
+                break;
+            };
+            client_to_server.remove(&client);
+            if let Some(server) = server
+                && server_to_client.get(&server) == Some(&client)
+            {
+                server_to_client.remove(&server);
+            }
+        }
+        history.push_back((client_serial, server_serial));
+        client_to_server.insert(client_serial, server_serial);
+        if let Some(server_serial) = server_serial {
+            server_to_client.insert(server_serial, client_serial);
+        }
+        client_serial
+    }
+
+    fn map_request(&self, client_serial: u32, request: &str) -> Option<u32> {
+        let serial = self.map_client_serial(client_serial);
+        if serial.is_none() {
+            log::debug!("Dropping {request} request with unknown serial {client_serial}");
+        }
+        serial
+    }
+
+    /// Installs a handler on a wl_seat that translates the serials of all wl_pointer,
+    /// wl_keyboard, and wl_touch objects created from it.
+    ///
+    /// The handlers forward all messages.
+    pub fn track_seat(self: &Rc<Self>, seat: &Rc<WlSeat>) {
+        seat.set_handler(SeatForwarder(self.clone()));
+    }
+
+    /// Installs a handler on a wl_data_device_manager that translates the serials of all
+    /// wl_data_device and wl_data_offer objects created from it.
+    ///
+    /// The handlers forward all messages.
+    pub fn track_data_device_manager(self: &Rc<Self>, manager: &Rc<WlDataDeviceManager>) {
+        manager.set_handler(DataDeviceManagerForwarder(self.clone()));
+    }
+
+    /// Installs a handler on a wl_shell that translates the serials of all
+    /// wl_shell_surface objects created from it.
+    ///
+    /// The handlers forward all messages.
+    pub fn track_shell(self: &Rc<Self>, shell: &Rc<WlShell>) {
+        shell.set_handler(ShellForwarder(self.clone()));
+    }
+
+    /// Installs a handler on an xdg_wm_base that translates its serials and the serials
+    /// of all xdg_surface, xdg_toplevel, xdg_popup, and xdg_positioner objects created
+    /// from it.
+    ///
+    /// The handlers forward all messages.
+    #[cfg(feature = "protocol-xdg_shell")]
+    pub fn track_xdg_wm_base(self: &Rc<Self>, xdg_wm_base: &Rc<XdgWmBase>) {
+        xdg_wm_base.set_handler(XdgWmBaseForwarder(self.clone()));
+    }
+
+    /// Installs a handler on a zwp_text_input_manager_v3 that translates the serials of
+    /// all zwp_text_input_v3 objects created from it.
+    ///
+    /// The serials of zwp_text_input_v3.done events count the commit requests of the
+    /// object. Commits that the proxy sends on its own must be sent via
+    /// [`Self::commit_text_input`] so that the serials seen by the client remain
+    /// consistent.
+    ///
+    /// The handlers forward all messages.
+    #[cfg(feature = "protocol-text_input_unstable_v3")]
+    pub fn track_text_input_manager_v3(self: &Rc<Self>, manager: &Rc<ZwpTextInputManagerV3>) {
+        manager.set_handler(TextInputManagerV3Forwarder(self.clone()));
+    }
+
+    /// Sends a zwp_text_input_v3.commit request that did not originate from the client.
+    #[cfg(feature = "protocol-text_input_unstable_v3")]
+    pub fn commit_text_input(text_input: &Rc<ZwpTextInputV3>) {
+        if let Ok(mut handler) = text_input.try_get_handler_mut::<TextInputV3Forwarder>() {
+            handler.server_commit();
+        }
+        text_input.send_commit();
+    }
+}
+
+impl WlSeatHandler for SeatForwarder {
+    fn handle_get_pointer(&mut self, slf: &Rc<WlSeat>, id: &Rc<WlPointer>) {
+        id.set_handler(SeatForwarder(self.0.clone()));
+        slf.send_get_pointer(id);
+    }
+
+    fn handle_get_keyboard(&mut self, slf: &Rc<WlSeat>, id: &Rc<WlKeyboard>) {
+        id.set_handler(SeatForwarder(self.0.clone()));
+        slf.send_get_keyboard(id);
+    }
+
+    fn handle_get_touch(&mut self, slf: &Rc<WlSeat>, id: &Rc<WlTouch>) {
+        id.set_handler(SeatForwarder(self.0.clone()));
+        slf.send_get_touch(id);
+    }
+}
+
+impl WlPointerHandler for SeatForwarder {
+    fn handle_set_cursor(
+        &mut self,
+        slf: &Rc<WlPointer>,
+        serial: u32,
+        surface: Option<&Rc<WlSurface>>,
+        hotspot_x: i32,
+        hotspot_y: i32,
+    ) {
+        if let Some(serial) = self.0.map_request(serial, "wl_pointer.set_cursor") {
+            slf.send_set_cursor(serial, surface, hotspot_x, hotspot_y);
+        }
+    }
+
+    fn handle_enter(
+        &mut self,
+        slf: &Rc<WlPointer>,
+        serial: u32,
+        surface: &Rc<WlSurface>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_enter(serial, surface, surface_x, surface_y);
+    }
+
+    fn handle_leave(&mut self, slf: &Rc<WlPointer>, serial: u32, surface: &Rc<WlSurface>) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_leave(serial, surface);
+    }
+
+    fn handle_button(
+        &mut self,
+        slf: &Rc<WlPointer>,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_button(serial, time, button, state);
+    }
+}
+
+impl WlKeyboardHandler for SeatForwarder {
+    fn handle_enter(
+        &mut self,
+        slf: &Rc<WlKeyboard>,
+        serial: u32,
+        surface: &Rc<WlSurface>,
+        keys: &[u8],
+    ) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_enter(serial, surface, keys);
+    }
+
+    fn handle_leave(&mut self, slf: &Rc<WlKeyboard>, serial: u32, surface: &Rc<WlSurface>) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_leave(serial, surface);
+    }
+
+    fn handle_key(
+        &mut self,
+        slf: &Rc<WlKeyboard>,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_key(serial, time, key, state);
+    }
+
+    fn handle_modifiers(
+        &mut self,
+        slf: &Rc<WlKeyboard>,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_modifiers(serial, mods_depressed, mods_latched, mods_locked, group);
+    }
+}
+
+impl WlTouchHandler for SeatForwarder {
+    fn handle_down(
+        &mut self,
+        slf: &Rc<WlTouch>,
+        serial: u32,
+        time: u32,
+        surface: &Rc<WlSurface>,
+        id: i32,
+        x: Fixed,
+        y: Fixed,
+    ) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_down(serial, time, surface, id, x, y);
+    }
+
+    fn handle_up(&mut self, slf: &Rc<WlTouch>, serial: u32, time: u32, id: i32) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_up(serial, time, id);
+    }
+}
+
+impl WlDataDeviceManagerHandler for DataDeviceManagerForwarder {
+    fn handle_get_data_device(
+        &mut self,
+        slf: &Rc<WlDataDeviceManager>,
+        id: &Rc<WlDataDevice>,
+        seat: &Rc<WlSeat>,
+    ) {
+        id.set_handler(DataDeviceManagerForwarder(self.0.clone()));
+        slf.send_get_data_device(id, seat);
+    }
+}
+
+impl WlDataDeviceHandler for DataDeviceManagerForwarder {
+    fn handle_start_drag(
+        &mut self,
+        slf: &Rc<WlDataDevice>,
+        source: Option<&Rc<WlDataSource>>,
+        origin: &Rc<WlSurface>,
+        icon: Option<&Rc<WlSurface>>,
+        serial: u32,
+    ) {
+        if let Some(serial) = self.0.map_request(serial, "wl_data_device.start_drag") {
+            slf.send_start_drag(source, origin, icon, serial);
+        }
+    }
+
+    fn handle_set_selection(
+        &mut self,
+        slf: &Rc<WlDataDevice>,
+        source: Option<&Rc<WlDataSource>>,
+        serial: u32,
+    ) {
+        if let Some(serial) = self.0.map_request(serial, "wl_data_device.set_selection") {
+            slf.send_set_selection(source, serial);
+        }
+    }
+
+    fn handle_data_offer(&mut self, slf: &Rc<WlDataDevice>, id: &Rc<WlDataOffer>) {
+        id.set_handler(DataDeviceManagerForwarder(self.0.clone()));
+        slf.send_data_offer(id);
+    }
+
+    fn handle_enter(
+        &mut self,
+        slf: &Rc<WlDataDevice>,
+        serial: u32,
+        surface: &Rc<WlSurface>,
+        x: Fixed,
+        y: Fixed,
+        id: Option<&Rc<WlDataOffer>>,
+    ) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_enter(serial, surface, x, y, id);
+    }
+}
+
+impl WlDataOfferHandler for DataDeviceManagerForwarder {
+    fn handle_accept(&mut self, slf: &Rc<WlDataOffer>, serial: u32, mime_type: Option<&str>) {
+        if let Some(serial) = self.0.map_request(serial, "wl_data_offer.accept") {
+            slf.send_accept(serial, mime_type);
+        }
+    }
+}
+
+impl WlShellHandler for ShellForwarder {
+    fn handle_get_shell_surface(
+        &mut self,
+        slf: &Rc<WlShell>,
+        id: &Rc<WlShellSurface>,
+        surface: &Rc<WlSurface>,
+    ) {
+        id.set_handler(ShellForwarder(self.0.clone()));
+        slf.send_get_shell_surface(id, surface);
+    }
+}
+
+impl WlShellSurfaceHandler for ShellForwarder {
+    fn handle_pong(&mut self, slf: &Rc<WlShellSurface>, serial: u32) {
+        if let Some(serial) = self.0.map_request(serial, "wl_shell_surface.pong") {
+            slf.send_pong(serial);
+        }
+    }
+
+    fn handle_move(&mut self, slf: &Rc<WlShellSurface>, seat: &Rc<WlSeat>, serial: u32) {
+        if let Some(serial) = self.0.map_request(serial, "wl_shell_surface.move") {
+            slf.send_move(seat, serial);
+        }
+    }
+
+    fn handle_resize(
+        &mut self,
+        slf: &Rc<WlShellSurface>,
+        seat: &Rc<WlSeat>,
+        serial: u32,
+        edges: WlShellSurfaceResize,
+    ) {
+        if let Some(serial) = self.0.map_request(serial, "wl_shell_surface.resize") {
+            slf.send_resize(seat, serial, edges);
+        }
+    }
+
+    fn handle_set_popup(
+        &mut self,
+        slf: &Rc<WlShellSurface>,
+        seat: &Rc<WlSeat>,
+        serial: u32,
+        parent: &Rc<WlSurface>,
+        x: i32,
+        y: i32,
+        flags: WlShellSurfaceTransient,
+    ) {
+        if let Some(serial) = self.0.map_request(serial, "wl_shell_surface.set_popup") {
+            slf.send_set_popup(seat, serial, parent, x, y, flags);
+        }
+    }
+
+    fn handle_ping(&mut self, slf: &Rc<WlShellSurface>, serial: u32) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_ping(serial);
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+impl XdgWmBaseHandler for XdgWmBaseForwarder {
+    fn handle_create_positioner(&mut self, slf: &Rc<XdgWmBase>, id: &Rc<XdgPositioner>) {
+        id.set_handler(XdgWmBaseForwarder(self.0.clone()));
+        slf.send_create_positioner(id);
+    }
+
+    fn handle_get_xdg_surface(
+        &mut self,
+        slf: &Rc<XdgWmBase>,
+        id: &Rc<XdgSurface>,
+        surface: &Rc<WlSurface>,
+    ) {
+        id.set_handler(XdgWmBaseForwarder(self.0.clone()));
+        slf.send_get_xdg_surface(id, surface);
+    }
+
+    fn handle_pong(&mut self, slf: &Rc<XdgWmBase>, serial: u32) {
+        if let Some(serial) = self.0.map_request(serial, "xdg_wm_base.pong") {
+            slf.send_pong(serial);
+        }
+    }
+
+    fn handle_ping(&mut self, slf: &Rc<XdgWmBase>, serial: u32) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_ping(serial);
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+impl XdgPositionerHandler for XdgWmBaseForwarder {
+    fn handle_set_parent_configure(&mut self, slf: &Rc<XdgPositioner>, serial: u32) {
+        if let Some(serial) = self
+            .0
+            .map_request(serial, "xdg_positioner.set_parent_configure")
+        {
+            slf.send_set_parent_configure(serial);
+        }
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+impl XdgSurfaceHandler for XdgWmBaseForwarder {
+    fn handle_get_toplevel(&mut self, slf: &Rc<XdgSurface>, id: &Rc<XdgToplevel>) {
+        id.set_handler(XdgWmBaseForwarder(self.0.clone()));
+        slf.send_get_toplevel(id);
+    }
+
+    fn handle_get_popup(
+        &mut self,
+        slf: &Rc<XdgSurface>,
+        id: &Rc<XdgPopup>,
+        parent: Option<&Rc<XdgSurface>>,
+        positioner: &Rc<XdgPositioner>,
+    ) {
+        id.set_handler(XdgWmBaseForwarder(self.0.clone()));
+        slf.send_get_popup(id, parent, positioner);
+    }
+
+    fn handle_ack_configure(&mut self, slf: &Rc<XdgSurface>, serial: u32) {
+        if let Some(serial) = self.0.map_request(serial, "xdg_surface.ack_configure") {
+            slf.send_ack_configure(serial);
+        }
+    }
+
+    fn handle_configure(&mut self, slf: &Rc<XdgSurface>, serial: u32) {
+        let serial = self.0.map_server_serial(serial);
+        slf.send_configure(serial);
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+impl XdgToplevelHandler for XdgWmBaseForwarder {
+    fn handle_show_window_menu(
+        &mut self,
+        slf: &Rc<XdgToplevel>,
+        seat: &Rc<WlSeat>,
+        serial: u32,
+        x: i32,
+        y: i32,
+    ) {
+        if let Some(serial) = self.0.map_request(serial, "xdg_toplevel.show_window_menu") {
+            slf.send_show_window_menu(seat, serial, x, y);
+        }
+    }
+
+    fn handle_move(&mut self, slf: &Rc<XdgToplevel>, seat: &Rc<WlSeat>, serial: u32) {
+        if let Some(serial) = self.0.map_request(serial, "xdg_toplevel.move") {
+            slf.send_move(seat, serial);
+        }
+    }
+
+    fn handle_resize(
+        &mut self,
+        slf: &Rc<XdgToplevel>,
+        seat: &Rc<WlSeat>,
+        serial: u32,
+        edges: XdgToplevelResizeEdge,
+    ) {
+        if let Some(serial) = self.0.map_request(serial, "xdg_toplevel.resize") {
+            slf.send_resize(seat, serial, edges);
+        }
+    }
+}
+
+#[cfg(feature = "protocol-xdg_shell")]
+impl XdgPopupHandler for XdgWmBaseForwarder {
+    fn handle_grab(&mut self, slf: &Rc<XdgPopup>, seat: &Rc<WlSeat>, serial: u32) {
+        if let Some(serial) = self.0.map_request(serial, "xdg_popup.grab") {
+            slf.send_grab(seat, serial);
+        }
+    }
+}
+
+#[cfg(feature = "protocol-text_input_unstable_v3")]
+impl ZwpTextInputManagerV3Handler for TextInputManagerV3Forwarder {
+    fn handle_get_text_input(
+        &mut self,
+        slf: &Rc<ZwpTextInputManagerV3>,
+        id: &Rc<ZwpTextInputV3>,
+        seat: &Rc<WlSeat>,
+    ) {
+        id.set_handler(TextInputV3Forwarder {
+            mapper: self.0.clone(),
+            client_commits: 0,
+            server_commits: 0,
+            history: Default::default(),
+        });
+        slf.send_get_text_input(id, seat);
+    }
+}
+
+#[cfg(feature = "protocol-text_input_unstable_v3")]
+impl TextInputV3Forwarder {
+    fn server_commit(&mut self) {
+        self.server_commits = self.server_commits.wrapping_add(1);
+        if self.history.len() >= TEXT_INPUT_HISTORY {
+            self.history.pop_front();
+        }
+        self.history
+            .push_back((self.server_commits, self.client_commits));
+    }
+}
+
+#[cfg(feature = "protocol-text_input_unstable_v3")]
+impl ZwpTextInputV3Handler for TextInputV3Forwarder {
+    fn handle_commit(&mut self, slf: &Rc<ZwpTextInputV3>) {
+        self.client_commits = self.client_commits.wrapping_add(1);
+        self.server_commit();
+        slf.send_commit();
+    }
+
+    fn handle_done(&mut self, slf: &Rc<ZwpTextInputV3>, serial: u32) {
+        let client_serial = match self.history.iter().rposition(|&(s, _)| s == serial) {
+            Some(idx) => {
+                let client_serial = self.history[idx].1;
+                self.history.drain(..idx);
+                client_serial
+            }
+            None if serial == 0 => 0,
+            None => {
+                let offset = self.server_commits.wrapping_sub(self.client_commits);
+                serial.wrapping_sub(offset)
+            }
+        };
+        slf.send_done(client_serial);
+    }
+
+    fn handle_action(
+        &mut self,
+        slf: &Rc<ZwpTextInputV3>,
+        action: ZwpTextInputV3Action,
+        serial: u32,
+    ) {
+        let serial = self.mapper.map_server_serial(serial);
+        slf.send_action(action, serial);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::serial_mapper::SerialMapper;
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+use {
+    crate::{
+        baseline::Baseline,
+        fixed::Fixed,
+        headless::Headless,
+        object::{ConcreteObject, Object, ObjectRcUtils},
+        protocols::{
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_compositor::WlCompositor,
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_keyboard::{WlKeyboard, WlKeyboardHandler},
+                wl_pointer::{WlPointer, WlPointerHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+                wl_seat::WlSeat,
+                wl_surface::WlSurface,
+            },
+            xdg_shell::{
+                xdg_surface::{XdgSurface, XdgSurfaceHandler},
+                xdg_wm_base::XdgWmBase,
+            },
+        },
+        state::State,
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+#[test]
+fn mapping() {
+    let mapper = SerialMapper::new();
+    assert_eq!(mapper.map_server_serial(100), 1);
+    assert_eq!(mapper.allocate(), 2);
+    assert_eq!(mapper.map_server_serial(1), 3);
+    assert_eq!(mapper.map_server_serial(100), 1);
+    assert_eq!(mapper.map_client_serial(1), Some(100));
+    assert_eq!(mapper.map_client_serial(2), None);
+    assert_eq!(mapper.map_client_serial(3), Some(1));
+    assert_eq!(mapper.map_client_serial(4), None);
+}
+
+#[test]
+fn eviction() {
+    let mapper = SerialMapper::with_capacity(2);
+    assert_eq!(mapper.map_server_serial(10), 1);
+    assert_eq!(mapper.map_server_serial(11), 2);
+    assert_eq!(mapper.allocate(), 3);
+    assert_eq!(mapper.map_client_serial(1), None);
+    assert_eq!(mapper.map_client_serial(2), Some(11));
+    assert_eq!(mapper.map_server_serial(10), 4);
+    assert_eq!(mapper.map_client_serial(2), None);
+    assert_eq!(mapper.map_client_serial(4), Some(10));
+}
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+#[derive(Clone)]
+struct Proxy(Rc<SerialMapper>);
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+impl WlDisplayHandler for Proxy {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(self.clone());
+        slf.send_get_registry(registry);
+    }
+}
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+impl WlRegistryHandler for Proxy {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        if let Some(seat) = id.try_downcast::<WlSeat>() {
+            self.0.track_seat(&seat);
+        }
+        if let Some(xdg_wm_base) = id.try_downcast::<XdgWmBase>() {
+            self.0.track_xdg_wm_base(&xdg_wm_base);
+        }
+        slf.send_bind(name, id);
+    }
+}
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+struct Done(Rc<Cell<bool>>);
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+/// Records the serials received by the client.
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+#[derive(Clone, Default)]
+struct Client(Rc<RefCell<Vec<(&'static str, u32)>>>);
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+impl XdgSurfaceHandler for Client {
+    fn handle_configure(&mut self, _slf: &Rc<XdgSurface>, serial: u32) {
+        self.0.borrow_mut().push(("configure", serial));
+    }
+}
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+impl WlKeyboardHandler for Client {
+    fn handle_enter(
+        &mut self,
+        _slf: &Rc<WlKeyboard>,
+        serial: u32,
+        _surface: &Rc<WlSurface>,
+        _keys: &[u8],
+    ) {
+        self.0.borrow_mut().push(("keyboard.enter", serial));
+    }
+
+    fn handle_modifiers(
+        &mut self,
+        _slf: &Rc<WlKeyboard>,
+        serial: u32,
+        _mods_depressed: u32,
+        _mods_latched: u32,
+        _mods_locked: u32,
+        _group: u32,
+    ) {
+        self.0.borrow_mut().push(("keyboard.modifiers", serial));
+    }
+}
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+impl WlPointerHandler for Client {
+    fn handle_enter(
+        &mut self,
+        _slf: &Rc<WlPointer>,
+        serial: u32,
+        _surface: &Rc<WlSurface>,
+        _surface_x: Fixed,
+        _surface_y: Fixed,
+    ) {
+        self.0.borrow_mut().push(("pointer.enter", serial));
+    }
+}
+
+#[cfg(all(feature = "headless", feature = "protocol-xdg_shell"))]
+#[test]
+fn forwarding() {
+    let headless = Headless::new().unwrap();
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let (proxy_client, client_fd) = proxy.connect().unwrap();
+    let mapper = SerialMapper::new();
+    proxy_client.display.set_handler(Proxy(mapper.clone()));
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .build()
+        .unwrap();
+    let _destructors = [proxy.create_destructor(), client.create_destructor()];
+    let roundtrip = || {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([&client, &proxy, headless.state()]).unwrap();
+        }
+    };
+    let registry = client.display().new_send_get_registry();
+    let compositor = client.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+    registry.send_bind(1, compositor.clone());
+    let wm_base = client.create_object::<XdgWmBase>(XdgWmBase::XML_VERSION);
+    registry.send_bind(4, wm_base.clone());
+    let seat = client.create_object::<WlSeat>(WlSeat::XML_VERSION);
+    registry.send_bind(5, seat.clone());
+    let events = Client::default();
+    seat.new_send_get_keyboard().set_handler(events.clone());
+    seat.new_send_get_pointer().set_handler(events.clone());
+
+    // Pretend that the proxy has already synthesized some events.
+    let synthetic = mapper.allocate();
+    mapper.allocate();
+
+    let surface = compositor.new_send_create_surface();
+    let xdg_surface = wm_base.new_send_get_xdg_surface(&surface);
+    xdg_surface.set_handler(events.clone());
+    let _toplevel = xdg_surface.new_send_get_toplevel();
+    surface.send_commit();
+    roundtrip();
+    let headless_toplevel = &headless.toplevels()[0];
+
+    let server_serial = headless_toplevel.configure(100, 200).unwrap();
+    roundtrip();
+    let (name, serial) = *events.0.borrow().last().unwrap();
+    assert_eq!(name, "configure");
+    assert_ne!(serial, server_serial);
+    assert_eq!(mapper.map_client_serial(serial), Some(server_serial));
+    xdg_surface.send_ack_configure(serial);
+    surface.send_commit();
+    roundtrip();
+    assert_eq!(headless_toplevel.acked_serial(), Some(server_serial));
+
+    // Acks of synthetic serials are not forwarded. The compositor would otherwise
+    // post an error.
+    xdg_surface.send_ack_configure(synthetic);
+    surface.send_commit();
+    roundtrip();
+    assert_eq!(headless_toplevel.acked_serial(), Some(server_serial));
+
+    let headless_surface = &headless.surfaces()[0];
+    events.0.borrow_mut().clear();
+    headless.keyboard_enter(headless_surface);
+    headless.pointer_enter(headless_surface, 1.0, 1.0);
+    roundtrip();
+    let events = events.0.borrow();
+    let names: Vec<_> = events.iter().map(|e| e.0).collect();
+    assert_eq!(
+        names,
+        ["keyboard.enter", "keyboard.modifiers", "pointer.enter"]
+    );
+    assert_eq!(events[0].1, events[1].1);
+    assert_ne!(events[0].1, events[2].1);
+    for (_, serial) in &*events {
+        assert!(mapper.map_client_serial(*serial).is_some());
+    }
+}
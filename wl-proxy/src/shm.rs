@@ -0,0 +1,542 @@
+//! Access to the contents of wl_shm buffers.
+//!
+//! This module requires the `shm` feature.
+//!
+//! Proxies that want to inspect the contents of shm buffers created by clients can use
+//! [`track_shm`] when a client binds wl_shm. This installs handlers on the wl_shm
+//! object and on all pools and buffers created via it. These handlers forward all
+//! messages and record the file descriptors, sizes, and buffer layouts. Afterwards,
+//! [`ShmBuffer::get`] returns the layout of a wl_buffer and
+//! [`ShmBuffer::access`] provides access to its contents, for example, when a surface
+//! that the buffer is attached to is committed.
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use wl_proxy::{
+//! #     protocols::wayland::{wl_buffer::WlBuffer, wl_surface::{WlSurface, WlSurfaceHandler}},
+//! #     shm::ShmBuffer,
+//! # };
+//! struct Surface {
+//!     pending_buffer: Option<Rc<WlBuffer>>,
+//! }
+//!
+//! impl WlSurfaceHandler for Surface {
+//!     fn handle_attach(&mut self, slf: &Rc<WlSurface>, buffer: Option<&Rc<WlBuffer>>, x: i32, y: i32) {
+//!         self.pending_buffer = buffer.cloned();
+//!         slf.send_attach(buffer, x, y);
+//!     }
+//!
+//!     fn handle_commit(&mut self, slf: &Rc<WlSurface>) {
+//!         if let Some(buffer) = self.pending_buffer.take()
+//!             && let Some(shm_buffer) = ShmBuffer::get(&buffer)
+//!         {
+//!             let pixel = shm_buffer.access(|view| view.pixel(0, 0));
+//!             eprintln!("top-left pixel: {:?}", pixel);
+//!         }
+//!         slf.send_commit();
+//!     }
+//! }
+//! ```
+//!
+//! Accessing the contents is protected against clients that truncate the file of the
+//! pool. In this case, [`ShmBuffer::access`] returns an error instead of the process
+//! crashing with SIGBUS. To achieve this, this module installs a process-wide SIGBUS
+//! handler the first time a buffer is accessed. Signals that are not caused by such
+//! accesses are handled by the previously installed handler.
+//!
+//! Proxies that want to display their own contents can allocate buffers with
+//! [`OwnedShmBuffer`].
+
+use {
+    crate::{
+        object::{ObjectCoreApi, ObjectUtils},
+        protocols::wayland::{
+            wl_buffer::{WlBuffer, WlBufferHandler},
+            wl_shm::{WlShm, WlShmFormat, WlShmHandler},
+            wl_shm_pool::{WlShmPool, WlShmPoolHandler},
+        },
+    },
+    std::{
+        cell::{Cell, RefCell},
+        io,
+        os::fd::{AsRawFd, OwnedFd},
+        ptr::{self, NonNull},
+        rc::Rc,
+        slice,
+    },
+    thiserror::Error,
+    uapi::c,
+};
+
+mod sigbus;
+#[cfg(test)]
+mod tests;
+
+/// A wl_shm_pool whose file descriptor is known to the proxy.
+pub struct ShmPool {
+    fd: Rc<OwnedFd>,
+    size: Cell<i32>,
+    mapping: RefCell<Option<Rc<Mapping>>>,
+}
+
+/// A wl_buffer created from a [`ShmPool`].
+pub struct ShmBuffer {
+    pool: Rc<ShmPool>,
+    offset: i32,
+    width: i32,
+    height: i32,
+    stride: i32,
+    format: WlShmFormat,
+}
+
+/// A view of the contents of a [`ShmBuffer`].
+///
+/// This type is passed to the callback of [`ShmBuffer::access`].
+pub struct ShmBufferView<'a> {
+    buffer: &'a ShmBuffer,
+    data: *const u8,
+    len: usize,
+}
+
+/// A buffer allocated by the proxy.
+///
+/// The buffer is backed by a sealed memfd that is mapped into the proxy. The contents
+/// can be modified via [`Self::data_mut`].
+///
+/// Dropping this object unmaps the memory but does not destroy the wl_buffer. The
+/// buffer can still be used by the compositor until it is destroyed via
+/// [`WlBuffer::send_destroy`].
+pub struct OwnedShmBuffer {
+    wl_buffer: Rc<WlBuffer>,
+    mapping: Mapping,
+    width: i32,
+    height: i32,
+    stride: i32,
+    format: WlShmFormat,
+}
+
+struct Mapping {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+struct ShmTracker;
+
+struct PoolTracker(Rc<ShmPool>);
+
+struct BufferTracker(Rc<ShmBuffer>);
+
+/// An error emitted by the functions in this module.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct ShmError(#[from] ShmErrorKind);
+
+#[derive(Debug, Error)]
+enum ShmErrorKind {
+    #[error("the buffer is not contained in its pool")]
+    OutOfBounds,
+    #[error("the buffer size is invalid")]
+    InvalidSize,
+    #[error("the format {0:?} is not supported")]
+    UnsupportedFormat(WlShmFormat),
+    #[error("could not map the pool")]
+    Map(#[source] io::Error),
+    #[error("the file of the pool is smaller than the pool")]
+    Truncated,
+    #[error("could not create a memfd")]
+    CreateMemfd(#[source] io::Error),
+    #[error("could not resize the memfd")]
+    ResizeMemfd(#[source] io::Error),
+    #[error("could not seal the memfd")]
+    SealMemfd(#[source] io::Error),
+}
+
+/// Starts tracking the pools and buffers created via a wl_shm object.
+///
+/// This replaces the handler of the object. The new handler forwards all messages.
+pub fn track_shm(shm: &Rc<WlShm>) {
+    shm.set_handler(ShmTracker);
+}
+
+/// Starts tracking the buffers created via a wl_shm_pool object.
+///
+/// This can be used by proxies that implement their own wl_shm handler. This function
+/// should be called from [`WlShmHandler::handle_create_pool`]. It replaces the handler
+/// of the pool. The new handler forwards all messages.
+pub fn track_pool(pool: &Rc<WlShmPool>, fd: &Rc<OwnedFd>, size: i32) -> Rc<ShmPool> {
+    let shm_pool = Rc::new(ShmPool {
+        fd: fd.clone(),
+        size: Cell::new(size),
+        mapping: Default::default(),
+    });
+    pool.set_handler(PoolTracker(shm_pool.clone()));
+    shm_pool
+}
+
+impl ShmPool {
+    /// Returns the file descriptor of the pool.
+    pub fn fd(&self) -> &Rc<OwnedFd> {
+        &self.fd
+    }
+
+    /// Returns the current size of the pool.
+    pub fn size(&self) -> i32 {
+        self.size.get()
+    }
+
+    fn mapping(&self) -> Result<Rc<Mapping>, ShmError> {
+        let size = self.size.get().max(0) as usize;
+        let mapping = &mut *self.mapping.borrow_mut();
+        if let Some(mapping) = mapping
+            && mapping.len == size
+        {
+            return Ok(mapping.clone());
+        }
+        let new = Rc::new(Mapping::new(&self.fd, size, false).map_err(ShmErrorKind::Map)?);
+        *mapping = Some(new.clone());
+        Ok(new)
+    }
+}
+
+impl ShmBuffer {
+    /// Returns the shm buffer of a wl_buffer.
+    ///
+    /// This returns `None` if the wl_buffer was not created from a pool tracked by this
+    /// module or if its handler has been replaced.
+    pub fn get(buffer: &WlBuffer) -> Option<Rc<ShmBuffer>> {
+        let tracker = buffer.try_get_handler_ref::<BufferTracker>().ok()?;
+        Some(tracker.0.clone())
+    }
+
+    /// Returns the pool that the buffer was created from.
+    pub fn pool(&self) -> &Rc<ShmPool> {
+        &self.pool
+    }
+
+    /// Returns the offset of the buffer within its pool.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// Returns the width of the buffer in pixels.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Returns the height of the buffer in pixels.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns the stride of the buffer in bytes.
+    pub fn stride(&self) -> i32 {
+        self.stride
+    }
+
+    /// Returns the format of the buffer.
+    pub fn format(&self) -> WlShmFormat {
+        self.format
+    }
+
+    /// Provides access to the contents of the buffer.
+    ///
+    /// The contents can be modified by the client at any time. Callers should copy the
+    /// parts they need instead of reading the same data multiple times.
+    ///
+    /// If the client truncates the file of the pool while `f` is running, the reads in
+    /// `f` return zeros and this function returns an error.
+    pub fn access<T>(&self, f: impl FnOnce(&ShmBufferView<'_>) -> T) -> Result<T, ShmError> {
+        if self.offset < 0 || self.height < 0 || self.stride < 0 {
+            return Err(ShmErrorKind::OutOfBounds.into());
+        }
+        let offset = self.offset as usize;
+        let len = self.stride as usize * self.height as usize;
+        if offset + len > self.pool.size.get().max(0) as usize {
+            return Err(ShmErrorKind::OutOfBounds.into());
+        }
+        let mapping = self.pool.mapping()?;
+        let data = unsafe { mapping.ptr.as_ptr().add(offset) };
+        let view = ShmBufferView {
+            buffer: self,
+            data,
+            len,
+        };
+        let (res, faulted) = sigbus::protect(mapping.ptr.as_ptr(), mapping.len, || f(&view));
+        if faulted {
+            self.pool.mapping.take();
+            return Err(ShmErrorKind::Truncated.into());
+        }
+        Ok(res)
+    }
+}
+
+impl ShmBufferView<'_> {
+    /// Returns the buffer.
+    pub fn buffer(&self) -> &ShmBuffer {
+        self.buffer
+    }
+
+    /// Copies bytes starting at `offset` into `dst`.
+    ///
+    /// Returns `false` if the range is not contained in the buffer.
+    pub fn read(&self, offset: usize, dst: &mut [u8]) -> bool {
+        if offset > self.len || dst.len() > self.len - offset {
+            return false;
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(self.data.add(offset), dst.as_mut_ptr(), dst.len());
+        }
+        true
+    }
+
+    /// Returns a copy of the contents of the buffer.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut data = vec![0; self.len];
+        self.read(0, &mut data);
+        data
+    }
+
+    /// Returns the pixel at the given position.
+    ///
+    /// The pixel is read as a native-endian 32-bit integer as described by the
+    /// `ARGB8888` and `XRGB8888` formats.
+    pub fn pixel(&self, x: i32, y: i32) -> Option<u32> {
+        let buffer = self.buffer;
+        if x < 0 || y < 0 || x >= buffer.width || y >= buffer.height {
+            return None;
+        }
+        if (x as usize + 1) * 4 > buffer.stride as usize {
+            return None;
+        }
+        let mut bytes = [0; 4];
+        let offset = y as usize * buffer.stride as usize + x as usize * 4;
+        self.read(offset, &mut bytes)
+            .then_some(u32::from_ne_bytes(bytes))
+    }
+}
+
+impl OwnedShmBuffer {
+    /// Allocates a buffer and creates a wl_buffer for it.
+    ///
+    /// The contents of the buffer are initially zero. Events sent to the wl_buffer are
+    /// not forwarded to the client.
+    ///
+    /// Only formats with a single plane and a whole number of bytes per pixel are
+    /// supported. The stride must be large enough to hold `width` pixels.
+    pub fn new(
+        shm: &WlShm,
+        width: i32,
+        height: i32,
+        stride: i32,
+        format: WlShmFormat,
+    ) -> Result<Self, ShmError> {
+        let Some(bpp) = bytes_per_pixel(format) else {
+            return Err(ShmErrorKind::UnsupportedFormat(format).into());
+        };
+        if width <= 0 || height <= 0 || width.checked_mul(bpp).is_none_or(|w| stride < w) {
+            return Err(ShmErrorKind::InvalidSize.into());
+        }
+        let size = stride as usize * height as usize;
+        if size > i32::MAX as usize {
+            return Err(ShmErrorKind::InvalidSize.into());
+        }
+        let fd = uapi::memfd_create("wl-proxy-shm", c::MFD_CLOEXEC | c::MFD_ALLOW_SEALING)
+            .map_err(|e| ShmErrorKind::CreateMemfd(e.into()))?;
+        uapi::ftruncate(fd.raw(), size as c::off_t)
+            .map_err(|e| ShmErrorKind::ResizeMemfd(e.into()))?;
+        let seals = c::F_SEAL_SHRINK | c::F_SEAL_GROW | c::F_SEAL_SEAL;
+        uapi::fcntl_add_seals(fd.raw(), seals).map_err(|e| ShmErrorKind::SealMemfd(e.into()))?;
+        let fd: OwnedFd = fd.into();
+        let mapping = Mapping::new(&fd, size, true).map_err(ShmErrorKind::Map)?;
+        let pool = shm.new_send_create_pool(&Rc::new(fd), size as i32);
+        let wl_buffer = pool.new_send_create_buffer(0, width, height, stride, format);
+        wl_buffer.set_forward_to_client(false);
+        pool.send_destroy();
+        Ok(Self {
+            wl_buffer,
+            mapping,
+            width,
+            height,
+            stride,
+            format,
+        })
+    }
+
+    /// Returns the wl_buffer.
+    pub fn wl_buffer(&self) -> &Rc<WlBuffer> {
+        &self.wl_buffer
+    }
+
+    /// Returns the width of the buffer in pixels.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Returns the height of the buffer in pixels.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns the stride of the buffer in bytes.
+    pub fn stride(&self) -> i32 {
+        self.stride
+    }
+
+    /// Returns the format of the buffer.
+    pub fn format(&self) -> WlShmFormat {
+        self.format
+    }
+
+    /// Returns the contents of the buffer.
+    pub fn data(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.mapping.ptr.as_ptr(), self.mapping.len) }
+    }
+
+    /// Returns a mutable reference to the contents of the buffer.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.mapping.ptr.as_ptr(), self.mapping.len) }
+    }
+}
+
+impl Mapping {
+    fn new(fd: &OwnedFd, len: usize, writable: bool) -> io::Result<Self> {
+        if len == 0 {
+            return Ok(Self {
+                ptr: NonNull::dangling(),
+                len,
+            });
+        }
+        let prot = match writable {
+            true => c::PROT_READ | c::PROT_WRITE,
+            false => c::PROT_READ,
+        };
+        let ptr = unsafe { c::mmap(ptr::null_mut(), len, prot, c::MAP_SHARED, fd.as_raw_fd(), 0) };
+        if ptr == c::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            ptr: NonNull::new(ptr.cast()).unwrap(),
+            len,
+        })
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe {
+                c::munmap(self.ptr.as_ptr().cast(), self.len);
+            }
+        }
+    }
+}
+
+impl WlShmHandler for ShmTracker {
+    fn handle_create_pool(
+        &mut self,
+        slf: &Rc<WlShm>,
+        id: &Rc<WlShmPool>,
+        fd: &Rc<OwnedFd>,
+        size: i32,
+    ) {
+        track_pool(id, fd, size);
+        slf.send_create_pool(id, fd, size);
+    }
+}
+
+impl WlShmPoolHandler for PoolTracker {
+    fn handle_create_buffer(
+        &mut self,
+        slf: &Rc<WlShmPool>,
+        id: &Rc<WlBuffer>,
+        offset: i32,
+        width: i32,
+        height: i32,
+        stride: i32,
+        format: WlShmFormat,
+    ) {
+        id.set_handler(BufferTracker(Rc::new(ShmBuffer {
+            pool: self.0.clone(),
+            offset,
+            width,
+            height,
+            stride,
+            format,
+        })));
+        slf.send_create_buffer(id, offset, width, height, stride, format);
+    }
+
+    fn handle_resize(&mut self, slf: &Rc<WlShmPool>, size: i32) {
+        if size > self.0.size.get() {
+            self.0.size.set(size);
+        }
+        slf.send_resize(size);
+    }
+}
+
+impl WlBufferHandler for BufferTracker {}
+
+/// Returns the number of bytes per pixel of single-plane formats.
+fn bytes_per_pixel(format: WlShmFormat) -> Option<i32> {
+    use WlShmFormat as F;
+    let bpp = match format {
+        F::C8 | F::RGB332 | F::BGR233 | F::R8 => 1,
+        F::XRGB4444
+        | F::XBGR4444
+        | F::RGBX4444
+        | F::BGRX4444
+        | F::ARGB4444
+        | F::ABGR4444
+        | F::RGBA4444
+        | F::BGRA4444
+        | F::XRGB1555
+        | F::XBGR1555
+        | F::RGBX5551
+        | F::BGRX5551
+        | F::ARGB1555
+        | F::ABGR1555
+        | F::RGBA5551
+        | F::BGRA5551
+        | F::RGB565
+        | F::BGR565
+        | F::R16
+        | F::R16F
+        | F::RG88
+        | F::GR88 => 2,
+        F::RGB888 | F::BGR888 => 3,
+        F::ARGB8888
+        | F::XRGB8888
+        | F::XBGR8888
+        | F::RGBX8888
+        | F::BGRX8888
+        | F::ABGR8888
+        | F::RGBA8888
+        | F::BGRA8888
+        | F::XRGB2101010
+        | F::XBGR2101010
+        | F::RGBX1010102
+        | F::BGRX1010102
+        | F::ARGB2101010
+        | F::ABGR2101010
+        | F::RGBA1010102
+        | F::BGRA1010102
+        | F::RG1616
+        | F::GR1616
+        | F::GR1616F
+        | F::R32F => 4,
+        F::RGB161616 | F::BGR161616 | F::BGR161616F => 6,
+        F::XRGB16161616
+        | F::XBGR16161616
+        | F::ARGB16161616
+        | F::ABGR16161616
+        | F::XRGB16161616F
+        | F::XBGR16161616F
+        | F::ARGB16161616F
+        | F::ABGR16161616F
+        | F::GR3232F => 8,
+        F::BGR323232F => 12,
+        F::ABGR32323232F => 16,
+        _ => return None,
+    };
+    Some(bpp)
+}
@@ -0,0 +1,117 @@
+//! Protection against SIGBUS when accessing memory shared with clients.
+//!
+//! If a client truncates the file of a wl_shm pool, accessing the mapped memory beyond
+//! the end of the file raises SIGBUS. While an access is active, the signal handler
+//! replaces the mapping by anonymous memory and records the fault. The access then
+//! reads zeros instead of crashing the process. Faults outside of active accesses are
+//! passed on to the handler that was installed before.
+
+use {
+    std::{
+        cell::Cell,
+        mem, ptr,
+        sync::{
+            Once, OnceLock,
+            atomic::{AtomicBool, Ordering::Relaxed},
+        },
+    },
+    uapi::c,
+};
+
+struct Access {
+    start: usize,
+    len: usize,
+    faulted: AtomicBool,
+    prev: *const Access,
+}
+
+thread_local! {
+    static ACCESS: Cell<*const Access> = const { Cell::new(ptr::null()) };
+}
+
+static INSTALL: Once = Once::new();
+static PREVIOUS: OnceLock<c::sigaction> = OnceLock::new();
+
+/// Runs `f` while faults in the given memory range are being handled.
+///
+/// Returns `true` in the second field if a fault occurred. In this case, the range
+/// has been replaced by anonymous memory.
+pub(super) fn protect<T>(start: *const u8, len: usize, f: impl FnOnce() -> T) -> (T, bool) {
+    install();
+    let access = Access {
+        start: start as usize,
+        len,
+        faulted: AtomicBool::new(false),
+        prev: ACCESS.get(),
+    };
+    ACCESS.set(&access);
+    let res = {
+        let _reset = run_on_drop::on_drop(|| ACCESS.set(access.prev));
+        f()
+    };
+    (res, access.faulted.load(Relaxed))
+}
+
+fn install() {
+    INSTALL.call_once(|| unsafe {
+        let mut previous: c::sigaction = mem::zeroed();
+        c::sigaction(c::SIGBUS, ptr::null(), &mut previous);
+        let _ = PREVIOUS.set(previous);
+        let mut action: c::sigaction = mem::zeroed();
+        action.sa_sigaction = handle_sigbus as *const () as usize;
+        action.sa_flags = c::SA_SIGINFO | c::SA_NODEFER;
+        c::sigemptyset(&mut action.sa_mask);
+        c::sigaction(c::SIGBUS, &action, ptr::null_mut());
+    });
+}
+
+unsafe extern "C" fn handle_sigbus(
+    sig: c::c_int,
+    info: *mut c::siginfo_t,
+    context: *mut c::c_void,
+) {
+    let addr = unsafe { (*info).si_addr() } as usize;
+    let mut cur = ACCESS.get();
+    while let Some(access) = unsafe { cur.as_ref() } {
+        if addr >= access.start && addr - access.start < access.len {
+            let res = unsafe {
+                c::mmap(
+                    access.start as *mut c::c_void,
+                    access.len,
+                    c::PROT_READ | c::PROT_WRITE,
+                    c::MAP_PRIVATE | c::MAP_FIXED | c::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )
+            };
+            if res != c::MAP_FAILED {
+                access.faulted.store(true, Relaxed);
+                return;
+            }
+            break;
+        }
+        cur = access.prev;
+    }
+    // The fault is not ours. Chain to the previous handler.
+    let previous = PREVIOUS.get().map(|p| p.sa_sigaction).unwrap_or(c::SIG_DFL);
+    let flags = PREVIOUS.get().map(|p| p.sa_flags).unwrap_or(0);
+    match previous {
+        c::SIG_DFL | c::SIG_IGN => unsafe {
+            // Restore the default action so that the fault is raised again and terminates
+            // the process when we return. Ignoring the signal would retry the faulting
+            // instruction forever.
+            c::signal(c::SIGBUS, c::SIG_DFL);
+        },
+        handler if flags & c::SA_SIGINFO != 0 => unsafe {
+            let handler = mem::transmute::<
+                usize,
+                unsafe extern "C" fn(c::c_int, *mut c::siginfo_t, *mut c::c_void),
+            >(handler);
+            handler(sig, info, context);
+        },
+        handler => unsafe {
+            let handler = mem::transmute::<usize, unsafe extern "C" fn(c::c_int)>(handler);
+            handler(sig);
+        },
+    }
+}
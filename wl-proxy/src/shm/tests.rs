@@ -0,0 +1,232 @@
+#[cfg(feature = "headless")]
+use {
+    crate::{
+        baseline::Baseline,
+        headless::Headless,
+        object::{ConcreteObject, Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::wayland::{
+            wl_buffer::WlBuffer,
+            wl_compositor::{WlCompositor, WlCompositorHandler},
+            wl_display::{WlDisplay, WlDisplayHandler},
+            wl_registry::{WlRegistry, WlRegistryHandler},
+            wl_shm::WlShm,
+            wl_surface::{WlSurface, WlSurfaceHandler},
+        },
+        shm::{OwnedShmBuffer, track_shm},
+        state::State,
+        test_framework::{
+            headless::{HeadlessEnv, roundtrip},
+            install_logger,
+        },
+    },
+    std::cell::RefCell,
+};
+use {
+    crate::{
+        protocols::wayland::wl_shm::WlShmFormat,
+        shm::{ShmBuffer, ShmPool},
+    },
+    std::{
+        cell::Cell,
+        os::fd::{AsRawFd, OwnedFd},
+        rc::Rc,
+    },
+    uapi::c,
+};
+
+fn pool(pixels: &[u32]) -> (OwnedFd, Rc<ShmPool>) {
+    let fd = uapi::memfd_create("test", c::MFD_CLOEXEC).unwrap();
+    uapi::write(fd.raw(), uapi::as_bytes(pixels)).unwrap();
+    let fd: OwnedFd = fd.into();
+    let pool = Rc::new(ShmPool {
+        fd: Rc::new(fd.try_clone().unwrap()),
+        size: Cell::new(size_of_val(pixels) as i32),
+        mapping: Default::default(),
+    });
+    (fd, pool)
+}
+
+#[test]
+fn truncated() {
+    let (fd, pool) = pool(&[0x11111111; 1024]);
+    let buffer = ShmBuffer {
+        pool,
+        offset: 0,
+        width: 32,
+        height: 32,
+        stride: 128,
+        format: WlShmFormat::ARGB8888,
+    };
+    let pixel = buffer.access(|view| view.pixel(31, 31)).unwrap();
+    assert_eq!(pixel, Some(0x11111111));
+
+    uapi::ftruncate(fd.as_raw_fd(), 0).unwrap();
+    assert!(buffer.access(|view| view.pixel(31, 31)).is_err());
+
+    uapi::ftruncate(fd.as_raw_fd(), 4096).unwrap();
+    uapi::pwrite(fd.as_raw_fd(), &0x22222222u32.to_ne_bytes(), 4092).unwrap();
+    let pixel = buffer.access(|view| view.pixel(31, 31)).unwrap();
+    assert_eq!(pixel, Some(0x22222222));
+}
+
+#[test]
+fn out_of_bounds() {
+    let (_fd, pool) = pool(&[0; 16]);
+    let buffer = ShmBuffer {
+        pool,
+        offset: 16,
+        width: 4,
+        height: 4,
+        stride: 16,
+        format: WlShmFormat::ARGB8888,
+    };
+    assert!(buffer.access(|_| ()).is_err());
+}
+
+#[cfg(feature = "headless")]
+fn env() -> (HeadlessEnv, Proxy) {
+    let commits = Proxy(Default::default());
+    let env = HeadlessEnv::with_proxy_client(|proxy_client| {
+        proxy_client.display.set_handler(commits.clone());
+    });
+    (env, commits)
+}
+
+/// Records the contents of the buffers committed by the client.
+#[cfg(feature = "headless")]
+#[derive(Clone)]
+struct Proxy(Rc<RefCell<Vec<Option<Vec<u8>>>>>);
+
+#[cfg(feature = "headless")]
+struct ProxySurface {
+    commits: Proxy,
+    pending_buffer: Option<Rc<WlBuffer>>,
+}
+
+#[cfg(feature = "headless")]
+impl WlDisplayHandler for Proxy {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(self.clone());
+        slf.send_get_registry(registry);
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlRegistryHandler for Proxy {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        if let Some(shm) = id.try_downcast::<WlShm>() {
+            track_shm(&shm);
+        }
+        if let Some(compositor) = id.try_downcast::<WlCompositor>() {
+            compositor.set_handler(self.clone());
+        }
+        slf.send_bind(name, id);
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlCompositorHandler for Proxy {
+    fn handle_create_surface(&mut self, slf: &Rc<WlCompositor>, id: &Rc<WlSurface>) {
+        id.set_handler(ProxySurface {
+            commits: self.clone(),
+            pending_buffer: None,
+        });
+        slf.send_create_surface(id);
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlSurfaceHandler for ProxySurface {
+    fn handle_attach(
+        &mut self,
+        slf: &Rc<WlSurface>,
+        buffer: Option<&Rc<WlBuffer>>,
+        x: i32,
+        y: i32,
+    ) {
+        self.pending_buffer = buffer.cloned();
+        slf.send_attach(buffer, x, y);
+    }
+
+    fn handle_commit(&mut self, slf: &Rc<WlSurface>) {
+        let contents = self
+            .pending_buffer
+            .take()
+            .and_then(|buffer| ShmBuffer::get(&buffer))
+            .and_then(|buffer| buffer.access(|view| view.to_vec()).ok());
+        self.commits.0.borrow_mut().push(contents);
+        slf.send_commit();
+    }
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn track() {
+    let (env, commits) = env();
+    let compositor = env.bind::<WlCompositor>(1);
+    let shm = env.bind::<WlShm>(2);
+    let surface = compositor.new_send_create_surface();
+    let fd = uapi::memfd_create("test", c::MFD_CLOEXEC).unwrap();
+    uapi::write(fd.raw(), uapi::as_bytes(&[1u32, 2, 3, 4])).unwrap();
+    let fd: OwnedFd = fd.into();
+    let pool = shm.new_send_create_pool(&Rc::new(fd.try_clone().unwrap()), 16);
+    let buffer = pool.new_send_create_buffer(0, 2, 2, 8, WlShmFormat::ARGB8888);
+    surface.send_attach(Some(&buffer), 0, 0);
+    surface.send_commit();
+    env.roundtrip();
+    assert_eq!(
+        *commits.0.borrow(),
+        [Some(uapi::as_bytes(&[1u32, 2, 3, 4]).to_vec())],
+    );
+
+    // Buffers beyond the original size of the pool are accessible after a resize.
+    uapi::pwrite(fd.as_raw_fd(), uapi::as_bytes(&[5u32, 6]), 16).unwrap();
+    pool.send_resize(24);
+    let buffer = pool.new_send_create_buffer(16, 2, 1, 8, WlShmFormat::ARGB8888);
+    surface.send_attach(Some(&buffer), 0, 0);
+    surface.send_commit();
+    surface.send_attach(None, 0, 0);
+    surface.send_commit();
+    env.roundtrip();
+    assert_eq!(
+        commits.0.borrow()[1..],
+        [Some(uapi::as_bytes(&[5u32, 6]).to_vec()), None],
+    );
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn owned_buffer() {
+    install_logger();
+    let headless = Headless::new().unwrap();
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let _destructor = proxy.create_destructor();
+    proxy.set_default_forward_to_client(false);
+    let registry = proxy.display().new_send_get_registry();
+    let compositor = proxy.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+    registry.send_bind(1, compositor.clone());
+    let shm = proxy.create_object::<WlShm>(WlShm::XML_VERSION);
+    registry.send_bind(2, shm.clone());
+
+    // The stride is measured in bytes.
+    assert!(OwnedShmBuffer::new(&shm, 3, 2, 8, WlShmFormat::ARGB8888).is_err());
+    assert!(OwnedShmBuffer::new(&shm, 3, 2, 12, WlShmFormat::NV12).is_err());
+
+    let mut buffer = OwnedShmBuffer::new(&shm, 3, 2, 12, WlShmFormat::ARGB8888).unwrap();
+    assert_eq!(buffer.data().len(), 24);
+    assert!(buffer.data().iter().all(|&b| b == 0));
+    buffer.data_mut()[20..].copy_from_slice(&0xff00ff00u32.to_ne_bytes());
+    let surface = compositor.new_send_create_surface();
+    surface.send_attach(Some(buffer.wl_buffer()), 0, 0);
+    surface.send_commit();
+    roundtrip(&proxy, [&proxy, headless.state()]);
+
+    let image = headless.surfaces()[0].image().unwrap();
+    assert_eq!((image.width(), image.height()), (3, 2));
+    assert_eq!(image.pixel(2, 1), Some(0xff00ff00));
+    assert_eq!(image.pixel(0, 0), Some(0));
+    assert!(buffer.wl_buffer().server_id().is_some());
+}
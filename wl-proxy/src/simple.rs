@@ -4,18 +4,23 @@ use {
     crate::{
         acceptor::{Acceptor, AcceptorError},
         baseline::Baseline,
-        client::ClientHandler,
+        client::{Client, ClientHandler},
+        poll::{self, MAX_EVENTS, PollError, PollEvent, Poller},
         protocols::wayland::wl_display::WlDisplayHandler,
-        state::{Destructor, State},
-        utils::env::WAYLAND_DISPLAY,
+        state::{Destructor, State, StateError, StateHandler},
+        utils::env::{WAYLAND_DISPLAY, WAYLAND_SOCKET},
     },
     error_reporter::Report,
     parking_lot::Mutex,
     run_on_drop::on_drop,
     std::{
+        collections::HashMap,
         io,
-        os::unix::prelude::ExitStatusExt,
-        process::{Command, exit},
+        os::{
+            fd::{AsFd, AsRawFd, OwnedFd},
+            unix::{prelude::ExitStatusExt, process::CommandExt},
+        },
+        process::{Child, Command, exit},
         rc::Rc,
         sync::atomic::{AtomicUsize, Ordering::Relaxed},
         thread,
@@ -24,16 +29,74 @@ use {
     uapi::raise,
 };
 
+#[cfg(test)]
+mod tests;
+
 /// A simple proxy server that spawns a thread for each client.
 ///
 /// This server will create an acceptor and create a [`State`] for
 /// each client that connects to the acceptor.
+///
+/// See [`SharedProxy`] for a server that handles all clients in a single thread.
 pub struct SimpleProxy {
     baseline: Baseline,
     acceptor: Rc<Acceptor>,
 }
 
-/// An error returned by a [`SimpleProxy`].
+/// A simple proxy server that handles all clients in a single thread.
+///
+/// This server uses a single event loop for all clients. Depending on the
+/// [`ServerConnection`], it either creates a [`State`] with its own server connection for
+/// each client or a single [`State`] whose server connection is shared by all clients.
+pub struct SharedProxy {
+    baseline: Baseline,
+    acceptor: Rc<Acceptor>,
+    shared_state: Option<Rc<State>>,
+}
+
+/// A simple proxy server for a single client.
+///
+/// Instead of creating an acceptor, this server creates a socketpair with
+/// [`State::connect`]. The client end of the socketpair should be passed to the client
+/// via [`SimpleCommandExt::with_wayland_socket`]. Since there is no socket in the file
+/// system, no other process can connect to this proxy.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::process::Command;
+/// # use wl_proxy::baseline::Baseline;
+/// # use wl_proxy::protocols::wayland::wl_display::WlDisplayHandler;
+/// # use wl_proxy::simple::{SimpleCommandExt, SingleClientProxy, wait_and_forward_exit_code};
+/// # struct Handler;
+/// # impl WlDisplayHandler for Handler { }
+/// let mut proxy = SingleClientProxy::new(Baseline::ALL_OF_THEM).unwrap();
+/// let mut child = Command::new("some-wayland-application")
+///     .with_wayland_socket(proxy.take_socket().unwrap())
+///     .spawn()
+///     .unwrap();
+/// proxy.run(Handler).unwrap();
+/// let err = wait_and_forward_exit_code(&mut child);
+/// panic!("could not wait for the child: {err}");
+/// ```
+pub struct SingleClientProxy {
+    state: Rc<State>,
+    client: Rc<Client>,
+    socket: Option<OwnedFd>,
+}
+
+/// The server connections used by a [`SharedProxy`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ServerConnection {
+    /// Each client uses its own connection to the server.
+    PerClient,
+    /// All clients share a single connection to the server.
+    ///
+    /// In this mode, all clients share a single wl_display object.
+    Shared,
+}
+
+/// An error returned by a [`SimpleProxy`] or a [`SharedProxy`].
 #[derive(Debug, Error)]
 #[error(transparent)]
 pub struct SimpleProxyError(#[from] SimpleProxyErrorKind);
@@ -46,6 +109,12 @@ enum SimpleProxyErrorKind {
     AcceptConnection(#[source] AcceptorError),
     #[error("could not spawn a thread")]
     SpawnThread(#[source] io::Error),
+    #[error("could not create a state")]
+    CreateState(#[source] StateError),
+    #[error("could not dispatch the state")]
+    DispatchState(#[source] StateError),
+    #[error(transparent)]
+    Poll(PollError),
 }
 
 impl SimpleProxy {
@@ -119,7 +188,7 @@ impl SimpleProxy {
                         };
                         client.set_handler(ClientHandlerImpl {
                             id,
-                            _destructor: state.create_destructor(),
+                            _destructor: Some(state.create_destructor()),
                         });
                         let handler = display_handler();
                         client.display().set_handler(handler);
@@ -138,9 +207,309 @@ impl SimpleProxy {
     }
 }
 
+impl SharedProxy {
+    /// Creates a new [`SharedProxy`].
+    ///
+    /// If `server_connection` is [`ServerConnection::Shared`], this function immediately
+    /// connects to the server.
+    pub fn new(
+        baseline: Baseline,
+        server_connection: ServerConnection,
+    ) -> Result<SharedProxy, SimpleProxyError> {
+        let slf = match server_connection {
+            ServerConnection::PerClient => Self {
+                baseline,
+                acceptor: Acceptor::new(1000, true)
+                    .map_err(SimpleProxyErrorKind::CreateAcceptor)?,
+                shared_state: None,
+            },
+            ServerConnection::Shared => {
+                let state = State::builder(baseline)
+                    .build()
+                    .map_err(SimpleProxyErrorKind::CreateState)?;
+                Self::with_shared_state(baseline, state)?
+            }
+        };
+        Ok(slf)
+    }
+
+    fn with_shared_state(baseline: Baseline, state: Rc<State>) -> Result<Self, SimpleProxyError> {
+        let acceptor = match state.create_acceptor(1000) {
+            Ok(a) => a,
+            Err(e) => {
+                state.destroy();
+                return Err(SimpleProxyErrorKind::CreateState(e).into());
+            }
+        };
+        Ok(Self {
+            baseline,
+            acceptor,
+            shared_state: Some(state),
+        })
+    }
+
+    /// Returns the name of the display used by this proxy.
+    ///
+    /// The `WAYLAND_DISPLAY` environment variable should be set to this value for clients
+    /// that should connect to this proxy. See [`SimpleCommandExt::with_wayland_display`].
+    pub fn display(&self) -> &str {
+        self.acceptor.display()
+    }
+
+    /// Runs the proxy indefinitely.
+    ///
+    /// The closure is invoked once for each client and the returned handler is installed
+    /// on the wl_display object of the client.
+    ///
+    /// This function does not return unless a fatal error occurs.
+    pub fn run<H>(self, display_handler: impl Fn() -> H + 'static) -> SimpleProxyError
+    where
+        H: WlDisplayHandler,
+    {
+        let err = match &self.shared_state {
+            Some(state) => self.run_shared(state, display_handler),
+            None => self.run_per_client(display_handler),
+        };
+        SimpleProxyError(err)
+    }
+
+    fn run_shared<H>(
+        &self,
+        state: &Rc<State>,
+        display_handler: impl Fn() -> H + 'static,
+    ) -> SimpleProxyErrorKind
+    where
+        H: WlDisplayHandler,
+    {
+        state.set_handler(SharedStateHandler {
+            next_id: 1,
+            display_handler,
+        });
+        loop {
+            if let Err(e) = state.dispatch_blocking() {
+                return SimpleProxyErrorKind::DispatchState(e);
+            }
+        }
+    }
+
+    fn run_per_client<H>(&self, display_handler: impl Fn() -> H) -> SimpleProxyErrorKind
+    where
+        H: WlDisplayHandler,
+    {
+        let mut states = HashMap::new();
+        let err = self.run_per_client_(&mut states, display_handler);
+        for state in states.values() {
+            state.destroy();
+        }
+        err
+    }
+
+    fn run_per_client_<H>(
+        &self,
+        states: &mut HashMap<u64, Rc<State>>,
+        display_handler: impl Fn() -> H,
+    ) -> SimpleProxyErrorKind
+    where
+        H: WlDisplayHandler,
+    {
+        const ACCEPTOR_ID: u64 = 0;
+        let poller = match Poller::new() {
+            Ok(p) => p,
+            Err(e) => return SimpleProxyErrorKind::Poll(e),
+        };
+        let acceptor = self.acceptor.as_fd();
+        if let Err(e) = poller.register(ACCEPTOR_ID, acceptor) {
+            return SimpleProxyErrorKind::Poll(e);
+        }
+        let mut next_id = 1;
+        let mut events = [PollEvent::default(); MAX_EVENTS];
+        let mut arm_acceptor = true;
+        loop {
+            if arm_acceptor {
+                if let Err(e) = poller.update_interests(ACCEPTOR_ID, acceptor, poll::READABLE) {
+                    return SimpleProxyErrorKind::Poll(e);
+                }
+                arm_acceptor = false;
+            }
+            let n = match poller.read_events(-1, &mut events) {
+                Ok(n) => n,
+                Err(e) => return SimpleProxyErrorKind::Poll(e),
+            };
+            for event in &events[..n] {
+                if event.u64 != ACCEPTOR_ID {
+                    dispatch_client_state(&poller, states, event.u64);
+                    continue;
+                }
+                arm_acceptor = true;
+                loop {
+                    let socket = match self.acceptor.accept() {
+                        Ok(Some(s)) => s,
+                        Ok(None) => break,
+                        Err(e) => return SimpleProxyErrorKind::AcceptConnection(e),
+                    };
+                    let id = next_id;
+                    next_id += 1;
+                    log::debug!("Client {id} connected");
+                    let Some(state) = self.create_state(id, socket, &display_handler) else {
+                        continue;
+                    };
+                    let key = id as u64;
+                    if let Err(e) = poller.register(key, state.poll_fd().as_fd()) {
+                        state.destroy();
+                        return SimpleProxyErrorKind::Poll(e);
+                    }
+                    states.insert(key, state);
+                    dispatch_client_state(&poller, states, key);
+                }
+            }
+        }
+    }
+
+    fn create_state<H>(
+        &self,
+        id: usize,
+        socket: OwnedFd,
+        display_handler: impl Fn() -> H,
+    ) -> Option<Rc<State>>
+    where
+        H: WlDisplayHandler,
+    {
+        let name = format!("socket-{id}");
+        let state = State::builder(self.baseline).with_log_prefix(&name).build();
+        let state = match state {
+            Ok(s) => s,
+            Err(e) => {
+                log::error!("Could not create a new state: {}", Report::new(e));
+                return None;
+            }
+        };
+        let client = match state.add_client(&Rc::new(socket)) {
+            Ok(c) => c,
+            Err(e) => {
+                log::error!("Could not add client to state: {}", Report::new(e));
+                state.destroy();
+                return None;
+            }
+        };
+        client.set_handler(ClientHandlerImpl {
+            id,
+            _destructor: Some(state.create_destructor()),
+        });
+        client.display().set_handler(display_handler());
+        Some(state)
+    }
+}
+
+impl SingleClientProxy {
+    /// Creates a new [`SingleClientProxy`].
+    ///
+    /// This function immediately connects to the server.
+    pub fn new(baseline: Baseline) -> Result<SingleClientProxy, SimpleProxyError> {
+        let state = State::builder(baseline)
+            .build()
+            .map_err(SimpleProxyErrorKind::CreateState)?;
+        let (client, socket) = match state.connect() {
+            Ok(c) => c,
+            Err(e) => {
+                state.destroy();
+                return Err(SimpleProxyErrorKind::CreateState(e).into());
+            }
+        };
+        client.set_handler(ClientHandlerImpl {
+            id: 1,
+            _destructor: Some(state.create_destructor()),
+        });
+        Ok(Self {
+            state,
+            client,
+            socket: Some(socket),
+        })
+    }
+
+    /// Takes the client end of the connection.
+    ///
+    /// This returns `None` if the socket has already been taken.
+    pub fn take_socket(&mut self) -> Option<OwnedFd> {
+        self.socket.take()
+    }
+
+    /// Runs the proxy until the client disconnects.
+    pub fn run<H>(self, display_handler: H) -> Result<(), SimpleProxyError>
+    where
+        H: WlDisplayHandler,
+    {
+        self.client.display().set_handler(display_handler);
+        while self.state.is_not_destroyed() {
+            if let Err(e) = self.state.dispatch_blocking() {
+                if self.state.is_destroyed() {
+                    break;
+                }
+                return Err(SimpleProxyErrorKind::DispatchState(e).into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SingleClientProxy {
+    fn drop(&mut self) {
+        self.state.destroy();
+    }
+}
+
+impl Drop for SharedProxy {
+    fn drop(&mut self) {
+        if let Some(state) = &self.shared_state {
+            state.destroy();
+        }
+    }
+}
+
+fn dispatch_client_state(poller: &Poller, states: &mut HashMap<u64, Rc<State>>, id: u64) {
+    let Some(state) = states.get(&id) else {
+        return;
+    };
+    if let Err(e) = state.dispatch_available() {
+        log::error!("Could not dispatch state: {}", Report::new(e));
+    }
+    let fd = state.poll_fd().as_fd();
+    if state.is_not_destroyed()
+        && let Err(e) = poller.update_interests(id, fd, poll::READABLE)
+    {
+        log::error!("Could not update epoll interests: {}", Report::new(e));
+        state.destroy();
+    }
+    if state.is_destroyed() {
+        poller.unregister(fd);
+        states.remove(&id);
+    }
+}
+
+struct SharedStateHandler<F> {
+    next_id: usize,
+    display_handler: F,
+}
+
+impl<F, H> StateHandler for SharedStateHandler<F>
+where
+    F: Fn() -> H + 'static,
+    H: WlDisplayHandler,
+{
+    fn new_client(&mut self, client: &Rc<Client>) {
+        let id = self.next_id;
+        self.next_id += 1;
+        log::debug!("Client {id} connected");
+        client.set_handler(ClientHandlerImpl {
+            id,
+            _destructor: None,
+        });
+        client.display().set_handler((self.display_handler)());
+    }
+}
+
 struct ClientHandlerImpl {
     id: usize,
-    _destructor: Destructor,
+    _destructor: Option<Destructor>,
 }
 
 impl ClientHandler for ClientHandlerImpl {
@@ -153,6 +522,18 @@ impl ClientHandler for ClientHandlerImpl {
 pub trait SimpleCommandExt {
     /// Sets the `WAYLAND_DISPLAY` environment variable.
     fn with_wayland_display(&mut self, display: &str) -> &mut Command;
+    /// Passes the socket to the application via the `WAYLAND_SOCKET` environment
+    /// variable.
+    ///
+    /// The socket is closed in this process when the [`Command`] is dropped.
+    ///
+    /// This also removes `WAYLAND_DISPLAY` from the environment so that wayland clients
+    /// started by the application do not connect to the compositor directly. Note that
+    /// libwayland removes `WAYLAND_SOCKET` from the environment when it connects. Only
+    /// the first wayland client started by the application connects to the proxy. Later
+    /// clients fall back to the default display `wayland-0` and bypass the proxy if a
+    /// compositor listens on that socket.
+    fn with_wayland_socket(&mut self, socket: OwnedFd) -> &mut Command;
     /// Spawns the application, waits for it to exit, and then calls [`exit`] with the
     /// same exit code.
     fn spawn_and_forward_exit_code(&mut self) -> Result<(), io::Error>;
@@ -163,25 +544,44 @@ impl SimpleCommandExt for Command {
         self.env(WAYLAND_DISPLAY, display)
     }
 
+    fn with_wayland_socket(&mut self, socket: OwnedFd) -> &mut Command {
+        self.env(WAYLAND_SOCKET, socket.as_raw_fd().to_string());
+        self.env_remove(WAYLAND_DISPLAY);
+        // SAFETY: fcntl is async-signal-safe.
+        unsafe {
+            self.pre_exec(move || {
+                uapi::fcntl_setfd(socket.as_raw_fd(), 0)?;
+                Ok(())
+            })
+        }
+    }
+
     fn spawn_and_forward_exit_code(&mut self) -> Result<(), io::Error> {
         let mut child = self.spawn()?;
-        thread::spawn(move || match child.wait() {
-            Ok(e) => {
-                if let Some(code) = e.code() {
-                    exit(code);
-                }
-                if let Some(signal) = e.signal() {
-                    let _ = raise(signal);
-                    exit(1);
-                }
-                eprintln!("Child terminated with neither a signal nor an exit code");
-                exit(1);
-            }
-            Err(e) => {
-                eprintln!("Could not wait for child: {}", Report::new(e));
-                exit(1);
-            }
+        thread::spawn(move || {
+            let e = wait_and_forward_exit_code(&mut child);
+            eprintln!("Could not wait for child: {}", Report::new(e));
+            exit(1);
         });
         Ok(())
     }
 }
+
+/// Waits for the child to exit and then calls [`exit`] with the same exit code.
+///
+/// This function only returns if waiting for the child fails.
+pub fn wait_and_forward_exit_code(child: &mut Child) -> io::Error {
+    let status = match child.wait() {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    if let Some(code) = status.code() {
+        exit(code);
+    }
+    if let Some(signal) = status.signal() {
+        let _ = raise(signal);
+        exit(1);
+    }
+    eprintln!("Child terminated with neither a signal nor an exit code");
+    exit(1);
+}
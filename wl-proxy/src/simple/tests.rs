@@ -0,0 +1,107 @@
+#![cfg(feature = "headless")]
+
+use {
+    crate::{
+        baseline::Baseline,
+        headless::Headless,
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+            },
+        },
+        simple::{SharedProxy, SharedStateHandler},
+        state::State,
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+struct HideSeat;
+
+impl WlDisplayHandler for HideSeat {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(HideSeat);
+        slf.send_get_registry(registry);
+    }
+}
+
+impl WlRegistryHandler for HideSeat {
+    fn handle_global(
+        &mut self,
+        slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: ObjectInterface,
+        version: u32,
+    ) {
+        if interface != ObjectInterface::WlSeat {
+            slf.send_global(name, interface, version);
+        }
+    }
+}
+
+struct Globals(Rc<RefCell<Vec<ObjectInterface>>>);
+
+impl WlRegistryHandler for Globals {
+    fn handle_global(
+        &mut self,
+        _slf: &Rc<WlRegistry>,
+        _name: u32,
+        interface: ObjectInterface,
+        _version: u32,
+    ) {
+        self.0.borrow_mut().push(interface);
+    }
+}
+
+struct Done(Rc<Cell<bool>>);
+
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn shared_server_connection() {
+    let headless = Headless::new().unwrap();
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let proxy = SharedProxy::with_shared_state(Baseline::ALL_OF_THEM, state.clone()).unwrap();
+    state.set_handler(SharedStateHandler {
+        next_id: 1,
+        display_handler: || HideSeat,
+    });
+
+    // The handler is installed for every client.
+    for _ in 0..2 {
+        let client = State::builder(Baseline::ALL_OF_THEM)
+            .with_server_display_name(proxy.display())
+            .build()
+            .unwrap();
+        let _destructor = client.create_destructor();
+        let globals = Rc::new(RefCell::new(vec![]));
+        client
+            .display()
+            .new_send_get_registry()
+            .set_handler(Globals(globals.clone()));
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([&client, &state, headless.state()]).unwrap();
+        }
+        let globals = globals.take();
+        assert!(globals.contains(&ObjectInterface::WlCompositor));
+        assert!(!globals.contains(&ObjectInterface::WlSeat));
+    }
+}
@@ -1,16 +1,28 @@
 //! The proxy state.
 
+#[cfg(feature = "recovery")]
+use crate::recovery::Recovery;
+#[cfg(feature = "protocol-security_context_v1")]
+use crate::security_context::{SecurityContextError, SecurityContextListener};
+#[cfg(feature = "logging")]
+use crate::{
+    logging::{LogArg, LogFilter, LogRecord, LogSink},
+    recording::Direction,
+};
 use {
     crate::{
         acceptor::{Acceptor, AcceptorError},
         baseline::Baseline,
-        client::Client,
+        client::{Client, ClientCredentials},
         endpoint::{Endpoint, EndpointError},
         handler::HandlerHolder,
+        interceptor::MessageInterceptor,
         object::{Object, ObjectCoreApi, ObjectErrorKind, ObjectPrivate},
         poll::{self, PollError, PollEvent, Poller},
         protocols::wayland::wl_display::WlDisplay,
+        recording::{Peer, Recorder},
         trans::{FlushResult, TransError},
+        unknown::{UnknownInterface, UnknownInterfaces, UnknownObject},
         utils::{
             env::{WAYLAND_DISPLAY, WAYLAND_SOCKET, XDG_RUNTIME_DIR},
             stack::Stack,
@@ -41,6 +53,8 @@ pub use {
 
 mod builder;
 mod destructor;
+#[cfg(feature = "recovery")]
+mod recovery;
 #[cfg(test)]
 mod tests;
 
@@ -69,6 +83,10 @@ enum StateErrorKind {
     CreateAcceptor(AcceptorError),
     #[error("could not accept a new connection")]
     AcceptConnection(AcceptorError),
+    #[error("the acceptor has already been added to a state")]
+    AcceptorAlreadyAdded,
+    #[error("could not make the acceptor non-blocking")]
+    AcceptorNonBlocking(#[source] io::Error),
     #[error("could not create a pipe")]
     CreatePipe(#[source] io::Error),
     #[error("could not read {} environment variable", WAYLAND_DISPLAY)]
@@ -93,6 +111,30 @@ enum StateErrorKind {
     PollError(PollError),
     #[error("Could not create an eventfd")]
     CreateEventfd(#[source] io::Error),
+    #[cfg(feature = "protocol-security_context_v1")]
+    #[error("could not connect to the security context listener")]
+    ConnectSecurityContext(#[source] Box<SecurityContextError>),
+    #[cfg(feature = "protocol-security_context_v1")]
+    #[error("the acceptor uses a different security context listener than the state")]
+    SecurityContextMismatch,
+    #[cfg(feature = "recovery")]
+    #[error("could not reconnect to the server")]
+    Reconnect(#[source] io::Error),
+    #[cfg(feature = "recovery")]
+    #[error("the server did not respond in time during recovery")]
+    RecoveryTimeout,
+    #[cfg(feature = "recovery")]
+    #[error("recovery requires a connector if the server is given as a file descriptor")]
+    RecoveryAddressUnknown,
+    #[cfg(feature = "recovery")]
+    #[error("recovery is not enabled")]
+    RecoveryNotEnabled,
+    #[cfg(feature = "recovery")]
+    #[error("the state is already detached")]
+    AlreadyDetached,
+    #[cfg(feature = "recovery")]
+    #[error("could not start the stub server")]
+    StartStub(#[source] io::Error),
 }
 
 /// The proxy state.
@@ -175,6 +217,11 @@ pub struct State {
     pub(crate) log_prefix: String,
     #[cfg(feature = "logging")]
     log_writer: RefCell<io::BufWriter<uapi::Fd>>,
+    #[cfg(feature = "logging")]
+    log_filter: Option<LogFilter>,
+    #[cfg(feature = "logging")]
+    log_sink: Option<RefCell<Box<dyn LogSink>>>,
+    pub(crate) interceptor: Option<RefCell<Box<dyn MessageInterceptor>>>,
     global_lock_held: Cell<bool>,
     pub(crate) object_stash: Stash<Rc<dyn Object>>,
     pub(crate) forward_to_client: Cell<bool>,
@@ -183,6 +230,13 @@ pub struct State {
     unsuspend_requests: Stack<EndpointWithClient>,
     has_unsuspend_requests: Cell<bool>,
     unsuspend_triggered: Cell<bool>,
+    pub(crate) unknown_interfaces: UnknownInterfaces,
+    pub(crate) recorder: Option<Rc<Recorder>>,
+    #[cfg(feature = "protocol-security_context_v1")]
+    pub(crate) security_context: Option<Rc<SecurityContextListener>>,
+    #[cfg(feature = "recovery")]
+    pub(crate) recovery: Option<Recovery>,
+    pub(crate) delete_ids_on_destroy: Cell<bool>,
 }
 
 /// A handler for events emitted by a [`State`].
@@ -213,6 +267,15 @@ pub trait StateHandler: 'static {
         let _ = error;
         let _ = msg;
     }
+
+    /// The connection to the server has been re-established after the server hung up.
+    ///
+    /// This is only emitted if recovery has been enabled with
+    /// [`StateBuilder::with_recovery`]. At this point, the recreated objects have
+    /// received new server IDs. See the [`recovery`](crate::recovery) module for
+    /// details.
+    #[cfg(feature = "recovery")]
+    fn server_recovered(&mut self) {}
 }
 
 enum Pollable {
@@ -235,7 +298,7 @@ pub(crate) struct HandlerLock<'a> {
 impl State {
     pub(crate) fn remove_endpoint(&self, endpoint: &Endpoint) {
         self.pollables.borrow_mut().remove(&endpoint.id);
-        self.poller.unregister(endpoint.socket.as_fd());
+        self.poller.unregister(endpoint.socket.borrow().as_fd());
         endpoint.unregistered.set(true);
     }
 
@@ -251,6 +314,9 @@ impl State {
         did_work |= self.perform_writes(lock)?;
         did_work |= self.kill_clients();
         self.update_interests()?;
+        if let Some(recorder) = &self.recorder {
+            recorder.flush();
+        }
         Ok(did_work)
     }
 
@@ -287,6 +353,10 @@ impl State {
                         }
                         self.add_client_to_kill(client);
                     } else {
+                        #[cfg(feature = "recovery")]
+                        if self.request_recovery() {
+                            continue;
+                        }
                         if is_closed {
                             return Err(StateErrorKind::ServerHangup.into());
                         }
@@ -343,7 +413,7 @@ impl State {
                 let Some(socket) = socket else {
                     break;
                 };
-                self.create_client(Some(lock), &Rc::new(socket))?;
+                self.create_client(Some(lock), Some(&acceptor), &Rc::new(socket))?;
             }
         }
         self.has_acceptable_acceptors.set(false);
@@ -358,9 +428,17 @@ impl State {
             let res = ewc.endpoint.read_messages(lock, ewc.client.as_ref());
             if let Err(e) = res {
                 if let Some(client) = &ewc.client {
-                    log::error!("Could not handle client message: {}", Report::new(e));
+                    if !matches!(e, EndpointError::Read(TransError::Closed)) {
+                        log::error!("Could not handle client message: {}", Report::new(e));
+                    }
                     self.add_client_to_kill(client);
                 } else {
+                    #[cfg(feature = "recovery")]
+                    if let EndpointError::Read(_) = &e
+                        && self.request_recovery()
+                    {
+                        continue;
+                    }
                     if let EndpointError::HandleMessage(msg) = &e
                         && let ObjectErrorKind::ServerError(object, server_id, error, msg) =
                             &msg.source.0
@@ -471,6 +549,10 @@ impl State {
                             if let Some(client) = &ewc.client {
                                 self.add_client_to_kill(client);
                             } else {
+                                #[cfg(feature = "recovery")]
+                                if self.request_recovery() {
+                                    continue;
+                                }
                                 return Err(StateErrorKind::ServerHangup.into());
                             }
                             continue;
@@ -544,7 +626,7 @@ impl State {
                     continue;
                 }
                 self.poller
-                    .update_interests(endpoint.id, endpoint.socket.as_fd(), desired)
+                    .update_interests(endpoint.id, endpoint.socket.borrow().as_fd(), desired)
                     .map_err(StateErrorKind::PollError)?;
                 endpoint.current_interest.set(desired);
             }
@@ -553,7 +635,7 @@ impl State {
         if self.has_interest_update_acceptors.get() {
             while let Some(acceptor) = self.interest_update_acceptors.pop() {
                 self.poller
-                    .update_interests(acceptor.id, acceptor.socket.as_fd(), poll::READABLE)
+                    .update_interests(acceptor.id.get(), acceptor.socket.as_fd(), poll::READABLE)
                     .map_err(StateErrorKind::PollError)?;
             }
             self.has_interest_update_acceptors.set(false);
@@ -570,10 +652,38 @@ impl State {
 
     #[cfg(feature = "logging")]
     #[cold]
-    pub(crate) fn log(&self, args: std::fmt::Arguments<'_>) {
+    pub(crate) fn log(
+        &self,
+        client_id: Option<u64>,
+        direction: Direction,
+        interface: &str,
+        object_id: u32,
+        message: &str,
+        args: &[LogArg<'_>],
+    ) {
+        if let Some(filter) = &self.log_filter
+            && !filter.matches(interface, message)
+        {
+            return;
+        }
+        let record = LogRecord::new(
+            &self.log_prefix,
+            direction,
+            client_id,
+            interface,
+            object_id,
+            message,
+            args,
+        );
+        if let Some(sink) = &self.log_sink {
+            if let Ok(mut sink) = sink.try_borrow_mut() {
+                sink.log(&record);
+            }
+            return;
+        }
         use std::io::Write;
         let writer = &mut *self.log_writer.borrow_mut();
-        let _ = writer.write_fmt(args);
+        let _ = writeln!(writer, "{record}");
         let _ = writer.flush();
     }
 }
@@ -628,10 +738,19 @@ impl State {
             did_work |= self.flush_locked(&lock)?;
         }
         self.wait_for_work(&lock, timeout)?;
+        #[cfg(feature = "recovery")]
+        {
+            did_work |= self.recover(&lock)?;
+        }
         self.unsuspend_endpoints(&lock)?;
         did_work |= self.accept_connections(&lock)?;
         did_work |= self.read_messages(&lock)?;
         did_work |= self.flush_locked(&lock)?;
+        #[cfg(feature = "recovery")]
+        if self.recover(&lock)? {
+            did_work = true;
+            self.flush_locked(&lock)?;
+        }
         destroy_on_error.forget();
         Ok(did_work)
     }
@@ -707,6 +826,28 @@ impl State {
         P::new(self, version)
     }
 
+    /// Creates a new object with an interface that is not known to this crate.
+    ///
+    /// If the interface has been described via [`StateBuilder::with_unknown_interface`],
+    /// the object uses that description. Otherwise, messages of the object are
+    /// forwarded verbatim.
+    pub fn create_unknown_object(
+        self: &Rc<Self>,
+        interface: &str,
+        version: u32,
+    ) -> Rc<UnknownObject> {
+        let interface = self.unknown_interfaces.get_or_default(interface);
+        UnknownObject::create(self, interface, version)
+    }
+
+    /// Returns the description of an interface that is not known to this crate.
+    ///
+    /// This function returns `None` unless the interface has been described via
+    /// [`StateBuilder::with_unknown_interface`].
+    pub fn unknown_interface(&self, interface: &str) -> Option<Rc<UnknownInterface>> {
+        self.unknown_interfaces.get_described(interface)
+    }
+
     /// Returns a wl_display object.
     pub fn display(self: &Rc<Self>) -> Rc<WlDisplay> {
         let display = WlDisplay::new(self, 1);
@@ -749,7 +890,7 @@ impl State {
             0,
         )
         .map_err(|e| StateErrorKind::Socketpair(e.into()))?;
-        let client = self.create_client(None, &Rc::new(server_fd.into()))?;
+        let client = self.create_client(None, None, &Rc::new(server_fd.into()))?;
         Ok((client, client_fd.into()))
     }
 
@@ -761,7 +902,23 @@ impl State {
     ///
     /// The [`StateHandler::new_client`] callback will not be invoked.
     pub fn add_client(self: &Rc<Self>, socket: &Rc<OwnedFd>) -> Result<Rc<Client>, StateError> {
-        self.create_client(None, socket)
+        self.create_client(None, None, socket)
+    }
+
+    /// Creates a new connection to this proxy from a network connection.
+    ///
+    /// The file descriptor should be a stream socket, such as a TCP or vsock connection,
+    /// whose other end is the server connection of a state that was built with
+    /// [`StateBuilder::with_network_server`]. See the [`network`](crate::network)
+    /// module for details.
+    ///
+    /// The [`StateHandler::new_client`] callback will not be invoked.
+    #[cfg(feature = "network")]
+    pub fn add_network_client(
+        self: &Rc<Self>,
+        socket: &Rc<OwnedFd>,
+    ) -> Result<Rc<Client>, StateError> {
+        self.create_client_with(None, None, socket, |endpoint| endpoint.set_network(false))
     }
 
     /// Creates a new file-system acceptor and starts listening for connections.
@@ -773,32 +930,93 @@ impl State {
     /// happens.
     pub fn create_acceptor(&self, max_tries: u32) -> Result<Rc<Acceptor>, StateError> {
         self.check_destroyed()?;
+        #[cfg(feature = "protocol-security_context_v1")]
+        if let Some(listener) = &self.security_context {
+            let acceptor = Acceptor::with_security_context(listener, max_tries, true)
+                .map_err(StateErrorKind::CreateAcceptor)?;
+            self.add_acceptor(&acceptor)?;
+            return Ok(acceptor);
+        }
+        let acceptor = Acceptor::new(max_tries, true).map_err(StateErrorKind::CreateAcceptor)?;
+        self.add_acceptor(&acceptor)?;
+        Ok(acceptor)
+    }
+
+    /// Starts listening for connections on an existing acceptor.
+    ///
+    /// This can be used with acceptors created via [`Acceptor::with_path`],
+    /// [`Acceptor::with_abstract_name`], or [`Acceptor::from_listen_fds`]. The socket of
+    /// the acceptor is made non-blocking. An acceptor can only be added to a single
+    /// state.
+    ///
+    /// If the acceptor was created with [`Acceptor::with_security_context`] and the
+    /// state was built with
+    /// [`StateBuilder::with_security_context`](crate::state::StateBuilder::with_security_context),
+    /// both must use the same listener.
+    ///
+    /// Calling [`State::dispatch`] will automatically accept connections from this
+    /// acceptor. The [`StateHandler::new_client`] callback will be invoked when this
+    /// happens.
+    pub fn add_acceptor(&self, acceptor: &Rc<Acceptor>) -> Result<(), StateError> {
+        self.check_destroyed()?;
+        if acceptor.id.get() != 0 {
+            return Err(StateErrorKind::AcceptorAlreadyAdded.into());
+        }
+        #[cfg(feature = "protocol-security_context_v1")]
+        if let Some(a) = &acceptor.security_context
+            && let Some(s) = &self.security_context
+            && !Rc::ptr_eq(a, s)
+        {
+            return Err(StateErrorKind::SecurityContextMismatch.into());
+        }
+        let fd = acceptor.socket.as_raw_fd();
+        let res = uapi::fcntl_getfl(fd).and_then(|fl| uapi::fcntl_setfl(fd, fl | c::O_NONBLOCK));
+        if let Err(e) = res {
+            return Err(StateErrorKind::AcceptorNonBlocking(e.into()).into());
+        }
         let id = self.create_pollable_id();
-        let acceptor =
-            Acceptor::create(id, max_tries, true).map_err(StateErrorKind::CreateAcceptor)?;
         self.poller
             .register(id, acceptor.socket.as_fd())
             .map_err(StateErrorKind::PollError)?;
+        acceptor.id.set(id);
         self.update_interests()?;
         self.interest_update_acceptors.push(acceptor.clone());
         self.has_interest_update_acceptors.set(true);
         self.pollables
             .borrow_mut()
             .insert(id, Pollable::Acceptor(acceptor.clone()));
-        Ok(acceptor)
+        Ok(())
     }
 
     fn create_client(
         self: &Rc<Self>,
         lock: Option<&HandlerLock<'_>>,
+        acceptor: Option<&Acceptor>,
+        socket: &Rc<OwnedFd>,
+    ) -> Result<Rc<Client>, StateError> {
+        self.create_client_with(lock, acceptor, socket, |_| ())
+    }
+
+    #[cfg_attr(
+        not(feature = "protocol-security_context_v1"),
+        expect(unused_variables)
+    )]
+    fn create_client_with(
+        self: &Rc<Self>,
+        lock: Option<&HandlerLock<'_>>,
+        acceptor: Option<&Acceptor>,
         socket: &Rc<OwnedFd>,
+        configure: impl FnOnce(&mut Endpoint),
     ) -> Result<Rc<Client>, StateError> {
         self.check_destroyed()?;
         let id = self.create_pollable_id();
         self.poller
             .register(id, socket.as_fd())
             .map_err(StateErrorKind::PollError)?;
-        let endpoint = Endpoint::new(id, socket);
+        let recorder = self.recorder.as_ref().map(|r| r.endpoint(id, Peer::Client));
+        let mut endpoint = Endpoint::create(id, socket, recorder);
+        configure(&mut endpoint);
+        let endpoint = Rc::new(endpoint);
         self.change_interest(&endpoint, |i| i | poll::READABLE);
         self.update_interests()?;
         let client = Rc::new(Client {
@@ -807,6 +1025,11 @@ impl State {
             display: self.display(),
             destroyed: Cell::new(false),
             handler: Default::default(),
+            credentials: ClientCredentials::retrieve(socket.as_fd()),
+            #[cfg(feature = "protocol-security_context_v1")]
+            security_context: acceptor
+                .and_then(|a| a.security_context.clone())
+                .or_else(|| self.security_context.clone()),
         });
         client
             .display
@@ -899,13 +1122,15 @@ impl State {
         }
         let objects = &mut *self.object_stash.borrow();
         for pollable in self.pollables.borrow().values() {
+            let socket;
             let fd = match pollable {
                 Pollable::Endpoint(ewc) => {
                     if let Some(c) = &ewc.client {
                         c.destroyed.set(true);
                     }
                     objects.extend(ewc.endpoint.objects.borrow_mut().drain().map(|v| v.1));
-                    &ewc.endpoint.socket
+                    socket = ewc.endpoint.socket();
+                    &socket
                 }
                 Pollable::Acceptor(a) => &a.socket,
                 Pollable::Destructor(fd, _) => fd,
@@ -1,18 +1,32 @@
+#[cfg(feature = "logging")]
+use crate::logging::{LogFilter, LogSink};
+#[cfg(feature = "protocol-security_context_v1")]
+use crate::security_context::SecurityContextListener;
+#[cfg(feature = "recovery")]
+use {
+    crate::recovery::{Connector, Recovery},
+    std::{io, time::Duration},
+};
 use {
     crate::{
         baseline::Baseline,
         endpoint::Endpoint,
+        interceptor::MessageInterceptor,
         object::{Object, ObjectPrivate},
         poll::{self, Poller},
         protocols::wayland::wl_display::WlDisplay,
+        recording::{Peer, Recorder, RecordingFormat},
         state::{EndpointWithClient, Pollable, State, StateError, StateErrorKind},
+        unknown::{UnknownInterface, UnknownInterfaces},
         utils::env::{WAYLAND_DISPLAY, WAYLAND_SOCKET, WL_PROXY_DEBUG, XDG_RUNTIME_DIR},
     },
+    isnt::std_1::string::IsntStringExt,
     linearize::Linearize,
     std::{
         cell::{Cell, RefCell},
         collections::HashMap,
         env::{remove_var, var, var_os},
+        io::Write,
         os::{
             fd::{AsFd, FromRawFd, OwnedFd},
             unix::ffi::OsStrExt,
@@ -31,12 +45,27 @@ pub struct StateBuilder {
     server: Option<Server>,
     log: bool,
     log_prefix: String,
+    unknown_interfaces: bool,
+    verbatim_fds: bool,
+    unknown_interface_descriptions: Vec<UnknownInterface>,
+    recorder: Option<(Box<dyn Write>, RecordingFormat)>,
+    #[cfg(feature = "logging")]
+    log_filter: Option<String>,
+    #[cfg(feature = "logging")]
+    log_sink: Option<Box<dyn LogSink>>,
+    interceptor: Option<Box<dyn MessageInterceptor>>,
+    #[cfg(feature = "recovery")]
+    recovery: Option<(Duration, Option<Connector>)>,
 }
 
 enum Server {
     None,
     Fd(Rc<OwnedFd>),
+    #[cfg(feature = "network")]
+    Network(Rc<OwnedFd>),
     DisplayName(String),
+    #[cfg(feature = "protocol-security_context_v1")]
+    SecurityContext(Rc<SecurityContextListener>),
 }
 
 #[derive(Copy, Clone, Linearize)]
@@ -47,11 +76,25 @@ pub(crate) enum StaticPollableIds {
 
 impl StateBuilder {
     pub(super) fn new(baseline: Baseline) -> Self {
+        let log_filter = var(WL_PROXY_DEBUG)
+            .ok()
+            .filter(|f| f.is_not_empty() && f != "0");
         Self {
             baseline,
             server: Default::default(),
-            log: var(WL_PROXY_DEBUG).as_deref() == Ok("1"),
+            log: log_filter.is_some(),
             log_prefix: Default::default(),
+            unknown_interfaces: false,
+            verbatim_fds: false,
+            unknown_interface_descriptions: Default::default(),
+            recorder: None,
+            #[cfg(feature = "logging")]
+            log_filter,
+            #[cfg(feature = "logging")]
+            log_sink: None,
+            interceptor: None,
+            #[cfg(feature = "recovery")]
+            recovery: None,
         }
     }
 
@@ -59,19 +102,50 @@ impl StateBuilder {
     ///
     /// The server to connect to is chosen as follows:
     ///
-    /// - If [`Self::with_server_fd`] was used, that FD is used.
+    /// - If [`Self::with_server_fd`] or `Self::with_network_server` was used, that FD is
+    ///   used.
+    /// - Otherwise, if `Self::with_security_context` was used, a new connection to the
+    ///   security context listener is used.
     /// - Otherwise, if [`Self::with_server_display_name`] was used, that display name is
     ///   used.
     /// - Otherwise, if the `WAYLAND_SOCKET` environment variable is set, that FD is used.
     /// - Otherwise, the display name from the `WAYLAND_DISPLAY` environment variable is
     ///   used.
     pub fn build(self) -> Result<Rc<State>, StateError> {
+        #[cfg(feature = "protocol-security_context_v1")]
+        let mut security_context = None;
+        #[cfg(feature = "recovery")]
+        let mut connector = None::<Connector>;
+        #[cfg(feature = "network")]
+        let mut network = false;
         let server_fd = 'fd: {
             let display_name = match self.server {
                 None => None,
                 Some(Server::None) => break 'fd None,
                 Some(Server::Fd(fd)) => break 'fd Some(fd),
+                #[cfg(feature = "network")]
+                Some(Server::Network(fd)) => {
+                    network = true;
+                    break 'fd Some(fd);
+                }
                 Some(Server::DisplayName(n)) => Some(n),
+                #[cfg(feature = "protocol-security_context_v1")]
+                Some(Server::SecurityContext(listener)) => {
+                    let fd = listener
+                        .connect()
+                        .map_err(|e| StateErrorKind::ConnectSecurityContext(Box::new(e)))?;
+                    #[cfg(feature = "recovery")]
+                    {
+                        let listener = listener.clone();
+                        connector = Some(Box::new(move || {
+                            listener.connect().map_err(|e| {
+                                io::Error::other(error_reporter::Report::new(e).to_string())
+                            })
+                        }));
+                    }
+                    security_context = Some(listener);
+                    break 'fd Some(Rc::new(fd));
+                }
             };
             if display_name.is_none()
                 && let Some(wayland_socket) = var_os(WAYLAND_SOCKET)
@@ -91,41 +165,49 @@ impl StateBuilder {
                 };
                 break 'fd Some(fd);
             }
-            let mut name = match display_name {
+            let name = match display_name {
                 Some(n) => n,
                 _ => var(WAYLAND_DISPLAY)
                     .ok()
                     .ok_or(StateErrorKind::WaylandDisplay)?,
             };
-            if name.is_empty() {
-                return Err(StateErrorKind::WaylandDisplayEmpty.into());
-            }
-            if !name.starts_with("/") {
-                let Ok(xrd) = var(XDG_RUNTIME_DIR) else {
-                    return Err(StateErrorKind::XrdNotSet.into());
-                };
-                name = format!("{xrd}/{name}");
+            let (name, addr) = display_addr(name)?;
+            let socket = connect_display(&name, &addr)?;
+            #[cfg(feature = "recovery")]
+            {
+                connector = Some(display_connector(addr));
             }
-            let mut addr = sockaddr_un {
-                sun_family: c::AF_UNIX as _,
-                sun_path: [0; 108],
-            };
-            if name.len() > addr.sun_path.len() - 1 {
-                return Err(StateErrorKind::SocketPathTooLong.into());
+            Some(Rc::new(socket))
+        };
+        #[cfg(feature = "recovery")]
+        let recovery = match self.recovery {
+            Some((timeout, custom)) if server_fd.is_some() => {
+                let connector = custom
+                    .or(connector)
+                    .ok_or(StateErrorKind::RecoveryAddressUnknown)?;
+                Some(Recovery::new(timeout, connector))
             }
-            let sun_path = uapi::as_bytes_mut(&mut addr.sun_path[..]);
-            sun_path[..name.len()].copy_from_slice(name.as_bytes());
-            sun_path[name.len()] = 0;
-            let socket = uapi::socket(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0)
-                .map_err(|e| StateErrorKind::CreateSocket(e.into()))?;
-            uapi::connect(socket.raw(), &addr)
-                .map_err(|e| StateErrorKind::Connect(name.to_string(), e.into()))?;
-            Some(Rc::new(socket.into()))
+            _ => None,
         };
+        let recorder = self
+            .recorder
+            .map(|(writer, format)| Recorder::new(writer, format));
         let mut endpoints = HashMap::new();
         let mut server = None;
         if let Some(server_fd) = &server_fd {
-            let s = Endpoint::new(StaticPollableIds::Server as u64, server_fd);
+            let id = StaticPollableIds::Server as u64;
+            let endpoint_recorder = recorder.as_ref().map(|r| r.endpoint(id, Peer::Server));
+            #[allow(unused_mut)]
+            let mut s = Endpoint::create(id, server_fd, endpoint_recorder);
+            #[cfg(feature = "recovery")]
+            if let Some(recovery) = &recovery {
+                s.set_journal(&recovery.journal);
+            }
+            #[cfg(feature = "network")]
+            if network {
+                s.set_network(true);
+            }
+            let s = Rc::new(s);
             s.idl.acquire();
             s.idl.acquire();
             endpoints.insert(
@@ -144,7 +226,7 @@ impl StateBuilder {
         let poller = Poller::new().map_err(StateErrorKind::PollError)?;
         #[cfg(feature = "logging")]
         let log_prefix = {
-            use {crate::utils::env::WL_PROXY_PREFIX, isnt::std_1::string::IsntStringExt};
+            use crate::utils::env::WL_PROXY_PREFIX;
             let mut log_prefix = String::new();
             if let Ok(prefix) = var(WL_PROXY_PREFIX) {
                 log_prefix = prefix;
@@ -155,9 +237,6 @@ impl StateBuilder {
                 }
                 log_prefix.push_str(&self.log_prefix);
             }
-            if log_prefix.is_not_empty() {
-                log_prefix = format!("{{{}}} ", log_prefix);
-            }
             log_prefix
         };
         let state = Rc::new(State {
@@ -191,6 +270,14 @@ impl StateBuilder {
                 1024,
                 uapi::Fd::new(c::STDERR_FILENO),
             )),
+            #[cfg(feature = "logging")]
+            log_filter: self
+                .log_filter
+                .filter(|f| f != "1")
+                .map(|f| LogFilter::parse(&f)),
+            #[cfg(feature = "logging")]
+            log_sink: self.log_sink.map(RefCell::new),
+            interceptor: self.interceptor.map(RefCell::new),
             global_lock_held: Default::default(),
             object_stash: Default::default(),
             forward_to_client: Cell::new(true),
@@ -199,18 +286,33 @@ impl StateBuilder {
             unsuspend_requests: Default::default(),
             has_unsuspend_requests: Default::default(),
             unsuspend_triggered: Default::default(),
+            unknown_interfaces: UnknownInterfaces::new(
+                self.unknown_interfaces,
+                self.verbatim_fds,
+                self.unknown_interface_descriptions,
+            ),
+            recorder,
+            #[cfg(feature = "protocol-security_context_v1")]
+            security_context,
+            #[cfg(feature = "recovery")]
+            recovery,
+            delete_ids_on_destroy: Default::default(),
         });
         if let Some(server) = &state.server {
             state.change_interest(server, |i| i | poll::READABLE);
             state
                 .poller
-                .register(server.id, server.socket.as_fd())
+                .register(server.id, server.socket.borrow().as_fd())
                 .map_err(StateErrorKind::PollError)?;
             let display = WlDisplay::new(&state, 1);
             display
                 .core()
                 .set_server_id_unchecked(1, display.clone())
                 .unwrap();
+            #[cfg(feature = "recovery")]
+            if let Some(recovery) = &state.recovery {
+                recovery.journal.set_endpoint(server);
+            }
         }
         state
             .poller
@@ -235,6 +337,63 @@ impl StateBuilder {
         self
     }
 
+    /// Sets a network connection to the server.
+    ///
+    /// The connection must be a stream socket, such as a TCP or vsock connection, whose
+    /// other end is a client of a state that was added with
+    /// [`State::add_network_client`]. See the [`network`](crate::network) module for
+    /// details.
+    #[cfg(feature = "network")]
+    pub fn with_network_server(mut self, fd: &Rc<OwnedFd>) -> Self {
+        self.server = Some(Server::Network(fd.clone()));
+        self
+    }
+
+    /// Connects to the server via a security context listener.
+    ///
+    /// Each call to [`Self::build`] creates a new connection to the listener. The
+    /// [`SecurityContext`](crate::security_context::SecurityContext) of the listener
+    /// is available via [`Client::security_context`](crate::client::Client::security_context)
+    /// for the clients of the state, and acceptors created with
+    /// [`State::create_acceptor`](crate::state::State::create_acceptor) are associated
+    /// with the listener.
+    #[cfg(feature = "protocol-security_context_v1")]
+    pub fn with_security_context(mut self, listener: &Rc<SecurityContextListener>) -> Self {
+        self.server = Some(Server::SecurityContext(listener.clone()));
+        self
+    }
+
+    /// Enables recovery from server hangups.
+    ///
+    /// If the server hangs up the connection, the state connects to the server again
+    /// instead of being destroyed. Connection attempts are retried until the timeout
+    /// has expired. The thread is blocked while the state reconnects. See the
+    /// [`recovery`](crate::recovery) module for details.
+    ///
+    /// The state reconnects to the same display name or security context listener that
+    /// was used to build it. If the server is given as a file descriptor or via the
+    /// `WAYLAND_SOCKET` environment variable, [`Self::with_recovery_connector`] must be
+    /// used instead. Otherwise [`Self::build`] returns an error.
+    #[cfg(feature = "recovery")]
+    pub fn with_recovery(mut self, timeout: Duration) -> Self {
+        self.recovery = Some((timeout, None));
+        self
+    }
+
+    /// Enables recovery from server hangups with a custom connector.
+    ///
+    /// This is the same as [`Self::with_recovery`] except that new connections are
+    /// created by calling `connector`.
+    #[cfg(feature = "recovery")]
+    pub fn with_recovery_connector(
+        mut self,
+        timeout: Duration,
+        connector: impl FnMut() -> io::Result<OwnedFd> + 'static,
+    ) -> Self {
+        self.recovery = Some((timeout, Some(Box::new(connector))));
+        self
+    }
+
     /// Sets the server display name to connect to.
     pub fn with_server_display_name(mut self, name: &str) -> Self {
         self.server = Some(Server::DisplayName(name.to_owned()));
@@ -244,7 +403,8 @@ impl StateBuilder {
     /// Enables or disables logging.
     ///
     /// If this function is not used, then logging is enabled if and only if the
-    /// `WL_PROXY_DEBUG` environment variable is set to `1`.
+    /// `WL_PROXY_DEBUG` environment variable is set and neither empty nor `0`. See
+    /// [`Self::with_log_filter`].
     pub fn with_logging(mut self, log: bool) -> Self {
         self.log = log;
         self
@@ -255,4 +415,166 @@ impl StateBuilder {
         self.log_prefix = prefix.to_string();
         self
     }
+
+    /// Sets a filter for logged messages.
+    ///
+    /// The filter is a comma-separated list of directives. Each directive has the form
+    /// `interface` or `interface.message` and can be prefixed with `-` to disable
+    /// logging of matching messages. Interface and message names can contain `*`
+    /// wildcards. The directive `1` matches all messages.
+    ///
+    /// If multiple directives match a message, the last one takes precedence. If no
+    /// directive matches a message, the message is logged if and only if all directives
+    /// are prefixed with `-`.
+    ///
+    /// For example, `xdg_*,-xdg_surface.ack_configure` logs all messages of xdg_*
+    /// interfaces except for `xdg_surface.ack_configure` and `1,-wl_pointer.motion`
+    /// logs all messages except for `wl_pointer.motion`.
+    ///
+    /// This also enables logging.
+    ///
+    /// If this function is not used, then the filter is taken from the `WL_PROXY_DEBUG`
+    /// environment variable. Logging is enabled by default if that variable is set and
+    /// neither empty nor `0`.
+    #[cfg(feature = "logging")]
+    pub fn with_log_filter(mut self, filter: &str) -> Self {
+        self.log = true;
+        self.log_filter = Some(filter.to_string());
+        self
+    }
+
+    /// Passes logged messages to a sink instead of writing them to STDERR.
+    ///
+    /// This also enables logging. See the [`logging`](crate::logging) module for
+    /// details.
+    #[cfg(feature = "logging")]
+    pub fn with_log_sink(mut self, sink: impl LogSink + 'static) -> Self {
+        self.log = true;
+        self.log_sink = Some(Box::new(sink));
+        self
+    }
+
+    /// Installs an interceptor that sees every message before it is dispatched.
+    ///
+    /// The interceptor is invoked for every request received from a client and every
+    /// event received from the server before the message is passed to the handler of
+    /// the object. See the [`interceptor`](crate::interceptor) module for details.
+    pub fn with_interceptor(mut self, interceptor: impl MessageInterceptor + 'static) -> Self {
+        self.interceptor = Some(Box::new(interceptor));
+        self
+    }
+
+    /// Enables or disables forwarding of globals with unknown interfaces.
+    ///
+    /// By default, globals whose interface is not known to this crate are not forwarded
+    /// to clients. If this is enabled, such globals are forwarded and objects with such
+    /// interfaces are represented as [`UnknownObject`](crate::unknown::UnknownObject)s.
+    ///
+    /// Unless the interface has been described with [`Self::with_unknown_interface`],
+    /// messages of such objects are forwarded verbatim. See the
+    /// [module documentation](crate::unknown) for the limitations.
+    pub fn with_unknown_interfaces(mut self, forward: bool) -> Self {
+        self.unknown_interfaces = forward;
+        self
+    }
+
+    /// Enables or disables forwarding of file descriptors with verbatim messages.
+    ///
+    /// By default, messages of unknown objects whose interface has not been described
+    /// with [`Self::with_unknown_interface`] are forwarded without file descriptors. If
+    /// this is enabled, all file descriptors that have been received but not yet
+    /// consumed are forwarded with such messages instead. This is only correct if no
+    /// other message that has been received at the same time carries file descriptors.
+    pub fn with_verbatim_fds(mut self, forward: bool) -> Self {
+        self.verbatim_fds = forward;
+        self
+    }
+
+    /// Adds a description of an interface that is not known to this crate.
+    ///
+    /// Globals with this interface are forwarded even if
+    /// [`Self::with_unknown_interfaces`] has not been used.
+    ///
+    /// Descriptions of interfaces that are known to this crate are ignored.
+    pub fn with_unknown_interface(mut self, interface: UnknownInterface) -> Self {
+        self.unknown_interface_descriptions.push(interface);
+        self
+    }
+
+    /// Records all messages received and sent by this state.
+    ///
+    /// The messages are written to the writer in a compact binary format that can be
+    /// loaded with [`Capture::parse`](crate::recording::Capture::parse). See the
+    /// [`recording`](crate::recording) module for details.
+    ///
+    /// This replaces the writer set with [`Self::with_pcapng_recorder`]. If writing to
+    /// the writer fails, an error is logged and recording stops.
+    pub fn with_recorder(mut self, writer: impl Write + 'static) -> Self {
+        self.recorder = Some((Box::new(writer), RecordingFormat::Capture));
+        self
+    }
+
+    /// Records all messages received and sent by this state in the pcapng format.
+    ///
+    /// The resulting file can be opened with Wireshark. See the
+    /// [`recording`](crate::recording) module for details.
+    ///
+    /// This replaces the writer set with [`Self::with_recorder`]. If writing to the
+    /// writer fails, an error is logged and recording stops.
+    pub fn with_pcapng_recorder(mut self, writer: impl Write + 'static) -> Self {
+        self.recorder = Some((Box::new(writer), RecordingFormat::Pcapng));
+        self
+    }
+
+    /// Adds the interfaces of a protocol loaded at runtime.
+    ///
+    /// This is the same as calling [`Self::with_unknown_interface`] for each interface
+    /// of the protocol.
+    #[cfg(feature = "dynamic")]
+    pub fn with_dynamic_protocol(mut self, protocol: &crate::dynamic::DynamicProtocol) -> Self {
+        self.unknown_interface_descriptions
+            .extend(protocol.interfaces().iter().cloned());
+        self
+    }
+}
+
+/// Resolves a display name to the path and address of its socket.
+pub(super) fn display_addr(mut name: String) -> Result<(String, sockaddr_un), StateErrorKind> {
+    if name.is_empty() {
+        return Err(StateErrorKind::WaylandDisplayEmpty);
+    }
+    if !name.starts_with("/") {
+        let Ok(xrd) = var(XDG_RUNTIME_DIR) else {
+            return Err(StateErrorKind::XrdNotSet);
+        };
+        name = format!("{xrd}/{name}");
+    }
+    let mut addr = sockaddr_un {
+        sun_family: c::AF_UNIX as _,
+        sun_path: [0; 108],
+    };
+    if name.len() > addr.sun_path.len() - 1 {
+        return Err(StateErrorKind::SocketPathTooLong);
+    }
+    let sun_path = uapi::as_bytes_mut(&mut addr.sun_path[..]);
+    sun_path[..name.len()].copy_from_slice(name.as_bytes());
+    sun_path[name.len()] = 0;
+    Ok((name, addr))
+}
+
+pub(super) fn connect_display(name: &str, addr: &sockaddr_un) -> Result<OwnedFd, StateErrorKind> {
+    let socket = uapi::socket(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0)
+        .map_err(|e| StateErrorKind::CreateSocket(e.into()))?;
+    uapi::connect(socket.raw(), addr)
+        .map_err(|e| StateErrorKind::Connect(name.to_string(), e.into()))?;
+    Ok(socket.into())
+}
+
+#[cfg(feature = "recovery")]
+pub(super) fn display_connector(addr: sockaddr_un) -> Connector {
+    Box::new(move || {
+        let socket = uapi::socket(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0)?;
+        uapi::connect(socket.raw(), &addr)?;
+        Ok(socket.into())
+    })
 }
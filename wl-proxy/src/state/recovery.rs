@@ -0,0 +1,225 @@
+use {
+    crate::{
+        endpoint::{Endpoint, EndpointError},
+        object::{MIN_SERVER_ID, ObjectCoreApi},
+        poll,
+        protocols::wayland::wl_callback::{WlCallback, WlCallbackHandler},
+        recovery::{Recovery, stub},
+        state::{
+            HandlerLock, State, StateError, StateErrorKind,
+            builder::{connect_display, display_addr, display_connector},
+        },
+        trans::{FlushResult, TransError},
+    },
+    run_on_drop::on_drop,
+    std::{
+        cell::Cell,
+        collections::HashSet,
+        os::fd::{AsFd, AsRawFd, OwnedFd},
+        rc::Rc,
+        time::Instant,
+    },
+    uapi::c,
+};
+
+/// These functions can be used to move clients between servers.
+///
+/// These functions require the `recovery` feature and that recovery has been enabled
+/// with [`StateBuilder::with_recovery`](crate::state::StateBuilder::with_recovery) or
+/// [`StateBuilder::with_recovery_connector`](crate::state::StateBuilder::with_recovery_connector).
+/// See the [`recovery`](crate::recovery) module for details.
+impl State {
+    /// Detaches the clients from the server.
+    ///
+    /// The server connection is replaced by a connection to a built-in stub server that
+    /// keeps the clients alive until [`Self::reattach`] is called.
+    ///
+    /// This function is not reentrant. It should not be called from within a callback.
+    pub fn detach(self: &Rc<Self>) -> Result<(), StateError> {
+        let recovery = self.recovery()?;
+        if self.is_detached() {
+            return Err(StateErrorKind::AlreadyDetached.into());
+        }
+        let lock = self.acquire_handler_lock()?;
+        let destroy_on_error = on_drop(|| self.destroy());
+        let (socket, stub) =
+            stub::spawn(recovery.journal.globals()).map_err(StateErrorKind::StartStub)?;
+        self.switch_server(&lock, recovery, socket)?;
+        *recovery.stub.borrow_mut() = Some(stub);
+        self.flush_locked(&lock)?;
+        destroy_on_error.forget();
+        Ok(())
+    }
+
+    /// Attaches the clients to the server with the given display name.
+    ///
+    /// The objects of the clients are recreated on that server. If the state is not
+    /// detached, the clients are moved from the current server to the new server.
+    ///
+    /// If the new server hangs up the connection, the state reconnects to this
+    /// display name.
+    ///
+    /// This function is not reentrant. It should not be called from within a callback.
+    pub fn reattach(self: &Rc<Self>, display: &str) -> Result<(), StateError> {
+        let recovery = self.recovery()?;
+        let (name, addr) = display_addr(display.to_string())?;
+        let socket = connect_display(&name, &addr)?;
+        let lock = self.acquire_handler_lock()?;
+        let destroy_on_error = on_drop(|| self.destroy());
+        self.switch_server(&lock, recovery, socket)?;
+        recovery.set_connector(display_connector(addr));
+        recovery.stub.take();
+        self.flush_locked(&lock)?;
+        destroy_on_error.forget();
+        Ok(())
+    }
+
+    /// Returns whether the clients are currently attached to the stub server.
+    pub fn is_detached(&self) -> bool {
+        match &self.recovery {
+            Some(recovery) => recovery.stub.borrow().is_some(),
+            None => false,
+        }
+    }
+
+    fn recovery(&self) -> Result<&Recovery, StateError> {
+        self.check_destroyed()?;
+        self.recovery
+            .as_ref()
+            .ok_or(StateErrorKind::RecoveryNotEnabled.into())
+    }
+}
+
+impl State {
+    /// Schedules a recovery after the server connection has been lost.
+    ///
+    /// Returns `false` if recovery is not enabled.
+    pub(super) fn request_recovery(&self) -> bool {
+        let Some(recovery) = &self.recovery else {
+            return false;
+        };
+        recovery.pending.set(true);
+        true
+    }
+
+    /// Performs a scheduled recovery.
+    pub(super) fn recover(self: &Rc<Self>, lock: &HandlerLock<'_>) -> Result<bool, StateError> {
+        let Some(recovery) = &self.recovery else {
+            return Ok(false);
+        };
+        if !recovery.pending.replace(false) {
+            return Ok(false);
+        }
+        log::warn!("The server hung up the connection. Reconnecting.");
+        let deadline = recovery.deadline();
+        let socket = recovery
+            .connect(deadline)
+            .map_err(StateErrorKind::Reconnect)?;
+        self.switch_server(lock, recovery, socket)?;
+        if let Some(handler) = &mut *self.handler.borrow_mut() {
+            handler.server_recovered();
+        }
+        Ok(true)
+    }
+
+    /// Replaces the server connection and recreates the recorded objects.
+    fn switch_server(
+        self: &Rc<Self>,
+        lock: &HandlerLock<'_>,
+        recovery: &Recovery,
+        socket: OwnedFd,
+    ) -> Result<(), StateError> {
+        let server = self.server.as_ref().unwrap();
+        let deadline = recovery.deadline();
+        let socket = Rc::new(socket);
+        let journal = &recovery.journal;
+        journal.retire(self, server);
+        self.poller.unregister(server.socket.borrow().as_fd());
+        let mut objects = server.reset(&socket);
+        self.poller
+            .register(server.id, socket.as_fd())
+            .map_err(StateErrorKind::PollError)?;
+        self.change_interest(server, |i| i | poll::READABLE);
+        if let Some(display) = objects.remove(&1) {
+            server.objects.borrow_mut().insert(1, display);
+        }
+        let mut live = HashSet::new();
+        for (id, object) in &objects {
+            object.core().server_obj_id.take();
+            if *id < MIN_SERVER_ID {
+                live.insert(object.unique_id());
+            }
+        }
+        let suspended = server.suspended.replace(false);
+        let res = (|| {
+            journal.probe(self);
+            self.server_roundtrip(lock, server, deadline)?;
+            journal.replay(server, &live);
+            self.server_roundtrip(lock, server, deadline)
+        })();
+        journal.finish();
+        server.suspended.set(suspended);
+        drop(objects);
+        res?;
+        self.update_interests()
+    }
+
+    fn server_roundtrip(
+        self: &Rc<Self>,
+        lock: &HandlerLock<'_>,
+        server: &Endpoint,
+        deadline: Option<Instant>,
+    ) -> Result<(), StateError> {
+        let done = Rc::new(Cell::new(false));
+        self.display()
+            .new_send_sync()
+            .set_handler(SyncHandler { done: done.clone() });
+        let mut events = c::POLLIN;
+        loop {
+            match server.flush() {
+                Ok(FlushResult::Done) => events &= !c::POLLOUT,
+                Ok(FlushResult::Blocked) => events |= c::POLLOUT,
+                Err(EndpointError::Flush(TransError::Closed)) => {
+                    return Err(StateErrorKind::ServerHangup.into());
+                }
+                Err(e) => return Err(StateErrorKind::WriteToServer(e).into()),
+            }
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    timeout.as_millis().try_into().unwrap_or(-1)
+                }
+                None => -1,
+            };
+            let mut pollfd = [c::pollfd {
+                fd: server.socket.borrow().as_raw_fd(),
+                events,
+                revents: 0,
+            }];
+            let n = uapi::poll(&mut pollfd, timeout)
+                .map_err(|e| StateErrorKind::Reconnect(e.into()))?;
+            if n == 0 {
+                return Err(StateErrorKind::RecoveryTimeout.into());
+            }
+            server
+                .read_messages(lock, None)
+                .map_err(StateErrorKind::DispatchEvents)?;
+            if done.get() {
+                return Ok(());
+            }
+            if pollfd[0].revents & (c::POLLHUP | c::POLLERR) != 0 {
+                return Err(StateErrorKind::ServerHangup.into());
+            }
+        }
+    }
+}
+
+struct SyncHandler {
+    done: Rc<Cell<bool>>,
+}
+
+impl WlCallbackHandler for SyncHandler {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.done.set(true);
+    }
+}
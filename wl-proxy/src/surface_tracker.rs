@@ -0,0 +1,1069 @@
+//! A tracker for the double-buffered state of wl_surfaces.
+//!
+//! Most requests sent to a wl_surface only modify the pending state of the surface.
+//! The pending state is applied atomically when the surface is committed. For
+//! synchronized subsurfaces, the committed state is cached and only applied when the
+//! parent applies its own state. [`SurfaceTracker`] models these semantics and
+//! provides a snapshot of the state that is currently applied for each surface.
+//!
+//! # Integration
+//!
+//! Each surface is represented by a [`TrackedSurface`] that is created via
+//! [`SurfaceTracker::create_surface`]. Proxies that implement their own
+//! [`WlSurfaceHandler`] call the functions of the [`TrackedSurface`] that correspond to
+//! the requests they receive:
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use wl_proxy::{
+//! #     protocols::wayland::wl_surface::{WlSurface, WlSurfaceHandler},
+//! #     surface_tracker::TrackedSurface,
+//! # };
+//! struct Surface {
+//!     tracked: Rc<TrackedSurface>,
+//! }
+//!
+//! impl WlSurfaceHandler for Surface {
+//!     fn handle_set_buffer_scale(&mut self, slf: &Rc<WlSurface>, scale: i32) {
+//!         self.tracked.set_buffer_scale(scale);
+//!         slf.send_set_buffer_scale(scale);
+//!     }
+//!
+//!     fn handle_commit(&mut self, slf: &Rc<WlSurface>) {
+//!         self.tracked.commit();
+//!         slf.send_commit();
+//!         let scale = self.tracked.current().buffer_scale;
+//!         # let _ = scale;
+//!     }
+//!
+//!     // ...
+//! }
+//! ```
+//!
+//! Proxies that do not need their own handlers can use
+//! [`SurfaceTracker::track_compositor`] instead. This installs handlers on the
+//! wl_compositor and on all surfaces and regions created from it. These handlers
+//! forward all messages.
+//!
+//! The state of regions, subsurfaces, viewports, and fractional scale objects is
+//! tracked by installing handlers on the respective objects. See
+//! [`SurfaceTracker::track_region`], [`SurfaceTracker::track_subcompositor`],
+//! [`SurfaceTracker::track_viewporter`], and
+//! [`SurfaceTracker::track_fractional_scale_manager`].
+//!
+//! Whenever a new state is applied to a surface, [`SurfaceTrackerHandler::applied`] is
+//! invoked.
+
+#[cfg(feature = "protocol-fractional_scale_v1")]
+use crate::protocols::fractional_scale_v1::{
+    wp_fractional_scale_manager_v1::{
+        WpFractionalScaleManagerV1, WpFractionalScaleManagerV1Handler,
+    },
+    wp_fractional_scale_v1::{WpFractionalScaleV1, WpFractionalScaleV1Handler},
+};
+#[cfg(feature = "protocol-viewporter")]
+use crate::protocols::viewporter::{
+    wp_viewport::{WpViewport, WpViewportHandler},
+    wp_viewporter::{WpViewporter, WpViewporterHandler},
+};
+use {
+    crate::{
+        fixed::Fixed,
+        handler::HandlerHolder,
+        object::{ObjectCoreApi, ObjectUtils},
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_buffer::WlBuffer,
+                wl_callback::WlCallback,
+                wl_compositor::{WlCompositor, WlCompositorHandler},
+                wl_output::WlOutputTransform,
+                wl_region::{WlRegion, WlRegionHandler},
+                wl_subcompositor::{WlSubcompositor, WlSubcompositorHandler},
+                wl_subsurface::{WlSubsurface, WlSubsurfaceHandler},
+                wl_surface::{WlSurface, WlSurfaceHandler},
+            },
+        },
+    },
+    error_reporter::Report,
+    std::{
+        cell::{Cell, RefCell},
+        collections::BTreeMap,
+        mem,
+        rc::{Rc, Weak},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A tracker for the state of wl_surfaces.
+///
+/// Usually there is one tracker per client.
+pub struct SurfaceTracker {
+    surfaces: RefCell<BTreeMap<u64, Weak<TrackedSurface>>>,
+    handler: HandlerHolder<dyn SurfaceTrackerHandler>,
+}
+
+/// A handler for events emitted by a [`SurfaceTracker`].
+pub trait SurfaceTrackerHandler: 'static {
+    /// A new state has been applied to a surface.
+    ///
+    /// For most surfaces, this happens when the surface is committed. For synchronized
+    /// subsurfaces, this happens when the parent applies its state.
+    ///
+    /// The new state can be retrieved via [`TrackedSurface::current`].
+    fn applied(&mut self, surface: &Rc<TrackedSurface>) {
+        let _ = surface;
+    }
+}
+
+/// A wl_surface tracked by a [`SurfaceTracker`].
+pub struct TrackedSurface {
+    tracker: Weak<SurfaceTracker>,
+    id: u64,
+    wl_surface: Weak<WlSurface>,
+    pending: RefCell<PendingState>,
+    cached: RefCell<Option<PendingState>>,
+    current: RefCell<Rc<SurfaceState>>,
+    role: Cell<Option<ObjectInterface>>,
+    subsurface: RefCell<Option<Subsurface>>,
+    /// The surface and its subsurfaces, from bottom to top.
+    stack: RefCell<Vec<Weak<TrackedSurface>>>,
+    pending_stack: RefCell<Option<Vec<Weak<TrackedSurface>>>>,
+    preferred_scale: Cell<Option<u32>>,
+    destroyed: Cell<bool>,
+}
+
+struct Subsurface {
+    parent: Weak<TrackedSurface>,
+    sync: bool,
+    position: (i32, i32),
+    pending_position: Option<(i32, i32)>,
+}
+
+/// The state of a surface.
+///
+/// A snapshot of the applied state can be retrieved via [`TrackedSurface::current`].
+/// Rectangles are given as `[x, y, width, height]`.
+#[derive(Clone, Debug)]
+pub struct SurfaceState {
+    /// The attached buffer.
+    pub buffer: Option<Rc<WlBuffer>>,
+    /// Whether this state was created by a commit that attached a buffer.
+    ///
+    /// This is also true if the same buffer was attached again or if the buffer was
+    /// detached.
+    pub buffer_attached: bool,
+    /// The offset set by this state via wl_surface.attach or wl_surface.offset.
+    pub offset: (i32, i32),
+    /// The damage in surface coordinates added by this state.
+    pub surface_damage: Vec<[i32; 4]>,
+    /// The damage in buffer coordinates added by this state.
+    pub buffer_damage: Vec<[i32; 4]>,
+    /// The buffer scale.
+    pub buffer_scale: i32,
+    /// The buffer transform.
+    pub buffer_transform: WlOutputTransform,
+    /// The opaque region. `None` if the region is empty.
+    pub opaque_region: Option<Rc<Region>>,
+    /// The input region. `None` if the region is infinite.
+    pub input_region: Option<Rc<Region>>,
+    /// The source rectangle set via wp_viewport.set_source.
+    pub viewport_source: Option<[Fixed; 4]>,
+    /// The destination size set via wp_viewport.set_destination.
+    pub viewport_destination: Option<(i32, i32)>,
+    /// The frame callbacks added by this state.
+    pub frame_callbacks: Vec<Rc<WlCallback>>,
+}
+
+#[derive(Default)]
+struct PendingState {
+    buffer: Option<Option<Rc<WlBuffer>>>,
+    offset: Option<(i32, i32)>,
+    surface_damage: Vec<[i32; 4]>,
+    buffer_damage: Vec<[i32; 4]>,
+    buffer_scale: Option<i32>,
+    buffer_transform: Option<WlOutputTransform>,
+    opaque_region: Option<Option<Rc<Region>>>,
+    input_region: Option<Option<Rc<Region>>>,
+    viewport_source: Option<Option<[Fixed; 4]>>,
+    viewport_destination: Option<Option<(i32, i32)>>,
+    frame_callbacks: Vec<Rc<WlCallback>>,
+}
+
+/// A region built from wl_region requests.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Region {
+    operations: Vec<RegionOperation>,
+}
+
+/// An operation applied to a [`Region`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RegionOperation {
+    /// A rectangle `[x, y, width, height]` was added to the region.
+    Add([i32; 4]),
+    /// A rectangle `[x, y, width, height]` was subtracted from the region.
+    Subtract([i32; 4]),
+}
+
+/// An error emitted by a [`SurfaceTracker`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct SurfaceTrackerError(#[from] SurfaceTrackerErrorKind);
+
+#[derive(Debug, Error)]
+enum SurfaceTrackerErrorKind {
+    #[error("the surface already has the role {}", .0.name())]
+    HasRole(ObjectInterface),
+    #[error("the surface is not tracked")]
+    NotTracked,
+    #[error("the surface already has a subsurface object")]
+    HasSubsurface,
+    #[error("the parent is the surface itself or one of its subsurfaces")]
+    Cycle,
+    #[error("the sibling is neither the parent nor a sibling of the surface")]
+    NotSibling,
+    #[error("the surface is not a subsurface")]
+    NotSubsurface,
+}
+
+struct CompositorTracker(Rc<SurfaceTracker>);
+
+struct SurfaceForwarder(Rc<TrackedSurface>);
+
+struct RegionTracker(Region);
+
+struct SubcompositorTracker(Rc<SurfaceTracker>);
+
+struct SubsurfaceTracker {
+    tracker: Rc<SurfaceTracker>,
+    surface: Rc<TrackedSurface>,
+}
+
+#[cfg(feature = "protocol-viewporter")]
+struct ViewporterTracker(Rc<SurfaceTracker>);
+
+#[cfg(feature = "protocol-viewporter")]
+struct ViewportTracker(Option<Rc<TrackedSurface>>);
+
+#[cfg(feature = "protocol-fractional_scale_v1")]
+struct FractionalScaleManagerTracker(Rc<SurfaceTracker>);
+
+#[cfg(feature = "protocol-fractional_scale_v1")]
+struct FractionalScaleTracker(Option<Rc<TrackedSurface>>);
+
+impl Default for SurfaceState {
+    fn default() -> Self {
+        Self {
+            buffer: None,
+            buffer_attached: false,
+            offset: (0, 0),
+            surface_damage: vec![],
+            buffer_damage: vec![],
+            buffer_scale: 1,
+            buffer_transform: WlOutputTransform::NORMAL,
+            opaque_region: None,
+            input_region: None,
+            viewport_source: None,
+            viewport_destination: None,
+            frame_callbacks: vec![],
+        }
+    }
+}
+
+impl SurfaceTracker {
+    /// Creates a new tracker.
+    pub fn new() -> Rc<Self> {
+        Rc::new(Self {
+            surfaces: Default::default(),
+            handler: Default::default(),
+        })
+    }
+
+    /// Sets the handler of the tracker.
+    pub fn set_handler(&self, handler: impl SurfaceTrackerHandler) {
+        self.handler.set(Some(Box::new(handler)));
+    }
+
+    /// Unsets the handler of the tracker.
+    pub fn unset_handler(&self) {
+        self.handler.set(None);
+    }
+
+    /// Starts tracking a surface.
+    ///
+    /// This should be called from [`WlCompositorHandler::handle_create_surface`]. The
+    /// handler of the surface has to forward the requests it receives to the returned
+    /// object.
+    pub fn create_surface(self: &Rc<Self>, surface: &Rc<WlSurface>) -> Rc<TrackedSurface> {
+        let tracked = Rc::new_cyclic(|slf: &Weak<TrackedSurface>| TrackedSurface {
+            tracker: Rc::downgrade(self),
+            id: surface.unique_id(),
+            wl_surface: Rc::downgrade(surface),
+            pending: Default::default(),
+            cached: Default::default(),
+            current: Default::default(),
+            role: Default::default(),
+            subsurface: Default::default(),
+            stack: RefCell::new(vec![slf.clone()]),
+            pending_stack: Default::default(),
+            preferred_scale: Default::default(),
+            destroyed: Default::default(),
+        });
+        self.surfaces
+            .borrow_mut()
+            .insert(tracked.id, Rc::downgrade(&tracked));
+        tracked
+    }
+
+    /// Starts tracking a surface and installs a handler that forwards all requests.
+    pub fn track_surface(self: &Rc<Self>, surface: &Rc<WlSurface>) -> Rc<TrackedSurface> {
+        let tracked = self.create_surface(surface);
+        surface.set_handler(SurfaceForwarder(tracked.clone()));
+        tracked
+    }
+
+    /// Returns the tracked surface of a wl_surface.
+    pub fn get(&self, surface: &WlSurface) -> Option<Rc<TrackedSurface>> {
+        self.surfaces.borrow().get(&surface.unique_id())?.upgrade()
+    }
+
+    /// Returns all tracked surfaces in the order in which they were created.
+    pub fn surfaces(&self) -> Vec<Rc<TrackedSurface>> {
+        self.surfaces
+            .borrow()
+            .values()
+            .filter_map(|s| s.upgrade())
+            .collect()
+    }
+
+    /// Installs a handler on a wl_compositor that tracks all surfaces and regions
+    /// created from it.
+    ///
+    /// The handlers forward all messages.
+    pub fn track_compositor(self: &Rc<Self>, compositor: &Rc<WlCompositor>) {
+        compositor.set_handler(CompositorTracker(self.clone()));
+    }
+
+    /// Installs a handler on a wl_region that tracks its contents.
+    ///
+    /// The handler forwards all messages. Regions that are not tracked are treated as
+    /// empty when they are used.
+    pub fn track_region(&self, region: &Rc<WlRegion>) {
+        region.set_handler(RegionTracker(Region::default()));
+    }
+
+    /// Installs a handler on a wl_subcompositor that tracks all subsurfaces created
+    /// from it.
+    ///
+    /// The handlers forward all messages.
+    pub fn track_subcompositor(self: &Rc<Self>, subcompositor: &Rc<WlSubcompositor>) {
+        subcompositor.set_handler(SubcompositorTracker(self.clone()));
+    }
+
+    /// Installs a handler on a wp_viewporter that tracks all viewports created from
+    /// it.
+    ///
+    /// The handlers forward all messages.
+    #[cfg(feature = "protocol-viewporter")]
+    pub fn track_viewporter(self: &Rc<Self>, viewporter: &Rc<WpViewporter>) {
+        viewporter.set_handler(ViewporterTracker(self.clone()));
+    }
+
+    /// Installs a handler on a wp_fractional_scale_manager_v1 that tracks the preferred
+    /// scales of surfaces.
+    ///
+    /// The handlers forward all messages.
+    #[cfg(feature = "protocol-fractional_scale_v1")]
+    pub fn track_fractional_scale_manager(
+        self: &Rc<Self>,
+        manager: &Rc<WpFractionalScaleManagerV1>,
+    ) {
+        manager.set_handler(FractionalScaleManagerTracker(self.clone()));
+    }
+
+    /// Makes a surface a subsurface of another surface.
+    ///
+    /// This should be called from [`WlSubcompositorHandler::handle_get_subsurface`] by
+    /// proxies that do not use [`Self::track_subcompositor`].
+    pub fn get_subsurface(
+        &self,
+        surface: &WlSurface,
+        parent: &WlSurface,
+    ) -> Result<Rc<TrackedSurface>, SurfaceTrackerError> {
+        let surface = self
+            .get(surface)
+            .ok_or(SurfaceTrackerErrorKind::NotTracked)?;
+        let parent = self
+            .get(parent)
+            .ok_or(SurfaceTrackerErrorKind::NotTracked)?;
+        if surface.subsurface.borrow().is_some() {
+            return Err(SurfaceTrackerErrorKind::HasSubsurface.into());
+        }
+        if parent.is_descendant_of(&surface) {
+            return Err(SurfaceTrackerErrorKind::Cycle.into());
+        }
+        surface.set_role(ObjectInterface::WlSubsurface)?;
+        *surface.subsurface.borrow_mut() = Some(Subsurface {
+            parent: Rc::downgrade(&parent),
+            sync: true,
+            position: (0, 0),
+            pending_position: None,
+        });
+        parent.stack.borrow_mut().push(Rc::downgrade(&surface));
+        if let Some(stack) = &mut *parent.pending_stack.borrow_mut() {
+            stack.push(Rc::downgrade(&surface));
+        }
+        Ok(surface)
+    }
+
+    fn applied(&self, surface: &Rc<TrackedSurface>) {
+        if let Some(mut handler) = self.handler.try_borrow_mut()
+            && let Some(handler) = &mut *handler
+        {
+            handler.applied(surface);
+        }
+    }
+}
+
+impl TrackedSurface {
+    /// Returns the wl_surface.
+    pub fn wl_surface(&self) -> Option<Rc<WlSurface>> {
+        self.wl_surface.upgrade()
+    }
+
+    /// Returns the currently applied state.
+    pub fn current(&self) -> Rc<SurfaceState> {
+        self.current.borrow().clone()
+    }
+
+    /// Returns whether the surface has committed state that has not yet been applied.
+    ///
+    /// This can only happen for synchronized subsurfaces.
+    pub fn has_cached_state(&self) -> bool {
+        self.cached.borrow().is_some()
+    }
+
+    /// Returns the role of the surface.
+    ///
+    /// The role is identified by the interface of the role object, for example,
+    /// [`ObjectInterface::WlSubsurface`] or `ObjectInterface::XdgToplevel`.
+    pub fn role(&self) -> Option<ObjectInterface> {
+        self.role.get()
+    }
+
+    /// Assigns a role to the surface.
+    ///
+    /// Subsurface roles are assigned automatically. Other roles have to be assigned by
+    /// the handlers of the respective objects. Once a surface has a role, it cannot be
+    /// assigned a different role. Assigning the same role again succeeds.
+    pub fn set_role(&self, role: ObjectInterface) -> Result<(), SurfaceTrackerError> {
+        if let Some(old) = self.role.get()
+            && old != role
+        {
+            return Err(SurfaceTrackerErrorKind::HasRole(old).into());
+        }
+        self.role.set(Some(role));
+        Ok(())
+    }
+
+    /// Returns the parent of the surface, if it is a subsurface.
+    pub fn parent(&self) -> Option<Rc<TrackedSurface>> {
+        self.subsurface.borrow().as_ref()?.parent.upgrade()
+    }
+
+    /// Returns the applied position of the surface relative to its parent.
+    ///
+    /// This is `(0, 0)` if the surface is not a subsurface.
+    pub fn position(&self) -> (i32, i32) {
+        match &*self.subsurface.borrow() {
+            Some(s) => s.position,
+            None => (0, 0),
+        }
+    }
+
+    /// Returns whether the surface is a synchronized subsurface.
+    ///
+    /// A subsurface is synchronized if it is in synchronized mode or if its parent is
+    /// synchronized.
+    pub fn is_synchronized(&self) -> bool {
+        let parent = {
+            let subsurface = &*self.subsurface.borrow();
+            let Some(subsurface) = subsurface else {
+                return false;
+            };
+            if subsurface.sync {
+                return true;
+            }
+            subsurface.parent.upgrade()
+        };
+        parent.is_some_and(|p| p.is_synchronized())
+    }
+
+    /// Returns the applied stacking order of the surface and its subsurfaces.
+    ///
+    /// The list is ordered from bottom to top and contains the surface itself.
+    pub fn stacking_order(&self) -> Vec<Rc<TrackedSurface>> {
+        self.stack
+            .borrow()
+            .iter()
+            .filter_map(|s| s.upgrade())
+            .collect()
+    }
+
+    /// Returns the preferred scale sent via wp_fractional_scale_v1.preferred_scale.
+    ///
+    /// The scale is given in multiples of 1/120.
+    pub fn preferred_scale(&self) -> Option<u32> {
+        self.preferred_scale.get()
+    }
+
+    /// Records a wp_fractional_scale_v1.preferred_scale event.
+    pub fn set_preferred_scale(&self, scale: u32) {
+        self.preferred_scale.set(Some(scale));
+    }
+
+    /// Handles a wl_surface.attach request.
+    pub fn attach(&self, buffer: Option<&Rc<WlBuffer>>, x: i32, y: i32) {
+        let pending = &mut *self.pending.borrow_mut();
+        pending.buffer = Some(buffer.cloned());
+        if (x, y) != (0, 0) {
+            pending.offset = Some((x, y));
+        }
+    }
+
+    /// Handles a wl_surface.offset request.
+    pub fn offset(&self, x: i32, y: i32) {
+        self.pending.borrow_mut().offset = Some((x, y));
+    }
+
+    /// Handles a wl_surface.damage request.
+    pub fn damage(&self, x: i32, y: i32, width: i32, height: i32) {
+        let damage = &mut self.pending.borrow_mut().surface_damage;
+        damage.push([x, y, width, height]);
+    }
+
+    /// Handles a wl_surface.damage_buffer request.
+    pub fn damage_buffer(&self, x: i32, y: i32, width: i32, height: i32) {
+        let damage = &mut self.pending.borrow_mut().buffer_damage;
+        damage.push([x, y, width, height]);
+    }
+
+    /// Handles a wl_surface.frame request.
+    pub fn frame(&self, callback: &Rc<WlCallback>) {
+        let callbacks = &mut self.pending.borrow_mut().frame_callbacks;
+        callbacks.push(callback.clone());
+    }
+
+    /// Handles a wl_surface.set_opaque_region request.
+    pub fn set_opaque_region(&self, region: Option<&Rc<WlRegion>>) {
+        self.pending.borrow_mut().opaque_region = Some(region.map(Region::get));
+    }
+
+    /// Handles a wl_surface.set_input_region request.
+    pub fn set_input_region(&self, region: Option<&Rc<WlRegion>>) {
+        self.pending.borrow_mut().input_region = Some(region.map(Region::get));
+    }
+
+    /// Handles a wl_surface.set_buffer_scale request.
+    pub fn set_buffer_scale(&self, scale: i32) {
+        self.pending.borrow_mut().buffer_scale = Some(scale);
+    }
+
+    /// Handles a wl_surface.set_buffer_transform request.
+    pub fn set_buffer_transform(&self, transform: WlOutputTransform) {
+        self.pending.borrow_mut().buffer_transform = Some(transform);
+    }
+
+    /// Handles a wp_viewport.set_source request.
+    ///
+    /// `None` unsets the source rectangle.
+    pub fn set_viewport_source(&self, source: Option<[Fixed; 4]>) {
+        self.pending.borrow_mut().viewport_source = Some(source);
+    }
+
+    /// Handles a wp_viewport.set_destination request.
+    ///
+    /// `None` unsets the destination size.
+    pub fn set_viewport_destination(&self, destination: Option<(i32, i32)>) {
+        self.pending.borrow_mut().viewport_destination = Some(destination);
+    }
+
+    /// Handles a wl_subsurface.set_position request.
+    pub fn set_subsurface_position(&self, x: i32, y: i32) -> Result<(), SurfaceTrackerError> {
+        let subsurface = &mut *self.subsurface.borrow_mut();
+        let subsurface = subsurface
+            .as_mut()
+            .ok_or(SurfaceTrackerErrorKind::NotSubsurface)?;
+        subsurface.pending_position = Some((x, y));
+        Ok(())
+    }
+
+    /// Handles a wl_subsurface.place_above request.
+    pub fn place_above(&self, sibling: &TrackedSurface) -> Result<(), SurfaceTrackerError> {
+        self.place(sibling, 1)
+    }
+
+    /// Handles a wl_subsurface.place_below request.
+    pub fn place_below(&self, sibling: &TrackedSurface) -> Result<(), SurfaceTrackerError> {
+        self.place(sibling, 0)
+    }
+
+    fn place(&self, sibling: &TrackedSurface, offset: usize) -> Result<(), SurfaceTrackerError> {
+        let parent = self
+            .parent()
+            .ok_or(SurfaceTrackerErrorKind::NotSubsurface)?;
+        if sibling.id == self.id {
+            return Err(SurfaceTrackerErrorKind::NotSibling.into());
+        }
+        let pending_stack = &mut *parent.pending_stack.borrow_mut();
+        let stack = pending_stack.get_or_insert_with(|| parent.stack.borrow().clone());
+        let is = |s: &Weak<TrackedSurface>, id: u64| s.upgrade().is_some_and(|s| s.id == id);
+        if !stack.iter().any(|s| is(s, sibling.id)) {
+            return Err(SurfaceTrackerErrorKind::NotSibling.into());
+        }
+        let Some(pos) = stack.iter().position(|s| is(s, self.id)) else {
+            return Err(SurfaceTrackerErrorKind::NotSubsurface.into());
+        };
+        let slf = stack.remove(pos);
+        let pos = stack.iter().position(|s| is(s, sibling.id)).unwrap();
+        stack.insert(pos + offset, slf);
+        Ok(())
+    }
+
+    /// Handles a wl_subsurface.set_sync or wl_subsurface.set_desync request.
+    pub fn set_sync(&self, sync: bool) -> Result<(), SurfaceTrackerError> {
+        let subsurface = &mut *self.subsurface.borrow_mut();
+        let subsurface = subsurface
+            .as_mut()
+            .ok_or(SurfaceTrackerErrorKind::NotSubsurface)?;
+        subsurface.sync = sync;
+        Ok(())
+    }
+
+    /// Handles a wl_subsurface.destroy request.
+    ///
+    /// The surface is removed from its parent. Its role remains unchanged.
+    pub fn destroy_subsurface(&self) {
+        let Some(subsurface) = self.subsurface.take() else {
+            return;
+        };
+        if let Some(parent) = subsurface.parent.upgrade() {
+            parent.remove_from_stack(self.id);
+        }
+    }
+
+    /// Handles a wl_surface.destroy request.
+    pub fn destroy(&self) {
+        if self.destroyed.replace(true) {
+            return;
+        }
+        self.destroy_subsurface();
+        if let Some(tracker) = self.tracker.upgrade() {
+            tracker.surfaces.borrow_mut().remove(&self.id);
+        }
+        for child in self.stacking_order() {
+            if child.id != self.id {
+                child.subsurface.take();
+            }
+        }
+        self.stack.borrow_mut().clear();
+        self.pending_stack.take();
+        self.cached.take();
+        *self.pending.borrow_mut() = Default::default();
+    }
+
+    /// Handles a wl_surface.commit request.
+    pub fn commit(self: &Rc<Self>) {
+        let pending = mem::take(&mut *self.pending.borrow_mut());
+        let state = match self.cached.take() {
+            Some(mut cached) => {
+                cached.merge(pending);
+                cached
+            }
+            None => pending,
+        };
+        if self.is_synchronized() {
+            *self.cached.borrow_mut() = Some(state);
+        } else {
+            self.apply(state);
+        }
+    }
+
+    fn apply(self: &Rc<Self>, pending: PendingState) {
+        {
+            let current = &mut *self.current.borrow_mut();
+            let mut state = SurfaceState {
+                buffer_attached: pending.buffer.is_some(),
+                offset: pending.offset.unwrap_or_default(),
+                surface_damage: pending.surface_damage,
+                buffer_damage: pending.buffer_damage,
+                frame_callbacks: pending.frame_callbacks,
+                ..(**current).clone()
+            };
+            macro_rules! apply {
+                ($($field:ident,)*) => {
+                    $(
+                        if let Some(v) = pending.$field {
+                            state.$field = v;
+                        }
+                    )*
+                };
+            }
+            apply! {
+                buffer,
+                buffer_scale,
+                buffer_transform,
+                opaque_region,
+                input_region,
+                viewport_source,
+                viewport_destination,
+            }
+            *current = Rc::new(state);
+        }
+        if let Some(stack) = self.pending_stack.take() {
+            *self.stack.borrow_mut() = stack;
+        }
+        let children: Vec<_> = self
+            .stacking_order()
+            .into_iter()
+            .filter(|c| c.id != self.id)
+            .collect();
+        for child in &children {
+            if let Some(subsurface) = &mut *child.subsurface.borrow_mut()
+                && let Some(position) = subsurface.pending_position.take()
+            {
+                subsurface.position = position;
+            }
+        }
+        if let Some(tracker) = self.tracker.upgrade() {
+            tracker.applied(self);
+        }
+        for child in children {
+            let cached = child.cached.take();
+            if let Some(cached) = cached {
+                child.apply(cached);
+            }
+        }
+    }
+
+    fn is_descendant_of(&self, other: &TrackedSurface) -> bool {
+        if self.id == other.id {
+            return true;
+        }
+        match self.parent() {
+            Some(parent) => parent.is_descendant_of(other),
+            None => false,
+        }
+    }
+
+    fn remove_from_stack(&self, id: u64) {
+        let is_not = |s: &Weak<TrackedSurface>| s.upgrade().is_some_and(|s| s.id != id);
+        self.stack.borrow_mut().retain(is_not);
+        if let Some(stack) = &mut *self.pending_stack.borrow_mut() {
+            stack.retain(is_not);
+        }
+    }
+}
+
+impl PendingState {
+    fn merge(&mut self, newer: PendingState) {
+        macro_rules! replace {
+            ($($field:ident,)*) => {
+                $(
+                    if newer.$field.is_some() {
+                        self.$field = newer.$field;
+                    }
+                )*
+            };
+        }
+        replace! {
+            buffer,
+            offset,
+            buffer_scale,
+            buffer_transform,
+            opaque_region,
+            input_region,
+            viewport_source,
+            viewport_destination,
+        }
+        self.surface_damage.extend(newer.surface_damage);
+        self.buffer_damage.extend(newer.buffer_damage);
+        self.frame_callbacks.extend(newer.frame_callbacks);
+    }
+}
+
+impl Region {
+    fn get(region: &Rc<WlRegion>) -> Rc<Region> {
+        let region = match region.try_get_handler_ref::<RegionTracker>() {
+            Ok(tracker) => tracker.0.clone(),
+            Err(_) => Region::default(),
+        };
+        Rc::new(region)
+    }
+
+    /// Returns the operations that were applied to the region.
+    pub fn operations(&self) -> &[RegionOperation] {
+        &self.operations
+    }
+
+    /// Returns whether the region contains a point.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let mut contains = false;
+        for op in &self.operations {
+            let (add, [rx, ry, rw, rh]) = match *op {
+                RegionOperation::Add(rect) => (true, rect),
+                RegionOperation::Subtract(rect) => (false, rect),
+            };
+            let x = x as i64;
+            let y = y as i64;
+            let (rx, ry) = (rx as i64, ry as i64);
+            if x >= rx && y >= ry && x < rx + rw as i64 && y < ry + rh as i64 {
+                contains = add;
+            }
+        }
+        contains
+    }
+}
+
+fn log_error(e: SurfaceTrackerError) {
+    log::warn!("Could not track a surface request: {}", Report::new(e));
+}
+
+impl WlCompositorHandler for CompositorTracker {
+    fn handle_create_surface(&mut self, slf: &Rc<WlCompositor>, id: &Rc<WlSurface>) {
+        self.0.track_surface(id);
+        slf.send_create_surface(id);
+    }
+
+    fn handle_create_region(&mut self, slf: &Rc<WlCompositor>, id: &Rc<WlRegion>) {
+        self.0.track_region(id);
+        slf.send_create_region(id);
+    }
+}
+
+impl WlSurfaceHandler for SurfaceForwarder {
+    fn handle_destroy(&mut self, slf: &Rc<WlSurface>) {
+        self.0.destroy();
+        slf.send_destroy();
+    }
+
+    fn handle_attach(
+        &mut self,
+        slf: &Rc<WlSurface>,
+        buffer: Option<&Rc<WlBuffer>>,
+        x: i32,
+        y: i32,
+    ) {
+        self.0.attach(buffer, x, y);
+        slf.send_attach(buffer, x, y);
+    }
+
+    fn handle_damage(&mut self, slf: &Rc<WlSurface>, x: i32, y: i32, width: i32, height: i32) {
+        self.0.damage(x, y, width, height);
+        slf.send_damage(x, y, width, height);
+    }
+
+    fn handle_frame(&mut self, slf: &Rc<WlSurface>, callback: &Rc<WlCallback>) {
+        self.0.frame(callback);
+        slf.send_frame(callback);
+    }
+
+    fn handle_set_opaque_region(&mut self, slf: &Rc<WlSurface>, region: Option<&Rc<WlRegion>>) {
+        self.0.set_opaque_region(region);
+        slf.send_set_opaque_region(region);
+    }
+
+    fn handle_set_input_region(&mut self, slf: &Rc<WlSurface>, region: Option<&Rc<WlRegion>>) {
+        self.0.set_input_region(region);
+        slf.send_set_input_region(region);
+    }
+
+    fn handle_commit(&mut self, slf: &Rc<WlSurface>) {
+        self.0.commit();
+        slf.send_commit();
+    }
+
+    fn handle_set_buffer_transform(&mut self, slf: &Rc<WlSurface>, transform: WlOutputTransform) {
+        self.0.set_buffer_transform(transform);
+        slf.send_set_buffer_transform(transform);
+    }
+
+    fn handle_set_buffer_scale(&mut self, slf: &Rc<WlSurface>, scale: i32) {
+        self.0.set_buffer_scale(scale);
+        slf.send_set_buffer_scale(scale);
+    }
+
+    fn handle_damage_buffer(
+        &mut self,
+        slf: &Rc<WlSurface>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.0.damage_buffer(x, y, width, height);
+        slf.send_damage_buffer(x, y, width, height);
+    }
+
+    fn handle_offset(&mut self, slf: &Rc<WlSurface>, x: i32, y: i32) {
+        self.0.offset(x, y);
+        slf.send_offset(x, y);
+    }
+}
+
+impl WlRegionHandler for RegionTracker {
+    fn handle_add(&mut self, slf: &Rc<WlRegion>, x: i32, y: i32, width: i32, height: i32) {
+        let op = RegionOperation::Add([x, y, width, height]);
+        self.0.operations.push(op);
+        slf.send_add(x, y, width, height);
+    }
+
+    fn handle_subtract(&mut self, slf: &Rc<WlRegion>, x: i32, y: i32, width: i32, height: i32) {
+        let op = RegionOperation::Subtract([x, y, width, height]);
+        self.0.operations.push(op);
+        slf.send_subtract(x, y, width, height);
+    }
+}
+
+impl WlSubcompositorHandler for SubcompositorTracker {
+    fn handle_get_subsurface(
+        &mut self,
+        slf: &Rc<WlSubcompositor>,
+        id: &Rc<WlSubsurface>,
+        surface: &Rc<WlSurface>,
+        parent: &Rc<WlSurface>,
+    ) {
+        match self.0.get_subsurface(surface, parent) {
+            Ok(surface) => id.set_handler(SubsurfaceTracker {
+                tracker: self.0.clone(),
+                surface,
+            }),
+            Err(e) => log_error(e),
+        }
+        slf.send_get_subsurface(id, surface, parent);
+    }
+}
+
+impl WlSubsurfaceHandler for SubsurfaceTracker {
+    fn handle_destroy(&mut self, slf: &Rc<WlSubsurface>) {
+        self.surface.destroy_subsurface();
+        slf.send_destroy();
+    }
+
+    fn handle_set_position(&mut self, slf: &Rc<WlSubsurface>, x: i32, y: i32) {
+        if let Err(e) = self.surface.set_subsurface_position(x, y) {
+            log_error(e);
+        }
+        slf.send_set_position(x, y);
+    }
+
+    fn handle_place_above(&mut self, slf: &Rc<WlSubsurface>, sibling: &Rc<WlSurface>) {
+        let res = match self.tracker.get(sibling) {
+            Some(sibling) => self.surface.place_above(&sibling),
+            None => Err(SurfaceTrackerErrorKind::NotTracked.into()),
+        };
+        if let Err(e) = res {
+            log_error(e);
+        }
+        slf.send_place_above(sibling);
+    }
+
+    fn handle_place_below(&mut self, slf: &Rc<WlSubsurface>, sibling: &Rc<WlSurface>) {
+        let res = match self.tracker.get(sibling) {
+            Some(sibling) => self.surface.place_below(&sibling),
+            None => Err(SurfaceTrackerErrorKind::NotTracked.into()),
+        };
+        if let Err(e) = res {
+            log_error(e);
+        }
+        slf.send_place_below(sibling);
+    }
+
+    fn handle_set_sync(&mut self, slf: &Rc<WlSubsurface>) {
+        if let Err(e) = self.surface.set_sync(true) {
+            log_error(e);
+        }
+        slf.send_set_sync();
+    }
+
+    fn handle_set_desync(&mut self, slf: &Rc<WlSubsurface>) {
+        if let Err(e) = self.surface.set_sync(false) {
+            log_error(e);
+        }
+        slf.send_set_desync();
+    }
+}
+
+#[cfg(feature = "protocol-viewporter")]
+impl WpViewporterHandler for ViewporterTracker {
+    fn handle_get_viewport(
+        &mut self,
+        slf: &Rc<WpViewporter>,
+        id: &Rc<WpViewport>,
+        surface: &Rc<WlSurface>,
+    ) {
+        id.set_handler(ViewportTracker(self.0.get(surface)));
+        slf.send_get_viewport(id, surface);
+    }
+}
+
+#[cfg(feature = "protocol-viewporter")]
+impl WpViewportHandler for ViewportTracker {
+    fn handle_destroy(&mut self, slf: &Rc<WpViewport>) {
+        if let Some(surface) = &self.0 {
+            surface.set_viewport_source(None);
+            surface.set_viewport_destination(None);
+        }
+        slf.send_destroy();
+    }
+
+    fn handle_set_source(
+        &mut self,
+        slf: &Rc<WpViewport>,
+        x: Fixed,
+        y: Fixed,
+        width: Fixed,
+        height: Fixed,
+    ) {
+        if let Some(surface) = &self.0 {
+            let rect = [x, y, width, height];
+            let unset = rect.iter().all(|&v| v == Fixed::from_i32_saturating(-1));
+            surface.set_viewport_source((!unset).then_some(rect));
+        }
+        slf.send_set_source(x, y, width, height);
+    }
+
+    fn handle_set_destination(&mut self, slf: &Rc<WpViewport>, width: i32, height: i32) {
+        if let Some(surface) = &self.0 {
+            let unset = (width, height) == (-1, -1);
+            surface.set_viewport_destination((!unset).then_some((width, height)));
+        }
+        slf.send_set_destination(width, height);
+    }
+}
+
+#[cfg(feature = "protocol-fractional_scale_v1")]
+impl WpFractionalScaleManagerV1Handler for FractionalScaleManagerTracker {
+    fn handle_get_fractional_scale(
+        &mut self,
+        slf: &Rc<WpFractionalScaleManagerV1>,
+        id: &Rc<WpFractionalScaleV1>,
+        surface: &Rc<WlSurface>,
+    ) {
+        id.set_handler(FractionalScaleTracker(self.0.get(surface)));
+        slf.send_get_fractional_scale(id, surface);
+    }
+}
+
+#[cfg(feature = "protocol-fractional_scale_v1")]
+impl WpFractionalScaleV1Handler for FractionalScaleTracker {
+    fn handle_preferred_scale(&mut self, slf: &Rc<WpFractionalScaleV1>, scale: u32) {
+        if let Some(surface) = &self.0 {
+            surface.set_preferred_scale(scale);
+        }
+        slf.send_preferred_scale(scale);
+    }
+}
@@ -0,0 +1,330 @@
+#[cfg(feature = "headless")]
+use crate::{
+    baseline::Baseline,
+    headless::Headless,
+    object::{ConcreteObject, Object, ObjectRcUtils},
+    protocols::wayland::{
+        wl_callback::WlCallbackHandler,
+        wl_compositor::WlCompositor,
+        wl_display::{WlDisplay, WlDisplayHandler},
+        wl_registry::{WlRegistry, WlRegistryHandler},
+        wl_subcompositor::WlSubcompositor,
+    },
+    state::State,
+    test_framework::proxy::dispatch_blocking,
+};
+#[cfg(feature = "headless")]
+use std::cell::Cell;
+use {
+    crate::{
+        fixed::Fixed,
+        object::ObjectCoreApi,
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_buffer::WlBuffer, wl_callback::WlCallback, wl_output::WlOutputTransform,
+                wl_surface::WlSurface,
+            },
+        },
+        surface_tracker::{SurfaceTracker, SurfaceTrackerHandler, TrackedSurface},
+        test_framework::proxy::test_proxy,
+    },
+    std::{cell::RefCell, rc::Rc},
+};
+
+#[derive(Clone, Default)]
+struct Applied(Rc<RefCell<Vec<u64>>>);
+
+impl SurfaceTrackerHandler for Applied {
+    fn applied(&mut self, surface: &Rc<TrackedSurface>) {
+        let id = surface.wl_surface().unwrap().unique_id();
+        self.0.borrow_mut().push(id);
+    }
+}
+
+#[test]
+fn double_buffering() {
+    let proxy = test_proxy();
+    let state = &proxy.proxy_state;
+    let tracker = SurfaceTracker::new();
+    let applied = Applied::default();
+    tracker.set_handler(applied.clone());
+    let wl_surface = state.create_object::<WlSurface>(1);
+    let surface = tracker.create_surface(&wl_surface);
+    assert!(Rc::ptr_eq(&tracker.get(&wl_surface).unwrap(), &surface));
+
+    let buffer = state.create_object::<WlBuffer>(1);
+    let callback = state.create_object::<WlCallback>(1);
+    surface.attach(Some(&buffer), 0, 0);
+    surface.damage_buffer(0, 0, 10, 10);
+    surface.set_buffer_scale(2);
+    surface.set_buffer_transform(WlOutputTransform::_90);
+    surface.frame(&callback);
+    let current = surface.current();
+    assert!(current.buffer.is_none());
+    assert_eq!(current.buffer_scale, 1);
+    assert!(applied.0.borrow().is_empty());
+
+    surface.commit();
+    let current = surface.current();
+    assert!(Rc::ptr_eq(current.buffer.as_ref().unwrap(), &buffer));
+    assert!(current.buffer_attached);
+    assert_eq!(current.buffer_damage, [[0, 0, 10, 10]]);
+    assert_eq!(current.buffer_scale, 2);
+    assert_eq!(current.buffer_transform, WlOutputTransform::_90);
+    assert_eq!(current.frame_callbacks.len(), 1);
+    assert_eq!(*applied.0.borrow(), [wl_surface.unique_id()]);
+
+    // Persistent state is retained, per-commit state is reset.
+    surface.offset(3, 4);
+    surface.commit();
+    let current = surface.current();
+    assert!(current.buffer.is_some());
+    assert!(!current.buffer_attached);
+    assert_eq!(current.offset, (3, 4));
+    assert!(current.buffer_damage.is_empty());
+    assert!(current.frame_callbacks.is_empty());
+    assert_eq!(current.buffer_scale, 2);
+
+    surface.attach(None, 0, 0);
+    surface.commit();
+    let current = surface.current();
+    assert!(current.buffer.is_none());
+    assert!(current.buffer_attached);
+    assert_eq!(current.offset, (0, 0));
+
+    surface.destroy();
+    assert!(tracker.get(&wl_surface).is_none());
+    assert!(tracker.surfaces().is_empty());
+}
+
+#[test]
+fn synchronized_subsurface() {
+    let proxy = test_proxy();
+    let state = &proxy.proxy_state;
+    let tracker = SurfaceTracker::new();
+    let applied = Applied::default();
+    tracker.set_handler(applied.clone());
+    let wl_parent = state.create_object::<WlSurface>(1);
+    let wl_child = state.create_object::<WlSurface>(1);
+    let wl_grandchild = state.create_object::<WlSurface>(1);
+    let parent = tracker.create_surface(&wl_parent);
+    let child = tracker.create_surface(&wl_child);
+    let grandchild = tracker.create_surface(&wl_grandchild);
+    tracker.get_subsurface(&wl_child, &wl_parent).unwrap();
+    tracker.get_subsurface(&wl_grandchild, &wl_child).unwrap();
+    assert!(tracker.get_subsurface(&wl_parent, &wl_grandchild).is_err());
+    assert_eq!(child.role(), Some(ObjectInterface::WlSubsurface));
+    assert!(Rc::ptr_eq(&child.parent().unwrap(), &parent));
+
+    // Subsurfaces are synchronized by default.
+    child.set_buffer_scale(2);
+    child.set_subsurface_position(5, 6).unwrap();
+    child.commit();
+    grandchild.set_buffer_scale(3);
+    grandchild.commit();
+    assert!(child.has_cached_state());
+    assert_eq!(child.current().buffer_scale, 1);
+    assert_eq!(child.position(), (0, 0));
+    assert!(applied.0.borrow().is_empty());
+
+    parent.commit();
+    assert_eq!(child.current().buffer_scale, 2);
+    assert_eq!(child.position(), (5, 6));
+    assert_eq!(grandchild.current().buffer_scale, 3);
+    assert!(!child.has_cached_state());
+    assert_eq!(
+        *applied.0.borrow(),
+        [
+            wl_parent.unique_id(),
+            wl_child.unique_id(),
+            wl_grandchild.unique_id(),
+        ],
+    );
+
+    // A desynchronized subsurface is still synchronized if its parent is.
+    grandchild.set_sync(false).unwrap();
+    assert!(grandchild.is_synchronized());
+    child.set_sync(false).unwrap();
+    assert!(!grandchild.is_synchronized());
+    applied.0.borrow_mut().clear();
+    grandchild.set_buffer_scale(4);
+    grandchild.commit();
+    assert_eq!(grandchild.current().buffer_scale, 4);
+    assert_eq!(*applied.0.borrow(), [wl_grandchild.unique_id()]);
+
+    // Cached state is merged with later commits.
+    child.set_sync(true).unwrap();
+    child.damage(0, 0, 1, 1);
+    child.commit();
+    child.damage(1, 1, 1, 1);
+    child.set_buffer_scale(5);
+    child.commit();
+    parent.commit();
+    let current = child.current();
+    assert_eq!(current.surface_damage, [[0, 0, 1, 1], [1, 1, 1, 1]]);
+    assert_eq!(current.buffer_scale, 5);
+}
+
+#[test]
+fn stacking_order() {
+    let proxy = test_proxy();
+    let state = &proxy.proxy_state;
+    let tracker = SurfaceTracker::new();
+    let wl_parent = state.create_object::<WlSurface>(1);
+    let wl_a = state.create_object::<WlSurface>(1);
+    let wl_b = state.create_object::<WlSurface>(1);
+    let parent = tracker.create_surface(&wl_parent);
+    let a = tracker.create_surface(&wl_a);
+    let b = tracker.create_surface(&wl_b);
+    tracker.get_subsurface(&wl_a, &wl_parent).unwrap();
+    tracker.get_subsurface(&wl_b, &wl_parent).unwrap();
+    let order = |s: &TrackedSurface| {
+        s.stacking_order()
+            .iter()
+            .map(|s| s.wl_surface().unwrap().unique_id())
+            .collect::<Vec<_>>()
+    };
+    let [p, a_id, b_id] = [&wl_parent, &wl_a, &wl_b].map(|s| s.unique_id());
+    assert_eq!(order(&parent), [p, a_id, b_id]);
+
+    a.place_below(&parent).unwrap();
+    b.place_above(&a).unwrap();
+    assert_eq!(order(&parent), [p, a_id, b_id]);
+    parent.commit();
+    assert_eq!(order(&parent), [a_id, b_id, p]);
+
+    assert!(a.place_above(&a).is_err());
+    assert!(parent.place_above(&a).is_err());
+
+    b.destroy_subsurface();
+    assert_eq!(order(&parent), [a_id, p]);
+    assert!(b.parent().is_none());
+    assert_eq!(b.role(), Some(ObjectInterface::WlSubsurface));
+}
+
+#[test]
+fn roles() {
+    let proxy = test_proxy();
+    let tracker = SurfaceTracker::new();
+    let wl_surface = proxy.proxy_state.create_object::<WlSurface>(1);
+    let surface = tracker.create_surface(&wl_surface);
+    assert_eq!(surface.role(), None);
+    surface.set_role(ObjectInterface::WlShellSurface).unwrap();
+    surface.set_role(ObjectInterface::WlShellSurface).unwrap();
+    assert!(surface.set_role(ObjectInterface::WlSubsurface).is_err());
+    assert_eq!(surface.role(), Some(ObjectInterface::WlShellSurface));
+}
+
+#[test]
+fn viewport() {
+    let proxy = test_proxy();
+    let tracker = SurfaceTracker::new();
+    let wl_surface = proxy.proxy_state.create_object::<WlSurface>(1);
+    let surface = tracker.create_surface(&wl_surface);
+    let source = [1, 2, 3, 4].map(Fixed::from_i32_saturating);
+    surface.set_viewport_source(Some(source));
+    surface.set_viewport_destination(Some((10, 20)));
+    surface.commit();
+    let current = surface.current();
+    assert_eq!(current.viewport_source, Some(source));
+    assert_eq!(current.viewport_destination, Some((10, 20)));
+    surface.set_viewport_source(None);
+    surface.commit();
+    let current = surface.current();
+    assert_eq!(current.viewport_source, None);
+    assert_eq!(current.viewport_destination, Some((10, 20)));
+}
+
+#[cfg(feature = "headless")]
+#[derive(Clone)]
+struct Proxy(Rc<SurfaceTracker>);
+
+#[cfg(feature = "headless")]
+impl WlDisplayHandler for Proxy {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(self.clone());
+        slf.send_get_registry(registry);
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlRegistryHandler for Proxy {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        if let Some(compositor) = id.try_downcast::<WlCompositor>() {
+            self.0.track_compositor(&compositor);
+        }
+        if let Some(subcompositor) = id.try_downcast::<WlSubcompositor>() {
+            self.0.track_subcompositor(&subcompositor);
+        }
+        slf.send_bind(name, id);
+    }
+}
+
+#[cfg(feature = "headless")]
+struct Done(Rc<Cell<bool>>);
+
+#[cfg(feature = "headless")]
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn track_compositor() {
+    let headless = Headless::new().unwrap();
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let (proxy_client, client_fd) = proxy.connect().unwrap();
+    let tracker = SurfaceTracker::new();
+    proxy_client.display.set_handler(Proxy(tracker.clone()));
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .build()
+        .unwrap();
+    let _destructors = [proxy.create_destructor(), client.create_destructor()];
+    client.set_default_forward_to_client(false);
+    let registry = client.display().new_send_get_registry();
+    let compositor = client.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+    registry.send_bind(1, compositor.clone());
+    let subcompositor = client.create_object::<WlSubcompositor>(WlSubcompositor::XML_VERSION);
+    registry.send_bind(3, subcompositor.clone());
+
+    let parent = compositor.new_send_create_surface();
+    let child = compositor.new_send_create_surface();
+    let subsurface = subcompositor.new_send_get_subsurface(&child, &parent);
+    let region = compositor.new_send_create_region();
+    region.send_add(0, 0, 10, 10);
+    region.send_subtract(5, 5, 5, 5);
+    child.send_set_input_region(Some(&region));
+    region.send_destroy();
+    child.send_commit();
+    subsurface.send_set_position(7, 8);
+    subsurface.send_set_desync();
+    parent.send_commit();
+
+    let done = Rc::new(Cell::new(false));
+    client
+        .display()
+        .new_send_sync()
+        .set_handler(Done(done.clone()));
+    while !done.get() {
+        dispatch_blocking([&client, &proxy, headless.state()]).unwrap();
+    }
+
+    let surfaces = tracker.surfaces();
+    assert_eq!(surfaces.len(), 2);
+    let child = &surfaces[1];
+    assert!(Rc::ptr_eq(&child.parent().unwrap(), &surfaces[0]));
+    assert_eq!(child.position(), (7, 8));
+    assert!(!child.is_synchronized());
+    let input = child.current().input_region.clone().unwrap();
+    assert!(input.contains(2, 2));
+    assert!(!input.contains(7, 7));
+    assert!(!input.contains(10, 0));
+    assert_eq!(headless.surfaces().len(), 2);
+}
@@ -1,5 +1,7 @@
 use log::LevelFilter;
 
+#[cfg(feature = "headless")]
+pub mod headless;
 pub mod proxy;
 pub mod server;
 
@@ -0,0 +1,101 @@
+use {
+    crate::{
+        baseline::Baseline,
+        client::Client,
+        headless::Headless,
+        object::ConcreteObject,
+        protocols::wayland::{
+            wl_callback::{WlCallback, WlCallbackHandler},
+            wl_registry::WlRegistry,
+        },
+        state::{Destructor, State},
+        test_framework::{install_logger, proxy::dispatch_blocking},
+    },
+    std::{cell::Cell, os::fd::OwnedFd, rc::Rc},
+};
+
+/// A client that is connected to a headless compositor via one or more proxies.
+pub struct HeadlessEnv {
+    pub headless: Headless,
+    /// The proxies, starting with the one connected to the compositor.
+    pub proxies: Vec<Rc<State>>,
+    pub client: Rc<State>,
+    pub registry: Rc<WlRegistry>,
+    _destructors: Vec<Destructor>,
+}
+
+impl HeadlessEnv {
+    /// Connects a client to the compositor via a single proxy.
+    pub fn new() -> Self {
+        Self::with_proxy_client(|_| ())
+    }
+
+    /// Like [`Self::new`] but invokes `f` with the client of the proxy before the
+    /// client connects.
+    pub fn with_proxy_client(f: impl FnOnce(&Rc<Client>)) -> Self {
+        install_logger();
+        let headless = Headless::new().unwrap();
+        let proxy = State::builder(Baseline::ALL_OF_THEM)
+            .with_server_fd(&Rc::new(headless.connect().unwrap()))
+            .build()
+            .unwrap();
+        let (proxy_client, client_fd) = proxy.connect().unwrap();
+        f(&proxy_client);
+        Self::with_proxies(headless, vec![proxy], client_fd)
+    }
+
+    /// Connects a client to the last of the proxies via `client_fd`.
+    pub fn with_proxies(headless: Headless, proxies: Vec<Rc<State>>, client_fd: OwnedFd) -> Self {
+        let client = State::builder(Baseline::ALL_OF_THEM)
+            .with_server_fd(&Rc::new(client_fd))
+            .build()
+            .unwrap();
+        client.set_default_forward_to_client(false);
+        let registry = client.display().new_send_get_registry();
+        let _destructors = proxies
+            .iter()
+            .chain([&client])
+            .map(|s| s.create_destructor())
+            .collect();
+        Self {
+            headless,
+            proxies,
+            client,
+            registry,
+            _destructors,
+        }
+    }
+
+    pub fn roundtrip(&self) {
+        let mut states = vec![&self.client];
+        states.extend(&self.proxies);
+        states.push(self.headless.state());
+        roundtrip(&self.client, states);
+    }
+
+    pub fn bind<T: ConcreteObject>(&self, name: u32) -> Rc<T> {
+        let object = self.client.create_object::<T>(T::XML_VERSION);
+        self.registry.send_bind(name, object.clone());
+        object
+    }
+}
+
+pub struct Done(pub Rc<Cell<bool>>);
+
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+/// Sends a sync request via `state` and dispatches `states` until it has completed.
+pub fn roundtrip<'a>(state: &Rc<State>, states: impl AsRef<[&'a Rc<State>]>) {
+    let done = Rc::new(Cell::new(false));
+    state
+        .display()
+        .new_send_sync()
+        .set_handler(Done(done.clone()));
+    while !done.get() {
+        dispatch_blocking(states.as_ref()).unwrap();
+    }
+}
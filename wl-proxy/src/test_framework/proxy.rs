@@ -69,7 +69,7 @@ fn test_proxy_(log: bool) -> TestProxy {
     }
 }
 
-fn test_proxy_client(proxy_state: &Rc<State>, log: bool) -> TestProxyClient {
+pub fn test_proxy_client(proxy_state: &Rc<State>, log: bool) -> TestProxyClient {
     let (client, client_fd) = proxy_state.connect().unwrap();
     struct Handler(Rc<RefCell<Option<Rc<WlproxyTest>>>>);
     impl WlDisplayHandler for Handler {
@@ -115,7 +115,8 @@ fn test_proxy_client(proxy_state: &Rc<State>, log: bool) -> TestProxyClient {
     }
 }
 
-pub fn dispatch_blocking<const N: usize>(states: [&Rc<State>; N]) -> Result<(), StateError> {
+pub fn dispatch_blocking<'a>(states: impl AsRef<[&'a Rc<State>]>) -> Result<(), StateError> {
+    let states = states.as_ref();
     let mut did_work = false;
     for state in states {
         did_work |= state.dispatch_available()?;
@@ -126,11 +127,14 @@ pub fn dispatch_blocking<const N: usize>(states: [&Rc<State>; N]) -> Result<(),
     for state in states {
         state.before_poll().unwrap();
     }
-    let mut pollfd = states.map(|s| c::pollfd {
-        fd: s.poll_fd().as_raw_fd(),
-        events: c::POLLIN,
-        revents: 0,
-    });
+    let mut pollfd: Vec<_> = states
+        .iter()
+        .map(|s| c::pollfd {
+            fd: s.poll_fd().as_raw_fd(),
+            events: c::POLLIN,
+            revents: 0,
+        })
+        .collect();
     uapi::poll(&mut pollfd, -1).unwrap();
     Ok(())
 }
@@ -1,4 +1,7 @@
+#[cfg(feature = "recovery")]
+use crate::recovery::Journal;
 use {
+    crate::recording::{Direction, EndpointRecorder},
     isnt::std_1::primitive::IsntSliceExt,
     smallvec::SmallVec,
     std::{
@@ -17,7 +20,7 @@ use {
 mod tests;
 
 const WORD_SIZE: usize = size_of::<u32>();
-const MAX_MESSAGE_SIZE: usize = 4096;
+pub(crate) const MAX_MESSAGE_SIZE: usize = 4096;
 const MAX_MESSAGE_WORDS: usize = MAX_MESSAGE_SIZE / WORD_SIZE;
 const BUFFER_LEN: usize = MAX_MESSAGE_WORDS * 2;
 const BUFFER_SIZE: usize = BUFFER_LEN * WORD_SIZE;
@@ -56,12 +59,25 @@ pub(crate) struct MessageFormatter<'a> {
     old_fds_len: usize,
     fd_offsets: &'a mut VecDeque<FdOffset>,
     valid_to_byte: &'a mut usize,
+    recorder: Option<&'a EndpointRecorder>,
+    #[cfg(feature = "recovery")]
+    journal: Option<&'a Journal>,
+}
+
+#[derive(Copy, Clone, Default)]
+pub(crate) struct FormatterHooks<'a> {
+    pub(crate) recorder: Option<&'a EndpointRecorder>,
+    #[cfg(feature = "recovery")]
+    pub(crate) journal: Option<&'a Journal>,
 }
 
 #[derive(Default)]
 pub(crate) struct OutputSwapchain {
     pending: VecDeque<Box<OutputBuffer>>,
     stash: Vec<Box<OutputBuffer>>,
+    recorder: Option<Rc<EndpointRecorder>>,
+    #[cfg(feature = "recovery")]
+    pub(crate) journal: Option<Rc<Journal>>,
 }
 
 #[derive(Debug, Error)]
@@ -78,6 +94,15 @@ pub enum TransError {
     MessageTooLarge(usize),
     #[error("message has a supposed length {0} that is not a multiple of {WORD_SIZE}")]
     MessageNotAligned(usize),
+    #[cfg(feature = "network")]
+    #[error("received an invalid network frame")]
+    InvalidFrame,
+    #[cfg(feature = "network")]
+    #[error("received a reference to an unknown file {0}")]
+    UnknownFile(u32),
+    #[cfg(feature = "network")]
+    #[error("could not replicate a file")]
+    ReplicateFile(#[source] io::Error),
 }
 
 pub(crate) fn read_message<'a>(
@@ -85,7 +110,7 @@ pub(crate) fn read_message<'a>(
     may_read_from_socket: &mut bool,
     buffer: &'a mut InputBuffer,
     fds: &mut VecDeque<Rc<OwnedFd>>,
-) -> Result<Option<&'a [u32]>, TransError> {
+) -> Result<Option<&'a mut [u32]>, TransError> {
     if buffer.valid_bytes == 0 {
         buffer.valid_from_word = 0;
     }
@@ -130,7 +155,7 @@ pub(crate) fn read_message<'a>(
     let end = start + size_words;
     buffer.valid_from_word += size_words;
     buffer.valid_bytes -= size;
-    Ok(Some(&buffer.buffer[start..end]))
+    Ok(Some(&mut buffer.buffer[start..end]))
 }
 
 fn read_from_socket(
@@ -269,7 +294,10 @@ impl Default for OutputBuffer {
 }
 
 impl OutputBuffer {
-    pub(crate) fn formatter(&mut self) -> Option<MessageFormatter<'_>> {
+    pub(crate) fn formatter<'a>(
+        &'a mut self,
+        hooks: FormatterHooks<'a>,
+    ) -> Option<MessageFormatter<'a>> {
         if self.valid_from_byte == self.valid_to_byte {
             self.valid_from_byte = 0;
             self.valid_to_byte = 0;
@@ -285,13 +313,58 @@ impl OutputBuffer {
             fds: &mut self.fds,
             fd_offsets: &mut self.fd_offsets,
             valid_to_byte: &mut self.valid_to_byte,
+            recorder: hooks.recorder,
+            #[cfg(feature = "recovery")]
+            journal: hooks.journal,
         })
     }
+
+    /// Passes all unflushed messages and their file descriptors to `f`.
+    #[cfg(feature = "network")]
+    fn drain(&mut self, f: &mut impl FnMut(&[u32], Vec<Rc<OwnedFd>>)) {
+        let mut pos = self.valid_from_byte;
+        while pos < self.valid_to_byte {
+            let mut fds = vec![];
+            if let Some(fdo) = self.fd_offsets.front()
+                && fdo.offset_bytes == pos
+            {
+                let num_fds = fdo.num_fds;
+                self.fd_offsets.pop_front();
+                fds.extend(self.fds.drain(..num_fds));
+            }
+            let start = pos / WORD_SIZE;
+            let size = (self.buffer[start + 1] >> 16) as usize;
+            f(&self.buffer[start..start + size / WORD_SIZE], fds);
+            pos += size;
+        }
+        self.valid_from_byte = 0;
+        self.valid_to_byte = 0;
+    }
+
+    #[cfg(feature = "recovery")]
+    fn clear(&mut self) {
+        self.valid_from_byte = 0;
+        self.valid_to_byte = 0;
+        self.fds.clear();
+        self.fd_offsets.clear();
+    }
 }
 
 impl Drop for MessageFormatter<'_> {
     fn drop(&mut self) {
         assert!(self.words_written >= HEADER_WORDS);
+        let message_size = self.words_written * 4;
+        self.buffer[1] |= (message_size as u32) << 16;
+        #[cfg(feature = "recovery")]
+        if let Some(journal) = self.journal
+            && !journal.record_request(
+                &mut self.buffer[..self.words_written],
+                self.fds.range(self.old_fds_len..),
+            )
+        {
+            self.fds.truncate(self.old_fds_len);
+            return;
+        }
         let num_fds = self.fds.len() - self.old_fds_len;
         if num_fds > 0 {
             self.fd_offsets.push_back(FdOffset {
@@ -299,16 +372,33 @@ impl Drop for MessageFormatter<'_> {
                 num_fds,
             });
         }
-        let message_size = self.words_written * 4;
-        self.buffer[1] |= (message_size as u32) << 16;
         *self.valid_to_byte += message_size;
+        if let Some(recorder) = self.recorder {
+            recorder.record(
+                Direction::Sent,
+                &self.buffer[..self.words_written],
+                self.fds.range(self.old_fds_len..),
+            );
+        }
     }
 }
 
 impl OutputSwapchain {
+    pub(crate) fn new(recorder: Option<Rc<EndpointRecorder>>) -> Self {
+        Self {
+            recorder,
+            ..Default::default()
+        }
+    }
+
     pub(crate) fn formatter(&mut self) -> MessageFormatter<'_> {
+        let hooks = FormatterHooks {
+            recorder: self.recorder.as_deref(),
+            #[cfg(feature = "recovery")]
+            journal: self.journal.as_deref(),
+        };
         if let Some(last) = self.pending.back_mut()
-            && let Some(fmt) = last.formatter()
+            && let Some(fmt) = last.formatter(hooks)
         {
             // This is a limitation in the borrow checker. Without this transmute, the
             // return causes the self.pending borrow to last till the end of the function.
@@ -316,7 +406,25 @@ impl OutputSwapchain {
         }
         let fmt = self.stash.pop().unwrap_or_default();
         self.pending.push_back(fmt);
-        self.pending.back_mut().unwrap().formatter().unwrap()
+        self.pending.back_mut().unwrap().formatter(hooks).unwrap()
+    }
+
+    /// Discards all messages that have not yet been flushed.
+    #[cfg(feature = "recovery")]
+    pub(crate) fn clear(&mut self) {
+        while let Some(mut buf) = self.pending.pop_front() {
+            buf.clear();
+            self.stash.push(buf);
+        }
+    }
+
+    /// Passes all unflushed messages and their file descriptors to `f`.
+    #[cfg(feature = "network")]
+    pub(crate) fn drain(&mut self, mut f: impl FnMut(&[u32], Vec<Rc<OwnedFd>>)) {
+        while let Some(mut buf) = self.pending.pop_front() {
+            buf.drain(&mut f);
+            self.stash.push(buf);
+        }
     }
 
     pub(crate) fn flush(&mut self, fd: RawFd) -> Result<FlushResult, TransError> {
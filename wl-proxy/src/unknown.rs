@@ -0,0 +1,1183 @@
+//! Objects with interfaces that are not known to this crate.
+//!
+//! By default, globals whose interface is not known to this crate are not forwarded to
+//! clients. [`StateBuilder::with_unknown_interfaces`](crate::state::StateBuilder::with_unknown_interfaces)
+//! and [`StateBuilder::with_unknown_interface`](crate::state::StateBuilder::with_unknown_interface)
+//! can be used to forward such globals. Objects
+//! bound to such globals are represented as [`UnknownObject`]s.
+//!
+//! Without a description of the interface, the messages of such objects are forwarded
+//! verbatim. In this case, object IDs contained in the messages are not translated.
+//! Since the proxy uses its own object IDs, this only works for messages whose arguments
+//! are plain integers. Since the number of file descriptors of such messages is also not
+//! known, no file descriptors are forwarded with them by default.
+//! [`StateBuilder::with_verbatim_fds`](crate::state::StateBuilder::with_verbatim_fds)
+//! can be used to instead forward all file descriptors that have been received but not
+//! yet consumed. This is only correct if no other message that has been received at the
+//! same time carries file descriptors.
+//!
+//! Globals with unknown interfaces are forwarded with the version announced by the
+//! server unless a maximum version has been set with [`UnknownInterface::with_version`].
+//!
+//! If the interface has been described with an [`UnknownInterface`], then messages are
+//! parsed according to the signatures in the description. This allows object and
+//! new_id arguments as well as file descriptors to be forwarded.
+//!
+//! # Example
+//!
+//! ```
+//! # use wl_proxy::{baseline::Baseline, state::State, unknown::{UnknownInterface, UnknownMessage}};
+//! let interface = UnknownInterface::new("wp_example_manager_v1")
+//!     .with_request(UnknownMessage::new("destroy", "").unwrap().with_destructor(true))
+//!     .with_request(UnknownMessage::new("get_example", "n[wp_example_v1]o").unwrap())
+//!     .with_event(UnknownMessage::new("fd", "hu").unwrap());
+//! let state = State::builder(Baseline::ALL_OF_THEM)
+//!     .without_server()
+//!     .with_unknown_interface(interface)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+#[cfg(feature = "logging")]
+use crate::recording::Direction;
+use {
+    crate::{
+        client::Client,
+        endpoint::Endpoint,
+        fixed::Fixed,
+        handler::{HandlerAccessError, HandlerHolder, HandlerMut, HandlerRef},
+        object::{
+            ConcreteObject, Object, ObjectCore, ObjectCoreApi, ObjectError, ObjectErrorKind,
+            ObjectPrivate,
+        },
+        protocol_helpers::{
+            NonNullString, NullableString, log_forward, log_send, parse_array, parse_string,
+        },
+        protocols::{ObjectInterface, create_object_for_interface},
+        state::State,
+        trans::MAX_MESSAGE_SIZE,
+    },
+    std::{
+        any::Any,
+        collections::{HashMap, VecDeque},
+        fmt::{Debug, Formatter},
+        os::fd::OwnedFd,
+        rc::Rc,
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The description of an interface that is not known to this crate.
+///
+/// Requests and events are assigned opcodes in the order in which they are added.
+#[derive(Clone, Debug)]
+pub struct UnknownInterface {
+    name: Rc<str>,
+    version: u32,
+    described: bool,
+    requests: Vec<UnknownMessage>,
+    events: Vec<UnknownMessage>,
+}
+
+/// The description of a message of an [`UnknownInterface`].
+#[derive(Clone, Debug)]
+pub struct UnknownMessage {
+    name: String,
+    signature: String,
+    args: Vec<ArgType>,
+    #[cfg(feature = "logging")]
+    arg_names: Vec<String>,
+    destructor: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum ArgType {
+    Int,
+    Uint,
+    Fixed,
+    String { nullable: bool },
+    Object { nullable: bool },
+    NewId { interface: Option<String> },
+    Array,
+    Fd,
+}
+
+/// An error returned when a message signature cannot be parsed.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct UnknownSignatureError(#[from] UnknownSignatureErrorKind);
+
+#[derive(Debug, Error)]
+enum UnknownSignatureErrorKind {
+    #[error("unknown argument type `{0}`")]
+    UnknownType(char),
+    #[error("arguments of type `{0}` cannot be nullable")]
+    NotNullable(char),
+    #[error("the signature ends with `?`")]
+    TrailingNullable,
+    #[error("the interface of a new_id argument is not terminated")]
+    UnterminatedInterface,
+    #[error("the interface of a new_id argument is empty")]
+    EmptyInterface,
+}
+
+/// An argument of a message of an [`UnknownObject`].
+#[derive(Clone, Debug)]
+pub enum UnknownArg<'a> {
+    /// An `int` argument.
+    Int(i32),
+    /// A `uint` argument.
+    ///
+    /// If the interface has not been described, all words of the message are represented
+    /// as `uint` arguments.
+    Uint(u32),
+    /// A `fixed` argument.
+    Fixed(Fixed),
+    /// A `string` argument.
+    String(Option<&'a str>),
+    /// An `object` argument.
+    Object(Option<Rc<dyn Object>>),
+    /// A `new_id` argument.
+    NewId(Rc<dyn Object>),
+    /// An `array` argument.
+    Array(&'a [u8]),
+    /// An `fd` argument.
+    ///
+    /// If the interface has not been described, the file descriptors that were pending
+    /// when the message was received follow the `uint` arguments.
+    Fd(Rc<OwnedFd>),
+}
+
+/// An object whose interface is not known to this crate.
+///
+/// See the documentation of [the module][self] for more information.
+///
+/// Such objects should be created with [`State::create_unknown_object`]. Objects created
+/// via [`State::create_object`] or [`ObjectCoreApi::create_child`] have the interface name `unknown` and forward messages
+/// verbatim.
+pub struct UnknownObject {
+    core: ObjectCore,
+    handler: HandlerHolder<dyn UnknownObjectHandler>,
+    interface: Rc<UnknownInterface>,
+}
+
+struct DefaultHandler;
+
+impl UnknownObjectHandler for DefaultHandler {}
+
+impl ConcreteObject for UnknownObject {
+    const XML_VERSION: u32 = u32::MAX;
+    const INTERFACE: ObjectInterface = ObjectInterface::Unknown;
+    const INTERFACE_NAME: &str = "unknown";
+}
+
+#[derive(Default)]
+pub(crate) struct UnknownInterfaces {
+    forward_all: bool,
+    pub(crate) verbatim_fds: bool,
+    described: HashMap<Rc<str>, Rc<UnknownInterface>>,
+}
+
+impl UnknownInterface {
+    /// Creates a new interface without a description.
+    ///
+    /// Messages of objects with this interface are forwarded verbatim until a request or
+    /// event is added.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            version: u32::MAX,
+            described: false,
+            requests: Default::default(),
+            events: Default::default(),
+        }
+    }
+
+    /// Returns the name of the interface.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets the maximum version of the interface.
+    ///
+    /// Globals with this interface are announced to clients with at most this version
+    /// and objects bound to them are created with at most this version. By default, the
+    /// version is not limited.
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Returns the maximum version of the interface.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Adds a request.
+    ///
+    /// The first request has opcode 0.
+    pub fn with_request(mut self, message: UnknownMessage) -> Self {
+        self.described = true;
+        self.requests.push(message);
+        self
+    }
+
+    /// Adds an event.
+    ///
+    /// The first event has opcode 0.
+    pub fn with_event(mut self, message: UnknownMessage) -> Self {
+        self.described = true;
+        self.events.push(message);
+        self
+    }
+
+    /// Returns whether the interface has a description.
+    pub fn is_described(&self) -> bool {
+        self.described
+    }
+
+    /// Returns the opcode of the request with the given name.
+    pub fn request_opcode(&self, name: &str) -> Option<u32> {
+        self.requests
+            .iter()
+            .position(|m| m.name == name)
+            .map(|p| p as u32)
+    }
+
+    /// Returns the opcode of the event with the given name.
+    pub fn event_opcode(&self, name: &str) -> Option<u32> {
+        self.events
+            .iter()
+            .position(|m| m.name == name)
+            .map(|p| p as u32)
+    }
+
+    /// Returns the requests of the interface.
+    pub fn requests(&self) -> &[UnknownMessage] {
+        &self.requests
+    }
+
+    /// Returns the events of the interface.
+    pub fn events(&self) -> &[UnknownMessage] {
+        &self.events
+    }
+
+    #[cfg_attr(not(feature = "dynamic"), expect(dead_code))]
+    pub(crate) fn with_described(mut self, described: bool) -> Self {
+        self.described = described;
+        self
+    }
+
+    fn request(&self, opcode: u32) -> Result<Option<&UnknownMessage>, ObjectError> {
+        self.message(&self.requests, opcode)
+    }
+
+    fn event(&self, opcode: u32) -> Result<Option<&UnknownMessage>, ObjectError> {
+        self.message(&self.events, opcode)
+    }
+
+    fn message<'a>(
+        &self,
+        messages: &'a [UnknownMessage],
+        opcode: u32,
+    ) -> Result<Option<&'a UnknownMessage>, ObjectError> {
+        if !self.described {
+            return Ok(None);
+        }
+        match messages.get(opcode as usize) {
+            Some(m) => Ok(Some(m)),
+            None => Err(ObjectError(ObjectErrorKind::UnknownMessageId(opcode))),
+        }
+    }
+}
+
+impl UnknownMessage {
+    /// Creates a new message.
+    ///
+    /// The signature is a sequence of the following argument types:
+    ///
+    /// - `i`: int
+    /// - `u`: uint
+    /// - `f`: fixed
+    /// - `s`: string
+    /// - `o`: object
+    /// - `n[interface]`: new_id with the given interface
+    /// - `n`: new_id without an interface
+    /// - `a`: array
+    /// - `h`: fd
+    ///
+    /// `s` and `o` can be prefixed by `?` to mark them as nullable.
+    ///
+    /// Objects created by new_id arguments with an interface have the same version as
+    /// the object the message is sent to. new_id arguments without an interface are
+    /// sent on the wire as the interface name, the version, and the object ID, like the
+    /// `id` argument of wl_registry.bind.
+    pub fn new(name: &str, signature: &str) -> Result<Self, UnknownSignatureError> {
+        let mut args = vec![];
+        let mut chars = signature.chars();
+        let mut nullable = false;
+        while let Some(c) = chars.next() {
+            if c == '?' {
+                nullable = true;
+                continue;
+            }
+            if nullable && !matches!(c, 's' | 'o') {
+                return Err(UnknownSignatureErrorKind::NotNullable(c).into());
+            }
+            let ty = match c {
+                'i' => ArgType::Int,
+                'u' => ArgType::Uint,
+                'f' => ArgType::Fixed,
+                's' => ArgType::String { nullable },
+                'o' => ArgType::Object { nullable },
+                'a' => ArgType::Array,
+                'h' => ArgType::Fd,
+                'n' => {
+                    let Some(rest) = chars.as_str().strip_prefix('[') else {
+                        args.push(ArgType::NewId { interface: None });
+                        continue;
+                    };
+                    let Some(end) = rest.find(']') else {
+                        return Err(UnknownSignatureErrorKind::UnterminatedInterface.into());
+                    };
+                    if end == 0 {
+                        return Err(UnknownSignatureErrorKind::EmptyInterface.into());
+                    }
+                    let interface = Some(rest[..end].to_string());
+                    chars = rest[end + 1..].chars();
+                    ArgType::NewId { interface }
+                }
+                _ => return Err(UnknownSignatureErrorKind::UnknownType(c).into()),
+            };
+            nullable = false;
+            args.push(ty);
+        }
+        if nullable {
+            return Err(UnknownSignatureErrorKind::TrailingNullable.into());
+        }
+        Ok(Self::from_args(name, args, vec![]))
+    }
+
+    pub(crate) fn from_args(name: &str, args: Vec<ArgType>, arg_names: Vec<String>) -> Self {
+        #[cfg(not(feature = "logging"))]
+        let _ = arg_names;
+        let mut signature = String::new();
+        for arg in &args {
+            match arg {
+                ArgType::Int => signature.push('i'),
+                ArgType::Uint => signature.push('u'),
+                ArgType::Fixed => signature.push('f'),
+                ArgType::String { nullable } => {
+                    signature.push_str(if *nullable { "?s" } else { "s" })
+                }
+                ArgType::Object { nullable } => {
+                    signature.push_str(if *nullable { "?o" } else { "o" })
+                }
+                ArgType::NewId { interface: None } => signature.push('n'),
+                ArgType::NewId {
+                    interface: Some(interface),
+                } => {
+                    signature.push_str(&format!("n[{interface}]"));
+                }
+                ArgType::Array => signature.push('a'),
+                ArgType::Fd => signature.push('h'),
+            }
+        }
+        Self {
+            name: name.to_string(),
+            signature,
+            args,
+            #[cfg(feature = "logging")]
+            arg_names,
+            destructor: false,
+        }
+    }
+
+    /// Sets whether this message is a destructor.
+    ///
+    /// By default, messages are not destructors.
+    pub fn with_destructor(mut self, destructor: bool) -> Self {
+        self.destructor = destructor;
+        self
+    }
+
+    /// Returns the name of the message.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the signature of the message in the format accepted by [`Self::new`].
+    pub fn signature(&self) -> &str {
+        &self.signature
+    }
+
+    /// Returns whether this message is a destructor.
+    pub fn is_destructor(&self) -> bool {
+        self.destructor
+    }
+}
+
+impl ArgType {
+    fn name(&self) -> &'static str {
+        match self {
+            ArgType::Int => "int",
+            ArgType::Uint => "uint",
+            ArgType::Fixed => "fixed",
+            ArgType::String { .. } => "string",
+            ArgType::Object { .. } => "object",
+            ArgType::NewId { .. } => "new_id",
+            ArgType::Array => "array",
+            ArgType::Fd => "fd",
+        }
+    }
+
+    fn matches(&self, arg: &UnknownArg<'_>) -> bool {
+        match (self, arg) {
+            (ArgType::Int, UnknownArg::Int(_)) => true,
+            (ArgType::Uint, UnknownArg::Uint(_)) => true,
+            (ArgType::Fixed, UnknownArg::Fixed(_)) => true,
+            (ArgType::String { nullable }, UnknownArg::String(s)) => *nullable || s.is_some(),
+            (ArgType::Object { nullable }, UnknownArg::Object(o)) => *nullable || o.is_some(),
+            (ArgType::NewId { interface }, UnknownArg::NewId(o)) => {
+                interface.as_ref().is_none_or(|i| o.interface_name() == i)
+            }
+            (ArgType::Array, UnknownArg::Array(_)) => true,
+            (ArgType::Fd, UnknownArg::Fd(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl UnknownInterfaces {
+    pub(crate) fn new(
+        forward_all: bool,
+        verbatim_fds: bool,
+        described: Vec<UnknownInterface>,
+    ) -> Self {
+        let described = described
+            .into_iter()
+            .filter(|i| ObjectInterface::from_str(&i.name).is_none())
+            .map(|i| (i.name.clone(), Rc::new(i)))
+            .collect();
+        Self {
+            forward_all,
+            verbatim_fds,
+            described,
+        }
+    }
+
+    /// Returns the maximum version of globals with this unknown interface, if they
+    /// should be forwarded.
+    pub(crate) fn max_version(&self, interface: &str) -> Option<u32> {
+        if let Some(i) = self.described.get(interface) {
+            return Some(i.version);
+        }
+        self.forward_all.then_some(u32::MAX)
+    }
+
+    fn get(&self, interface: &str) -> Option<Rc<UnknownInterface>> {
+        if let Some(i) = self.described.get(interface) {
+            return Some(i.clone());
+        }
+        if self.forward_all {
+            return Some(Rc::new(UnknownInterface::new(interface)));
+        }
+        None
+    }
+
+    pub(crate) fn get_described(&self, interface: &str) -> Option<Rc<UnknownInterface>> {
+        self.described.get(interface).cloned()
+    }
+
+    pub(crate) fn get_or_default(&self, interface: &str) -> Rc<UnknownInterface> {
+        self.get(interface)
+            .unwrap_or_else(|| Rc::new(UnknownInterface::new(interface)))
+    }
+}
+
+pub(crate) fn create_unknown_object(
+    state: &Rc<State>,
+    interface: &str,
+    version: u32,
+) -> Result<Rc<dyn Object>, ObjectError> {
+    let Some(interface) = state.unknown_interfaces.get(interface) else {
+        return Err(ObjectError(ObjectErrorKind::UnsupportedInterface(
+            interface.to_string(),
+        )));
+    };
+    let version = version.min(interface.version);
+    Ok(UnknownObject::create(state, interface, version))
+}
+
+fn create_new_id(
+    state: &Rc<State>,
+    interface: &str,
+    version: u32,
+) -> Result<Rc<dyn Object>, ObjectError> {
+    let version = match ObjectInterface::from_str(interface) {
+        Some(i) => version.min(i.xml_version()),
+        None => version,
+    };
+    create_object_for_interface(state, interface, version)
+}
+
+impl UnknownObject {
+    pub(crate) fn create(
+        state: &Rc<State>,
+        interface: Rc<UnknownInterface>,
+        version: u32,
+    ) -> Rc<Self> {
+        Rc::<Self>::new_cyclic(|slf| Self {
+            core: ObjectCore::new_unknown(state, slf.clone(), &interface.name, version),
+            handler: Default::default(),
+            interface,
+        })
+    }
+
+    /// Returns the description of the interface of this object.
+    pub fn description(&self) -> &Rc<UnknownInterface> {
+        &self.interface
+    }
+
+    /// Sets a new handler.
+    pub fn set_handler(&self, handler: impl UnknownObjectHandler) {
+        self.set_boxed_handler(Box::new(handler));
+    }
+
+    /// Sets a new, already boxed handler.
+    pub fn set_boxed_handler(&self, handler: Box<dyn UnknownObjectHandler>) {
+        if self.core.state.destroyed.get() {
+            return;
+        }
+        self.handler.set(Some(handler));
+    }
+}
+
+impl Debug for UnknownObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnknownObject")
+            .field("interface", &self.interface.name)
+            .field("server_obj_id", &self.core.server_obj_id.get())
+            .field("client_id", &self.core.client_id.get())
+            .field("client_obj_id", &self.core.client_obj_id.get())
+            .finish()
+    }
+}
+
+fn check_args(
+    message: Option<&UnknownMessage>,
+    args: &[UnknownArg<'_>],
+) -> Result<(), ObjectError> {
+    if let Some(message) = message
+        && (message.args.len() != args.len()
+            || message
+                .args
+                .iter()
+                .zip(args)
+                .any(|(ty, arg)| !ty.matches(arg)))
+    {
+        return Err(ObjectError(ObjectErrorKind::SignatureMismatch));
+    }
+    let mut words = 2;
+    for (idx, arg) in args.iter().enumerate() {
+        words += match arg {
+            UnknownArg::String(Some(s)) => 1 + (s.len() + 1).div_ceil(4),
+            UnknownArg::Array(a) => 1 + a.len().div_ceil(4),
+            UnknownArg::NewId(o) if is_untyped_new_id(message, idx) => {
+                3 + (o.interface_name().len() + 1).div_ceil(4)
+            }
+            UnknownArg::Fd(_) => 0,
+            _ => 1,
+        };
+    }
+    if words * 4 > MAX_MESSAGE_SIZE {
+        return Err(ObjectError(ObjectErrorKind::MessageTooLarge(
+            MAX_MESSAGE_SIZE,
+        )));
+    }
+    Ok(())
+}
+
+fn is_untyped_new_id(message: Option<&UnknownMessage>, idx: usize) -> bool {
+    message.is_some_and(|m| matches!(m.args[idx], ArgType::NewId { interface: None }))
+}
+
+fn write_args(
+    fmt: &mut crate::trans::MessageFormatter<'_>,
+    message: Option<&UnknownMessage>,
+    args: &[UnknownArg<'_>],
+    ids: &[u32],
+) {
+    let mut ids = ids.iter();
+    for (idx, arg) in args.iter().enumerate() {
+        match arg {
+            UnknownArg::NewId(o) if is_untyped_new_id(message, idx) => {
+                fmt.string(o.interface_name());
+                fmt.words([o.core().version, *ids.next().unwrap()]);
+            }
+            UnknownArg::Int(v) => fmt.words([*v as u32]),
+            UnknownArg::Uint(v) => fmt.words([*v]),
+            UnknownArg::Fixed(v) => fmt.words([v.to_wire() as u32]),
+            UnknownArg::String(Some(s)) => fmt.string(s),
+            UnknownArg::String(None) | UnknownArg::Object(None) => fmt.words([0]),
+            UnknownArg::Object(Some(_)) | UnknownArg::NewId(_) => fmt.words([*ids.next().unwrap()]),
+            UnknownArg::Array(a) => fmt.array(a),
+            UnknownArg::Fd(fd) => fmt.fds.push_back(fd.clone()),
+        }
+    }
+}
+
+fn parse_args<'a>(
+    state: &Rc<State>,
+    version: u32,
+    message: Option<&UnknownMessage>,
+    msg: &'a [u32],
+    fds: &mut VecDeque<Rc<OwnedFd>>,
+    lookup: impl Fn(u32) -> Result<Rc<dyn Object>, ObjectError>,
+    register: impl Fn(&Rc<dyn Object>, u32) -> Result<(), ObjectError>,
+) -> Result<Vec<UnknownArg<'a>>, ObjectError> {
+    let Some(message) = message else {
+        let mut args: Vec<_> = msg[2..].iter().map(|&w| UnknownArg::Uint(w)).collect();
+        if state.unknown_interfaces.verbatim_fds {
+            args.extend(fds.drain(..).map(UnknownArg::Fd));
+        }
+        return Ok(args);
+    };
+    let mut args = Vec::with_capacity(message.args.len());
+    let mut offset = 2;
+    for ty in &message.args {
+        let name = ty.name();
+        let arg = match ty {
+            ArgType::String { nullable } => {
+                let s;
+                if *nullable {
+                    (s, offset) = parse_string::<NullableString>(msg, offset, name)?;
+                } else {
+                    let ns;
+                    (ns, offset) = parse_string::<NonNullString>(msg, offset, name)?;
+                    s = Some(ns);
+                }
+                UnknownArg::String(s)
+            }
+            ArgType::Array => {
+                let a;
+                (a, offset) = parse_array(msg, offset, name)?;
+                UnknownArg::Array(a)
+            }
+            ArgType::Fd => {
+                let Some(fd) = fds.pop_front() else {
+                    return Err(ObjectError(ObjectErrorKind::MissingFd(name)));
+                };
+                UnknownArg::Fd(fd)
+            }
+            ArgType::NewId { interface: None } => {
+                let interface;
+                (interface, offset) = parse_string::<NonNullString>(msg, offset, name)?;
+                let Some(&[version, id]) = msg.get(offset..offset + 2) else {
+                    return Err(ObjectError(ObjectErrorKind::MissingArgument(name)));
+                };
+                offset += 2;
+                let obj = create_object_for_interface(state, interface, version)?;
+                register(&obj, id)?;
+                UnknownArg::NewId(obj)
+            }
+            _ => {
+                let Some(&word) = msg.get(offset) else {
+                    return Err(ObjectError(ObjectErrorKind::MissingArgument(name)));
+                };
+                offset += 1;
+                match ty {
+                    ArgType::Int => UnknownArg::Int(word as i32),
+                    ArgType::Uint => UnknownArg::Uint(word),
+                    ArgType::Fixed => UnknownArg::Fixed(Fixed::from_wire(word as i32)),
+                    ArgType::Object { nullable: true } if word == 0 => UnknownArg::Object(None),
+                    ArgType::Object { .. } => UnknownArg::Object(Some(lookup(word)?)),
+                    ArgType::NewId {
+                        interface: Some(interface),
+                    } => {
+                        let obj = create_new_id(state, interface, version)?;
+                        register(&obj, word)?;
+                        UnknownArg::NewId(obj)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        };
+        args.push(arg);
+    }
+    if offset != msg.len() {
+        return Err(ObjectError(ObjectErrorKind::TrailingBytes));
+    }
+    Ok(args)
+}
+
+#[cfg(feature = "logging")]
+#[cold]
+#[expect(clippy::too_many_arguments)]
+fn log_message(
+    state: &State,
+    client_id: Option<u64>,
+    direction: Direction,
+    interface: &str,
+    id: u32,
+    opcode: u32,
+    message: Option<&UnknownMessage>,
+    args: &[UnknownArg<'_>],
+    ids: &[u32],
+) {
+    use {
+        crate::logging::{LogArg, LogValue},
+        std::os::fd::AsRawFd,
+    };
+    let name = match message {
+        Some(m) => m.name.clone(),
+        None => format!("msg{}", opcode),
+    };
+    let mut ids = ids.iter();
+    let args: Vec<_> = args
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| {
+            let value = match arg {
+                UnknownArg::Int(v) => LogValue::Int(*v),
+                UnknownArg::Uint(v) => LogValue::Uint(*v),
+                UnknownArg::Fixed(v) => LogValue::Fixed(*v),
+                UnknownArg::String(s) => LogValue::String(*s),
+                UnknownArg::Object(None) => LogValue::Object {
+                    interface: None,
+                    id: 0,
+                },
+                UnknownArg::Object(Some(o)) => LogValue::Object {
+                    interface: Some(o.interface_name()),
+                    id: *ids.next().unwrap(),
+                },
+                UnknownArg::NewId(o) => LogValue::NewId {
+                    interface: o.interface_name(),
+                    id: *ids.next().unwrap(),
+                    version: None,
+                },
+                UnknownArg::Array(a) => LogValue::Array(a),
+                UnknownArg::Fd(fd) => LogValue::Fd(fd.as_raw_fd()),
+            };
+            let name = message.and_then(|m| m.arg_names.get(idx));
+            LogArg::new(name.map(|n| &**n), value)
+        })
+        .collect();
+    state.log(client_id, direction, interface, id, &name, &args);
+}
+
+impl UnknownObject {
+    /// Sends a request to the server.
+    ///
+    /// If the interface has been described, the arguments must match the signature of
+    /// the request. Otherwise, the arguments are sent as they are.
+    pub fn try_send_request(
+        &self,
+        opcode: u32,
+        args: &[UnknownArg<'_>],
+    ) -> Result<(), ObjectError> {
+        let message = self.interface.request(opcode)?;
+        check_args(message, args)?;
+        let core = self.core();
+        let Some(id) = core.server_obj_id.get() else {
+            return Err(ObjectError(ObjectErrorKind::ReceiverNoServerId));
+        };
+        let mut ids = Vec::new();
+        for arg in args {
+            match arg {
+                UnknownArg::Object(Some(o)) => match o.core().server_obj_id.get() {
+                    None => return Err(ObjectError(ObjectErrorKind::ArgNoServerId("object"))),
+                    Some(id) => ids.push(id),
+                },
+                UnknownArg::NewId(o) => {
+                    o.core()
+                        .generate_server_id(o.clone())
+                        .map_err(|e| ObjectError(ObjectErrorKind::GenerateServerId("new_id", e)))?;
+                    ids.push(o.core().server_obj_id.get().unwrap_or(0));
+                }
+                _ => {}
+            }
+        }
+        #[cfg(feature = "logging")]
+        if self.core.state.log {
+            log_message(
+                &self.core.state,
+                None,
+                Direction::Sent,
+                &self.interface.name,
+                id,
+                opcode,
+                message,
+                args,
+                &ids,
+            );
+        }
+        let Some(endpoint) = &self.core.state.server else {
+            return Ok(());
+        };
+        if !endpoint.flush_queued.replace(true) {
+            self.core.state.add_flushable_endpoint(endpoint, None);
+        }
+        let mut outgoing_ref = endpoint.outgoing.borrow_mut();
+        let outgoing = &mut *outgoing_ref;
+        let mut fmt = outgoing.formatter();
+        fmt.words([id, opcode]);
+        write_args(&mut fmt, message, args, &ids);
+        drop(fmt);
+        drop(outgoing_ref);
+        if message.is_some_and(|m| m.destructor) {
+            self.core.handle_server_destroy();
+        }
+        Ok(())
+    }
+
+    /// Sends a request to the server.
+    ///
+    /// If the interface has been described, the arguments must match the signature of
+    /// the request. Otherwise, the arguments are sent as they are.
+    pub fn send_request(&self, opcode: u32, args: &[UnknownArg<'_>]) {
+        let res = self.try_send_request(opcode, args);
+        if let Err(e) = res {
+            log_send(&format!("{}.request#{}", self.interface.name, opcode), &e);
+        }
+    }
+
+    /// Sends an event to the client.
+    ///
+    /// If the interface has been described, the arguments must match the signature of
+    /// the event. Otherwise, the arguments are sent as they are.
+    pub fn try_send_event(&self, opcode: u32, args: &[UnknownArg<'_>]) -> Result<(), ObjectError> {
+        let message = self.interface.event(opcode)?;
+        check_args(message, args)?;
+        let core = self.core();
+        let client_ref = core.client.borrow();
+        let Some(client) = &*client_ref else {
+            return Err(ObjectError(ObjectErrorKind::ReceiverNoClient));
+        };
+        let id = core.client_obj_id.get().unwrap_or(0);
+        let mut ids = Vec::new();
+        for arg in args {
+            match arg {
+                UnknownArg::Object(Some(o)) => {
+                    let o = o.core();
+                    if o.client_id.get() != Some(client.endpoint.id) {
+                        return Err(ObjectError(ObjectErrorKind::ArgNoClientId(
+                            "object",
+                            client.endpoint.id,
+                        )));
+                    }
+                    ids.push(o.client_obj_id.get().unwrap_or(0));
+                }
+                UnknownArg::NewId(o) => {
+                    o.core()
+                        .generate_client_id(client, o.clone())
+                        .map_err(|e| ObjectError(ObjectErrorKind::GenerateClientId("new_id", e)))?;
+                    ids.push(o.core().client_obj_id.get().unwrap_or(0));
+                }
+                _ => {}
+            }
+        }
+        #[cfg(feature = "logging")]
+        if self.core.state.log {
+            log_message(
+                &self.core.state,
+                Some(client.endpoint.id),
+                Direction::Sent,
+                &self.interface.name,
+                id,
+                opcode,
+                message,
+                args,
+                &ids,
+            );
+        }
+        let endpoint = &client.endpoint;
+        if !endpoint.flush_queued.replace(true) {
+            self.core
+                .state
+                .add_flushable_endpoint(endpoint, Some(client));
+        }
+        let mut outgoing_ref = endpoint.outgoing.borrow_mut();
+        let outgoing = &mut *outgoing_ref;
+        let mut fmt = outgoing.formatter();
+        fmt.words([id, opcode]);
+        write_args(&mut fmt, message, args, &ids);
+        drop(fmt);
+        drop(outgoing_ref);
+        drop(client_ref);
+        if message.is_some_and(|m| m.destructor) {
+            self.core.handle_client_destroy();
+        }
+        Ok(())
+    }
+
+    /// Sends an event to the client.
+    ///
+    /// If the interface has been described, the arguments must match the signature of
+    /// the event. Otherwise, the arguments are sent as they are.
+    pub fn send_event(&self, opcode: u32, args: &[UnknownArg<'_>]) {
+        let res = self.try_send_event(opcode, args);
+        if let Err(e) = res {
+            log_send(&format!("{}.event#{}", self.interface.name, opcode), &e);
+        }
+    }
+}
+
+/// A message handler for [`UnknownObject`] proxies.
+pub trait UnknownObjectHandler: Any {
+    /// Event handler for wl_display.delete_id messages deleting the ID of this object.
+    ///
+    /// The default handler forwards the event to the client, if any.
+    #[inline]
+    fn delete_id(&mut self, slf: &Rc<UnknownObject>) {
+        slf.core.delete_id();
+    }
+
+    /// Handles a request sent by the client.
+    ///
+    /// The default handler forwards the request to the server.
+    #[inline]
+    fn handle_request(&mut self, slf: &Rc<UnknownObject>, opcode: u32, args: &[UnknownArg<'_>]) {
+        if !slf.core.forward_to_server.get() {
+            return;
+        }
+        let res = slf.try_send_request(opcode, args);
+        if let Err(e) = res {
+            log_forward(&format!("{}.request#{}", slf.interface.name, opcode), &e);
+        }
+    }
+
+    /// Handles an event sent by the server.
+    ///
+    /// The default handler forwards the event to the client, if any.
+    #[inline]
+    fn handle_event(&mut self, slf: &Rc<UnknownObject>, opcode: u32, args: &[UnknownArg<'_>]) {
+        if !slf.core.forward_to_client.get() {
+            return;
+        }
+        if let Some(client_id) = slf.core.client_id.get() {
+            for arg in args {
+                if let UnknownArg::Object(Some(o)) = arg
+                    && let Some(client_id_2) = o.core().client_id.get()
+                    && client_id != client_id_2
+                {
+                    return;
+                }
+            }
+        }
+        let res = slf.try_send_event(opcode, args);
+        if let Err(e) = res {
+            log_forward(&format!("{}.event#{}", slf.interface.name, opcode), &e);
+        }
+    }
+}
+
+impl ObjectPrivate for UnknownObject {
+    fn new(state: &Rc<State>, version: u32) -> Rc<Self> {
+        let interface = Rc::new(UnknownInterface::new(ObjectInterface::Unknown.name()));
+        Self::create(state, interface, version)
+    }
+
+    fn delete_id(self: Rc<Self>) -> Result<(), (ObjectError, Rc<dyn Object>)> {
+        let Some(mut handler) = self.handler.try_borrow_mut() else {
+            return Err((ObjectError(ObjectErrorKind::HandlerBorrowed), self));
+        };
+        if let Some(handler) = &mut *handler {
+            handler.delete_id(&self);
+        } else {
+            self.core.delete_id();
+        }
+        Ok(())
+    }
+
+    fn handle_request(
+        self: Rc<Self>,
+        client: &Rc<Client>,
+        msg: &[u32],
+        fds: &mut VecDeque<Rc<OwnedFd>>,
+    ) -> Result<(), ObjectError> {
+        let Some(mut handler) = self.handler.try_borrow_mut() else {
+            return Err(ObjectError(ObjectErrorKind::HandlerBorrowed));
+        };
+        let handler = &mut *handler;
+        let opcode = msg[1] & 0xffff;
+        let message = self.interface.request(opcode)?;
+        let args = parse_args(
+            &self.core.state,
+            self.core.version,
+            message,
+            msg,
+            fds,
+            |id| {
+                client
+                    .endpoint
+                    .lookup(id)
+                    .ok_or(ObjectError(ObjectErrorKind::NoClientObject(
+                        client.endpoint.id,
+                        id,
+                    )))
+            },
+            |obj, id| {
+                obj.core()
+                    .set_client_id(client, id, obj.clone())
+                    .map_err(|e| ObjectError(ObjectErrorKind::SetClientId(id, "new_id", e)))
+            },
+        )?;
+        #[cfg(feature = "logging")]
+        if self.core.state.log {
+            let ids: Vec<_> = args
+                .iter()
+                .filter_map(|arg| match arg {
+                    UnknownArg::Object(Some(o)) | UnknownArg::NewId(o) => {
+                        Some(o.core().client_obj_id.get().unwrap_or(0))
+                    }
+                    _ => None,
+                })
+                .collect();
+            log_message(
+                &self.core.state,
+                Some(client.endpoint.id),
+                Direction::Received,
+                &self.interface.name,
+                msg[0],
+                opcode,
+                message,
+                &args,
+                &ids,
+            );
+        }
+        if message.is_some_and(|m| m.destructor) {
+            self.core.handle_client_destroy();
+        }
+        if let Some(handler) = handler {
+            (**handler).handle_request(&self, opcode, &args);
+        } else {
+            DefaultHandler.handle_request(&self, opcode, &args);
+        }
+        Ok(())
+    }
+
+    fn handle_event(
+        self: Rc<Self>,
+        server: &Endpoint,
+        msg: &[u32],
+        fds: &mut VecDeque<Rc<OwnedFd>>,
+    ) -> Result<(), ObjectError> {
+        let Some(mut handler) = self.handler.try_borrow_mut() else {
+            return Err(ObjectError(ObjectErrorKind::HandlerBorrowed));
+        };
+        let handler = &mut *handler;
+        let opcode = msg[1] & 0xffff;
+        let message = self.interface.event(opcode)?;
+        let args = parse_args(
+            &self.core.state,
+            self.core.version,
+            message,
+            msg,
+            fds,
+            |id| {
+                server
+                    .lookup(id)
+                    .ok_or(ObjectError(ObjectErrorKind::NoServerObject(id)))
+            },
+            |obj, id| {
+                obj.core()
+                    .set_server_id(id, obj.clone())
+                    .map_err(|e| ObjectError(ObjectErrorKind::SetServerId(id, "new_id", e)))
+            },
+        )?;
+        #[cfg(feature = "logging")]
+        if self.core.state.log {
+            let ids: Vec<_> = args
+                .iter()
+                .filter_map(|arg| match arg {
+                    UnknownArg::Object(Some(o)) | UnknownArg::NewId(o) => {
+                        Some(o.core().server_obj_id.get().unwrap_or(0))
+                    }
+                    _ => None,
+                })
+                .collect();
+            log_message(
+                &self.core.state,
+                None,
+                Direction::Received,
+                &self.interface.name,
+                msg[0],
+                opcode,
+                message,
+                &args,
+                &ids,
+            );
+        }
+        if message.is_some_and(|m| m.destructor) {
+            self.core.handle_server_destroy();
+        }
+        if let Some(handler) = handler {
+            (**handler).handle_event(&self, opcode, &args);
+        } else {
+            DefaultHandler.handle_event(&self, opcode, &args);
+        }
+        Ok(())
+    }
+
+    fn get_request_name(&self, id: u32) -> Option<&str> {
+        Some(self.interface.request(id).ok()??.name())
+    }
+
+    fn get_event_name(&self, id: u32) -> Option<&str> {
+        Some(self.interface.event(id).ok()??.name())
+    }
+
+    fn get_request_signature(&self, id: u32) -> Option<&str> {
+        Some(self.interface.request(id).ok()??.signature())
+    }
+
+    fn get_event_signature(&self, id: u32) -> Option<&str> {
+        Some(self.interface.event(id).ok()??.signature())
+    }
+
+    fn create_zombie(&self) -> Rc<dyn Object> {
+        let slf = Self::create(&self.core.state, self.interface.clone(), self.core.version);
+        slf.core.make_zombie();
+        slf
+    }
+}
+
+impl Object for UnknownObject {
+    fn core(&self) -> &ObjectCore {
+        &self.core
+    }
+
+    fn unset_handler(&self) {
+        self.handler.set(None);
+    }
+
+    fn get_handler_any_ref(&self) -> Result<HandlerRef<'_, dyn Any>, HandlerAccessError> {
+        let borrowed = self
+            .handler
+            .try_borrow()
+            .ok_or(HandlerAccessError::AlreadyBorrowed)?;
+        if borrowed.is_none() {
+            return Err(HandlerAccessError::NoHandler);
+        }
+        Ok(HandlerRef::map(borrowed, |handler| {
+            &**handler.as_ref().unwrap() as &dyn Any
+        }))
+    }
+
+    fn get_handler_any_mut(&self) -> Result<HandlerMut<'_, dyn Any>, HandlerAccessError> {
+        let borrowed = self
+            .handler
+            .try_borrow_mut()
+            .ok_or(HandlerAccessError::AlreadyBorrowed)?;
+        if borrowed.is_none() {
+            return Err(HandlerAccessError::NoHandler);
+        }
+        Ok(HandlerMut::map(borrowed, |handler| {
+            &mut **handler.as_mut().unwrap() as &mut dyn Any
+        }))
+    }
+}
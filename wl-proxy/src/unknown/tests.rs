@@ -0,0 +1,639 @@
+use {
+    crate::{
+        baseline::Baseline,
+        interceptor::{InterceptedMessage, Interception, MessageArg},
+        object::{Object, ObjectCoreApi, ObjectRcUtils},
+        protocols::{
+            ObjectInterface,
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+                wl_shm::WlShm,
+            },
+        },
+        state::{Destructor, State, StateBuilder},
+        test_framework::{install_logger, proxy::dispatch_blocking},
+        unknown::{
+            UnknownArg, UnknownInterface, UnknownMessage, UnknownObject, UnknownObjectHandler,
+        },
+    },
+    std::{
+        cell::{Cell, RefCell},
+        os::fd::{AsRawFd, OwnedFd},
+        rc::Rc,
+    },
+    uapi::c,
+};
+
+const INTERFACE: &str = "wp_example_v1";
+
+fn interface() -> UnknownInterface {
+    UnknownInterface::new(INTERFACE)
+        .with_request(UnknownMessage::new("create", "n[wp_example_v1]").unwrap())
+        .with_request(UnknownMessage::new("send", "?ohsia").unwrap())
+        .with_request(
+            UnknownMessage::new("destroy", "")
+                .unwrap()
+                .with_destructor(true),
+        )
+        .with_event(UnknownMessage::new("received", "?ohsia").unwrap())
+}
+
+struct Chain {
+    _destructors: Vec<Destructor>,
+    server: Rc<State>,
+    proxy: Rc<State>,
+    client: Rc<State>,
+    display: Rc<WlDisplay>,
+    global: Rc<Cell<Option<(u32, u32)>>>,
+    registry: Rc<WlRegistry>,
+}
+
+struct ServerDisplayHandler;
+
+impl WlDisplayHandler for ServerDisplayHandler {
+    fn handle_sync(&mut self, _slf: &Rc<WlDisplay>, callback: &Rc<WlCallback>) {
+        callback.send_done(0);
+        callback.delete_id();
+    }
+
+    fn handle_get_registry(&mut self, _slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(ServerRegistryHandler);
+        registry.send_unknown_global(1, INTERFACE, 2);
+        registry.send_global(2, ObjectInterface::WlShm, 1);
+    }
+}
+
+struct ServerRegistryHandler;
+
+impl WlRegistryHandler for ServerRegistryHandler {
+    fn handle_bind(&mut self, _slf: &Rc<WlRegistry>, _name: u32, id: Rc<dyn Object>) {
+        if let Some(id) = id.try_downcast::<UnknownObject>() {
+            id.set_handler(ServerObjectHandler);
+        }
+    }
+}
+
+struct ServerObjectHandler;
+
+impl UnknownObjectHandler for ServerObjectHandler {
+    fn handle_request(&mut self, slf: &Rc<UnknownObject>, opcode: u32, args: &[UnknownArg<'_>]) {
+        match opcode {
+            0 => {
+                let UnknownArg::NewId(child) = &args[0] else {
+                    unreachable!();
+                };
+                child
+                    .downcast::<UnknownObject>()
+                    .set_handler(ServerObjectHandler);
+            }
+            1 => slf.send_event(0, args),
+            2 => slf.delete_id(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct ClientRegistryHandler(Rc<Cell<Option<(u32, u32)>>>);
+
+impl WlRegistryHandler for ClientRegistryHandler {
+    fn handle_unknown_global(
+        &mut self,
+        _slf: &Rc<WlRegistry>,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        assert_eq!(interface, INTERFACE);
+        self.0.set(Some((name, version)));
+    }
+}
+
+fn chain(
+    peer: impl Fn(StateBuilder) -> StateBuilder,
+    proxy: impl FnOnce(StateBuilder) -> StateBuilder,
+) -> Chain {
+    install_logger();
+    let server = peer(
+        State::builder(Baseline::ALL_OF_THEM)
+            .without_server()
+            .with_logging(true)
+            .with_log_prefix("server"),
+    )
+    .build()
+    .unwrap();
+    server.set_default_forward_to_server(false);
+    let (server_client, server_fd) = server.connect().unwrap();
+    server_client.display().set_handler(ServerDisplayHandler);
+    let proxy = proxy(
+        State::builder(Baseline::ALL_OF_THEM)
+            .with_server_fd(&Rc::new(server_fd))
+            .with_logging(true)
+            .with_log_prefix("proxy "),
+    )
+    .build()
+    .unwrap();
+    let (_, client_fd) = proxy.connect().unwrap();
+    let client = peer(
+        State::builder(Baseline::ALL_OF_THEM)
+            .with_server_fd(&Rc::new(client_fd))
+            .with_logging(true)
+            .with_log_prefix("client"),
+    )
+    .build()
+    .unwrap();
+    client.set_default_forward_to_client(false);
+    let display = client.display();
+    let registry = display.new_send_get_registry();
+    let global = Rc::new(Cell::new(None));
+    registry.set_handler(ClientRegistryHandler(global.clone()));
+    let chain = Chain {
+        _destructors: vec![
+            server.create_destructor(),
+            proxy.create_destructor(),
+            client.create_destructor(),
+        ],
+        server,
+        proxy,
+        client,
+        display,
+        global,
+        registry,
+    };
+    chain.sync();
+    chain
+}
+
+impl Chain {
+    fn dispatch_blocking(&self) {
+        dispatch_blocking([&self.server, &self.proxy, &self.client]).unwrap();
+    }
+
+    fn sync(&self) {
+        struct H(Rc<Cell<bool>>);
+        impl WlCallbackHandler for H {
+            fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+                self.0.set(true);
+            }
+        }
+        let done = Rc::new(Cell::new(false));
+        self.display.new_send_sync().set_handler(H(done.clone()));
+        while !done.get() {
+            self.dispatch_blocking();
+        }
+    }
+}
+
+#[derive(Default)]
+struct Received {
+    object: Option<u64>,
+    fd: Option<Rc<OwnedFd>>,
+    string: Option<String>,
+    int: Option<i32>,
+    array: Option<Vec<u8>>,
+    words: Option<Vec<u32>>,
+}
+
+struct ClientObjectHandler(Rc<RefCell<Received>>);
+
+impl UnknownObjectHandler for ClientObjectHandler {
+    fn handle_event(&mut self, _slf: &Rc<UnknownObject>, opcode: u32, args: &[UnknownArg<'_>]) {
+        assert_eq!(opcode, 0);
+        let r = &mut *self.0.borrow_mut();
+        for arg in args {
+            match arg {
+                UnknownArg::Object(o) => r.object = o.as_ref().map(|o| o.unique_id()),
+                UnknownArg::Fd(fd) => r.fd = Some(fd.clone()),
+                UnknownArg::String(s) => r.string = s.map(|s| s.to_string()),
+                UnknownArg::Int(i) => r.int = Some(*i),
+                UnknownArg::Array(a) => r.array = Some(a.to_vec()),
+                UnknownArg::Uint(u) => r.words.get_or_insert_default().push(*u),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+fn same_file(a: &OwnedFd, b: &OwnedFd) -> bool {
+    let a = uapi::fstat(a.as_raw_fd()).unwrap();
+    let b = uapi::fstat(b.as_raw_fd()).unwrap();
+    (a.st_dev, a.st_ino) == (b.st_dev, b.st_ino)
+}
+
+#[test]
+fn described() {
+    let chain = chain(
+        |s| s.with_unknown_interface(interface()),
+        |s| s.with_unknown_interface(interface()),
+    );
+    let (name, version) = chain.global.get().unwrap();
+    assert_eq!(version, 2);
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    assert_eq!(obj.interface_name(), INTERFACE);
+    let received = Rc::new(RefCell::new(Received::default()));
+    obj.set_handler(ClientObjectHandler(received.clone()));
+    chain.registry.send_bind(name, obj.clone());
+    let child = chain.client.create_unknown_object(INTERFACE, 1);
+    obj.try_send_request(0, &[UnknownArg::NewId(child.clone())])
+        .unwrap();
+    let (read, _write) = uapi::pipe().unwrap();
+    let fd = Rc::new(OwnedFd::from(read));
+    obj.try_send_request(
+        1,
+        &[
+            UnknownArg::Object(Some(child.clone())),
+            UnknownArg::Fd(fd.clone()),
+            UnknownArg::String(Some("hello")),
+            UnknownArg::Int(-1),
+            UnknownArg::Array(&[1, 2, 3]),
+        ],
+    )
+    .unwrap();
+    chain.sync();
+    let r = received.borrow();
+    assert_eq!(r.object, Some(child.unique_id()));
+    assert!(same_file(r.fd.as_ref().unwrap(), &fd));
+    assert_eq!(r.string.as_deref(), Some("hello"));
+    assert_eq!(r.int, Some(-1));
+    assert_eq!(r.array.as_deref(), Some(&[1, 2, 3][..]));
+    drop(r);
+    child.try_send_request(2, &[]).unwrap();
+    chain.sync();
+}
+
+#[test]
+fn names() {
+    use crate::object::ObjectPrivate;
+
+    assert_eq!(
+        UnknownMessage::new("send", "?ohsia").unwrap().signature(),
+        "?ohsia",
+    );
+    let state = State::builder(Baseline::ALL_OF_THEM)
+        .without_server()
+        .with_unknown_interface(interface())
+        .build()
+        .unwrap();
+    let obj = state.create_unknown_object(INTERFACE, 1);
+    assert_eq!(obj.get_request_name(1), Some("send"));
+    assert_eq!(obj.get_request_signature(0), Some("n[wp_example_v1]"));
+    assert_eq!(obj.get_request_name(3), None);
+    assert_eq!(obj.get_event_name(0), Some("received"));
+    assert_eq!(obj.get_event_signature(0), Some("?ohsia"));
+    let obj = state.create_unknown_object("wp_other_v1", 1);
+    assert_eq!(obj.get_request_name(0), None);
+}
+
+fn replace_string(message: &InterceptedMessage<'_>) -> Interception {
+    if message.interface_name() != INTERFACE || message.name() != Some("send") {
+        return Interception::Forward;
+    }
+    let mut args = message.args().unwrap();
+    args[2] = MessageArg::String(Some("replaced"));
+    message.replace(message.opcode(), &args).unwrap()
+}
+
+#[test]
+fn intercepted() {
+    let chain = chain(
+        |s| s.with_unknown_interface(interface()),
+        |s| {
+            s.with_unknown_interface(interface())
+                .with_interceptor(replace_string)
+        },
+    );
+    let (name, _) = chain.global.get().unwrap();
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    let received = Rc::new(RefCell::new(Received::default()));
+    obj.set_handler(ClientObjectHandler(received.clone()));
+    chain.registry.send_bind(name, obj.clone());
+    let (read, _write) = uapi::pipe().unwrap();
+    let fd = Rc::new(OwnedFd::from(read));
+    obj.try_send_request(
+        1,
+        &[
+            UnknownArg::Object(None),
+            UnknownArg::Fd(fd.clone()),
+            UnknownArg::String(Some("hello")),
+            UnknownArg::Int(1),
+            UnknownArg::Array(&[]),
+        ],
+    )
+    .unwrap();
+    chain.sync();
+    let r = received.borrow();
+    assert_eq!(r.string.as_deref(), Some("replaced"));
+    assert!(same_file(r.fd.as_ref().unwrap(), &fd));
+}
+
+#[test]
+fn signature_mismatch() {
+    let chain = chain(
+        |s| s.with_unknown_interface(interface()),
+        |s| s.with_unknown_interface(interface()),
+    );
+    let (name, version) = chain.global.get().unwrap();
+    assert_eq!(version, 2);
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    chain.registry.send_bind(name, obj.clone());
+    assert!(obj.try_send_request(1, &[UnknownArg::Int(1)]).is_err());
+    assert!(obj.try_send_request(3, &[]).is_err());
+    let array = [0; 4096];
+    let (read, _write) = uapi::pipe().unwrap();
+    let res = obj.try_send_request(
+        1,
+        &[
+            UnknownArg::Object(None),
+            UnknownArg::Fd(Rc::new(read.into())),
+            UnknownArg::String(None),
+            UnknownArg::Int(0),
+            UnknownArg::Array(&array),
+        ],
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn verbatim() {
+    let chain = chain(
+        |s| {
+            s.with_unknown_interface(
+                UnknownInterface::new(INTERFACE)
+                    .with_request(UnknownMessage::new("send", "uu").unwrap())
+                    .with_event(UnknownMessage::new("received", "uu").unwrap()),
+            )
+        },
+        |s| s.with_unknown_interfaces(true),
+    );
+    let (name, version) = chain.global.get().unwrap();
+    assert_eq!(version, 2);
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    let received = Rc::new(RefCell::new(Received::default()));
+    obj.set_handler(ClientObjectHandler(received.clone()));
+    chain.registry.send_bind(name, obj.clone());
+    chain.sync();
+    let server_obj = chain
+        .server
+        .all_objects
+        .borrow()
+        .values()
+        .filter_map(|o| o.upgrade())
+        .find(|o| o.interface_name() == INTERFACE)
+        .unwrap();
+    server_obj
+        .downcast::<UnknownObject>()
+        .set_handler(VerbatimServerHandler);
+    obj.try_send_request(0, &[UnknownArg::Uint(1), UnknownArg::Uint(2)])
+        .unwrap();
+    chain.sync();
+    assert_eq!(received.borrow().words.as_deref(), Some(&[1, 2][..]));
+}
+
+struct VerbatimServerHandler;
+
+impl UnknownObjectHandler for VerbatimServerHandler {
+    fn handle_request(&mut self, slf: &Rc<UnknownObject>, opcode: u32, args: &[UnknownArg<'_>]) {
+        slf.send_event(opcode, args);
+    }
+}
+
+#[cfg(feature = "logging")]
+#[test]
+fn log_null_object() {
+    use crate::logging::LogRecord;
+
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let chain = chain(
+        |s| {
+            let records = records.clone();
+            s.with_unknown_interface(
+                UnknownInterface::new(INTERFACE)
+                    .with_request(UnknownMessage::new("send", "?oo").unwrap())
+                    .with_event(UnknownMessage::new("received", "?oo").unwrap()),
+            )
+            .with_log_sink(move |record: &LogRecord<'_>| {
+                if record.interface() != INTERFACE {
+                    return;
+                }
+                let args: Vec<_> = record
+                    .args()
+                    .iter()
+                    .map(|arg| arg.value().to_string())
+                    .collect();
+                let object = format!("{INTERFACE}#{}", record.object_id());
+                records.borrow_mut().push((
+                    record.message().to_string(),
+                    args,
+                    vec!["unknown#0".to_string(), object],
+                ));
+            })
+        },
+        |s| s.with_unknown_interfaces(true),
+    );
+    let (name, _) = chain.global.get().unwrap();
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    let received = Rc::new(RefCell::new(Received::default()));
+    obj.set_handler(ClientObjectHandler(received.clone()));
+    chain.registry.send_bind(name, obj.clone());
+    chain.sync();
+    let server_obj = chain
+        .server
+        .all_objects
+        .borrow()
+        .values()
+        .filter_map(|o| o.upgrade())
+        .find(|o| o.interface_name() == INTERFACE)
+        .unwrap();
+    server_obj
+        .downcast::<UnknownObject>()
+        .set_handler(VerbatimServerHandler);
+    obj.try_send_request(
+        0,
+        &[
+            UnknownArg::Object(None),
+            UnknownArg::Object(Some(obj.clone())),
+        ],
+    )
+    .unwrap();
+    chain.sync();
+    assert_eq!(received.borrow().object, Some(obj.unique_id()));
+    let records = records.borrow();
+    let messages: Vec<_> = records.iter().map(|r| &*r.0).collect();
+    assert_eq!(messages, ["send", "send", "received", "received"]);
+    for (_, args, expected) in &*records {
+        assert_eq!(args, expected);
+    }
+}
+
+#[test]
+fn not_forwarded_by_default() {
+    let chain = chain(|s| s.with_unknown_interface(interface()), |s| s);
+    assert_eq!(chain.global.get(), None);
+}
+
+#[test]
+fn verbatim_fds() {
+    let chain = chain(
+        |s| {
+            s.with_unknown_interface(
+                UnknownInterface::new(INTERFACE)
+                    .with_request(UnknownMessage::new("send", "uh").unwrap())
+                    .with_event(UnknownMessage::new("received", "uh").unwrap()),
+            )
+        },
+        |s| s.with_unknown_interfaces(true).with_verbatim_fds(true),
+    );
+    let (name, _) = chain.global.get().unwrap();
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    let received = Rc::new(RefCell::new(Received::default()));
+    obj.set_handler(ClientObjectHandler(received.clone()));
+    chain.registry.send_bind(name, obj.clone());
+    chain.sync();
+    let server_obj = chain
+        .server
+        .all_objects
+        .borrow()
+        .values()
+        .filter_map(|o| o.upgrade())
+        .find(|o| o.interface_name() == INTERFACE)
+        .unwrap();
+    server_obj
+        .downcast::<UnknownObject>()
+        .set_handler(VerbatimServerHandler);
+    let (read, _write) = uapi::pipe().unwrap();
+    let fd = Rc::new(OwnedFd::from(read));
+    obj.try_send_request(0, &[UnknownArg::Uint(1), UnknownArg::Fd(fd.clone())])
+        .unwrap();
+    chain.sync();
+    let r = received.borrow();
+    assert_eq!(r.words.as_deref(), Some(&[1][..]));
+    assert!(same_file(r.fd.as_ref().unwrap(), &fd));
+}
+
+fn drop_fds(message: &InterceptedMessage<'_>) -> Interception {
+    if message.interface_name() != INTERFACE || !message.is_request() {
+        return Interception::Forward;
+    }
+    match &*message.args().unwrap() {
+        [MessageArg::Uint(1), MessageArg::Fd(_)] => Interception::Drop,
+        _ => Interception::Forward,
+    }
+}
+
+struct RecordingServerHandler(Rc<RefCell<Vec<String>>>);
+
+impl UnknownObjectHandler for RecordingServerHandler {
+    fn handle_request(&mut self, _slf: &Rc<UnknownObject>, _opcode: u32, args: &[UnknownArg<'_>]) {
+        for arg in args {
+            let arg = match arg {
+                UnknownArg::Uint(u) => u.to_string(),
+                UnknownArg::Fd(_) => "fd".to_string(),
+                _ => unreachable!(),
+            };
+            self.0.borrow_mut().push(arg);
+        }
+    }
+}
+
+#[test]
+fn intercepted_verbatim_fds() {
+    let chain = chain(
+        |s| s.with_unknown_interfaces(true),
+        |s| {
+            s.with_unknown_interfaces(true)
+                .with_verbatim_fds(true)
+                .with_interceptor(drop_fds)
+        },
+    );
+    let (name, _) = chain.global.get().unwrap();
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    chain.registry.send_bind(name, obj.clone());
+    chain.sync();
+    let server_obj = chain
+        .server
+        .all_objects
+        .borrow()
+        .values()
+        .filter_map(|o| o.upgrade())
+        .find(|o| o.interface_name() == INTERFACE)
+        .unwrap();
+    let received = Rc::new(RefCell::new(vec![]));
+    server_obj
+        .downcast::<UnknownObject>()
+        .set_handler(RecordingServerHandler(received.clone()));
+    // The file descriptor of the dropped message is not forwarded with the next one.
+    let (read, _write) = uapi::pipe().unwrap();
+    obj.try_send_request(
+        0,
+        &[UnknownArg::Uint(1), UnknownArg::Fd(Rc::new(read.into()))],
+    )
+    .unwrap();
+    obj.try_send_request(0, &[UnknownArg::Uint(2), UnknownArg::Uint(3)])
+        .unwrap();
+    chain.sync();
+    assert_eq!(*received.borrow(), ["2", "3"]);
+}
+
+#[test]
+fn verbatim_without_fds() {
+    let chain = chain(
+        |s| s.with_unknown_interfaces(true),
+        |s| s.with_unknown_interfaces(true),
+    );
+    let (name, _) = chain.global.get().unwrap();
+    let obj = chain.client.create_unknown_object(INTERFACE, 1);
+    chain.registry.send_bind(name, obj.clone());
+    let shm = chain.client.create_object::<WlShm>(1);
+    chain.registry.send_bind(2, shm.clone());
+    chain.sync();
+    let server_obj = chain
+        .server
+        .all_objects
+        .borrow()
+        .values()
+        .filter_map(|o| o.upgrade())
+        .find(|o| o.interface_name() == INTERFACE)
+        .unwrap();
+    let received = Rc::new(RefCell::new(vec![]));
+    server_obj
+        .downcast::<UnknownObject>()
+        .set_handler(RecordingServerHandler(received.clone()));
+    // The file descriptor of the second message is not forwarded with the first one.
+    let memfd = uapi::memfd_create("test", c::MFD_CLOEXEC).unwrap();
+    uapi::ftruncate(memfd.raw(), 4096).unwrap();
+    obj.try_send_request(0, &[UnknownArg::Uint(1)]).unwrap();
+    shm.new_send_create_pool(&Rc::new(memfd.into()), 4096);
+    chain.sync();
+    assert_eq!(*received.borrow(), ["1"]);
+    let has_pool = chain
+        .server
+        .all_objects
+        .borrow()
+        .values()
+        .filter_map(|o| o.upgrade())
+        .any(|o| o.interface_name() == "wl_shm_pool");
+    assert!(has_pool);
+}
+
+#[test]
+fn max_version() {
+    let chain = chain(
+        |s| s.with_unknown_interface(interface()),
+        |s| s.with_unknown_interface(interface().with_version(1)),
+    );
+    let (name, version) = chain.global.get().unwrap();
+    assert_eq!(version, 1);
+    let obj = chain.client.create_unknown_object(INTERFACE, 2);
+    chain.registry.send_bind(name, obj.clone());
+    chain.sync();
+    let server_obj = chain
+        .server
+        .all_objects
+        .borrow()
+        .values()
+        .filter_map(|o| o.upgrade())
+        .find(|o| o.interface_name() == INTERFACE)
+        .unwrap();
+    assert_eq!(server_obj.version(), 1);
+}
@@ -1,5 +1,7 @@
 pub(crate) mod cold_path;
 pub(crate) mod env;
 pub(crate) mod free_list;
+#[cfg(any(feature = "logging", feature = "policy"))]
+pub(crate) mod glob;
 pub(crate) mod stack;
 pub(crate) mod stash;
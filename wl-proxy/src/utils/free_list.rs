@@ -33,6 +33,14 @@ impl<T, const N: usize> Debug for FreeList<T, N> {
 }
 
 impl<T, const N: usize> FreeList<T, N> {
+    #[cfg_attr(not(feature = "recovery"), expect(dead_code))]
+    pub(crate) fn clear(&self) {
+        let levels = unsafe { &mut *self.levels.get() };
+        for level in levels {
+            level.clear();
+        }
+    }
+
     pub(crate) fn release(&self, n: T)
     where
         T: Into<u32>,
@@ -0,0 +1,19 @@
+/// Matches a string against a pattern in which `*` matches any sequence of characters.
+pub(crate) fn glob(pattern: &str, s: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut s) = s.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<_> = parts.collect();
+    let Some(last) = parts.pop() else {
+        return s.is_empty();
+    };
+    for part in parts {
+        match s.find(part) {
+            Some(pos) => s = &s[pos + part.len()..],
+            None => return false,
+        }
+    }
+    s.ends_with(last)
+}
@@ -0,0 +1,714 @@
+//! A tracker for the role state of xdg_surfaces.
+//!
+//! The compositor configures xdg_surfaces with a sequence of role-specific events
+//! (xdg_toplevel.configure, xdg_popup.configure, ...) that is terminated by an
+//! xdg_surface.configure event with a serial. The client responds with an
+//! xdg_surface.ack_configure request and the acked state becomes current with the next
+//! wl_surface.commit. [`TrackedXdgSurface`] models this state machine.
+//!
+//! # Serials
+//!
+//! The serials seen by the client are allocated by the tracker and are independent of
+//! the serials used by the compositor. This allows proxies to inject their own
+//! configure sequences and to coalesce sequences sent by the compositor without
+//! confusing either side. When the client acks a configure event,
+//! [`TrackedXdgSurface::ack_configure`] returns the compositor serial that should be
+//! acked in turn.
+//!
+//! # Integration
+//!
+//! Proxies that do not need their own handlers can use
+//! [`XdgTracker::track_xdg_wm_base`]. This installs handlers on the xdg_wm_base and on
+//! all xdg_surfaces, xdg_toplevels, and xdg_popups created from it. These handlers
+//! forward all messages and remap configure serials.
+//!
+//! Proxies with their own handlers create the tracked surfaces via
+//! [`XdgTracker::create_xdg_surface`] and call the corresponding functions when they
+//! receive or send messages. The role events recorded in the tracker should be the
+//! events that the client actually sees.
+//!
+//! In both cases, [`TrackedXdgSurface::commit`] must be called when the wl_surface is
+//! committed. This can be done from a [`SurfaceTrackerHandler`]:
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use wl_proxy::{
+//! #     surface_tracker::{SurfaceTrackerHandler, TrackedSurface},
+//! #     xdg_tracker::XdgTracker,
+//! # };
+//! struct Handler {
+//!     xdg: Rc<XdgTracker>,
+//! }
+//!
+//! impl SurfaceTrackerHandler for Handler {
+//!     fn applied(&mut self, surface: &Rc<TrackedSurface>) {
+//!         if let Some(wl_surface) = surface.wl_surface()
+//!             && let Some(xdg_surface) = self.xdg.get(&wl_surface)
+//!         {
+//!             xdg_surface.commit();
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`SurfaceTrackerHandler`]: crate::surface_tracker::SurfaceTrackerHandler
+
+use {
+    crate::{
+        object::{ObjectCoreApi, ObjectUtils},
+        protocols::{
+            wayland::wl_surface::WlSurface,
+            xdg_shell::{
+                xdg_popup::{XdgPopup, XdgPopupHandler},
+                xdg_positioner::XdgPositioner,
+                xdg_surface::{XdgSurface, XdgSurfaceHandler},
+                xdg_toplevel::{XdgToplevel, XdgToplevelHandler, XdgToplevelState},
+                xdg_wm_base::{XdgWmBase, XdgWmBaseHandler},
+            },
+        },
+    },
+    error_reporter::Report,
+    std::{
+        cell::{Cell, RefCell},
+        collections::{BTreeMap, VecDeque},
+        rc::{Rc, Weak},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A tracker for xdg_surfaces.
+///
+/// Usually there is one tracker per client.
+pub struct XdgTracker {
+    surfaces: RefCell<BTreeMap<u64, Weak<TrackedXdgSurface>>>,
+}
+
+/// An xdg_surface tracked by an [`XdgTracker`].
+pub struct TrackedXdgSurface {
+    tracker: Weak<XdgTracker>,
+    id: u64,
+    wl_surface: Weak<WlSurface>,
+    xdg_surface: Weak<XdgSurface>,
+    role: Cell<Option<XdgRole>>,
+    toplevel: RefCell<Weak<XdgToplevel>>,
+    popup: RefCell<Weak<XdgPopup>>,
+    parent: RefCell<Weak<TrackedXdgSurface>>,
+    serials: RefCell<Serials>,
+    /// The role events of the configure sequence that has not yet been terminated.
+    pending_events: RefCell<ConfigureEvents>,
+    /// The role events of the last configure sequence sent to the client.
+    last_events: RefCell<ConfigureEvents>,
+    acked: RefCell<Option<Rc<XdgConfigure>>>,
+    current: RefCell<Option<Rc<XdgConfigure>>>,
+    pending_geometry: Cell<Option<[i32; 4]>>,
+    geometry: Cell<Option<[i32; 4]>>,
+    pending_min_size: Cell<Option<(i32, i32)>>,
+    min_size: Cell<(i32, i32)>,
+    pending_max_size: Cell<Option<(i32, i32)>>,
+    max_size: Cell<(i32, i32)>,
+}
+
+/// The role of an xdg_surface.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum XdgRole {
+    /// The surface has an xdg_toplevel.
+    Toplevel,
+    /// The surface has an xdg_popup.
+    Popup,
+}
+
+/// A configure sequence sent to the client.
+#[derive(Clone, Debug)]
+pub struct XdgConfigure {
+    /// The serial seen by the client.
+    pub serial: u32,
+    /// The serial of the compositor configure event that this sequence corresponds to.
+    ///
+    /// This is `None` for sequences injected by the proxy.
+    pub server_serial: Option<u32>,
+    /// The xdg_toplevel state of the sequence.
+    pub toplevel: Option<XdgToplevelConfigure>,
+    /// The xdg_popup state of the sequence.
+    pub popup: Option<XdgPopupConfigure>,
+}
+
+/// The state sent in xdg_toplevel events.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct XdgToplevelConfigure {
+    /// The suggested width. 0 if the client should decide.
+    pub width: i32,
+    /// The suggested height. 0 if the client should decide.
+    pub height: i32,
+    /// The states of the toplevel.
+    pub states: Vec<XdgToplevelState>,
+    /// The bounds sent via xdg_toplevel.configure_bounds.
+    pub bounds: Option<(i32, i32)>,
+}
+
+/// The state sent in xdg_popup events.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct XdgPopupConfigure {
+    /// The x position relative to the parent's window geometry.
+    pub x: i32,
+    /// The y position relative to the parent's window geometry.
+    pub y: i32,
+    /// The width of the popup.
+    pub width: i32,
+    /// The height of the popup.
+    pub height: i32,
+    /// The token of the xdg_popup.repositioned event that preceded the configure
+    /// event, if any.
+    pub repositioned: Option<u32>,
+}
+
+#[derive(Default)]
+struct Serials {
+    /// The next serial sent to the client. Serials are tracked as u64 to handle
+    /// wrap-around of the u32 wire serials.
+    next: u64,
+    /// The configure sequences sent to the client that have not yet been acked.
+    pending: VecDeque<(u64, XdgConfigure)>,
+    /// The last serial acked by the client. 0 if no serial has been acked.
+    acked: u64,
+}
+
+#[derive(Clone, Default)]
+struct ConfigureEvents {
+    toplevel: Option<XdgToplevelConfigure>,
+    popup: Option<XdgPopupConfigure>,
+}
+
+/// An error emitted by an [`XdgTracker`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct XdgTrackerError(#[from] XdgTrackerErrorKind);
+
+#[derive(Debug, Error)]
+enum XdgTrackerErrorKind {
+    #[error("the xdg_surface already has the role {0:?}")]
+    HasRole(XdgRole),
+    #[error("the xdg_surface does not have the role {0:?}")]
+    NotRole(XdgRole),
+    #[error("serial {0} does not belong to an unacked configure event")]
+    InvalidSerial(u32),
+    #[error("the xdg_surface object has been destroyed")]
+    Destroyed,
+}
+
+struct XdgWmBaseTracker(Rc<XdgTracker>);
+
+struct XdgSurfaceForwarder(Rc<TrackedXdgSurface>);
+
+struct XdgToplevelForwarder(Rc<TrackedXdgSurface>);
+
+struct XdgPopupForwarder(Rc<TrackedXdgSurface>);
+
+impl XdgTracker {
+    /// Creates a new tracker.
+    pub fn new() -> Rc<Self> {
+        Rc::new(Self {
+            surfaces: Default::default(),
+        })
+    }
+
+    /// Starts tracking an xdg_surface.
+    ///
+    /// This should be called from [`XdgWmBaseHandler::handle_get_xdg_surface`].
+    pub fn create_xdg_surface(
+        self: &Rc<Self>,
+        xdg_surface: &Rc<XdgSurface>,
+        wl_surface: &Rc<WlSurface>,
+    ) -> Rc<TrackedXdgSurface> {
+        let tracked = Rc::new(TrackedXdgSurface {
+            tracker: Rc::downgrade(self),
+            id: wl_surface.unique_id(),
+            wl_surface: Rc::downgrade(wl_surface),
+            xdg_surface: Rc::downgrade(xdg_surface),
+            role: Default::default(),
+            toplevel: Default::default(),
+            popup: Default::default(),
+            parent: Default::default(),
+            serials: RefCell::new(Serials {
+                next: 1,
+                ..Default::default()
+            }),
+            pending_events: Default::default(),
+            last_events: Default::default(),
+            acked: Default::default(),
+            current: Default::default(),
+            pending_geometry: Default::default(),
+            geometry: Default::default(),
+            pending_min_size: Default::default(),
+            min_size: Default::default(),
+            pending_max_size: Default::default(),
+            max_size: Default::default(),
+        });
+        self.surfaces
+            .borrow_mut()
+            .insert(tracked.id, Rc::downgrade(&tracked));
+        tracked
+    }
+
+    /// Returns the tracked xdg_surface of a wl_surface.
+    pub fn get(&self, wl_surface: &WlSurface) -> Option<Rc<TrackedXdgSurface>> {
+        self.surfaces
+            .borrow()
+            .get(&wl_surface.unique_id())?
+            .upgrade()
+    }
+
+    /// Returns all tracked xdg_surfaces in the order in which they were created.
+    pub fn surfaces(&self) -> Vec<Rc<TrackedXdgSurface>> {
+        self.surfaces
+            .borrow()
+            .values()
+            .filter_map(|s| s.upgrade())
+            .collect()
+    }
+
+    /// Installs a handler on an xdg_wm_base that tracks all xdg_surfaces created from
+    /// it.
+    ///
+    /// The handlers forward all messages. Configure serials are remapped.
+    pub fn track_xdg_wm_base(self: &Rc<Self>, xdg_wm_base: &Rc<XdgWmBase>) {
+        xdg_wm_base.set_handler(XdgWmBaseTracker(self.clone()));
+    }
+}
+
+impl TrackedXdgSurface {
+    /// Returns the wl_surface.
+    pub fn wl_surface(&self) -> Option<Rc<WlSurface>> {
+        self.wl_surface.upgrade()
+    }
+
+    /// Returns the xdg_surface.
+    pub fn xdg_surface(&self) -> Option<Rc<XdgSurface>> {
+        self.xdg_surface.upgrade()
+    }
+
+    /// Returns the role of the surface.
+    pub fn role(&self) -> Option<XdgRole> {
+        self.role.get()
+    }
+
+    /// Returns the xdg_toplevel of the surface.
+    pub fn toplevel(&self) -> Option<Rc<XdgToplevel>> {
+        self.toplevel.borrow().upgrade()
+    }
+
+    /// Returns the xdg_popup of the surface.
+    pub fn popup(&self) -> Option<Rc<XdgPopup>> {
+        self.popup.borrow().upgrade()
+    }
+
+    /// Returns the parent of the popup, if any.
+    pub fn parent(&self) -> Option<Rc<TrackedXdgSurface>> {
+        self.parent.borrow().upgrade()
+    }
+
+    /// Handles an xdg_surface.get_toplevel request.
+    pub fn set_toplevel(&self, toplevel: &Rc<XdgToplevel>) -> Result<(), XdgTrackerError> {
+        self.set_role(XdgRole::Toplevel)?;
+        *self.toplevel.borrow_mut() = Rc::downgrade(toplevel);
+        Ok(())
+    }
+
+    /// Handles an xdg_surface.get_popup request.
+    pub fn set_popup(
+        &self,
+        popup: &Rc<XdgPopup>,
+        parent: Option<&Rc<TrackedXdgSurface>>,
+    ) -> Result<(), XdgTrackerError> {
+        self.set_role(XdgRole::Popup)?;
+        *self.popup.borrow_mut() = Rc::downgrade(popup);
+        *self.parent.borrow_mut() = parent.map(Rc::downgrade).unwrap_or_default();
+        Ok(())
+    }
+
+    fn set_role(&self, role: XdgRole) -> Result<(), XdgTrackerError> {
+        if let Some(old) = self.role.get() {
+            return Err(XdgTrackerErrorKind::HasRole(old).into());
+        }
+        self.role.set(Some(role));
+        Ok(())
+    }
+
+    /// Handles an xdg_surface.set_window_geometry request.
+    pub fn set_window_geometry(&self, x: i32, y: i32, width: i32, height: i32) {
+        self.pending_geometry.set(Some([x, y, width, height]));
+    }
+
+    /// Handles an xdg_toplevel.set_min_size request.
+    pub fn set_min_size(&self, width: i32, height: i32) {
+        self.pending_min_size.set(Some((width, height)));
+    }
+
+    /// Handles an xdg_toplevel.set_max_size request.
+    pub fn set_max_size(&self, width: i32, height: i32) {
+        self.pending_max_size.set(Some((width, height)));
+    }
+
+    /// Returns the committed window geometry `[x, y, width, height]`.
+    ///
+    /// This is `None` if the client has never set the window geometry.
+    pub fn window_geometry(&self) -> Option<[i32; 4]> {
+        self.geometry.get()
+    }
+
+    /// Returns the committed minimum size of the toplevel.
+    pub fn min_size(&self) -> (i32, i32) {
+        self.min_size.get()
+    }
+
+    /// Returns the committed maximum size of the toplevel.
+    pub fn max_size(&self) -> (i32, i32) {
+        self.max_size.get()
+    }
+
+    /// Records an xdg_toplevel.configure event sent to the client.
+    ///
+    /// `states` is the array of states as it appears on the wire.
+    pub fn toplevel_configure(&self, width: i32, height: i32, states: &[u8]) {
+        let states = states
+            .chunks_exact(4)
+            .map(|s| XdgToplevelState(u32::from_ne_bytes(s.try_into().unwrap())))
+            .collect();
+        let events = &mut *self.pending_events.borrow_mut();
+        let toplevel = events.toplevel.get_or_insert_default();
+        toplevel.width = width;
+        toplevel.height = height;
+        toplevel.states = states;
+    }
+
+    /// Records an xdg_toplevel.configure_bounds event sent to the client.
+    pub fn toplevel_configure_bounds(&self, width: i32, height: i32) {
+        let events = &mut *self.pending_events.borrow_mut();
+        let toplevel = events.toplevel.get_or_insert_default();
+        toplevel.bounds = Some((width, height));
+    }
+
+    /// Records an xdg_popup.configure event sent to the client.
+    pub fn popup_configure(&self, x: i32, y: i32, width: i32, height: i32) {
+        let events = &mut *self.pending_events.borrow_mut();
+        let popup = events.popup.get_or_insert_default();
+        popup.x = x;
+        popup.y = y;
+        popup.width = width;
+        popup.height = height;
+    }
+
+    /// Records an xdg_popup.repositioned event sent to the client.
+    pub fn popup_repositioned(&self, token: u32) {
+        let events = &mut *self.pending_events.borrow_mut();
+        let popup = events.popup.get_or_insert_default();
+        popup.repositioned = Some(token);
+    }
+
+    /// Terminates the current configure sequence.
+    ///
+    /// This should be called before an xdg_surface.configure event is sent to the
+    /// client. `server_serial` is the serial of the corresponding compositor event, if
+    /// any. The returned serial must be used in the event sent to the client.
+    ///
+    /// Role events that were not recorded for this sequence retain their values from
+    /// the previous sequence, except for xdg_popup.repositioned.
+    pub fn configure(&self, server_serial: Option<u32>) -> u32 {
+        let events = self.take_events();
+        let serials = &mut *self.serials.borrow_mut();
+        let serial = serials.next;
+        serials.next += 1;
+        let configure = XdgConfigure {
+            serial: serial as u32,
+            server_serial,
+            toplevel: events.toplevel,
+            popup: events.popup,
+        };
+        serials.pending.push_back((serial, configure));
+        serial as u32
+    }
+
+    /// Coalesces a compositor configure sequence with the last sequence sent to the
+    /// client.
+    ///
+    /// This can be used instead of [`Self::configure`] if the proxy does not want to
+    /// forward the xdg_surface.configure event. When the client acks the last sequence
+    /// sent to it, `server_serial` will be acked instead.
+    ///
+    /// Returns the serial of the client sequence that the compositor sequence was
+    /// merged into. If there is no unacked client sequence, returns `None` and the
+    /// proxy should ack `server_serial` itself.
+    pub fn coalesce_configure(&self, server_serial: u32) -> Option<u32> {
+        let serials = &mut *self.serials.borrow_mut();
+        let (_, configure) = serials.pending.back_mut()?;
+        configure.server_serial = Some(server_serial);
+        Some(configure.serial)
+    }
+
+    fn take_events(&self) -> ConfigureEvents {
+        let pending = self.pending_events.take();
+        let last = &mut *self.last_events.borrow_mut();
+        if pending.toplevel.is_some() {
+            last.toplevel = pending.toplevel;
+        }
+        if let Some(popup) = &mut last.popup {
+            popup.repositioned = None;
+        }
+        if pending.popup.is_some() {
+            last.popup = pending.popup;
+        }
+        last.clone()
+    }
+
+    /// Sends an xdg_toplevel.configure event followed by an xdg_surface.configure
+    /// event to the client.
+    ///
+    /// The events are not forwarded to the compositor. Returns the serial of the
+    /// configure event.
+    pub fn inject_toplevel_configure(
+        &self,
+        width: i32,
+        height: i32,
+        states: &[XdgToplevelState],
+    ) -> Result<u32, XdgTrackerError> {
+        if self.role.get() != Some(XdgRole::Toplevel) {
+            return Err(XdgTrackerErrorKind::NotRole(XdgRole::Toplevel).into());
+        }
+        let toplevel = self.toplevel().ok_or(XdgTrackerErrorKind::Destroyed)?;
+        let xdg_surface = self.xdg_surface().ok_or(XdgTrackerErrorKind::Destroyed)?;
+        let states: Vec<u32> = states.iter().map(|s| s.0).collect();
+        let states = uapi::as_bytes(&*states);
+        self.toplevel_configure(width, height, states);
+        toplevel.send_configure(width, height, states);
+        let serial = self.configure(None);
+        xdg_surface.send_configure(serial);
+        Ok(serial)
+    }
+
+    /// Sends an xdg_popup.configure event followed by an xdg_surface.configure event to
+    /// the client.
+    ///
+    /// The events are not forwarded to the compositor. Returns the serial of the
+    /// configure event.
+    pub fn inject_popup_configure(
+        &self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> Result<u32, XdgTrackerError> {
+        if self.role.get() != Some(XdgRole::Popup) {
+            return Err(XdgTrackerErrorKind::NotRole(XdgRole::Popup).into());
+        }
+        let popup = self.popup().ok_or(XdgTrackerErrorKind::Destroyed)?;
+        let xdg_surface = self.xdg_surface().ok_or(XdgTrackerErrorKind::Destroyed)?;
+        self.popup_configure(x, y, width, height);
+        popup.send_configure(x, y, width, height);
+        let serial = self.configure(None);
+        xdg_surface.send_configure(serial);
+        Ok(serial)
+    }
+
+    /// Handles an xdg_surface.ack_configure request.
+    ///
+    /// The serial must belong to a configure event that has been sent to the client and
+    /// that has not yet been acked. Acking a serial implicitly acks all earlier serials.
+    ///
+    /// Returns the compositor serial that should be acked, if any. This is the serial
+    /// of the latest compositor sequence covered by the acked serial.
+    pub fn ack_configure(&self, serial: u32) -> Result<Option<u32>, XdgTrackerError> {
+        let serials = &mut *self.serials.borrow_mut();
+        let full = serials.recover(serial);
+        if !serials.pending.iter().any(|(s, _)| *s == full) {
+            return Err(XdgTrackerErrorKind::InvalidSerial(serial).into());
+        }
+        let mut server_serial = None;
+        while let Some((s, configure)) = serials.pending.pop_front() {
+            if configure.server_serial.is_some() {
+                server_serial = configure.server_serial;
+            }
+            if s == full {
+                serials.acked = s;
+                *self.acked.borrow_mut() = Some(Rc::new(configure));
+                break;
+            }
+        }
+        Ok(server_serial)
+    }
+
+    /// Returns whether the client has acked the configure event with the serial.
+    ///
+    /// This is also true if the client has acked a later serial.
+    pub fn is_acked(&self, serial: u32) -> bool {
+        let serials = &*self.serials.borrow();
+        let full = serials.recover(serial);
+        full <= serials.acked
+    }
+
+    /// Returns whether there are configure events that the client has not yet acked.
+    pub fn has_unacked_configure(&self) -> bool {
+        !self.serials.borrow().pending.is_empty()
+    }
+
+    /// Returns the last configure sequence acked by the client that has not yet been
+    /// committed.
+    pub fn acked_configure(&self) -> Option<Rc<XdgConfigure>> {
+        self.acked.borrow().clone()
+    }
+
+    /// Returns the configure sequence that the client has committed to.
+    ///
+    /// This is `None` until the client commits after the first ack.
+    pub fn current_configure(&self) -> Option<Rc<XdgConfigure>> {
+        self.current.borrow().clone()
+    }
+
+    /// Handles a wl_surface.commit request.
+    ///
+    /// This applies the window geometry and size constraints and makes the last acked
+    /// configure sequence current.
+    pub fn commit(&self) {
+        if let Some(geometry) = self.pending_geometry.take() {
+            self.geometry.set(Some(geometry));
+        }
+        if let Some(size) = self.pending_min_size.take() {
+            self.min_size.set(size);
+        }
+        if let Some(size) = self.pending_max_size.take() {
+            self.max_size.set(size);
+        }
+        if let Some(acked) = self.acked.take() {
+            *self.current.borrow_mut() = Some(acked);
+        }
+    }
+
+    /// Handles an xdg_surface.destroy request.
+    pub fn destroy(&self) {
+        if let Some(tracker) = self.tracker.upgrade() {
+            let surfaces = &mut *tracker.surfaces.borrow_mut();
+            if surfaces.get(&self.id).is_some_and(|s| s.as_ptr() == self) {
+                surfaces.remove(&self.id);
+            }
+        }
+    }
+}
+
+impl Serials {
+    fn recover(&self, serial: u32) -> u64 {
+        let mut full = self.next & !(u32::MAX as u64) | serial as u64;
+        if full >= self.next {
+            full = full.saturating_sub(u32::MAX as u64 + 1);
+        }
+        full
+    }
+}
+
+fn log_error(e: XdgTrackerError) {
+    log::warn!("Could not track an xdg_surface message: {}", Report::new(e));
+}
+
+impl XdgWmBaseHandler for XdgWmBaseTracker {
+    fn handle_get_xdg_surface(
+        &mut self,
+        slf: &Rc<XdgWmBase>,
+        id: &Rc<XdgSurface>,
+        surface: &Rc<WlSurface>,
+    ) {
+        let tracked = self.0.create_xdg_surface(id, surface);
+        id.set_handler(XdgSurfaceForwarder(tracked));
+        slf.send_get_xdg_surface(id, surface);
+    }
+}
+
+impl XdgSurfaceHandler for XdgSurfaceForwarder {
+    fn handle_destroy(&mut self, slf: &Rc<XdgSurface>) {
+        self.0.destroy();
+        slf.send_destroy();
+    }
+
+    fn handle_get_toplevel(&mut self, slf: &Rc<XdgSurface>, id: &Rc<XdgToplevel>) {
+        match self.0.set_toplevel(id) {
+            Ok(()) => id.set_handler(XdgToplevelForwarder(self.0.clone())),
+            Err(e) => log_error(e),
+        }
+        slf.send_get_toplevel(id);
+    }
+
+    fn handle_get_popup(
+        &mut self,
+        slf: &Rc<XdgSurface>,
+        id: &Rc<XdgPopup>,
+        parent: Option<&Rc<XdgSurface>>,
+        positioner: &Rc<XdgPositioner>,
+    ) {
+        let tracked_parent = parent
+            .and_then(|p| p.try_get_handler_ref::<XdgSurfaceForwarder>().ok())
+            .map(|p| p.0.clone());
+        match self.0.set_popup(id, tracked_parent.as_ref()) {
+            Ok(()) => id.set_handler(XdgPopupForwarder(self.0.clone())),
+            Err(e) => log_error(e),
+        }
+        slf.send_get_popup(id, parent, positioner);
+    }
+
+    fn handle_set_window_geometry(
+        &mut self,
+        slf: &Rc<XdgSurface>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.0.set_window_geometry(x, y, width, height);
+        slf.send_set_window_geometry(x, y, width, height);
+    }
+
+    fn handle_ack_configure(&mut self, slf: &Rc<XdgSurface>, serial: u32) {
+        match self.0.ack_configure(serial) {
+            Ok(Some(serial)) => slf.send_ack_configure(serial),
+            Ok(None) => {}
+            Err(e) => log_error(e),
+        }
+    }
+
+    fn handle_configure(&mut self, slf: &Rc<XdgSurface>, serial: u32) {
+        let serial = self.0.configure(Some(serial));
+        slf.send_configure(serial);
+    }
+}
+
+impl XdgToplevelHandler for XdgToplevelForwarder {
+    fn handle_set_max_size(&mut self, slf: &Rc<XdgToplevel>, width: i32, height: i32) {
+        self.0.set_max_size(width, height);
+        slf.send_set_max_size(width, height);
+    }
+
+    fn handle_set_min_size(&mut self, slf: &Rc<XdgToplevel>, width: i32, height: i32) {
+        self.0.set_min_size(width, height);
+        slf.send_set_min_size(width, height);
+    }
+
+    fn handle_configure(&mut self, slf: &Rc<XdgToplevel>, width: i32, height: i32, states: &[u8]) {
+        self.0.toplevel_configure(width, height, states);
+        slf.send_configure(width, height, states);
+    }
+
+    fn handle_configure_bounds(&mut self, slf: &Rc<XdgToplevel>, width: i32, height: i32) {
+        self.0.toplevel_configure_bounds(width, height);
+        slf.send_configure_bounds(width, height);
+    }
+}
+
+impl XdgPopupHandler for XdgPopupForwarder {
+    fn handle_configure(&mut self, slf: &Rc<XdgPopup>, x: i32, y: i32, width: i32, height: i32) {
+        self.0.popup_configure(x, y, width, height);
+        slf.send_configure(x, y, width, height);
+    }
+
+    fn handle_repositioned(&mut self, slf: &Rc<XdgPopup>, token: u32) {
+        self.0.popup_repositioned(token);
+        slf.send_repositioned(token);
+    }
+}
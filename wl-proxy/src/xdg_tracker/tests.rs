@@ -0,0 +1,299 @@
+#[cfg(feature = "headless")]
+use {
+    crate::{
+        baseline::Baseline,
+        headless::Headless,
+        object::{ConcreteObject, Object, ObjectRcUtils},
+        protocols::{
+            wayland::{
+                wl_callback::{WlCallback, WlCallbackHandler},
+                wl_compositor::WlCompositor,
+                wl_display::{WlDisplay, WlDisplayHandler},
+                wl_registry::{WlRegistry, WlRegistryHandler},
+            },
+            xdg_shell::{
+                xdg_surface::XdgSurfaceHandler, xdg_toplevel::XdgToplevelHandler,
+                xdg_wm_base::XdgWmBase,
+            },
+        },
+        state::State,
+        surface_tracker::{SurfaceTracker, SurfaceTrackerHandler, TrackedSurface},
+        test_framework::proxy::dispatch_blocking,
+    },
+    std::cell::{Cell, RefCell},
+};
+use {
+    crate::{
+        protocols::{
+            wayland::wl_surface::WlSurface,
+            xdg_shell::{
+                xdg_popup::XdgPopup,
+                xdg_surface::XdgSurface,
+                xdg_toplevel::{XdgToplevel, XdgToplevelState},
+            },
+        },
+        test_framework::proxy::{TestProxy, test_proxy},
+        xdg_tracker::{TrackedXdgSurface, XdgRole, XdgTracker},
+    },
+    std::rc::Rc,
+};
+
+fn surface(proxy: &TestProxy, tracker: &Rc<XdgTracker>) -> (Rc<WlSurface>, Rc<TrackedXdgSurface>) {
+    let state = &proxy.proxy_state;
+    let wl_surface = state.create_object::<WlSurface>(1);
+    let xdg_surface = state.create_object::<XdgSurface>(1);
+    let surface = tracker.create_xdg_surface(&xdg_surface, &wl_surface);
+    (wl_surface, surface)
+}
+
+#[test]
+fn serials() {
+    let proxy = test_proxy();
+    let tracker = XdgTracker::new();
+    let (_wl_surface, surface) = surface(&proxy, &tracker);
+    let toplevel = proxy.proxy_state.create_object::<XdgToplevel>(1);
+    surface.set_toplevel(&toplevel).unwrap();
+    assert_eq!(surface.role(), Some(XdgRole::Toplevel));
+
+    let states = [XdgToplevelState::ACTIVATED.0];
+    surface.toplevel_configure(100, 200, uapi::as_bytes(&states));
+    assert_eq!(surface.configure(Some(50)), 1);
+    assert_eq!(surface.configure(Some(51)), 2);
+    assert_eq!(surface.coalesce_configure(52), Some(2));
+    assert!(surface.ack_configure(5).is_err());
+    assert_eq!(surface.ack_configure(1).unwrap(), Some(50));
+    assert!(surface.ack_configure(1).is_err());
+    assert!(surface.is_acked(1));
+    assert!(!surface.is_acked(2));
+    assert!(surface.current_configure().is_none());
+
+    let acked = surface.acked_configure().unwrap();
+    let toplevel_state = acked.toplevel.as_ref().unwrap();
+    assert_eq!((toplevel_state.width, toplevel_state.height), (100, 200));
+    assert_eq!(toplevel_state.states, [XdgToplevelState::ACTIVATED]);
+    surface.commit();
+    assert_eq!(surface.current_configure().unwrap().serial, 1);
+    assert!(surface.acked_configure().is_none());
+
+    // Injected sequences ack the latest compositor serial they cover.
+    assert_eq!(surface.configure(None), 3);
+    assert!(surface.has_unacked_configure());
+    assert_eq!(surface.ack_configure(3).unwrap(), Some(52));
+    assert!(!surface.has_unacked_configure());
+    let acked = surface.acked_configure().unwrap();
+    assert_eq!(acked.server_serial, None);
+    assert_eq!(acked.toplevel.as_ref().unwrap().width, 100);
+    assert_eq!(surface.configure(None), 4);
+    assert_eq!(surface.ack_configure(4).unwrap(), None);
+    assert_eq!(surface.coalesce_configure(53), None);
+
+    // Serials wrap around.
+    surface.serials.borrow_mut().next = u32::MAX as u64;
+    assert_eq!(surface.configure(Some(60)), u32::MAX);
+    assert_eq!(surface.configure(Some(61)), 0);
+    assert_eq!(surface.ack_configure(u32::MAX).unwrap(), Some(60));
+    assert!(surface.is_acked(u32::MAX));
+    assert!(!surface.is_acked(0));
+    assert_eq!(surface.ack_configure(0).unwrap(), Some(61));
+}
+
+#[test]
+fn popup() {
+    let proxy = test_proxy();
+    let tracker = XdgTracker::new();
+    let (_wl_parent, parent) = surface(&proxy, &tracker);
+    let (_wl_surface, surface) = surface(&proxy, &tracker);
+    let popup = proxy.proxy_state.create_object::<XdgPopup>(1);
+    surface.set_popup(&popup, Some(&parent)).unwrap();
+    assert!(Rc::ptr_eq(&surface.parent().unwrap(), &parent));
+    let toplevel = proxy.proxy_state.create_object::<XdgToplevel>(1);
+    assert!(surface.set_toplevel(&toplevel).is_err());
+    assert!(surface.inject_toplevel_configure(0, 0, &[]).is_err());
+
+    surface.popup_repositioned(7);
+    surface.popup_configure(1, 2, 3, 4);
+    let serial = surface.configure(Some(10));
+    surface.ack_configure(serial).unwrap();
+    let popup_state = surface.acked_configure().unwrap().popup.unwrap();
+    assert_eq!(popup_state.repositioned, Some(7));
+    assert_eq!([popup_state.x, popup_state.width], [1, 3]);
+
+    let serial = surface.configure(Some(11));
+    surface.ack_configure(serial).unwrap();
+    let popup_state = surface.acked_configure().unwrap().popup.unwrap();
+    assert_eq!(popup_state.repositioned, None);
+    assert_eq!([popup_state.x, popup_state.width], [1, 3]);
+}
+
+#[test]
+fn double_buffering() {
+    let proxy = test_proxy();
+    let tracker = XdgTracker::new();
+    let (wl_surface, surface) = surface(&proxy, &tracker);
+    assert!(Rc::ptr_eq(&tracker.get(&wl_surface).unwrap(), &surface));
+    surface.set_window_geometry(1, 2, 3, 4);
+    surface.set_min_size(10, 20);
+    assert_eq!(surface.window_geometry(), None);
+    assert_eq!(surface.min_size(), (0, 0));
+    surface.commit();
+    assert_eq!(surface.window_geometry(), Some([1, 2, 3, 4]));
+    assert_eq!(surface.min_size(), (10, 20));
+    surface.set_max_size(30, 40);
+    surface.commit();
+    assert_eq!(surface.window_geometry(), Some([1, 2, 3, 4]));
+    assert_eq!(surface.max_size(), (30, 40));
+
+    surface.destroy();
+    assert!(tracker.get(&wl_surface).is_none());
+}
+
+#[cfg(feature = "headless")]
+#[derive(Clone)]
+struct Proxy {
+    surfaces: Rc<SurfaceTracker>,
+    xdg: Rc<XdgTracker>,
+}
+
+#[cfg(feature = "headless")]
+impl WlDisplayHandler for Proxy {
+    fn handle_get_registry(&mut self, slf: &Rc<WlDisplay>, registry: &Rc<WlRegistry>) {
+        registry.set_handler(self.clone());
+        slf.send_get_registry(registry);
+    }
+}
+
+#[cfg(feature = "headless")]
+impl WlRegistryHandler for Proxy {
+    fn handle_bind(&mut self, slf: &Rc<WlRegistry>, name: u32, id: Rc<dyn Object>) {
+        if let Some(compositor) = id.try_downcast::<WlCompositor>() {
+            self.surfaces.track_compositor(&compositor);
+        }
+        if let Some(xdg_wm_base) = id.try_downcast::<XdgWmBase>() {
+            self.xdg.track_xdg_wm_base(&xdg_wm_base);
+        }
+        slf.send_bind(name, id);
+    }
+}
+
+#[cfg(feature = "headless")]
+impl SurfaceTrackerHandler for Proxy {
+    fn applied(&mut self, surface: &Rc<TrackedSurface>) {
+        if let Some(wl_surface) = surface.wl_surface()
+            && let Some(xdg_surface) = self.xdg.get(&wl_surface)
+        {
+            xdg_surface.commit();
+        }
+    }
+}
+
+#[cfg(feature = "headless")]
+struct Done(Rc<Cell<bool>>);
+
+#[cfg(feature = "headless")]
+impl WlCallbackHandler for Done {
+    fn handle_done(&mut self, _slf: &Rc<WlCallback>, _callback_data: u32) {
+        self.0.set(true);
+    }
+}
+
+/// Records the configure events received by the client.
+#[cfg(feature = "headless")]
+#[derive(Clone, Default)]
+struct Client(Rc<RefCell<Vec<(u32, i32, i32)>>>, Rc<Cell<(i32, i32)>>);
+
+#[cfg(feature = "headless")]
+impl XdgSurfaceHandler for Client {
+    fn handle_configure(&mut self, _slf: &Rc<XdgSurface>, serial: u32) {
+        let (width, height) = self.1.get();
+        self.0.borrow_mut().push((serial, width, height));
+    }
+}
+
+#[cfg(feature = "headless")]
+impl XdgToplevelHandler for Client {
+    fn handle_configure(
+        &mut self,
+        _slf: &Rc<XdgToplevel>,
+        width: i32,
+        height: i32,
+        _states: &[u8],
+    ) {
+        self.1.set((width, height));
+    }
+}
+
+#[cfg(feature = "headless")]
+#[test]
+fn track_xdg_wm_base() {
+    let headless = Headless::new().unwrap();
+    let proxy = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(headless.connect().unwrap()))
+        .build()
+        .unwrap();
+    let (proxy_client, client_fd) = proxy.connect().unwrap();
+    let handler = Proxy {
+        surfaces: SurfaceTracker::new(),
+        xdg: XdgTracker::new(),
+    };
+    handler.surfaces.set_handler(handler.clone());
+    proxy_client.display.set_handler(handler.clone());
+    let client = State::builder(Baseline::ALL_OF_THEM)
+        .with_server_fd(&Rc::new(client_fd))
+        .build()
+        .unwrap();
+    let _destructors = [proxy.create_destructor(), client.create_destructor()];
+    let roundtrip = || {
+        let done = Rc::new(Cell::new(false));
+        client
+            .display()
+            .new_send_sync()
+            .set_handler(Done(done.clone()));
+        while !done.get() {
+            dispatch_blocking([&client, &proxy, headless.state()]).unwrap();
+        }
+    };
+    let registry = client.display().new_send_get_registry();
+    let compositor = client.create_object::<WlCompositor>(WlCompositor::XML_VERSION);
+    registry.send_bind(1, compositor.clone());
+    let wm_base = client.create_object::<XdgWmBase>(XdgWmBase::XML_VERSION);
+    registry.send_bind(4, wm_base.clone());
+
+    let surface = compositor.new_send_create_surface();
+    let xdg_surface = wm_base.new_send_get_xdg_surface(&surface);
+    let configures = Client::default();
+    xdg_surface.set_handler(configures.clone());
+    let toplevel = xdg_surface.new_send_get_toplevel();
+    toplevel.set_handler(configures.clone());
+    xdg_surface.send_set_window_geometry(0, 0, 10, 10);
+    surface.send_commit();
+    roundtrip();
+
+    let tracked = &handler.xdg.surfaces()[0];
+    let headless_toplevel = &headless.toplevels()[0];
+    assert_eq!(*configures.0.borrow(), [(1, 0, 0)]);
+    assert_eq!(tracked.window_geometry(), Some([0, 0, 10, 10]));
+
+    // Compositor sequences are acked with the compositor serial.
+    let server_serial = headless_toplevel.configure(100, 200).unwrap();
+    roundtrip();
+    assert_eq!(configures.0.borrow()[1], (2, 100, 200));
+    xdg_surface.send_ack_configure(2);
+    surface.send_commit();
+    roundtrip();
+    assert_eq!(headless_toplevel.acked_serial(), Some(server_serial));
+    let current = tracked.current_configure().unwrap();
+    assert_eq!(current.server_serial, Some(server_serial));
+    assert_eq!(current.toplevel.as_ref().unwrap().width, 100);
+
+    // Injected sequences are not acked to the compositor.
+    let serial = tracked.inject_toplevel_configure(300, 400, &[]).unwrap();
+    roundtrip();
+    assert_eq!(configures.0.borrow()[2], (serial, 300, 400));
+    xdg_surface.send_ack_configure(serial);
+    surface.send_commit();
+    roundtrip();
+    assert_eq!(headless_toplevel.acked_serial(), Some(server_serial));
+    let current = tracked.current_configure().unwrap();
+    assert_eq!(current.server_serial, None);
+    assert_eq!(current.toplevel.as_ref().unwrap().width, 300);
+}